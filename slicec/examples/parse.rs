@@ -7,5 +7,6 @@ use std::process::exit;
 pub fn main() {
     let options = SliceOptions::parse();
     let state = slicec::compile_from_options(&options, |_| {}, |_| {});
-    exit(i32::from(state.emit_diagnostics(&options)));
+    let has_errors = state.emit_diagnostics(&options).unwrap_or(true);
+    exit(i32::from(has_errors));
 }
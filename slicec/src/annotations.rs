@@ -0,0 +1,135 @@
+// Copyright (c) ZeroC, Inc.
+
+//! Side tables for attaching computed data to Slice elements, see [Annotations].
+
+use crate::grammar::{Element, TypeAlias};
+use std::any::{Any, TypeId};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+/// A kind of data which can be attached to Slice elements with [Annotations].
+///
+/// Annotations are declared by implementing this trait on a marker type, which is then used to access the annotation.
+///
+/// # Examples
+///
+/// ```
+/// # use slicec::annotations::Annotation;
+/// # use slicec::grammar::Struct;
+/// /// The name that a struct is mapped to in the generated code.
+/// struct MappedName;
+///
+/// impl Annotation for MappedName {
+///     type Element = Struct;
+///     type Value = String;
+/// }
+/// ```
+pub trait Annotation: 'static {
+    /// The type of element this annotation can be attached to.
+    type Element: Element + ?Sized;
+
+    /// The type of data this annotation attaches to elements.
+    type Value: Send + Sync + 'static;
+}
+
+/// The relative paths of the files which reference a type alias. This is attached to type aliases while patching.
+///
+/// References to type aliases are resolved to the alias's underlying type while patching, so afterwards, this is the
+/// only record of where each type alias is used. Type aliases which are never referenced don't have this annotation.
+pub struct TypeAliasReferences;
+
+impl Annotation for TypeAliasReferences {
+    type Element = TypeAlias;
+    type Value = BTreeSet<String>;
+}
+
+/// Side tables which hold data computed by backends and compiler passes, keyed by the elements the data is for.
+///
+/// This lets data that's expensive to compute (like mapped names, or wire sizes) be computed once and then looked up
+/// by later passes, instead of being recomputed by every visitor that needs it.
+///
+/// Each [Annotation] gets its own table, and elements are keyed by identity, not by identifier. So elements which share
+/// an identifier (like the parameters and return members of an operation) never share annotations. Since elements are
+/// only used as keys, and never stored, annotations don't borrow from the AST, and can outlive any references to it.
+/// However, keys are only meaningful for the AST that the annotated elements are stored in.
+#[derive(Default)]
+pub struct Annotations {
+    tables: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl Annotations {
+    /// Creates an empty set of side tables.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attaches a value to the provided element, returning the value that was previously attached to it, if any.
+    pub fn insert<A: Annotation>(&mut self, element: &A::Element, value: A::Value) -> Option<A::Value> {
+        self.table_mut::<A>().insert(key_for(element), value)
+    }
+
+    /// Returns a reference to the value attached to the provided element, if there is one.
+    pub fn get<A: Annotation>(&self, element: &A::Element) -> Option<&A::Value> {
+        self.table::<A>()?.get(&key_for(element))
+    }
+
+    /// Returns a mutable reference to the value attached to the provided element, if there is one.
+    pub fn get_mut<A: Annotation>(&mut self, element: &A::Element) -> Option<&mut A::Value> {
+        self.table_mut::<A>().get_mut(&key_for(element))
+    }
+
+    /// Returns a mutable reference to the value attached to the provided element. If there is no value attached to
+    /// it yet, one is computed by calling `compute`, and attached to the element first.
+    pub fn get_or_insert_with<A: Annotation>(
+        &mut self,
+        element: &A::Element,
+        compute: impl FnOnce() -> A::Value,
+    ) -> &mut A::Value {
+        self.table_mut::<A>().entry(key_for(element)).or_insert_with(compute)
+    }
+
+    /// Removes the value attached to the provided element, and returns it, if there was one.
+    pub fn remove<A: Annotation>(&mut self, element: &A::Element) -> Option<A::Value> {
+        self.table_mut::<A>().remove(&key_for(element))
+    }
+
+    /// Returns true if a value of the specified annotation is attached to the provided element.
+    pub fn contains<A: Annotation>(&self, element: &A::Element) -> bool {
+        self.get::<A>(element).is_some()
+    }
+
+    /// Returns the number of elements that a value of the specified annotation is attached to.
+    pub fn count<A: Annotation>(&self) -> usize {
+        self.table::<A>().map_or(0, HashMap::len)
+    }
+
+    /// Removes all the values of the specified annotation.
+    pub fn clear<A: Annotation>(&mut self) {
+        self.tables.remove(&TypeId::of::<A>());
+    }
+
+    fn table<A: Annotation>(&self) -> Option<&HashMap<usize, A::Value>> {
+        let table = self.tables.get(&TypeId::of::<A>())?;
+        Some(table.downcast_ref().expect("annotation table has the wrong type"))
+    }
+
+    fn table_mut<A: Annotation>(&mut self) -> &mut HashMap<usize, A::Value> {
+        let table = self.tables.entry(TypeId::of::<A>()).or_insert_with(|| {
+            let table: HashMap<usize, A::Value> = HashMap::new();
+            Box::new(table)
+        });
+        table.downcast_mut().expect("annotation table has the wrong type")
+    }
+}
+
+impl fmt::Debug for Annotations {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Annotations").field("tables", &self.tables.len()).finish()
+    }
+}
+
+/// Returns the key that values attached to the provided element are stored under: the element's address.
+/// Elements are boxed by the AST, so their addresses are stable, and unique for as long as the AST is alive.
+fn key_for<T: ?Sized>(element: &T) -> usize {
+    element as *const T as *const () as usize
+}
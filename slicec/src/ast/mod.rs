@@ -3,9 +3,13 @@
 //! TODO write a doc comment for the module.
 
 pub mod node;
+pub mod node_id;
+pub mod query;
 
 use self::node::Node;
-use crate::grammar::{Element, NamedSymbol, Primitive};
+use self::node_id::NodeId;
+use self::query::Query;
+use crate::grammar::{Element, Entity, NamedSymbol, Primitive};
 use crate::utils::ptr_util::{OwnedPtr, WeakPtr};
 use std::collections::HashMap;
 
@@ -45,7 +49,7 @@ impl Ast {
     /// ```
     /// # use slicec::ast::Ast;
     /// let ast = Ast::create();
-    /// assert_eq!(ast.as_slice().len(), 17); // Only the 17 primitives are defined.
+    /// assert_eq!(ast.as_slice().len(), 20); // Only the 20 primitives are defined.
     /// ```
     pub fn create() -> Ast {
         // Primitive types are built in to the compiler. Since they aren't defined in Slice, we 'define' them here,
@@ -68,6 +72,9 @@ impl Ast {
             Node::Primitive(OwnedPtr::new(Primitive::Float32)),
             Node::Primitive(OwnedPtr::new(Primitive::Float64)),
             Node::Primitive(OwnedPtr::new(Primitive::String)),
+            Node::Primitive(OwnedPtr::new(Primitive::Uuid)),
+            Node::Primitive(OwnedPtr::new(Primitive::Timestamp)),
+            Node::Primitive(OwnedPtr::new(Primitive::Duration)),
             Node::Primitive(OwnedPtr::new(Primitive::AnyClass)),
         ];
 
@@ -88,7 +95,10 @@ impl Ast {
             ("float32".to_owned(), 13),
             ("float64".to_owned(), 14),
             ("string".to_owned(), 15),
-            ("AnyClass".to_owned(), 16),
+            ("uuid".to_owned(), 16),
+            ("timestamp".to_owned(), 17),
+            ("duration".to_owned(), 18),
+            ("AnyClass".to_owned(), 19),
         ]);
 
         Ast { elements, lookup_table }
@@ -288,6 +298,73 @@ impl Ast {
         self.find_node_with_scope(identifier, scope).and_then(|x| x.try_into())
     }
 
+    /// Returns a typed [handle](NodeId) to the Slice element with the provided identifier and specified type, if one
+    /// exists. The identifier must be fully qualified, as in [`find_element`](Ast::find_element).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicec::ast::Ast;
+    /// # use slicec::grammar::*;
+    /// let ast = Ast::create();
+    ///
+    /// let int32_id = ast.find_node_id::<Primitive>("int32").unwrap();
+    /// assert_eq!(ast.get(int32_id), &Primitive::Int32);
+    /// ```
+    pub fn find_node_id<'a, T: Element + ?Sized + 'a>(
+        &'a self,
+        identifier: &str,
+    ) -> Result<NodeId<T>, LookupError>
+    where
+        &'a T: TryFrom<&'a Node, Error = LookupError>,
+    {
+        let index = *self.lookup_table.get(identifier).ok_or_else(|| LookupError::DoesNotExist {
+            identifier: identifier.to_owned(),
+        })?;
+        <&T>::try_from(&self.elements[index]).map(|_| NodeId::new(index))
+    }
+
+    /// Returns typed [handles](NodeId) to every Slice element of the specified type in this AST, in the order they're
+    /// stored in.
+    pub fn node_ids<'a, T: Element + ?Sized + 'a>(&'a self) -> impl Iterator<Item = NodeId<T>> + 'a
+    where
+        &'a T: TryFrom<&'a Node, Error = LookupError>,
+    {
+        let elements = self.elements.iter().enumerate();
+        elements.filter_map(|(index, node)| <&T>::try_from(node).ok().map(|_| NodeId::new(index)))
+    }
+
+    /// Returns a reference to the Slice element that the provided [handle](NodeId) refers to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle was created from a different AST, and doesn't refer to an element of the correct type.
+    pub fn get<'a, T: Element + ?Sized>(&'a self, id: NodeId<T>) -> &'a T
+    where
+        &'a T: TryFrom<&'a Node, Error = LookupError>,
+    {
+        let node = self.elements.get(id.index()).expect("node id is out of bounds for this AST");
+        node.try_into().expect("node id refers to an element of a different type")
+    }
+
+    /// Returns a [Query] over all the [entities](Entity) contained in this AST, which can be narrowed down by chaining
+    /// filters onto it. See [Query] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicec::ast::Ast;
+    /// # use slicec::grammar::*;
+    /// let ast = Ast::create();
+    ///
+    /// // Find all the structs in module 'Foo'.
+    /// let structs = ast.query().of_type::<Struct>().in_module("Foo");
+    /// assert_eq!(structs.iter().count(), 0);
+    /// ```
+    pub fn query(&self) -> Query<'_, dyn Entity> {
+        Query::new(self)
+    }
+
     /// Returns an immutable slice of all the [nodes](Node) contained in this AST.
     ///
     /// # Examples
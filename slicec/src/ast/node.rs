@@ -83,7 +83,7 @@ macro_rules! generate_node_enum {
 // generate the `Node` enum with variants for every type allowed to be in the AST.
 generate_node_enum! {
     Module, Struct, Class, Exception, Field, Interface, Operation, Parameter, Enum,
-    Enumerator, CustomType, TypeAlias, ResultType, Sequence, Dictionary, Primitive, Attribute
+    Enumerator, CustomType, TypeAlias, Constant, ResultType, Sequence, Dictionary, Primitive, Attribute
 }
 
 impl<'a> TryFrom<&'a Node> for WeakPtr<dyn Type> {
@@ -161,6 +161,7 @@ impl<'a> TryFrom<&'a Node> for &'a dyn NamedSymbol {
             Node::Enumerator(enumerator_ptr) => Ok(enumerator_ptr.borrow()),
             Node::CustomType(custom_type_ptr) => Ok(custom_type_ptr.borrow()),
             Node::TypeAlias(type_alias_ptr) => Ok(type_alias_ptr.borrow()),
+            Node::Constant(constant_ptr) => Ok(constant_ptr.borrow()),
             _ => Err(LookupError::TypeMismatch {
                 expected: "named symbol".to_owned(),
                 actual: ccase!(lower, node.to_string()),
@@ -190,6 +191,7 @@ impl<'a> TryFrom<&'a Node> for WeakPtr<dyn Entity> {
             Node::Enumerator(enumerator_ptr) => Ok(downgrade_as!(enumerator_ptr, dyn Entity)),
             Node::CustomType(custom_type_ptr) => Ok(downgrade_as!(custom_type_ptr, dyn Entity)),
             Node::TypeAlias(type_alias_ptr) => Ok(downgrade_as!(type_alias_ptr, dyn Entity)),
+            Node::Constant(constant_ptr) => Ok(downgrade_as!(constant_ptr, dyn Entity)),
             _ => Err(LookupError::TypeMismatch {
                 expected: "entity".to_owned(),
                 actual: ccase!(lower, node.to_string()),
@@ -219,6 +221,7 @@ impl<'a> TryFrom<&'a Node> for &'a dyn Entity {
             Node::Enumerator(enumerator_ptr) => Ok(enumerator_ptr.borrow()),
             Node::CustomType(custom_type_ptr) => Ok(custom_type_ptr.borrow()),
             Node::TypeAlias(type_alias_ptr) => Ok(type_alias_ptr.borrow()),
+            Node::Constant(constant_ptr) => Ok(constant_ptr.borrow()),
             _ => Err(LookupError::TypeMismatch {
                 expected: "entity".to_owned(),
                 actual: ccase!(lower, node.to_string()),
@@ -255,6 +258,7 @@ impl_into_node_for!(Enum);
 impl_into_node_for!(Enumerator);
 impl_into_node_for!(CustomType);
 impl_into_node_for!(TypeAlias);
+impl_into_node_for!(Constant);
 impl_into_node_for!(ResultType);
 impl_into_node_for!(Sequence);
 impl_into_node_for!(Dictionary);
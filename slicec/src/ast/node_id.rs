@@ -0,0 +1,75 @@
+// Copyright (c) ZeroC, Inc.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// A typed handle to a Slice element stored in an [Ast](super::Ast), which can be resolved back to the element with
+/// [`Ast::get`](super::Ast::get).
+///
+/// Handles are just an index into the AST, so unlike references, they are cheap to store, have no lifetime, and can be
+/// freely serialized (see [`index`](NodeId::index)). Since the type of the element is encoded in the handle's type,
+/// resolving a handle never fails, as long as it's resolved against the AST it was created from.
+///
+/// Handles don't change how elements are stored. Each one is an index into the AST's list of elements, which are still
+/// owned by [OwnedPtr](crate::utils::ptr_util::OwnedPtr)s, and still refer to each other with
+/// [WeakPtr](crate::utils::ptr_util::WeakPtr)s.
+pub struct NodeId<T: ?Sized> {
+    index: usize,
+    _phantom: PhantomData<fn() -> *const T>,
+}
+
+impl<T: ?Sized> NodeId<T> {
+    pub(crate) fn new(index: usize) -> Self {
+        NodeId {
+            index,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the index of the element this handle refers to, in [`Ast::as_slice`](super::Ast::as_slice).
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+// These are implemented by hand, since deriving them would add unnecessary bounds on `T`.
+impl<T: ?Sized> Clone for NodeId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for NodeId<T> {}
+
+impl<T: ?Sized> PartialEq for NodeId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T: ?Sized> Eq for NodeId<T> {}
+
+impl<T: ?Sized> Hash for NodeId<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl<T: ?Sized> PartialOrd for NodeId<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ?Sized> Ord for NodeId<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for NodeId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NodeId({})", self.index)
+    }
+}
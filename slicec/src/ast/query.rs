@@ -0,0 +1,122 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module provides a simple way to search an [Ast] for elements, without needing to write a custom [Visitor].
+//!
+//! [Visitor]: crate::visitor::Visitor
+
+use super::node::Node;
+use super::{Ast, LookupError};
+use crate::grammar::Entity;
+use std::marker::PhantomData;
+
+type NodeFilter<'a> = Box<dyn Fn(&'a Node) -> bool + 'a>;
+
+/// A query over the [entities](Entity) stored in an [Ast], created with [`Ast::query`].
+///
+/// Queries are built up by chaining filters, and then iterated over to get the matching elements. Elements are returned
+/// in the order they're stored in the AST. Each filter is applied to every element, so filters can be specified in any
+/// order. For example, to find all the deprecated operations in module `Foo`:
+///
+/// ```
+/// # use slicec::ast::Ast;
+/// # use slicec::grammar::*;
+/// # let ast = Ast::create();
+/// let operations = ast.query().of_type::<Operation>().in_module("Foo").with_attribute("deprecated");
+/// for operation in operations {
+///     println!("{}", operation.identifier());
+/// }
+/// ```
+pub struct Query<'a, T: ?Sized> {
+    ast: &'a Ast,
+    filters: Vec<NodeFilter<'a>>,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a> Query<'a, dyn Entity> {
+    pub(super) fn new(ast: &'a Ast) -> Self {
+        Query {
+            ast,
+            filters: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ?Sized + 'a> Query<'a, T>
+where
+    &'a T: TryFrom<&'a Node, Error = LookupError>,
+{
+    /// Restricts this query to elements of the specified type. Filters that were already added are kept.
+    pub fn of_type<U: ?Sized + 'a>(self) -> Query<'a, U>
+    where
+        &'a U: TryFrom<&'a Node, Error = LookupError>,
+    {
+        Query {
+            ast: self.ast,
+            filters: self.filters,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Restricts this query to entities that are declared within the specified module.
+    /// The module's identifier must be fully scoped (Ex: `Foo::Bar`), and nested modules aren't included.
+    pub fn in_module(self, module: &str) -> Self {
+        let module = module.trim_start_matches("::").to_owned();
+        self.filter_entities(move |entity| entity.module_scope() == module)
+    }
+
+    /// Restricts this query to entities that have an attribute with the specified directive applied to them.
+    pub fn with_attribute(self, directive: &str) -> Self {
+        let directive = directive.to_owned();
+        self.filter_entities(move |entity| {
+            let attributes = entity.attributes();
+            attributes.iter().any(|attribute| attribute.kind.directive() == directive)
+        })
+    }
+
+    /// Restricts this query to entities with the specified (unscoped) identifier.
+    pub fn with_identifier(self, identifier: &str) -> Self {
+        let identifier = identifier.to_owned();
+        self.filter_entities(move |entity| entity.identifier() == identifier)
+    }
+
+    /// Restricts this query to elements which satisfy the provided predicate.
+    pub fn filter(mut self, predicate: impl Fn(&'a T) -> bool + 'a) -> Self {
+        let filter = move |node: &'a Node| <&T>::try_from(node).is_ok_and(&predicate);
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// Returns an iterator over the elements matched by this query.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.ast
+            .as_slice()
+            .iter()
+            .filter(|node| self.filters.iter().all(|filter| filter(node)))
+            .filter_map(|node| node.try_into().ok())
+    }
+
+    fn filter_entities(mut self, predicate: impl Fn(&'a dyn Entity) -> bool + 'a) -> Self {
+        let filter = move |node: &'a Node| <&dyn Entity>::try_from(node).is_ok_and(&predicate);
+        self.filters.push(Box::new(filter));
+        self
+    }
+}
+
+impl<'a, T: ?Sized + 'a> IntoIterator for Query<'a, T>
+where
+    &'a T: TryFrom<&'a Node, Error = LookupError>,
+{
+    type Item = &'a T;
+    type IntoIter = Box<dyn Iterator<Item = &'a T> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let Query { ast, filters, .. } = self;
+        let iter = ast
+            .as_slice()
+            .iter()
+            .filter(move |node| filters.iter().all(|filter| filter(node)))
+            .filter_map(|node| node.try_into().ok());
+        Box::new(iter)
+    }
+}
@@ -0,0 +1,487 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains builders for constructing Slice definitions programmatically, instead of writing Slice files.
+//! This is intended for tools which convert other IDLs (like Protobuf or OpenAPI) into Slice, so they can reuse the
+//! compiler's validation and code generators, without generating and managing Slice files themselves.
+//!
+//! Each file is described by a [FileBuilder], and files are compiled with
+//! [compile_from_builders](crate::compile_from_builders). Builders are emitted as Slice source code in the canonical
+//! style (see [ToSliceString]), and then compiled like any other file. So the resulting AST is exactly the same as the
+//! AST of the equivalent Slice file, and it's patched and validated the same way. Each file's `raw_text` holds its
+//! emitted source code, and diagnostics point into it.
+//!
+//! Identifiers which are Slice keywords are escaped automatically. Types are written in Slice syntax, and can refer to
+//! any type which is visible from the file's module. Ex: `"int32"`, `"Sequence<Point>"`, or `"::Other::Widget?"`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use slicec::ast_builder::*;
+//! # use slicec::grammar::Struct;
+//! let file = FileBuilder::new("converted.slice", "Acme")
+//!     .add_struct(
+//!         StructBuilder::new("Point")
+//!             .field(MemberBuilder::new("x", "int32"))
+//!             .field(MemberBuilder::new("y", "int32")),
+//!     )
+//!     .add_interface(
+//!         InterfaceBuilder::new("Plotter").operation(
+//!             OperationBuilder::new("plot")
+//!                 .parameter(MemberBuilder::new("point", "Point"))
+//!                 .returns("bool"),
+//!         ),
+//!     );
+//!
+//! let state = slicec::compile_from_builders(&[file], None, |_| {}, |_| {});
+//! assert!(state.diagnostics.is_empty());
+//! assert!(state.ast.find_element::<Struct>("Acme::Point").is_ok());
+//! ```
+
+use crate::grammar::{format_body, format_list, ToSliceString};
+use crate::parsers::is_keyword;
+
+/// Describes a Slice file, containing a single module.
+#[derive(Clone, Debug)]
+pub struct FileBuilder {
+    /// The path that the file is reported under. It doesn't need to exist on disk.
+    pub path: String,
+    module: String,
+    definitions: Vec<String>,
+}
+
+impl FileBuilder {
+    /// Creates a file whose definitions are in the provided module. Ex: `"Acme::Widgets"`.
+    pub fn new(path: impl Into<String>, module: impl Into<String>) -> Self {
+        FileBuilder {
+            path: path.into(),
+            module: module.into(),
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Adds a struct to this file.
+    pub fn add_struct(mut self, struct_builder: StructBuilder) -> Self {
+        self.definitions.push(struct_builder.to_slice_string());
+        self
+    }
+
+    /// Adds an interface to this file.
+    pub fn add_interface(mut self, interface_builder: InterfaceBuilder) -> Self {
+        self.definitions.push(interface_builder.to_slice_string());
+        self
+    }
+
+    /// Adds an enum to this file.
+    pub fn add_enum(mut self, enum_builder: EnumBuilder) -> Self {
+        self.definitions.push(enum_builder.to_slice_string());
+        self
+    }
+}
+
+impl ToSliceString for FileBuilder {
+    fn to_slice_string(&self) -> String {
+        let module = self.module.split("::").map(escape_identifier).collect::<Vec<_>>().join("::");
+        let mut sections = vec![format!("module {module}")];
+        sections.extend(self.definitions.iter().cloned());
+        sections.join("\n\n") + "\n"
+    }
+}
+
+/// Describes a struct.
+#[derive(Clone, Debug)]
+pub struct StructBuilder {
+    prelude: Prelude,
+    identifier: String,
+    is_compact: bool,
+    fields: Vec<MemberBuilder>,
+}
+
+impl StructBuilder {
+    pub fn new(identifier: impl Into<String>) -> Self {
+        StructBuilder {
+            prelude: Prelude::default(),
+            identifier: identifier.into(),
+            is_compact: false,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Sets the doc comment of this struct. See [Prelude::doc].
+    pub fn doc(mut self, comment: impl Into<String>) -> Self {
+        self.prelude.doc = Some(comment.into());
+        self
+    }
+
+    /// Adds an attribute to this struct. See [Prelude::attributes].
+    pub fn attribute(mut self, attribute: impl Into<String>) -> Self {
+        self.prelude.attributes.push(attribute.into());
+        self
+    }
+
+    /// Makes this struct compact.
+    pub fn compact(mut self) -> Self {
+        self.is_compact = true;
+        self
+    }
+
+    /// Adds a field to this struct.
+    pub fn field(mut self, field: MemberBuilder) -> Self {
+        self.fields.push(field);
+        self
+    }
+}
+
+impl ToSliceString for StructBuilder {
+    fn to_slice_string(&self) -> String {
+        let compact = if self.is_compact { "compact " } else { "" };
+        let fields = self.fields.iter().map(ToSliceString::to_slice_string).collect::<Vec<_>>();
+        let body = format_body(&fields.join("\n"));
+        let identifier = escape_identifier(&self.identifier);
+        format!("{}{compact}struct {identifier}{body}", self.prelude.to_slice_string())
+    }
+}
+
+/// Describes an enum.
+#[derive(Clone, Debug)]
+pub struct EnumBuilder {
+    prelude: Prelude,
+    identifier: String,
+    is_unchecked: bool,
+    underlying: Option<String>,
+    enumerators: Vec<EnumeratorBuilder>,
+}
+
+impl EnumBuilder {
+    pub fn new(identifier: impl Into<String>) -> Self {
+        EnumBuilder {
+            prelude: Prelude::default(),
+            identifier: identifier.into(),
+            is_unchecked: false,
+            underlying: None,
+            enumerators: Vec::new(),
+        }
+    }
+
+    /// Sets the doc comment of this enum. See [Prelude::doc].
+    pub fn doc(mut self, comment: impl Into<String>) -> Self {
+        self.prelude.doc = Some(comment.into());
+        self
+    }
+
+    /// Adds an attribute to this enum. See [Prelude::attributes].
+    pub fn attribute(mut self, attribute: impl Into<String>) -> Self {
+        self.prelude.attributes.push(attribute.into());
+        self
+    }
+
+    /// Makes this enum unchecked. Unchecked enums must have an underlying type.
+    pub fn unchecked(mut self) -> Self {
+        self.is_unchecked = true;
+        self
+    }
+
+    /// Sets the underlying type of this enum. Ex: `"int32"`.
+    pub fn underlying(mut self, underlying: impl Into<String>) -> Self {
+        self.underlying = Some(underlying.into());
+        self
+    }
+
+    /// Adds an enumerator to this enum.
+    pub fn enumerator(mut self, enumerator: EnumeratorBuilder) -> Self {
+        self.enumerators.push(enumerator);
+        self
+    }
+}
+
+impl ToSliceString for EnumBuilder {
+    fn to_slice_string(&self) -> String {
+        let unchecked = if self.is_unchecked { "unchecked " } else { "" };
+        let underlying = self.underlying.as_ref().map_or(String::new(), |underlying| format!(" : {underlying}"));
+        let enumerators = self.enumerators.iter().map(ToSliceString::to_slice_string).collect::<Vec<_>>();
+        let body = format_body(&enumerators.join("\n"));
+        let identifier = escape_identifier(&self.identifier);
+        format!("{}{unchecked}enum {identifier}{underlying}{body}", self.prelude.to_slice_string())
+    }
+}
+
+/// Describes an enumerator.
+#[derive(Clone, Debug)]
+pub struct EnumeratorBuilder {
+    prelude: Prelude,
+    identifier: String,
+    value: Option<i128>,
+}
+
+impl EnumeratorBuilder {
+    pub fn new(identifier: impl Into<String>) -> Self {
+        EnumeratorBuilder {
+            prelude: Prelude::default(),
+            identifier: identifier.into(),
+            value: None,
+        }
+    }
+
+    /// Sets the doc comment of this enumerator. See [Prelude::doc].
+    pub fn doc(mut self, comment: impl Into<String>) -> Self {
+        self.prelude.doc = Some(comment.into());
+        self
+    }
+
+    /// Adds an attribute to this enumerator. See [Prelude::attributes].
+    pub fn attribute(mut self, attribute: impl Into<String>) -> Self {
+        self.prelude.attributes.push(attribute.into());
+        self
+    }
+
+    /// Sets the value of this enumerator explicitly. Otherwise, it's one more than the previous enumerator's value.
+    pub fn value(mut self, value: i128) -> Self {
+        self.value = Some(value);
+        self
+    }
+}
+
+impl ToSliceString for EnumeratorBuilder {
+    fn to_slice_string(&self) -> String {
+        let value = self.value.map_or(String::new(), |value| format!(" = {value}"));
+        let identifier = escape_identifier(&self.identifier);
+        format!("{}{identifier}{value}", self.prelude.to_slice_string())
+    }
+}
+
+/// Describes an interface.
+#[derive(Clone, Debug)]
+pub struct InterfaceBuilder {
+    prelude: Prelude,
+    identifier: String,
+    bases: Vec<String>,
+    operations: Vec<OperationBuilder>,
+}
+
+impl InterfaceBuilder {
+    pub fn new(identifier: impl Into<String>) -> Self {
+        InterfaceBuilder {
+            prelude: Prelude::default(),
+            identifier: identifier.into(),
+            bases: Vec::new(),
+            operations: Vec::new(),
+        }
+    }
+
+    /// Sets the doc comment of this interface. See [Prelude::doc].
+    pub fn doc(mut self, comment: impl Into<String>) -> Self {
+        self.prelude.doc = Some(comment.into());
+        self
+    }
+
+    /// Adds an attribute to this interface. See [Prelude::attributes].
+    pub fn attribute(mut self, attribute: impl Into<String>) -> Self {
+        self.prelude.attributes.push(attribute.into());
+        self
+    }
+
+    /// Adds a base interface to this interface, which is referred to like a type. Ex: `"Other::Base"`.
+    pub fn base(mut self, base: impl Into<String>) -> Self {
+        self.bases.push(base.into());
+        self
+    }
+
+    /// Adds an operation to this interface.
+    pub fn operation(mut self, operation: OperationBuilder) -> Self {
+        self.operations.push(operation);
+        self
+    }
+}
+
+impl ToSliceString for InterfaceBuilder {
+    fn to_slice_string(&self) -> String {
+        let bases = match self.bases.is_empty() {
+            true => String::new(),
+            false => format!(" : {}", self.bases.join(", ")),
+        };
+        let operations = self.operations.iter().map(ToSliceString::to_slice_string).collect::<Vec<_>>();
+        let body = format_body(&operations.join("\n"));
+        let identifier = escape_identifier(&self.identifier);
+        format!("{}interface {identifier}{bases}{body}", self.prelude.to_slice_string())
+    }
+}
+
+/// Describes an operation.
+#[derive(Clone, Debug)]
+pub struct OperationBuilder {
+    prelude: Prelude,
+    identifier: String,
+    is_idempotent: bool,
+    parameters: Vec<MemberBuilder>,
+    return_type: ReturnType,
+    thrown_types: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+enum ReturnType {
+    None,
+    Single(String),
+    Tuple(Vec<MemberBuilder>),
+}
+
+impl OperationBuilder {
+    pub fn new(identifier: impl Into<String>) -> Self {
+        OperationBuilder {
+            prelude: Prelude::default(),
+            identifier: identifier.into(),
+            is_idempotent: false,
+            parameters: Vec::new(),
+            return_type: ReturnType::None,
+            thrown_types: Vec::new(),
+        }
+    }
+
+    /// Sets the doc comment of this operation. See [Prelude::doc].
+    pub fn doc(mut self, comment: impl Into<String>) -> Self {
+        self.prelude.doc = Some(comment.into());
+        self
+    }
+
+    /// Adds an attribute to this operation. See [Prelude::attributes].
+    pub fn attribute(mut self, attribute: impl Into<String>) -> Self {
+        self.prelude.attributes.push(attribute.into());
+        self
+    }
+
+    /// Makes this operation idempotent.
+    pub fn idempotent(mut self) -> Self {
+        self.is_idempotent = true;
+        self
+    }
+
+    /// Adds a parameter to this operation.
+    pub fn parameter(mut self, parameter: MemberBuilder) -> Self {
+        self.parameters.push(parameter);
+        self
+    }
+
+    /// Sets the return type of this operation to a single unnamed type. This replaces any return members.
+    pub fn returns(mut self, data_type: impl Into<String>) -> Self {
+        self.return_type = ReturnType::Single(data_type.into());
+        self
+    }
+
+    /// Adds a named member to the return tuple of this operation. This replaces any unnamed return type.
+    pub fn return_member(mut self, member: MemberBuilder) -> Self {
+        match &mut self.return_type {
+            ReturnType::Tuple(members) => members.push(member),
+            return_type => *return_type = ReturnType::Tuple(vec![member]),
+        }
+        self
+    }
+
+    /// Adds a type to the exception specification of this operation.
+    pub fn throws(mut self, thrown_type: impl Into<String>) -> Self {
+        self.thrown_types.push(thrown_type.into());
+        self
+    }
+}
+
+impl ToSliceString for OperationBuilder {
+    fn to_slice_string(&self) -> String {
+        let idempotent = if self.is_idempotent { "idempotent " } else { "" };
+        let parameters = format_list(self.parameters.iter().map(ToSliceString::to_slice_string).collect());
+        let return_type = match &self.return_type {
+            ReturnType::None => String::new(),
+            ReturnType::Single(data_type) => format!(" -> {data_type}"),
+            ReturnType::Tuple(members) => {
+                let members = format_list(members.iter().map(ToSliceString::to_slice_string).collect());
+                format!(" -> ({members})")
+            }
+        };
+        let exception_specification = match self.thrown_types.as_slice() {
+            [] => String::new(),
+            [thrown_type] => format!(" throws {thrown_type}"),
+            thrown_types => format!(" throws ({})", thrown_types.join(", ")),
+        };
+        let identifier = escape_identifier(&self.identifier);
+        let prelude = self.prelude.to_slice_string();
+        format!("{prelude}{idempotent}{identifier}({parameters}){return_type}{exception_specification}")
+    }
+}
+
+/// Describes a field, parameter, or return member.
+#[derive(Clone, Debug)]
+pub struct MemberBuilder {
+    prelude: Prelude,
+    identifier: String,
+    data_type: String,
+    tag: Option<u32>,
+}
+
+impl MemberBuilder {
+    pub fn new(identifier: impl Into<String>, data_type: impl Into<String>) -> Self {
+        MemberBuilder {
+            prelude: Prelude::default(),
+            identifier: identifier.into(),
+            data_type: data_type.into(),
+            tag: None,
+        }
+    }
+
+    /// Sets the doc comment of this member. See [Prelude::doc].
+    /// Doc comments can only be applied to fields; parameters are documented with their operation's `@param` tags.
+    pub fn doc(mut self, comment: impl Into<String>) -> Self {
+        self.prelude.doc = Some(comment.into());
+        self
+    }
+
+    /// Adds an attribute to this member. See [Prelude::attributes].
+    pub fn attribute(mut self, attribute: impl Into<String>) -> Self {
+        self.prelude.attributes.push(attribute.into());
+        self
+    }
+
+    /// Makes this member tagged, with the provided tag. Tagged members must have optional types.
+    pub fn tag(mut self, tag: u32) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+}
+
+impl ToSliceString for MemberBuilder {
+    fn to_slice_string(&self) -> String {
+        let tag = self.tag.map_or(String::new(), |tag| format!("tag({tag}) "));
+        let identifier = escape_identifier(&self.identifier);
+        format!("{}{tag}{identifier}: {}", self.prelude.to_slice_string(), self.data_type)
+    }
+}
+
+/// The doc comment and attributes of a definition.
+#[derive(Clone, Debug, Default)]
+pub struct Prelude {
+    /// The doc comment, without any leading slashes. It can span multiple lines, and contain doc comment tags.
+    /// Ex: `"A point in 2D space.\n@see Line"`.
+    pub doc: Option<String>,
+    /// The attributes, written in Slice syntax without brackets. Ex: `"deprecated(\"use Point3D instead\")"`.
+    pub attributes: Vec<String>,
+}
+
+impl ToSliceString for Prelude {
+    fn to_slice_string(&self) -> String {
+        let mut prelude = String::new();
+        for line in self.doc.iter().flat_map(|doc| doc.lines()) {
+            match line.is_empty() {
+                true => prelude.push_str("///\n"),
+                false => prelude.push_str(&format!("/// {line}\n")),
+            }
+        }
+        for attribute in &self.attributes {
+            prelude.push_str(&format!("[{attribute}]\n"));
+        }
+        prelude
+    }
+}
+
+/// Escapes the provided identifier (by prefixing it with a '\') if it's a Slice keyword.
+fn escape_identifier(identifier: &str) -> String {
+    let is_identifier = identifier.chars().all(crate::parsers::is_identifier_continue);
+    match !identifier.is_empty() && is_identifier && is_keyword(identifier) {
+        true => format!("\\{identifier}"),
+        false => identifier.to_owned(),
+    }
+}
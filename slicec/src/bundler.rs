@@ -0,0 +1,153 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains the logic for bundling the files of a compilation into a single, self-contained Slice file.
+//! This is useful for distributing schemas to third parties, who only need to be given one file.
+//!
+//! Bundles are created by re-emitting the AST (see [ToSliceString]), so they're in the canonical Slice style, and any
+//! non-doc comments are dropped. Each module is emitted as a single module block, containing the definitions from
+//! every file which contributes to that module. Modules are sorted by their identifiers, and definitions are kept in
+//! the order they were defined in, with files sorted by their paths, so bundling the same files always produces the
+//! same output.
+//!
+//! Since a bundle is a single file, it can only have one compilation mode, and one set of file attributes. Files with
+//! different compilation modes can't be bundled together, and the file attributes of every file are applied to the
+//! entire bundle. File doc comments describe individual files, so they aren't included in bundles.
+
+use crate::compilation_state::CompilationState;
+use crate::grammar::*;
+use crate::slice_file::SliceFile;
+
+/// The error type for bundling operations.
+#[derive(Debug)]
+pub enum BundleError {
+    /// The compilation state contained errors, so its AST may be incomplete.
+    CompilationFailed,
+
+    /// The files being bundled don't all use the same compilation mode.
+    MixedCompilationModes {
+        /// The path of a file which uses Slice1 mode.
+        slice1_file: String,
+        /// The path of a file which uses Slice2 mode.
+        slice2_file: String,
+    },
+}
+
+/// Bundles the files of the provided compilation state into a single Slice file, and returns its contents.
+///
+/// If `include_references` is true, reference files are bundled alongside source files, so that the bundle is
+/// self-contained. Otherwise, only source files are bundled.
+pub fn bundle(state: &CompilationState, include_references: bool) -> Result<String, BundleError> {
+    if state.diagnostics.has_errors() {
+        return Err(BundleError::CompilationFailed);
+    }
+
+    let mut files = state
+        .files
+        .iter()
+        .filter(|file| include_references || file.is_source)
+        .collect::<Vec<_>>();
+    files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+    let mut header = Vec::new();
+    if let Some(mode) = get_compilation_mode(&files)? {
+        header.push(format!("mode = {mode}"));
+    }
+
+    let mut file_attributes = Vec::new();
+    for attribute in files.iter().flat_map(|file| &file.attributes) {
+        let attribute = format!("[[{}]]", attribute.borrow().to_slice_string());
+        if !file_attributes.contains(&attribute) {
+            file_attributes.push(attribute);
+        }
+    }
+    header.extend(file_attributes);
+
+    let mut sections = Vec::new();
+    if !header.is_empty() {
+        sections.push(header.join("\n"));
+    }
+    sections.extend(collect_modules(&files).iter().map(BundledModule::to_slice_string));
+
+    match sections.is_empty() {
+        true => Ok(String::new()),
+        false => Ok(sections.join("\n\n") + "\n"),
+    }
+}
+
+/// Returns the compilation mode that the bundle should specify, or `None` if it should use the default mode.
+fn get_compilation_mode(files: &[&SliceFile]) -> Result<Option<CompilationMode>, BundleError> {
+    let slice1_file = files.iter().find(|file| file.compilation_mode() == CompilationMode::Slice1);
+    let slice2_file = files.iter().find(|file| file.compilation_mode() == CompilationMode::Slice2);
+    match (slice1_file, slice2_file) {
+        (Some(slice1_file), Some(slice2_file)) => Err(BundleError::MixedCompilationModes {
+            slice1_file: slice1_file.relative_path.clone(),
+            slice2_file: slice2_file.relative_path.clone(),
+        }),
+        (Some(_), None) => Ok(Some(CompilationMode::Slice1)),
+        _ => Ok(None),
+    }
+}
+
+/// A module in a bundle. This holds every declaration of the module across all the bundled files.
+struct BundledModule<'a> {
+    identifier: &'a str,
+    declarations: Vec<&'a Module>,
+    definitions: Vec<&'a Definition>,
+}
+
+impl BundledModule<'_> {
+    fn to_slice_string(&self) -> String {
+        // Modules can have a different doc comment for each declaration, but a bundle only declares each module once.
+        // So we keep the first doc comment, and the attributes from every declaration.
+        let comment = self.declarations.iter().find_map(|module| module.comment());
+        let mut attributes: Vec<&Attribute> = Vec::new();
+        for attribute in self.declarations.iter().flat_map(|module| module.attributes()) {
+            let attribute_string = attribute.to_slice_string();
+            if !attributes.iter().any(|other| other.to_slice_string() == attribute_string) {
+                attributes.push(attribute);
+            }
+        }
+
+        let prelude = format_prelude(comment, attributes);
+        let contents = self.definitions.iter().map(|definition| definition.to_slice_string());
+        let body = format_body(&contents.collect::<Vec<_>>().join("\n\n"));
+        format!("{prelude}module {}{body}", self.identifier)
+    }
+}
+
+/// Groups the definitions of the provided files by the module they're defined in, and returns them sorted by module.
+fn collect_modules<'a>(files: &[&'a SliceFile]) -> Vec<BundledModule<'a>> {
+    let mut modules: Vec<BundledModule> = Vec::new();
+    for file in files {
+        for module_ptr in &file.modules {
+            let module = module_ptr.borrow();
+            let identifier = module.nested_module_identifier();
+            match modules.iter_mut().find(|bundled| bundled.identifier == identifier) {
+                Some(bundled_module) => bundled_module.declarations.push(module),
+                None => modules.push(BundledModule {
+                    identifier,
+                    declarations: vec![module],
+                    definitions: Vec::new(),
+                }),
+            }
+        }
+
+        for definition in &file.contents {
+            let identifier = definition.borrow().get_module().nested_module_identifier();
+            if let Some(bundled_module) = modules.iter_mut().find(|bundled| bundled.identifier == identifier) {
+                bundled_module.definitions.push(definition);
+            }
+        }
+    }
+
+    // Modules which are empty in every file are only kept if they have a doc comment or attributes.
+    modules.retain(|module| {
+        !module.definitions.is_empty()
+            || module
+                .declarations
+                .iter()
+                .any(|declaration| declaration.comment.is_some() || !declaration.attributes.is_empty())
+    });
+    modules.sort_by(|a, b| a.identifier.cmp(b.identifier));
+    modules
+}
@@ -1,16 +1,26 @@
 // Copyright (c) ZeroC, Inc.
 
+use crate::annotations::Annotations;
 use crate::ast::Ast;
 use crate::diagnostic_emitter::{emit_totals, DiagnosticEmitter};
-use crate::diagnostics::{get_totals, Diagnostic, Diagnostics};
+use crate::diagnostics::{get_lint_level, get_totals, Diagnostic, DiagnosticLevel, Diagnostics, Lint};
+use crate::grammar::Entity;
+use crate::metrics::CompilationMetrics;
 use crate::slice_file::SliceFile;
 use crate::slice_options::{DiagnosticFormat, SliceOptions};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 #[derive(Debug, Default)]
 pub struct CompilationState {
     pub ast: Ast,
     pub diagnostics: Diagnostics,
     pub files: Vec<SliceFile>,
+    pub metrics: CompilationMetrics,
+
+    /// Side tables for attaching data computed by backends and compiler passes to elements in the [Ast].
+    pub annotations: Annotations,
 }
 
 impl CompilationState {
@@ -19,6 +29,8 @@ impl CompilationState {
             ast: Ast::create(),
             diagnostics: Diagnostics::new(),
             files: Vec::new(),
+            metrics: CompilationMetrics::default(),
+            annotations: Annotations::new(),
         }
     }
 
@@ -43,24 +55,56 @@ impl CompilationState {
         }
     }
 
+    /// Returns the level that the provided lint would be reported with, if it was reported on the provided entity.
+    /// This takes into account the `allow` and `deny` attributes on the entity, its parents, and its file,
+    /// along with any `--allow` and `--deny` flags. See [get_lint_level] for how these configurations are combined.
+    pub fn get_lint_level(&self, lint: &Lint, entity: &dyn Entity, options: &SliceOptions) -> DiagnosticLevel {
+        let file = self.files.iter().find(|file| file.relative_path == entity.span().file);
+        get_lint_level(lint, file, Some(entity), options)
+    }
+
     /// This function is the exit point of the compiler.
-    /// It emits diagnostics to the console, along with the total number of warning/errors emitted.
-    /// After this it returns whether any errors were emitted.
-    pub fn emit_diagnostics(self, options: &SliceOptions) -> bool {
+    /// It emits diagnostics to the console (or to `options.error_output` if it's set), along with the total number of
+    /// warning/errors emitted. After this it returns whether any errors were emitted, or an error if the diagnostics
+    /// couldn't be written.
+    pub fn emit_diagnostics(self, options: &SliceOptions) -> io::Result<bool> {
+        // If requested, report how long compilation took before emitting any diagnostics.
+        if let Some(format) = options.timings {
+            eprint!("{}", self.metrics.report(format));
+        }
+
         let diagnostics = self.diagnostics.into_updated(&self.ast, &self.files, options);
         let (total_warnings, total_errors) = get_totals(&diagnostics);
 
         // Print any diagnostics to the console, along with the total number of warnings and errors emitted.
-        let mut stderr = console::Term::stderr();
-        let mut emitter = DiagnosticEmitter::new(&mut stderr, options, &self.files);
-        DiagnosticEmitter::emit_diagnostics(&mut emitter, diagnostics).expect("failed to emit diagnostics");
+        let mut output: Box<dyn Write> = match &options.error_output {
+            Some(error_output) => Box::new(error_output.clone()),
+            None => Box::new(console::Term::stderr()),
+        };
+        let mut emitter = DiagnosticEmitter::new(&mut output, options, &self.files);
+        match options.diagnostic_format {
+            // Errors were already streamed to the output as they were reported.
+            DiagnosticFormat::Ndjson => emitter.emit_deferred_diagnostics(diagnostics)?,
+            _ => emitter.emit_diagnostics(diagnostics)?,
+        }
 
         // Only emit the summary message if we're writing human-readable output.
-        if options.diagnostic_format == DiagnosticFormat::Human {
-            emit_totals(total_warnings, total_errors).expect("failed to emit totals");
+        if matches!(options.diagnostic_format, DiagnosticFormat::Human | DiagnosticFormat::Pretty) {
+            emit_totals(total_warnings, total_errors)?;
         }
 
-        total_errors != 0
+        Ok(total_errors != 0)
+    }
+
+    /// Consumes this `CompilationState` and returns an immutable [snapshot](CompilationSnapshot) of it, which can be
+    /// cheaply cloned and shared between threads. The snapshot's diagnostics are updated with the provided options.
+    pub fn into_snapshot(self, options: &SliceOptions) -> CompilationSnapshot {
+        let diagnostics = self.diagnostics.into_updated(&self.ast, &self.files, options);
+        CompilationSnapshot(Arc::new(SnapshotData {
+            ast: self.ast,
+            files: self.files,
+            diagnostics,
+        }))
     }
 
     /// Consumes this `CompilationState` and returns the diagnostics it contains.
@@ -69,3 +113,70 @@ impl CompilationState {
         self.diagnostics.into_updated(&self.ast, &self.files, options)
     }
 }
+
+/// An immutable snapshot of a finished compilation, created by [`CompilationState::into_snapshot`].
+///
+/// Snapshots are reference counted, so cloning one is cheap, and all its clones share the same AST. This allows tools
+/// like language servers to keep using the last successful compilation while a new one is in progress, instead of
+/// reparsing everything up-front. Once the last clone of a snapshot is dropped, its contents are dropped with it.
+#[derive(Clone, Debug)]
+pub struct CompilationSnapshot(Arc<SnapshotData>);
+
+#[derive(Debug)]
+struct SnapshotData {
+    ast: Ast,
+    files: Vec<SliceFile>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl CompilationSnapshot {
+    pub fn ast(&self) -> &Ast {
+        &self.0.ast
+    }
+
+    pub fn files(&self) -> &[SliceFile] {
+        &self.0.files
+    }
+
+    /// Returns the diagnostics that were reported during the compilation, with their levels updated.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.0.diagnostics
+    }
+
+    /// Returns true if any errors were reported during the compilation.
+    pub fn has_errors(&self) -> bool {
+        self.0.diagnostics.iter().any(|diagnostic| diagnostic.level() == DiagnosticLevel::Error)
+    }
+
+    /// Returns true if both snapshots refer to the same compilation.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A token for cooperatively cancelling a compilation, which is passed to
+/// [compile_from_options_with_cancel](crate::compile_from_options_with_cancel).
+///
+/// Tokens can be cloned and shared between threads, and all clones of a token are cancelled together. The compiler
+/// checks whether its token was cancelled between each file it parses, and between each of its passes. If it was,
+/// the compilation stops, and an [Error::CompilationCancelled](crate::diagnostics::Error::CompilationCancelled) is
+/// reported.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a new token which hasn't been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests that the compilation using this token be cancelled.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains the logic for loading compiler options from a configuration file (typically `slice.toml`).
+//!
+//! Configuration files use a subset of TOML, supporting string and array values, comments, and tables.
+//! For example:
+//! ```toml
+//! sources = ["slice/"]
+//! references = ["../common/slice"]
+//! defined-symbols = ["DEBUG"]
+//! output-dir = "generated"
+//!
+//! [lints]
+//! allow = ["Deprecated"]
+//! deny = ["BrokenDocLink"]
+//! ```
+//!
+//! Relative paths in configuration files are resolved relative to the directory containing the file.
+
+use crate::diagnostics::{Diagnostic, Diagnostics, Error, Lint};
+use crate::slice_options::SliceOptions;
+use std::path::Path;
+use std::{fs, io};
+
+/// The compiler options that were loaded from a configuration file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigFile {
+    pub sources: Option<Vec<String>>,
+    pub references: Option<Vec<String>>,
+    pub defined_symbols: Option<Vec<String>>,
+    pub output_dir: Option<String>,
+    pub allowed_lints: Vec<String>,
+    pub denied_lints: Vec<String>,
+}
+
+impl ConfigFile {
+    /// Parses the provided text as a configuration file.
+    /// If the text is malformed, or contains unknown options, this returns an error message describing the problem.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut config = ConfigFile::default();
+        let mut parser = Parser { chars: text.chars().peekable(), line: 1 };
+        let mut table = String::new();
+
+        while let Some(c) = parser.skip_whitespace_and_comments() {
+            if c == '[' {
+                parser.next();
+                table = parser.parse_key()?;
+                parser.expect(']')?;
+                if table != "lints" {
+                    return Err(parser.error(format!("unknown table '{table}'")));
+                }
+                continue;
+            }
+
+            let key = parser.parse_key()?;
+            parser.expect('=')?;
+            let value = parser.parse_value()?;
+            match (table.as_str(), key.as_str()) {
+                ("", "sources") => config.sources = Some(parser.to_strings(value, &key)?),
+                ("", "references") => config.references = Some(parser.to_strings(value, &key)?),
+                ("", "defined-symbols") => config.defined_symbols = Some(parser.to_strings(value, &key)?),
+                ("", "output-dir") => config.output_dir = Some(parser.to_string(value, &key)?),
+                ("lints", "allow") => config.allowed_lints = parser.to_lints(value, &key)?,
+                ("lints", "deny") => config.denied_lints = parser.to_lints(value, &key)?,
+                ("", _) => return Err(parser.error(format!("unknown option '{key}'"))),
+                _ => return Err(parser.error(format!("unknown option '{key}' in table '{table}'"))),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Reads and parses the configuration file at the provided path.
+    /// Any relative paths in the file are resolved relative to the directory containing the file.
+    pub fn read(path: &str) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let mut config = Self::parse(&text).map_err(|message| io::Error::other(format!("malformed file: {message}")))?;
+
+        let directory = Path::new(path).parent().unwrap_or(Path::new(""));
+        let resolve = |p: &mut String| {
+            if Path::new(p).is_relative() {
+                *p = directory.join(&*p).to_string_lossy().into_owned();
+            }
+        };
+        config.sources.iter_mut().flatten().for_each(resolve);
+        config.references.iter_mut().flatten().for_each(resolve);
+        config.output_dir.iter_mut().for_each(resolve);
+        Ok(config)
+    }
+
+    /// Applies the options in this configuration file to the provided options.
+    ///
+    /// Options that were already set (on the command line) take precedence over the ones in this file. Lint levels are
+    /// combined, but if a lint is allowed on the command line and denied in this file (or vice versa), the command line
+    /// takes precedence.
+    pub fn apply_to(self, options: &mut SliceOptions) {
+        fn apply_list(option: &mut Vec<String>, value: Option<Vec<String>>) {
+            if let (true, Some(value)) = (option.is_empty(), value) {
+                *option = value;
+            }
+        }
+
+        apply_list(&mut options.sources, self.sources);
+        apply_list(&mut options.references, self.references);
+        apply_list(&mut options.defined_symbols, self.defined_symbols);
+        if options.output_dir.is_none() {
+            options.output_dir = self.output_dir;
+        }
+
+        let allowed = self.allowed_lints.into_iter().filter(|lint| !options.denied_lints.contains(lint));
+        let denied = self.denied_lints.into_iter().filter(|lint| !options.allowed_lints.contains(lint));
+        let (allowed, denied) = (allowed.collect::<Vec<_>>(), denied.collect::<Vec<_>>());
+        options.allowed_lints.extend(allowed);
+        options.denied_lints.extend(denied);
+    }
+}
+
+/// Loads the configuration file specified by `options.config` (if any), and applies it to `options`.
+/// See [ConfigFile::apply_to] for how options in the file are combined with those passed on the command line.
+/// If the file can't be read or is malformed, an error is reported and `options` are left unchanged.
+pub fn apply_config_file(options: &mut SliceOptions, diagnostics: &mut Diagnostics) {
+    let Some(path) = options.config.clone() else {
+        return;
+    };
+
+    match ConfigFile::read(&path) {
+        Ok(config) => config.apply_to(options),
+        Err(error) => Diagnostic::new(Error::IO {
+            action: "read",
+            path,
+            error,
+        })
+        .push_into(diagnostics),
+    }
+}
+
+enum Value {
+    String(String),
+    Array(Vec<Value>),
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if c == Some('\n') {
+            self.line += 1;
+        }
+        c
+    }
+
+    fn error(&self, message: String) -> String {
+        format!("line {}: {message}", self.line)
+    }
+
+    /// Skips any whitespace and comments, and returns the next character without consuming it.
+    fn skip_whitespace_and_comments(&mut self) -> Option<char> {
+        while let Some(&c) = self.chars.peek() {
+            match c {
+                '#' => {
+                    while self.chars.peek().is_some_and(|&c| c != '\n') {
+                        self.next();
+                    }
+                }
+                c if c.is_whitespace() => {
+                    self.next();
+                }
+                _ => return Some(c),
+            }
+        }
+        None
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.skip_whitespace_and_comments() {
+            Some(c) if c == expected => {
+                self.next();
+                Ok(())
+            }
+            Some(c) => Err(self.error(format!("expected '{expected}' but found '{c}'"))),
+            None => Err(self.error(format!("expected '{expected}' but found the end of the file"))),
+        }
+    }
+
+    fn parse_key(&mut self) -> Result<String, String> {
+        self.skip_whitespace_and_comments();
+        let mut key = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if !(c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+                break;
+            }
+            key.push(c);
+            self.next();
+        }
+
+        match key.is_empty() {
+            true => Err(self.error("expected a key".to_owned())),
+            false => Ok(key),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, String> {
+        match self.skip_whitespace_and_comments() {
+            Some('"') => {
+                self.next();
+                self.parse_string().map(Value::String)
+            }
+            Some('[') => {
+                self.next();
+                let mut elements = Vec::new();
+                loop {
+                    if self.skip_whitespace_and_comments() == Some(']') {
+                        self.next();
+                        return Ok(Value::Array(elements));
+                    }
+                    elements.push(self.parse_value()?);
+                    match self.skip_whitespace_and_comments() {
+                        Some(',') => {
+                            self.next();
+                        }
+                        _ => {
+                            self.expect(']')?;
+                            return Ok(Value::Array(elements));
+                        }
+                    }
+                }
+            }
+            Some(c) => Err(self.error(format!("expected a value but found '{c}'"))),
+            None => Err(self.error("expected a value but found the end of the file".to_owned())),
+        }
+    }
+
+    /// Parses the remainder of a string, whose opening quote has already been consumed.
+    fn parse_string(&mut self) -> Result<String, String> {
+        let mut value = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(value),
+                Some('\\') => match self.next() {
+                    Some('"') => value.push('"'),
+                    Some('\\') => value.push('\\'),
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some(c) => return Err(self.error(format!("invalid escape sequence '\\{c}'"))),
+                    None => return Err(self.error("unterminated string".to_owned())),
+                },
+                Some('\n') | None => return Err(self.error("unterminated string".to_owned())),
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    fn to_string(&self, value: Value, key: &str) -> Result<String, String> {
+        match value {
+            Value::String(s) => Ok(s),
+            _ => Err(self.error(format!("'{key}' must be a string"))),
+        }
+    }
+
+    fn to_strings(&self, value: Value, key: &str) -> Result<Vec<String>, String> {
+        let error = || self.error(format!("'{key}' must be an array of strings"));
+        match value {
+            Value::Array(elements) => elements
+                .into_iter()
+                .map(|element| match element {
+                    Value::String(s) => Ok(s),
+                    _ => Err(error()),
+                })
+                .collect(),
+            _ => Err(error()),
+        }
+    }
+
+    fn to_lints(&self, value: Value, key: &str) -> Result<Vec<String>, String> {
+        let lints = self.to_strings(value, key)?;
+        match lints.iter().find(|lint| !Lint::is_allowable_identifier(lint)) {
+            Some(lint) => Err(self.error(format!("'{lint}' is not a valid lint identifier"))),
+            None => Ok(lints),
+        }
+    }
+}
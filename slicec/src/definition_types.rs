@@ -87,21 +87,25 @@ implement_encode_into_for_struct!(Module, identifier, attributes);
 #[derive(Clone, Debug)]
 pub struct Struct {
     pub entity_info: EntityInfo,
+    pub is_internal: bool,
     pub is_compact: bool,
     pub fields: Vec<Field>,
+    pub constants: Vec<Constant>,
 }
-implement_encode_into_for_struct!(Struct, entity_info, is_compact, fields);
+implement_encode_into_for_struct!(Struct, entity_info, is_internal, is_compact, fields, constants);
 
 #[derive(Clone, Debug)]
 pub struct Field {
     pub entity_info: EntityInfo,
     pub tag: Option<i32>, // TODO: varint32 isn't a real type?
     pub data_type: TypeRef,
+    pub default_value: Option<ConstantValue>,
 }
 impl EncodeInto<Slice2> for &Field {
     fn encode_into(self, encoder: &mut Encoder<impl OutputTarget>) -> Result<()> {
-        // Encode the bit-sequence. With only one optional, this is just a bool.
-        encoder.encode(self.tag.is_some())?;
+        // Encode the bit-sequence. With two optionals, this is a single byte, with one bit per optional.
+        let bit_sequence = u8::from(self.tag.is_some()) | (u8::from(self.default_value.is_some()) << 1);
+        encoder.encode(bit_sequence)?;
 
         // Encode the actual fields.
         encoder.encode(&self.entity_info)?;
@@ -109,6 +113,9 @@ impl EncodeInto<Slice2> for &Field {
             encoder.encode_varint(tag_value)?;
         }
         encoder.encode(&self.data_type)?;
+        if let Some(default_value) = &self.default_value {
+            encoder.encode(default_value)?;
+        }
         encoder.encode_varint(TAG_END_MARKER)?;
         Ok(())
     }
@@ -117,37 +124,67 @@ impl EncodeInto<Slice2> for &Field {
 #[derive(Clone, Debug)]
 pub struct Interface {
     pub entity_info: EntityInfo,
+    pub is_internal: bool,
     pub bases: Vec<EntityId>,
     pub operations: Vec<Operation>,
+    pub constants: Vec<Constant>,
 }
-implement_encode_into_for_struct!(Interface, entity_info, bases, operations);
+implement_encode_into_for_struct!(Interface, entity_info, is_internal, bases, operations, constants);
 
 #[derive(Clone, Debug)]
 pub struct Operation {
     pub entity_info: EntityInfo,
     pub is_idempotent: bool,
+    pub is_readonly: bool,
+    pub is_oneway: bool,
     pub parameters: Vec<Field>,
     pub has_streamed_parameter: bool,
+    pub streamed_parameter_element_size: Option<u32>,
     pub return_type: Vec<Field>,
     pub has_streamed_return: bool,
+    pub streamed_return_element_size: Option<u32>,
+    pub error_type: Option<TypeRef>,
+}
+impl EncodeInto<Slice2> for &Operation {
+    fn encode_into(self, encoder: &mut Encoder<impl OutputTarget>) -> Result<()> {
+        // Encode the bit-sequence. With three optionals, this is a single byte, with one bit per optional.
+        let bit_sequence = u8::from(self.streamed_parameter_element_size.is_some())
+            | (u8::from(self.streamed_return_element_size.is_some()) << 1)
+            | (u8::from(self.error_type.is_some()) << 2);
+        encoder.encode(bit_sequence)?;
+
+        // Encode the actual fields.
+        encoder.encode(&self.entity_info)?;
+        encoder.encode(self.is_idempotent)?;
+        encoder.encode(self.is_readonly)?;
+        encoder.encode(self.is_oneway)?;
+        encoder.encode(&self.parameters)?;
+        encoder.encode(self.has_streamed_parameter)?;
+        if let Some(element_size) = self.streamed_parameter_element_size {
+            encoder.encode(element_size)?;
+        }
+        encoder.encode(&self.return_type)?;
+        encoder.encode(self.has_streamed_return)?;
+        if let Some(element_size) = self.streamed_return_element_size {
+            encoder.encode(element_size)?;
+        }
+        if let Some(error_type) = &self.error_type {
+            encoder.encode(error_type)?;
+        }
+        encoder.encode_varint(TAG_END_MARKER)?;
+        Ok(())
+    }
 }
-implement_encode_into_for_struct!(
-    Operation,
-    entity_info,
-    is_idempotent,
-    parameters,
-    has_streamed_parameter,
-    return_type,
-    has_streamed_return,
-);
 
 #[derive(Clone, Debug)]
 pub struct Enum {
     pub entity_info: EntityInfo,
+    pub is_internal: bool,
     pub is_compact: bool,
     pub is_unchecked: bool,
     pub underlying: Option<TypeId>,
     pub enumerators: Vec<Enumerator>,
+    pub constants: Vec<Constant>,
 }
 impl EncodeInto<Slice2> for &Enum {
     fn encode_into(self, encoder: &mut Encoder<impl OutputTarget>) -> Result<()> {
@@ -156,12 +193,14 @@ impl EncodeInto<Slice2> for &Enum {
 
         // Encode the actual fields.
         encoder.encode(&self.entity_info)?;
+        encoder.encode(self.is_internal)?;
         encoder.encode(self.is_compact)?;
         encoder.encode(self.is_unchecked)?;
         if let Some(underlying_value) = &self.underlying {
             encoder.encode(underlying_value)?;
         }
         encoder.encode(&self.enumerators)?;
+        encoder.encode(&self.constants)?;
         encoder.encode_varint(TAG_END_MARKER)?;
         Ok(())
     }
@@ -185,15 +224,70 @@ implement_encode_into_for_struct!(Discriminant, absolute_value, is_negative);
 #[derive(Clone, Debug)]
 pub struct CustomType {
     pub entity_info: EntityInfo,
+    pub is_internal: bool,
 }
-implement_encode_into_for_struct!(CustomType, entity_info);
+implement_encode_into_for_struct!(CustomType, entity_info, is_internal);
 
 #[derive(Clone, Debug)]
 pub struct TypeAlias {
     pub entity_info: EntityInfo,
+    pub is_internal: bool,
     pub underlying_type: TypeRef, // Can never be optional.
 }
-implement_encode_into_for_struct!(TypeAlias, entity_info, underlying_type);
+implement_encode_into_for_struct!(TypeAlias, entity_info, is_internal, underlying_type);
+
+#[derive(Clone, Debug)]
+pub struct Constant {
+    pub entity_info: EntityInfo,
+    pub is_internal: bool,
+    pub data_type: Option<TypeId>,
+    pub value: ConstantValue,
+}
+impl EncodeInto<Slice2> for &Constant {
+    fn encode_into(self, encoder: &mut Encoder<impl OutputTarget>) -> Result<()> {
+        // Encode the bit-sequence. With only one optional, this is just a bool.
+        encoder.encode(self.data_type.is_some())?;
+
+        // Encode the actual fields.
+        encoder.encode(&self.entity_info)?;
+        encoder.encode(self.is_internal)?;
+        if let Some(data_type_value) = &self.data_type {
+            encoder.encode(data_type_value)?;
+        }
+        encoder.encode(&self.value)?;
+        encoder.encode_varint(TAG_END_MARKER)?;
+        Ok(())
+    }
+}
+
+#[repr(u8)]
+#[derive(Clone, Debug)]
+pub enum ConstantValue {
+    Integer(Discriminant) = 0,
+    Bool(bool) = 1,
+    String(String) = 2,
+}
+impl EncodeInto<Slice2> for &ConstantValue {
+    fn encode_into(self, encoder: &mut Encoder<impl OutputTarget>) -> Result<()> {
+        // Write the discriminant value.
+        // SAFETY: this cast is guaranteed to be safe because the enum is marked with `repr(u8)`, so it's safe to cast
+        // it directly to a `u8`.
+        unsafe {
+            let discriminant = *<*const _>::from(self).cast::<u8>();
+            encoder.encode_varint(discriminant)?;
+        }
+
+        // Encode the actual value.
+        match self {
+            ConstantValue::Integer(v) => encoder.encode(v)?,
+            ConstantValue::Bool(v) => encoder.encode(*v)?,
+            ConstantValue::String(v) => encoder.encode(v)?,
+        }
+
+        encoder.encode_varint(TAG_END_MARKER)?;
+        Ok(())
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct SequenceType {
@@ -269,6 +363,7 @@ pub enum Symbol {
     DictionaryType(DictionaryType) = 5,
     ResultType(ResultType) = 6, // TODO make result come before dictionary!
     TypeAlias(TypeAlias) = 7,
+    Constant(Constant) = 8,
 }
 impl EncodeInto<Slice2> for &Symbol {
     fn encode_into(self, encoder: &mut Encoder<impl OutputTarget>) -> Result<()> {
@@ -290,6 +385,7 @@ impl EncodeInto<Slice2> for &Symbol {
             Symbol::DictionaryType(v) => encoder.encode(v)?,
             Symbol::ResultType(v) => encoder.encode(v)?,
             Symbol::TypeAlias(v) => encoder.encode(v)?,
+            Symbol::Constant(v) => encoder.encode(v)?,
         }
 
         encoder.encode_varint(TAG_END_MARKER)?;
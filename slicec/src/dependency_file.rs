@@ -0,0 +1,192 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains the logic for writing dependency files, which list the Slice files that each source file
+//! depends on (similar to `gcc -MD`). Build systems can use these files to only recompile the Slice files which are
+//! affected by a change.
+//!
+//! A file depends on every file that defines something it references, directly or indirectly. Dependency files can be
+//! written in one of two formats (see [DependencyFileFormat]):
+//! - `make`: one rule per source file. Ex: `a.slice: b.slice c.slice`.
+//! - `json`: a single object mapping each source file to an array of its dependencies.
+
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostic, Error};
+use crate::grammar::*;
+use crate::slice_file::SliceFile;
+use crate::slice_options::{DependencyFileFormat, SliceOptions};
+use crate::visitor::Visitor;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+
+/// Returns the dependencies of each of the provided files, keyed by their relative paths.
+///
+/// A file's dependencies are the files defining anything it references (including through base types, exception
+/// specifications, and type aliases), and transitively, the dependencies of those files. Files never depend on
+/// themselves.
+pub fn collect_dependencies(files: &[SliceFile]) -> BTreeMap<String, BTreeSet<String>> {
+    // Type aliases are resolved away while patching, so type references point directly at the alias's underlying type.
+    // To still depend on the files defining these aliases, we record which aliases resolve to each definition.
+    // This may over-approximate the dependencies of a file, but never misses one.
+    let mut alias_files: HashMap<*const (), Vec<&str>> = HashMap::new();
+    for file in files {
+        for definition in &file.contents {
+            if let Definition::TypeAlias(type_alias_ptr) = definition {
+                let type_alias = type_alias_ptr.borrow();
+                if let TypeRefDefinition::Patched(_) = &type_alias.underlying.definition {
+                    let address = definition_address(type_alias.underlying.definition());
+                    alias_files.entry(address).or_default().push(&file.relative_path);
+                }
+            }
+        }
+    }
+
+    // First collect the files that each file directly depends on.
+    let mut direct_dependencies = BTreeMap::new();
+    for file in files {
+        let mut collector = DependencyCollector {
+            alias_files: &alias_files,
+            dependencies: BTreeSet::new(),
+        };
+        file.visit_with(&mut collector);
+        direct_dependencies.insert(file.relative_path.clone(), collector.dependencies);
+    }
+
+    // Then compute the transitive closure of those dependencies for each file.
+    let mut dependencies = BTreeMap::new();
+    for (path, direct) in &direct_dependencies {
+        let mut all = BTreeSet::new();
+        let mut pending = direct.iter().cloned().collect::<Vec<_>>();
+        while let Some(dependency) = pending.pop() {
+            if &dependency == path || !all.insert(dependency.clone()) {
+                continue;
+            }
+            if let Some(next) = direct_dependencies.get(&dependency) {
+                pending.extend(next.iter().cloned());
+            }
+        }
+        dependencies.insert(path.clone(), all);
+    }
+    dependencies
+}
+
+/// Writes a dependency file for the source files in `state`, if one was requested by `options.depfile`.
+///
+/// Only dependencies which exist on disk are written; built-in definitions (from the prelude or imported modules) are
+/// omitted, since build systems cannot track them. Nothing is written if `--dry-run` was set.
+pub(crate) fn write_dependency_file(state: &mut CompilationState, options: &SliceOptions) {
+    let Some(path) = options.depfile.as_ref().filter(|_| !options.dry_run) else {
+        return;
+    };
+
+    let dependencies = collect_dependencies(&state.files)
+        .into_iter()
+        .filter(|(file, _)| state.files.iter().any(|f| f.is_source && &f.relative_path == file))
+        .map(|(file, dependencies)| {
+            let on_disk = dependencies.into_iter().filter(|d| Path::new(d).is_file()).collect();
+            (file, on_disk)
+        })
+        .collect::<BTreeMap<_, BTreeSet<_>>>();
+
+    let text = match options.depfile_format {
+        DependencyFileFormat::Make => format_make(&dependencies),
+        DependencyFileFormat::Json => {
+            let mut text = serde_json::to_string_pretty(&dependencies).expect("failed to serialize dependencies");
+            text.push('\n');
+            text
+        }
+    };
+
+    if let Err(error) = fs::write(path, text) {
+        Diagnostic::new(Error::IO {
+            action: "write",
+            path: path.clone(),
+            error,
+        })
+        .push_into(&mut state.diagnostics);
+    }
+}
+
+/// Formats the provided dependencies as Makefile rules, one per file.
+fn format_make(dependencies: &BTreeMap<String, BTreeSet<String>>) -> String {
+    // Make treats spaces as separators, '#' as the start of a comment, and '$' as the start of a variable.
+    let escape = |path: &str| path.replace('$', "$$").replace('#', "\\#").replace(' ', "\\ ");
+
+    let mut text = String::new();
+    for (file, dependencies) in dependencies {
+        text += &escape(file);
+        text.push(':');
+        for dependency in dependencies {
+            text.push(' ');
+            text += &escape(dependency);
+        }
+        text.push('\n');
+    }
+    text
+}
+
+fn definition_address(definition: &dyn Type) -> *const () {
+    definition as *const dyn Type as *const ()
+}
+
+struct DependencyCollector<'a> {
+    alias_files: &'a HashMap<*const (), Vec<&'a str>>,
+    dependencies: BTreeSet<String>,
+}
+
+impl DependencyCollector<'_> {
+    fn add_entity(&mut self, entity: &dyn Entity) {
+        self.dependencies.insert(entity.span().file.clone());
+    }
+
+    fn add_type_ref<T: Entity>(&mut self, type_ref: &TypeRef<T>) {
+        if let TypeRefDefinition::Patched(_) = &type_ref.definition {
+            self.add_entity(type_ref.definition());
+        }
+    }
+}
+
+impl Visitor for DependencyCollector<'_> {
+    fn visit_class(&mut self, class_def: &Class) {
+        if let Some(base) = &class_def.base {
+            self.add_type_ref(base);
+        }
+    }
+
+    fn visit_exception(&mut self, exception_def: &Exception) {
+        if let Some(base) = &exception_def.base {
+            self.add_type_ref(base);
+        }
+    }
+
+    fn visit_interface(&mut self, interface_def: &Interface) {
+        for base in &interface_def.bases {
+            self.add_type_ref(base);
+        }
+    }
+
+    fn visit_operation(&mut self, operation: &Operation) {
+        for exception in &operation.exception_specification {
+            self.add_type_ref(exception);
+        }
+    }
+
+    fn visit_type_ref(&mut self, type_ref: &TypeRef) {
+        let TypeRefDefinition::Patched(_) = &type_ref.definition else {
+            return;
+        };
+
+        let definition = type_ref.definition();
+        if let Some(files) = self.alias_files.get(&definition_address(definition)) {
+            self.dependencies.extend(files.iter().map(|&file| file.to_owned()));
+        }
+
+        match definition.concrete_type() {
+            Types::Struct(struct_def) => self.add_entity(struct_def),
+            Types::Class(class_def) => self.add_entity(class_def),
+            Types::Enum(enum_def) => self.add_entity(enum_def),
+            Types::CustomType(custom_type) => self.add_entity(custom_type),
+            Types::ResultType(_) | Types::Sequence(_) | Types::Dictionary(_) | Types::Primitive(_) => {}
+        }
+    }
+}
@@ -1,10 +1,11 @@
 // Copyright (c) ZeroC, Inc.
 
-use crate::diagnostics::{Diagnostic, DiagnosticLevel};
+use crate::diagnostics::{render_diagnostic, Diagnostic, DiagnosticLevel};
 use crate::slice_file::{SliceFile, Span};
 use crate::slice_options::{DiagnosticFormat, SliceOptions};
 use serde::ser::SerializeStruct;
 use serde::Serializer;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::{Result, Write};
 use std::path::Path;
 
@@ -12,10 +13,12 @@ use std::path::Path;
 pub struct DiagnosticEmitter<'a, T: Write> {
     /// Reference to the output that diagnostics should be emitted to.
     output: &'a mut T,
-    /// Can specify `json` to serialize errors as JSON or `human` to pretty-print them.
+    /// Can specify `json` or `ndjson` to serialize errors as JSON or `human` or `pretty` to pretty-print them.
     diagnostic_format: DiagnosticFormat,
-    /// If true, diagnostic output will not be styled with colors (only used in `human` format).
+    /// If true, diagnostic output will not be styled with colors (only used in `human` and `pretty` formats).
     disable_color: bool,
+    /// The maximum number of diagnostics to emit per diagnostic code, if any.
+    max_diagnostics_per_code: Option<usize>,
     /// Provides the emitter access to the slice files that were compiled so it can extract snippets from them.
     files: &'a [SliceFile],
 }
@@ -26,6 +29,7 @@ impl<'a, T: Write> DiagnosticEmitter<'a, T> {
             output,
             diagnostic_format: slice_options.diagnostic_format,
             disable_color: slice_options.disable_color,
+            max_diagnostics_per_code: slice_options.max_diagnostics_per_code,
             files,
         }
     }
@@ -37,10 +41,82 @@ impl<'a, T: Write> DiagnosticEmitter<'a, T> {
             console::set_colors_enabled_stderr(false);
         }
 
+        // Remove any diagnostics past the per-code limit (if one was set).
+        let (diagnostics, suppressed_counts) = self.limit_diagnostics(diagnostics);
+
         // Emit the diagnostics in whatever form the user requested.
         match self.diagnostic_format {
-            DiagnosticFormat::Human => self.emit_diagnostics_in_human(diagnostics),
-            DiagnosticFormat::Json => self.emit_diagnostics_in_json(diagnostics),
+            DiagnosticFormat::Human => self.emit_diagnostics_in_human(diagnostics)?,
+            DiagnosticFormat::Pretty => self.emit_diagnostics_in_pretty(diagnostics)?,
+            DiagnosticFormat::Json | DiagnosticFormat::Ndjson => self.emit_diagnostics_in_json(diagnostics)?,
+        }
+
+        // Emit a summary for each code that had diagnostics suppressed.
+        for (code, count) in suppressed_counts {
+            self.emit_suppression_summary(&code, count)?;
+        }
+        Ok(())
+    }
+
+    /// Emits all the provided diagnostics except for errors, which are assumed to have already been emitted by a
+    /// [DiagnosticStream] as they were reported. Errors are still counted towards the per-code limit, so suppression
+    /// summaries are emitted for them as usual.
+    pub fn emit_deferred_diagnostics(&mut self, diagnostics: Vec<Diagnostic>) -> Result<()> {
+        let (diagnostics, suppressed_counts) = self.limit_diagnostics(diagnostics);
+
+        let deferred = diagnostics.into_iter().filter(|d| d.level() != DiagnosticLevel::Error);
+        self.emit_diagnostics_in_json(deferred.collect())?;
+
+        for (code, count) in suppressed_counts {
+            self.emit_suppression_summary(&code, count)?;
+        }
+        Ok(())
+    }
+
+    /// Removes any diagnostics past the `max_diagnostics_per_code` limit, and returns the remaining diagnostics,
+    /// along with how many diagnostics were suppressed for each code.
+    /// Allowed diagnostics don't count towards the limit.
+    fn limit_diagnostics(&self, diagnostics: Vec<Diagnostic>) -> (Vec<Diagnostic>, BTreeMap<String, usize>) {
+        let Some(limit) = self.max_diagnostics_per_code else {
+            return (diagnostics, BTreeMap::new());
+        };
+
+        let mut emitted_counts = BTreeMap::<String, usize>::new();
+        let mut suppressed_counts = BTreeMap::<String, usize>::new();
+        let mut limited_diagnostics = Vec::new();
+        for diagnostic in diagnostics {
+            if diagnostic.level() != DiagnosticLevel::Allowed {
+                let emitted_count = emitted_counts.entry(diagnostic.code().to_owned()).or_default();
+                if *emitted_count >= limit {
+                    *suppressed_counts.entry(diagnostic.code().to_owned()).or_default() += 1;
+                    continue;
+                }
+                *emitted_count += 1;
+            }
+            limited_diagnostics.push(diagnostic);
+        }
+        (limited_diagnostics, suppressed_counts)
+    }
+
+    fn emit_suppression_summary(&mut self, code: &str, count: usize) -> Result<()> {
+        let message = format!("{count} similar diagnostic(s) with code '{code}' were suppressed");
+        match self.diagnostic_format {
+            DiagnosticFormat::Human | DiagnosticFormat::Pretty => {
+                let prefix = console::style("note").blue().bold();
+                writeln!(self.output, "{prefix}: {}", console::style(message).bold())
+            }
+            DiagnosticFormat::Json | DiagnosticFormat::Ndjson => {
+                let mut serializer = serde_json::Serializer::new(&mut *self.output);
+                let mut state = serializer.serialize_struct("Diagnostic", 6)?;
+                state.serialize_field("message", &message)?;
+                state.serialize_field("severity", "note")?;
+                state.serialize_field("span", &None::<Span>)?;
+                state.serialize_field("notes", &[] as &[()])?;
+                state.serialize_field("suggestions", &[] as &[()])?;
+                state.serialize_field("error_code", code)?;
+                state.end()?;
+                writeln!(self.output)
+            }
         }
     }
 
@@ -76,6 +152,26 @@ impl<'a, T: Write> DiagnosticEmitter<'a, T> {
                     self.emit_snippet(span)?;
                 }
             }
+
+            // If the diagnostic contains suggestions, display them, along with any text they'd insert.
+            for suggestion in diagnostic.suggestions() {
+                let mut message = console::style(&suggestion.message).bold().to_string();
+                if !suggestion.replacement.is_empty() {
+                    message = format!("{message}: `{}`", suggestion.replacement);
+                }
+                writeln!(self.output, "{}: {message}", console::style("help").cyan().bold())?;
+                self.emit_snippet(&suggestion.span)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn emit_diagnostics_in_pretty(&mut self, diagnostics: Vec<Diagnostic>) -> Result<()> {
+        for diagnostic in diagnostics {
+            // Allowed diagnostics are rendered as empty strings, so we skip them entirely.
+            if diagnostic.level() != DiagnosticLevel::Allowed {
+                writeln!(self.output, "{}", render_diagnostic(&diagnostic, self.files))?;
+            }
         }
         Ok(())
     }
@@ -83,21 +179,7 @@ impl<'a, T: Write> DiagnosticEmitter<'a, T> {
     fn emit_diagnostics_in_json(&mut self, diagnostics: Vec<Diagnostic>) -> Result<()> {
         // Write each diagnostic as a single line of JSON.
         for diagnostic in diagnostics {
-            let severity = match diagnostic.level() {
-                DiagnosticLevel::Error => "error",
-                DiagnosticLevel::Warning => "warning",
-                DiagnosticLevel::Allowed => continue,
-            };
-
-            let mut serializer = serde_json::Serializer::new(&mut *self.output);
-            let mut state = serializer.serialize_struct("Diagnostic", 5)?;
-            state.serialize_field("message", &diagnostic.message())?;
-            state.serialize_field("severity", severity)?;
-            state.serialize_field("span", &diagnostic.span())?;
-            state.serialize_field("notes", diagnostic.notes())?;
-            state.serialize_field("error_code", diagnostic.code())?;
-            state.end()?;
-            writeln!(self.output)?; // Separate each diagnostic by a newline character.
+            emit_diagnostic_in_json(&mut *self.output, &diagnostic)?;
         }
         Ok(())
     }
@@ -121,6 +203,77 @@ impl<'a, T: Write> DiagnosticEmitter<'a, T> {
     }
 }
 
+/// Emits errors to an output as newline-delimited JSON as soon as they're reported, flushing the output after each one.
+///
+/// Only errors are streamed, since their level is known when they're reported. The level of a lint can still be
+/// changed by `allow` attributes, so lints must be emitted after compilation with
+/// [DiagnosticEmitter::emit_deferred_diagnostics].
+///
+/// Streamed errors are deduplicated exactly like
+/// [Diagnostics::into_updated](crate::diagnostics::Diagnostics::into_updated) would, and limited exactly like
+/// [DiagnosticEmitter::emit_diagnostics] would.
+#[derive(Debug)]
+pub struct DiagnosticStream<T: Write> {
+    /// The output that errors should be streamed to.
+    output: T,
+    /// The maximum number of diagnostics to emit per diagnostic code, if any.
+    max_diagnostics_per_code: Option<usize>,
+    /// The duplicate key of every error streamed so far, used to skip duplicates.
+    seen: HashSet<(String, Option<Span>, String)>,
+    /// How many errors have been streamed so far for each code.
+    emitted_counts: HashMap<String, usize>,
+}
+
+impl<T: Write> DiagnosticStream<T> {
+    pub fn new(output: T, slice_options: &SliceOptions) -> Self {
+        DiagnosticStream {
+            output,
+            max_diagnostics_per_code: slice_options.max_diagnostics_per_code,
+            seen: HashSet::new(),
+            emitted_counts: HashMap::new(),
+        }
+    }
+
+    /// Emits the provided diagnostic if it's an error, and it isn't a duplicate or past the per-code limit.
+    pub fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) -> Result<()> {
+        if diagnostic.level() != DiagnosticLevel::Error {
+            return Ok(());
+        }
+        if !self.seen.insert(diagnostic.duplicate_key()) {
+            return Ok(());
+        }
+
+        let emitted_count = self.emitted_counts.entry(diagnostic.code().to_owned()).or_default();
+        if self.max_diagnostics_per_code.is_some_and(|limit| *emitted_count >= limit) {
+            return Ok(());
+        }
+        *emitted_count += 1;
+
+        emit_diagnostic_in_json(&mut self.output, diagnostic)?;
+        self.output.flush()
+    }
+}
+
+/// Writes the provided diagnostic as a single line of JSON. Allowed diagnostics are skipped.
+fn emit_diagnostic_in_json(output: &mut impl Write, diagnostic: &Diagnostic) -> Result<()> {
+    let severity = match diagnostic.level() {
+        DiagnosticLevel::Error => "error",
+        DiagnosticLevel::Warning => "warning",
+        DiagnosticLevel::Allowed => return Ok(()),
+    };
+
+    let mut serializer = serde_json::Serializer::new(&mut *output);
+    let mut state = serializer.serialize_struct("Diagnostic", 6)?;
+    state.serialize_field("message", &diagnostic.message())?;
+    state.serialize_field("severity", severity)?;
+    state.serialize_field("span", &diagnostic.span())?;
+    state.serialize_field("notes", diagnostic.notes())?;
+    state.serialize_field("suggestions", diagnostic.suggestions())?;
+    state.serialize_field("error_code", diagnostic.code())?;
+    state.end()?;
+    writeln!(output) // Separate each diagnostic by a newline character.
+}
+
 pub fn emit_totals(total_warnings: usize, total_errors: usize) -> Result<()> {
     // Totals are always printed to stdout.
     let stdout = &mut console::Term::stdout();
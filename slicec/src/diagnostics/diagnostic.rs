@@ -1,10 +1,11 @@
 // Copyright (c) ZeroC, Inc.
 
-use super::{Error, Lint, Note};
+use super::{Error, Lint, Note, Suggestion};
 use crate::ast::Ast;
 use crate::grammar::{attributes, Attributable, Entity};
-use crate::slice_file::{SliceFile, Span};
+use crate::slice_file::{Location, SliceFile, Span};
 use crate::slice_options::SliceOptions;
+use std::collections::HashSet;
 
 /// A diagnostic is a message that is reported to the user during compilation.
 /// It can either hold an [Error] or a [Lint].
@@ -15,6 +16,7 @@ pub struct Diagnostic {
     span: Option<Span>,
     scope: Option<String>,
     notes: Vec<Note>,
+    suggestions: Vec<Suggestion>,
 }
 
 impl Diagnostic {
@@ -31,6 +33,7 @@ impl Diagnostic {
             span: None,
             scope: None,
             notes: Vec::new(),
+            suggestions: Vec::new(),
         }
     }
 
@@ -61,6 +64,13 @@ impl Diagnostic {
         self.span.as_ref()
     }
 
+    /// Returns the key which identifies duplicates of this diagnostic: its code, span, and message.
+    /// The message is included so that diagnostics without spans (like IO errors) are only duplicates if they're
+    /// reporting the same thing.
+    pub(crate) fn duplicate_key(&self) -> (String, Option<Span>, String) {
+        (self.code().to_owned(), self.span.clone(), self.message())
+    }
+
     /// Returns the scope of this diagnostic if it has one.
     pub fn scope(&self) -> Option<&String> {
         self.scope.as_ref()
@@ -71,6 +81,11 @@ impl Diagnostic {
         &self.notes
     }
 
+    /// Returns any [Suggestions](Suggestion) for fixing this diagnostic.
+    pub fn suggestions(&self) -> &[Suggestion] {
+        &self.suggestions
+    }
+
     pub fn set_span(mut self, span: &Span) -> Self {
         self.span = Some(span.to_owned());
         self
@@ -94,8 +109,36 @@ impl Diagnostic {
         self
     }
 
+    /// Attaches a suggested edit to this diagnostic, which replaces the text covered by `span` with `replacement`.
+    pub fn add_suggestion(mut self, message: impl Into<String>, span: &Span, replacement: impl Into<String>) -> Self {
+        self.suggestions.push(Suggestion {
+            message: message.into(),
+            span: span.clone(),
+            replacement: replacement.into(),
+            is_machine_applicable: true,
+        });
+        self
+    }
+
+    /// Attaches a suggested edit that isn't [machine applicable](Suggestion::is_machine_applicable) to this diagnostic.
+    /// These are shown to users, but are never applied by `--fix`.
+    pub fn add_manual_suggestion(
+        mut self,
+        message: impl Into<String>,
+        span: &Span,
+        replacement: impl Into<String>,
+    ) -> Self {
+        self.suggestions.push(Suggestion {
+            message: message.into(),
+            span: span.clone(),
+            replacement: replacement.into(),
+            is_machine_applicable: false,
+        });
+        self
+    }
+
     pub fn push_into(self, diagnostics: &mut Diagnostics) {
-        diagnostics.0.push(self);
+        diagnostics.push(self);
     }
 }
 
@@ -131,8 +174,11 @@ pub enum DiagnosticLevel {
     Allowed,
 }
 
-#[derive(Debug, Default)]
-pub struct Diagnostics(Vec<Diagnostic>);
+/// A function that is called with each diagnostic as it's reported. See [Diagnostics::set_listener].
+pub type DiagnosticListener = Box<dyn FnMut(&Diagnostic)>;
+
+#[derive(Default)]
+pub struct Diagnostics(Vec<Diagnostic>, Option<DiagnosticListener>);
 
 impl Diagnostics {
     /// Creates a new diagnostics container that is empty.
@@ -140,8 +186,23 @@ impl Diagnostics {
         Self::default()
     }
 
+    /// Sets a function that is called with each diagnostic as it's reported to this container.
+    /// Only a single listener can be set at a time; setting a new listener replaces the previous one.
+    pub fn set_listener(&mut self, listener: impl FnMut(&Diagnostic) + 'static) {
+        self.1 = Some(Box::new(listener));
+    }
+
+    fn push(&mut self, diagnostic: Diagnostic) {
+        if let Some(listener) = &mut self.1 {
+            listener(&diagnostic);
+        }
+        self.0.push(diagnostic);
+    }
+
     pub fn extend(&mut self, other: Diagnostics) {
-        self.0.extend(other.0);
+        for diagnostic in other.0 {
+            self.push(diagnostic);
+        }
     }
 
     /// Returns true if this contains any diagnostics that are errors.
@@ -157,55 +218,103 @@ impl Diagnostics {
 
     /// Returns the diagnostics this struct contains after it has patched and updated them.
     /// Lint levels can be configured via attributes or command line options, but these aren't applied until this runs.
+    ///
+    /// Any duplicate diagnostics are removed, keeping only the first occurrence of each. Diagnostics are duplicates if
+    /// they have the same code, span, and message, which can happen when the same problem is detected by multiple
+    /// passes of the compiler.
     pub fn into_updated(mut self, ast: &Ast, files: &[SliceFile], options: &SliceOptions) -> Vec<Diagnostic> {
-        // Helper function that checks whether a lint should be allowed according to the provided identifiers.
-        fn is_lint_allowed_by<'b>(mut identifiers: impl Iterator<Item = &'b String>, lint: &Lint) -> bool {
-            identifiers.any(|identifier| identifier == "All" || identifier == lint.code())
-        }
-
-        // Helper function that checks whether a lint is allowed by attributes on the provided entity.
-        fn is_lint_allowed_by_attributes(attributable: &(impl Attributable + ?Sized), lint: &Lint) -> bool {
-            let attributes = attributable.all_attributes().concat().into_iter();
-            let mut allowed = attributes.filter_map(|a| a.downcast::<attributes::Allow>());
-            allowed.any(|allow| is_lint_allowed_by(allow.allowed_lints.iter(), lint))
-        }
+        let mut seen = HashSet::new();
+        self.0.retain(|diagnostic| seen.insert(diagnostic.duplicate_key()));
 
         for diagnostic in &mut self.0 {
             // If this diagnostic is a lint, update its diagnostic level. Errors always have a level of `Error`.
             if let DiagnosticKind::Lint(lint) = &diagnostic.kind {
-                // Check if the lint is allowed by an `--allow` flag passed on the command line.
-                if is_lint_allowed_by(options.allowed_lints.iter(), lint) {
-                    diagnostic.level = DiagnosticLevel::Allowed;
-                }
-
-                // If the diagnostic has a span, check if it's affected by an `allow` attribute on its file.
-                if let Some(span) = diagnostic.span() {
-                    let file = files.iter().find(|f| f.relative_path == span.file).expect("no file");
-                    if is_lint_allowed_by_attributes(file, lint) {
-                        diagnostic.level = DiagnosticLevel::Allowed;
-                    }
-                }
-
-                // If the diagnostic has a scope, check if it's affected by an `allow` attribute in that scope.
-                if let Some(scope) = diagnostic.scope() {
-                    if let Ok(entity) = ast.find_element::<dyn Entity>(scope) {
-                        if is_lint_allowed_by_attributes(entity, lint) {
-                            diagnostic.level = DiagnosticLevel::Allowed;
-                        }
-                    }
-                }
+                // If the diagnostic has a span, it's affected by the attributes on its file.
+                let file = diagnostic.span().map(|span| {
+                    let file = files.iter().find(|f| f.relative_path == span.file);
+                    file.expect("no file")
+                });
+
+                // If the diagnostic has a scope, it's affected by the attributes in that scope.
+                let scope = diagnostic.scope();
+                let entity = scope.and_then(|scope| ast.find_element::<dyn Entity>(scope).ok());
+
+                diagnostic.level = get_lint_level(lint, file, entity, options);
             }
         }
+
+        // If requested, sort the diagnostics by their locations. Since the sort is stable, diagnostics reported at the
+        // same location keep the order they were reported in.
+        if options.sort_diagnostics {
+            fn location(diagnostic: &Diagnostic) -> Option<(&str, Location)> {
+                diagnostic.span().map(|span| (span.file.as_str(), span.start))
+            }
+            self.0.sort_by(|a, b| location(a).cmp(&location(b)));
+        }
         self.0
     }
 
     /// Returns the diagnostics held by this without any updates or patches.
-    /// This should only be called by tests that want to bypass this behavior.
+    /// This should only be called by tests that want to bypass this behavior, or when merging diagnostics that were
+    /// collected separately (whose updates are applied once they've been merged).
     pub fn into_inner(self) -> Vec<Diagnostic> {
         self.0
     }
 }
 
+impl std::fmt::Debug for Diagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Diagnostics").field(&self.0).finish()
+    }
+}
+
+/// Returns the level that the provided lint is reported with, after applying any `--allow` and `--deny` flags,
+/// along with any `allow` and `deny` attributes on the provided file and entity (and the entity's parents).
+///
+/// More specific configurations take precedence: attributes on an entity override attributes on its parents, which
+/// override attributes on its file, which override the command line flags. At each of these levels, configurations
+/// naming the lint take precedence over 'All', and if a lint is both allowed and denied, denying it takes precedence.
+pub fn get_lint_level(
+    lint: &Lint,
+    file: Option<&SliceFile>,
+    entity: Option<&dyn Entity>,
+    options: &SliceOptions,
+) -> DiagnosticLevel {
+    // Helper function that returns the level a lint should be reported with according to the provided identifiers,
+    // or `None` if the lint isn't affected by them.
+    fn level_set_by(allowed: &[&String], denied: &[&String], lint: &Lint) -> Option<DiagnosticLevel> {
+        for identifier in [lint.code(), "All"] {
+            if denied.iter().any(|denied_lint| *denied_lint == identifier) {
+                return Some(DiagnosticLevel::Error);
+            }
+            if allowed.iter().any(|allowed_lint| *allowed_lint == identifier) {
+                return Some(DiagnosticLevel::Allowed);
+            }
+        }
+        None
+    }
+
+    // Helper function that returns the level a lint should be reported with according to the `allow` and `deny`
+    // attributes on the provided element, or its parents.
+    fn level_set_by_attributes(attributable: &(impl Attributable + ?Sized), lint: &Lint) -> Option<DiagnosticLevel> {
+        attributable.all_attributes().into_iter().find_map(|attributes| {
+            let allows = attributes.iter().filter_map(|a| a.downcast::<attributes::Allow>());
+            let denies = attributes.iter().filter_map(|a| a.downcast::<attributes::Deny>());
+            let allowed = allows.flat_map(|allow| &allow.allowed_lints).collect::<Vec<_>>();
+            let denied = denies.flat_map(|deny| &deny.denied_lints).collect::<Vec<_>>();
+            level_set_by(&allowed, &denied, lint)
+        })
+    }
+
+    let allowed = options.allowed_lints.iter().collect::<Vec<_>>();
+    let denied = options.denied_lints.iter().collect::<Vec<_>>();
+    entity
+        .and_then(|entity| level_set_by_attributes(entity, lint))
+        .or_else(|| file.and_then(|file| level_set_by_attributes(file, lint)))
+        .or_else(|| level_set_by(&allowed, &denied, lint))
+        .unwrap_or_else(|| lint.get_default_level())
+}
+
 pub fn get_totals(diagnostics: &[Diagnostic]) -> (usize, usize) {
     let (mut total_warnings, mut total_errors) = (0, 0);
 
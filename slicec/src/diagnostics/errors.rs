@@ -119,6 +119,69 @@ pub enum Error {
         kind: Option<String>,
     },
 
+    // ----------------  Constant Errors ---------------- //
+    /// Evaluating a constant expression caused an overflow.
+    ConstantExpressionOverflows,
+
+    /// A constant expression contained a division (or remainder) by zero.
+    DivisionByZero,
+
+    /// An operator was applied to operands of a type it doesn't support.
+    InvalidOperandTypes {
+        /// The operator that was applied.
+        operator: String,
+        /// The types of the operands the operator was applied to.
+        operands: String,
+    },
+
+    /// A constant's value references itself, either directly or indirectly.
+    SelfReferentialConstant {
+        /// The identifier of the constant.
+        identifier: String,
+        /// The cycle that was found.
+        cycle: String,
+    },
+
+    /// A constant's value was outside the bounds of its type.
+    ConstantValueOutOfBounds {
+        /// The identifier of the constant.
+        identifier: String,
+        /// The value of the constant.
+        value: i128,
+        /// The minimum value of the constant's type.
+        min: i128,
+        /// The maximum value of the constant's type.
+        max: i128,
+    },
+
+    /// A constant was declared with a type that constants cannot have.
+    ConstantTypeNotSupported {
+        /// The identifier of the constant.
+        identifier: String,
+        /// The name of the type the constant was declared with.
+        kind: String,
+    },
+
+    /// A member's default value was outside the bounds of its type.
+    DefaultValueOutOfBounds {
+        /// The identifier of the member.
+        identifier: String,
+        /// The default value of the member.
+        value: i128,
+        /// The minimum value of the member's type.
+        min: i128,
+        /// The maximum value of the member's type.
+        max: i128,
+    },
+
+    /// A default value was given to a member whose type cannot have default values.
+    DefaultValueTypeNotSupported {
+        /// The identifier of the member.
+        identifier: String,
+        /// The name of the member's type.
+        kind: String,
+    },
+
     // ----------------  Exception Errors ---------------- //
     /// Exception specifications can only be used in Slice1 mode.
     ExceptionSpecificationNotSupported,
@@ -136,6 +199,12 @@ pub enum Error {
     /// Multiple streamed parameters were used as parameters for an operation.
     MultipleStreamedMembers,
 
+    /// A parameter without a default value came after a parameter with a default value.
+    ParameterMissingDefaultValue {
+        /// The identifier of the parameter that caused the error.
+        parameter_identifier: String,
+    },
+
     // ----------------  Struct Errors ---------------- //
     /// Compact structs cannot be empty.
     CompactStructCannotBeEmpty,
@@ -215,6 +284,146 @@ pub enum Error {
         base: u32,
     },
 
+    /// A character literal didn't contain exactly one character.
+    InvalidCharacterLiteral {
+        /// The contents of the character literal (without its enclosing quotation marks).
+        literal: String,
+    },
+
+    /// A string or character literal contained an escape sequence that couldn't be decoded.
+    InvalidEscapeSequence {
+        /// The invalid escape sequence, including its leading backslash. Ex: `\q`.
+        sequence: String,
+        /// Why the escape sequence is invalid.
+        reason: String,
+    },
+
+    /// A type or construct was used that is denied by the project's type policy (configured with `--deny-type`).
+    /// If the policy provided its own code for the denied type, it's reported with that code instead of `E064`.
+    DeniedTypeUsed {
+        /// The name of the denied type or construct.
+        name: String,
+        /// The policy-specific code to report this error with, if any. Ex: `myorg::NoFloats`.
+        policy_code: Option<String>,
+    },
+
+    /// An enumerator in a flags enum has a value which isn't a valid flag or combination of flags.
+    InvalidFlagValue {
+        /// The identifier of the enumerator.
+        enumerator_identifier: String,
+        /// The value of the enumerator.
+        value: i128,
+    },
+
+    /// The same modifier was applied to an operation multiple times.
+    DuplicateOperationModifier {
+        /// The keyword of the duplicated modifier.
+        modifier: String,
+    },
+
+    /// An operation was given modifiers which cannot be used together.
+    ConflictingOperationModifiers {
+        /// The keyword of the modifier that conflicts with a previously applied modifier.
+        modifier: String,
+        /// The keyword of the previously applied modifier.
+        conflicting_modifier: String,
+    },
+
+    /// An interface inherits multiple, distinct operations with the same name from different base interfaces.
+    AmbiguousInheritedOperation {
+        /// The identifier of the interface that inherits the operations.
+        interface_identifier: String,
+        /// The identifier shared by the inherited operations.
+        operation_identifier: String,
+    },
+
+    /// An interface inherits from an interface which doesn't support its file's compilation mode.
+    IncompatibleBaseInterface {
+        /// The identifier of the interface that inherits from the base interface.
+        interface_identifier: String,
+        /// The identifier of the base interface.
+        base_identifier: String,
+        /// The compilation mode of the inheriting interface's file.
+        mode: CompilationMode,
+    },
+
+    /// Multiple classes, exceptions, or interfaces have the same type ID.
+    /// See [TypeIdStrategy](crate::utils::type_id_util::TypeIdStrategy).
+    DuplicateTypeId {
+        /// The type ID that's used by multiple definitions.
+        type_id: String,
+    },
+
+    /// Multiple classes have the same compact type ID.
+    DuplicateCompactTypeId {
+        /// The compact type ID that's used by multiple classes.
+        compact_id: u32,
+    },
+
+    /// An identifier collides with an identifier that's reserved by the code generator.
+    ReservedIdentifier {
+        /// The identifier that was used.
+        identifier: String,
+        /// The name of the code generator which reserved the identifier.
+        reserved_by: String,
+    },
+
+    /// An operation defined in Slice2 mode declared more than one error type in its throws clause.
+    MultipleErrorTypes {
+        /// The identifier of the operation.
+        operation_identifier: String,
+    },
+
+    /// Multiple files generated by a code generator have the same path.
+    GeneratedFileConflict {
+        /// The path that's used by multiple generated files.
+        path: String,
+    },
+
+    /// A tagged member uses a tag that was reserved with the `reservedTags` attribute.
+    TagIsReserved {
+        /// The identifier of the tagged member.
+        identifier: String,
+        /// The reserved tag that the member uses.
+        tag: u32,
+    },
+
+    /// An operation marked with the `oneway` modifier has return members or a throws clause.
+    OnewayOperationReturnsData {
+        /// The identifier of the operation.
+        identifier: String,
+    },
+
+    /// The compilation was cancelled with its [CancellationToken](crate::compilation_state::CancellationToken)
+    /// before it could finish.
+    CompilationCancelled,
+
+    /// The compiler violated one of its internal invariants, and panicked. This is always a bug in slicec (or in an
+    /// embedder-provided patcher or validator), and is only reported by [compile_from_strings_infallible](
+    /// crate::compile_from_strings_infallible), which converts panics into diagnostics instead of unwinding.
+    InternalError {
+        /// The message that the compiler panicked with.
+        message: String,
+    },
+
+    /// A public definition uses an internal type in its public API (as a field type, parameter type, base type, etc.).
+    ExposesInternalType {
+        /// The kind of the public definition.
+        kind: String,
+        /// The identifier of the public definition.
+        identifier: String,
+        /// The identifier of the internal type it exposes.
+        type_identifier: String,
+    },
+
+    /// An element was marked as removed in a version that isn't after the version it was added in.
+    InvalidVersionRange {
+        /// The version the element was added in.
+        since: String,
+        /// The version the element was removed in.
+        removed: String,
+    },
+
     /// An invalid compilation mode was specified.
     InvalidCompilationMode {
         /// The compilation mode that was specified.
@@ -229,6 +438,18 @@ pub enum Error {
         cycle: String,
     },
 
+    /// A value recorded in the lock file was changed without regenerating the lock file.
+    LockedValueChanged {
+        /// The fully scoped identifier of the element whose value changed.
+        identifier: String,
+        /// The kind of value that changed; Ex: "enumerator value", "tag".
+        kind: &'static str,
+        /// The value recorded in the lock file.
+        locked: i128,
+        /// The value currently defined in Slice.
+        actual: i128,
+    },
+
     /// No element with the specified identifier was found.
     DoesNotExist {
         /// The identifier that was not found.
@@ -559,6 +780,190 @@ implement_diagnostic_functions!(
         CannotBeCompact,
         format!("'{kind}' '{identifier}' cannot be marked compact"),
         kind, identifier
+    ),
+    (
+        "E056",
+        LockedValueChanged,
+        format!("the {kind} of '{identifier}' is locked to '{locked}', but was changed to '{actual}'"),
+        identifier, kind, locked, actual
+    ),
+    (
+        "E057",
+        ConstantExpressionOverflows,
+        "constant expression overflows the range of -2^127 <= i <= 2^127 - 1"
+    ),
+    (
+        "E058",
+        DivisionByZero,
+        "attempted to divide by zero in constant expression"
+    ),
+    (
+        "E059",
+        InvalidOperandTypes,
+        format!("operator '{operator}' cannot be applied to operands of type {operands}"),
+        operator, operands
+    ),
+    (
+        "E060",
+        SelfReferentialConstant,
+        format!("constant '{identifier}' illegally references itself: {cycle}"),
+        identifier, cycle
+    ),
+    (
+        "E061",
+        ConstantValueOutOfBounds,
+        format!(
+            "invalid constant '{identifier}': value '{value}' is out of bounds. The value must be between '{min}..{max}', inclusive",
+        ),
+        identifier, value, min, max
+    ),
+    (
+        "E062",
+        ConstantTypeNotSupported,
+        format!("invalid constant '{identifier}': constants cannot be of type '{kind}'"),
+        identifier, kind
+    ),
+    (
+        "E063",
+        InvalidCharacterLiteral,
+        format!("invalid character literal '{literal}': character literals must contain exactly one character"),
+        literal
+    ),
+    (
+        "E064",
+        DeniedTypeUsed,
+        format!("use of '{name}' is denied by this project's type policy"),
+        name
+    ),
+    (
+        "E065",
+        InvalidFlagValue,
+        format!("enumerator '{enumerator_identifier}' has an invalid flag value of '{value}'"),
+        enumerator_identifier,
+        value
+    ),
+    (
+        "E066",
+        DuplicateOperationModifier,
+        format!("the '{modifier}' modifier was applied multiple times"),
+        modifier
+    ),
+    (
+        "E067",
+        ConflictingOperationModifiers,
+        format!("the '{modifier}' modifier cannot be used with the '{conflicting_modifier}' modifier"),
+        modifier,
+        conflicting_modifier
+    ),
+    (
+        "E068",
+        AmbiguousInheritedOperation,
+        format!("interface '{interface_identifier}' inherits multiple operations named '{operation_identifier}'"),
+        interface_identifier,
+        operation_identifier
+    ),
+    (
+        "E069",
+        IncompatibleBaseInterface,
+        format!("interface '{interface_identifier}' cannot inherit from '{base_identifier}' in {mode} mode"),
+        interface_identifier,
+        base_identifier,
+        mode
+    ),
+    (
+        "E070",
+        DuplicateTypeId,
+        format!("the type ID '{type_id}' is used by multiple definitions"),
+        type_id
+    ),
+    (
+        "E071",
+        DuplicateCompactTypeId,
+        format!("the compact type ID '{compact_id}' is used by multiple classes"),
+        compact_id
+    ),
+    (
+        "E072",
+        ReservedIdentifier,
+        format!("'{identifier}' is a reserved identifier of {reserved_by}"),
+        identifier,
+        reserved_by
+    ),
+    (
+        "E073",
+        MultipleErrorTypes,
+        format!(
+            "operation '{operation_identifier}' declares multiple error types, but Slice2 operations can only have one",
+        ),
+        operation_identifier
+    ),
+    (
+        "E074",
+        GeneratedFileConflict,
+        format!("multiple generated files have the same path: '{path}'"),
+        path
+    ),
+    (
+        "E075",
+        TagIsReserved,
+        format!("invalid tag on member '{identifier}': tag '{tag}' is reserved"),
+        identifier,
+        tag
+    ),
+    (
+        "E076",
+        OnewayOperationReturnsData,
+        format!("oneway operation '{identifier}' cannot return or throw anything"),
+        identifier
+    ),
+    ("E077", CompilationCancelled, "compilation was cancelled before it finished"),
+    (
+        "E078",
+        ExposesInternalType,
+        format!("public {kind} '{identifier}' exposes internal type '{type_identifier}'"),
+        kind,
+        identifier,
+        type_identifier
+    ),
+    (
+        "E079",
+        InvalidVersionRange,
+        format!("an element added in version '{since}' cannot be removed in version '{removed}'"),
+        since,
+        removed
+    ),
+    (
+        "E080",
+        DefaultValueOutOfBounds,
+        format!(
+            "invalid default value for '{identifier}': value '{value}' is out of bounds. The value must be between '{min}..{max}', inclusive",
+        ),
+        identifier, value, min, max
+    ),
+    (
+        "E081",
+        DefaultValueTypeNotSupported,
+        format!("invalid default value for '{identifier}': members of type '{kind}' cannot have default values"),
+        identifier, kind
+    ),
+    (
+        "E082",
+        ParameterMissingDefaultValue,
+        format!("parameter '{parameter_identifier}' must have a default value, since it follows a parameter with one"),
+        parameter_identifier
+    ),
+    (
+        "E083",
+        InternalError,
+        format!("internal compiler error: {message}"),
+        message
+    ),
+    (
+        "E084",
+        InvalidEscapeSequence,
+        format!("invalid escape sequence '{sequence}': {reason}"),
+        sequence,
+        reason
     )
 );
 
@@ -0,0 +1,166 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains a corpus of synthetic examples, one for every [Error](super::Error) and [Lint](super::Lint)
+//! that slicec can report.
+//!
+//! Each example shows a minimal input which causes the compiler to report its diagnostic. Downstream tools can use
+//! this corpus to check that they handle every diagnostic code, and documentation can be generated from it.
+
+/// A synthetic example of a diagnostic, showing an input which causes slicec to report it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DiagnosticExample {
+    /// The code of the diagnostic this example triggers (ex: `"E012"` or `"Deprecated"`).
+    pub code: &'static str,
+
+    /// The input which triggers the diagnostic.
+    pub input: ExampleInput,
+}
+
+/// The input of a [DiagnosticExample].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExampleInput {
+    /// Slice files which trigger the diagnostic when compiled together with the default options.
+    /// Each string is the contents of a separate Slice file.
+    Slice(&'static [&'static str]),
+
+    /// The diagnostic can't be triggered by compiling Slice files with the default options.
+    /// The reason describes what does cause the diagnostic to be reported.
+    Unavailable { reason: &'static str },
+}
+
+/// Returns the example for the diagnostic with the provided code, if one exists.
+pub fn example_for(code: &str) -> Option<&'static DiagnosticExample> {
+    all_examples().iter().find(|example| example.code == code)
+}
+
+/// Returns the examples of every diagnostic slicec can report. Examples for errors come first, ordered by code,
+/// followed by examples for lints, in the same order as [Lint::ALL_CODES](super::Lint::ALL_CODES).
+///
+/// Every code in [Error::ALL_CODES](super::Error::ALL_CODES) and [Lint::ALL_CODES](super::Lint::ALL_CODES) has
+/// exactly one example.
+pub fn all_examples() -> &'static [DiagnosticExample] {
+    EXAMPLES
+}
+
+macro_rules! slice {
+    ($($file:literal),+) => {
+        ExampleInput::Slice(&[$($file),+])
+    };
+}
+
+macro_rules! unavailable {
+    ($reason:literal) => {
+        ExampleInput::Unavailable { reason: $reason }
+    };
+}
+
+macro_rules! examples {
+    ($(($code:literal, $input:expr)),* $(,)?) => {
+        &[$(DiagnosticExample { code: $code, input: $input }),*]
+    };
+}
+
+// The test suite checks that this list contains exactly one example for every error and lint code,
+// and that compiling each example's Slice files reports its diagnostic.
+#[rustfmt::skip]
+const EXAMPLES: &[DiagnosticExample] = examples![
+    ("E001", unavailable!("reported when slicec fails to read a Slice file, or write an output file")),
+    ("E002", slice!("module Test\nstruct S {")),
+    ("E004", slice!("[[allow(Fake)]]")),
+    ("E005", slice!("module Test\ntypealias D = Dictionary<int32?, string>")),
+    ("E006", slice!("module Test\nstruct S { a: int32 }\ntypealias D = Dictionary<S, string>")),
+    ("E007", slice!("module Test\ntypealias D = Dictionary<float32, string>")),
+    ("E008", slice!("module Test\ncompact struct S { a: float32 }\ntypealias D = Dictionary<S, string>")),
+    ("E009", slice!("module Test\nenum E : uint8? { A }")),
+    ("E010", slice!("module Test\nenum E : uint8 {}")),
+    ("E011", slice!("module Test\nenum E : string { A }")),
+    ("E012", slice!("module Test\nstruct S {}\nstruct S {}")),
+    ("E013", slice!("mode = Slice1\nmodule Test\nclass A { a: int32 }\nclass B : A { a: int32 }")),
+    ("E014", slice!("module Test\nstruct S {\n    tag(1) a: int32?\n    tag(1) b: int32?\n}")),
+    ("E016", slice!("module Test\ninterface I {\n    op(a: stream int32, b: int32)\n}")),
+    ("E017", slice!("module Test\ninterface I {\n    op() -> (a: int32)\n}")),
+    ("E018", slice!("module Test\ncompact struct S { tag(1) a: int32? }")),
+    ("E019", slice!("module Test\nstruct S { tag(1) a: int32 }")),
+    ("E020", slice!("mode = Slice1\nmodule Test\nclass C {}\nclass D { tag(1) c: C? }")),
+    ("E021", slice!(
+        "mode = Slice1\nmodule Test\nclass C {}\ncompact struct S { c: C }\ninterface I {\n    op(tag(1) s: S?)\n}"
+    )),
+    ("E022", slice!("module Test\nstruct S {}\nconst C = S + 1")),
+    ("E024", slice!("module Test\ncompact struct S {}")),
+    ("E025", slice!("module Test\ntypealias Foo = Foo")),
+    ("E026", slice!("module Test\nenum E : int8 { A = 200 }")),
+    ("E027", slice!("module Test\nstruct S { tag(2147483648) a: int32? }")),
+    ("E028", slice!("module Test\nenum E : uint8 {\n    A = 1\n    B = 1\n}")),
+    ("E029", slice!("module Test\nclass C {}")),
+    ("E030", slice!("mode = Slice1\nmodule Test\nstruct S { a: uint16 }")),
+    ("E032", slice!("mode = Slice1\nmodule Test\nstruct S { a: int32? }")),
+    ("E033", slice!("mode = Slice1\nmodule Test\ninterface I {\n    op(s: stream int32)\n}")),
+    ("E034", slice!("module Test\n[flags]\nstruct S {}")),
+    ("E035", slice!("[[allow]]")),
+    ("E036", slice!("module Test\n[deprecated(\"a\", \"b\")]\nstruct S {}")),
+    ("E037", unavailable!("reported by code generators when an element is missing an attribute they require")),
+    ("E038", slice!("module Test\ninterface I {\n    op(a: stream int32, b: stream int32)\n}")),
+    ("E039", slice!("mode = Slice1\nmodule Test\nclass C(2147483648) {}")),
+    ("E040", slice!("module Test\nconst C = 999999999999999999999999999999999999999999999")),
+    ("E041", slice!("module Test\nconst C = 0b102")),
+    ("E042", slice!("mode = Slice3")),
+    ("E043", slice!("mode = Slice1\nmode = Slice2")),
+    ("E047", slice!("module Test\nstruct Container { c: Container }")),
+    ("E049", slice!("module Test\nstruct S { a: Fake }")),
+    ("E050", slice!("module Test\n[deprecated]\n[deprecated]\nstruct S {}")),
+    ("E051", slice!("module Test\ntypealias A = int32?")),
+    ("E052", slice!(
+        "mode = Slice1\nmodule Test\nexception E {}",
+        "module Test\ninterface I {\n    op() throws E\n}"
+    )),
+    ("E054", slice!("module Test\nenum E : uint8 { A(a: int32) }")),
+    ("E055", slice!("module Test\ncompact unchecked enum E { A }")),
+    ("E056", unavailable!("reported when a lock file is provided with '--lock-file', and a locked value was changed")),
+    ("E057", slice!("module Test\nconst C = 170141183460469231731687303715884105727 + 1")),
+    ("E058", slice!("module Test\nconst C = 1 / 0")),
+    ("E059", slice!("module Test\nconst C = \"a\" - \"b\"")),
+    ("E060", slice!("module Test\nconst A = B\nconst B = A")),
+    ("E061", slice!("module Test\nconst C: uint8 = 255 + 1")),
+    ("E062", slice!("module Test\nconst C: float32 = 5")),
+    ("E063", slice!("module Test\nconst C = 'ab'")),
+    ("E064", unavailable!("reported when a type policy is provided with '--deny-type', and a denied type is used")),
+    ("E065", slice!("module Test\n[flags]\nenum E : uint8 {\n    A = 1\n    B = 6\n}")),
+    ("E066", slice!("module Test\ninterface I {\n    idempotent idempotent op()\n}")),
+    ("E067", slice!("module Test\ninterface I {\n    readonly idempotent op()\n}")),
+    ("E068", slice!("module Test\ninterface A { op() }\ninterface B { op() }\ninterface C : A, B {}")),
+    ("E069", slice!(
+        "module Test\ninterface A {}",
+        "mode = Slice1\nmodule Test\ninterface B : A {}"
+    )),
+    ("E070", unavailable!("reported when a custom type ID strategy assigns the same type ID to multiple definitions")),
+    ("E071", slice!("mode = Slice1\nmodule Test\nclass A(1) {}\nclass B(1) {}")),
+    ("E072", unavailable!("reported when an identifier reserved by a code generator is used in a definition")),
+    ("E073", slice!("module Test\nstruct A {}\nstruct B {}\ninterface I { op() throws (A, B) }")),
+    ("E074", unavailable!("reported when a code generator generates multiple files with the same path")),
+    ("E075", slice!("module Test\n[reservedTags(\"1\")]\nstruct S { tag(1) a: int32? }")),
+    ("E076", slice!("module Test\ninterface I {\n    oneway op() -> bool\n}")),
+    ("E077", unavailable!("reported when a compilation is cancelled through its cancellation token")),
+    ("E078", slice!("module Test\ninternal struct Secret {}\nstruct S { secret: Secret }")),
+    ("E079", slice!("module Test\n[since(\"2.0\")]\n[removed(\"1.0\")]\nstruct S {}")),
+    ("E080", slice!("module Test\nstruct S { a: uint8 = 256 }")),
+    ("E081", slice!("module Test\nstruct S { a: float32 = 1 }")),
+    ("E082", slice!("module Test\ninterface I {\n    op(a: int32 = 0, b: int32)\n}")),
+    ("E083", unavailable!("reported when the compiler panics while compiling with 'compile_from_strings_infallible'")),
+    ("E084", slice!("module Test\nconst Greeting: string = \"Hello\\q\"")),
+    ("DuplicateFile", unavailable!("reported when the same file path is passed to slicec more than once")),
+    ("SymlinkCycle", unavailable!("reported when a referenced directory contains a symbolic link to its parent")),
+    ("IgnoredPath", unavailable!("reported when a path in a referenced directory matches a '.sliceignore' file")),
+    ("Deprecated", slice!("module Test\n[deprecated]\nstruct S {}\nstruct T { s: S }")),
+    ("MalformedDocComment", slice!("module Test\n/// @param\nstruct S {}")),
+    ("MalformedInlineTag", slice!("module Test\n/// See {@link}.\nstruct S {}")),
+    ("IncorrectDocComment", slice!("module Test\n/// @returns: nothing.\nstruct S {}")),
+    ("BrokenDocLink", slice!("module Test\n/// See {@link Fake}.\nstruct S {}")),
+    ("UnsupportedDocMarkup", unavailable!("reported by code generators when doc comment markup isn't supported")),
+    ("RedundantSeparator", slice!("module Test\nstruct S {};")),
+    ("UnreservedTagGap", unavailable!("reported when '--audit-tags' is set, and a type skips over unreserved tags")),
+    ("UnusedDefinition", unavailable!("reported when '--report-unused' is set, and a definition can't be reached")),
+    ("KeywordCollision", unavailable!("reported when an identifier is a keyword of a code generator's language")),
+    ("NamingConvention", unavailable!("reported when '--naming-convention' is set, and an identifier breaks it")),
+    ("OperationCaseCollision", slice!("module Test\ninterface I {\n    getItem()\n    GetItem()\n}")),
+    ("ConfusableIdentifier", slice!("module Test\nstruct Foo {}\nstruct F\u{43e}o {}")),
+];
@@ -13,6 +13,23 @@ pub enum Lint {
         path: String,
     },
 
+    /// A referenced directory contains a symbolic link to itself, or to one of its parent directories.
+    /// The link is skipped, since following it would recurse forever.
+    SymlinkCycle {
+        /// The path of the symbolic link.
+        path: String,
+    },
+
+    /// A path in a referenced directory was skipped, because it matched a pattern in an ignore file.
+    /// See [ignore_util](crate::utils::ignore_util). This is only reported when `--verbose` is set.
+    IgnoredPath {
+        /// The path that was skipped.
+        path: String,
+
+        /// The path of the ignore file which matched it.
+        ignore_file: String,
+    },
+
     /// A deprecated Slice element was used.
     Deprecated {
         /// The element's identifier.
@@ -25,6 +42,11 @@ pub enum Lint {
     /// A syntactical mistake in a doc-comment.
     MalformedDocComment { message: String },
 
+    /// An inline tag in a doc-comment is malformed. Either:
+    /// - The tag is missing its argument or closing brace. Ex: `{@link}`, or `{@link Foo`.
+    /// - The tag's argument is invalid. Ex: `{@link Foo Bar}`.
+    MalformedInlineTag { message: String },
+
     /// A doc comment contains an incorrect tag. Either:
     /// - The tag itself is incorrect. Ex: using `@throws` on an element that can't or doesn't throw an exception.
     /// - The tag describes something incorrect. Ex: specifying `@param foo` when no parameter named "foo" exists.
@@ -34,6 +56,91 @@ pub enum Lint {
     /// - The link pointed to an un-linkable element, e.g. a module, result, sequence, dictionary, or primitive.
     /// - The link pointed to a non-existent element.
     BrokenDocLink { message: String },
+
+    /// A doc comment contains markup (like an HTML tag) which can't be represented in a backend's target doc format.
+    /// See [DocTextPolicy](crate::utils::doc_comment_util::DocTextPolicy).
+    UnsupportedDocMarkup {
+        /// The unsupported markup, formatted as a comma separated list.
+        markup: String,
+    },
+
+    /// A redundant separator was used, which isn't part of Slice's canonical style. Either:
+    /// - A semicolon was placed after a definition or member.
+    /// - A trailing comma was placed after the last element of a comma separated list.
+    RedundantSeparator {
+        /// The separator that was used.
+        separator: String,
+    },
+
+    /// The tags of a type's tagged members skip over some tags, which haven't been reserved with the `reservedTags`
+    /// attribute. This is only reported when `--audit-tags` is set.
+    UnreservedTagGap {
+        /// The skipped tags, formatted as a range. Ex: `2..=4`.
+        tags: String,
+    },
+
+    /// A type, exception, or constant in a source file is never used.
+    /// This is only reported when `--report-unused` is set.
+    UnusedDefinition {
+        /// The kind of the unused definition. Ex: `struct`.
+        kind: String,
+
+        /// The identifier of the unused definition.
+        identifier: String,
+    },
+
+    /// An identifier collides with a keyword of a language that code is generated for, so it will be escaped in the
+    /// generated code. See [TargetKeywords](crate::slice_options::TargetKeywords).
+    KeywordCollision {
+        /// The identifier that collides with a keyword.
+        identifier: String,
+
+        /// The language that the identifier is a keyword in. Ex: `C#`.
+        language: String,
+    },
+
+    /// An identifier doesn't follow the naming convention configured for its kind of definition.
+    /// See [NamingConvention](crate::slice_options::NamingConvention).
+    NamingConvention {
+        /// The kind of definition the identifier belongs to. Ex: `operation`.
+        kind: String,
+
+        /// The identifier that doesn't follow the convention.
+        identifier: String,
+
+        /// The case style that the identifier should be written in. Ex: `camelCase`.
+        style: String,
+    },
+
+    /// An interface has multiple operations (declared or inherited) whose names only differ by case.
+    /// These can collide in the generated code of languages whose case style differs from Slice's.
+    OperationCaseCollision {
+        /// The identifier of the operation.
+        identifier: String,
+
+        /// The identifier of the operation it collides with.
+        other_identifier: String,
+    },
+
+    /// An identifier looks like another identifier, but is made up of different characters.
+    /// Ex: 'Foo' and 'Fоo', where the second uses a Cyrillic 'о'.
+    ConfusableIdentifier {
+        /// The identifier that is confusable.
+        identifier: String,
+
+        /// The identifier it can be confused with.
+        other_identifier: String,
+    },
+
+    /// A lint reported by a third-party validator (see [ValidatorPlugin](crate::slice_options::ValidatorPlugin)).
+    /// Custom lints can be allowed and denied like any other lint, by their code. Since their codes contain '::', they
+    /// must be quoted when used as attribute arguments. Ex: `[allow("myorg::NamingConvention")]`.
+    Custom {
+        /// The code of this lint. This must be namespaced to avoid collisions. Ex: `myorg::NamingConvention`.
+        code: String,
+        /// The message to report.
+        message: String,
+    },
 }
 
 impl Lint {
@@ -41,12 +148,33 @@ impl Lint {
     pub fn get_default_level(&self) -> DiagnosticLevel {
         match self {
             Self::DuplicateFile { .. } => DiagnosticLevel::Warning,
+            Self::SymlinkCycle { .. } => DiagnosticLevel::Warning,
+            Self::IgnoredPath { .. } => DiagnosticLevel::Warning,
             Self::Deprecated { .. } => DiagnosticLevel::Warning,
             Self::MalformedDocComment { .. } => DiagnosticLevel::Warning,
+            Self::MalformedInlineTag { .. } => DiagnosticLevel::Warning,
             Self::BrokenDocLink { .. } => DiagnosticLevel::Warning,
+            Self::UnsupportedDocMarkup { .. } => DiagnosticLevel::Warning,
             Self::IncorrectDocComment { .. } => DiagnosticLevel::Warning,
+            Self::RedundantSeparator { .. } => DiagnosticLevel::Warning,
+            Self::UnreservedTagGap { .. } => DiagnosticLevel::Warning,
+            Self::UnusedDefinition { .. } => DiagnosticLevel::Warning,
+            Self::KeywordCollision { .. } => DiagnosticLevel::Warning,
+            Self::NamingConvention { .. } => DiagnosticLevel::Warning,
+            Self::OperationCaseCollision { .. } => DiagnosticLevel::Warning,
+            Self::ConfusableIdentifier { .. } => DiagnosticLevel::Warning,
+            Self::Custom { .. } => DiagnosticLevel::Warning,
         }
     }
+
+    /// Returns true if lints can be allowed or denied with the provided identifier. This is either one of the
+    /// [ALLOWABLE_LINT_IDENTIFIERS](Self::ALLOWABLE_LINT_IDENTIFIERS), or the namespaced code of a custom lint.
+    pub fn is_allowable_identifier(identifier: &str) -> bool {
+        let is_custom = identifier
+            .split_once("::")
+            .is_some_and(|(namespace, name)| !namespace.is_empty() && !name.is_empty());
+        is_custom || Self::ALLOWABLE_LINT_IDENTIFIERS.contains(&identifier)
+    }
 }
 
 implement_diagnostic_functions!(
@@ -56,6 +184,17 @@ implement_diagnostic_functions!(
         format!("slice file was provided more than once: '{path}'"),
         path
     ),
+    (
+        SymlinkCycle,
+        format!("skipping '{path}', since it links to one of its own parent directories"),
+        path
+    ),
+    (
+        IgnoredPath,
+        format!("skipping '{path}', since it's ignored by '{ignore_file}'"),
+        path,
+        ignore_file
+    ),
     (
         Deprecated,
         if let Some(reason) = reason {
@@ -67,6 +206,53 @@ implement_diagnostic_functions!(
         reason
     ),
     (MalformedDocComment, message, message),
+    (MalformedInlineTag, message, message),
     (IncorrectDocComment, message, message),
-    (BrokenDocLink, message, message)
+    (BrokenDocLink, message, message),
+    (
+        UnsupportedDocMarkup,
+        format!("doc comment contains markup which can't be represented in the target doc format: {markup}"),
+        markup
+    ),
+    (
+        RedundantSeparator,
+        format!("redundant '{separator}' can be removed"),
+        separator
+    ),
+    (
+        UnreservedTagGap,
+        format!("tags '{tags}' are skipped, but aren't reserved; reserve them with the 'reservedTags' attribute"),
+        tags
+    ),
+    (
+        UnusedDefinition,
+        format!("{kind} '{identifier}' is never used"),
+        kind,
+        identifier
+    ),
+    (
+        KeywordCollision,
+        format!("'{identifier}' is a keyword in {language}, so it will be escaped in the generated code"),
+        identifier,
+        language
+    ),
+    (
+        NamingConvention,
+        format!("{kind} '{identifier}' should be written in {style}"),
+        kind,
+        identifier,
+        style
+    ),
+    (
+        OperationCaseCollision,
+        format!("operations '{identifier}' and '{other_identifier}' only differ by case, and may collide in generated code"),
+        identifier,
+        other_identifier
+    ),
+    (
+        ConfusableIdentifier,
+        format!("identifier '{identifier}' can be confused with '{other_identifier}'"),
+        identifier,
+        other_identifier
+    )
 );
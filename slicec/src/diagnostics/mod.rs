@@ -5,11 +5,14 @@ use serde::Serialize;
 
 mod diagnostic;
 mod errors;
+pub mod examples;
 mod lints;
+mod renderer;
 
 pub use diagnostic::*;
 pub use errors::Error;
 pub use lints::Lint;
+pub use renderer::render_diagnostic;
 
 /// Stores additional information about a diagnostic.
 #[derive(Serialize, Debug, Clone)]
@@ -18,20 +21,37 @@ pub struct Note {
     pub span: Option<Span>,
 }
 
+/// An edit that resolves a diagnostic, by replacing the text covered by `span` with `replacement`.
+/// An empty replacement removes the spanned text entirely.
+#[derive(Serialize, Debug, Clone)]
+pub struct Suggestion {
+    pub message: String,
+    pub span: Span,
+    pub replacement: String,
+    /// Whether this edit is always correct on its own, and so can be applied automatically (by `--fix`).
+    /// Suggestions which require other changes to be made alongside them (Ex: renaming a definition without updating
+    /// its uses) aren't machine applicable.
+    pub is_machine_applicable: bool,
+}
+
 /// A macro that implements the `code` and `message` functions for [Lint] and [Error] enums.
 #[macro_export]
 macro_rules! implement_diagnostic_functions {
     (Lint, $(($kind:ident, $message:expr $(, $variant:ident)* )),*) => {
         impl Lint {
             // TODO maybe we should move this somewhere other than `Lint`? Like in `Attribute` maybe?
-            /// This array contains all the valid arguments for the 'allow' attribute.
-            pub const ALLOWABLE_LINT_IDENTIFIERS: [&'static str; 6] = [
+            /// This array contains all the valid arguments for the 'allow' and 'deny' attributes.
+            pub const ALLOWABLE_LINT_IDENTIFIERS: [&'static str; 17] = [
                 "All",
                 $(stringify!($kind)),*
             ];
 
+            /// This array contains the codes of all the lints that slicec can report, in the order they're declared.
+            pub const ALL_CODES: &'static [&'static str] = &[$(stringify!($kind)),*];
+
             pub fn code(&self) -> &str {
                 match self {
+                    Lint::Custom { code, .. } => code,
                     $(
                         implement_diagnostic_functions!(@error Lint::$kind, $($variant),*) => stringify!($kind),
                     )*
@@ -40,6 +60,7 @@ macro_rules! implement_diagnostic_functions {
 
             pub fn message(&self) -> String {
                 match self {
+                    Lint::Custom { message, .. } => message.clone(),
                     $(
                         implement_diagnostic_functions!(@description Lint::$kind, $($variant),*) => $message.into(),
                     )*
@@ -50,8 +71,12 @@ macro_rules! implement_diagnostic_functions {
 
     (Error, $(($code:literal, $kind:ident, $message:expr $(, $variant:ident)* )),*) => {
         impl Error {
+            /// This array contains the codes of all the errors that slicec can report, in the order they're declared.
+            pub const ALL_CODES: &'static [&'static str] = &[$($code),*];
+
             pub fn code(&self) -> &str {
                 match self {
+                    Error::DeniedTypeUsed { policy_code: Some(code), .. } => code,
                     $(
                         implement_diagnostic_functions!(@error Error::$kind, $($variant),*) => $code,
                     )*
@@ -81,6 +106,6 @@ macro_rules! implement_diagnostic_functions {
     };
 
     (@description $kind:path, $($variant:ident),+) => {
-        $kind{$($variant),*}
+        $kind{$($variant),*, ..}
     };
 }
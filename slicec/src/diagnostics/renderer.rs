@@ -0,0 +1,216 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::{Diagnostic, DiagnosticLevel, Note, Suggestion};
+use crate::slice_file::{SliceFile, Span};
+use console::{style, Style};
+use std::fmt::Write;
+
+/// The number of columns a tab is expanded to when rendering source lines.
+const TAB_WIDTH: usize = 4;
+
+/// Renders the provided diagnostic in the same style as `rustc`: a colored severity label and message, followed by the
+/// offending source lines, with the diagnostic's span underlined with carets ('^').
+///
+/// Notes with spans in the same file as the diagnostic are rendered inline, underlined with dashes ('-') and labeled
+/// with their message. Notes with spans in other files are rendered separately with their own snippets, and notes
+/// without spans are listed beneath the snippet. Suggestions are rendered last, showing the source with their edits
+/// applied. Allowed diagnostics render as an empty string.
+pub fn render_diagnostic(diagnostic: &Diagnostic, files: &[SliceFile]) -> String {
+    let (label, level_style) = match diagnostic.level() {
+        DiagnosticLevel::Error => ("error", Style::new().red().bold()),
+        DiagnosticLevel::Warning => ("warning", Style::new().yellow().bold()),
+        DiagnosticLevel::Allowed => return String::new(),
+    };
+
+    // Sort the notes by how they should be rendered.
+    let span = diagnostic.span();
+    let (mut inline_notes, mut separate_notes, mut plain_notes) = (Vec::new(), Vec::new(), Vec::new());
+    for note in diagnostic.notes() {
+        match &note.span {
+            Some(note_span) if span.is_some_and(|s| s.file == note_span.file) => inline_notes.push(note),
+            Some(_) => separate_notes.push(note),
+            None => plain_notes.push(note),
+        }
+    }
+
+    // Use the same gutter width for every snippet, so that they all line up.
+    let spans = span
+        .into_iter()
+        .chain(diagnostic.notes().iter().filter_map(|note| note.span.as_ref()))
+        .chain(diagnostic.suggestions().iter().map(|suggestion| &suggestion.span));
+    let gutter_width = spans.map(|span| span.end.row.to_string().len()).max().unwrap_or(0);
+    let gutter = " ".repeat(gutter_width);
+    let separator = style("|").blue().bold();
+
+    let code = diagnostic.code();
+    let mut output = String::new();
+    let header = level_style.apply_to(format!("{label}[{code}]"));
+    writeln!(output, "{header}: {}", style(diagnostic.message()).bold()).unwrap();
+
+    if let Some(span) = span {
+        let mut annotations = vec![Annotation::primary(span, level_style.clone())];
+        annotations.extend(inline_notes.into_iter().map(Annotation::secondary));
+        render_snippet(&mut output, span, annotations, files, gutter_width);
+    }
+
+    if !plain_notes.is_empty() {
+        if span.is_some() {
+            writeln!(output, "{gutter} {separator}").unwrap();
+        }
+        for note in plain_notes {
+            writeln!(output, "{gutter} {} note: {}", style("=").blue().bold(), note.message).unwrap();
+        }
+    }
+
+    for note in separate_notes {
+        let span = note.span.as_ref().unwrap();
+        writeln!(output, "{}: {}", style("note").blue().bold(), style(&note.message).bold()).unwrap();
+        render_snippet(&mut output, span, vec![Annotation::secondary(note)], files, gutter_width);
+    }
+
+    for suggestion in diagnostic.suggestions() {
+        render_suggestion(&mut output, suggestion, files, gutter_width);
+    }
+
+    output
+}
+
+/// An underlined span within a snippet, with an optional label.
+struct Annotation<'a> {
+    span: &'a Span,
+    label: Option<&'a str>,
+    marker: char,
+    style: Style,
+}
+
+impl<'a> Annotation<'a> {
+    fn primary(span: &'a Span, style: Style) -> Self {
+        Annotation {
+            span,
+            label: None,
+            marker: '^',
+            style,
+        }
+    }
+
+    fn secondary(note: &'a Note) -> Self {
+        Annotation {
+            span: note.span.as_ref().unwrap(),
+            label: Some(&note.message),
+            marker: '-',
+            style: Style::new().blue().bold(),
+        }
+    }
+}
+
+/// Renders the location of `span`, followed by the source lines covered by the provided annotations.
+fn render_snippet(output: &mut String, span: &Span, annotations: Vec<Annotation>, files: &[SliceFile], width: usize) {
+    let gutter = " ".repeat(width);
+    let separator = style("|").blue().bold();
+    let location = format!("{}:{}:{}", span.file, span.start.row, span.start.col);
+    writeln!(output, "{gutter}{} {location}", style("-->").blue().bold()).unwrap();
+
+    // If the file isn't available, we can only render the location.
+    let Some(file) = files.iter().find(|file| file.relative_path == span.file) else {
+        return;
+    };
+    let lines = file.raw_text.lines().collect::<Vec<_>>();
+
+    let mut rows = annotations
+        .iter()
+        .flat_map(|annotation| annotation.span.start.row..=annotation.span.end.row)
+        .filter(|row| *row >= 1 && *row <= lines.len())
+        .collect::<Vec<_>>();
+    rows.sort_unstable();
+    rows.dedup();
+
+    writeln!(output, "{gutter} {separator}").unwrap();
+    let mut previous_row = None;
+    for row in rows {
+        // Elide any lines between annotations which aren't adjacent.
+        if previous_row.is_some_and(|previous| row > previous + 1) {
+            writeln!(output, "{}", style("...").blue().bold()).unwrap();
+        }
+        previous_row = Some(row);
+
+        let line = lines[row - 1];
+        let line_number = style(format!("{row:<width$}")).blue().bold();
+        writeln!(output, "{line_number} {separator} {}", line.replace('\t', &" ".repeat(TAB_WIDTH))).unwrap();
+
+        // Underline each annotation which covers this line, in the order they appear in the line.
+        let mut underlines = annotations
+            .iter()
+            .filter(|annotation| (annotation.span.start.row..=annotation.span.end.row).contains(&row))
+            .map(|annotation| (underline_range(line, row, annotation.span), annotation))
+            .collect::<Vec<_>>();
+        underlines.sort_by_key(|((start, _), _)| *start);
+
+        for ((start, end), annotation) in underlines {
+            let markers = annotation.marker.to_string().repeat(end - start);
+            let mut underline = annotation.style.apply_to(markers).to_string();
+            if let Some(label) = annotation.label.filter(|_| row == annotation.span.end.row) {
+                underline = format!("{underline} {}", annotation.style.apply_to(label));
+            }
+            writeln!(output, "{gutter} {separator} {}{underline}", " ".repeat(start)).unwrap();
+        }
+    }
+}
+
+/// Renders the line affected by `suggestion` with its edit applied, and any inserted text underlined with tildes ('~').
+/// Suggestions spanning multiple lines (or in unavailable files) are only listed by their message.
+fn render_suggestion(output: &mut String, suggestion: &Suggestion, files: &[SliceFile], width: usize) {
+    let gutter = " ".repeat(width);
+    let separator = style("|").blue().bold();
+    let help = style("help").cyan().bold();
+
+    let span = &suggestion.span;
+    let file = files.iter().find(|file| file.relative_path == span.file);
+    let line = file.and_then(|file| file.raw_text.lines().nth(span.start.row.wrapping_sub(1)));
+    let Some(line) = line.filter(|_| span.start.row == span.end.row) else {
+        writeln!(output, "{gutter} {} {help}: {}", style("=").blue().bold(), suggestion.message).unwrap();
+        return;
+    };
+
+    // Apply the suggested edit to the line. Spans can extend past the end of a line, so the indices are clamped.
+    let chars = line.chars().collect::<Vec<_>>();
+    let start = span.start.col.saturating_sub(1).min(chars.len());
+    let end = span.end.col.saturating_sub(1).clamp(start, chars.len());
+    let before = chars[..start].iter().collect::<String>();
+    let after = chars[end..].iter().collect::<String>();
+    let edited = format!("{before}{}{after}", suggestion.replacement);
+
+    writeln!(output, "{help}: {}", style(&suggestion.message).bold()).unwrap();
+    writeln!(output, "{gutter} {separator}").unwrap();
+    let line_number = style(format!("{:<width$}", span.start.row)).blue().bold();
+    writeln!(output, "{line_number} {separator} {}", edited.replace('\t', &" ".repeat(TAB_WIDTH))).unwrap();
+
+    if !suggestion.replacement.is_empty() {
+        let offset = before.chars().map(|c| if c == '\t' { TAB_WIDTH } else { 1 }).sum::<usize>();
+        let tildes = style("~".repeat(suggestion.replacement.chars().count())).cyan().bold();
+        writeln!(output, "{gutter} {separator} {}{tildes}", " ".repeat(offset)).unwrap();
+    }
+}
+
+/// Returns the (visual) columns that should be underlined on the provided line to highlight `span`.
+/// The returned range is half-open, and always contains at least one column.
+fn underline_range(line: &str, row: usize, span: &Span) -> (usize, usize) {
+    let chars = line.chars().collect::<Vec<_>>();
+
+    // Lines after the first are underlined starting from their first non-whitespace character.
+    let start = match row == span.start.row {
+        true => span.start.col.saturating_sub(1),
+        false => chars.iter().take_while(|c| c.is_whitespace()).count(),
+    };
+    let end = match row == span.end.row {
+        true => span.end.col.saturating_sub(1),
+        false => chars.len(),
+    };
+
+    // Spans can extend past the end of a line (Ex: errors at the end of a file), so indices aren't clamped.
+    let visual_column = |index: usize| -> usize {
+        let tabs = chars.iter().take(index).filter(|c| **c == '\t').count();
+        index + tabs * (TAB_WIDTH - 1)
+    };
+    let (start, end) = (visual_column(start), visual_column(end.max(start)));
+    (start, end.max(start + 1))
+}
@@ -0,0 +1,285 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains the logic for generating diagrams of the relationships between Slice definitions, which can be
+//! rendered by Graphviz or Mermaid (see [DiagramFormat]).
+//!
+//! Each struct, class, exception, interface, enum, and custom type is drawn as a node, grouped by module. Nodes are
+//! connected by three kinds of relationships:
+//! - inheritance: from a class, exception, or interface to its bases.
+//! - containment: from a struct, class, exception, or enum to the types of its fields, labeled with their names.
+//! - usage: from an interface to the types its operations use or throw, labeled with the operations' names.
+//!
+//! Type aliases are resolved to their underlying types, and sequences, dictionaries, and results to their element
+//! types.
+//!
+//! Diagrams include the definitions of the source files which are in scope, and the definitions that they're related
+//! to, up to a maximum depth. So with a depth of `0`, only the definitions in scope are drawn.
+
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostic, Error};
+use crate::grammar::*;
+use crate::slice_file::SliceFile;
+use crate::slice_options::{DiagramFormat, SliceOptions};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fs;
+
+/// Returns a diagram of the relationships between the definitions in `files`, in the provided format.
+///
+/// If `scope` is set, only definitions in that module (or its submodules) are drawn, along with the definitions
+/// they're related to. If `depth` is set, related definitions are only drawn if they're at most that many
+/// relationships away from a definition in scope. Otherwise, every related definition is drawn.
+pub fn generate_diagram(
+    files: &[SliceFile],
+    format: DiagramFormat,
+    scope: Option<&str>,
+    depth: Option<usize>,
+) -> String {
+    let scope = scope.map(|scope| scope.trim_start_matches("::"));
+    let is_in_scope = |module: &str| {
+        scope.is_none_or(|scope| module == scope || module.strip_prefix(scope).is_some_and(|m| m.starts_with("::")))
+    };
+
+    // Collect every node and relationship, and which nodes are the roots of the diagram.
+    let mut nodes = BTreeMap::new();
+    let mut collector = RelationshipCollector::default();
+    let mut pending = VecDeque::new();
+    for file in files {
+        for definition in &file.contents {
+            let entity = definition.borrow();
+            let kind = match definition {
+                Definition::Struct(ptr) => {
+                    collector.add_fields(entity, ptr.borrow().fields());
+                    "struct"
+                }
+                Definition::Class(ptr) => {
+                    let class_def = ptr.borrow();
+                    collector.add_bases(entity, class_def.base.iter());
+                    collector.add_fields(entity, class_def.fields());
+                    "class"
+                }
+                Definition::Exception(ptr) => {
+                    let exception_def = ptr.borrow();
+                    collector.add_bases(entity, exception_def.base.iter());
+                    collector.add_fields(entity, exception_def.fields());
+                    "exception"
+                }
+                Definition::Interface(ptr) => {
+                    let interface_def = ptr.borrow();
+                    collector.add_bases(entity, interface_def.bases.iter());
+                    collector.add_operations(entity, interface_def.operations());
+                    "interface"
+                }
+                Definition::Enum(ptr) => {
+                    let enumerators = ptr.borrow().enumerators();
+                    collector.add_fields(entity, enumerators.into_iter().flat_map(|e| e.fields()).collect());
+                    "enum"
+                }
+                Definition::CustomType(_) => "custom",
+                Definition::TypeAlias(_) | Definition::Constant(_) => continue,
+            };
+
+            let id = entity.parser_scoped_identifier();
+            if file.is_source && is_in_scope(entity.module_scope()) {
+                pending.push_back((id.clone(), 0));
+            }
+            nodes.insert(id, (kind, entity));
+        }
+    }
+
+    // Walk outwards from the roots, to find which nodes are close enough to them to be drawn.
+    let mut adjacent = HashMap::<&str, Vec<&str>>::new();
+    for (from, to, _) in collector.relationships.keys() {
+        adjacent.entry(from).or_default().push(to);
+    }
+    let mut drawn = pending.iter().map(|(id, _)| id.clone()).collect::<HashSet<_>>();
+    while let Some((id, distance)) = pending.pop_front() {
+        if depth.is_some_and(|depth| distance >= depth) {
+            continue;
+        }
+        for &next in adjacent.get(id.as_str()).into_iter().flatten() {
+            if nodes.contains_key(next) && drawn.insert(next.to_owned()) {
+                pending.push_back((next.to_owned(), distance + 1));
+            }
+        }
+    }
+
+    // Group the drawn nodes by module, and only keep the relationships between drawn nodes.
+    let mut modules = Modules::new();
+    for (id, (kind, entity)) in &nodes {
+        if drawn.contains(id) {
+            modules.entry(entity.module_scope()).or_default().push((kind, *entity));
+        }
+    }
+    let relationships = collector
+        .relationships
+        .iter()
+        .filter(|((from, to, _), _)| drawn.contains(from) && drawn.contains(to))
+        .map(|((from, to, relationship), labels)| (from.as_str(), to.as_str(), *relationship, labels.join(", ")))
+        .collect::<Vec<_>>();
+
+    match format {
+        DiagramFormat::Dot => format_dot(&modules, &relationships),
+        DiagramFormat::Mermaid => format_mermaid(&modules, &relationships),
+    }
+}
+
+/// Writes a diagram of the source files in `state` to the file specified by `options.diagram`, if any.
+/// Nothing is written if `--dry-run` was set.
+pub(crate) fn write_diagram(state: &mut CompilationState, options: &SliceOptions) {
+    let Some(path) = options.diagram.as_ref().filter(|_| !options.dry_run) else {
+        return;
+    };
+
+    let scope = options.diagram_scope.as_deref();
+    let text = generate_diagram(&state.files, options.diagram_format, scope, options.diagram_depth);
+    if let Err(error) = fs::write(path, text) {
+        Diagnostic::new(Error::IO {
+            action: "write",
+            path: path.clone(),
+            error,
+        })
+        .push_into(&mut state.diagnostics);
+    }
+}
+
+/// The nodes to draw, grouped by module. Each node is stored as its kind, and the definition it represents.
+type Modules<'a> = BTreeMap<&'a str, Vec<(&'a str, &'a dyn Entity)>>;
+
+/// A relationship to draw, stored as the parser-scoped identifiers of the definitions it connects, its kind, and its
+/// label.
+type Relationship<'a> = (&'a str, &'a str, RelationshipKind, String);
+
+/// Formats the provided nodes and relationships as a Graphviz (DOT) directed graph, with a cluster for each module.
+fn format_dot(modules: &Modules, relationships: &[Relationship]) -> String {
+    let mut text = "digraph Slice {\n    node [shape=box];\n".to_owned();
+    for (module, nodes) in modules {
+        text += &format!("\n    subgraph \"cluster_{module}\" {{\n        label=\"{module}\";\n");
+        for (kind, entity) in nodes {
+            let (id, identifier) = (entity.parser_scoped_identifier(), entity.identifier());
+            text += &format!("        \"{id}\" [label=\"<<{kind}>>\\n{identifier}\"];\n");
+        }
+        text += "    }\n";
+    }
+
+    if !relationships.is_empty() {
+        text.push('\n');
+    }
+    for (from, to, kind, label) in relationships {
+        let attributes = match kind {
+            RelationshipKind::Inherits => "arrowhead=empty".to_owned(),
+            RelationshipKind::Contains => format!("dir=back, arrowtail=diamond, label=\"{label}\""),
+            RelationshipKind::Uses => format!("style=dashed, arrowhead=open, label=\"{label}\""),
+        };
+        text += &format!("    \"{from}\" -> \"{to}\" [{attributes}];\n");
+    }
+    text + "}\n"
+}
+
+/// Formats the provided nodes and relationships as a Mermaid class diagram, with a namespace for each module.
+fn format_mermaid(modules: &Modules, relationships: &[Relationship]) -> String {
+    // Mermaid identifiers can only contain alphanumeric characters and underscores, so we replace each `::` with `__`.
+    let mermaid_id = |id: &str| id.replace("::", "__");
+
+    let mut text = "classDiagram\n".to_owned();
+    let mut annotations = String::new();
+    for (module, nodes) in modules {
+        text += &format!("    namespace {} {{\n", mermaid_id(module));
+        for (kind, entity) in nodes {
+            let id = mermaid_id(&entity.parser_scoped_identifier());
+            text += &format!("        class {id}[\"{}\"]\n", entity.identifier());
+            annotations += &format!("    <<{kind}>> {id}\n");
+        }
+        text += "    }\n";
+    }
+    text += &annotations;
+
+    for (from, to, kind, label) in relationships {
+        let (from, to) = (mermaid_id(from), mermaid_id(to));
+        text += &match kind {
+            RelationshipKind::Inherits => format!("    {to} <|-- {from}\n"),
+            RelationshipKind::Contains => format!("    {from} *-- {to} : {label}\n"),
+            RelationshipKind::Uses => format!("    {from} ..> {to} : {label}\n"),
+        };
+    }
+    text
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum RelationshipKind {
+    Inherits,
+    Contains,
+    Uses,
+}
+
+/// Collects the relationships between definitions, keyed by the parser-scoped identifiers of the definitions they
+/// connect. Each relationship stores the names of the members that created it, in the order they were declared.
+#[derive(Default)]
+struct RelationshipCollector {
+    relationships: BTreeMap<(String, String, RelationshipKind), Vec<String>>,
+}
+
+impl RelationshipCollector {
+    fn add(&mut self, from: &dyn Entity, to: &dyn Entity, kind: RelationshipKind, label: Option<&str>) {
+        let key = (from.parser_scoped_identifier(), to.parser_scoped_identifier(), kind);
+        let labels = self.relationships.entry(key).or_default();
+        if let Some(label) = label.filter(|label| !labels.iter().any(|l| l == label)) {
+            labels.push(label.to_owned());
+        }
+    }
+
+    fn add_bases<'a, T: Entity + 'a>(&mut self, from: &dyn Entity, bases: impl Iterator<Item = &'a TypeRef<T>>) {
+        for base in bases {
+            if let TypeRefDefinition::Patched(_) = &base.definition {
+                self.add(from, base.definition(), RelationshipKind::Inherits, None);
+            }
+        }
+    }
+
+    fn add_fields(&mut self, from: &dyn Entity, fields: Vec<&Field>) {
+        for field in fields {
+            self.add_type_ref(from, &field.data_type, RelationshipKind::Contains, field.identifier());
+        }
+    }
+
+    fn add_operations(&mut self, from: &dyn Entity, operations: Vec<&Operation>) {
+        for operation in operations {
+            let label = operation.identifier();
+            for parameter in operation.parameters_and_return_members() {
+                self.add_type_ref(from, &parameter.data_type, RelationshipKind::Uses, label);
+            }
+            for thrown_type in operation.thrown_types() {
+                match thrown_type {
+                    ThrownType::Exception(exception_ref) => {
+                        if let TypeRefDefinition::Patched(_) = &exception_ref.definition {
+                            self.add(from, exception_ref.definition(), RelationshipKind::Uses, Some(label));
+                        }
+                    }
+                    ThrownType::Error(type_ref) => self.add_type_ref(from, type_ref, RelationshipKind::Uses, label),
+                }
+            }
+        }
+    }
+
+    fn add_type_ref(&mut self, from: &dyn Entity, type_ref: &TypeRef, kind: RelationshipKind, label: &str) {
+        let TypeRefDefinition::Patched(_) = &type_ref.definition else {
+            return;
+        };
+
+        match type_ref.definition().concrete_type() {
+            Types::Struct(struct_def) => self.add(from, struct_def, kind, Some(label)),
+            Types::Class(class_def) => self.add(from, class_def, kind, Some(label)),
+            Types::Enum(enum_def) => self.add(from, enum_def, kind, Some(label)),
+            Types::CustomType(custom_type) => self.add(from, custom_type, kind, Some(label)),
+            Types::Sequence(sequence) => self.add_type_ref(from, &sequence.element_type, kind, label),
+            Types::Dictionary(dictionary) => {
+                self.add_type_ref(from, &dictionary.key_type, kind, label);
+                self.add_type_ref(from, &dictionary.value_type, kind, label);
+            }
+            Types::ResultType(result_type) => {
+                self.add_type_ref(from, &result_type.success_type, kind, label);
+                self.add_type_ref(from, &result_type.failure_type, kind, label);
+            }
+            Types::Primitive(_) => {}
+        }
+    }
+}
@@ -0,0 +1,513 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module generates reference documentation from the definitions and doc comments of the source files.
+//!
+//! Documentation is generated as a static site (see [DocFormat] for the supported formats), with an index page listing
+//! every module, and one page per module. Each module page documents its public definitions, grouped by kind:
+//! - Every definition has its declaration, a deprecation badge (if it's deprecated), and its doc comment's overview,
+//!   along with any `@see` tags.
+//! - Structs, classes, and exceptions have a table of their fields, and enums a table of their enumerators.
+//! - Interfaces have a table of their operations, with their parameters, return types, and thrown types.
+//!
+//! References to other documented definitions (in types, and in `{@link}` and `@see` tags) are cross-linked.
+//! Internal definitions, and definitions from reference files, aren't documented; references to them are left unlinked.
+
+mod renderer;
+
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostic, Error};
+use crate::generated_file::write_if_changed;
+use crate::grammar::attributes::Deprecated;
+use crate::grammar::*;
+use crate::slice_file::SliceFile;
+use crate::slice_options::{DocFormat, SliceOptions};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+/// A page of generated documentation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocPage {
+    /// The path of the page, relative to the documentation's root directory. Ex: `Acme.Widgets.md`.
+    pub path: String,
+    /// The contents of the page.
+    pub contents: String,
+}
+
+/// Returns the documentation pages for the source files in `files`, in the provided format.
+/// The index page is always first, followed by the module pages, sorted by module.
+pub fn generate_documentation(files: &[SliceFile], format: DocFormat) -> Vec<DocPage> {
+    // Group the documented definitions by module, and then by kind.
+    let mut modules = BTreeMap::<&str, Vec<&Definition>>::new();
+    for file in files.iter().filter(|file| file.is_source) {
+        for definition in file.contents.iter().filter(|definition| is_public(definition)) {
+            modules.entry(definition.borrow().module_scope()).or_default().push(definition);
+        }
+    }
+    let documented = modules.values().flatten().map(|d| d.borrow().parser_scoped_identifier()).collect();
+    let generator = DocGenerator { format, documented };
+
+    let mut pages = vec![generator.index_page(modules.keys().copied())];
+    for (module, definitions) in &modules {
+        pages.push(generator.module_page(module, definitions));
+    }
+    pages
+}
+
+/// Writes documentation for the source files in `state` to the directory specified by `options.doc_dir`, if any.
+/// Pages are only written if their contents changed, so the timestamps of unchanged pages are preserved.
+/// Nothing is written if `--dry-run` was set.
+pub(crate) fn write_documentation(state: &mut CompilationState, options: &SliceOptions) {
+    let Some(doc_dir) = options.doc_dir.as_ref().filter(|_| !options.dry_run) else {
+        return;
+    };
+
+    if let Err(error) = fs::create_dir_all(doc_dir) {
+        Diagnostic::new(Error::IO {
+            action: "create",
+            path: doc_dir.clone(),
+            error,
+        })
+        .push_into(&mut state.diagnostics);
+        return;
+    }
+
+    for page in generate_documentation(&state.files, options.doc_format) {
+        let path = Path::new(doc_dir).join(&page.path);
+        if let Err(error) = write_if_changed(&path, &page.contents) {
+            Diagnostic::new(Error::IO {
+                action: "write",
+                path: path.display().to_string(),
+                error,
+            })
+            .push_into(&mut state.diagnostics);
+        }
+    }
+}
+
+/// A block of content on a page.
+#[derive(Debug)]
+enum Block {
+    /// A heading, with an optional anchor that links can target.
+    Heading(usize, Option<String>, String),
+    Paragraph(Vec<Inline>),
+    Table(Vec<&'static str>, Vec<Vec<Vec<Inline>>>),
+}
+
+/// A span of content within a block.
+#[derive(Debug)]
+enum Inline {
+    Text(String),
+    Code(String),
+    /// A link, whose text is rendered as code.
+    Link(String, String),
+    Badge(&'static str),
+}
+
+struct DocGenerator {
+    format: DocFormat,
+    /// The parser-scoped identifiers of the definitions which have documentation that can be linked to.
+    documented: HashSet<String>,
+}
+
+impl DocGenerator {
+    fn index_page<'a>(&self, modules: impl Iterator<Item = &'a str>) -> DocPage {
+        let mut blocks = vec![Block::Heading(1, None, "API Reference".to_owned())];
+        let rows = modules.map(|module| vec![vec![Inline::Link(module.to_owned(), self.page_path(module))]]);
+        blocks.push(Block::Table(vec!["Module"], rows.collect()));
+        self.page(self.index_path(), "API Reference", &blocks)
+    }
+
+    fn module_page(&self, module: &str, definitions: &[&Definition]) -> DocPage {
+        let index_link = Inline::Link("API Reference".to_owned(), self.index_path());
+        let mut blocks = vec![
+            Block::Paragraph(vec![index_link]),
+            Block::Heading(1, None, format!("Module {module}")),
+        ];
+
+        for (index, title) in SECTIONS.into_iter().enumerate() {
+            let mut section = definitions.iter().filter(|d| section_of(d) == index).collect::<Vec<_>>();
+            if section.is_empty() {
+                continue;
+            }
+            section.sort_by_key(|definition| definition.borrow().identifier());
+
+            blocks.push(Block::Heading(2, None, title.to_owned()));
+            for definition in section {
+                self.document_definition(definition, &mut blocks);
+            }
+        }
+        self.page(self.page_path(module), &format!("Module {module}"), &blocks)
+    }
+
+    fn document_definition(&self, definition: &Definition, blocks: &mut Vec<Block>) {
+        let entity = definition.borrow();
+        let identifier = entity.identifier();
+        blocks.push(Block::Heading(3, Some(anchor_for(identifier)), identifier.to_owned()));
+        blocks.push(Block::Paragraph(self.declaration(definition)));
+
+        if let Some(deprecated) = entity.find_attribute::<Deprecated>() {
+            let mut inlines = vec![Inline::Badge("Deprecated")];
+            inlines.extend(deprecated.reason.iter().map(|reason| Inline::Text(format!(" {reason}"))));
+            blocks.push(Block::Paragraph(inlines));
+        }
+
+        let comment = match definition {
+            Definition::Struct(ptr) => ptr.borrow().effective_comment(),
+            Definition::Class(ptr) => ptr.borrow().effective_comment(),
+            Definition::Exception(ptr) => ptr.borrow().effective_comment(),
+            Definition::Interface(ptr) => ptr.borrow().effective_comment(),
+            Definition::Enum(ptr) => ptr.borrow().effective_comment(),
+            Definition::CustomType(ptr) => ptr.borrow().effective_comment(),
+            Definition::TypeAlias(ptr) => ptr.borrow().effective_comment(),
+            Definition::Constant(ptr) => ptr.borrow().effective_comment(),
+        };
+        if let Some(overview) = comment.and_then(|comment| comment.overview.as_ref()) {
+            blocks.push(Block::Paragraph(self.message(overview)));
+        }
+        if let Some(see_tags) = comment.map(|comment| &comment.see).filter(|see_tags| !see_tags.is_empty()) {
+            let mut inlines = vec![Inline::Text("See also: ".to_owned())];
+            for (i, see_tag) in see_tags.iter().enumerate() {
+                if i > 0 {
+                    inlines.push(Inline::Text(", ".to_owned()));
+                }
+                inlines.push(self.link_definition(&see_tag.link));
+            }
+            blocks.push(Block::Paragraph(inlines));
+        }
+
+        match definition {
+            Definition::Struct(ptr) => blocks.extend(self.fields_table(ptr.borrow().fields())),
+            Definition::Class(ptr) => blocks.extend(self.fields_table(ptr.borrow().fields())),
+            Definition::Exception(ptr) => blocks.extend(self.fields_table(ptr.borrow().fields())),
+            Definition::Enum(ptr) => blocks.extend(self.enumerators_table(ptr.borrow().enumerators())),
+            Definition::Interface(ptr) => blocks.extend(self.operations_table(ptr.borrow().operations())),
+            Definition::CustomType(_) | Definition::TypeAlias(_) | Definition::Constant(_) => {}
+        }
+    }
+
+    /// Returns the declaration of the provided definition, like it's written in Slice, with any types linked.
+    fn declaration(&self, definition: &Definition) -> Vec<Inline> {
+        let mut inlines = Vec::new();
+        match definition {
+            Definition::Struct(ptr) => {
+                let struct_def = ptr.borrow();
+                let compact = if struct_def.is_compact { "compact " } else { "" };
+                inlines.push(Inline::Code(format!("{compact}struct {}", struct_def.identifier())));
+            }
+            Definition::Class(ptr) => {
+                let class_def = ptr.borrow();
+                inlines.push(Inline::Code(format!("class {}", class_def.identifier())));
+                if let Some(base) = &class_def.base {
+                    inlines.push(Inline::Code(" : ".to_owned()));
+                    inlines.push(self.link_entity(base.definition()));
+                }
+            }
+            Definition::Exception(ptr) => {
+                let exception_def = ptr.borrow();
+                inlines.push(Inline::Code(format!("exception {}", exception_def.identifier())));
+                if let Some(base) = &exception_def.base {
+                    inlines.push(Inline::Code(" : ".to_owned()));
+                    inlines.push(self.link_entity(base.definition()));
+                }
+            }
+            Definition::Interface(ptr) => {
+                let interface_def = ptr.borrow();
+                inlines.push(Inline::Code(format!("interface {}", interface_def.identifier())));
+                for (i, base) in interface_def.bases.iter().enumerate() {
+                    inlines.push(Inline::Code(if i == 0 { " : " } else { ", " }.to_owned()));
+                    inlines.push(self.link_entity(base.definition()));
+                }
+            }
+            Definition::Enum(ptr) => {
+                let enum_def = ptr.borrow();
+                let unchecked = if enum_def.is_unchecked { "unchecked " } else { "" };
+                inlines.push(Inline::Code(format!("{unchecked}enum {}", enum_def.identifier())));
+                if let Some(underlying) = &enum_def.underlying {
+                    inlines.push(Inline::Code(format!(" : {}", underlying.type_string())));
+                }
+            }
+            Definition::CustomType(ptr) => {
+                inlines.push(Inline::Code(format!("custom {}", ptr.borrow().identifier())));
+            }
+            Definition::TypeAlias(ptr) => {
+                let type_alias = ptr.borrow();
+                inlines.push(Inline::Code(format!("typealias {} = ", type_alias.identifier())));
+                inlines.extend(self.type_ref(&type_alias.underlying));
+            }
+            Definition::Constant(ptr) => {
+                let constant = ptr.borrow();
+                let data_type = constant.data_type.as_ref().map_or(String::new(), |t| format!(": {}", t.type_string()));
+                let value = constant.value();
+                inlines.push(Inline::Code(format!("const {}{data_type} = {value}", constant.identifier())));
+            }
+        }
+        inlines
+    }
+
+    fn fields_table(&self, fields: Vec<&Field>) -> Option<Block> {
+        if fields.is_empty() {
+            return None;
+        }
+
+        let rows = fields.into_iter().map(|field| {
+            let mut name = vec![Inline::Code(field.identifier().to_owned())];
+            if let Some(tag) = field.tag() {
+                name.push(Inline::Text(format!(" (tag {tag})")));
+            }
+            vec![name, self.type_ref(field.data_type()), self.description(field)]
+        });
+        Some(Block::Table(vec!["Field", "Type", "Description"], rows.collect()))
+    }
+
+    fn enumerators_table(&self, enumerators: Vec<&Enumerator>) -> Option<Block> {
+        if enumerators.is_empty() {
+            return None;
+        }
+
+        let rows = enumerators.into_iter().map(|enumerator| {
+            let mut name = vec![Inline::Code(enumerator.identifier().to_owned())];
+            if let Some(fields) = &enumerator.fields {
+                let fields = fields.iter().map(|field| field.borrow());
+                name.extend(self.member_list(fields.map(|field| (field.identifier(), field.data_type(), false))));
+            }
+            let value = vec![Inline::Code(enumerator.value().to_string())];
+            vec![name, value, self.description(enumerator)]
+        });
+        Some(Block::Table(vec!["Enumerator", "Value", "Description"], rows.collect()))
+    }
+
+    fn operations_table(&self, operations: Vec<&Operation>) -> Option<Block> {
+        if operations.is_empty() {
+            return None;
+        }
+
+        // The 'Throws' column is only included if at least one of the operations throws something.
+        let has_throws = operations.iter().any(|operation| operation.throws_anything());
+        let rows = operations.into_iter().map(|operation| {
+            let mut name = Vec::new();
+            if operation.is_idempotent() {
+                name.push(Inline::Badge("idempotent"));
+                name.push(Inline::Text(" ".to_owned()));
+            }
+            name.push(Inline::Code(operation.identifier().to_owned()));
+
+            let parameters = operation.parameters().into_iter();
+            let parameters = self.member_list(parameters.map(|p| (p.identifier(), &p.data_type, p.is_streamed)));
+
+            let returns = match operation.return_members().as_slice() {
+                [] => Vec::new(),
+                [member] => {
+                    let mut inlines = self.type_ref(&member.data_type);
+                    if member.is_streamed {
+                        inlines.insert(0, Inline::Code("stream ".to_owned()));
+                    }
+                    inlines
+                }
+                members => {
+                    let members = members.iter().map(|m| (m.identifier(), &m.data_type, m.is_streamed));
+                    self.member_list(members)
+                }
+            };
+
+            let mut throws = Vec::new();
+            for (i, thrown_type) in operation.thrown_types().into_iter().enumerate() {
+                if i > 0 {
+                    throws.push(Inline::Text(", ".to_owned()));
+                }
+                match thrown_type {
+                    ThrownType::Exception(type_ref) => throws.push(self.link_entity(type_ref.definition())),
+                    ThrownType::Error(type_ref) => throws.extend(self.type_ref(type_ref)),
+                }
+            }
+
+            match has_throws {
+                true => vec![name, parameters, returns, throws, self.description(operation)],
+                false => vec![name, parameters, returns, self.description(operation)],
+            }
+        });
+        let headers = match has_throws {
+            true => vec!["Operation", "Parameters", "Returns", "Throws", "Description"],
+            false => vec!["Operation", "Parameters", "Returns", "Description"],
+        };
+        Some(Block::Table(headers, rows.collect()))
+    }
+
+    /// Returns a parenthesized list of members, like it's written in Slice. Ex: `(a: int32, b: stream string)`.
+    fn member_list<'a>(&self, members: impl Iterator<Item = (&'a str, &'a TypeRef, bool)>) -> Vec<Inline> {
+        let mut inlines = vec![Inline::Code("(".to_owned())];
+        for (i, (identifier, data_type, is_streamed)) in members.enumerate() {
+            let separator = if i > 0 { ", " } else { "" };
+            let stream = if is_streamed { "stream " } else { "" };
+            inlines.push(Inline::Code(format!("{separator}{identifier}: {stream}")));
+            inlines.extend(self.type_ref(data_type));
+        }
+        inlines.push(Inline::Code(")".to_owned()));
+        inlines
+    }
+
+    /// Returns the description of a member: a deprecation badge if it's deprecated, and the overview of its comment.
+    fn description(&self, member: &impl Commentable) -> Vec<Inline> {
+        let mut inlines = Vec::new();
+        if let Some(deprecated) = member.find_attribute::<Deprecated>() {
+            inlines.push(Inline::Badge("Deprecated"));
+            inlines.extend(deprecated.reason.iter().map(|reason| Inline::Text(format!(" {reason}"))));
+        }
+        if let Some(overview) = member.comment().and_then(|comment| comment.overview.as_ref()) {
+            if !inlines.is_empty() {
+                inlines.push(Inline::Text(" ".to_owned()));
+            }
+            inlines.extend(self.message(overview));
+        }
+        inlines
+    }
+
+    /// Returns the provided type, like it's written in Slice, with any documented definitions linked.
+    fn type_ref(&self, type_ref: &TypeRef) -> Vec<Inline> {
+        let mut inlines = match type_ref.definition().concrete_type() {
+            Types::Struct(struct_def) => vec![self.link_entity(struct_def)],
+            Types::Class(class_def) => vec![self.link_entity(class_def)],
+            Types::Enum(enum_def) => vec![self.link_entity(enum_def)],
+            Types::CustomType(custom_type) => vec![self.link_entity(custom_type)],
+            Types::Sequence(sequence) => {
+                let mut inlines = vec![Inline::Code("Sequence<".to_owned())];
+                inlines.extend(self.type_ref(&sequence.element_type));
+                inlines.push(Inline::Code(">".to_owned()));
+                inlines
+            }
+            Types::Dictionary(dictionary) => {
+                let mut inlines = vec![Inline::Code("Dictionary<".to_owned())];
+                inlines.extend(self.type_ref(&dictionary.key_type));
+                inlines.push(Inline::Code(", ".to_owned()));
+                inlines.extend(self.type_ref(&dictionary.value_type));
+                inlines.push(Inline::Code(">".to_owned()));
+                inlines
+            }
+            Types::ResultType(result_type) => {
+                let mut inlines = vec![Inline::Code("Result<".to_owned())];
+                inlines.extend(self.type_ref(&result_type.success_type));
+                inlines.push(Inline::Code(", ".to_owned()));
+                inlines.extend(self.type_ref(&result_type.failure_type));
+                inlines.push(Inline::Code(">".to_owned()));
+                inlines
+            }
+            Types::Primitive(primitive) => vec![Inline::Code(primitive.type_string())],
+        };
+        if type_ref.is_optional {
+            inlines.push(Inline::Code("?".to_owned()));
+        }
+        inlines
+    }
+
+    fn message(&self, message: &Message) -> Vec<Inline> {
+        let components = message.value.iter().map(|component| match component {
+            MessageComponent::Text(text) => Inline::Text(text.clone()),
+            MessageComponent::Link(link_tag) => self.link_definition(&link_tag.link),
+        });
+        let mut inlines = components.collect::<Vec<_>>();
+
+        // Messages end with a newline, which would otherwise be rendered as trailing whitespace.
+        if let Some(Inline::Text(text)) = inlines.last_mut() {
+            text.truncate(text.trim_end().len());
+        }
+        inlines
+    }
+
+    fn link_definition(&self, link: &TypeRefDefinition<dyn Entity>) -> Inline {
+        match link {
+            TypeRefDefinition::Patched(ptr) => self.link_entity(ptr.borrow()),
+            TypeRefDefinition::Unpatched(identifier) => Inline::Code(identifier.value.to_string()),
+        }
+    }
+
+    /// Returns a link to the documentation of the provided entity. Since only top-level definitions have their own
+    /// sections, links to members (like fields, or operations) target the section of the definition containing them.
+    /// If the entity isn't documented, its identifier is returned as code, without a link.
+    fn link_entity(&self, entity: &dyn Entity) -> Inline {
+        let definition: &dyn Entity = match entity.concrete_entity() {
+            Entities::Field(field) => field.parent(),
+            Entities::Enumerator(enumerator) => enumerator.parent(),
+            Entities::Operation(operation) => operation.parent(),
+            Entities::Parameter(parameter) => parameter.parent().parent(),
+            _ => entity,
+        };
+        let identifier = entity.identifier().to_owned();
+        match self.documented.contains(&definition.parser_scoped_identifier()) {
+            true => {
+                let page_path = self.page_path(definition.module_scope());
+                Inline::Link(identifier, format!("{page_path}#{}", anchor_for(definition.identifier())))
+            }
+            false => Inline::Code(identifier),
+        }
+    }
+
+    fn page(&self, path: String, title: &str, blocks: &[Block]) -> DocPage {
+        let contents = match self.format {
+            DocFormat::Markdown => renderer::render_markdown(blocks),
+            DocFormat::Html => renderer::render_html(title, blocks),
+        };
+        DocPage { path, contents }
+    }
+
+    fn index_path(&self) -> String {
+        format!("index.{}", self.extension())
+    }
+
+    /// Returns the path of the provided module's page. Ex: `Acme::Widgets` => `Acme.Widgets.md`.
+    fn page_path(&self, module: &str) -> String {
+        format!("{}.{}", module.replace("::", "."), self.extension())
+    }
+
+    fn extension(&self) -> &'static str {
+        match self.format {
+            DocFormat::Markdown => "md",
+            DocFormat::Html => "html",
+        }
+    }
+}
+
+/// The titles of the sections that definitions are grouped into on module pages, in the order they're documented.
+const SECTIONS: [&str; 8] = [
+    "Interfaces",
+    "Structs",
+    "Classes",
+    "Exceptions",
+    "Enums",
+    "Custom Types",
+    "Type Aliases",
+    "Constants",
+];
+
+/// Returns the index of the section (in [SECTIONS]) that the provided definition is documented in.
+fn section_of(definition: &Definition) -> usize {
+    match definition {
+        Definition::Interface(_) => 0,
+        Definition::Struct(_) => 1,
+        Definition::Class(_) => 2,
+        Definition::Exception(_) => 3,
+        Definition::Enum(_) => 4,
+        Definition::CustomType(_) => 5,
+        Definition::TypeAlias(_) => 6,
+        Definition::Constant(_) => 7,
+    }
+}
+
+/// Returns the anchor of the section documenting the definition with the provided identifier.
+/// This matches the anchors that Markdown renderers generate for headings, so links work with either format.
+fn anchor_for(identifier: &str) -> String {
+    identifier.to_lowercase()
+}
+
+fn is_public(definition: &Definition) -> bool {
+    let visibility = match definition {
+        Definition::Struct(ptr) => ptr.borrow().visibility,
+        Definition::Class(ptr) => ptr.borrow().visibility,
+        Definition::Exception(ptr) => ptr.borrow().visibility,
+        Definition::Interface(ptr) => ptr.borrow().visibility,
+        Definition::Enum(ptr) => ptr.borrow().visibility,
+        Definition::CustomType(ptr) => ptr.borrow().visibility,
+        Definition::TypeAlias(ptr) => ptr.borrow().visibility,
+        Definition::Constant(ptr) => ptr.borrow().visibility,
+    };
+    visibility == Visibility::Public
+}
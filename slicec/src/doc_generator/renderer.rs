@@ -0,0 +1,131 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module renders the blocks of a documentation page in each of the supported formats.
+
+use super::{Block, Inline};
+
+const HTML_STYLE: &str = "\
+body { font-family: sans-serif; line-height: 1.5; margin: 2em auto; max-width: 60em; padding: 0 1em; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { border: 1px solid #d0d7de; padding: 0.3em 0.6em; text-align: left; vertical-align: top; }
+code { background: #f6f8fa; border-radius: 3px; padding: 0.1em 0.3em; }
+.badge { background: #fff1e5; border: 1px solid #d4a72c; border-radius: 1em; font-size: 0.85em; padding: 0 0.5em; }";
+
+pub(super) fn render_markdown(blocks: &[Block]) -> String {
+    let rendered = blocks.iter().map(|block| match block {
+        Block::Heading(level, _, text) => format!("{} {}", "#".repeat(*level), escape_markdown(text)),
+        Block::Paragraph(inlines) => render_markdown_inlines(inlines),
+        Block::Table(headers, rows) => {
+            let mut table = format!("| {} |\n", headers.join(" | "));
+            table += &format!("|{}\n", " --- |".repeat(headers.len()));
+            for row in rows {
+                let cells = row.iter().map(|cell| render_markdown_inlines(cell).replace('\n', " "));
+                let row = format!("| {} |", cells.collect::<Vec<_>>().join(" | "));
+                // Empty cells would otherwise be rendered with two spaces between their pipes.
+                table += &row.replace("|  |", "| |");
+                table.push('\n');
+            }
+            table.trim_end().to_owned()
+        }
+    });
+    rendered.collect::<Vec<_>>().join("\n\n") + "\n"
+}
+
+fn render_markdown_inlines(inlines: &[Inline]) -> String {
+    merge_code(inlines)
+        .into_iter()
+        .map(|inline| match inline {
+            MergedInline::Text(text) => escape_markdown(text),
+            MergedInline::Code(code) => format!("`{code}`"),
+            MergedInline::Link(text, target) => format!("[`{text}`]({target})"),
+            MergedInline::Badge(text) => format!("**{text}**"),
+        })
+        .collect()
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+pub(super) fn render_html(title: &str, blocks: &[Block]) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{HTML_STYLE}\n</style>\n</head>\n<body>\n",
+        escape_html(title),
+    );
+    for block in blocks {
+        match block {
+            Block::Heading(level, anchor, text) => {
+                let id = anchor.as_ref().map_or(String::new(), |anchor| format!(" id=\"{}\"", escape_html(anchor)));
+                html += &format!("<h{level}{id}>{}</h{level}>\n", escape_html(text));
+            }
+            Block::Paragraph(inlines) => html += &format!("<p>{}</p>\n", render_html_inlines(inlines)),
+            Block::Table(headers, rows) => {
+                html += "<table>\n<thead>\n<tr>";
+                for header in headers {
+                    html += &format!("<th>{header}</th>");
+                }
+                html += "</tr>\n</thead>\n<tbody>\n";
+                for row in rows {
+                    html += "<tr>";
+                    for cell in row {
+                        html += &format!("<td>{}</td>", render_html_inlines(cell));
+                    }
+                    html += "</tr>\n";
+                }
+                html += "</tbody>\n</table>\n";
+            }
+        }
+    }
+    html + "</body>\n</html>\n"
+}
+
+fn render_html_inlines(inlines: &[Inline]) -> String {
+    merge_code(inlines)
+        .into_iter()
+        .map(|inline| match inline {
+            MergedInline::Text(text) => escape_html(text),
+            MergedInline::Code(code) => format!("<code>{}</code>", escape_html(&code)),
+            MergedInline::Link(text, target) => {
+                format!("<a href=\"{}\"><code>{}</code></a>", escape_html(target), escape_html(text))
+            }
+            MergedInline::Badge(text) => format!("<span class=\"badge\">{text}</span>"),
+        })
+        .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// An inline, where runs of adjacent code spans have been merged into a single span.
+enum MergedInline<'a> {
+    Text(&'a str),
+    Code(String),
+    Link(&'a str, &'a str),
+    Badge(&'a str),
+}
+
+/// Merges adjacent code spans, since most Markdown renderers can't render code spans which directly follow each other.
+fn merge_code(inlines: &[Inline]) -> Vec<MergedInline<'_>> {
+    let mut merged = Vec::new();
+    for inline in inlines {
+        match (inline, merged.last_mut()) {
+            (Inline::Code(code), Some(MergedInline::Code(previous))) => previous.push_str(code),
+            (Inline::Code(code), _) => merged.push(MergedInline::Code(code.clone())),
+            (Inline::Text(text), _) => merged.push(MergedInline::Text(text)),
+            (Inline::Link(text, target), _) => merged.push(MergedInline::Link(text, target)),
+            (Inline::Badge(text), _) => merged.push(MergedInline::Badge(text)),
+        }
+    }
+    merged
+}
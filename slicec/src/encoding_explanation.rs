@@ -0,0 +1,244 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module explains how the compiler determined the compilation mode and supported encodings of an element.
+//!
+//! The encoding patcher computes which encodings each element supports, but only reports *why* when an element isn't
+//! supported by its file's compilation mode. [explain_encodings] reconstructs the reasons for any element, which is
+//! useful for tooling (hover text, documentation, etc.) and for debugging surprising results.
+
+use crate::diagnostics::Note;
+use crate::grammar::*;
+use crate::slice_file::{SliceFile, Span};
+use crate::supported_encodings::SupportedEncodings;
+
+/// The compilation mode and supported encodings of an element, along with the reasons they're what they are.
+#[derive(Clone, Debug)]
+pub struct EncodingExplanation {
+    /// The compilation mode of the file that the element is defined in.
+    pub compilation_mode: CompilationMode,
+
+    /// The encodings that the element supports.
+    pub supported_encodings: SupportedEncodings,
+
+    /// The chain of reasons for the element's supported encodings, in the order they were applied.
+    /// The first note always explains the file's compilation mode. Each subsequent note explains why an encoding
+    /// which was still supported at that point was removed, and points to the responsible member where possible.
+    pub notes: Vec<Note>,
+}
+
+/// Explains why the provided entity has the compilation mode and supported encodings that it does.
+///
+/// This should only be called on entities from a fully patched AST, and `files` must contain the file that the entity
+/// is defined in.
+pub fn explain_encodings(entity: &dyn Entity, files: &[SliceFile]) -> EncodingExplanation {
+    let slice_file = files.iter().find(|f| f.relative_path == entity.span().file).unwrap();
+    let compilation_mode = slice_file.compilation_mode();
+
+    let mut explainer = EncodingExplainer {
+        encodings: SupportedEncodings::new(match compilation_mode {
+            CompilationMode::Slice1 => vec![Encoding::Slice1, Encoding::Slice2],
+            CompilationMode::Slice2 => vec![Encoding::Slice2],
+        }),
+        notes: vec![compilation_mode_note(slice_file)],
+    };
+
+    let supported_encodings = match entity.concrete_entity() {
+        Entities::Struct(struct_def) => {
+            explainer.explain_fields(struct_def.fields());
+            if !struct_def.is_compact {
+                explainer.remove(Encoding::Slice1, "structs which aren't 'compact' don't support Slice1", None);
+            }
+            struct_def.supported_encodings()
+        }
+        Entities::Class(class_def) => {
+            explainer.explain_fields(class_def.all_fields());
+            explainer.remove(Encoding::Slice2, "classes only support Slice1", None);
+            class_def.supported_encodings()
+        }
+        Entities::Exception(exception_def) => {
+            explainer.explain_fields(exception_def.all_fields());
+            explainer.remove(Encoding::Slice2, "exceptions only support Slice1", None);
+            exception_def.supported_encodings()
+        }
+        Entities::Enum(enum_def) => {
+            if let Some(underlying) = &enum_def.underlying {
+                let message = "enums with underlying types don't support Slice1";
+                explainer.remove(Encoding::Slice1, message, Some(underlying.span()));
+            }
+            if enum_def.is_compact {
+                explainer.remove(Encoding::Slice1, "compact enums don't support Slice1", None);
+            }
+            if let Some(enumerator) = enum_def.enumerators().into_iter().find(|e| e.fields.is_some()) {
+                let message = format!(
+                    "enumerator '{}' has fields, and enums with enumerator fields don't support Slice1",
+                    enumerator.identifier(),
+                );
+                explainer.remove(Encoding::Slice1, message, Some(enumerator.span()));
+            }
+            enum_def.supported_encodings()
+        }
+        Entities::Enumerator(enumerator) => {
+            let mut explanation = explain_encodings(enumerator.parent(), files);
+            explanation.notes.push(Note {
+                message: "enumerators support the same encodings as their enum".to_owned(),
+                span: Some(enumerator.parent().span().clone()),
+            });
+            return explanation;
+        }
+        Entities::TypeAlias(type_alias) => {
+            let underlying = &type_alias.underlying;
+            explainer.explain_type_ref(underlying, false, "underlying type", underlying.span());
+            type_alias.supported_encodings()
+        }
+        Entities::Field(field) => {
+            explainer.explain_member(field);
+            explainer.encodings
+        }
+        Entities::Parameter(parameter) => {
+            explainer.explain_member(parameter);
+            explainer.encodings
+        }
+        Entities::Constant(constant) => {
+            if let Some(data_type) = &constant.data_type {
+                explainer.explain_type_ref(data_type, false, "type", data_type.span());
+            }
+            explainer.encodings
+        }
+        Entities::Operation(operation) => {
+            // Operations are always encoded with the encoding of the file they're defined in.
+            let message = "operations only support the encoding of the file they're defined in".to_owned();
+            explainer.notes.push(Note { message, span: None });
+            SupportedEncodings::new(vec![operation.encoding])
+        }
+        Entities::Interface(interface_def) => interface_def.supported_encodings(),
+        Entities::CustomType(custom_type) => custom_type.supported_encodings(),
+    };
+
+    EncodingExplanation {
+        compilation_mode,
+        supported_encodings,
+        notes: explainer.notes,
+    }
+}
+
+/// Returns a note explaining the compilation mode of the provided file, and which encodings that mode allows.
+fn compilation_mode_note(slice_file: &SliceFile) -> Note {
+    let compilation_mode = slice_file.compilation_mode();
+    let allowed_encodings = match compilation_mode {
+        CompilationMode::Slice1 => "the Slice1 and Slice2 encodings",
+        CompilationMode::Slice2 => "only the Slice2 encoding",
+    };
+
+    match &slice_file.mode {
+        Some(mode) => Note {
+            message: format!(
+                "'{}' is set to {compilation_mode} mode, which allows {allowed_encodings}",
+                slice_file.relative_path,
+            ),
+            span: Some(mode.span.clone()),
+        },
+        None => Note {
+            message: format!(
+                "'{}' uses {compilation_mode} mode by default, which allows {allowed_encodings}",
+                slice_file.relative_path,
+            ),
+            span: None,
+        },
+    }
+}
+
+struct EncodingExplainer {
+    /// The encodings which are still supported, after applying all the reasons explained so far.
+    encodings: SupportedEncodings,
+    notes: Vec<Note>,
+}
+
+impl EncodingExplainer {
+    /// Removes support for the provided encoding, and records why, but only if it's still supported.
+    fn remove(&mut self, encoding: Encoding, message: impl Into<String>, span: Option<&Span>) {
+        if self.encodings.supports(encoding) {
+            self.encodings.disable(encoding);
+            self.notes.push(Note {
+                message: message.into(),
+                span: span.cloned(),
+            });
+        }
+    }
+
+    fn explain_fields(&mut self, fields: Vec<&Field>) {
+        for field in fields {
+            self.explain_member(field);
+        }
+    }
+
+    fn explain_member(&mut self, member: &impl Member) {
+        let description = format!("{} '{}'", member.kind(), member.identifier());
+        self.explain_type_ref(member.data_type(), member.is_tagged(), &description, member.span());
+    }
+
+    /// Removes any encodings that the provided type reference doesn't support, and records why.
+    /// `description` describes what's using the type reference, ex: "field 'a'" or "underlying type".
+    fn explain_type_ref(
+        &mut self,
+        type_ref: &TypeRef<impl Type + ?Sized>,
+        is_tagged: bool,
+        description: &str,
+        span: &Span,
+    ) {
+        let type_encodings = get_supported_encodings_for_type_ref(type_ref, is_tagged);
+        let non_optional_encodings = get_supported_encodings_for_type_ref(type_ref, true);
+
+        for encoding in [Encoding::Slice1, Encoding::Slice2] {
+            if type_encodings.supports(encoding) {
+                continue;
+            }
+
+            let message = if non_optional_encodings.supports(encoding) {
+                format!(
+                    "{description} has an optional type ('{}'), which doesn't support {encoding} unless it's tagged",
+                    type_ref.type_string(),
+                )
+            } else {
+                format!(
+                    "{description} has type '{}', which doesn't support {encoding}",
+                    type_ref.type_string(),
+                )
+            };
+            self.remove(encoding, message, Some(span));
+        }
+    }
+}
+
+/// Returns the encodings supported by the provided type reference, using the same rules as the encoding patcher.
+///
+/// Optional types don't support Slice1, unless `allow_optional_with_slice1` is true,
+/// or the type is a class, `AnyClass`, or a custom type.
+fn get_supported_encodings_for_type_ref(
+    type_ref: &TypeRef<impl Type + ?Sized>,
+    mut allow_optional_with_slice1: bool,
+) -> SupportedEncodings {
+    let mut supported_encodings = match type_ref.concrete_type() {
+        Types::Class(_) | Types::CustomType(_) | Types::Primitive(Primitive::AnyClass) => {
+            allow_optional_with_slice1 = true;
+            type_ref.supported_encodings()
+        }
+        Types::ResultType(result_type) => {
+            let mut encodings = get_supported_encodings_for_type_ref(&result_type.success_type, false);
+            encodings.intersect_with(&get_supported_encodings_for_type_ref(&result_type.failure_type, false));
+            encodings.disable(Encoding::Slice1);
+            encodings
+        }
+        Types::Sequence(sequence) => get_supported_encodings_for_type_ref(&sequence.element_type, false),
+        Types::Dictionary(dictionary) => {
+            let mut encodings = get_supported_encodings_for_type_ref(&dictionary.key_type, false);
+            encodings.intersect_with(&get_supported_encodings_for_type_ref(&dictionary.value_type, false));
+            encodings
+        }
+        Types::Struct(_) | Types::Enum(_) | Types::Primitive(_) => type_ref.supported_encodings(),
+    };
+
+    if type_ref.is_optional && !allow_optional_with_slice1 {
+        supported_encodings.disable(Encoding::Slice1);
+    }
+    supported_encodings
+}
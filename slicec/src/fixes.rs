@@ -0,0 +1,125 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains the logic for applying the [suggestions](Suggestion) attached to diagnostics to the Slice files
+//! they were reported in, similar to `cargo fix`.
+//!
+//! Fixes are only collected from diagnostics that will be emitted (allowed lints are skipped), and are only applied to
+//! source files; reference files and built-in definitions are never modified. Suggestions which aren't
+//! [machine applicable](Suggestion::is_machine_applicable) are never applied.
+
+use crate::diagnostics::{Diagnostic, DiagnosticLevel, Diagnostics, Error, Suggestion};
+use crate::slice_file::{Location, SliceFile};
+use std::fs;
+
+/// The contents of a Slice file after its fixes have been applied.
+#[derive(Debug)]
+pub struct FixedFile {
+    /// The path of the file, relative to where the compiler was invoked.
+    pub relative_path: String,
+    /// The text of the file with all its fixes applied.
+    pub text: String,
+    /// The number of fixes that were applied to the file.
+    pub fix_count: usize,
+}
+
+/// Returns the fixed contents of each source file that the provided diagnostics suggest edits for.
+///
+/// The provided diagnostics should already be [updated](Diagnostics::into_updated), so that allowed lints can be
+/// skipped. Files without any applicable suggestions aren't returned.
+pub fn collect_fixes(files: &[SliceFile], diagnostics: &[Diagnostic]) -> Vec<FixedFile> {
+    let suggestions = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.level() != DiagnosticLevel::Allowed)
+        .flat_map(|diagnostic| diagnostic.suggestions())
+        .filter(|suggestion| suggestion.is_machine_applicable)
+        .collect::<Vec<_>>();
+
+    files
+        .iter()
+        .filter(|file| file.is_source)
+        .filter_map(|file| {
+            let file_suggestions = suggestions
+                .iter()
+                .copied()
+                .filter(|suggestion| suggestion.span.file == file.relative_path)
+                .collect::<Vec<_>>();
+
+            let (text, fix_count) = apply_suggestions(&file.raw_text, &file_suggestions);
+            (fix_count > 0).then(|| FixedFile {
+                relative_path: file.relative_path.clone(),
+                text,
+                fix_count,
+            })
+        })
+        .collect()
+}
+
+/// Applies the provided suggestions to `text`, returning the edited text and how many suggestions were applied.
+///
+/// Suggestions are applied in the order they appear in the text. If a suggestion overlaps with one that was already
+/// applied, it's skipped, since its span may no longer refer to the text it was created for. Running the compiler again
+/// will report it again, at its updated location.
+pub fn apply_suggestions(text: &str, suggestions: &[&Suggestion]) -> (String, usize) {
+    let mut edits = suggestions
+        .iter()
+        .map(|suggestion| {
+            let start = byte_offset(text, suggestion.span.start);
+            let end = byte_offset(text, suggestion.span.end).max(start);
+            (start, end, suggestion.replacement.as_str())
+        })
+        .collect::<Vec<_>>();
+    // Identical suggestions can be attached to duplicate diagnostics, so they should only be applied once.
+    edits.sort();
+    edits.dedup();
+
+    let mut result = String::with_capacity(text.len());
+    let mut position = 0;
+    let mut fix_count = 0;
+    for (start, end, replacement) in edits {
+        // Skip any edits which overlap with an edit we've already applied.
+        if start < position {
+            continue;
+        }
+
+        result.push_str(&text[position..start]);
+        result.push_str(replacement);
+        position = end;
+        fix_count += 1;
+    }
+    result.push_str(&text[position..]);
+    (result, fix_count)
+}
+
+/// Writes each of the provided fixed files back to disk, reporting an error for any files that couldn't be written.
+pub fn write_fixes(fixed_files: &[FixedFile], diagnostics: &mut Diagnostics) {
+    for fixed_file in fixed_files {
+        if let Err(error) = fs::write(&fixed_file.relative_path, &fixed_file.text) {
+            Diagnostic::new(Error::IO {
+                action: "write",
+                path: fixed_file.relative_path.clone(),
+                error,
+            })
+            .push_into(diagnostics);
+        }
+    }
+}
+
+/// Returns the byte offset of the provided location in `text`.
+/// Locations past the end of a line are clamped to the end of that line (before its newline character).
+fn byte_offset(text: &str, location: Location) -> usize {
+    let mut line_start = 0;
+    for _ in 1..location.row {
+        match text[line_start..].find('\n') {
+            Some(index) => line_start += index + 1,
+            None => return text.len(),
+        }
+    }
+
+    let line = &text[line_start..];
+    let line_end = line.find('\n').unwrap_or(line.len());
+    let column_offset = line[..line_end]
+        .char_indices()
+        .nth(location.col.saturating_sub(1))
+        .map_or(line_end, |(index, _)| index);
+    line_start + column_offset
+}
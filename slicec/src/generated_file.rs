@@ -0,0 +1,286 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains the logic for writing the files produced by code generators to disk.
+//!
+//! Code generators describe each of their outputs as a [GeneratedFile], and pass them to [write_generated_files], which
+//! takes care of the output directory (`--output-dir`), dry runs (`--dry-run`), and removing any stale files left over
+//! from previous compilations.
+//!
+//! To know which files are stale, a manifest listing every file that was generated is written to the output directory.
+//! On the next compilation, any files in this manifest that weren't generated again are removed.
+//!
+//! Code generators can also record which Slice definition produced each region of a generated file (see
+//! [GeneratedFile::append_mapped]). If `--source-maps` is set, these are written alongside each generated file as a
+//! JSON source map (`<file>.slicemap`), so that tools can point from generated code back to the Slice definitions.
+//!
+//! Files are written atomically, and only if their contents have changed (see [write_if_changed]).
+
+use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::slice_file::Span;
+use crate::slice_options::SliceOptions;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::ffi::OsString;
+use std::{fs, io};
+use std::path::{Path, PathBuf};
+
+/// The name of the manifest file which lists the files generated by the previous compilation.
+pub const MANIFEST_FILE_NAME: &str = ".slicec-generated";
+
+/// The extension appended to the path of a generated file to get the path of its source map.
+pub const SOURCE_MAP_EXTENSION: &str = "slicemap";
+
+/// A file produced by a code generator.
+#[derive(Clone, Debug, Default)]
+pub struct GeneratedFile {
+    /// The path of the file, relative to the output directory.
+    pub path: String,
+    /// The contents of the file.
+    pub contents: String,
+    /// The spans of the Slice definitions that this file was generated from.
+    pub source_spans: Vec<Span>,
+    /// Which lines of this file were generated from which Slice definitions, in the order they were appended.
+    pub mappings: Vec<SourceMapping>,
+}
+
+/// Links a range of lines in a generated file back to the Slice definition they were generated from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SourceMapping {
+    /// The first line of the range (1-based).
+    pub start_line: usize,
+    /// The last line of the range (inclusive).
+    pub end_line: usize,
+    /// The span of the Slice definition that these lines were generated from.
+    pub span: Span,
+}
+
+impl GeneratedFile {
+    pub fn new(path: impl Into<String>, contents: impl Into<String>) -> Self {
+        GeneratedFile {
+            path: path.into(),
+            contents: contents.into(),
+            source_spans: Vec::new(),
+            mappings: Vec::new(),
+        }
+    }
+
+    /// Appends the provided code to the end of this file.
+    pub fn append(&mut self, code: &str) {
+        self.contents.push_str(code);
+    }
+
+    /// Appends the provided code to the end of this file, and records that it was generated from the Slice definition
+    /// at the provided span. Mappings can be nested: code can be mapped to a definition, and then the code containing
+    /// it can be mapped to the definition's parent.
+    pub fn append_mapped(&mut self, code: &str, span: &Span) {
+        // If the file doesn't end with a newline, the code starts on the same line as the file's last line.
+        let start_line = self.contents.matches('\n').count() + 1;
+        self.contents.push_str(code);
+        let end_line = self.contents.trim_end_matches('\n').matches('\n').count() + 1;
+
+        self.add_mapping(start_line, end_line.max(start_line), span);
+    }
+
+    /// Records that the lines from `start_line` to `end_line` (inclusive, and 1-based) were generated from the Slice
+    /// definition at the provided span.
+    pub fn add_mapping(&mut self, start_line: usize, end_line: usize, span: &Span) {
+        self.add_source_span(span);
+        self.mappings.push(SourceMapping {
+            start_line,
+            end_line,
+            span: span.clone(),
+        });
+    }
+
+    /// Returns the span of the most specific Slice definition that the provided line was generated from, if any.
+    /// If multiple mappings include the line, the one covering the fewest lines is returned.
+    pub fn find_source(&self, line: usize) -> Option<&Span> {
+        self.mappings
+            .iter()
+            .filter(|mapping| (mapping.start_line..=mapping.end_line).contains(&line))
+            .min_by_key(|mapping| mapping.end_line - mapping.start_line)
+            .map(|mapping| &mapping.span)
+    }
+
+    /// Returns the path of this file's source map, relative to the output directory.
+    pub fn source_map_path(&self) -> String {
+        format!("{}.{SOURCE_MAP_EXTENSION}", self.path)
+    }
+
+    /// Returns this file's source map, serialized as JSON. Ex:
+    /// `{"file":"A.cs","mappings":[{"start_line":1,"end_line":3,"span":{"start":...,"end":...,"file":"a.slice"}}]}`
+    pub fn source_map(&self) -> String {
+        #[derive(Serialize)]
+        struct SourceMap<'a> {
+            file: &'a str,
+            mappings: &'a [SourceMapping],
+        }
+
+        let source_map = SourceMap {
+            file: &self.path,
+            mappings: &self.mappings,
+        };
+        serde_json::to_string(&source_map).expect("failed to serialize source map")
+    }
+
+    /// Records that this file contains code generated from the Slice definition at the provided span.
+    pub fn add_source_span(&mut self, span: &Span) {
+        if !self.source_spans.contains(span) {
+            self.source_spans.push(span.clone());
+        }
+    }
+
+    /// Returns the set of Slice files that this file was generated from.
+    pub fn source_files(&self) -> BTreeSet<&str> {
+        self.source_spans.iter().map(|span| span.file.as_str()).collect()
+    }
+
+    /// Writes this file into the provided directory (see [write_if_changed]), along with its source map, if
+    /// `source_map` is true and the file has any mappings.
+    ///
+    /// This is for code generators which manage their own outputs; most should use [write_generated_files] instead.
+    /// Returns true if anything was written, and false if everything was already up to date.
+    pub fn write_to(&self, directory: &Path, source_map: bool) -> io::Result<bool> {
+        let mut written = write_if_changed(&directory.join(&self.path), &self.contents)?;
+        if source_map && !self.mappings.is_empty() {
+            written |= write_if_changed(&directory.join(self.source_map_path()), &self.source_map())?;
+        }
+        Ok(written)
+    }
+}
+
+/// Returns the directory that generated files should be written to: `--output-dir` if it was set, otherwise the
+/// current working directory.
+pub fn output_directory(options: &SliceOptions) -> PathBuf {
+    PathBuf::from(options.output_dir.as_deref().unwrap_or("."))
+}
+
+/// Writes the provided files into the output directory, and removes any stale files from previous compilations.
+///
+/// Files are only written if their contents have changed, so that build systems don't consider them out of date.
+/// If multiple files have the same path, an error is reported and nothing is written. If `--dry-run` was set, the
+/// files are checked for conflicts, but nothing is written or removed. If `--source-maps` was set, the source map of
+/// each file with mappings is written alongside it.
+pub fn write_generated_files(files: &[GeneratedFile], options: &SliceOptions, diagnostics: &mut Diagnostics) {
+    if check_for_conflicts(files, diagnostics) || options.dry_run {
+        return;
+    }
+
+    let output_dir = output_directory(options);
+    if let Err(error) = fs::create_dir_all(&output_dir) {
+        report_io_error("create", &output_dir, error, diagnostics);
+        return;
+    }
+
+    let mut outputs = Vec::new();
+    for file in files {
+        outputs.push((file.path.clone(), file.contents.clone()));
+        if options.source_maps && !file.mappings.is_empty() {
+            outputs.push((file.source_map_path(), file.source_map()));
+        }
+    }
+
+    for (relative_path, contents) in &outputs {
+        let path = output_dir.join(relative_path);
+        if let Err(error) = write_if_changed(&path, contents) {
+            report_io_error("write", &path, error, diagnostics);
+        }
+    }
+
+    let generated = outputs.iter().map(|(path, _)| path.as_str()).collect();
+    remove_stale_files(generated, &output_dir, diagnostics);
+}
+
+/// Atomically writes the provided contents to a file, unless the file already has exactly those contents.
+///
+/// Unchanged files are left untouched, so their modification times are preserved and build systems (like MSBuild or
+/// CMake) don't needlessly rebuild everything that depends on them. Otherwise, the contents are written to a temporary
+/// file in the same directory, which is then renamed over the original. So readers either see the old contents or
+/// the new contents, never a partially written file. Any missing parent directories are created.
+///
+/// Returns true if the file was written, and false if it was already up to date.
+pub fn write_if_changed(path: &Path, contents: &str) -> io::Result<bool> {
+    if fs::read(path).is_ok_and(|existing| existing == contents.as_bytes()) {
+        return Ok(false);
+    }
+
+    let Some(file_name) = path.file_name() else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "path doesn't name a file"));
+    };
+    let parent = path.parent().filter(|parent| !parent.as_os_str().is_empty());
+    if let Some(parent) = parent {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut temp_name = OsString::from(".");
+    temp_name.push(file_name);
+    temp_name.push(format!(".{}.tmp", std::process::id()));
+    let temp_path = path.with_file_name(temp_name);
+
+    let result = fs::write(&temp_path, contents).and_then(|_| fs::rename(&temp_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result.map(|_| true)
+}
+
+/// Reports an error for each path that's used by more than one of the provided files.
+/// Returns true if any conflicts were found.
+fn check_for_conflicts(files: &[GeneratedFile], diagnostics: &mut Diagnostics) -> bool {
+    let mut files_by_path: HashMap<&str, Vec<&GeneratedFile>> = HashMap::new();
+    for file in files {
+        files_by_path.entry(&file.path).or_default().push(file);
+    }
+
+    let mut conflicts = files_by_path
+        .into_iter()
+        .filter(|(_, files)| files.len() > 1)
+        .collect::<Vec<_>>();
+    conflicts.sort_by_key(|(path, _)| *path);
+
+    for (path, files) in &conflicts {
+        let mut diagnostic = Diagnostic::new(Error::GeneratedFileConflict { path: path.to_string() });
+        for span in files.iter().flat_map(|file| &file.source_spans) {
+            diagnostic = diagnostic.add_note("code for this definition is generated into the file", Some(span));
+        }
+        diagnostic.push_into(diagnostics);
+    }
+    !conflicts.is_empty()
+}
+
+/// Removes any files listed in the output directory's manifest which weren't generated by this compilation, and then
+/// updates the manifest to list the files that were.
+fn remove_stale_files(generated: BTreeSet<&str>, output_dir: &Path, diagnostics: &mut Diagnostics) {
+    let manifest_path = output_dir.join(MANIFEST_FILE_NAME);
+
+    // If there's no manifest, this is the first compilation into this directory, so there's nothing to remove.
+    if let Ok(manifest) = fs::read_to_string(&manifest_path) {
+        for stale_path in manifest.lines().filter(|line| !line.is_empty() && !generated.contains(line)) {
+            let path = output_dir.join(stale_path);
+            if let Err(error) = fs::remove_file(&path) {
+                // It's fine if the user already removed the file themselves.
+                if error.kind() != io::ErrorKind::NotFound {
+                    report_io_error("remove", &path, error, diagnostics);
+                }
+            }
+        }
+    }
+
+    let mut manifest = String::new();
+    for path in generated {
+        manifest.push_str(path);
+        manifest.push('\n');
+    }
+    if let Err(error) = write_if_changed(&manifest_path, &manifest) {
+        report_io_error("write", &manifest_path, error, diagnostics);
+    }
+}
+
+fn report_io_error(action: &'static str, path: &Path, error: io::Error, diagnostics: &mut Diagnostics) {
+    Diagnostic::new(Error::IO {
+        action,
+        path: path.display().to_string(),
+        error,
+    })
+    .push_into(diagnostics);
+}
@@ -0,0 +1,317 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains a harness for golden-file (snapshot) tests of code generators.
+//! It's only available when the `test-support` feature is enabled, like the [test_helpers](crate::test_helpers).
+//!
+//! A golden-file test compiles a directory of Slice fixtures, runs a code generator over each of them, and compares the
+//! [files it generates](GeneratedFile) against snapshots which are checked in alongside the fixtures. Each `.slice`
+//! file directly in the fixtures directory is compiled on its own, and each sub-directory is compiled as a whole (with
+//! all the `.slice` files in it). The snapshots for a fixture are stored in a directory named after it:
+//! ```text
+//! tests/golden/
+//! ├── structs.slice
+//! ├── multi_file/
+//! │   ├── a.slice
+//! │   └── b.slice
+//! └── snapshots/
+//!     ├── structs/Structs.cs
+//!     └── multi_file/A.cs
+//! ```
+//!
+//! When snapshots are out of date, the test fails with a diff of each change. Running the test with the
+//! [`SLICEC_BLESS`](BLESS_ENV_VAR) environment variable set updates the snapshots to match the generated files instead.
+
+use crate::compilation_state::CompilationState;
+use crate::compile_from_options;
+use crate::diagnostics::{Diagnostic, DiagnosticLevel};
+use crate::generated_file::GeneratedFile;
+use crate::slice_options::SliceOptions;
+use std::fmt::{self, Write};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The environment variable which, when set (to anything other than `0`), makes golden-file tests update their
+/// snapshots instead of failing when they're out of date.
+pub const BLESS_ENV_VAR: &str = "SLICEC_BLESS";
+
+/// The number of unchanged lines shown around each change in a diff.
+const DIFF_CONTEXT_LINES: usize = 3;
+
+/// A golden-file test over a directory of Slice fixtures. See the [module documentation](self) for more information.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slicec::compilation_state::CompilationState;
+/// # use slicec::generated_file::GeneratedFile;
+/// # use slicec::golden_files::GoldenTest;
+/// fn generate_code(state: &CompilationState) -> Vec<GeneratedFile> {
+///     // ...
+/// #   Vec::new()
+/// }
+///
+/// GoldenTest::new("tests/golden").assert(generate_code);
+/// ```
+pub struct GoldenTest {
+    /// The directory containing the Slice fixtures.
+    pub fixtures_dir: PathBuf,
+
+    /// The directory containing the snapshots. By default, this is the `snapshots` sub-directory of the fixtures.
+    pub snapshots_dir: PathBuf,
+
+    /// The options that each fixture is compiled with. Any `sources` in these options are replaced by the fixture.
+    pub options: SliceOptions,
+
+    /// The patcher that each fixture is compiled with, see [compile_from_options].
+    pub patcher: unsafe fn(&mut CompilationState),
+
+    /// The validator that each fixture is compiled with, see [compile_from_options].
+    pub validator: fn(&mut CompilationState),
+
+    /// Whether to update snapshots which are out of date, instead of reporting them.
+    /// By default, this is true if the [`SLICEC_BLESS`](BLESS_ENV_VAR) environment variable is set.
+    pub bless: bool,
+}
+
+impl GoldenTest {
+    /// Creates a golden-file test over the fixtures in the provided directory, using the default options.
+    pub fn new(fixtures_dir: impl Into<PathBuf>) -> Self {
+        let fixtures_dir = fixtures_dir.into();
+        let bless = std::env::var(BLESS_ENV_VAR).is_ok_and(|value| value != "0");
+        GoldenTest {
+            snapshots_dir: fixtures_dir.join("snapshots"),
+            fixtures_dir,
+            options: SliceOptions::default(),
+            patcher: |_| {},
+            validator: |_| {},
+            bless,
+        }
+    }
+
+    /// Compiles each fixture, runs the provided code generator on it, and compares the generated files against the
+    /// fixture's snapshots. Returns a description of each mismatch that was found, in the order the fixtures are
+    /// stored in (sorted by name). If [bless](GoldenTest::bless) is set, mismatched snapshots are updated instead, and
+    /// only fixtures which failed to compile are returned.
+    pub fn run(mut self, generator: impl Fn(&CompilationState) -> Vec<GeneratedFile>) -> Vec<SnapshotMismatch> {
+        let mut mismatches = Vec::new();
+        for (name, sources) in self.find_fixtures() {
+            self.options.sources = sources.iter().map(|path| path.display().to_string()).collect();
+            let state = compile_from_options(&self.options, self.patcher, self.validator);
+
+            if state.diagnostics.has_errors() {
+                let mut diagnostics = state.into_diagnostics(&self.options);
+                diagnostics.retain(|diagnostic| diagnostic.level() == DiagnosticLevel::Error);
+                mismatches.push(SnapshotMismatch::CompilationFailed { fixture: name, diagnostics });
+                continue;
+            }
+
+            let generated_files = generator(&state);
+            let snapshot_dir = self.snapshots_dir.join(&name);
+            let fixture_mismatches = compare_snapshots(&generated_files, &snapshot_dir);
+            match self.bless {
+                true => update_snapshots(&generated_files, &snapshot_dir, &fixture_mismatches),
+                false => mismatches.extend(fixture_mismatches),
+            }
+        }
+        mismatches
+    }
+
+    /// Runs this test like [run](GoldenTest::run), but panics with a report of every mismatch, if there were any.
+    pub fn assert(self, generator: impl Fn(&CompilationState) -> Vec<GeneratedFile>) {
+        let mismatches = self.run(generator);
+        if mismatches.is_empty() {
+            return;
+        }
+
+        let mut report = String::new();
+        for mismatch in &mismatches {
+            writeln!(report, "{mismatch}").unwrap();
+        }
+        writeln!(report, "{} snapshot mismatch(es) found.", mismatches.len()).unwrap();
+        writeln!(report, "To update the snapshots, re-run the tests with '{BLESS_ENV_VAR}=1'.").unwrap();
+        panic!("{report}");
+    }
+
+    /// Returns the name and source files of each fixture in the fixtures directory, sorted by name.
+    fn find_fixtures(&self) -> Vec<(String, Vec<PathBuf>)> {
+        let mut fixtures = Vec::new();
+        for path in sorted_dir_entries(&self.fixtures_dir) {
+            if path.is_dir() && path != self.snapshots_dir {
+                let Some(name) = path.file_name().and_then(|name| name.to_str()) else { continue };
+                let sources = sorted_dir_entries(&path).into_iter().filter(|path| is_slice_file(path));
+                fixtures.push((name.to_owned(), sources.collect()));
+            } else if is_slice_file(&path) {
+                let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else { continue };
+                fixtures.push((name.to_owned(), vec![path]));
+            }
+        }
+        fixtures
+    }
+}
+
+/// A difference between the files generated for a fixture and the fixture's snapshots.
+#[derive(Debug)]
+pub enum SnapshotMismatch {
+    /// A file was generated, but there's no snapshot for it.
+    Missing { snapshot: PathBuf, contents: String },
+
+    /// A file was generated, but its contents differ from its snapshot.
+    Changed { snapshot: PathBuf, contents: String, diff: String },
+
+    /// There's a snapshot for a file which wasn't generated.
+    Stale { snapshot: PathBuf },
+
+    /// The fixture failed to compile, so no code was generated for it.
+    CompilationFailed { fixture: String, diagnostics: Vec<Diagnostic> },
+}
+
+impl fmt::Display for SnapshotMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing { snapshot, .. } => writeln!(f, "missing snapshot '{}'", snapshot.display()),
+            Self::Changed { snapshot, diff, .. } => {
+                writeln!(f, "snapshot '{}' doesn't match the generated file:", snapshot.display())?;
+                write!(f, "{diff}")
+            }
+            Self::Stale { snapshot } => writeln!(f, "stale snapshot '{}' wasn't generated", snapshot.display()),
+            Self::CompilationFailed { fixture, diagnostics } => {
+                writeln!(f, "fixture '{fixture}' failed to compile:")?;
+                for diagnostic in diagnostics {
+                    let location = diagnostic.span().map_or(String::new(), |span| {
+                        format!(" ({}:{}:{})", span.file, span.start.row, span.start.col)
+                    });
+                    writeln!(f, "    [{}] {}{location}", diagnostic.code(), diagnostic.message())?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Compares the provided files against the snapshots in the provided directory, and returns any mismatches.
+/// Line endings are normalized before comparing, so snapshots can be checked out with either line ending.
+fn compare_snapshots(generated_files: &[GeneratedFile], snapshot_dir: &Path) -> Vec<SnapshotMismatch> {
+    let mut mismatches = Vec::new();
+    for file in generated_files {
+        let snapshot = snapshot_dir.join(&file.path);
+        let contents = file.contents.clone();
+        match fs::read_to_string(&snapshot) {
+            Ok(expected) => {
+                let expected = expected.replace("\r\n", "\n");
+                if expected != contents.replace("\r\n", "\n") {
+                    let diff = diff_lines(&expected, &contents);
+                    mismatches.push(SnapshotMismatch::Changed { snapshot, contents, diff });
+                }
+            }
+            Err(_) => mismatches.push(SnapshotMismatch::Missing { snapshot, contents }),
+        }
+    }
+
+    // Any snapshots that weren't generated are stale.
+    for snapshot in files_in(snapshot_dir) {
+        if !generated_files.iter().any(|file| snapshot_dir.join(&file.path) == snapshot) {
+            mismatches.push(SnapshotMismatch::Stale { snapshot });
+        }
+    }
+    mismatches
+}
+
+/// Updates the snapshots in the provided directory to fix the provided mismatches.
+fn update_snapshots(generated_files: &[GeneratedFile], snapshot_dir: &Path, mismatches: &[SnapshotMismatch]) {
+    for mismatch in mismatches {
+        match mismatch {
+            SnapshotMismatch::Missing { snapshot, contents } | SnapshotMismatch::Changed { snapshot, contents, .. } => {
+                if let Some(parent) = snapshot.parent() {
+                    fs::create_dir_all(parent).expect("failed to create snapshot directory");
+                }
+                fs::write(snapshot, contents).expect("failed to write snapshot");
+            }
+            SnapshotMismatch::Stale { snapshot } => fs::remove_file(snapshot).expect("failed to remove snapshot"),
+            SnapshotMismatch::CompilationFailed { .. } => {}
+        }
+    }
+
+    // If the fixture doesn't generate any files anymore, remove its (now empty) snapshot directory.
+    if generated_files.is_empty() && snapshot_dir.exists() {
+        let _ = fs::remove_dir_all(snapshot_dir);
+    }
+}
+
+/// Returns a line-by-line diff between the expected and actual strings. Removed lines are prefixed with '-', added
+/// lines with '+', and unchanged lines with a space. Only the unchanged lines near a change are included.
+fn diff_lines(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+
+    // Compute the length of the longest common subsequence of each pair of suffixes.
+    let mut lengths = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i][j] = match expected[i] == actual[j] {
+                true => lengths[i + 1][j + 1] + 1,
+                false => lengths[i + 1][j].max(lengths[i][j + 1]),
+            };
+        }
+    }
+
+    // Walk through the table to find which lines were removed, added, or left unchanged.
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            lines.push((' ', expected[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < expected.len() && (j == actual.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            lines.push(('-', expected[i]));
+            i += 1;
+        } else {
+            lines.push(('+', actual[j]));
+            j += 1;
+        }
+    }
+
+    // Only include unchanged lines which are close to a change.
+    let is_near_change = |index: usize| {
+        let start = index.saturating_sub(DIFF_CONTEXT_LINES);
+        let end = (index + DIFF_CONTEXT_LINES + 1).min(lines.len());
+        lines[start..end].iter().any(|(kind, _)| *kind != ' ')
+    };
+    let mut diff = String::new();
+    let mut skipped = false;
+    for (index, (kind, line)) in lines.iter().enumerate() {
+        if is_near_change(index) {
+            if skipped {
+                diff.push_str("    ...\n");
+                skipped = false;
+            }
+            writeln!(diff, "  {kind} {line}").unwrap();
+        } else {
+            skipped = true;
+        }
+    }
+    diff
+}
+
+/// Returns all the files in the provided directory and its sub-directories, or nothing if it doesn't exist.
+fn files_in(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for path in sorted_dir_entries(dir) {
+        match path.is_dir() {
+            true => files.extend(files_in(&path)),
+            false => files.push(path),
+        }
+    }
+    files
+}
+
+/// Returns the paths of the entries in the provided directory, sorted by name, or nothing if it can't be read.
+fn sorted_dir_entries(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    let mut paths = entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+fn is_slice_file(path: &Path) -> bool {
+    path.is_file() && path.extension().is_some_and(|extension| extension == "slice")
+}
@@ -9,13 +9,14 @@ pub struct Compress {
 }
 
 impl Compress {
-    pub fn parse_from(Unparsed { directive, args }: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+    pub fn parse_from(unparsed: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+        let Unparsed { directive, args, arg_spans } = unparsed;
         debug_assert_eq!(directive, Self::directive());
 
         check_that_arguments_were_provided(args, Self::directive(), span, diagnostics);
 
         let (mut compress_args, mut compress_return) = (false, false);
-        for arg in args {
+        for (arg, arg_span) in args.iter().zip(arg_spans) {
             match arg.as_str() {
                 "Args" => compress_args = true,
                 "Return" => compress_return = true,
@@ -24,7 +25,7 @@ impl Compress {
                         argument: arg.clone(),
                         directive: Self::directive().to_owned(),
                     })
-                    .set_span(span)
+                    .set_span(arg_span)
                     .add_note("'Args' and 'Return' are the only valid arguments", None)
                     .push_into(diagnostics);
                 }
@@ -0,0 +1,74 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::*;
+use std::time::Duration;
+
+/// Specifies the default deadline for invocations of an operation, or of every operation in an interface.
+/// Its argument is a positive integer followed by a unit: `ms`, `s`, `m`, or `h`. Ex: `[deadline("500ms")]`.
+#[derive(Debug)]
+pub struct Deadline {
+    pub timeout: Duration,
+}
+
+impl Deadline {
+    pub fn parse_from(unparsed: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+        let Unparsed { directive, args, arg_spans } = unparsed;
+        debug_assert_eq!(directive, Self::directive());
+
+        check_that_exactly_one_argument_was_provided(args, Self::directive(), span, diagnostics);
+
+        let mut timeout = Duration::ZERO;
+        if let (Some(arg), Some(arg_span)) = (args.first(), arg_spans.first()) {
+            match parse_duration(arg) {
+                Some(duration) => timeout = duration,
+                None => {
+                    Diagnostic::new(Error::ArgumentNotSupported {
+                        argument: arg.clone(),
+                        directive: Self::directive().to_owned(),
+                    })
+                    .set_span(arg_span)
+                    .add_note("deadlines must be a positive integer followed by 'ms', 's', 'm', or 'h'", None)
+                    .push_into(diagnostics);
+                }
+            }
+        }
+
+        Deadline { timeout }
+    }
+
+    pub fn validate_on(&self, applied_on: Attributables, span: &Span, diagnostics: &mut Diagnostics) {
+        if !matches!(applied_on, Attributables::Operation(_) | Attributables::Interface(_)) {
+            let note = "the deadline attribute can only be applied to interfaces and operations";
+            report_unexpected_attribute(self, span, Some(note), diagnostics);
+        }
+    }
+}
+
+/// Returns a string representation of the provided deadline, using the largest unit that represents it exactly.
+pub fn format_deadline(timeout: &Duration) -> String {
+    let millis = timeout.as_millis();
+    match millis {
+        _ if millis % 3_600_000 == 0 => format!("{}h", millis / 3_600_000),
+        _ if millis % 60_000 == 0 => format!("{}m", millis / 60_000),
+        _ if millis % 1_000 == 0 => format!("{}s", millis / 1_000),
+        _ => format!("{millis}ms"),
+    }
+}
+
+fn parse_duration(s: &str) -> Option<Duration> {
+    let s = s.trim();
+    let split_index = s.find(|c: char| !c.is_ascii_digit())?;
+    let (value, unit) = s.split_at(split_index);
+    let value: u64 = value.parse().ok().filter(|value| *value > 0)?;
+
+    let millis = match unit {
+        "ms" => value,
+        "s" => value.checked_mul(1_000)?,
+        "m" => value.checked_mul(60_000)?,
+        "h" => value.checked_mul(3_600_000)?,
+        _ => return None,
+    };
+    Some(Duration::from_millis(millis))
+}
+
+implement_attribute_kind_for!(Deadline, "deadline", false);
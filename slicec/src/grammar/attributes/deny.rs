@@ -0,0 +1,29 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::*;
+
+#[derive(Debug)]
+pub struct Deny {
+    pub denied_lints: Vec<String>,
+}
+
+impl Deny {
+    pub fn parse_from(unparsed: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+        let Unparsed { directive, args, arg_spans } = unparsed;
+        debug_assert_eq!(directive, Self::directive());
+
+        check_that_arguments_were_provided(args, Self::directive(), span, diagnostics);
+        check_lint_identifiers(args, arg_spans, Self::directive(), diagnostics);
+
+        let denied_lints = args.clone();
+        Deny { denied_lints }
+    }
+
+    pub fn validate_on(&self, applied_on: Attributables, span: &Span, diagnostics: &mut Diagnostics) {
+        if matches!(applied_on, Attributables::TypeRef(_)) {
+            report_unexpected_attribute(self, span, None, diagnostics);
+        }
+    }
+}
+
+implement_attribute_kind_for!(Deny, "deny", true);
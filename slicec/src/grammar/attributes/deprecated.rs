@@ -8,7 +8,7 @@ pub struct Deprecated {
 }
 
 impl Deprecated {
-    pub fn parse_from(Unparsed { directive, args }: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+    pub fn parse_from(Unparsed { directive, args, .. }: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
         debug_assert_eq!(directive, Self::directive());
 
         check_that_at_most_one_argument_was_provided(args, Self::directive(), span, diagnostics);
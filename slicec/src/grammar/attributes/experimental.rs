@@ -0,0 +1,36 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::*;
+
+/// Marks an element as experimental, meaning its API isn't stable yet, and may change or be removed in the future.
+/// It can optionally take a reason. Ex: `[experimental("this API is still being designed")]`.
+#[derive(Debug)]
+pub struct Experimental {
+    pub reason: Option<String>,
+}
+
+impl Experimental {
+    pub fn parse_from(Unparsed { directive, args, .. }: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+        debug_assert_eq!(directive, Self::directive());
+
+        check_that_at_most_one_argument_was_provided(args, Self::directive(), span, diagnostics);
+
+        let reason = args.first().cloned();
+        Experimental { reason }
+    }
+
+    pub fn validate_on(&self, applied_on: Attributables, span: &Span, diagnostics: &mut Diagnostics) {
+        match applied_on {
+            Attributables::Module(_) | Attributables::TypeRef(_) | Attributables::SliceFile(_) => {
+                report_unexpected_attribute(self, span, None, diagnostics);
+            }
+            Attributables::Parameter(_) => {
+                let note = "parameters cannot be individually marked as experimental";
+                report_unexpected_attribute(self, span, Some(note), diagnostics);
+            }
+            _ => {}
+        }
+    }
+}
+
+implement_attribute_kind_for!(Experimental, "experimental", false);
@@ -0,0 +1,51 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::*;
+
+#[derive(Debug)]
+pub struct Flags {
+    pub allow_overlap: bool,
+}
+
+impl Flags {
+    pub fn parse_from(unparsed: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+        let Unparsed { directive, args, arg_spans } = unparsed;
+        debug_assert_eq!(directive, Self::directive());
+
+        check_that_at_most_one_argument_was_provided(args, Self::directive(), span, diagnostics);
+
+        let mut allow_overlap = false;
+        for (arg, arg_span) in args.iter().zip(arg_spans) {
+            match arg.as_str() {
+                "AllowOverlap" => allow_overlap = true,
+                _ => {
+                    Diagnostic::new(Error::ArgumentNotSupported {
+                        argument: arg.clone(),
+                        directive: Self::directive().to_owned(),
+                    })
+                    .set_span(arg_span)
+                    .add_note("'AllowOverlap' is the only valid argument", None)
+                    .push_into(diagnostics);
+                }
+            }
+        }
+
+        Flags { allow_overlap }
+    }
+
+    pub fn validate_on(&self, applied_on: Attributables, span: &Span, diagnostics: &mut Diagnostics) {
+        match applied_on {
+            Attributables::Enum(enum_def) if enum_def.underlying.is_none() => {
+                let note = "the flags attribute can only be applied to enums with an underlying type";
+                report_unexpected_attribute(self, span, Some(note), diagnostics);
+            }
+            Attributables::Enum(_) => {}
+            _ => {
+                let note = "the flags attribute can only be applied to enums";
+                report_unexpected_attribute(self, span, Some(note), diagnostics);
+            }
+        }
+    }
+}
+
+implement_attribute_kind_for!(Flags, "flags", false);
@@ -2,14 +2,28 @@
 
 mod allow;
 mod compress;
+mod deadline;
+mod deny;
 mod deprecated;
+mod experimental;
+mod flags;
 mod oneway;
+mod removed;
+mod reserved_tags;
+mod since;
 mod sliced_format;
 
 pub use allow::*;
 pub use compress::*;
+pub use deadline::*;
+pub use deny::*;
 pub use deprecated::*;
+pub use experimental::*;
+pub use flags::*;
 pub use oneway::*;
+pub use removed::*;
+pub use reserved_tags::*;
+pub use since::*;
 pub use sliced_format::*;
 
 use super::Attributables;
@@ -17,7 +31,7 @@ use crate::diagnostics::{Diagnostic, Diagnostics, Error, Lint};
 use crate::slice_file::Span;
 use crate::utils::attribute_parsing_util::*;
 
-pub trait AttributeKind: std::fmt::Debug {
+pub trait AttributeKind: std::fmt::Debug + Send + Sync {
     fn is_repeatable(&self) -> bool;
     fn validate_on(&self, applied_on: Attributables, span: &Span, diagnostics: &mut Diagnostics);
     fn as_any(&self) -> &dyn std::any::Any;
@@ -59,6 +73,9 @@ pub use implement_attribute_kind_for;
 pub struct Unparsed {
     pub directive: String,
     pub args: Vec<String>,
+    /// The span of each argument, in the same order as `args`.
+    /// Diagnostics about a specific argument should use these, instead of the span of the entire attribute.
+    pub arg_spans: Vec<Span>,
 }
 
 impl AttributeKind for Unparsed {
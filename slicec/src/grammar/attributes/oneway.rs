@@ -6,7 +6,7 @@ use super::*;
 pub struct Oneway {}
 
 impl Oneway {
-    pub fn parse_from(Unparsed { directive, args }: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+    pub fn parse_from(Unparsed { directive, args, .. }: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
         debug_assert_eq!(directive, Self::directive());
 
         check_that_no_arguments_were_provided(args, Self::directive(), span, diagnostics);
@@ -17,7 +17,7 @@ impl Oneway {
     pub fn validate_on(&self, applied_on: Attributables, span: &Span, diagnostics: &mut Diagnostics) {
         if let Attributables::Operation(operation) = applied_on {
             // If the operation can return or throw data, it can't be marked oneway.
-            if !operation.return_type.is_empty() || !operation.exception_specification.is_empty() {
+            if !operation.return_type.is_empty() || operation.throws_anything() {
                 let note = "operations that return or throw data cannot be marked oneway";
                 report_unexpected_attribute(self, span, Some(note), diagnostics);
             }
@@ -0,0 +1,30 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::*;
+
+/// Specifies the version of the schema that an element was removed in. The element is still available in all earlier
+/// versions. Its argument is a version, like the argument of [since](Since). Ex: `[removed("3.0")]`.
+#[derive(Debug)]
+pub struct Removed {
+    pub version: String,
+}
+
+impl Removed {
+    pub fn parse_from(unparsed: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+        debug_assert_eq!(unparsed.directive, Self::directive());
+
+        let version = parse_version_argument(unparsed, Self::directive(), span, diagnostics);
+        Removed { version }
+    }
+
+    pub fn validate_on(&self, applied_on: Attributables, span: &Span, diagnostics: &mut Diagnostics) {
+        if matches!(
+            applied_on,
+            Attributables::Module(_) | Attributables::TypeRef(_) | Attributables::SliceFile(_),
+        ) {
+            report_unexpected_attribute(self, span, None, diagnostics);
+        }
+    }
+}
+
+implement_attribute_kind_for!(Removed, "removed", false);
@@ -0,0 +1,76 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::*;
+use std::ops::RangeInclusive;
+
+/// Reserves tags, so they can't be used by any of the tagged members of the element it's applied to.
+/// Each argument is either a single tag (`"7"`), a range that excludes its end (`"100..200"`), or a range that includes
+/// its end (`"100..=199"`). Ex: `[reservedTags("7", "100..200")]`.
+#[derive(Debug)]
+pub struct ReservedTags {
+    pub ranges: Vec<RangeInclusive<u32>>,
+}
+
+impl ReservedTags {
+    pub fn parse_from(unparsed: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+        let Unparsed { directive, args, arg_spans } = unparsed;
+        debug_assert_eq!(directive, Self::directive());
+
+        check_that_arguments_were_provided(args, Self::directive(), span, diagnostics);
+
+        let mut ranges = Vec::new();
+        for (arg, arg_span) in args.iter().zip(arg_spans) {
+            match parse_tag_range(arg) {
+                Some(range) => ranges.push(range),
+                None => {
+                    Diagnostic::new(Error::ArgumentNotSupported {
+                        argument: arg.clone(),
+                        directive: Self::directive().to_owned(),
+                    })
+                    .set_span(arg_span)
+                    .add_note("tags must be reserved as 'N', 'N..M', or 'N..=M', where 'N' is at most 'M'", None)
+                    .push_into(diagnostics);
+                }
+            }
+        }
+
+        ReservedTags { ranges }
+    }
+
+    pub fn validate_on(&self, applied_on: Attributables, span: &Span, diagnostics: &mut Diagnostics) {
+        match applied_on {
+            Attributables::Struct(_)
+            | Attributables::Class(_)
+            | Attributables::Exception(_)
+            | Attributables::Enumerator(_)
+            | Attributables::Operation(_) => {}
+            _ => {
+                let note = "tags can only be reserved on structs, classes, exceptions, enumerators, and operations";
+                report_unexpected_attribute(self, span, Some(note), diagnostics);
+            }
+        }
+    }
+}
+
+/// Returns a string representation of the provided range of tags. Ex: `7`, or `100..=199`.
+pub fn format_tag_range(range: &RangeInclusive<u32>) -> String {
+    match range.start() == range.end() {
+        true => range.start().to_string(),
+        false => format!("{}..={}", range.start(), range.end()),
+    }
+}
+
+fn parse_tag_range(s: &str) -> Option<RangeInclusive<u32>> {
+    let range = if let Some((start, end)) = s.split_once("..=") {
+        start.trim().parse().ok()?..=end.trim().parse().ok()?
+    } else if let Some((start, end)) = s.split_once("..") {
+        let end = end.trim().parse::<u32>().ok()?.checked_sub(1)?;
+        start.trim().parse().ok()?..=end
+    } else {
+        let tag = s.trim().parse().ok()?;
+        tag..=tag
+    };
+    (!range.is_empty()).then_some(range)
+}
+
+implement_attribute_kind_for!(ReservedTags, "reservedTags", true);
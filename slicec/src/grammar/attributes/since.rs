@@ -0,0 +1,35 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::*;
+
+/// Specifies the version of the schema that an element was introduced in.
+/// Its argument is a version made of 1 to 3 dot-separated numbers. Ex: `[since("2.1")]`.
+#[derive(Debug)]
+pub struct Since {
+    pub version: String,
+}
+
+impl Since {
+    pub fn parse_from(unparsed: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+        debug_assert_eq!(unparsed.directive, Self::directive());
+
+        let version = parse_version_argument(unparsed, Self::directive(), span, diagnostics);
+        Since { version }
+    }
+
+    /// Returns the components of this attribute's version. Ex: `"2.1"` returns `[2, 1]`.
+    pub fn version_components(&self) -> Vec<u64> {
+        self.version.split('.').filter_map(|component| component.parse().ok()).collect()
+    }
+
+    pub fn validate_on(&self, applied_on: Attributables, span: &Span, diagnostics: &mut Diagnostics) {
+        if matches!(
+            applied_on,
+            Attributables::Module(_) | Attributables::TypeRef(_) | Attributables::SliceFile(_),
+        ) {
+            report_unexpected_attribute(self, span, None, diagnostics);
+        }
+    }
+}
+
+implement_attribute_kind_for!(Since, "since", false);
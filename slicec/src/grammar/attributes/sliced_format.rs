@@ -1,6 +1,7 @@
 // Copyright (c) ZeroC, Inc.
 
 use super::*;
+use crate::grammar::{Contained, Encoding};
 
 #[derive(Debug)]
 pub struct SlicedFormat {
@@ -9,13 +10,14 @@ pub struct SlicedFormat {
 }
 
 impl SlicedFormat {
-    pub fn parse_from(Unparsed { directive, args }: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+    pub fn parse_from(unparsed: &Unparsed, span: &Span, diagnostics: &mut Diagnostics) -> Self {
+        let Unparsed { directive, args, arg_spans } = unparsed;
         debug_assert_eq!(directive, Self::directive());
 
         check_that_arguments_were_provided(args, Self::directive(), span, diagnostics);
 
         let (mut sliced_args, mut sliced_return) = (false, false);
-        for arg in args {
+        for (arg, arg_span) in args.iter().zip(arg_spans) {
             match arg.as_str() {
                 "Args" => sliced_args = true,
                 "Return" => sliced_return = true,
@@ -24,7 +26,7 @@ impl SlicedFormat {
                         argument: arg.clone(),
                         directive: Self::directive().to_owned(),
                     })
-                    .set_span(span)
+                    .set_span(arg_span)
                     .add_note("'Args' and 'Return' are the only valid arguments", None)
                     .push_into(diagnostics);
                 }
@@ -38,7 +40,13 @@ impl SlicedFormat {
     }
 
     pub fn validate_on(&self, applied_on: Attributables, span: &Span, diagnostics: &mut Diagnostics) {
-        if !matches!(applied_on, Attributables::Operation(_)) {
+        if let Attributables::Operation(operation) = applied_on {
+            // Classes (and so class slicing) are only supported in Slice1 mode.
+            if !operation.parent().supported_encodings().supports(Encoding::Slice1) {
+                let note = "the slicedFormat attribute can only be used in Slice1 mode";
+                report_unexpected_attribute(self, span, Some(note), diagnostics);
+            }
+        } else {
             let note = "the slicedFormat attribute can only be applied to operations";
             report_unexpected_attribute(self, span, Some(note), diagnostics);
         }
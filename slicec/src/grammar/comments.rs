@@ -10,9 +10,34 @@ pub struct DocComment {
     pub returns: Vec<ReturnsTag>,
     pub throws: Vec<ThrowsTag>,
     pub see: Vec<SeeTag>,
+    pub examples: Vec<ExampleTag>,
+    pub inherit_doc: Option<InheritDocTag>,
+    pub locales: Vec<LocaleTag>,
     pub span: Span,
 }
 
+impl DocComment {
+    /// Returns the overview that should be rendered for the provided locale (Ex: `fr` or `pt_BR`).
+    ///
+    /// If the comment has a `@locale` tag for exactly that locale, its message is returned. Otherwise, if the locale
+    /// has a region (Ex: `pt_BR`), a tag for just the language (`pt`) is used. If neither exists, this falls back to
+    /// the comment's overview. Locales are compared case-insensitively, and `-` and `_` are treated as equivalent.
+    pub fn localized_overview(&self, locale: &str) -> Option<&Message> {
+        let locale = normalize_locale(locale);
+        let language = locale.split('_').next().unwrap();
+
+        let find_tag = |target: &str| self.locales.iter().find(|tag| normalize_locale(&tag.locale.value) == target);
+        find_tag(&locale)
+            .or_else(|| find_tag(language))
+            .map(|tag| &tag.message)
+            .or(self.overview.as_ref())
+    }
+}
+
+fn normalize_locale(locale: &str) -> String {
+    locale.to_ascii_lowercase().replace('-', "_")
+}
+
 #[derive(Debug)]
 pub struct ParamTag {
     pub identifier: Identifier,
@@ -29,13 +54,16 @@ pub struct ReturnsTag {
 
 #[derive(Debug)]
 pub struct ThrowsTag {
-    pub thrown_type: TypeRefDefinition<Exception>,
+    /// The type documented by this tag. This is either an exception (Slice1) or an operation's error type (Slice2).
+    pub thrown_type: TypeRefDefinition<dyn Entity>,
+    /// The span of the thrown type's identifier. Ex: `MyError` in `@throws MyError: ...`.
+    pub thrown_type_span: Span,
     pub message: Message,
     pub span: Span,
 }
 
 impl ThrowsTag {
-    pub fn thrown_type(&self) -> Result<&Exception, &Identifier> {
+    pub fn thrown_type(&self) -> Result<&dyn Entity, &Identifier> {
         match &self.thrown_type {
             TypeRefDefinition::Patched(ptr) => Ok(ptr.borrow()),
             TypeRefDefinition::Unpatched(identifier) => Err(identifier),
@@ -46,6 +74,8 @@ impl ThrowsTag {
 #[derive(Debug)]
 pub struct SeeTag {
     pub link: TypeRefDefinition<dyn Entity>,
+    /// The span of the linked-to identifier. Ex: `MyStruct` in `@see MyStruct`.
+    pub link_span: Span,
     pub span: Span,
 }
 
@@ -58,9 +88,54 @@ impl SeeTag {
     }
 }
 
+/// An example invocation of an operation, of the form: `@example(name = value, ...): description`.
+///
+/// Each argument names one of the operation's parameters and provides a value for it. These are validated against the
+/// operation's signature, so code generators can safely render them as runnable examples in each language.
+#[derive(Debug)]
+pub struct ExampleTag {
+    pub arguments: Vec<ExampleArgument>,
+    pub message: Message,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct ExampleArgument {
+    pub identifier: Identifier,
+    pub value: ExampleValue,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum ExampleValue {
+    /// A literal value: an integer, string, or one of the boolean literals `true` and `false`.
+    Literal(ConstantValue),
+
+    /// A bare identifier, which must name an enumerator of the parameter's enum type.
+    Enumerator(Identifier),
+}
+
+/// Requests that an element's documentation be inherited from its base type, of the form: `@inheritdoc`.
+/// See [Commentable::effective_comment] for how the inherited documentation is resolved.
+#[derive(Debug)]
+pub struct InheritDocTag {
+    pub span: Span,
+}
+
+/// A translation of an element's overview into another language, of the form: `@locale <locale>: message`.
+/// The locale is an identifier like `fr` or `pt_BR`. See [DocComment::localized_overview].
+#[derive(Debug)]
+pub struct LocaleTag {
+    pub locale: Identifier,
+    pub message: Message,
+    pub span: Span,
+}
+
 #[derive(Debug)]
 pub struct LinkTag {
     pub link: TypeRefDefinition<dyn Entity>,
+    /// The span of the linked-to identifier. Ex: `MyStruct` in `{@link MyStruct}`.
+    pub link_span: Span,
     pub span: Span,
 }
 
@@ -95,6 +170,14 @@ implement_Element_for!(ThrowsTag, "throws tag");
 implement_Symbol_for!(ThrowsTag);
 implement_Element_for!(SeeTag, "see tag");
 implement_Symbol_for!(SeeTag);
+implement_Element_for!(ExampleTag, "example tag");
+implement_Symbol_for!(ExampleTag);
+implement_Element_for!(ExampleArgument, "example argument");
+implement_Symbol_for!(ExampleArgument);
+implement_Element_for!(InheritDocTag, "inheritdoc tag");
+implement_Symbol_for!(InheritDocTag);
+implement_Element_for!(LocaleTag, "locale tag");
+implement_Symbol_for!(LocaleTag);
 implement_Element_for!(LinkTag, "link tag");
 implement_Symbol_for!(LinkTag);
 implement_Element_for!(Message, "doc message");
@@ -1,5 +1,6 @@
 // Copyright (c) ZeroC, Inc.
 
+use super::super::attributes::*;
 use super::super::*;
 use crate::slice_file::Span;
 
@@ -10,11 +11,94 @@ pub struct Attribute {
 }
 
 impl Attribute {
-    pub fn new(directive: String, args: Vec<String>, span: Span) -> Self {
-        let kind = Box::new(attributes::Unparsed { directive, args });
+    /// Creates a new unparsed attribute. Each of its arguments is paired with the span it was written at.
+    pub fn new(directive: String, arguments: Vec<(String, Span)>, span: Span) -> Self {
+        let (args, arg_spans) = arguments.into_iter().unzip();
+        let kind = Box::new(attributes::Unparsed {
+            directive,
+            args,
+            arg_spans,
+        });
         Self { kind, span }
     }
 
+    /// Returns the arguments of this attribute, formatted the same way they'd be written in Slice.
+    /// If this attribute was parsed into a kind that's defined outside of slicec, this returns an empty vector.
+    pub fn arguments(&self) -> Vec<String> {
+        if let Some(unparsed) = self.downcast::<Unparsed>() {
+            return unparsed.args.clone();
+        }
+
+        if let Some(allow) = self.downcast::<Allow>() {
+            return allow.allowed_lints.clone();
+        }
+
+        if let Some(deny) = self.downcast::<Deny>() {
+            return deny.denied_lints.clone();
+        }
+
+        if let Some(compress) = self.downcast::<Compress>() {
+            let mut args = Vec::new();
+            if compress.compress_args {
+                args.push("Args".to_owned());
+            }
+            if compress.compress_return {
+                args.push("Return".to_owned());
+            }
+            return args;
+        }
+
+        if let Some(deadline) = self.downcast::<Deadline>() {
+            return vec![format_deadline(&deadline.timeout)];
+        }
+
+        if let Some(deprecated) = self.downcast::<Deprecated>() {
+            return deprecated.reason.iter().cloned().collect();
+        }
+
+        if let Some(experimental) = self.downcast::<Experimental>() {
+            return experimental.reason.iter().cloned().collect();
+        }
+
+        if let Some(flags) = self.downcast::<Flags>() {
+            let mut args = Vec::new();
+            if flags.allow_overlap {
+                args.push("AllowOverlap".to_owned());
+            }
+            return args;
+        }
+
+        if self.downcast::<Oneway>().is_some() {
+            return Vec::new();
+        }
+
+        if let Some(removed) = self.downcast::<Removed>() {
+            return vec![removed.version.clone()];
+        }
+
+        if let Some(reserved_tags) = self.downcast::<ReservedTags>() {
+            return reserved_tags.ranges.iter().map(format_tag_range).collect();
+        }
+
+        if let Some(since) = self.downcast::<Since>() {
+            return vec![since.version.clone()];
+        }
+
+        if let Some(sliced_format) = self.downcast::<SlicedFormat>() {
+            let mut args = Vec::new();
+            if sliced_format.sliced_args {
+                args.push("Args".to_owned());
+            }
+            if sliced_format.sliced_return {
+                args.push("Return".to_owned());
+            }
+            return args;
+        }
+
+        // This attribute was parsed into a kind that's defined outside of slicec, so its arguments can't be recovered.
+        Vec::new()
+    }
+
     pub fn downcast<T: AttributeKind + 'static>(&self) -> Option<&T> {
         self.kind.as_any().downcast_ref()
     }
@@ -11,6 +11,7 @@ pub struct Class {
     pub fields: Vec<WeakPtr<Field>>,
     pub compact_id: Option<Integer<u32>>,
     pub base: Option<TypeRef<Class>>,
+    pub visibility: Visibility,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
     pub comment: Option<DocComment>,
@@ -51,6 +52,15 @@ impl Type for Class {
         None
     }
 
+    fn min_wire_size(&self) -> u32 {
+        // Class instances are encoded by reference, and the smallest reference (a null or repeated instance) is 1 byte.
+        1
+    }
+
+    fn max_wire_size(&self) -> Option<u32> {
+        None
+    }
+
     fn is_class_type(&self) -> bool {
         true
     }
@@ -62,10 +72,21 @@ impl Type for Class {
     fn supported_encodings(&self) -> SupportedEncodings {
         self.supported_encodings.clone().unwrap()
     }
+
+    fn encoding_order(&self, _: Encoding) -> Vec<&Field> {
+        // Classes are encoded one slice at a time, starting with the most derived class's slice.
+        let mut fields = sort_for_encoding(self.fields());
+        let mut base = self.base_class();
+        while let Some(base_class) = base {
+            fields.extend(sort_for_encoding(base_class.fields()));
+            base = base_class.base_class();
+        }
+        fields
+    }
 }
 
 implement_Element_for!(Class, "class");
-implement_Attributable_for!(Class);
+implement_Attributable_for!(@Scoped Class);
 implement_Entity_for!(Class);
 implement_Commentable_for!(Class);
 implement_Container_for!(Class, Field, fields);
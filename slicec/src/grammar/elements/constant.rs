@@ -0,0 +1,30 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::super::*;
+use crate::slice_file::Span;
+use crate::utils::ptr_util::WeakPtr;
+
+#[derive(Debug)]
+pub struct Constant {
+    pub identifier: Identifier,
+    pub data_type: Option<TypeRef<Primitive>>,
+    pub expression: Expression,
+    pub visibility: Visibility,
+    pub scope: Scope,
+    pub attributes: Vec<WeakPtr<Attribute>>,
+    pub comment: Option<DocComment>,
+    pub span: Span,
+    pub(crate) value: Option<ConstantValue>,
+}
+
+impl Constant {
+    /// Returns the value of this constant, computed by evaluating its expression.
+    pub fn value(&self) -> &ConstantValue {
+        self.value.as_ref().unwrap()
+    }
+}
+
+implement_Element_for!(Constant, "constant");
+implement_Attributable_for!(@Scoped Constant);
+implement_Entity_for!(Constant);
+implement_Commentable_for!(Constant);
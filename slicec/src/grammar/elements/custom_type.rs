@@ -8,6 +8,7 @@ use crate::utils::ptr_util::WeakPtr;
 #[derive(Debug)]
 pub struct CustomType {
     pub identifier: Identifier,
+    pub visibility: Visibility,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
     pub comment: Option<DocComment>,
@@ -24,6 +25,15 @@ impl Type for CustomType {
         None
     }
 
+    fn min_wire_size(&self) -> u32 {
+        // Custom types are encoded by user-provided code, so we can't know anything about their size.
+        0
+    }
+
+    fn max_wire_size(&self) -> Option<u32> {
+        None
+    }
+
     fn is_class_type(&self) -> bool {
         false
     }
@@ -35,9 +45,13 @@ impl Type for CustomType {
     fn supported_encodings(&self) -> SupportedEncodings {
         self.supported_encodings.clone().unwrap()
     }
+
+    fn encoding_order(&self, _: Encoding) -> Vec<&Field> {
+        Vec::new()
+    }
 }
 
 implement_Element_for!(CustomType, "custom type");
-implement_Attributable_for!(CustomType);
+implement_Attributable_for!(@Scoped CustomType);
 implement_Entity_for!(CustomType);
 implement_Commentable_for!(CustomType);
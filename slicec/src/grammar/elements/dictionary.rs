@@ -22,6 +22,15 @@ impl Type for Dictionary {
         None
     }
 
+    fn min_wire_size(&self) -> u32 {
+        // An empty dictionary is encoded as just its size: `0`.
+        1
+    }
+
+    fn max_wire_size(&self) -> Option<u32> {
+        None
+    }
+
     fn is_class_type(&self) -> bool {
         false
     }
@@ -39,6 +48,10 @@ impl Type for Dictionary {
         encodings.intersect_with(&self.value_type.supported_encodings());
         encodings
     }
+
+    fn encoding_order(&self, _: Encoding) -> Vec<&Field> {
+        Vec::new()
+    }
 }
 
 implement_Element_for!(Dictionary, "dictionary");
@@ -1,6 +1,7 @@
 // Copyright (c) ZeroC, Inc.
 
 use super::super::*;
+use crate::grammar::attributes::Flags;
 use crate::slice_file::Span;
 use crate::supported_encodings::SupportedEncodings;
 use crate::utils::ptr_util::WeakPtr;
@@ -9,9 +10,11 @@ use crate::utils::ptr_util::WeakPtr;
 pub struct Enum {
     pub identifier: Identifier,
     pub enumerators: Vec<WeakPtr<Enumerator>>,
+    pub constants: Vec<WeakPtr<Constant>>,
     pub underlying: Option<TypeRef<Primitive>>,
     pub is_compact: bool,
     pub is_unchecked: bool,
+    pub visibility: Visibility,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
     pub comment: Option<DocComment>,
@@ -24,16 +27,82 @@ impl Enum {
         self.contents()
     }
 
-    pub fn get_min_max_values(&self) -> Option<(i128, i128)> {
-        let values = self.enumerators.iter().map(|enumerator| enumerator.borrow().value());
+    /// Returns the constants that are declared inside this enum.
+    pub fn constants(&self) -> Vec<&Constant> {
+        self.constants.iter().map(|ptr| ptr.borrow()).collect()
+    }
+
+    /// Returns true if this enum is marked with the `flags` attribute.
+    ///
+    /// Flag enums have unchecked semantics: any combination of their enumerators' bits is a valid value,
+    /// so decoders must accept values which don't correspond to a single enumerator.
+    pub fn is_flags(&self) -> bool {
+        self.has_attribute::<Flags>()
+    }
+
+    /// Returns the underlying type of this enum, if one was specified.
+    ///
+    /// Enums with an underlying type are encoded as a value of that type, regardless of whether they're checked,
+    /// unchecked, or compact. Enums without one are encoded as a discriminant, possibly followed by fields.
+    pub fn underlying_type(&self) -> Option<&Primitive> {
+        self.underlying.as_ref().map(TypeRef::definition)
+    }
+
+    /// Returns the (inclusive) range of values that this enum's enumerators can hold, as a `(min, max)` pair.
+    ///
+    /// For enums with an underlying type, this is the range of that type. Otherwise, enumerators must be positive and
+    /// fit within an `int32`. This returns `None` if the enum's underlying type isn't an integral type.
+    pub fn enumerator_bounds(&self) -> Option<(i128, i128)> {
+        match self.underlying_type() {
+            Some(underlying_type) => underlying_type.numeric_bounds(),
+            None => Some((0, i32::MAX as i128)),
+        }
+    }
 
+    pub fn get_min_max_values(&self) -> Option<(i128, i128)> {
         // There might not be a minimum value if the enum is empty.
-        values.clone().min().map(|min| {
-            (
-                min,
-                values.max().unwrap(), // A 'min' guarantees a 'max' exists too, so unwrap is safe.
-            )
-        })
+        // A 'min' guarantees a 'max' exists too, so unwrap is safe.
+        self.min_value().map(|min| (min, self.max_value().unwrap()))
+    }
+
+    /// Returns the smallest value held by any of this enum's enumerators, or `None` if the enum is empty.
+    pub fn min_value(&self) -> Option<i128> {
+        self.enumerators().into_iter().map(Enumerator::value).min()
+    }
+
+    /// Returns the largest value held by any of this enum's enumerators, or `None` if the enum is empty.
+    pub fn max_value(&self) -> Option<i128> {
+        self.enumerators().into_iter().map(Enumerator::value).max()
+    }
+
+    /// Returns true if this enum's enumerators hold every value between its minimum and maximum values (inclusive).
+    ///
+    /// Decoders for contiguous enums can validate and map values with a range check or lookup table,
+    /// instead of matching against each enumerator. Empty enums aren't considered contiguous.
+    pub fn is_contiguous(&self) -> bool {
+        // Enumerator values are validated to be unique, so counting them is enough to detect any gaps.
+        let count = self.enumerators.len() as i128;
+        self.get_min_max_values().is_some_and(|(min, max)| max - min + 1 == count)
+    }
+
+    /// Returns the enumerator of this enum which holds the provided value, if there is one.
+    pub fn enumerator_for_value(&self, value: i128) -> Option<&Enumerator> {
+        self.enumerators().into_iter().find(|enumerator| enumerator.value() == value)
+    }
+
+    /// Returns the minimum and maximum number of bytes that the provided enumerator of this enum can occupy on the
+    /// wire with the Slice2 encoding. This is only meaningful for enums without an underlying type.
+    fn enumerator_wire_size(&self, enumerator: &Enumerator) -> (u32, Option<u32>) {
+        // Enumerators are encoded as a discriminant (their value, which is always positive), then their fields.
+        let discriminant_size = varuint_wire_size(enumerator.value() as u64);
+        let (fields_min, fields_max) = members_wire_size(&enumerator.fields());
+
+        // Non-compact enums encode the size of each enumerator's fields before them, and a tag end marker after them.
+        let with_framing = |fields_size: u32| match self.is_compact {
+            true => discriminant_size + fields_size,
+            false => discriminant_size + varuint_wire_size(u64::from(fields_size + 1)) + fields_size + 1,
+        };
+        (with_framing(fields_min), fields_max.map(with_framing))
     }
 }
 
@@ -46,6 +115,31 @@ impl Type for Enum {
         self.underlying.as_ref().and_then(TypeRef::fixed_wire_size)
     }
 
+    fn min_wire_size(&self) -> u32 {
+        match &self.underlying {
+            Some(underlying) => underlying.min_wire_size(),
+            None => {
+                let sizes = self.enumerators().into_iter().map(|enumerator| self.enumerator_wire_size(enumerator).0);
+                sizes.min().unwrap_or(1) // Empty (unchecked) enums still encode a discriminant.
+            }
+        }
+    }
+
+    fn max_wire_size(&self) -> Option<u32> {
+        match &self.underlying {
+            Some(underlying) => underlying.max_wire_size(),
+
+            // Unchecked enums can hold enumerators we don't know about, which could have fields of any size.
+            None if self.is_unchecked => None,
+
+            None => {
+                let enumerators = self.enumerators().into_iter();
+                let mut sizes = enumerators.map(|enumerator| self.enumerator_wire_size(enumerator).1);
+                sizes.try_fold(0, |max, size| size.map(|size| u32::max(max, size)))
+            }
+        }
+    }
+
     fn is_class_type(&self) -> bool {
         false
     }
@@ -60,10 +154,14 @@ impl Type for Enum {
     fn supported_encodings(&self) -> SupportedEncodings {
         self.supported_encodings.clone().unwrap()
     }
+
+    fn encoding_order(&self, _: Encoding) -> Vec<&Field> {
+        Vec::new()
+    }
 }
 
 implement_Element_for!(Enum, "enum");
-implement_Attributable_for!(Enum);
+implement_Attributable_for!(@Scoped Enum);
 implement_Entity_for!(Enum);
 implement_Commentable_for!(Enum);
 implement_Container_for!(Enum, Enumerator, enumerators);
@@ -21,6 +21,7 @@ impl Enumerator {
         match &self.value {
             EnumeratorValue::Implicit(value) => *value,
             EnumeratorValue::Explicit(integer) => integer.value,
+            EnumeratorValue::Constant(_, value) => value.expect("enumerator value was not patched"),
         }
     }
 
@@ -36,6 +37,10 @@ impl Enumerator {
 pub enum EnumeratorValue {
     Implicit(i128),
     Explicit(Integer<i128>),
+
+    /// A reference to a constant, whose value is used as the enumerator's value. Ex: `A = MyConstants::BitOne`.
+    /// The value is `None` until the reference is resolved while patching the AST.
+    Constant(Identifier, Option<i128>),
 }
 
 impl Container<Field> for Enumerator {
@@ -10,6 +10,7 @@ pub struct Exception {
     pub identifier: Identifier,
     pub fields: Vec<WeakPtr<Field>>,
     pub base: Option<TypeRef<Exception>>,
+    pub visibility: Visibility,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
     pub comment: Option<DocComment>,
@@ -47,7 +48,7 @@ impl Exception {
 }
 
 implement_Element_for!(Exception, "exception");
-implement_Attributable_for!(Exception);
+implement_Attributable_for!(@Scoped Exception);
 implement_Entity_for!(Exception);
 implement_Commentable_for!(Exception);
 implement_Container_for!(Exception, Field, fields);
@@ -0,0 +1,108 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::super::*;
+use crate::slice_file::Span;
+use std::fmt;
+
+/// A constant expression, as written in a Slice file. Expressions are evaluated after parsing, once all the constants
+/// they might reference are known. The result of evaluating an expression is a [ConstantValue].
+#[derive(Debug)]
+pub struct Expression {
+    pub kind: ExpressionKind,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum ExpressionKind {
+    /// A literal value. Ex: `5`, `"hello"`, `true`.
+    Literal(ConstantValue),
+
+    /// A reference to another constant. Ex: `KiB`, `Foo::Bar`.
+    Reference(Identifier),
+
+    /// A unary operator applied to an operand. Ex: `-X`, `~X`.
+    Unary(UnaryOperator, Box<Expression>),
+
+    /// A binary operator applied to a pair of operands. Ex: `X * 64`.
+    Binary(Box<Expression>, BinaryOperator, Box<Expression>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnaryOperator {
+    /// Arithmetic negation: `-`.
+    Negate,
+    /// Bitwise complement (for integers) or logical negation (for booleans): `~`.
+    Not,
+}
+
+impl fmt::Display for UnaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Negate => "-",
+            Self::Not => "~",
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    ShiftLeft,
+    ShiftRight,
+    BitAnd,
+    BitOr,
+    BitXor,
+}
+
+impl fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Add => "+",
+            Self::Subtract => "-",
+            Self::Multiply => "*",
+            Self::Divide => "/",
+            Self::Remainder => "%",
+            Self::ShiftLeft => "<<",
+            Self::ShiftRight => ">>",
+            Self::BitAnd => "&",
+            Self::BitOr => "|",
+            Self::BitXor => "^",
+        })
+    }
+}
+
+/// The value of a constant, or of a constant expression.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConstantValue {
+    Integer(i128),
+    Bool(bool),
+    String(String),
+}
+
+impl ConstantValue {
+    /// Returns a string describing what kind of value this is. Ex: "integer".
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Integer(_) => "integer",
+            Self::Bool(_) => "bool",
+            Self::String(_) => "string",
+        }
+    }
+}
+
+impl fmt::Display for ConstantValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "\"{value}\""),
+        }
+    }
+}
+
+implement_Element_for!(Expression, "expression");
+implement_Symbol_for!(Expression);
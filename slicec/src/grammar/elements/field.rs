@@ -9,11 +9,13 @@ pub struct Field {
     pub identifier: Identifier,
     pub data_type: TypeRef,
     pub tag: Option<Integer<u32>>,
+    pub default: Option<Expression>,
     pub parent: WeakPtr<dyn Container<Field>>,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
     pub comment: Option<DocComment>,
     pub span: Span,
+    pub(crate) default_value: Option<ConstantValue>,
 }
 
 implement_Element_for!(Field, "field");
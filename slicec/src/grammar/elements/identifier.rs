@@ -2,10 +2,11 @@
 
 use super::super::*;
 use crate::slice_file::Span;
+use crate::utils::intern_util::InternedString;
 
 #[derive(Clone, Debug)]
 pub struct Identifier {
-    pub value: String,
+    pub value: InternedString,
     pub span: Span,
 }
 
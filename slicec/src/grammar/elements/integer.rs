@@ -10,5 +10,5 @@ pub struct Integer<T: Debug> {
     pub span: Span,
 }
 
-implement_Element_for!(Integer<T>, "integer", Debug);
-implement_Symbol_for!(Integer<T>, Debug);
+implement_Element_for!(Integer<T>, "integer", Debug + Send + Sync);
+implement_Symbol_for!(Integer<T>, Debug + Send + Sync);
@@ -4,12 +4,15 @@ use super::super::*;
 use crate::slice_file::Span;
 use crate::supported_encodings::SupportedEncodings;
 use crate::utils::ptr_util::WeakPtr;
+use std::collections::HashSet;
 
 #[derive(Debug)]
 pub struct Interface {
     pub identifier: Identifier,
     pub operations: Vec<WeakPtr<Operation>>,
+    pub constants: Vec<WeakPtr<Constant>>,
     pub bases: Vec<TypeRef<Interface>>,
+    pub visibility: Visibility,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
     pub comment: Option<DocComment>,
@@ -22,6 +25,11 @@ impl Interface {
         self.contents()
     }
 
+    /// Returns the constants that are declared inside this interface.
+    pub fn constants(&self) -> Vec<&Constant> {
+        self.constants.iter().map(|ptr| ptr.borrow()).collect()
+    }
+
     pub fn all_inherited_operations(&self) -> Vec<&Operation> {
         let mut operations = self
             .all_base_interfaces()
@@ -47,6 +55,42 @@ impl Interface {
         operations
     }
 
+    /// Returns all the operations of this interface, including inherited ones, in the same order as
+    /// [all_operations](Self::all_operations), along with which base interfaces each operation was inherited through,
+    /// and which other operations its name collides with.
+    pub fn all_operations_flattened(&self) -> Vec<FlattenedOperation<'_>> {
+        let operations = self.all_operations();
+        let base_operations = self
+            .base_interfaces()
+            .into_iter()
+            .map(|base| (base, base.all_operations()))
+            .collect::<Vec<_>>();
+
+        let mut flattened = Vec::new();
+        for &operation in &operations {
+            let inherited_through = base_operations
+                .iter()
+                .filter(|(_, inherited)| inherited.iter().any(|other| std::ptr::eq(*other, operation)))
+                .map(|(base, _)| *base)
+                .collect();
+
+            // Names which only differ by case can still collide after generators change the case of identifiers.
+            let identifier = operation.identifier().to_lowercase();
+            let collisions = operations
+                .iter()
+                .filter(|other| !std::ptr::eq(**other, operation) && other.identifier().to_lowercase() == identifier)
+                .copied()
+                .collect();
+
+            flattened.push(FlattenedOperation {
+                operation,
+                inherited_through,
+                collisions,
+            });
+        }
+        flattened
+    }
+
     pub fn base_interfaces(&self) -> Vec<&Interface> {
         self.bases.iter().map(TypeRef::definition).collect()
     }
@@ -62,14 +106,172 @@ impl Interface {
         all_bases
     }
 
+    /// Returns the C3 linearization of this interface's base interfaces (excluding this interface itself).
+    ///
+    /// Every base interface appears exactly once, after any interfaces that derive from it, and direct bases appear
+    /// in the order they were declared in. If the hierarchy has no consistent linearization (for example, if two
+    /// interfaces list the same bases in opposite orders), the left-most remaining candidate is picked at each step.
+    pub fn linearized_bases(&self) -> Vec<&Interface> {
+        let mut linearization = linearize(self);
+        linearization.remove(0);
+        linearization
+    }
+
+    /// Returns every user-defined type (structs, classes, enums, custom types, and exceptions) that can be reached from
+    /// this interface's operations, including inherited ones. This walks through the types of parameters, return
+    /// members, and thrown types, then transitively through the fields and bases of any types it finds.
+    ///
+    /// Type aliases are resolved to their underlying types. Each type is only returned once, in the order it was found.
+    pub fn referenced_types(&self) -> Vec<&dyn Entity> {
+        let mut collector = TypeCollector::default();
+        for operation in self.all_operations() {
+            for parameter in operation.parameters_and_return_members() {
+                collector.add_type_ref(parameter.data_type());
+            }
+            for thrown_type in operation.thrown_types() {
+                match thrown_type {
+                    ThrownType::Exception(exception_ref) => collector.add_exception(exception_ref.definition()),
+                    ThrownType::Error(type_ref) => collector.add_type_ref(type_ref),
+                }
+            }
+        }
+        collector.types
+    }
+
     // This intentionally shadows the trait method of the same name on `Type`.
     pub fn supported_encodings(&self) -> SupportedEncodings {
         self.supported_encodings.clone().unwrap()
     }
 }
 
+/// An operation of an interface, which was either declared in the interface, or inherited from one of its bases.
+/// See [Interface::all_operations_flattened].
+#[derive(Debug)]
+pub struct FlattenedOperation<'a> {
+    pub operation: &'a Operation,
+
+    /// The direct base interfaces that this operation was inherited through, in the order they were declared in.
+    /// This is empty for operations that were declared in the interface itself, and can contain multiple bases when
+    /// the operation is inherited through a diamond.
+    pub inherited_through: Vec<&'a Interface>,
+
+    /// The other operations of the interface whose names collide with this operation's name, ignoring case.
+    pub collisions: Vec<&'a Operation>,
+}
+
+impl FlattenedOperation<'_> {
+    /// Returns true if this operation was inherited from a base interface.
+    pub fn is_inherited(&self) -> bool {
+        !self.inherited_through.is_empty()
+    }
+}
+
+/// Collects the user-defined types that are transitively referenced by the type references it's given.
+#[derive(Default)]
+struct TypeCollector<'a> {
+    seen: HashSet<String>,
+    types: Vec<&'a dyn Entity>,
+}
+
+impl<'a> TypeCollector<'a> {
+    /// Records the provided type, returning true if this is the first time it's been seen.
+    fn add(&mut self, definition: &'a dyn Entity) -> bool {
+        let is_new = self.seen.insert(definition.parser_scoped_identifier());
+        if is_new {
+            self.types.push(definition);
+        }
+        is_new
+    }
+
+    fn add_fields(&mut self, fields: Vec<&'a Field>) {
+        for field in fields {
+            self.add_type_ref(field.data_type());
+        }
+    }
+
+    fn add_class(&mut self, class_def: &'a Class) {
+        if self.add(class_def) {
+            self.add_fields(class_def.fields());
+            if let Some(base) = class_def.base_class() {
+                self.add_class(base);
+            }
+        }
+    }
+
+    fn add_exception(&mut self, exception_def: &'a Exception) {
+        if self.add(exception_def) {
+            self.add_fields(exception_def.fields());
+            if let Some(base) = exception_def.base_exception() {
+                self.add_exception(base);
+            }
+        }
+    }
+
+    fn add_type_ref(&mut self, type_ref: &'a TypeRef) {
+        match type_ref.concrete_type() {
+            Types::Struct(struct_def) => {
+                if self.add(struct_def) {
+                    self.add_fields(struct_def.fields());
+                }
+            }
+            Types::Class(class_def) => self.add_class(class_def),
+            Types::Enum(enum_def) => {
+                if self.add(enum_def) {
+                    for enumerator in enum_def.enumerators() {
+                        self.add_fields(enumerator.fields());
+                    }
+                }
+            }
+            Types::CustomType(custom_type) => {
+                self.add(custom_type);
+            }
+            Types::ResultType(result_type) => {
+                self.add_type_ref(&result_type.success_type);
+                self.add_type_ref(&result_type.failure_type);
+            }
+            Types::Sequence(sequence) => self.add_type_ref(&sequence.element_type),
+            Types::Dictionary(dictionary) => {
+                self.add_type_ref(&dictionary.key_type);
+                self.add_type_ref(&dictionary.value_type);
+            }
+            Types::Primitive(_) => {}
+        }
+    }
+}
+
+/// Computes the C3 linearization of an interface, which starts with the interface itself.
+fn linearize(interface: &Interface) -> Vec<&Interface> {
+    fn is_same(a: &Interface, b: &Interface) -> bool {
+        std::ptr::eq(a, b)
+    }
+
+    let bases = interface.base_interfaces();
+    let mut sequences = bases.iter().map(|base| linearize(base)).collect::<Vec<_>>();
+    sequences.push(bases);
+
+    let mut linearization = vec![interface];
+    loop {
+        sequences.retain(|sequence| !sequence.is_empty());
+        let Some(first_sequence) = sequences.first() else { break };
+
+        // Pick the first head which doesn't appear in the tail of any sequence, falling back to the left-most head.
+        let is_in_a_tail = |candidate: &Interface| {
+            let mut tails = sequences.iter().flat_map(|sequence| &sequence[1..]);
+            tails.any(|other| is_same(other, candidate))
+        };
+        let mut heads = sequences.iter().map(|sequence| sequence[0]);
+        let next = heads.find(|head| !is_in_a_tail(head)).unwrap_or(first_sequence[0]);
+
+        linearization.push(next);
+        for sequence in &mut sequences {
+            sequence.retain(|other| !is_same(other, next));
+        }
+    }
+    linearization
+}
+
 implement_Element_for!(Interface, "interface");
-implement_Attributable_for!(Interface);
+implement_Attributable_for!(@Scoped Interface);
 implement_Entity_for!(Interface);
 implement_Commentable_for!(Interface);
 implement_Container_for!(Interface, Operation, operations);
@@ -3,11 +3,13 @@
 mod attribute;
 mod class;
 mod compilation_mode;
+mod constant;
 mod custom_type;
 mod dictionary;
 mod r#enum;
 mod enumerator;
 mod exception;
+mod expression;
 mod field;
 mod identifier;
 mod integer;
@@ -26,10 +28,12 @@ mod type_ref;
 pub use self::attribute::*;
 pub use self::class::*;
 pub use self::compilation_mode::*;
+pub use self::constant::*;
 pub use self::custom_type::*;
 pub use self::dictionary::*;
 pub use self::enumerator::*;
 pub use self::exception::*;
+pub use self::expression::*;
 pub use self::field::*;
 pub use self::identifier::*;
 pub use self::integer::*;
@@ -8,12 +8,21 @@ use crate::utils::ptr_util::WeakPtr;
 pub struct Module {
     pub identifier: Identifier,
     pub attributes: Vec<WeakPtr<Attribute>>,
+    pub comment: Option<DocComment>,
     pub span: Span,
 }
 
 impl Module {
-    /// If this module was declared using nested module syntax, this returns the entire nested identifier.
-    /// Otherwise this just returns the module's identifier.
+    /// Returns the doc comment applied to this module declaration, if there is one.
+    ///
+    /// Modules can be declared in multiple places (and in multiple files), and each declaration has its own comment.
+    /// Modules aren't [entities](Entity), so they don't implement [Commentable].
+    pub fn comment(&self) -> Option<&DocComment> {
+        self.comment.as_ref()
+    }
+
+    /// If this module was declared using nested module syntax (or within a module block), this returns the entire
+    /// nested identifier. Otherwise this just returns the module's identifier.
     pub fn nested_module_identifier(&self) -> &str {
         &self.identifier.value
     }
@@ -1,17 +1,24 @@
 // Copyright (c) ZeroC, Inc.
 
-use super::super::attributes::{Compress, SlicedFormat};
+use super::super::attributes::{Compress, Deadline, Oneway, SlicedFormat};
 use super::super::*;
 use crate::slice_file::Span;
 use crate::utils::ptr_util::WeakPtr;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct Operation {
     pub identifier: Identifier,
     pub parameters: Vec<WeakPtr<Parameter>>,
     pub return_type: Vec<WeakPtr<Parameter>>,
+    /// The exceptions this operation can throw. Only operations defined in Slice1 mode can throw exceptions.
     pub exception_specification: Vec<TypeRef<Exception>>,
-    pub is_idempotent: bool,
+    /// The type of error this operation can fail with. Only operations defined in Slice2 mode can have error types.
+    /// Unlike exceptions, errors are returned to the caller (like the failure type of a `Result`).
+    pub error_type: Option<TypeRef>,
+    pub modifiers: Vec<OperationModifier>,
+    /// The encoding used by this operation, which is determined by the compilation mode of the file it's defined in.
+    /// Operations inherited from an interface defined in a different file keep the encoding of that file.
     pub encoding: Encoding,
     pub parent: WeakPtr<Interface>,
     pub scope: Scope,
@@ -21,6 +28,38 @@ pub struct Operation {
 }
 
 impl Operation {
+    /// Returns true if this operation has a modifier of the specified kind.
+    pub fn has_modifier(&self, kind: OperationModifierKind) -> bool {
+        self.modifiers.iter().any(|modifier| modifier.kind == kind)
+    }
+
+    /// Returns true if this operation is idempotent. Readonly operations are implicitly idempotent.
+    pub fn is_idempotent(&self) -> bool {
+        self.has_modifier(OperationModifierKind::Idempotent) || self.is_readonly()
+    }
+
+    pub fn is_readonly(&self) -> bool {
+        self.has_modifier(OperationModifierKind::Readonly)
+    }
+
+    /// Returns true if this operation is oneway, either because of the `oneway` modifier, or the `oneway` attribute.
+    pub fn is_oneway(&self) -> bool {
+        self.has_modifier(OperationModifierKind::Oneway) || self.has_attribute::<Oneway>()
+    }
+
+    /// Returns the types this operation can fail with, as declared by its throws clause. This is the same for both
+    /// compilation modes: exceptions in Slice1 mode, or the operation's error type in Slice2 mode.
+    pub fn thrown_types(&self) -> Vec<ThrownType<'_>> {
+        let exceptions = self.exception_specification.iter().map(ThrownType::Exception);
+        let error_type = self.error_type.iter().map(ThrownType::Error);
+        exceptions.chain(error_type).collect()
+    }
+
+    /// Returns true if this operation has a throws clause (in either compilation mode).
+    pub fn throws_anything(&self) -> bool {
+        !self.exception_specification.is_empty() || self.error_type.is_some()
+    }
+
     pub fn parameters(&self) -> Vec<&Parameter> {
         self.parameters.iter().map(WeakPtr::borrow).collect()
     }
@@ -71,6 +110,16 @@ impl Operation {
             .collect()
     }
 
+    /// Returns the layout of this operation's non-streamed parameters, in the order they're encoded in.
+    pub fn parameter_layout(&self) -> MemberLayout<'_, Parameter> {
+        MemberLayout::new(self.non_streamed_parameters())
+    }
+
+    /// Returns the layout of this operation's non-streamed return members, in the order they're encoded in.
+    pub fn return_member_layout(&self) -> MemberLayout<'_, Parameter> {
+        MemberLayout::new(self.non_streamed_return_members())
+    }
+
     pub fn streamed_parameter(&self) -> Option<&Parameter> {
         // There can be only 1 streamed parameter and it must be the last parameter.
         self.parameters()
@@ -95,6 +144,14 @@ impl Operation {
         self.find_attribute::<Compress>().is_some_and(|a| a.compress_return)
     }
 
+    /// Returns the default deadline for invocations of this operation, if one was specified with a `deadline`
+    /// attribute. Deadlines applied to an operation take precedence over deadlines applied to its interface.
+    pub fn deadline(&self) -> Option<Duration> {
+        let deadline = self.find_attribute::<Deadline>();
+        let deadline = deadline.or_else(|| self.parent().find_attribute::<Deadline>());
+        deadline.map(|deadline| deadline.timeout)
+    }
+
     pub fn slice_classes_in_arguments(&self) -> bool {
         self.find_attribute::<SlicedFormat>().is_some_and(|a| a.sliced_args)
     }
@@ -104,8 +161,69 @@ impl Operation {
     }
 }
 
+/// A type that an operation can fail with, as declared in its throws clause.
+#[derive(Clone, Copy, Debug)]
+pub enum ThrownType<'a> {
+    /// An exception, which is thrown by an operation defined in Slice1 mode.
+    Exception(&'a TypeRef<Exception>),
+
+    /// An error type, which is returned by an operation defined in Slice2 mode.
+    Error(&'a TypeRef),
+}
+
+impl ThrownType<'_> {
+    /// Returns the span of the type reference in the operation's throws clause.
+    pub fn span(&self) -> &Span {
+        match self {
+            Self::Exception(type_ref) => type_ref.span(),
+            Self::Error(type_ref) => type_ref.span(),
+        }
+    }
+
+    /// Returns the type string of the thrown type. Ex: `MyException` or `Sequence<string>`.
+    pub fn type_string(&self) -> String {
+        match self {
+            Self::Exception(type_ref) => type_ref.identifier().to_owned(),
+            Self::Error(type_ref) => type_ref.type_string(),
+        }
+    }
+}
+
+/// A modifier keyword which was applied to an operation, along with where it was applied.
+#[derive(Debug)]
+pub struct OperationModifier {
+    pub kind: OperationModifierKind,
+    pub span: Span,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OperationModifierKind {
+    /// Marks the operation as idempotent; invoking it multiple times has the same effect as invoking it once.
+    Idempotent,
+
+    /// Marks the operation as readonly; invoking it doesn't modify any state. This implies `Idempotent`.
+    Readonly,
+
+    /// Marks the operation as oneway (fire-and-forget); invocations don't wait for a response.
+    /// Oneway operations cannot return or throw anything.
+    Oneway,
+}
+
+impl OperationModifierKind {
+    /// Returns the keyword that is used to apply this modifier in Slice.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Self::Idempotent => "idempotent",
+            Self::Readonly => "readonly",
+            Self::Oneway => "oneway",
+        }
+    }
+}
+
 implement_Element_for!(Operation, "operation");
 implement_Attributable_for!(@Contained Operation);
 implement_Entity_for!(Operation);
 implement_Commentable_for!(Operation);
 implement_Contained_for!(Operation, Interface);
+implement_Element_for!(OperationModifier, "operation modifier");
+implement_Symbol_for!(OperationModifier);
@@ -10,10 +10,22 @@ pub struct Parameter {
     pub data_type: TypeRef,
     pub tag: Option<Integer<u32>>,
     pub is_streamed: bool,
+    pub default: Option<Expression>,
     pub parent: WeakPtr<Operation>,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
     pub span: Span,
+    pub(crate) default_value: Option<ConstantValue>,
+}
+
+impl Parameter {
+    /// Returns the number of bytes that each element of this parameter's stream occupies on the wire, if this
+    /// parameter is streamed and its type is of fixed size. This lets generated code decode streams in chunks.
+    ///
+    /// If this parameter isn't streamed, or the streamed type is of variable size, this returns `None`.
+    pub fn stream_element_size(&self) -> Option<u32> {
+        self.is_streamed.then(|| self.data_type.fixed_wire_size()).flatten()
+    }
 }
 
 implement_Element_for!(Parameter, "parameter");
@@ -21,6 +21,14 @@ pub enum Primitive {
     Float32,
     Float64,
     String,
+    Uuid,
+
+    /// A point in time, encoded as an `int64` holding the number of nanoseconds since the Unix epoch (UTC).
+    Timestamp,
+
+    /// A span of time, encoded as an `int64` holding a (possibly negative) number of nanoseconds.
+    Duration,
+
     AnyClass,
 }
 
@@ -116,10 +124,31 @@ impl Type for Primitive {
             Self::Float32 => Some(4),
             Self::Float64 => Some(8),
             Self::String => None,
+            Self::Uuid => Some(16),
+            Self::Timestamp => Some(8),
+            Self::Duration => Some(8),
             Self::AnyClass => None,
         }
     }
 
+    fn min_wire_size(&self) -> u32 {
+        match self {
+            // Variable size integers can be encoded on a single byte, and the smallest string is the empty string.
+            Self::VarInt32 | Self::VarUInt32 | Self::VarInt62 | Self::VarUInt62 | Self::String => 1,
+            // The smallest class reference (a null or repeated instance) is 1 byte.
+            Self::AnyClass => 1,
+            _ => self.fixed_wire_size().unwrap(),
+        }
+    }
+
+    fn max_wire_size(&self) -> Option<u32> {
+        match self {
+            Self::VarInt32 | Self::VarUInt32 | Self::VarInt62 | Self::VarUInt62 => Some(8),
+            Self::String | Self::AnyClass => None,
+            _ => self.fixed_wire_size(),
+        }
+    }
+
     fn is_class_type(&self) -> bool {
         matches!(self, Self::AnyClass)
     }
@@ -142,6 +171,9 @@ impl Type for Primitive {
             Self::Float32 => Some(TagFormat::F4),
             Self::Float64 => Some(TagFormat::F8),
             Self::String => Some(TagFormat::OptimizedVSize),
+            Self::Uuid => Some(TagFormat::VSize),
+            Self::Timestamp => Some(TagFormat::F8),
+            Self::Duration => Some(TagFormat::F8),
             Self::AnyClass => Some(TagFormat::Class),
         }
     }
@@ -164,9 +196,16 @@ impl Type for Primitive {
             Self::Float32 => vec![Encoding::Slice1, Encoding::Slice2],
             Self::Float64 => vec![Encoding::Slice1, Encoding::Slice2],
             Self::String => vec![Encoding::Slice1, Encoding::Slice2],
+            Self::Uuid => vec![Encoding::Slice2],
+            Self::Timestamp => vec![Encoding::Slice1, Encoding::Slice2],
+            Self::Duration => vec![Encoding::Slice1, Encoding::Slice2],
             Self::AnyClass => vec![Encoding::Slice1],
         })
     }
+
+    fn encoding_order(&self, _: Encoding) -> Vec<&Field> {
+        Vec::new()
+    }
 }
 
 impl Element for Primitive {
@@ -188,6 +227,9 @@ impl Element for Primitive {
             Self::Float32 => "float32",
             Self::Float64 => "float64",
             Self::String => "string",
+            Self::Uuid => "uuid",
+            Self::Timestamp => "timestamp",
+            Self::Duration => "duration",
             Self::AnyClass => "AnyClass",
         }
     }
@@ -22,6 +22,17 @@ impl Type for ResultType {
         None
     }
 
+    fn min_wire_size(&self) -> u32 {
+        // Results are encoded as a 1 byte discriminant, followed by either a success or failure value.
+        1 + u32::min(self.success_type.min_wire_size(), self.failure_type.min_wire_size())
+    }
+
+    fn max_wire_size(&self) -> Option<u32> {
+        let success_max = self.success_type.max_wire_size()?;
+        let failure_max = self.failure_type.max_wire_size()?;
+        Some(1 + u32::max(success_max, failure_max))
+    }
+
     fn is_class_type(&self) -> bool {
         false
     }
@@ -36,6 +47,10 @@ impl Type for ResultType {
         encodings.disable(Encoding::Slice1);
         encodings
     }
+
+    fn encoding_order(&self, _: Encoding) -> Vec<&Field> {
+        Vec::new()
+    }
 }
 
 implement_Element_for!(ResultType, "result");
@@ -40,6 +40,15 @@ impl Type for Sequence {
         None
     }
 
+    fn min_wire_size(&self) -> u32 {
+        // An empty sequence is encoded as just its size: `0`.
+        1
+    }
+
+    fn max_wire_size(&self) -> Option<u32> {
+        None
+    }
+
     fn is_class_type(&self) -> bool {
         false
     }
@@ -55,6 +64,10 @@ impl Type for Sequence {
     fn supported_encodings(&self) -> SupportedEncodings {
         self.element_type.supported_encodings()
     }
+
+    fn encoding_order(&self, _: Encoding) -> Vec<&Field> {
+        Vec::new()
+    }
 }
 
 implement_Element_for!(Sequence, "sequence");
@@ -9,7 +9,9 @@ use crate::utils::ptr_util::WeakPtr;
 pub struct Struct {
     pub identifier: Identifier,
     pub fields: Vec<WeakPtr<Field>>,
+    pub constants: Vec<WeakPtr<Constant>>,
     pub is_compact: bool,
+    pub visibility: Visibility,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
     pub comment: Option<DocComment>,
@@ -21,6 +23,11 @@ impl Struct {
     pub fn fields(&self) -> Vec<&Field> {
         self.contents()
     }
+
+    /// Returns the constants that are declared inside this struct.
+    pub fn constants(&self) -> Vec<&Constant> {
+        self.constants.iter().map(|ptr| ptr.borrow()).collect()
+    }
 }
 
 impl Type for Struct {
@@ -40,6 +47,16 @@ impl Type for Struct {
             .map(|size: u32| size + u32::from(!self.is_compact))
     }
 
+    fn min_wire_size(&self) -> u32 {
+        let (min, _) = members_wire_size(&self.fields());
+        min + u32::from(!self.is_compact)
+    }
+
+    fn max_wire_size(&self) -> Option<u32> {
+        let (_, max) = members_wire_size(&self.fields());
+        max.map(|size| size + u32::from(!self.is_compact))
+    }
+
     fn is_class_type(&self) -> bool {
         false
     }
@@ -55,10 +72,14 @@ impl Type for Struct {
     fn supported_encodings(&self) -> SupportedEncodings {
         self.supported_encodings.clone().unwrap()
     }
+
+    fn encoding_order(&self, _: Encoding) -> Vec<&Field> {
+        sort_for_encoding(self.fields())
+    }
 }
 
 implement_Element_for!(Struct, "struct");
-implement_Attributable_for!(Struct);
+implement_Attributable_for!(@Scoped Struct);
 implement_Entity_for!(Struct);
 implement_Commentable_for!(Struct);
 implement_Container_for!(Struct, Field, fields);
@@ -9,6 +9,7 @@ use crate::utils::ptr_util::WeakPtr;
 pub struct TypeAlias {
     pub identifier: Identifier,
     pub underlying: TypeRef,
+    pub visibility: Visibility,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
     pub comment: Option<DocComment>,
@@ -31,6 +32,14 @@ impl Type for TypeAlias {
         self.underlying.fixed_wire_size()
     }
 
+    fn min_wire_size(&self) -> u32 {
+        self.underlying.min_wire_size()
+    }
+
+    fn max_wire_size(&self) -> Option<u32> {
+        self.underlying.max_wire_size()
+    }
+
     fn is_class_type(&self) -> bool {
         self.underlying.is_class_type()
     }
@@ -42,9 +51,13 @@ impl Type for TypeAlias {
     fn supported_encodings(&self) -> SupportedEncodings {
         self.supported_encodings.clone().unwrap()
     }
+
+    fn encoding_order(&self, encoding: Encoding) -> Vec<&Field> {
+        self.underlying.encoding_order(encoding)
+    }
 }
 
 implement_Element_for!(TypeAlias, "type alias");
-implement_Attributable_for!(TypeAlias);
+implement_Attributable_for!(@Scoped TypeAlias);
 implement_Entity_for!(TypeAlias);
 implement_Commentable_for!(TypeAlias);
@@ -50,6 +50,7 @@ impl<T: Element + ?Sized> TypeRef<T> {
 
 impl<T: Type + ?Sized> TypeRef<T> {
     // This intentionally shadows the trait method of the same name on `Type`.
+    // Type strings are built on demand instead of being stored, so unlike identifiers and scopes, they aren't interned.
     pub fn type_string(&self) -> String {
         let mut s = self.definition().type_string();
         if self.is_optional {
@@ -58,6 +59,15 @@ impl<T: Type + ?Sized> TypeRef<T> {
         s
     }
 
+    // This intentionally shadows the trait method of the same name on `Type`.
+    // Optional types can never be used as dictionary keys.
+    pub fn is_valid_dictionary_key(&self) -> Result<(), InvalidKeyReason> {
+        if self.is_optional {
+            return Err(InvalidKeyReason::Optional);
+        }
+        T::is_valid_dictionary_key(self)
+    }
+
     // This intentionally shadows the trait method of the same name on `Type`.
     pub fn fixed_wire_size(&self) -> Option<u32> {
         if self.is_optional {
@@ -66,6 +76,21 @@ impl<T: Type + ?Sized> TypeRef<T> {
             T::fixed_wire_size(self)
         }
     }
+
+    // This intentionally shadows the trait method of the same name on `Type`.
+    // Optional types can always be omitted, in which case they're only a bit in their container's bit sequence.
+    pub fn min_wire_size(&self) -> u32 {
+        if self.is_optional {
+            0
+        } else {
+            T::min_wire_size(self)
+        }
+    }
+
+    // This intentionally shadows the trait method of the same name on `Type`.
+    pub fn max_wire_size(&self) -> Option<u32> {
+        T::max_wire_size(self)
+    }
 }
 
 impl<T: Element + ?Sized> std::ops::Deref for TypeRef<T> {
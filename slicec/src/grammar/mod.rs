@@ -4,6 +4,7 @@ pub mod attributes;
 
 mod comments;
 mod elements;
+mod printer;
 mod traits;
 mod util;
 mod wrappers;
@@ -13,6 +14,7 @@ mod wrappers;
 pub use self::attributes::AttributeKind;
 pub use self::comments::*;
 pub use self::elements::*;
+pub use self::printer::*;
 pub use self::traits::*;
 pub use self::util::*;
 pub use self::wrappers::*;
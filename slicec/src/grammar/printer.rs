@@ -0,0 +1,609 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains the logic for re-emitting Slice source code from the AST.
+//!
+//! The emitted code is in the canonical Slice style, and isn't guaranteed to match how the code was originally
+//! written. Notably, references to named types and doc comment links are emitted relative to their enclosing module
+//! when possible (and fully qualified otherwise), and references to type aliases are emitted as their underlying
+//! types, since aliases are resolved while patching the AST. Non-doc comments aren't part of the AST, and so aren't
+//! emitted (see [trivia](crate::trivia) for preserving them).
+
+use super::*;
+use crate::parsers::{is_identifier_continue, is_identifier_start};
+use crate::slice_file::SliceFile;
+use crate::utils::ptr_util::WeakPtr;
+
+const INDENT: &str = "    ";
+
+/// Types that can be re-emitted as Slice source code.
+pub trait ToSliceString {
+    /// Returns the Slice source code for this element, including its doc comment and attributes (if it has any).
+    /// The returned string doesn't end with a newline, except for [SliceFile]s, which are emitted in full.
+    fn to_slice_string(&self) -> String;
+}
+
+impl ToSliceString for SliceFile {
+    fn to_slice_string(&self) -> String {
+        let mut header = Vec::new();
+        if let Some(comment) = &self.comment {
+            header.extend(doc_comment_lines(comment).into_iter().map(|line| format_comment_line("//!", &line)));
+        }
+        if let Some(mode) = &self.mode {
+            header.push(mode.to_slice_string());
+        }
+        for attribute in &self.attributes {
+            header.push(format!("[[{}]]", attribute.borrow().to_slice_string()));
+        }
+
+        // Sort the file's definitions by which module they're defined in.
+        let mut module_contents = self
+            .modules
+            .iter()
+            .map(|module_ptr| (module_ptr.borrow(), Vec::new()))
+            .collect::<Vec<_>>();
+        for definition in &self.contents {
+            let module = definition.borrow().get_module();
+            if let Some((_, contents)) = module_contents.iter_mut().find(|(m, _)| std::ptr::eq(*m, module)) {
+                contents.push(definition.to_slice_string());
+            }
+        }
+
+        // A file's top-level module is emitted as a declaration, and any module blocks are emitted after it.
+        // Module blocks are emitted one after another, instead of nested within each other, so we skip any which
+        // are empty, as long as they don't have a doc comment or attributes that would be lost by doing so.
+        // If every other module is nested within the file's first module, we emit that one as the top-level module.
+        // Even if it was declared as a block, this is equivalent, since nothing else can be declared outside it.
+        let file_module = self.modules.first().map(|module_ptr| module_ptr.borrow()).filter(|first_module| {
+            let prefix = format!("{}::", first_module.nested_module_identifier());
+            let mut other_modules = self.modules[1..].iter().map(|module_ptr| module_ptr.borrow());
+            other_modules.all(|module| module.nested_module_identifier().starts_with(&prefix))
+        });
+        let mut sections = Vec::new();
+        for (module, contents) in module_contents {
+            if file_module.is_some_and(|file_module| std::ptr::eq(file_module, module)) {
+                sections.push(module.to_slice_string());
+                sections.extend(contents);
+            } else if !contents.is_empty() || module.comment.is_some() || !module.attributes.is_empty() {
+                // Module blocks declared inside the file's module are relative to it, so we strip its identifier.
+                let identifier = match file_module {
+                    Some(file_module) => {
+                        let prefix = format!("{}::", file_module.nested_module_identifier());
+                        module.identifier.value.strip_prefix(&prefix).unwrap_or(&module.identifier.value)
+                    }
+                    None => &module.identifier.value,
+                };
+                let prelude = format_prelude(module.comment.as_ref(), module.attributes());
+                let body = format_body(&contents.join("\n\n"));
+                sections.push(format!("{prelude}module {identifier}{body}"));
+            }
+        }
+
+        if !header.is_empty() {
+            sections.insert(0, header.join("\n"));
+        }
+        match sections.is_empty() {
+            true => String::new(),
+            false => sections.join("\n\n") + "\n",
+        }
+    }
+}
+
+impl ToSliceString for Definition {
+    fn to_slice_string(&self) -> String {
+        match self {
+            Definition::Struct(struct_ptr) => struct_ptr.borrow().to_slice_string(),
+            Definition::Class(class_ptr) => class_ptr.borrow().to_slice_string(),
+            Definition::Exception(exception_ptr) => exception_ptr.borrow().to_slice_string(),
+            Definition::Interface(interface_ptr) => interface_ptr.borrow().to_slice_string(),
+            Definition::Enum(enum_ptr) => enum_ptr.borrow().to_slice_string(),
+            Definition::CustomType(custom_type_ptr) => custom_type_ptr.borrow().to_slice_string(),
+            Definition::TypeAlias(type_alias_ptr) => type_alias_ptr.borrow().to_slice_string(),
+            Definition::Constant(constant_ptr) => constant_ptr.borrow().to_slice_string(),
+        }
+    }
+}
+
+impl ToSliceString for FileCompilationMode {
+    fn to_slice_string(&self) -> String {
+        format!("mode = {}", self.version)
+    }
+}
+
+impl ToSliceString for Module {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment.as_ref(), self.attributes());
+        format!("{prelude}module {}", self.identifier.value)
+    }
+}
+
+impl ToSliceString for Struct {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment(), self.attributes());
+        let compact = if self.is_compact { "compact " } else { "" };
+        let members = self.fields.iter().map(|field| field.borrow().to_slice_string());
+        let constants = self.constants.iter().map(|constant| constant.borrow().to_slice_string());
+        let body = format_body(&members.chain(constants).collect::<Vec<_>>().join("\n"));
+        let visibility = format_visibility(self.visibility);
+        format!("{prelude}{visibility}{compact}struct {}{body}", self.identifier())
+    }
+}
+
+impl ToSliceString for Class {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment(), self.attributes());
+        let compact_id = self.compact_id.as_ref().map_or(String::new(), |id| format!("({})", id.value));
+        let base = self.base.as_ref().map_or(String::new(), |base| format!(" : {}", format_named_type_ref(base)));
+        let members = self.fields.iter().map(|field| field.borrow().to_slice_string());
+        let body = format_body(&members.collect::<Vec<_>>().join("\n"));
+        let visibility = format_visibility(self.visibility);
+        format!("{prelude}{visibility}class {}{compact_id}{base}{body}", self.identifier())
+    }
+}
+
+impl ToSliceString for Exception {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment(), self.attributes());
+        let base = self.base.as_ref().map_or(String::new(), |base| format!(" : {}", format_named_type_ref(base)));
+        let members = self.fields.iter().map(|field| field.borrow().to_slice_string());
+        let body = format_body(&members.collect::<Vec<_>>().join("\n"));
+        let visibility = format_visibility(self.visibility);
+        format!("{prelude}{visibility}exception {}{base}{body}", self.identifier())
+    }
+}
+
+impl ToSliceString for Field {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment(), self.attributes());
+        let tag = format_tag(self.tag.as_ref());
+        let default = format_default(self.default.as_ref());
+        format!("{prelude}{tag}{}: {}{default}", self.identifier(), self.data_type.to_slice_string())
+    }
+}
+
+impl ToSliceString for Interface {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment(), self.attributes());
+        let bases = match self.bases.is_empty() {
+            true => String::new(),
+            false => format!(" : {}", self.bases.iter().map(format_named_type_ref).collect::<Vec<_>>().join(", ")),
+        };
+        let members = self.operations.iter().map(|operation| operation.borrow().to_slice_string());
+        let constants = self.constants.iter().map(|constant| constant.borrow().to_slice_string());
+        let body = format_body(&members.chain(constants).collect::<Vec<_>>().join("\n"));
+        let visibility = format_visibility(self.visibility);
+        format!("{prelude}{visibility}interface {}{bases}{body}", self.identifier())
+    }
+}
+
+impl ToSliceString for Operation {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment(), self.attributes());
+        let modifiers = self
+            .modifiers
+            .iter()
+            .map(|modifier| match modifier.kind {
+                OperationModifierKind::Idempotent => "idempotent ",
+                OperationModifierKind::Readonly => "readonly ",
+                OperationModifierKind::Oneway => "oneway ",
+            })
+            .collect::<String>();
+        let parameters = format_list(self.parameters.iter().map(|p| p.borrow().to_slice_string()).collect());
+
+        // Nameless return types are stored as a single parameter, which we emit without its (synthesized) name.
+        let return_type = match self.return_type.as_slice() {
+            [] => String::new(),
+            [return_type] => {
+                let return_type = return_type.borrow();
+                let tag = format_tag(return_type.tag.as_ref());
+                let stream = if return_type.is_streamed { "stream " } else { "" };
+                format!(" -> {tag}{stream}{}", return_type.data_type.to_slice_string())
+            }
+            return_tuple => {
+                let elements = return_tuple.iter().map(|p| p.borrow().to_slice_string()).collect();
+                format!(" -> ({})", format_list(elements))
+            }
+        };
+
+        let mut thrown_types = self.exception_specification.iter().map(format_named_type_ref).collect::<Vec<_>>();
+        thrown_types.extend(self.error_type.iter().map(ToSliceString::to_slice_string));
+        let exception_specification = match thrown_types.as_slice() {
+            [] => String::new(),
+            [thrown_type] => format!(" throws {thrown_type}"),
+            _ => format!(" throws ({})", thrown_types.join(", ")),
+        };
+
+        let identifier = self.identifier();
+        format!("{prelude}{modifiers}{identifier}({parameters}){return_type}{exception_specification}")
+    }
+}
+
+impl ToSliceString for Parameter {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(None, self.attributes());
+        let tag = format_tag(self.tag.as_ref());
+        let stream = if self.is_streamed { "stream " } else { "" };
+        let default = format_default(self.default.as_ref());
+        format!("{prelude}{tag}{}: {stream}{}{default}", self.identifier(), self.data_type.to_slice_string())
+    }
+}
+
+impl ToSliceString for Enum {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment(), self.attributes());
+        let compact = if self.is_compact { "compact " } else { "" };
+        let unchecked = if self.is_unchecked { "unchecked " } else { "" };
+        let underlying = self.underlying.as_ref().map_or(String::new(), |underlying| {
+            format!(" : {}", underlying.to_slice_string())
+        });
+        let members = self.enumerators.iter().map(|enumerator| enumerator.borrow().to_slice_string());
+        let constants = self.constants.iter().map(|constant| constant.borrow().to_slice_string());
+        let body = format_body(&members.chain(constants).collect::<Vec<_>>().join("\n"));
+        let visibility = format_visibility(self.visibility);
+        format!("{prelude}{visibility}{compact}{unchecked}enum {}{underlying}{body}", self.identifier())
+    }
+}
+
+impl ToSliceString for Enumerator {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment(), self.attributes());
+        let fields = self.fields.as_ref().map_or(String::new(), |fields| {
+            format!("({})", format_list(fields.iter().map(|f| f.borrow().to_slice_string()).collect()))
+        });
+        let value = match &self.value {
+            EnumeratorValue::Implicit(_) => String::new(),
+            EnumeratorValue::Explicit(integer) => format!(" = {}", integer.value),
+            EnumeratorValue::Constant(identifier, _) => format!(" = {}", identifier.value),
+        };
+        format!("{prelude}{}{fields}{value}", self.identifier())
+    }
+}
+
+impl ToSliceString for CustomType {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment(), self.attributes());
+        let visibility = format_visibility(self.visibility);
+        format!("{prelude}{visibility}custom {}", self.identifier())
+    }
+}
+
+impl ToSliceString for TypeAlias {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment(), self.attributes());
+        let underlying = self.underlying.to_slice_string();
+        let visibility = format_visibility(self.visibility);
+        format!("{prelude}{visibility}typealias {} = {underlying}", self.identifier())
+    }
+}
+
+impl ToSliceString for Constant {
+    fn to_slice_string(&self) -> String {
+        let prelude = format_prelude(self.comment(), self.attributes());
+        let data_type = self.data_type.as_ref().map_or(String::new(), |data_type| {
+            format!(": {}", data_type.to_slice_string())
+        });
+        let expression = self.expression.to_slice_string();
+        let visibility = format_visibility(self.visibility);
+        format!("{prelude}{visibility}const {}{data_type} = {expression}", self.identifier())
+    }
+}
+
+impl ToSliceString for Expression {
+    fn to_slice_string(&self) -> String {
+        match &self.kind {
+            ExpressionKind::Literal(ConstantValue::Integer(value)) => value.to_string(),
+            ExpressionKind::Literal(ConstantValue::Bool(value)) => value.to_string(),
+            ExpressionKind::Literal(ConstantValue::String(value)) => format_string_literal(value),
+            ExpressionKind::Reference(identifier) => identifier.value.to_string(),
+            ExpressionKind::Unary(operator, operand) => {
+                let operator = match operator {
+                    UnaryOperator::Negate => "-",
+                    UnaryOperator::Not => "~",
+                };
+                match operand.kind {
+                    ExpressionKind::Binary(..) => format!("{operator}({})", operand.to_slice_string()),
+                    _ => format!("{operator}{}", operand.to_slice_string()),
+                }
+            }
+            ExpressionKind::Binary(lhs, operator, rhs) => {
+                // Operands are only parenthesized when necessary. Since all the binary operators are
+                // left-associative, a right operand also needs parentheses if it has the same precedence.
+                let precedence = binary_precedence(operator);
+                let lhs = match &lhs.kind {
+                    ExpressionKind::Binary(_, op, _) if binary_precedence(op) < precedence => {
+                        format!("({})", lhs.to_slice_string())
+                    }
+                    _ => lhs.to_slice_string(),
+                };
+                let rhs = match &rhs.kind {
+                    ExpressionKind::Binary(_, op, _) if binary_precedence(op) <= precedence => {
+                        format!("({})", rhs.to_slice_string())
+                    }
+                    _ => rhs.to_slice_string(),
+                };
+                let operator = match operator {
+                    BinaryOperator::Add => "+",
+                    BinaryOperator::Subtract => "-",
+                    BinaryOperator::Multiply => "*",
+                    BinaryOperator::Divide => "/",
+                    BinaryOperator::Remainder => "%",
+                    BinaryOperator::ShiftLeft => "<<",
+                    BinaryOperator::ShiftRight => ">>",
+                    BinaryOperator::BitAnd => "&",
+                    BinaryOperator::BitOr => "|",
+                    BinaryOperator::BitXor => "^",
+                };
+                format!("{lhs} {operator} {rhs}")
+            }
+        }
+    }
+}
+
+impl<T: Type + ?Sized> ToSliceString for TypeRef<T> {
+    fn to_slice_string(&self) -> String {
+        let definition = match &self.definition {
+            TypeRefDefinition::Patched(_) => match self.definition().concrete_type() {
+                Types::Struct(struct_def) => format_scoped_name(struct_def, &self.scope),
+                Types::Class(class_def) => format_scoped_name(class_def, &self.scope),
+                Types::Enum(enum_def) => format_scoped_name(enum_def, &self.scope),
+                Types::CustomType(custom_type) => format_scoped_name(custom_type, &self.scope),
+                Types::ResultType(result_type) => result_type.to_slice_string(),
+                Types::Sequence(sequence) => sequence.to_slice_string(),
+                Types::Dictionary(dictionary) => dictionary.to_slice_string(),
+                Types::Primitive(primitive) => primitive.to_slice_string(),
+            },
+            TypeRefDefinition::Unpatched(identifier) => identifier.value.to_string(),
+        };
+        format_type_ref(&self.attributes, &definition, self.is_optional)
+    }
+}
+
+impl ToSliceString for ResultType {
+    fn to_slice_string(&self) -> String {
+        let (success, failure) = (self.success_type.to_slice_string(), self.failure_type.to_slice_string());
+        format!("Result<{success}, {failure}>")
+    }
+}
+
+impl ToSliceString for Sequence {
+    fn to_slice_string(&self) -> String {
+        format!("Sequence<{}>", self.element_type.to_slice_string())
+    }
+}
+
+impl ToSliceString for Dictionary {
+    fn to_slice_string(&self) -> String {
+        let (key, value) = (self.key_type.to_slice_string(), self.value_type.to_slice_string());
+        format!("Dictionary<{key}, {value}>")
+    }
+}
+
+impl ToSliceString for Primitive {
+    fn to_slice_string(&self) -> String {
+        self.kind().to_owned()
+    }
+}
+
+impl ToSliceString for Attribute {
+    fn to_slice_string(&self) -> String {
+        let arguments = self.arguments();
+        match arguments.is_empty() {
+            true => self.kind.directive().to_owned(),
+            false => {
+                let arguments = arguments.iter().map(|argument| format_attribute_argument(argument));
+                format!("{}({})", self.kind.directive(), arguments.collect::<Vec<_>>().join(", "))
+            }
+        }
+    }
+}
+
+impl ToSliceString for DocComment {
+    fn to_slice_string(&self) -> String {
+        let lines = doc_comment_lines(self);
+        lines.iter().map(|line| format_comment_line("///", line)).collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// Returns the lines of the provided doc comment, without any leading slashes.
+fn doc_comment_lines(comment: &DocComment) -> Vec<String> {
+    let mut lines = Vec::new();
+    if let Some(overview) = &comment.overview {
+        lines.extend(format_message(overview).lines().map(str::to_owned));
+    }
+
+    // Tags with messages start on a single line, with the rest of their message (if any) on the following lines.
+    let mut push_tag = |tag: String, message: Option<&Message>| {
+        let message = message.map(format_message).unwrap_or_default();
+        let mut message_lines = message.lines();
+        match message_lines.next() {
+            Some(first_line) => lines.push(format!("{tag}: {first_line}")),
+            None => lines.push(tag),
+        }
+        lines.extend(message_lines.map(str::to_owned));
+    };
+    for param in &comment.params {
+        push_tag(format!("@param {}", param.identifier.value), Some(&param.message));
+    }
+    for returns in &comment.returns {
+        let tag = match &returns.identifier {
+            Some(identifier) => format!("@returns {}", identifier.value),
+            None => "@returns".to_owned(),
+        };
+        push_tag(tag, Some(&returns.message));
+    }
+    for throws in &comment.throws {
+        let thrown_type = format_link(&throws.thrown_type);
+        push_tag(format!("@throws {thrown_type}"), Some(&throws.message));
+    }
+    for see in &comment.see {
+        push_tag(format!("@see {}", format_link(&see.link)), None);
+    }
+    for example in &comment.examples {
+        let arguments = example.arguments.iter().map(|argument| {
+            let value = match &argument.value {
+                ExampleValue::Literal(ConstantValue::Integer(value)) => value.to_string(),
+                ExampleValue::Literal(ConstantValue::Bool(value)) => value.to_string(),
+                ExampleValue::Literal(ConstantValue::String(value)) => format_string_literal(value),
+                ExampleValue::Enumerator(identifier) => identifier.value.to_string(),
+            };
+            format!("{} = {value}", argument.identifier.value)
+        });
+        let arguments = arguments.collect::<Vec<_>>().join(", ");
+        push_tag(format!("@example({arguments})"), Some(&example.message));
+    }
+    if comment.inherit_doc.is_some() {
+        push_tag("@inheritdoc".to_owned(), None);
+    }
+    for locale in &comment.locales {
+        push_tag(format!("@locale {}", locale.locale.value), Some(&locale.message));
+    }
+    lines
+}
+
+fn format_message(message: &Message) -> String {
+    let text = message
+        .value
+        .iter()
+        .map(|component| match component {
+            MessageComponent::Text(text) => text.clone(),
+            MessageComponent::Link(link_tag) => format!("{{@link {}}}", format_link(&link_tag.link)),
+        })
+        .collect::<String>();
+    text.trim_end().to_owned()
+}
+
+/// Returns the identifier of a doc comment link. Resolved links are fully qualified, so they're unambiguous.
+fn format_link(link: &TypeRefDefinition<dyn Entity>) -> String {
+    match link {
+        TypeRefDefinition::Patched(entity_ptr) => format!("::{}", entity_ptr.borrow().parser_scoped_identifier()),
+        TypeRefDefinition::Unpatched(identifier) => identifier.value.to_string(),
+    }
+}
+
+fn format_comment_line(prefix: &str, line: &str) -> String {
+    match line.is_empty() {
+        true => prefix.to_owned(),
+        false => format!("{prefix} {line}"),
+    }
+}
+
+/// Returns an element's doc comment and attributes, each on their own line, and followed by a newline.
+pub(crate) fn format_prelude(comment: Option<&DocComment>, attributes: Vec<&Attribute>) -> String {
+    let mut prelude = String::new();
+    if let Some(comment) = comment {
+        prelude += &comment.to_slice_string();
+        prelude.push('\n');
+    }
+    for attribute in attributes {
+        prelude += &format!("[{}]\n", attribute.to_slice_string());
+    }
+    prelude
+}
+
+/// Returns the provided members (already joined into a single string) wrapped in braces, with a leading space.
+pub(crate) fn format_body(members: &str) -> String {
+    match members.is_empty() {
+        true => " {}".to_owned(),
+        false => format!(" {{\n{}\n}}", indent(members)),
+    }
+}
+
+/// Returns the provided elements as a comma separated list. If any of them span multiple lines (because they have
+/// doc comments or attributes), each element is placed on its own line instead.
+pub(crate) fn format_list(elements: Vec<String>) -> String {
+    match elements.iter().any(|element| element.contains('\n')) {
+        true => format!("\n{}\n", indent(&elements.join("\n"))),
+        false => elements.join(", "),
+    }
+}
+
+/// Definitions are public by default, so only `internal` definitions need a visibility modifier.
+fn format_visibility(visibility: Visibility) -> &'static str {
+    match visibility {
+        Visibility::Public => "",
+        Visibility::Internal => "internal ",
+    }
+}
+
+fn format_tag(tag: Option<&Integer<u32>>) -> String {
+    tag.map_or(String::new(), |tag| format!("tag({}) ", tag.value))
+}
+
+fn format_default(default: Option<&Expression>) -> String {
+    default.map_or(String::new(), |default| format!(" = {}", default.to_slice_string()))
+}
+
+/// Returns a reference to a type which isn't a [Type] (classes and exceptions used as bases, interfaces, etc.).
+fn format_named_type_ref<T: NamedSymbol + ?Sized>(type_ref: &TypeRef<T>) -> String {
+    let definition = match &type_ref.definition {
+        TypeRefDefinition::Patched(_) => format_scoped_name(type_ref.definition(), &type_ref.scope),
+        TypeRefDefinition::Unpatched(identifier) => identifier.value.to_string(),
+    };
+    format_type_ref(&type_ref.attributes, &definition, type_ref.is_optional)
+}
+
+fn format_type_ref(attributes: &[WeakPtr<Attribute>], definition: &str, is_optional: bool) -> String {
+    let attributes = attributes.iter().map(|attribute| format!("[{}] ", attribute.borrow().to_slice_string()));
+    let optional = if is_optional { "?" } else { "" };
+    format!("{}{definition}{optional}", attributes.collect::<String>())
+}
+
+/// Returns the identifier of the provided element, relative to the module of the provided scope if the element is
+/// defined in that module, and fully qualified otherwise.
+fn format_scoped_name<T: NamedSymbol + ?Sized>(element: &T, scope: &Scope) -> String {
+    let identifier = element.parser_scoped_identifier();
+    if let Some(module) = &scope.module {
+        let prefix = format!("{}::", module.borrow().nested_module_identifier());
+        if let Some(relative_identifier) = identifier.strip_prefix(&prefix) {
+            return relative_identifier.to_owned();
+        }
+    }
+    format!("::{identifier}")
+}
+
+/// Attribute arguments are emitted as-is if they're identifiers, and as string literals otherwise.
+fn format_attribute_argument(argument: &str) -> String {
+    let mut characters = argument.chars();
+    let is_identifier = characters.next().is_some_and(|c| is_identifier_start(c) || c == '_')
+        && characters.all(is_identifier_continue);
+    match is_identifier {
+        true => argument.to_owned(),
+        false => format_string_literal(argument),
+    }
+}
+
+fn format_string_literal(value: &str) -> String {
+    let mut literal = String::from('"');
+    for c in value.chars() {
+        match c {
+            '\\' => literal.push_str("\\\\"),
+            '"' => literal.push_str("\\\""),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            '\0' => literal.push_str("\\0"),
+            c if c.is_control() => literal.push_str(&format!("\\u{{{:x}}}", u32::from(c))),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+fn binary_precedence(operator: &BinaryOperator) -> u8 {
+    match operator {
+        BinaryOperator::BitOr => 0,
+        BinaryOperator::BitXor => 1,
+        BinaryOperator::BitAnd => 2,
+        BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight => 3,
+        BinaryOperator::Add | BinaryOperator::Subtract => 4,
+        BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Remainder => 5,
+    }
+}
+
+fn indent(s: &str) -> String {
+    let lines = s.lines().map(|line| match line.is_empty() {
+        true => String::new(),
+        false => format!("{INDENT}{line}"),
+    });
+    lines.collect::<Vec<_>>().join("\n")
+}
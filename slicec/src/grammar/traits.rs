@@ -2,13 +2,13 @@
 
 use super::attributes::AttributeKind;
 use super::comments::DocComment;
-use super::elements::{Attribute, Identifier, Integer, Module, TypeRef};
-use super::util::{Scope, TagFormat};
-use super::wrappers::{AsEntities, AsTypes};
+use super::elements::{Attribute, ConstantValue, Expression, Field, Identifier, Integer, Module, Primitive, TypeRef};
+use super::util::{Encoding, InvalidKeyReason, Scope, TagFormat};
+use super::wrappers::{AsEntities, AsTypes, Entities, Types};
 use crate::slice_file::Span;
 use crate::supported_encodings::SupportedEncodings;
 
-pub trait Element: std::fmt::Debug {
+pub trait Element: std::fmt::Debug + Send + Sync {
     fn kind(&self) -> &'static str;
 }
 
@@ -34,7 +34,8 @@ pub trait Attributable {
     /// Returns the attributes of the element.
     fn attributes(&self) -> Vec<&Attribute>;
 
-    /// Returns all the attributes of the element and its parents.
+    /// Returns all the attributes of the element and its parents, ordered from innermost to outermost.
+    /// For elements declared within a module, this includes the attributes applied to that module.
     fn all_attributes(&self) -> Vec<Vec<&Attribute>>;
 }
 
@@ -91,18 +92,116 @@ pub trait Member: Entity {
     fn is_tagged(&self) -> bool {
         self.raw_tag().is_some()
     }
+
+    /// Returns this member's tag as the `varint32` it's encoded as on the wire, or `None` if it isn't tagged.
+    ///
+    /// Tag values are range checked during validation, so backends can use the returned value as-is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tag is outside the range supported by every encoding. This can only happen if validation
+    /// reported an error, in which case the compilation should've stopped before reaching a backend.
+    fn validated_tag(&self) -> Option<i32> {
+        self.tag().map(|tag| {
+            let supported = Encoding::Slice1.tag_range().contains(&tag) && Encoding::Slice2.tag_range().contains(&tag);
+            assert!(supported, "tag '{tag}' of '{}' was never validated", self.identifier());
+            tag as i32
+        })
+    }
+
+    /// Returns the expression that this member's default value was declared with, if it has one.
+    fn default(&self) -> Option<&Expression>;
+
+    /// Returns this member's default value, computed by evaluating its default expression, if it has one.
+    fn default_value(&self) -> Option<&ConstantValue>;
 }
 
 pub trait Commentable: Entity {
     fn comment(&self) -> Option<&DocComment>;
+
+    /// Returns the doc comment which documents this element. This is its own comment, unless it doesn't have one, or
+    /// its comment has an `@inheritdoc` tag. In those cases, the effective comment of its base type is returned
+    /// instead (for interfaces, the first of its base interfaces that has one, in declaration order).
+    ///
+    /// Only classes, exceptions, and interfaces have base types to inherit documentation from. Since Slice doesn't
+    /// allow members to shadow inherited members, operations and fields are always documented by their own comments.
+    /// If there's nothing to inherit documentation from, this returns the element's own comment.
+    fn effective_comment(&self) -> Option<&DocComment> {
+        let comment = self.comment();
+        if comment.is_some_and(|comment| comment.inherit_doc.is_none()) {
+            return comment;
+        }
+
+        let inherited_comment = match self.concrete_entity() {
+            Entities::Class(class_def) => class_def.base_class().and_then(Commentable::effective_comment),
+            Entities::Exception(exception_def) => {
+                exception_def.base_exception().and_then(Commentable::effective_comment)
+            }
+            Entities::Interface(interface_def) => {
+                let mut bases = interface_def.base_interfaces().into_iter();
+                bases.find_map(Commentable::effective_comment)
+            }
+            _ => None,
+        };
+        inherited_comment.or(comment)
+    }
 }
 
 pub trait Type: Element + AsTypes {
     fn type_string(&self) -> String;
     fn fixed_wire_size(&self) -> Option<u32>;
+
+    /// Returns the minimum number of bytes that a value of this type can occupy on the wire with the Slice2 encoding.
+    /// For types of fixed size, this is equal to their [fixed_wire_size](Type::fixed_wire_size).
+    fn min_wire_size(&self) -> u32;
+
+    /// Returns the maximum number of bytes that a value of this type can occupy on the wire with the Slice2 encoding,
+    /// or `None` if it's unbounded (Ex: strings, sequences, and class types). For types of fixed size, this is equal
+    /// to their [fixed_wire_size](Type::fixed_wire_size).
+    fn max_wire_size(&self) -> Option<u32>;
+
     fn is_class_type(&self) -> bool;
     fn tag_format(&self) -> Option<TagFormat>;
     fn supported_encodings(&self) -> SupportedEncodings;
+
+    /// Returns the fields of this type in the order they're encoded in with the provided encoding.
+    /// Types which don't have any fields (or whose fields depend on their value, like enums) return an empty vector.
+    ///
+    /// See [sort_for_encoding](super::sort_for_encoding) for the order that fields are encoded in.
+    fn encoding_order(&self, encoding: Encoding) -> Vec<&Field>;
+
+    /// Returns `Ok` if this type can be used as the key type of a dictionary, and the reason it can't otherwise.
+    ///
+    /// Valid key types are: `bool`, integral types, `string`, `uuid`, custom types, enums with underlying types, and
+    /// compact structs whose fields are all valid key types. This doesn't account for optionality, see
+    /// [TypeRef::is_valid_dictionary_key] for that.
+    fn is_valid_dictionary_key(&self) -> Result<(), InvalidKeyReason> {
+        match self.concrete_type() {
+            Types::Struct(struct_def) => {
+                if !struct_def.is_compact {
+                    return Err(InvalidKeyReason::NonCompactStruct);
+                }
+
+                let invalid_fields = struct_def
+                    .fields()
+                    .into_iter()
+                    .filter_map(|field| match field.data_type().is_valid_dictionary_key() {
+                        Ok(()) => None,
+                        Err(reason) => Some((field.identifier().to_owned(), reason)),
+                    })
+                    .collect::<Vec<_>>();
+                match invalid_fields.is_empty() {
+                    true => Ok(()),
+                    false => Err(InvalidKeyReason::InvalidFields(invalid_fields)),
+                }
+            }
+            Types::Enum(enum_def) if enum_def.underlying.is_none() => Err(InvalidKeyReason::EnumWithoutUnderlyingType),
+            Types::Enum(_) | Types::CustomType(_) => Ok(()),
+            Types::Primitive(primitive) if primitive.is_integral() => Ok(()),
+            Types::Primitive(Primitive::Bool | Primitive::String | Primitive::Uuid) => Ok(()),
+            _ => Err(InvalidKeyReason::UnsupportedType),
+        }
+    }
 }
 
 macro_rules! implement_Element_for {
@@ -184,6 +283,23 @@ macro_rules! implement_Attributable_for {
             }
         }
     };
+    (@Scoped $type:ty) => {
+        impl Attributable for $type {
+            fn attributes(&self) -> Vec<&Attribute> {
+                self.attributes.iter().map(WeakPtr::borrow).collect()
+            }
+
+            fn all_attributes(&self) -> Vec<Vec<&Attribute>> {
+                // Definitions declared before any module declarations aren't placed in a module. This is reported
+                // as an error, but these definitions can still appear in diagnostics, so we don't assume a module.
+                let mut attributes_list = vec![self.attributes()];
+                if let Some(module) = &self.scope.module {
+                    attributes_list.push(module.borrow().attributes());
+                }
+                attributes_list
+            }
+        }
+    };
     (@Contained $type:ty$(, $($bounds:tt)+)?) => {
         impl$(<T: $($bounds)+>)? Attributable for $type {
             fn attributes(&self) -> Vec<&Attribute> {
@@ -249,6 +365,14 @@ macro_rules! implement_Member_for {
             fn raw_tag(&self) -> Option<&Integer<u32>> {
                 self.tag.as_ref()
             }
+
+            fn default(&self) -> Option<&Expression> {
+                self.default.as_ref()
+            }
+
+            fn default_value(&self) -> Option<&ConstantValue> {
+                self.default_value.as_ref()
+            }
         }
     };
 }
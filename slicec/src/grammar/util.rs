@@ -1,21 +1,26 @@
 // Copyright (c) ZeroC, Inc.
 
-use super::Module;
+use super::{Member, Module};
+use crate::utils::intern_util::InternedString;
 use crate::utils::ptr_util::WeakPtr;
 use std::fmt;
+use std::ops::RangeInclusive;
 
 #[derive(Clone, Debug, Default)]
 pub struct Scope {
-    pub parser_scope: String,
+    pub parser_scope: InternedString,
     pub module: Option<WeakPtr<Module>>,
 }
 
 impl Scope {
+    /// Makes the provided module the current module, and resets the parser scope to the module's (nested) identifier.
+    pub fn enter_module(&mut self, module: WeakPtr<Module>) {
+        self.parser_scope = InternedString::new(module.borrow().nested_module_identifier());
+        self.module = Some(module);
+    }
+
     pub fn push_scope(&mut self, scope: &str) {
-        if !self.parser_scope.is_empty() {
-            self.parser_scope.push_str("::");
-        }
-        self.parser_scope.push_str(scope);
+        self.parser_scope = InternedString::new(&get_scoped_identifier(scope, &self.parser_scope));
     }
 
     pub fn pop_scope(&mut self) {
@@ -27,12 +32,12 @@ impl Scope {
                 let module_scope = self.module.as_ref().map(|m| m.borrow().nested_module_identifier());
                 debug_assert!(self.parser_scope.len() > module_scope.map_or(0, str::len))
             }
-            self.parser_scope.truncate(last_scope_index);
+            self.parser_scope = InternedString::new(&self.parser_scope[..last_scope_index]);
         } else {
             // If the string doesn't contain '::', there's only a single scope. We pop it off by clearing the string.
             // This is only possible if we're not in a module, otherwise we'd always have at least 1 module scope.
             debug_assert!(self.module.is_none());
-            self.parser_scope.clear();
+            self.parser_scope = InternedString::default();
         }
     }
 }
@@ -46,6 +51,119 @@ pub fn get_scoped_identifier(identifier: &str, scope: &str) -> String {
     }
 }
 
+/// Returns the provided members sorted into the order they're encoded in:
+/// required members in the order they were declared, followed by tagged members sorted by tag.
+///
+/// This order is the same for both Slice1 and Slice2, and applies to any list of members: fields, parameters, etc.
+pub fn sort_for_encoding<T: Member>(members: Vec<&T>) -> Vec<&T> {
+    let (mut tagged, mut required): (Vec<&T>, Vec<&T>) = members.into_iter().partition(|member| member.is_tagged());
+    tagged.sort_by_key(|member| member.tag());
+    required.append(&mut tagged);
+    required
+}
+
+/// The members of a type or operation, partitioned and ordered the way encoders must process them.
+///
+/// The members are kept in the order returned by [sort_for_encoding], split into the required and tagged members.
+/// With Slice2, the required members are preceded by a bit sequence with a bit for each required member with an
+/// optional type, which is set if that member holds a value. See [bit_sequence_members](Self::bit_sequence_members).
+#[derive(Debug)]
+pub struct MemberLayout<'a, T: Member> {
+    /// The members which aren't tagged, in the order they were declared.
+    pub required: Vec<&'a T>,
+
+    /// The tagged members, sorted by tag.
+    pub tagged: Vec<&'a T>,
+}
+
+impl<'a, T: Member> MemberLayout<'a, T> {
+    /// Partitions and orders the provided members. Streamed parameters should be excluded beforehand,
+    /// since they're encoded separately from the other parameters of an operation.
+    pub fn new(members: Vec<&'a T>) -> Self {
+        let mut required = sort_for_encoding(members);
+
+        // Tagged members always come after the required members, so we can split the members at the first tagged one.
+        let tagged_start = required.iter().position(|member| member.is_tagged());
+        let tagged = required.split_off(tagged_start.unwrap_or(required.len()));
+        MemberLayout { required, tagged }
+    }
+
+    /// Returns the required members that are tracked by the bit sequence with the provided encoding, in order.
+    /// These are the required members with optional types. Slice1 doesn't use a bit sequence, so this is always empty.
+    pub fn bit_sequence_members(&self, encoding: Encoding) -> Vec<&'a T> {
+        match encoding {
+            Encoding::Slice1 => Vec::new(),
+            Encoding::Slice2 => {
+                let required = self.required.iter();
+                required.filter(|member| member.data_type().is_optional).copied().collect()
+            }
+        }
+    }
+
+    /// Returns the number of bytes occupied by the bit sequence with the provided encoding.
+    pub fn bit_sequence_size(&self, encoding: Encoding) -> u32 {
+        (self.bit_sequence_members(encoding).len() as u32).div_ceil(8)
+    }
+
+    /// Returns all the members in the order they're encoded in. This is the same order as [sort_for_encoding].
+    pub fn ordered(&self) -> Vec<&'a T> {
+        self.required.iter().chain(&self.tagged).copied().collect()
+    }
+}
+
+/// Returns the minimum and maximum number of bytes that the provided members can occupy on the wire with the Slice2
+/// encoding. The maximum is `None` if any of the members are of unbounded size.
+///
+/// This includes the bit sequence that tracks which optional members are set, but not the tag end marker,
+/// since it's only encoded after the fields of non-compact types.
+pub fn members_wire_size<T: Member>(members: &[&T]) -> (u32, Option<u32>) {
+    let layout = MemberLayout::new(members.to_vec());
+
+    // Each optional required member takes up one bit in the bit sequence, rounded up to the nearest byte.
+    let bit_sequence_size = layout.bit_sequence_size(Encoding::Slice2);
+
+    let mut min = bit_sequence_size;
+    let mut max = Some(bit_sequence_size);
+    for member in layout.required {
+        min += member.data_type().min_wire_size();
+        max = max.zip(member.data_type().max_wire_size()).map(|(a, b)| a + b);
+    }
+
+    // Tagged members are omitted when they're not set, so they only contribute to the maximum size.
+    // When they are set, they're encoded as a tag, then the size of their value, and then their value.
+    for member in layout.tagged {
+        let tag_size = varint_wire_size(i64::from(member.tag().unwrap()));
+        let value_max = member.data_type().max_wire_size();
+        let member_max = value_max.map(|value_max| tag_size + varuint_wire_size(u64::from(value_max)) + value_max);
+        max = max.zip(member_max).map(|(a, b)| a + b);
+    }
+    (min, max)
+}
+
+/// Returns the number of bytes that the provided value occupies when encoded as a `varint62`.
+pub fn varint_wire_size(value: i64) -> u32 {
+    // Compute how many bits are required to encode this value, including the sign bit.
+    let required_bits = match value < 0 {
+        false => i64::BITS - value.leading_zeros() + 1,
+        true => i64::BITS - value.leading_ones() + 1,
+    };
+    wire_size_for_bits(required_bits)
+}
+
+/// Returns the number of bytes that the provided value occupies when encoded as a `varuint62`.
+pub fn varuint_wire_size(value: u64) -> u32 {
+    wire_size_for_bits(u64::BITS - value.leading_zeros())
+}
+
+fn wire_size_for_bits(required_bits: u32) -> u32 {
+    match required_bits {
+        0..=6 => 1,
+        7..=14 => 2,
+        15..=30 => 4,
+        _ => 8,
+    }
+}
+
 /// This enum specifies all the encodings supported by IceRPC.
 ///
 /// These encodings identity the format used to convert Slice types to and from byte streams.
@@ -64,6 +182,19 @@ pub enum Encoding {
     Slice2,
 }
 
+impl Encoding {
+    /// Returns the range of tag values that can be encoded with this encoding.
+    ///
+    /// With Slice1, tags greater than 29 are encoded as a size, which is limited to `i32::MAX`.
+    /// With Slice2, tags are encoded as `varint32`s, and negative values are reserved (`-1` is the tag end marker).
+    pub fn tag_range(self) -> RangeInclusive<u32> {
+        match self {
+            Self::Slice1 => 0..=i32::MAX as u32,
+            Self::Slice2 => 0..=i32::MAX as u32,
+        }
+    }
+}
+
 impl fmt::Display for Encoding {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(match self {
@@ -78,6 +209,29 @@ impl fmt::Display for Encoding {
 /// For convenience it's an alias of `Encoding`, since they are used interchangeably in places.
 pub type CompilationMode = Encoding;
 
+/// This enum specifies the visibility of a definition, which controls whether it's exposed outside of its library.
+///
+/// Definitions are public unless they're marked with the `internal` keyword. Public definitions cannot expose
+/// internal types (as field types, parameter types, base types, etc.), since their users couldn't refer to them.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Visibility {
+    /// The definition can be used from anywhere, including from outside of its library.
+    #[default]
+    Public,
+
+    /// The definition can only be used from within its own library.
+    Internal,
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Public => "public",
+            Self::Internal => "internal",
+        })
+    }
+}
+
 /// This tag format describes how the data is encoded and how it can be skipped by the decoding
 /// code if the tagged parameter is present in the buffer but is not known to the receiver.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -125,3 +279,24 @@ impl fmt::Display for TagFormat {
         }
     }
 }
+
+/// The reasons that a type can't be used as a dictionary key.
+/// See [Type::is_valid_dictionary_key](super::Type::is_valid_dictionary_key).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvalidKeyReason {
+    /// The type is optional. Optional types can never be used as dictionary keys.
+    Optional,
+
+    /// The type is a struct which isn't compact. Only compact structs can be used as dictionary keys.
+    NonCompactStruct,
+
+    /// The type is a compact struct with fields that can't be used as dictionary keys.
+    /// Each invalid field is stored alongside its identifier, in the order they're defined.
+    InvalidFields(Vec<(String, InvalidKeyReason)>),
+
+    /// The type is an enum without an underlying type. Only enums with underlying types can be used as dictionary keys.
+    EnumWithoutUnderlyingType,
+
+    /// The type's kind can never be used as a dictionary key. Ex: classes, sequences, or floating point numbers.
+    UnsupportedType,
+}
@@ -23,7 +23,7 @@ macro_rules! generate_definition_wrapper {
     };
 }
 
-generate_definition_wrapper!(Struct, Class, Exception, Interface, Enum, CustomType, TypeAlias);
+generate_definition_wrapper!(Struct, Class, Exception, Interface, Enum, CustomType, TypeAlias, Constant);
 
 macro_rules! generate_entities_wrapper {
     ($($variant:ident),*) => {
@@ -47,7 +47,8 @@ pub trait AsEntities {
 }
 
 generate_entities_wrapper!(
-    Struct, Class, Exception, Field, Interface, Operation, Parameter, Enum, Enumerator, CustomType, TypeAlias
+    Struct, Class, Exception, Field, Interface, Operation, Parameter, Enum, Enumerator, CustomType, TypeAlias,
+    Constant
 );
 
 macro_rules! generate_attributables_wrapper {
@@ -73,7 +74,7 @@ pub trait AsAttributables {
 
 generate_attributables_wrapper!(
     Module, Struct, Class, Exception, Field, Interface, Operation, Parameter, Enum, Enumerator, CustomType, TypeAlias,
-    TypeRef, SliceFile
+    Constant, TypeRef, SliceFile
 );
 
 macro_rules! generate_types_wrapper {
@@ -0,0 +1,181 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module implements importing modules from one compilation into another.
+//!
+//! Elements are owned by the AST that they were parsed into, and hold pointers to other elements in that AST, so they
+//! cannot be moved between ASTs directly. Instead, the files that define the imported elements (and the files defining
+//! any elements they depend on) are copied into the new compilation as reference files. These files are then parsed
+//! and patched like any other file, which guarantees that the scopes and pointers of every imported element are
+//! anchored in the new compilation's AST.
+
+use crate::annotations::TypeAliasReferences;
+use crate::ast::{Ast, LookupError};
+use crate::compilation_state::CompilationState;
+use crate::grammar::*;
+use crate::slice_file::SliceFile;
+use crate::visitor::Visitor;
+use std::collections::{BTreeSet, HashMap};
+
+/// Selects a module (and all of its submodules) from an already compiled [CompilationState] for importing.
+#[derive(Clone, Copy, Debug)]
+pub struct ModuleImport<'a> {
+    /// The compilation to import the module from.
+    pub state: &'a CompilationState,
+    /// The fully scoped identifier of the module to import, without a leading '::'. Ex: `Foo::Bar`.
+    pub module: &'a str,
+}
+
+impl<'a> ModuleImport<'a> {
+    pub fn new(state: &'a CompilationState, module: &'a str) -> Self {
+        ModuleImport { state, module }
+    }
+
+    /// Returns copies of all the files needed to define the elements of the selected module in another compilation.
+    /// The returned files are always reference files, even if they were source files in the original compilation.
+    ///
+    /// This includes the files that define elements in the module or its submodules, and recursively, any files that
+    /// define elements those elements depend on, including any type aliases they reference.
+    ///
+    /// If the module doesn't exist in the compilation this is importing from, `Err` is returned.
+    pub fn collect_files(&self) -> Result<Vec<SliceFile>, LookupError> {
+        self.state.ast.find_element::<Module>(self.module)?;
+
+        let files = &self.state.files;
+        let mut required = files
+            .iter()
+            .filter(|file| file.contents.iter().any(|d| self.contains(d.borrow())))
+            .map(|file| file.relative_path.clone())
+            .collect::<BTreeSet<_>>();
+
+        // References to type aliases are resolved away while patching, so we use the files recorded by the patcher to
+        // find which files each file depends on for type aliases.
+        let mut alias_dependencies = HashMap::<&str, Vec<&str>>::new();
+        for file in files {
+            for definition in &file.contents {
+                let Definition::TypeAlias(type_alias_ptr) = definition else { continue };
+                let type_alias = type_alias_ptr.borrow();
+                for reference in self.state.annotations.get::<TypeAliasReferences>(type_alias).into_iter().flatten() {
+                    alias_dependencies.entry(reference).or_default().push(&type_alias.span().file);
+                }
+            }
+        }
+
+        // Keep adding the dependencies of the required files until there's nothing left to add.
+        let mut pending = required.iter().cloned().collect::<Vec<_>>();
+        while let Some(path) = pending.pop() {
+            let file = files.iter().find(|file| file.relative_path == path).unwrap();
+
+            let mut collector = DependencyCollector::new(&self.state.ast);
+            file.visit_with(&mut collector);
+            let aliases = alias_dependencies.get(path.as_str()).into_iter().flatten();
+            collector.dependencies.extend(aliases.map(|&file| file.to_owned()));
+            for dependency in collector.dependencies {
+                if required.insert(dependency.clone()) {
+                    pending.push(dependency);
+                }
+            }
+        }
+
+        // Preserve the order that the files were originally compiled in.
+        let imported = files
+            .iter()
+            .filter(|file| required.contains(&file.relative_path))
+            .map(|file| {
+                // Imported files are parsed with the symbols they were originally parsed with, so any preprocessor
+                // directives in them are evaluated the same way as in the compilation they're imported from.
+                let mut imported = SliceFile::new(file.relative_path.clone(), file.raw_text.clone(), false);
+                imported.defined_symbols = file.defined_symbols.clone();
+                imported
+            })
+            .collect();
+        Ok(imported)
+    }
+
+    /// Returns true if the provided entity is defined in the selected module, or one of its submodules.
+    fn contains(&self, entity: &dyn Entity) -> bool {
+        let module_scope = entity.module_scope();
+        module_scope == self.module
+            || (module_scope.starts_with(self.module) && module_scope[self.module.len()..].starts_with("::"))
+    }
+}
+
+/// Collects the names of the files that define any elements referenced by the visited elements.
+struct DependencyCollector<'a> {
+    ast: &'a Ast,
+    dependencies: BTreeSet<String>,
+}
+
+impl<'a> DependencyCollector<'a> {
+    fn new(ast: &'a Ast) -> Self {
+        let dependencies = BTreeSet::new();
+        DependencyCollector { ast, dependencies }
+    }
+
+    fn add_dependency(&mut self, symbol: &dyn Symbol) {
+        self.dependencies.insert(symbol.span().file.clone());
+    }
+
+    fn add_constant_dependency(&mut self, identifier: &Identifier, scope: &str) {
+        if let Ok(constant) = self.ast.find_element_with_scope::<Constant>(&identifier.value, scope) {
+            self.add_dependency(constant);
+        }
+    }
+
+    fn add_expression_dependencies(&mut self, expression: &Expression, scope: &str) {
+        match &expression.kind {
+            ExpressionKind::Literal(_) => {}
+            ExpressionKind::Reference(identifier) => self.add_constant_dependency(identifier, scope),
+            ExpressionKind::Unary(_, operand) => self.add_expression_dependencies(operand, scope),
+            ExpressionKind::Binary(lhs, _, rhs) => {
+                self.add_expression_dependencies(lhs, scope);
+                self.add_expression_dependencies(rhs, scope);
+            }
+        }
+    }
+}
+
+impl Visitor for DependencyCollector<'_> {
+    fn visit_class(&mut self, class_def: &Class) {
+        if let Some(base) = class_def.base_class() {
+            self.add_dependency(base);
+        }
+    }
+
+    fn visit_exception(&mut self, exception_def: &Exception) {
+        if let Some(base) = exception_def.base_exception() {
+            self.add_dependency(base);
+        }
+    }
+
+    fn visit_interface(&mut self, interface_def: &Interface) {
+        for base in interface_def.base_interfaces() {
+            self.add_dependency(base);
+        }
+    }
+
+    fn visit_operation(&mut self, operation: &Operation) {
+        for exception_ref in &operation.exception_specification {
+            self.add_dependency(exception_ref.definition());
+        }
+    }
+
+    fn visit_constant(&mut self, constant: &Constant) {
+        self.add_expression_dependencies(&constant.expression, constant.parser_scope());
+    }
+
+    fn visit_enumerator(&mut self, enumerator: &Enumerator) {
+        if let EnumeratorValue::Constant(identifier, _) = &enumerator.value {
+            self.add_constant_dependency(identifier, enumerator.parser_scope());
+        }
+    }
+
+    fn visit_type_ref(&mut self, type_ref: &TypeRef) {
+        match type_ref.concrete_type() {
+            Types::Struct(struct_def) => self.add_dependency(struct_def),
+            Types::Class(class_def) => self.add_dependency(class_def),
+            Types::Enum(enum_def) => self.add_dependency(enum_def),
+            Types::CustomType(custom_type) => self.add_dependency(custom_type),
+            Types::ResultType(_) | Types::Sequence(_) | Types::Dictionary(_) | Types::Primitive(_) => {}
+        }
+    }
+}
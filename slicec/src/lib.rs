@@ -1,41 +1,163 @@
 // Copyright (c) ZeroC, Inc.
 
+pub mod annotations;
 pub mod ast;
+pub mod ast_builder;
+pub mod bundler;
 pub mod compilation_state;
+pub mod config_file;
+pub mod dependency_file;
 pub mod diagnostic_emitter;
 pub mod diagnostics;
+pub mod diagram;
+pub mod doc_generator;
+pub mod encoding_explanation;
+pub mod fixes;
+pub mod generated_file;
+#[cfg(feature = "test-support")]
+pub mod golden_files;
 pub mod grammar;
+pub mod importer;
+pub mod lock_file;
+pub mod logging;
+pub mod metrics;
+pub mod prelude;
+pub mod protobuf_importer;
+pub mod schema_package;
 pub mod slice_file;
 pub mod slice_options;
 pub mod supported_encodings;
+#[cfg(feature = "test-support")]
 pub mod test_helpers;
+pub mod token_stream;
+pub mod trivia;
 pub mod utils;
+pub mod versioning;
 pub mod visitor;
+pub mod watch;
 
 mod parsers;
 mod patchers;
 mod validators;
 
-use compilation_state::CompilationState;
+use ast_builder::FileBuilder;
+use compilation_state::{CancellationToken, CompilationState};
+use diagnostic_emitter::DiagnosticStream;
+use diagnostics::{Diagnostic, Error};
+use grammar::ToSliceString;
+use importer::ModuleImport;
 use slice_file::SliceFile;
-use slice_options::SliceOptions;
+use slice_options::{DiagnosticFormat, SliceOptions};
 use std::collections::HashSet;
+use std::io::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
 use utils::file_util;
 
 pub fn compile_from_options(
     options: &SliceOptions,
     patcher: unsafe fn(&mut CompilationState),
     validator: fn(&mut CompilationState),
+) -> CompilationState {
+    compile_from_options_with_cancel(options, patcher, validator, &CancellationToken::new())
+}
+
+/// Compiles the files specified by `options`, like [compile_from_options], but stops early if the provided token is
+/// cancelled. See [CancellationToken] for more information.
+pub fn compile_from_options_with_cancel(
+    options: &SliceOptions,
+    patcher: unsafe fn(&mut CompilationState),
+    validator: fn(&mut CompilationState),
+    cancellation_token: &CancellationToken,
+) -> CompilationState {
+    resolve_and_compile_files(options, patcher, validator, cancellation_token, false)
+}
+
+/// Parses and validates the files specified by `options`, without running the phases that only produce output. This
+/// is intended for tools like editors, which only need diagnostics, and want them as quickly as possible.
+///
+/// Parsing, patching, and validation all run exactly as they do in [compile_from_options], since each of them can
+/// report diagnostics. Only the built-in patchers and validators are run; there's no embedder-provided patcher or
+/// validator. Compared to a full compilation, this skips:
+/// - collecting trivia, even if [SliceOptions::preserve_trivia] is set.
+/// - checking the lock file, so any diagnostics it would report are missing.
+/// - writing schema packages, dependency files, documentation, and diagrams, even if requested by `options`.
+pub fn validate_from_options(options: &SliceOptions) -> CompilationState {
+    validate_from_options_with_cancel(options, &CancellationToken::new())
+}
+
+/// Validates the files specified by `options`, like [validate_from_options], but stops early if the provided token is
+/// cancelled. See [CancellationToken] for more information.
+pub fn validate_from_options_with_cancel(
+    options: &SliceOptions,
+    cancellation_token: &CancellationToken,
+) -> CompilationState {
+    resolve_and_compile_files(options, |_| {}, |_| {}, cancellation_token, true)
+}
+
+fn resolve_and_compile_files(
+    options: &SliceOptions,
+    patcher: unsafe fn(&mut CompilationState),
+    validator: fn(&mut CompilationState),
+    cancellation_token: &CancellationToken,
+    validate_only: bool,
 ) -> CompilationState {
     // Create an instance of `CompilationState` for holding all the compiler's state.
-    let mut state = CompilationState::create();
+    let mut state = create_compilation_state(options);
 
     // Recursively resolve any Slice files contained in the paths specified by the user.
+    let start = Instant::now();
     state.files = file_util::resolve_files_from(options, &mut state.diagnostics);
+    state.metrics.record_phase("file resolution", start);
 
     // If any files were unreadable, return without parsing. Otherwise, parse the files normally.
     if !state.diagnostics.has_errors() {
-        compile_files(&mut state, options, patcher, validator);
+        compile_files(&mut state, options, patcher, validator, cancellation_token, validate_only);
+    }
+    state
+}
+
+/// Compiles the files specified by `options`, alongside any modules imported from other (already compiled)
+/// compilations. Imported files are treated as reference files. See [ModuleImport] for more information.
+pub fn compile_with_imports(
+    options: &SliceOptions,
+    imports: &[ModuleImport],
+    patcher: unsafe fn(&mut CompilationState),
+    validator: fn(&mut CompilationState),
+) -> CompilationState {
+    // Create an instance of `CompilationState` for holding all the compiler's state.
+    let mut state = create_compilation_state(options);
+
+    // Recursively resolve any Slice files contained in the paths specified by the user.
+    let start = Instant::now();
+    state.files = file_util::resolve_files_from(options, &mut state.diagnostics);
+    state.metrics.record_phase("file resolution", start);
+
+    // Add the files needed by each of the imported modules. Identical files that are needed by multiple imports, or
+    // that were already specified by the user, are only added once.
+    for import in imports {
+        match import.collect_files() {
+            Ok(files) => {
+                for file in files {
+                    let is_duplicate = state
+                        .files
+                        .iter()
+                        .any(|f| f.relative_path == file.relative_path && f.raw_text == file.raw_text);
+                    if !is_duplicate {
+                        state.files.push(file);
+                    }
+                }
+            }
+            Err(_) => Diagnostic::new(Error::DoesNotExist {
+                identifier: import.module.to_owned(),
+            })
+            .push_into(&mut state.diagnostics),
+        }
+    }
+
+    // If any files were unreadable, or any imports were invalid, return without parsing.
+    if !state.diagnostics.has_errors() {
+        compile_files(&mut state, options, patcher, validator, &CancellationToken::new(), false);
     }
     state
 }
@@ -46,20 +168,104 @@ pub fn compile_from_strings(
     patcher: unsafe fn(&mut CompilationState),
     validator: fn(&mut CompilationState),
 ) -> CompilationState {
+    let mut state = create_compilation_state_from_strings(inputs, options);
+
+    let default_options = SliceOptions::default();
+    let options = options.unwrap_or(&default_options);
+    compile_files(&mut state, options, patcher, validator, &CancellationToken::new(), false);
+
+    state
+}
+
+/// Compiles the provided strings, like [compile_from_strings], but never panics, regardless of their contents.
+/// This is intended for running slicec under a fuzzer, or inside a long-lived service.
+///
+/// Invalid input is always reported with diagnostics. If the compiler panics anyway (which is always a bug), the panic
+/// is caught and reported as an [InternalError](Error::InternalError), alongside any diagnostics that were reported
+/// before it. In that case, the returned AST may be only partially patched, so only its diagnostics should be used.
+///
+/// Note that this doesn't suppress the panic hook, so the panic's message is still printed to stderr by default.
+pub fn compile_from_strings_infallible(
+    inputs: &[&str],
+    options: Option<&SliceOptions>,
+    patcher: unsafe fn(&mut CompilationState),
+    validator: fn(&mut CompilationState),
+) -> CompilationState {
+    let mut state = create_compilation_state_from_strings(inputs, options);
+
+    let default_options = SliceOptions::default();
+    let options = options.unwrap_or(&default_options);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        compile_files(&mut state, options, patcher, validator, &CancellationToken::new(), false);
+    }));
+
+    if let Err(payload) = result {
+        // Panic payloads are almost always a `&str` or a `String`, but can technically be any type.
+        let message = match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+            (Some(message), _) => message.to_string(),
+            (_, Some(message)) => message.clone(),
+            _ => "the compiler panicked with an unknown payload".to_owned(),
+        };
+        Diagnostic::new(Error::InternalError { message }).push_into(&mut state.diagnostics);
+    }
+    state
+}
+
+/// Compiles the Slice files described by the provided builders, as source files.
+/// See [ast_builder] for more information.
+pub fn compile_from_builders(
+    files: &[FileBuilder],
+    options: Option<&SliceOptions>,
+    patcher: unsafe fn(&mut CompilationState),
+    validator: fn(&mut CompilationState),
+) -> CompilationState {
+    let mut state = match options {
+        Some(slice_options) => create_compilation_state(slice_options),
+        None => CompilationState::create(),
+    };
+    for file in files {
+        let slice_file = SliceFile::new(file.path.clone(), file.to_slice_string(), true);
+        state.files.push(slice_file);
+    }
+
+    let default_options = SliceOptions::default();
+    let options = options.unwrap_or(&default_options);
+    compile_files(&mut state, options, patcher, validator, &CancellationToken::new(), false);
+    state
+}
+
+/// Creates a `CompilationState` holding a Slice file for each of the provided strings.
+fn create_compilation_state_from_strings(inputs: &[&str], options: Option<&SliceOptions>) -> CompilationState {
     // Create an instance of `CompilationState` for holding all the compiler's state.
-    let mut state = CompilationState::create();
+    let mut state = match options {
+        Some(slice_options) => create_compilation_state(slice_options),
+        None => CompilationState::create(),
+    };
 
     // Create a Slice file from each of the strings.
     for (i, &input) in inputs.iter().enumerate() {
         let slice_file = SliceFile::new(format!("string-{i}"), input.to_owned(), false);
         state.files.push(slice_file);
     }
+    state
+}
 
-    match options {
-        Some(slice_options) => compile_files(&mut state, slice_options, patcher, validator),
-        None => compile_files(&mut state, &SliceOptions::default(), patcher, validator),
+/// Creates an empty `CompilationState`. If the `ndjson` diagnostic format was requested, the state's diagnostics are
+/// configured to stream any errors to the requested output (or stderr) as soon as they're reported.
+fn create_compilation_state(options: &SliceOptions) -> CompilationState {
+    let mut state = CompilationState::create();
+    if options.diagnostic_format == DiagnosticFormat::Ndjson {
+        let output: Box<dyn Write> = match &options.error_output {
+            Some(error_output) => Box::new(error_output.clone()),
+            None => Box::new(console::Term::stderr()),
+        };
+        let mut stream = DiagnosticStream::new(output, options);
+        state.diagnostics.set_listener(move |diagnostic| {
+            // If the output is broken, there's nowhere left to report errors to, so we just drop them.
+            // They're still recorded in the compilation state, so compilation still fails.
+            let _ = stream.emit_diagnostic(diagnostic);
+        });
     }
-
     state
 }
 
@@ -68,21 +274,99 @@ fn compile_files(
     options: &SliceOptions,
     patcher: unsafe fn(&mut CompilationState),
     validator: fn(&mut CompilationState),
+    cancellation_token: &CancellationToken,
+    validate_only: bool,
 ) {
     // Retrieve any preprocessor symbols defined by the compiler itself, or by the user on the command line.
     let defined_symbols = HashSet::from_iter(options.defined_symbols.clone());
 
+    // Add any built-in definitions provided by the embedder before the user's files.
+    prelude::inject_prelude(&options.prelude, &mut state.files);
+
     // There are several phases of compilation handled by `slicec`:
-    // 1) Parse the files passed in by the user.
+    // 1) Parse the files passed in by the user, and collect their trivia if requested.
     // 2) Patch the abstract syntax tree generated by the parser.
     // 3) Apply the user-provided patching function.
     // 4) Validate the AST, checking for language-mapping agnostic errors.
-    // 5) Apply the user-provided validation function.
-    parsers::parse_files(state, &defined_symbols);
+    // 5) Check for any uses of types that were denied on the command line, or identifiers reserved by the embedder,
+    //    and for identifiers that collide with the keywords of the embedder's target languages.
+    //    Then run any opt-in checks: naming conventions, auditing tags, and reporting unused definitions.
+    // 6) Apply the user-provided validation function.
+    // 7) Run any validator plugins provided by the embedder.
+    // 8) Check enumerator values and tags against the lock file, if one was provided.
+    // 9) Export the source files to a schema package, if requested.
+    // 10) Write a dependency file for the source files, if requested.
+    //
+    // Between each phase, we check whether the compilation was cancelled, and if so, stop early.
+    // If `validate_only` is set, we stop after phase 7, since the later phases are only needed for code generation.
+    // We also skip collecting trivia in that case, since it's only used when printing the AST back out.
+    let start = Instant::now();
+    parsers::parse_files(state, &defined_symbols, cancellation_token);
+    if options.preserve_trivia && !validate_only {
+        trivia::preserve_trivia(state);
+    }
+    state.metrics.record_phase("parsing", start);
+    if check_for_cancellation(state, cancellation_token) {
+        return;
+    }
 
+    let start = Instant::now();
     unsafe { state.apply_unsafe(patchers::patch_ast) };
     unsafe { state.apply_unsafe(patcher) };
+    state.metrics.record_phase("patching", start);
+    if check_for_cancellation(state, cancellation_token) {
+        return;
+    }
 
+    let start = Instant::now();
     state.apply(validators::validate_ast);
+    state.metrics.record_phase("validation", start);
+    if check_for_cancellation(state, cancellation_token) {
+        return;
+    }
+
+    let start = Instant::now();
+    if !state.diagnostics.has_errors() {
+        validators::check_type_policy(state, options);
+        validators::check_reserved_identifiers(state, options);
+        validators::check_keyword_collisions(state, options);
+        validators::check_naming_conventions(state, options);
+        validators::audit_tags(state, options);
+        validators::check_unused_definitions(state, options);
+    }
     state.apply(validator);
+    if !state.diagnostics.has_errors() {
+        validators::run_validator_plugins(state, options);
+    }
+    state.metrics.record_phase("checks", start);
+    if check_for_cancellation(state, cancellation_token) || validate_only {
+        return;
+    }
+
+    let start = Instant::now();
+    if !state.diagnostics.has_errors() {
+        lock_file::check_lock_file(state, options);
+    }
+    if !state.diagnostics.has_errors() {
+        schema_package::export_schema_package(state, options);
+    }
+    if !state.diagnostics.has_errors() {
+        dependency_file::write_dependency_file(state, options);
+    }
+    if !state.diagnostics.has_errors() {
+        doc_generator::write_documentation(state, options);
+    }
+    if !state.diagnostics.has_errors() {
+        diagram::write_diagram(state, options);
+    }
+    state.metrics.record_phase("output", start);
+}
+
+/// Returns true if the provided token was cancelled, after reporting an error to stop any further passes from running.
+fn check_for_cancellation(state: &mut CompilationState, cancellation_token: &CancellationToken) -> bool {
+    let is_cancelled = cancellation_token.is_cancelled();
+    if is_cancelled {
+        Diagnostic::new(Error::CompilationCancelled).push_into(&mut state.diagnostics);
+    }
+    is_cancelled
 }
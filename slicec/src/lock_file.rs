@@ -0,0 +1,197 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module implements lock files, which record the values of enumerators and the tags of tagged members.
+//! When a lock file is provided, the compiler checks that none of the previously locked values have changed,
+//! ensuring that definitions evolve in a wire-compatible way until the lock file is intentionally regenerated.
+
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::grammar::*;
+use crate::slice_file::{SliceFile, Span};
+use crate::slice_options::SliceOptions;
+use crate::visitor::Visitor;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::{fs, io};
+
+/// The values locked by a lock file, keyed by the fully scoped identifier of the element that they belong to.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedValues {
+    /// The value of each enumerator.
+    #[serde(default)]
+    pub enumerators: BTreeMap<String, i128>,
+
+    /// The tag of each tagged field and parameter.
+    #[serde(default)]
+    pub tags: BTreeMap<String, u32>,
+}
+
+impl LockedValues {
+    /// Collects the enumerator values and tags of all the elements defined in the provided files.
+    pub fn collect_from(files: &[SliceFile]) -> Self {
+        LockedValueCollector::collect_from(files).values
+    }
+}
+
+/// Checks the current compilation against the lock file specified in `options`, if there is one.
+///
+/// If the lock file doesn't exist yet, or `--update-lock-file` was passed, the lock file is (re)generated.
+/// Otherwise, an error is reported for each locked value that was changed. When no locked values were changed,
+/// any newly defined enumerators or tags are added to the lock file. If `--dry-run` was set, values are still checked
+/// against the lock file, but it's never written.
+pub(crate) fn check_lock_file(state: &mut CompilationState, options: &SliceOptions) {
+    let Some(path) = &options.lock_file else {
+        return;
+    };
+
+    let LockedValueCollector { values, spans } = LockedValueCollector::collect_from(&state.files);
+    let diagnostics = &mut state.diagnostics;
+
+    if options.update_lock_file || !std::path::Path::new(path).exists() {
+        if !options.dry_run {
+            write_lock_file(path, &values, diagnostics);
+        }
+        return;
+    }
+
+    let Some(locked) = read_lock_file(path, diagnostics) else {
+        return;
+    };
+
+    let mut changed = false;
+    for (identifier, value) in &values.enumerators {
+        if let Some(locked_value) = locked.enumerators.get(identifier) {
+            if locked_value != value {
+                report_changed_value(identifier, "enumerator value", *locked_value, *value, &spans, diagnostics);
+                changed = true;
+            }
+        }
+    }
+    for (identifier, tag) in &values.tags {
+        if let Some(locked_tag) = locked.tags.get(identifier) {
+            if locked_tag != tag {
+                let (locked_tag, tag) = (i128::from(*locked_tag), i128::from(*tag));
+                report_changed_value(identifier, "tag", locked_tag, tag, &spans, diagnostics);
+                changed = true;
+            }
+        }
+    }
+
+    // If nothing changed, lock the values of any elements that were added since the lock file was last written.
+    if !changed && !options.dry_run {
+        let mut updated = locked.clone();
+        updated.enumerators.extend(values.enumerators);
+        updated.tags.extend(values.tags);
+        if updated != locked {
+            write_lock_file(path, &updated, diagnostics);
+        }
+    }
+}
+
+fn report_changed_value(
+    identifier: &str,
+    kind: &'static str,
+    locked: i128,
+    actual: i128,
+    spans: &BTreeMap<String, Span>,
+    diagnostics: &mut Diagnostics,
+) {
+    let mut diagnostic = Diagnostic::new(Error::LockedValueChanged {
+        identifier: identifier.to_owned(),
+        kind,
+        locked,
+        actual,
+    });
+    if let Some(span) = spans.get(identifier) {
+        diagnostic = diagnostic.set_span(span);
+    }
+    diagnostic
+        .add_note(
+            "if this change is intentional, regenerate the lock file by passing '--update-lock-file'",
+            None,
+        )
+        .push_into(diagnostics);
+}
+
+fn read_lock_file(path: &str, diagnostics: &mut Diagnostics) -> Option<LockedValues> {
+    let result = fs::read_to_string(path).and_then(|text| {
+        serde_json::from_str(&text).map_err(|error| io::Error::other(format!("malformed lock file: {error}")))
+    });
+
+    match result {
+        Ok(locked) => Some(locked),
+        Err(error) => {
+            Diagnostic::new(Error::IO {
+                action: "read",
+                path: path.to_owned(),
+                error,
+            })
+            .push_into(diagnostics);
+            None
+        }
+    }
+}
+
+fn write_lock_file(path: &str, values: &LockedValues, diagnostics: &mut Diagnostics) {
+    if let Err(error) = fs::write(path, serialize(values)) {
+        Diagnostic::new(Error::IO {
+            action: "write",
+            path: path.to_owned(),
+            error,
+        })
+        .push_into(diagnostics);
+    }
+}
+
+fn serialize(values: &LockedValues) -> String {
+    let mut text = serde_json::to_string_pretty(values).expect("failed to serialize lock file");
+    text.push('\n');
+    text
+}
+
+struct LockedValueCollector {
+    values: LockedValues,
+    spans: BTreeMap<String, Span>,
+}
+
+impl LockedValueCollector {
+    fn collect_from(files: &[SliceFile]) -> Self {
+        let mut collector = LockedValueCollector {
+            values: LockedValues::default(),
+            spans: BTreeMap::new(),
+        };
+        for slice_file in files {
+            slice_file.visit_with(&mut collector);
+        }
+        collector
+    }
+
+    fn add_tag(&mut self, member: &impl Member) {
+        if let Some(tag) = member.raw_tag() {
+            let identifier = member.parser_scoped_identifier();
+            self.spans.insert(identifier.clone(), tag.span.clone());
+            self.values.tags.insert(identifier, tag.value);
+        }
+    }
+}
+
+impl Visitor for LockedValueCollector {
+    fn visit_enumerator(&mut self, enumerator: &Enumerator) {
+        let identifier = enumerator.parser_scoped_identifier();
+        let span = match &enumerator.value {
+            EnumeratorValue::Explicit(integer) => integer.span.clone(),
+            EnumeratorValue::Constant(identifier, _) => identifier.span.clone(),
+            EnumeratorValue::Implicit(_) => enumerator.span.clone(),
+        };
+        self.spans.insert(identifier.clone(), span);
+        self.values.enumerators.insert(identifier, enumerator.value());
+    }
+
+    fn visit_field(&mut self, field: &Field) {
+        self.add_tag(field);
+    }
+
+    fn visit_parameter(&mut self, parameter: &Parameter) {
+        self.add_tag(parameter);
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module describes the events that the compiler logs, so embedders can diagnose what it's doing on large builds.
+//!
+//! Events are logged through the [log] facade, so nothing is logged unless the embedder installs a logger (like
+//! `env_logger`). Each stage of the compiler logs under its own target, so they can be filtered separately:
+//! - [FILES]: each file that's resolved, or skipped because of an ignore file (at the `debug` level).
+//! - [PARSING]: each file that's parsed, with how long preprocessing and parsing it took (at the `debug` level).
+//! - [PATCHING]: each pass which patches the AST, with how long it took (at the `debug` level).
+//! - [VALIDATION]: each file that's validated, with how long it took (at the `debug` level).
+//! - [PHASES]: each phase of compilation (see [CompilationMetrics](crate::metrics::CompilationMetrics)), with how long
+//!   it took (at the `info` level).
+//!
+//! Event messages start with a short description, followed by a list of `key=value` fields. Durations are always in
+//! milliseconds, and their keys always end with `_ms`. Ex: `parsed 'a.slice': bytes=120 lines=8 parsing_ms=0.105`.
+
+use std::time::Duration;
+
+/// The target of events about resolving the files to compile.
+pub const FILES: &str = "slicec::files";
+
+/// The target of events about parsing files.
+pub const PARSING: &str = "slicec::parsing";
+
+/// The target of events about patching the AST.
+pub const PATCHING: &str = "slicec::patching";
+
+/// The target of events about validating files.
+pub const VALIDATION: &str = "slicec::validation";
+
+/// The target of events about the phases of compilation.
+pub const PHASES: &str = "slicec::phases";
+
+/// Returns the provided duration in milliseconds, for use as the value of a `_ms` field.
+pub(crate) fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
@@ -30,11 +30,11 @@ fn encode_generate_code_request(parsed_files: &[slicec::slice_file::SliceFile])
     let mut reference_files = Vec::new();
     for parsed_file in parsed_files {
         // Convert the Slice file from AST representation to Slice representation.
-        let converted_file = crate::definition_types::SliceFile::from(parsed_file);
+        let converted_files = crate::slice_file_converter::convert_slice_file(parsed_file)?;
         // Determine whether this is a source or reference file and place it accordingly.
         match parsed_file.is_source {
-            true => source_files.push(converted_file),
-            false => reference_files.push(converted_file),
+            true => source_files.extend(converted_files),
+            false => reference_files.extend(converted_files),
         }
     }
 
@@ -47,15 +47,68 @@ fn encode_generate_code_request(parsed_files: &[slicec::slice_file::SliceFile])
 }
 
 fn main() -> ExitCode {
-    // Parse the command-line input.
-    let slice_options = SliceOptions::parse();
+    // Parse the command-line input, and load any options from a configuration file.
+    let mut slice_options = SliceOptions::parse();
+    let mut config_diagnostics = slicec::diagnostics::Diagnostics::new();
+    slicec::config_file::apply_config_file(&mut slice_options, &mut config_diagnostics);
+    if config_diagnostics.has_errors() {
+        for diagnostic in config_diagnostics.into_inner() {
+            eprintln!("{diagnostic:?}");
+        }
+        return ExitCode::from(1);
+    }
+
+    // In watch mode, we only emit diagnostics, and keep recompiling until the process is killed.
+    if slice_options.watch {
+        let cancellation_token = slicec::compilation_state::CancellationToken::new();
+        let poll_interval = slicec::watch::DEFAULT_POLL_INTERVAL;
+        slicec::watch::watch(&slice_options, poll_interval, |_| {}, |_| {}, &cancellation_token, |state| {
+            let CompilationState { ast, diagnostics, files, .. } = state;
+            let updated_diagnostics = diagnostics.into_updated(&ast, &files, &slice_options);
+            let totals = slicec::diagnostics::get_totals(&updated_diagnostics);
+            print!("Diagnostics: ");
+            println!("{totals:?}");
+            for diagnostic in updated_diagnostics {
+                println!("{diagnostic:?}");
+            }
+        });
+        return ExitCode::from(0);
+    }
 
     // Perform the compilation.
     let compilation_state = slicec::compile_from_options(&slice_options, |_| {}, |_| {});
-    let CompilationState { ast, diagnostics, files } = compilation_state;
+    let CompilationState { ast, diagnostics, files, metrics, .. } = compilation_state;
+    if let Some(format) = slice_options.timings {
+        eprint!("{}", metrics.report(format));
+    }
 
     // Process the diagnostics (filter out allowed lints, and update diagnostic levels as necessary).
-    let updated_diagnostics = diagnostics.into_updated(&ast, &files, &slice_options);
+    let has_errors = diagnostics.has_errors();
+    let mut updated_diagnostics = diagnostics.into_updated(&ast, &files, &slice_options);
+
+    // If requested, apply any suggested fixes to the source files. We don't apply fixes if there were any errors,
+    // since the AST (and the suggestions made from it) may be incomplete.
+    if slice_options.fix && !has_errors {
+        let fixed_files = slicec::fixes::collect_fixes(&files, &updated_diagnostics);
+        let mut fix_diagnostics = slicec::diagnostics::Diagnostics::new();
+        slicec::fixes::write_fixes(&fixed_files, &mut fix_diagnostics);
+        if fix_diagnostics.has_errors() {
+            for diagnostic in fix_diagnostics.into_inner() {
+                eprintln!("{diagnostic:?}");
+            }
+            return ExitCode::from(1);
+        }
+
+        let fix_count = fixed_files.iter().map(|fixed_file| fixed_file.fix_count).sum::<usize>();
+        eprintln!("Applied {fix_count} fix(es) to {} file(s)", fixed_files.len());
+
+        // Don't report diagnostics that were just fixed. Any fixes that were skipped will be reported next time.
+        let is_fixed = |file: &String| fixed_files.iter().any(|fixed_file| &fixed_file.relative_path == file);
+        updated_diagnostics.retain(|diagnostic| {
+            let suggestions = diagnostic.suggestions();
+            suggestions.is_empty() || !suggestions.iter().all(|suggestion| is_fixed(&suggestion.span.file))
+        });
+    }
     let totals = slicec::diagnostics::get_totals(&updated_diagnostics);
 
     // TODO: replace this by forking a code-gen plugin once they exist.
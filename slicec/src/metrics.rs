@@ -0,0 +1,111 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains the logic for collecting and reporting how long each phase of a compilation took.
+
+use crate::logging;
+use crate::slice_options::TimingsFormat;
+use serde::{Serialize, Serializer};
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// Timing information and statistics that were collected during a compilation.
+///
+/// Metrics are always collected, since doing so is cheap. They're only reported if the `--timings` option is set.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CompilationMetrics {
+    /// How long each phase of the compilation took, in the order the phases ran in.
+    /// Phases that didn't run (because of errors reported by an earlier phase) aren't included.
+    pub phases: Vec<PhaseMetrics>,
+
+    /// Statistics for each file that was parsed, in the order the files were parsed in.
+    pub files: Vec<FileMetrics>,
+}
+
+/// How long a single phase of compilation took. See [CompilationMetrics::phases].
+#[derive(Clone, Debug, Serialize)]
+pub struct PhaseMetrics {
+    /// The name of the phase (Ex: `"parsing"`).
+    pub name: &'static str,
+
+    #[serde(rename = "duration_ms", serialize_with = "serialize_millis")]
+    pub duration: Duration,
+}
+
+/// Statistics about a single Slice file. See [CompilationMetrics::files].
+#[derive(Clone, Debug, Serialize)]
+pub struct FileMetrics {
+    pub relative_path: String,
+    pub bytes: usize,
+    pub lines: usize,
+
+    /// How long it took to preprocess the file.
+    #[serde(rename = "preprocessing_ms", serialize_with = "serialize_millis")]
+    pub preprocessing: Duration,
+
+    /// How long it took to lex and parse the preprocessed file (lexing is done on demand by the parser).
+    #[serde(rename = "parsing_ms", serialize_with = "serialize_millis")]
+    pub parsing: Duration,
+}
+
+impl CompilationMetrics {
+    /// Records that a phase with the provided name ran from `start` until now.
+    pub fn record_phase(&mut self, name: &'static str, start: Instant) {
+        let duration = start.elapsed();
+        log::info!(target: logging::PHASES, "finished {name}: duration_ms={:.3}", logging::millis(duration));
+        self.phases.push(PhaseMetrics { name, duration });
+    }
+
+    /// Returns how long the phase with the provided name took, or `None` if no such phase was run.
+    pub fn phase(&self, name: &str) -> Option<Duration> {
+        self.phases.iter().find(|phase| phase.name == name).map(|phase| phase.duration)
+    }
+
+    /// Returns the total duration of all the phases.
+    pub fn total(&self) -> Duration {
+        self.phases.iter().map(|phase| phase.duration).sum()
+    }
+
+    /// Returns a report of these metrics in the provided format. Reports always end with a newline.
+    pub fn report(&self, format: TimingsFormat) -> String {
+        match format {
+            TimingsFormat::Human => self.human_report(),
+            TimingsFormat::Json => {
+                let json = serde_json::to_string(self).expect("failed to serialize compilation metrics");
+                json + "\n"
+            }
+        }
+    }
+
+    fn human_report(&self) -> String {
+        let mut report = String::from("Compilation timings:\n");
+        for phase in &self.phases {
+            writeln!(report, "    {:<16} {}", phase.name, format_millis(phase.duration)).unwrap();
+        }
+        writeln!(report, "    {:<16} {}", "total", format_millis(self.total())).unwrap();
+
+        if !self.files.is_empty() {
+            report.push_str("File statistics:\n");
+        }
+        for file in &self.files {
+            writeln!(
+                report,
+                "    {} ({} lines, {} bytes): preprocessing {}, parsing {}",
+                file.relative_path,
+                file.lines,
+                file.bytes,
+                format_millis(file.preprocessing),
+                format_millis(file.parsing),
+            )
+            .unwrap();
+        }
+        report
+    }
+}
+
+fn format_millis(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn serialize_millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+}
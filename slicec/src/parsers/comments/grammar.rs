@@ -5,7 +5,10 @@
 //! While many of these functions could be written directly into the parser rules, we implement them here instead, to
 //! keep the rules focused on grammar instead of implementation details, making the grammar easier to read and modify.
 
-use crate::grammar::{DocComment, Message, MessageComponent};
+use super::parser::CommentParser;
+use crate::diagnostics::{Diagnostic, Lint};
+use crate::grammar::{ConstantValue, DocComment, ExampleValue, Identifier, Message, MessageComponent};
+use crate::parsers::common::{normalize_identifier, unescape_literal};
 use crate::slice_file::{Location, Span};
 use lalrpop_util::lalrpop_mod;
 
@@ -30,6 +33,25 @@ use append_tag_to_comment; // To let LALRPOP use the macro.
 
 // Grammar Rule Functions
 
+/// Decodes the escape sequences in the provided string literal, reporting a lint for each invalid escape sequence.
+/// The provided location is the start of the literal, which is always its opening quotation mark.
+fn unescape_string_literal(comment_parser: &mut CommentParser, s: &str, start: Location) -> String {
+    let (unescaped, invalid_escapes) = unescape_literal(s);
+    for invalid_escape in invalid_escapes {
+        let mut escape_start = start;
+        escape_start.col += 1 + invalid_escape.offset; // Skip over the opening quote and any preceding characters.
+        let mut escape_end = escape_start;
+        escape_end.col += invalid_escape.sequence.chars().count();
+
+        let message = format!("invalid escape sequence '{}': {}", invalid_escape.sequence, invalid_escape.reason);
+        Diagnostic::new(Lint::MalformedDocComment { message })
+            .set_span(&Span::new(escape_start, escape_end, comment_parser.file_name))
+            .set_scope(comment_parser.identifier)
+            .push_into(comment_parser.diagnostics);
+    }
+    unescaped
+}
+
 /// Creates a new doc comment with the specified overview and everything else empty.
 fn create_doc_comment(overview: Option<Message>, start: Location, file: &str) -> DocComment {
     // We subtract 3 from the start of the comment to account for the leading "///" that is always present.
@@ -48,10 +70,23 @@ fn create_doc_comment(overview: Option<Message>, start: Location, file: &str) ->
         returns: Vec::new(),
         throws: Vec::new(),
         see: Vec::new(),
+        examples: Vec::new(),
+        inherit_doc: None,
+        locales: Vec::new(),
         span,
     }
 }
 
+/// Converts an identifier used as an example value into a value. `true` and `false` are treated as boolean literals,
+/// and any other identifier is assumed to reference an enumerator.
+fn construct_example_identifier_value(identifier: Identifier) -> ExampleValue {
+    match identifier.value.as_str() {
+        "true" => ExampleValue::Literal(ConstantValue::Bool(true)),
+        "false" => ExampleValue::Literal(ConstantValue::Bool(false)),
+        _ => ExampleValue::Enumerator(identifier),
+    }
+}
+
 /// Creates a string representing a Slice identifier that can be relatively or globally scoped.
 fn get_scoped_identifier_string<'a>(first: &'a str, mut others: Vec<&'a str>, is_globally_scoped: bool) -> String {
     others.insert(0, first);
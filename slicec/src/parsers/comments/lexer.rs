@@ -1,6 +1,7 @@
 // Copyright (c) ZeroC, Inc.
 
 use super::tokens::*;
+use crate::parsers::{is_identifier_continue, is_identifier_start};
 use crate::slice_file::{Location, Span};
 
 use std::iter::Peekable;
@@ -34,6 +35,10 @@ pub struct Lexer<'input> {
 
     /// The current mode of the lexer; controls how the input is tokenized in a context-dependent manner.
     mode: LexerMode,
+
+    /// The location of the opening '{' of the inline tag currently being lexed.
+    /// Only meaningful while the lexer is in [`InlineTag`](LexerMode::InlineTag) mode.
+    inline_tag_start: Location,
 }
 
 impl<'input> Lexer<'input> {
@@ -50,6 +55,7 @@ impl<'input> Lexer<'input> {
             position: 0,
             cursor: Location::default(),
             mode: LexerMode::Message,
+            inline_tag_start: Location::default(),
         };
         lexer.switch_to_next_line(first_line, first_span); // Actually initialize the lexer.
         lexer
@@ -89,13 +95,13 @@ impl<'input> Lexer<'input> {
         }
     }
 
-    /// Reads, consumes, and returns a string of alphanumeric characters from the buffer.
-    /// After calling this function, the next char will be a non-alphanumeric character or `None` (end-of-buffer).
+    /// Reads, consumes, and returns an identifier from the buffer. After calling this function, the next char will be
+    /// one that can't appear in an identifier, or `None` (end-of-buffer).
     fn read_identifier(&mut self) -> &'input str {
         let start_position = self.position;
 
-        // Loop while the next character in the buffer is an alphanumeric or underscore.
-        while matches!(self.buffer.peek(), Some(c) if (c.is_ascii_alphanumeric() || *c == '_')) {
+        // Loop while the next character in the buffer can be part of an identifier.
+        while matches!(self.buffer.peek(), Some(c) if is_identifier_continue(*c)) {
             self.advance_buffer(); // Consume the character.
         }
 
@@ -124,6 +130,9 @@ impl<'input> Lexer<'input> {
             "throws" => Ok((start_location, TokenKind::ThrowsKeyword, self.cursor)),
             "see" => Ok((start_location, TokenKind::SeeKeyword, self.cursor)),
             "link" => Ok((start_location, TokenKind::LinkKeyword, self.cursor)),
+            "example" => Ok((start_location, TokenKind::ExampleKeyword, self.cursor)),
+            "inheritdoc" => Ok((start_location, TokenKind::InheritDocKeyword, self.cursor)),
+            "locale" => Ok((start_location, TokenKind::LocaleKeyword, self.cursor)),
             "" => Err((start_location, ErrorKind::MissingTag, self.cursor)),
             tag => Err((start_location, ErrorKind::UnknownTag { tag }, self.cursor)),
         };
@@ -136,7 +145,10 @@ impl<'input> Lexer<'input> {
                 TokenKind::ParamKeyword
                 | TokenKind::ReturnsKeyword
                 | TokenKind::ThrowsKeyword
-                | TokenKind::SeeKeyword => !is_inline,
+                | TokenKind::SeeKeyword
+                | TokenKind::ExampleKeyword
+                | TokenKind::InheritDocKeyword
+                | TokenKind::LocaleKeyword => !is_inline,
 
                 // These tags are only valid inline.
                 TokenKind::LinkKeyword => is_inline,
@@ -147,12 +159,119 @@ impl<'input> Lexer<'input> {
                 let error = ErrorKind::IncorrectContextForTag { tag: ident, is_inline };
                 return Err((*start, error, *end));
             }
+
+            // Inline tags take a single argument, which we check here so errors can point at the offending text.
+            if is_inline {
+                self.check_inline_tag_argument(ident)?;
+            }
         }
 
         // If all the checks were fine, we return the token here.
         token
     }
 
+    /// Checks that the remainder of the current inline tag (up to its closing '}') is a single scoped identifier.
+    /// This doesn't consume anything from the buffer; it only looks ahead to report precise errors.
+    ///
+    /// If the tag has no argument, this returns a `MissingInlineTagArgument` error spanning the entire tag.
+    /// If anything other than a scoped identifier is present, this returns an `InvalidInlineTagArgument` error
+    /// spanning only the offending text.
+    fn check_inline_tag_argument(&self, tag: &'input str) -> Result<(), Error<'input>> {
+        let remainder = &self.current_line[self.position..];
+        let (argument, is_terminated) = match remainder.find('}') {
+            Some(index) => (&remainder[..index], true),
+            None => (remainder, false),
+        };
+
+        // Returns the location of the character at the specified byte offset in `remainder`.
+        let location_of = |offset: usize| Location {
+            row: self.cursor.row,
+            col: self.cursor.col + remainder[..offset].chars().count(),
+        };
+
+        // If the argument is missing, the error spans from the opening '{' to the closing '}' (inclusive).
+        let trimmed = argument.trim();
+        if trimmed.is_empty() {
+            let end = location_of(argument.len() + usize::from(is_terminated));
+            return Err((self.inline_tag_start, ErrorKind::MissingInlineTagArgument { tag }, end));
+        }
+
+        // Skip over the (possibly global) scoped identifier at the start of the argument, if there is one.
+        let start_offset = argument.len() - argument.trim_start().len();
+        let mut rest = &argument[start_offset..];
+        rest = rest.strip_prefix("::").unwrap_or(rest);
+        let mut identifier_end = start_offset;
+        while rest.starts_with(is_identifier_start) {
+            let length = rest.find(|c: char| !is_identifier_continue(c));
+            rest = &rest[length.unwrap_or(rest.len())..];
+            identifier_end = argument.len() - rest.len();
+            match rest.strip_prefix("::") {
+                Some(remaining) => rest = remaining,
+                None => break,
+            }
+        }
+
+        // If anything (besides whitespace) follows the identifier, or there was no identifier, report it.
+        let invalid = argument[identifier_end..].trim_end();
+        if !invalid.is_empty() {
+            let start = identifier_end + (invalid.len() - invalid.trim_start().len());
+            let end = identifier_end + invalid.len();
+            let error = ErrorKind::InvalidInlineTagArgument {
+                tag,
+                argument: &argument[start..end],
+            };
+            return Err((location_of(start), error, location_of(end)));
+        }
+        Ok(())
+    }
+
+    /// Reads, consumes, and returns a string literal from the buffer. Backslashes escape the character after them.
+    /// The surrounding quotes are consumed, but aren't included in the returned token.
+    /// If the end of the line is reached before a closing quote, this returns an `UnterminatedStringLiteral` error.
+    fn read_string_literal(&mut self) -> LexerResult<'input> {
+        let start_location = self.cursor;
+
+        // Consume the opening '"' character.
+        debug_assert!(matches!(self.buffer.peek(), Some('"')));
+        self.advance_buffer();
+        let start_position = self.position;
+
+        let mut is_escaped = false;
+        while let Some(c) = self.buffer.peek().cloned() {
+            if c == '"' && !is_escaped {
+                let literal = &self.current_line[start_position..self.position];
+                self.advance_buffer(); // Consume the closing '"' character.
+                return Ok((start_location, TokenKind::StringLiteral(literal), self.cursor));
+            }
+            is_escaped = c == '\\' && !is_escaped;
+            self.advance_buffer(); // Consume the character.
+        }
+
+        Err((start_location, ErrorKind::UnterminatedStringLiteral, self.cursor))
+    }
+
+    /// Reads, consumes, and returns a (possibly negative) decimal integer literal from the buffer.
+    /// If the literal doesn't fit within an `i128`, this returns an `IntegerLiteralOutOfRange` error.
+    fn read_integer_literal(&mut self) -> LexerResult<'input> {
+        let start_location = self.cursor;
+        let start_position = self.position;
+
+        // Consume the optional leading '-', then any digits which follow it.
+        if matches!(self.buffer.peek(), Some('-')) {
+            self.advance_buffer();
+        }
+        while matches!(self.buffer.peek(), Some(c) if c.is_ascii_digit()) {
+            self.advance_buffer(); // Consume the digit.
+        }
+
+        let literal = &self.current_line[start_position..self.position];
+        match literal.parse::<i128>() {
+            Ok(value) => Ok((start_location, TokenKind::IntegerLiteral(value), self.cursor)),
+            Err(_) if literal == "-" => Err((start_location, ErrorKind::UnknownSymbol { symbol: '-' }, self.cursor)),
+            Err(_) => Err((start_location, ErrorKind::IntegerLiteralOutOfRange { literal }, self.cursor)),
+        }
+    }
+
     /// Reads and returns a token from the buffer while the lexer is in `Message` mode.
     /// If the first character in the buffer is a '{', this function checks if it's the start of an inline tag.
     /// If it is, this returns a '{' token and switches the lexer to `InlineTag` mode.
@@ -171,6 +290,7 @@ impl<'input> Lexer<'input> {
 
             if matches!(self.buffer.peek(), Some('@')) {
                 self.mode = LexerMode::InlineTag;
+                self.inline_tag_start = start_location;
                 return (start_location, TokenKind::LeftBrace, self.cursor);
             }
         }
@@ -226,8 +346,27 @@ impl<'input> Lexer<'input> {
                 Ok((start_location, TokenKind::RightBrace, self.cursor))
             }
 
+            // These symbols can only appear in the argument list of an example tag.
+            '(' | ')' | '=' | ',' => {
+                let start_location = self.cursor;
+                self.advance_buffer(); // Consume the symbol.
+                let token = match c {
+                    '(' => TokenKind::LeftParenthesis,
+                    ')' => TokenKind::RightParenthesis,
+                    '=' => TokenKind::Equals,
+                    _ => TokenKind::Comma,
+                };
+                Ok((start_location, token, self.cursor))
+            }
+
+            // If the next character is a '"', it's the start of a string literal.
+            '"' => self.read_string_literal(),
+
+            // If the next character is a digit or '-', it's the start of an integer literal.
+            c if c.is_ascii_digit() || c == '-' => self.read_integer_literal(),
+
             // If the next character is a letter, it's the start of an identifier.
-            c if c.is_ascii_alphabetic() => {
+            c if is_identifier_start(c) => {
                 let start_location = self.cursor;
                 let identifier = self.read_identifier();
                 Ok((start_location, TokenKind::Identifier(identifier), self.cursor))
@@ -270,7 +409,7 @@ impl<'input> Iterator for Lexer<'input> {
             // So, we return an `UnterminatedInlineTag` error since inline tags can't span multiple lines.
             LexerMode::InlineTag => {
                 self.mode = LexerMode::Message; // Change the mode so the error is only reported once.
-                Some(Err((self.cursor, ErrorKind::UnterminatedInlineTag, self.cursor)))
+                Some(Err((self.inline_tag_start, ErrorKind::UnterminatedInlineTag, self.cursor)))
             }
 
             // If the lexer is in `Message` or `BlockTag` mode when it hit EOL, this is normal and expected.
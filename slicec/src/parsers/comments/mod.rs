@@ -5,7 +5,7 @@ pub mod lexer;
 pub mod parser;
 pub mod tokens;
 
-use self::tokens::{Error, TokenKind};
+use self::tokens::{Error, ErrorKind, TokenKind};
 use crate::diagnostics::{Diagnostic, Lint};
 use crate::slice_file::{Location, Span};
 
@@ -21,8 +21,12 @@ fn construct_lint_from(parse_error: ParseError, file_name: &str) -> Diagnostic {
         ParseError::User {
             error: (start, parse_error_kind, end),
         } => {
-            let converted = Lint::MalformedDocComment {
-                message: parse_error_kind.to_string(),
+            let message = parse_error_kind.to_string();
+            let converted = match parse_error_kind {
+                ErrorKind::UnterminatedInlineTag
+                | ErrorKind::MissingInlineTagArgument { .. }
+                | ErrorKind::InvalidInlineTagArgument { .. } => Lint::MalformedInlineTag { message },
+                _ => Lint::MalformedDocComment { message },
             };
             Diagnostic::new(converted).set_span(&Span::new(start, end, file_name))
         }
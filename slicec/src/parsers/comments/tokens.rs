@@ -19,6 +19,12 @@ pub enum TokenKind<'input> {
     /// lines that continue another section, or after a ':' character on lines that start a new section.
     Text(&'input str),
 
+    /// An integer literal, which can only appear in the argument list of an example tag.
+    IntegerLiteral(i128), // "-?[0-9]+"
+
+    /// A string literal (without its surrounding quotes), which can only appear in the argument list of an example tag.
+    StringLiteral(&'input str),
+
     Newline, // "\n"
 
     // Tag keywords
@@ -27,12 +33,19 @@ pub enum TokenKind<'input> {
     ThrowsKeyword,  // "@throws"
     SeeKeyword,     // "@see"
     LinkKeyword,    // "@link"
+    ExampleKeyword, // "@example"
+    InheritDocKeyword, // "@inheritdoc"
+    LocaleKeyword,  // "@locale"
 
     // Symbols
-    LeftBrace,   // "{"
-    RightBrace,  // "}"
-    Colon,       // ":"
-    DoubleColon, // "::"
+    LeftBrace,        // "{"
+    RightBrace,       // "}"
+    LeftParenthesis,  // "("
+    RightParenthesis, // ")"
+    Colon,            // ":"
+    DoubleColon,      // "::"
+    Equals,           // "="
+    Comma,            // ","
 }
 
 /// This enum specifies all the kinds of errors that the comment [Lexer](super::lexer::Lexer) can return.
@@ -58,6 +71,22 @@ pub enum ErrorKind<'input> {
     /// Some tags can only be used inline, and others can only be used to start a new section.
     /// Ex: `{@param MyParam}`, param tags can't be used inline, and must be at the start of a new section.
     IncorrectContextForTag { tag: &'input str, is_inline: bool },
+
+    /// Returned when an inline tag is missing its argument.
+    /// Ex: `{@link}`, link tags must specify the element they're linking to.
+    MissingInlineTagArgument { tag: &'input str },
+
+    /// Returned when the argument of an inline tag isn't a valid scoped identifier, or has extra text after it.
+    /// Ex: `{@link Foo(bar)}`, "(bar)" can't appear in a link tag.
+    InvalidInlineTagArgument { tag: &'input str, argument: &'input str },
+
+    /// Returned when a string literal is missing its closing quote. Note that they cannot span multiple lines.
+    /// Ex: `@example(name = "foo)`, there's no closing '"' on this line.
+    UnterminatedStringLiteral,
+
+    /// Returned when an integer literal doesn't fit within the range of integers supported by the compiler.
+    /// Ex: `@example(value = 999999999999999999999999999999999999999999)`.
+    IntegerLiteralOutOfRange { literal: &'input str },
 }
 
 impl fmt::Display for ErrorKind<'_> {
@@ -71,6 +100,12 @@ impl fmt::Display for ErrorKind<'_> {
                 "doc comment tag '{tag}' cannot be used {}",
                 if *is_inline { "inline" } else { "to start a block" },
             )),
+            Self::MissingInlineTagArgument { tag } => write!(f, "doc comment tag '{tag}' is missing its argument"),
+            Self::InvalidInlineTagArgument { tag, argument } => {
+                write!(f, "'{argument}' is not a valid argument for doc comment tag '{tag}'")
+            }
+            Self::UnterminatedStringLiteral => f.write_str("unterminated string literal"),
+            Self::IntegerLiteralOutOfRange { literal } => write!(f, "integer literal '{literal}' is out of range"),
         }
     }
 }
@@ -3,6 +3,7 @@
 //! This module contains common types and functions that are useful to multiple parsers.
 
 use crate::slice_file::Location;
+use unicode_normalization::UnicodeNormalization;
 
 /// Stores a reference to a block of source code in a Slice file.
 #[derive(Clone, Copy, Debug)]
@@ -19,3 +20,107 @@ pub struct SourceBlock<'input> {
 /// A specialized [Result] type used by parsing functions. The `Err` variant is empty because errors are pushed into a
 /// [Diagnostics](crate::diagnostics::Diagnostics) container instead of being directly returned.
 pub type ParserResult<T> = Result<T, ()>;
+
+/// An escape sequence in a string or character literal which couldn't be decoded.
+#[derive(Debug)]
+pub struct InvalidEscape {
+    /// The invalid escape sequence, including its leading backslash. Ex: `\q`.
+    pub sequence: String,
+    /// Why the escape sequence is invalid.
+    pub reason: String,
+    /// The number of characters between the start of the literal's contents and the start of the escape sequence.
+    pub offset: usize,
+}
+
+/// Decodes the escape sequences in the contents of a string or character literal, returning the decoded string, and
+/// any invalid escape sequences it contained (which are left out of the decoded string).
+///
+/// The supported escape sequences are `\n`, `\r`, `\t`, `\0`, `\\`, `\'`, `\"`, and `\u{...}`, which holds the
+/// hexadecimal value of a Unicode code point, using between 1 and 6 digits. Ex: `\u{1F60A}`.
+pub fn unescape_literal(s: &str) -> (String, Vec<InvalidEscape>) {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut invalid_escapes = Vec::new();
+
+    let mut chars = s.chars().enumerate().peekable();
+    while let Some((offset, c)) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        // The lexer never ends a literal on an unescaped backslash, so there's always a character after it.
+        let Some((_, escaped)) = chars.next() else { break };
+        let mut sequence = format!("\\{escaped}");
+        let result = match escaped {
+            'n' => Ok('\n'),
+            'r' => Ok('\r'),
+            't' => Ok('\t'),
+            '0' => Ok('\0'),
+            '\\' | '\'' | '"' => Ok(escaped),
+            'u' => {
+                // Read the code point's digits, stopping at the first character that isn't a hex digit.
+                let has_open_brace = chars.next_if(|(_, c)| *c == '{').is_some();
+                let mut digits = String::new();
+                while let Some((_, digit)) = chars.next_if(|(_, c)| has_open_brace && c.is_ascii_hexdigit()) {
+                    digits.push(digit);
+                }
+                let has_close_brace = has_open_brace && chars.next_if(|(_, c)| *c == '}').is_some();
+
+                if has_open_brace {
+                    sequence.push('{');
+                    sequence.push_str(&digits);
+                }
+                if has_close_brace {
+                    sequence.push('}');
+                }
+
+                match (has_open_brace, has_close_brace) {
+                    (false, _) => Err("expected '{' after '\\u'".to_owned()),
+                    (true, false) => Err("expected hexadecimal digits followed by a closing '}'".to_owned()),
+                    _ if digits.is_empty() || digits.len() > 6 => {
+                        Err("unicode escapes must contain between 1 and 6 hexadecimal digits".to_owned())
+                    }
+                    _ => u32::from_str_radix(&digits, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| format!("'{digits}' isn't a valid Unicode code point")),
+                }
+            }
+            _ => Err(format!("unknown escape character '{escaped}'")),
+        };
+
+        match result {
+            Ok(c) => unescaped.push(c),
+            Err(reason) => invalid_escapes.push(InvalidEscape {
+                sequence,
+                reason,
+                offset,
+            }),
+        }
+    }
+    (unescaped, invalid_escapes)
+}
+
+/// Returns true if the provided character can start an identifier. ASCII identifiers must start with a letter, and
+/// non-ASCII identifiers can start with any character that has the Unicode `XID_Start` property.
+pub fn is_identifier_start(c: char) -> bool {
+    match c.is_ascii() {
+        true => c.is_ascii_alphabetic(),
+        false => unicode_ident::is_xid_start(c),
+    }
+}
+
+/// Returns true if the provided character can appear after the first character of an identifier. This includes ASCII
+/// letters, digits, and underscores, and any non-ASCII character that has the Unicode `XID_Continue` property.
+pub fn is_identifier_continue(c: char) -> bool {
+    match c.is_ascii() {
+        true => c.is_ascii_alphanumeric() || c == '_',
+        false => unicode_ident::is_xid_continue(c),
+    }
+}
+
+/// Returns the Unicode Normalization Form C (NFC) of the provided identifier, so identifiers which are written with
+/// different, but canonically equivalent, sequences of code points (Ex: 'é' and 'e' + U+0301) are treated as equal.
+pub fn normalize_identifier(identifier: &str) -> String {
+    identifier.nfc().collect()
+}
@@ -6,6 +6,8 @@
 pub use self::comments::parser::CommentParser;
 pub use self::preprocessor::parser::Preprocessor;
 pub use self::slice::parser::Parser;
+pub(crate) use self::common::{is_identifier_continue, is_identifier_start};
+pub(crate) use self::slice::lexer::is_keyword;
 
 mod comments;
 mod common;
@@ -13,33 +15,76 @@ mod preprocessor;
 mod slice;
 
 use crate::ast::Ast;
-use crate::compilation_state::CompilationState;
+use crate::compilation_state::{CancellationToken, CompilationState};
 use crate::diagnostics::{Diagnostic, Diagnostics, Error};
-use crate::slice_file::SliceFile;
+use crate::logging;
+use crate::metrics::FileMetrics;
+use crate::slice_file::{Location, SliceFile};
+use crate::token_stream::TokenCategory;
 use std::collections::HashSet;
+use std::time::{Duration, Instant};
 
-pub fn parse_files(state: &mut CompilationState, symbols: &HashSet<String>) {
+pub fn parse_files(state: &mut CompilationState, symbols: &HashSet<String>, cancellation_token: &CancellationToken) {
     for file in &mut state.files {
+        // Stop parsing if the compilation was cancelled. It's up to the caller to report this.
+        if cancellation_token.is_cancelled() {
+            break;
+        }
+
+        let mut metrics = FileMetrics {
+            relative_path: file.relative_path.clone(),
+            bytes: file.raw_text.len(),
+            lines: file.raw_text.lines().count(),
+            preprocessing: Duration::ZERO,
+            parsing: Duration::ZERO,
+        };
+
         // Attempt to parse the file.
         let mut diagnostics = Diagnostics::new();
-        parse_file(file, &mut state.ast, &mut diagnostics, symbols.clone());
+        let file_symbols = file.defined_symbols.get_or_insert_with(|| symbols.clone()).clone();
+        parse_file(file, &mut state.ast, &mut diagnostics, file_symbols, &mut metrics);
+
+        log::debug!(
+            target: logging::PARSING,
+            "parsed '{}': bytes={} lines={} preprocessing_ms={:.3} parsing_ms={:.3}",
+            metrics.relative_path,
+            metrics.bytes,
+            metrics.lines,
+            logging::millis(metrics.preprocessing),
+            logging::millis(metrics.parsing),
+        );
 
-        // Store any diagnostics that were emitted during parsing.
+        // Store any diagnostics that were emitted during parsing, and the file's statistics.
         state.diagnostics.extend(diagnostics);
+        state.metrics.files.push(metrics);
     }
 }
 
-fn parse_file(file: &mut SliceFile, ast: &mut Ast, diagnostics: &mut Diagnostics, mut symbols: HashSet<String>) {
+fn parse_file(
+    file: &mut SliceFile,
+    ast: &mut Ast,
+    diagnostics: &mut Diagnostics,
+    mut symbols: HashSet<String>,
+    metrics: &mut FileMetrics,
+) {
     // Pre-process the file's raw text.
+    let start = Instant::now();
     let preprocessor = Preprocessor::new(&file.relative_path, &mut symbols, diagnostics);
-    let Ok(preprocessed_text) = preprocessor.parse_slice_file(file.raw_text.as_str()) else { return };
+    let preprocessor_result = preprocessor.parse_slice_file(file.raw_text.as_str());
+    metrics.preprocessing = start.elapsed();
+    let Ok(preprocessed_text) = preprocessor_result else { return };
 
     // Parse the preprocessed text.
+    let start = Instant::now();
     let parser = Parser::new(&file.relative_path, ast, diagnostics);
-    let Ok((mode, attributes, module, definitions)) = parser.parse_slice_file(preprocessed_text) else { return };
+    let parser_result = parser.parse_slice_file(preprocessed_text);
+    metrics.parsing = start.elapsed();
+    let Ok((comment, mode, attributes, module, module_blocks, definitions)) = parser_result else {
+        return;
+    };
 
-    // Issue a syntax error if the user had definitions but forgot to declare a module.
-    if !definitions.is_empty() && module.is_none() {
+    // Issue a syntax error if the user had definitions outside of any module.
+    if definitions.iter().any(|d| d.borrow().get_raw_scope().module.is_none()) {
         Diagnostic::new(Error::Syntax {
             // TODO improve this message, see: #348
             message: "module declaration is required".to_owned(),
@@ -48,8 +93,24 @@ fn parse_file(file: &mut SliceFile, ast: &mut Ast, diagnostics: &mut Diagnostics
     }
 
     // Store the parsed data in the `SliceFile` it was parsed from.
+    file.comment = comment;
     file.mode = mode;
-    file.module = module.map(|m| ast.add_named_element(m));
+    let module = module.map(|m| ast.add_named_element(m));
+    file.modules = module.into_iter().chain(module_blocks).collect();
     file.attributes = attributes;
     file.contents = definitions;
 }
+
+/// Lexes the provided blocks of Slice source code, each of which is given as its content and starting and ending
+/// locations. This returns the location and category of each token (or error) found in them, in order.
+/// Whitespace and non-doc comments are skipped over by the lexer, and so aren't returned.
+pub fn lex_blocks(blocks: Vec<(&str, Location, Location)>) -> Vec<(Location, TokenCategory, Location)> {
+    let source_blocks = blocks.into_iter().map(|(content, start, end)| common::SourceBlock { content, start, end });
+    let lexer = slice::lexer::Lexer::from(source_blocks);
+    lexer
+        .map(|result| match result {
+            Ok((start, token_kind, end)) => (start, token_kind.category(), end),
+            Err((start, error_kind, end)) => (start, error_kind.category(), end),
+        })
+        .collect()
+}
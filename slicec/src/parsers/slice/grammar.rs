@@ -6,14 +6,16 @@
 //! keep the rules focused on grammar instead of implementation details, making the grammar easier to read and modify.
 
 use super::parser::Parser;
+use super::tokens::{self, TokenKind};
 use crate::ast::node::Node;
-use crate::diagnostics::{Diagnostic, Error};
+use crate::parsers::common::{normalize_identifier, unescape_literal};
+use crate::diagnostics::{Diagnostic, Error, Lint};
 use crate::grammar::*;
 use crate::parsers::CommentParser;
-use crate::slice_file::Span;
+use crate::slice_file::{Location, Span};
 use crate::utils::ptr_util::{OwnedPtr, WeakPtr};
 use crate::{downgrade_as, upcast_weak_as};
-use lalrpop_util::lalrpop_mod;
+use lalrpop_util::{lalrpop_mod, ErrorRecovery};
 use std::num::IntErrorKind;
 use std::ops::RangeInclusive;
 
@@ -53,11 +55,76 @@ macro_rules! set_fields_for {
     }};
 }
 
+macro_rules! set_constants_for {
+    ($parent_ptr:expr, $constants:ident, $parser:expr) => {{
+        for constant in $constants {
+            let weak_ptr = $parser.ast.add_named_element(constant);
+            unsafe { $parent_ptr.borrow_mut().constants.push(weak_ptr) };
+        }
+    }};
+}
+
+// Convenience type for storing a member of a type that can also contain associated constants.
+enum TypeMember<T> {
+    Member(T),
+    Constant(OwnedPtr<Constant>),
+}
+
+// Splits a list of type members into its normal members and its associated constants, preserving their order.
+fn split_type_members<T>(type_members: Vec<TypeMember<T>>) -> (Vec<T>, Vec<OwnedPtr<Constant>>) {
+    let (mut members, mut constants) = (Vec::new(), Vec::new());
+    for type_member in type_members {
+        match type_member {
+            TypeMember::Member(member) => members.push(member),
+            TypeMember::Constant(constant) => constants.push(constant),
+        }
+    }
+    (members, constants)
+}
+
+// Returns the visibility specified by a definition's visibility modifier (or the default if there isn't one), and the
+// location that the definition starts at. If there's a modifier, the definition starts at it, instead of at `start`.
+fn get_visibility_and_start(modifier: Option<(Visibility, Location)>, start: Location) -> (Visibility, Location) {
+    match modifier {
+        Some((visibility, modifier_start)) => (visibility, modifier_start),
+        None => (Visibility::default(), start),
+    }
+}
+
+pub type Recovery<'a> = ErrorRecovery<Location, TokenKind<'a>, tokens::Error>;
+
 // Convenience type for storing an unparsed doc comment. Each element of the vector is one line of the comment.
 type RawDocComment<'a> = Vec<(&'a str, Span)>;
 
 // Grammar Rule Functions
 
+fn recover_from_error(parser: &mut Parser, recovery: Recovery) {
+    // Report the syntax error.
+    let diagnostic = super::construct_error_from(recovery.error, parser.file_name);
+    diagnostic.push_into(parser.diagnostics);
+
+    // Any containers we were in the middle of parsing were abandoned, so reset the scope to the enclosing module's.
+    match parser.current_scope.module.clone() {
+        Some(module) => parser.current_scope.enter_module(module),
+        None => parser.current_scope = Scope::default(),
+    }
+}
+
+fn recover_from_error_in_container(parser: &mut Parser, recovery: Recovery, scope: Scope) {
+    // Report the syntax error.
+    let diagnostic = super::construct_error_from(recovery.error, parser.file_name);
+    diagnostic.push_into(parser.diagnostics);
+
+    // Any members we were in the middle of parsing were abandoned, so restore the scope of the container itself.
+    parser.current_scope = scope;
+}
+
+fn recover_from_error_in_attribute(parser: &mut Parser, recovery: Recovery) {
+    // Report the syntax error. Attributes don't affect the scope, so there's nothing else to do.
+    let diagnostic = super::construct_error_from(recovery.error, parser.file_name);
+    diagnostic.push_into(parser.diagnostics);
+}
+
 fn handle_file_compilation_mode(
     parser: &mut Parser,
     (previous_mode, attributes): (Option<FileCompilationMode>, Vec<WeakPtr<Attribute>>),
@@ -80,7 +147,7 @@ fn construct_file_compilation_mode(parser: &mut Parser, i: Identifier, span: Spa
         "Slice1" => CompilationMode::Slice1,
         "Slice2" => CompilationMode::Slice2,
         _ => {
-            Diagnostic::new(Error::InvalidCompilationMode { mode: i.value })
+            Diagnostic::new(Error::InvalidCompilationMode { mode: i.value.into() })
                 .set_span(&i.span)
                 .add_note("must be 'Slice1' or 'Slice2'", None)
                 .push_into(parser.diagnostics);
@@ -90,43 +157,92 @@ fn construct_file_compilation_mode(parser: &mut Parser, i: Identifier, span: Spa
     FileCompilationMode { version, span }
 }
 
+fn parse_file_doc_comment(parser: &mut Parser, raw_comment: RawDocComment) -> Option<DocComment> {
+    // File doc comments aren't within any module, so they're parsed in the global scope.
+    parse_doc_comment_with_scope(parser, &String::new(), raw_comment)
+}
+
 fn construct_module(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
     identifier: Identifier,
     span: Span,
 ) -> OwnedPtr<Module> {
-    if !raw_comment.is_empty() {
-        let error = Error::Syntax {
-            message: "doc comments cannot be applied to modules".to_owned(),
-        };
-        Diagnostic::new(error).set_span(&span).push_into(parser.diagnostics);
-    }
+    // Module identifiers are already fully scoped (module blocks are prefixed with their enclosing module's scope).
+    let comment = parse_doc_comment_with_scope(parser, &identifier.value.to_string(), raw_comment);
 
     let module_ptr = OwnedPtr::new(Module {
         identifier,
         attributes,
+        comment,
         span,
     });
 
-    parser.current_scope.module = Some(module_ptr.downgrade());
-    parser.current_scope.parser_scope = module_ptr.borrow().nested_module_identifier().to_owned();
+    parser.current_scope.enter_module(module_ptr.downgrade());
     module_ptr
 }
 
+fn enter_module_block(
+    parser: &mut Parser,
+    prelude: (RawDocComment, Vec<WeakPtr<Attribute>>),
+    mut identifier: Identifier,
+    span: Span,
+) -> (WeakPtr<Module>, Scope) {
+    // Module blocks are nested within their enclosing module (if there is one).
+    let previous_scope = parser.current_scope.clone();
+    if let Some(enclosing_module) = &previous_scope.module {
+        let enclosing_identifier = enclosing_module.borrow().nested_module_identifier();
+        identifier.value = format!("{enclosing_identifier}::{}", identifier.value).into();
+    }
+
+    let module_ptr = construct_module(parser, prelude, identifier, span);
+    (parser.ast.add_named_element(module_ptr), previous_scope)
+}
+
+fn exit_module_block(
+    parser: &mut Parser,
+    (module_ptr, previous_scope): (WeakPtr<Module>, Scope),
+    contents: Vec<(Vec<WeakPtr<Module>>, Vec<Definition>)>,
+) -> (Vec<WeakPtr<Module>>, Vec<Definition>) {
+    // Restore the scope that was active before entering this module block.
+    parser.current_scope = previous_scope;
+
+    // Flatten the block's contents, and list the block's module before any modules nested within it.
+    let (mut modules, definitions) = flatten_slice_file_contents(contents);
+    modules.insert(0, module_ptr);
+    (modules, definitions)
+}
+
+/// Flattens a list of file contents (which may contain nested module blocks) into lists of modules and definitions.
+fn flatten_slice_file_contents(
+    contents: Vec<(Vec<WeakPtr<Module>>, Vec<Definition>)>,
+) -> (Vec<WeakPtr<Module>>, Vec<Definition>) {
+    let mut modules = Vec::new();
+    let mut definitions = Vec::new();
+    for (content_modules, content_definitions) in contents {
+        modules.extend(content_modules);
+        definitions.extend(content_definitions);
+    }
+    (modules, definitions)
+}
+
 fn construct_struct(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
+    visibility: Visibility,
     is_compact: bool,
     identifier: Identifier,
-    fields: Vec<OwnedPtr<Field>>,
+    members: Vec<TypeMember<OwnedPtr<Field>>>,
     span: Span,
 ) -> OwnedPtr<Struct> {
+    let (fields, constants) = split_type_members(members);
     let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
     let mut struct_ptr = OwnedPtr::new(Struct {
         identifier,
         fields: Vec::new(),
+        constants: Vec::new(),
         is_compact,
+        visibility,
         scope: parser.current_scope.clone(),
         attributes,
         comment,
@@ -134,27 +250,97 @@ fn construct_struct(
         supported_encodings: None, // Patched by the encoding patcher.
     });
 
-    // Add all the fields to the struct.
+    // Add all the fields and associated constants to the struct.
     set_fields_for!(struct_ptr, fields, parser);
+    set_constants_for!(struct_ptr, constants, parser);
+
+    struct_ptr
+}
+
+fn enter_inline_struct(parser: &mut Parser, span: Span) -> (Identifier, Scope) {
+    // Inline structs are defined in the enclosing module, and are named after where they're declared.
+    // Ex: a field named 'origin' in a struct named 'Line' declares an inline struct named 'LineOrigin'.
+    let module_scope = match &parser.current_scope.module {
+        Some(module) => module.borrow().nested_module_identifier().to_owned(),
+        None => String::new(),
+    };
+    let enclosing_scopes = parser.current_scope.parser_scope.strip_prefix(module_scope.as_str()).unwrap_or_default();
+    let name = enclosing_scopes
+        .split("::")
+        .chain(std::iter::once(parser.current_member.as_str()))
+        .map(to_pascal_case)
+        .collect::<String>();
+    let identifier = Identifier { value: name.into(), span };
+
+    // Fields of the inline struct are scoped to it, not to whatever it was declared within.
+    let previous_scope = parser.current_scope.clone();
+    match previous_scope.module.clone() {
+        Some(module) => parser.current_scope.enter_module(module),
+        None => parser.current_scope = Scope::default(),
+    }
+    parser.current_scope.push_scope(&identifier.value);
+    (identifier, previous_scope)
+}
 
+fn construct_inline_struct(
+    parser: &mut Parser,
+    (identifier, previous_scope): (Identifier, Scope),
+    is_compact: bool,
+    fields: Vec<OwnedPtr<Field>>,
+    span: Span,
+) -> OwnedPtr<Struct> {
+    // Inline structs are defined in the enclosing module, so they use the module's scope, not the field's.
+    parser.current_scope.pop_scope();
+    let members = fields.into_iter().map(TypeMember::Member).collect();
+    let prelude = (Vec::new(), Vec::new()); // Inline structs can't have doc comments or attributes.
+    let visibility = Visibility::default();
+    let struct_ptr = construct_struct(parser, prelude, visibility, is_compact, identifier, members, span);
+
+    parser.current_scope = previous_scope;
     struct_ptr
 }
 
+/// Converts an identifier to PascalCase by capitalizing its first letter. Ex: `returnValue` becomes `ReturnValue`.
+fn to_pascal_case(identifier: &str) -> String {
+    let mut chars = identifier.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// Adds the inline struct to the AST, so it's listed as a normal definition, and returns a reference to it.
+fn inline_struct_to_type_ref_definition(parser: &mut Parser, struct_ptr: OwnedPtr<Struct>) -> TypeRefDefinition {
+    let scoped_identifier = format!("::{}", struct_ptr.borrow().parser_scoped_identifier());
+    let span = struct_ptr.borrow().identifier.span.clone();
+    parser.inline_structs.push(Definition::Struct(parser.ast.add_named_element(struct_ptr)));
+    construct_unpatched_type_ref_definition(Identifier { value: scoped_identifier.into(), span })
+}
+
+// Returns the provided definition, preceded by any inline structs that were declared within it.
+fn with_inline_structs(parser: &mut Parser, definition: Definition) -> Vec<Definition> {
+    let mut definitions = std::mem::take(&mut parser.inline_structs);
+    definitions.push(definition);
+    definitions
+}
+
 fn construct_exception(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
+    visibility: Visibility,
     identifier: Identifier,
     base_type: Option<TypeRef>,
     fields: Vec<OwnedPtr<Field>>,
     span: Span,
 ) -> OwnedPtr<Exception> {
-    let base = base_type.map(|type_ref| type_ref.downcast::<Exception>().unwrap());
+    let base = base_type.and_then(|type_ref| downcast_type_ref::<Exception>(parser, type_ref, "exception"));
     let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
 
     let mut exception_ptr = OwnedPtr::new(Exception {
         identifier,
         fields: Vec::new(),
         base,
+        visibility,
         scope: parser.current_scope.clone(),
         attributes,
         comment,
@@ -168,16 +354,18 @@ fn construct_exception(
     exception_ptr
 }
 
+#[allow(clippy::too_many_arguments)]
 fn construct_class(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
+    visibility: Visibility,
     identifier: Identifier,
     compact_id: Option<Integer<u32>>,
     base_type: Option<TypeRef>,
     fields: Vec<OwnedPtr<Field>>,
     span: Span,
 ) -> OwnedPtr<Class> {
-    let base = base_type.map(|type_ref| type_ref.downcast::<Class>().unwrap());
+    let base = base_type.and_then(|type_ref| downcast_type_ref::<Class>(parser, type_ref, "class"));
     let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
 
     let mut class_ptr = OwnedPtr::new(Class {
@@ -185,6 +373,7 @@ fn construct_class(
         fields: Vec::new(),
         compact_id,
         base,
+        visibility,
         scope: parser.current_scope.clone(),
         attributes,
         comment,
@@ -198,12 +387,14 @@ fn construct_class(
     class_ptr
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn construct_field(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
     identifier: Identifier,
     tag: Option<Integer<u32>>,
     data_type: TypeRef,
+    default: Option<Expression>,
     span: Span,
 ) -> OwnedPtr<Field> {
     let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
@@ -211,33 +402,39 @@ pub fn construct_field(
         identifier,
         data_type,
         tag,
+        default,
         parent: WeakPtr::create_uninitialized(), // Patched by its container.
         scope: parser.current_scope.clone(),
         attributes,
         comment,
         span,
+        default_value: None, // Patched by the constant patcher.
     })
 }
 
 fn construct_interface(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
+    visibility: Visibility,
     identifier: Identifier,
     bases: Option<Vec<TypeRef>>,
-    operations: Vec<OwnedPtr<Operation>>,
+    members: Vec<TypeMember<OwnedPtr<Operation>>>,
     span: Span,
 ) -> OwnedPtr<Interface> {
+    let (operations, constants) = split_type_members(members);
     let bases = bases
         .unwrap_or_default() // Create an empty vector if no bases were specified.
         .into_iter()
-        .map(|base| base.downcast::<Interface>().unwrap())
+        .filter_map(|base| downcast_type_ref::<Interface>(parser, base, "interface"))
         .collect::<Vec<_>>();
     let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
 
     let mut interface_ptr = OwnedPtr::new(Interface {
         identifier,
         operations: Vec::new(),
+        constants: Vec::new(),
         bases,
+        visibility,
         scope: parser.current_scope.clone(),
         attributes,
         comment,
@@ -245,8 +442,9 @@ fn construct_interface(
         supported_encodings: None, // Patched by the encoding patcher.
     });
 
-    // Add all the operations to the interface.
+    // Add all the operations and associated constants to the interface.
     set_children_for!(interface_ptr, operations, parser);
+    set_constants_for!(interface_ptr, constants, parser);
 
     interface_ptr
 }
@@ -255,7 +453,7 @@ fn construct_interface(
 fn construct_operation(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
-    is_idempotent: bool,
+    modifiers: Vec<OperationModifier>,
     identifier: Identifier,
     parameters: Vec<OwnedPtr<Parameter>>,
     return_type: Option<Vec<OwnedPtr<Parameter>>>,
@@ -265,12 +463,20 @@ fn construct_operation(
     // If no return type was provided set the return type to an empty Vec.
     let return_type = return_type.unwrap_or_default();
 
-    // If no throws clause was present, set the exception specification to an empty Vec.
+    // Operations defined in Slice1 mode throw exceptions, but operations defined in Slice2 mode return an error type.
     let throws_clause = throws_clause.unwrap_or_default();
-    let exception_specification = throws_clause
-        .into_iter()
-        .map(|type_ref| type_ref.downcast::<Exception>().unwrap())
-        .collect();
+    let (exception_specification, error_type) = match parser.compilation_mode {
+        CompilationMode::Slice1 => {
+            let exceptions = throws_clause
+                .into_iter()
+                .filter_map(|type_ref| downcast_type_ref::<Exception>(parser, type_ref, "exception"));
+            (exceptions.collect(), None)
+        }
+        CompilationMode::Slice2 => {
+            check_error_types(parser, &identifier, &throws_clause);
+            (Vec::new(), throws_clause.into_iter().next())
+        }
+    };
 
     let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
 
@@ -279,7 +485,8 @@ fn construct_operation(
         parameters: Vec::new(),
         return_type: Vec::new(),
         exception_specification,
-        is_idempotent,
+        error_type,
+        modifiers,
         encoding: parser.compilation_mode,
         parent: WeakPtr::create_uninitialized(), // Patched by its container.
         scope: parser.current_scope.clone(),
@@ -303,6 +510,7 @@ fn construct_parameter(
     tag: Option<Integer<u32>>,
     is_streamed: bool,
     data_type: TypeRef,
+    default: Option<Expression>,
     span: Span,
 ) -> OwnedPtr<Parameter> {
     if !raw_comment.is_empty() {
@@ -320,10 +528,12 @@ fn construct_parameter(
         data_type,
         tag,
         is_streamed,
+        default,
         parent: WeakPtr::create_uninitialized(), // Patched by its container.
         scope: parser.current_scope.clone(),
         attributes,
         span,
+        default_value: None, // Patched by the constant patcher.
     })
 }
 
@@ -336,7 +546,7 @@ fn construct_single_return_type(
 ) -> Vec<OwnedPtr<Parameter>> {
     // Create a dummy identifier for the return type, since it's nameless.
     let dummy_identifier = Identifier {
-        value: "returnValue".to_owned(),
+        value: "returnValue".into(),
         span: span.clone(),
     };
 
@@ -345,40 +555,87 @@ fn construct_single_return_type(
         data_type,
         tag,
         is_streamed,
+        default: None,
         parent: WeakPtr::create_uninitialized(), // Patched by its container.
         scope: parser.current_scope.clone(),
         attributes: Vec::new(),
         span,
+        default_value: None,
     })]
 }
 
+/// Downcasts the provided type reference to a reference of the expected kind of type. Most type references are only
+/// resolved by the patchers, which check their kinds then, but references to built-in types (Ex: `class C : int32`)
+/// are resolved by the parser. If one of these references isn't of the expected kind, this reports an error.
+fn downcast_type_ref<T: Element + 'static>(
+    parser: &mut Parser,
+    type_ref: TypeRef,
+    expected: &str,
+) -> Option<TypeRef<T>> {
+    let result = type_ref.downcast::<T>();
+    if result.is_err() {
+        Diagnostic::new(Error::TypeMismatch {
+            expected: expected.to_owned(),
+            actual: type_ref.definition().kind().to_owned(),
+            is_concrete: true,
+        })
+        .set_span(type_ref.span())
+        .push_into(parser.diagnostics);
+    }
+    result.ok()
+}
+
+fn check_error_types(parser: &mut Parser, identifier: &Identifier, error_types: &[TypeRef]) {
+    if let [first, .., last] = error_types {
+        Diagnostic::new(Error::MultipleErrorTypes {
+            operation_identifier: identifier.value.to_string(),
+        })
+        .set_span(&(first.span() + last.span()))
+        .add_note("to fail with multiple kinds of errors, use an enum with fields as the error type", None)
+        .push_into(parser.diagnostics);
+    }
+}
+
 fn check_return_tuple(parser: &mut Parser, return_tuple: &[OwnedPtr<Parameter>], span: Span) {
     if return_tuple.len() < 2 {
         let diagnostic = Diagnostic::new(Error::ReturnTuplesMustContainAtLeastTwoElements).set_span(&span);
         diagnostic.push_into(parser.diagnostics);
     }
+
+    let return_members = return_tuple.iter().map(OwnedPtr::borrow);
+    for return_member in return_members.filter(|member| member.default.is_some()) {
+        Diagnostic::new(Error::Syntax {
+            message: "return members cannot have default values".to_owned(),
+        })
+        .set_span(return_member.span())
+        .push_into(parser.diagnostics);
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
 fn construct_enum(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
+    visibility: Visibility,
     is_compact: bool,
     is_unchecked: bool,
     identifier: Identifier,
     underlying_type: Option<TypeRef>,
-    enumerators: Vec<OwnedPtr<Enumerator>>,
+    members: Vec<TypeMember<OwnedPtr<Enumerator>>>,
     span: Span,
 ) -> OwnedPtr<Enum> {
+    let (enumerators, constants) = split_type_members(members);
     let underlying = underlying_type.map(|type_ref| type_ref.downcast::<Primitive>().unwrap());
     let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
 
     let mut enum_ptr = OwnedPtr::new(Enum {
         identifier,
         enumerators: Vec::new(),
+        constants: Vec::new(),
         underlying,
         is_compact,
         is_unchecked,
+        visibility,
         scope: parser.current_scope.clone(),
         attributes,
         comment,
@@ -386,8 +643,9 @@ fn construct_enum(
         supported_encodings: None, // Patched by the encoding patcher.
     });
 
-    // Add all the enumerators to the enum.
+    // Add all the enumerators and associated constants to the enum.
     set_children_for!(enum_ptr, enumerators, parser);
+    set_constants_for!(enum_ptr, constants, parser);
 
     // Clear the `previous_enumerator_value` field since this is the end of the enum.
     parser.previous_enumerator_value = None;
@@ -400,7 +658,7 @@ fn construct_enumerator(
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
     identifier: Identifier,
     fields: Option<Vec<OwnedPtr<Field>>>,
-    enumerator_value: Option<Integer<i128>>,
+    enumerator_value: Option<EnumeratorValue>,
     span: Span,
 ) -> OwnedPtr<Enumerator> {
     let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
@@ -408,8 +666,10 @@ fn construct_enumerator(
     // If the enumerator was given an explicit value, use it. Otherwise an implicit value is calculated as follows:
     // If this is the first enumerator in the enum (`previous_enumerator_value` is `None`), its value is set to 0.
     // Otherwise, this enumerator's value is set to the previous enumerator's value plus 1.
+    // Note that the values of constants aren't known yet, so implicit values following a constant reference are
+    // placeholders that get recomputed after the reference is resolved.
     let value = match enumerator_value {
-        Some(integer) => EnumeratorValue::Explicit(integer),
+        Some(value) => value,
         None => EnumeratorValue::Implicit(parser.previous_enumerator_value.map_or(0, |x| x.wrapping_add(1))),
     };
 
@@ -437,19 +697,24 @@ fn construct_enumerator(
     }
 
     // Update `previous_enumerator_value` to be this enumerator's value.
-    parser.previous_enumerator_value = Some(enumerator.borrow().value());
+    parser.previous_enumerator_value = Some(match &enumerator.borrow().value {
+        EnumeratorValue::Constant(..) => 0, // Placeholder, since the constant's value isn't known yet.
+        _ => enumerator.borrow().value(),
+    });
     enumerator
 }
 
 fn construct_custom_type(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
+    visibility: Visibility,
     identifier: Identifier,
     span: Span,
 ) -> OwnedPtr<CustomType> {
     let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
     OwnedPtr::new(CustomType {
         identifier,
+        visibility,
         scope: parser.current_scope.clone(),
         attributes,
         comment,
@@ -461,6 +726,7 @@ fn construct_custom_type(
 fn construct_type_alias(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
+    visibility: Visibility,
     identifier: Identifier,
     underlying: TypeRef,
     span: Span,
@@ -469,6 +735,7 @@ fn construct_type_alias(
     OwnedPtr::new(TypeAlias {
         identifier,
         underlying,
+        visibility,
         scope: parser.current_scope.clone(),
         attributes,
         comment,
@@ -477,6 +744,86 @@ fn construct_type_alias(
     })
 }
 
+fn construct_constant(
+    parser: &mut Parser,
+    (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
+    visibility: Visibility,
+    identifier: Identifier,
+    data_type: Option<TypeRef>,
+    expression: Expression,
+    span: Span,
+) -> OwnedPtr<Constant> {
+    let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
+
+    // Constants can only have primitive types, so we downcast the type reference, like we do for enum underlying types.
+    // Anonymous types are patched during parsing, so if the downcast fails we know they used a non-primitive type.
+    let data_type = data_type.and_then(|type_ref| match type_ref.downcast::<Primitive>() {
+        Ok(primitive_type_ref) => Some(primitive_type_ref),
+        Err(_) => {
+            Diagnostic::new(Error::TypeMismatch {
+                expected: "primitive".to_owned(),
+                actual: type_ref.definition().kind().to_owned(),
+                is_concrete: true,
+            })
+            .set_span(type_ref.span())
+            .push_into(parser.diagnostics);
+            None
+        }
+    });
+
+    OwnedPtr::new(Constant {
+        identifier,
+        data_type,
+        expression,
+        visibility,
+        scope: parser.current_scope.clone(),
+        attributes,
+        comment,
+        span,
+        value: None, // Patched by the constant patcher.
+    })
+}
+
+fn construct_unary_expression(operator: UnaryOperator, operand: Expression, span: Span) -> Expression {
+    let kind = ExpressionKind::Unary(operator, Box::new(operand));
+    Expression { kind, span }
+}
+
+fn construct_binary_expression(lhs: Expression, operator: BinaryOperator, rhs: Expression, span: Span) -> Expression {
+    let kind = ExpressionKind::Binary(Box::new(lhs), operator, Box::new(rhs));
+    Expression { kind, span }
+}
+
+fn construct_identifier_expression(identifier: Identifier) -> Expression {
+    // The boolean literals aren't keywords, so we check for them here instead of in the lexer.
+    let span = identifier.span.clone();
+    let kind = match identifier.value.as_str() {
+        "true" => ExpressionKind::Literal(ConstantValue::Bool(true)),
+        "false" => ExpressionKind::Literal(ConstantValue::Bool(false)),
+        _ => ExpressionKind::Reference(identifier),
+    };
+    Expression { kind, span }
+}
+
+fn report_redundant_separator(parser: &mut Parser, separator: &str, span: Span) {
+    Diagnostic::new(Lint::RedundantSeparator {
+        separator: separator.to_owned(),
+    })
+    .set_span(&span)
+    .push_into(parser.diagnostics);
+}
+
+fn check_operator_is_contiguous(parser: &mut Parser, operator: &str, gap: Span) {
+    // Multi-character operators that are lexed as separate tokens cannot have anything in between them.
+    if gap.start != gap.end {
+        Diagnostic::new(Error::Syntax {
+            message: format!("expected '{operator}', but found whitespace between its characters"),
+        })
+        .set_span(&gap)
+        .push_into(parser.diagnostics);
+    }
+}
+
 fn construct_type_ref(
     parser: &Parser,
     attributes: Vec<WeakPtr<Attribute>>,
@@ -511,44 +858,29 @@ where
 
 fn construct_unpatched_type_ref_definition(mut identifier: Identifier) -> TypeRefDefinition {
     // Remove any whitespace from the identifier so it can be looked up in the AST.
-    identifier.value.retain(|c| !c.is_whitespace());
+    identifier.value = identifier.value.chars().filter(|c| !c.is_whitespace()).collect::<String>().into();
     TypeRefDefinition::Unpatched(identifier)
 }
 
 fn construct_attribute(
     parser: &mut Parser,
     directive: Identifier,
-    arguments: Option<Vec<String>>,
+    arguments: Option<Vec<(String, Span)>>,
     span: Span,
 ) -> WeakPtr<Attribute> {
-    let attribute = Attribute::new(directive.value, arguments.unwrap_or_default(), span);
+    let attribute = Attribute::new(directive.value.into(), arguments.unwrap_or_default(), span);
     parser.ast.add_element(OwnedPtr::new(attribute))
 }
 
-fn unescape_string_literal(s: &str) -> String {
-    // Flag that stores whether the next character we read is being escaped.
-    let mut is_escaped = false;
-    s.chars()
-        .filter(|c| {
-            // If `c` is a backslash, and it isn't already escaped (ie: "\\"), then it is an escape character.
-            let is_escape_character = *c == '\\' && !is_escaped;
-            // Set `is_escaped` accordingly, so we know if the next character is being escaped.
-            is_escaped = is_escape_character;
-
-            // Return false for escape characters to filter them out of the string.
-            !is_escape_character
-        })
-        .collect()
-}
-
 fn try_parse_integer(parser: &mut Parser, s: &str, span: Span) -> Integer<i128> {
     // Remove any underscores from the integer literal before trying to parse it.
     let sanitized = s.replace('_', "");
 
     // Check the literal for a base prefix. If present, remove it and set the base.
-    // "0b" = binary, "0x" = hexadecimal, otherwise we assume it's decimal.
+    // "0b" = binary, "0o" = octal, "0x" = hexadecimal, otherwise we assume it's decimal.
     let (literal, base) = match sanitized {
         _ if sanitized.starts_with("0b") => (&sanitized[2..], 2),
+        _ if sanitized.starts_with("0o") => (&sanitized[2..], 8),
         _ if sanitized.starts_with("0x") => (&sanitized[2..], 16),
         _ => (sanitized.as_str(), 10),
     };
@@ -568,16 +900,57 @@ fn try_parse_integer(parser: &mut Parser, s: &str, span: Span) -> Integer<i128>
     Integer { value, span }
 }
 
-fn parse_tag_value(parser: &mut Parser, i: Integer<i128>) -> Integer<u32> {
-    // Verify that the provided integer is a valid tag id.
-    if !RangeInclusive::new(0, i32::MAX as i128).contains(&i.value) {
-        let diagnostic = Diagnostic::new(Error::TagValueOutOfBounds).set_span(&i.span);
-        diagnostic.push_into(parser.diagnostics);
+fn try_parse_character(parser: &mut Parser, s: &str, span: Span) -> Integer<i128> {
+    // Character literals must contain exactly one (possibly escaped) character. Its value is its Unicode code point.
+    let (unescaped, is_valid) = unescape_and_report(parser, s, &span);
+    let mut chars = unescaped.chars();
+    let value = match (chars.next(), chars.next()) {
+        (Some(c), None) if is_valid => u32::from(c) as i128,
+        _ => {
+            // Invalid escape sequences have already been reported, so we only report an error for valid literals.
+            if is_valid {
+                let error = Error::InvalidCharacterLiteral { literal: s.to_owned() };
+                Diagnostic::new(error).set_span(&span).push_into(parser.diagnostics);
+            }
+            0 // Dummy value
+        }
+    };
+
+    Integer { value, span }
+}
+
+fn unescape_string_literal(parser: &mut Parser, s: &str, span: Span) -> String {
+    unescape_and_report(parser, s, &span).0
+}
+
+/// Decodes the escape sequences in the provided literal, reporting an error for each invalid escape sequence.
+/// The provided span is the span of the entire literal, including its enclosing quotation marks.
+/// Returns the decoded literal, and whether all of its escape sequences were valid.
+fn unescape_and_report(parser: &mut Parser, s: &str, span: &Span) -> (String, bool) {
+    let (unescaped, invalid_escapes) = unescape_literal(s);
+    let is_valid = invalid_escapes.is_empty();
+    for invalid_escape in invalid_escapes {
+        // Literals can't contain newlines, so escape sequences are always on the same line as the opening quote.
+        let mut start = span.start;
+        start.col += 1 + invalid_escape.offset; // Skip over the opening quote and any preceding characters.
+        let mut end = start;
+        end.col += invalid_escape.sequence.chars().count();
+
+        Diagnostic::new(Error::InvalidEscapeSequence {
+            sequence: invalid_escape.sequence,
+            reason: invalid_escape.reason,
+        })
+        .set_span(&Span::new(start, end, &span.file))
+        .push_into(parser.diagnostics);
     }
+    (unescaped, is_valid)
+}
 
-    // Cast the integer to a `u32` since it most closely matches the allowed range of tags.
-    // It's fine if the value doesn't fit, the cast will just give us a dummy value.
-    let value = i.value as u32;
+fn parse_tag_value(i: Integer<i128>) -> Integer<u32> {
+    // Tag values are range checked during validation, since the supported range depends on the encoding.
+    // Values which don't fit in a `u32` are out of range for every encoding, so we replace them with `u32::MAX`, which
+    // the validator will still report as out of range.
+    let value = u32::try_from(i.value).unwrap_or(u32::MAX);
     Integer { value, span: i.span }
 }
 
@@ -595,12 +968,16 @@ fn parse_compact_id_value(parser: &mut Parser, i: Integer<i128>) -> Integer<u32>
 }
 
 fn parse_doc_comment(parser: &mut Parser, identifier: &str, raw_comment: RawDocComment) -> Option<DocComment> {
+    let scoped_identifier = get_scoped_identifier(identifier, &parser.current_scope.parser_scope);
+    parse_doc_comment_with_scope(parser, &scoped_identifier, raw_comment)
+}
+
+fn parse_doc_comment_with_scope(parser: &mut Parser, scope: &String, raw_comment: RawDocComment) -> Option<DocComment> {
     if raw_comment.is_empty() {
         // If the doc comment had 0 lines, that just means there is no doc comment.
         None
     } else {
-        let scoped_identifier = get_scoped_identifier(identifier, &parser.current_scope.parser_scope);
-        let comment_parser = CommentParser::new(parser.file_name, &scoped_identifier, parser.diagnostics);
+        let comment_parser = CommentParser::new(parser.file_name, scope, parser.diagnostics);
         comment_parser.parse_doc_comment(raw_comment).ok()
     }
 }
@@ -1,6 +1,6 @@
 // Copyright (c) ZeroC, Inc.
 
-use super::super::common::SourceBlock;
+use super::super::common::{is_identifier_continue, is_identifier_start, SourceBlock};
 use super::tokens::*;
 use crate::slice_file::Location;
 
@@ -38,6 +38,8 @@ where
 
     /// This flag stores whether the lexer is currently lexing the inside of an attribute.
     /// It is set to true upon encountering an '[' character, and false upon an ']' character.
+    /// Since attributes can't contain '}' or ';' characters, it's also cleared by these, so that a malformed attribute
+    /// which is missing its closing bracket doesn't cause the rest of the file to be lexed in attribute mode.
     attribute_mode: bool,
 }
 
@@ -104,6 +106,21 @@ where
         }
     }
 
+    /// Reads, consumes, and returns an identifier from the buffer. Identifiers can contain non-ASCII characters, see
+    /// [is_identifier_continue]. After calling this function, the next character will be one that can't appear in an
+    /// identifier, or `None` (end of buffer).
+    fn read_identifier(&mut self) -> &'input str {
+        let start_position = self.get_position();
+
+        // Loop while the next character in the buffer can be part of an identifier.
+        while matches!(self.buffer.peek(), Some((_, c)) if is_identifier_continue(*c)) {
+            self.advance_buffer(); // Consume the identifier character.
+        }
+
+        let end_position = self.get_position();
+        &self.current_block.content[start_position..end_position]
+    }
+
     /// Reads, consumes, and returns a string of alphanumeric characters from the buffer.
     /// After calling this function, the next character will be a non-alphanumeric character or `None` (end of buffer).
     fn read_alphanumeric(&mut self) -> &'input str {
@@ -124,33 +141,92 @@ where
     ///
     /// This function expects the lexer's cursor to be immediately before the opening '"' character.
     fn read_string_literal(&mut self) -> Result<&'input str, ErrorKind> {
+        self.read_quoted_literal('"').ok_or(ErrorKind::UnterminatedStringLiteral)
+    }
+
+    /// Reads, consumes, and returns a character literal from the buffer.
+    /// Character literals are any characters contained within a pair of un-escaped single-quotes.
+    /// The returned string doesn't include the opening and closing quotation marks, just the content between them.
+    ///
+    /// This function expects the lexer's cursor to be immediately before the opening '\'' character.
+    fn read_character_literal(&mut self) -> Result<&'input str, ErrorKind> {
+        self.read_quoted_literal('\'').ok_or(ErrorKind::UnterminatedCharacterLiteral)
+    }
+
+    /// Reads and consumes characters from the buffer until an un-escaped `quote` character is reached, and returns
+    /// them (without the enclosing quotes).
+    /// If a newline or the end of the buffer is reached first, this returns `None`.
+    ///
+    /// This function expects the lexer's cursor to be immediately before the opening quote character.
+    fn read_quoted_literal(&mut self, quote: char) -> Option<&'input str> {
         self.advance_buffer(); // Consume the opening quotation mark.
 
         let start_position = self.get_position();
         let mut is_next_char_escaped = false;
         while let Some((_, c)) = self.buffer.peek() {
             if *c == '\n' {
-                // String literals cannot contain newlines.
-                return Err(ErrorKind::UnterminatedStringLiteral);
+                // Quoted literals cannot contain newlines.
+                return None;
             } else if is_next_char_escaped {
                 // If this character is escaped, don't check it and just reset the flag.
                 is_next_char_escaped = false;
-            } else {
-                match c {
-                    '"' => {
-                        // We've reached the end of the string literal.
-                        let end_position = self.get_position();
-                        self.advance_buffer(); // Consume the closing quotation mark.
-                        return Ok(&self.current_block.content[start_position..end_position]);
-                    }
-                    '\\' => is_next_char_escaped = true,
-                    _ => {}
-                }
+            } else if *c == quote {
+                // We've reached the end of the literal.
+                let end_position = self.get_position();
+                self.advance_buffer(); // Consume the closing quotation mark.
+                return Some(&self.current_block.content[start_position..end_position]);
+            } else if *c == '\\' {
+                is_next_char_escaped = true;
             }
             self.advance_buffer(); // Consume the character.
         }
 
-        // Reaching this means we hit the end of a buffer before the end of the string literal.
+        // Reaching this means we hit the end of a buffer before the end of the literal.
+        None
+    }
+
+    /// Returns true if the buffer is positioned at the start of a raw string literal: an 'r', followed by any number
+    /// of '#' characters, followed by a '"'. This only peeks at the buffer, it doesn't consume anything.
+    fn is_at_raw_string_literal(&self) -> bool {
+        let mut lookahead = self.buffer.clone().map(|(_, c)| c).skip(1); // Skip the 'r' character.
+        lookahead.find(|c| *c != '#') == Some('"')
+    }
+
+    /// Reads, consumes, and returns a raw string literal from the buffer.
+    /// Raw string literals start with an 'r', followed by any number of '#' characters and a '"'. They end at the
+    /// first '"' that is followed by the same number of '#' characters. Escape sequences aren't processed in raw
+    /// strings, and unlike normal string literals, they can span multiple lines.
+    /// The returned string only includes the content between the opening and closing delimiters.
+    ///
+    /// This function expects the lexer's cursor to be immediately before the 'r' character.
+    fn read_raw_string_literal(&mut self) -> Result<&'input str, ErrorKind> {
+        self.advance_buffer(); // Consume the 'r' character.
+        let mut hash_count = 0;
+        while matches!(self.buffer.peek(), Some((_, '#'))) {
+            self.advance_buffer(); // Consume the '#' character.
+            hash_count += 1;
+        }
+        self.advance_buffer(); // Consume the opening quotation mark.
+
+        let start_position = self.get_position();
+        while let Some((_, c)) = self.buffer.peek().cloned() {
+            let end_position = self.get_position();
+            self.advance_buffer(); // Consume the character.
+
+            // Check if this quotation mark is followed by enough '#' characters to close the literal.
+            if c == '"' {
+                let mut closing_hash_count = 0;
+                while closing_hash_count < hash_count && matches!(self.buffer.peek(), Some((_, '#'))) {
+                    self.advance_buffer(); // Consume the '#' character.
+                    closing_hash_count += 1;
+                }
+                if closing_hash_count == hash_count {
+                    return Ok(&self.current_block.content[start_position..end_position]);
+                }
+            }
+        }
+
+        // Reaching this means we hit the end of a buffer before the end of the literal.
         Err(ErrorKind::UnterminatedStringLiteral)
     }
 
@@ -185,7 +261,7 @@ where
     /// Checks if an identifier corresponds to a Slice keyword. If it does,
     /// return the keyword's token. Otherwise, return an `[TokenKind::Identifier]` token.
     fn check_if_keyword(identifier: &str) -> TokenKind<'_> {
-        debug_assert!(identifier.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+        debug_assert!(identifier.chars().all(is_identifier_continue));
         debug_assert!(!identifier.is_empty());
 
         match identifier {
@@ -198,6 +274,7 @@ where
             "custom" => TokenKind::CustomKeyword,
             "typealias" => TokenKind::TypeAliasKeyword,
             "Result" => TokenKind::ResultKeyword,
+            "const" => TokenKind::ConstKeyword,
             "Sequence" => TokenKind::SequenceKeyword,
             "Dictionary" => TokenKind::DictionaryKeyword,
             "bool" => TokenKind::BoolKeyword,
@@ -216,10 +293,17 @@ where
             "float32" => TokenKind::Float32Keyword,
             "float64" => TokenKind::Float64Keyword,
             "string" => TokenKind::StringKeyword,
+            "uuid" => TokenKind::UuidKeyword,
+            "timestamp" => TokenKind::TimestampKeyword,
+            "duration" => TokenKind::DurationKeyword,
             "AnyClass" => TokenKind::AnyClassKeyword,
             "compact" => TokenKind::CompactKeyword,
             "idempotent" => TokenKind::IdempotentKeyword,
+            "internal" => TokenKind::InternalKeyword,
             "mode" => TokenKind::ModeKeyword,
+            "oneway" => TokenKind::OnewayKeyword,
+            "public" => TokenKind::PublicKeyword,
+            "readonly" => TokenKind::ReadonlyKeyword,
             "stream" => TokenKind::StreamKeyword,
             "tag" => TokenKind::TagKeyword,
             "throws" => TokenKind::ThrowsKeyword,
@@ -271,10 +355,17 @@ where
                 }
             }
             '{' => self.return_simple_token(TokenKind::LeftBrace, start_location),
-            '}' => self.return_simple_token(TokenKind::RightBrace, start_location),
+            '}' => {
+                self.attribute_mode = false;
+                self.return_simple_token(TokenKind::RightBrace, start_location)
+            }
             '<' => self.return_simple_token(TokenKind::LeftChevron, start_location),
             '>' => self.return_simple_token(TokenKind::RightChevron, start_location),
             ',' => self.return_simple_token(TokenKind::Comma, start_location),
+            ';' => {
+                self.attribute_mode = false;
+                self.return_simple_token(TokenKind::Semicolon, start_location)
+            }
             ':' => {
                 self.advance_buffer(); // Consume the ':' character.
                                        // Check if the next character is also ':'.
@@ -287,6 +378,13 @@ where
             }
             '=' => self.return_simple_token(TokenKind::Equals, start_location),
             '?' => self.return_simple_token(TokenKind::QuestionMark, start_location),
+            '+' => self.return_simple_token(TokenKind::Plus, start_location),
+            '*' => self.return_simple_token(TokenKind::Star, start_location),
+            '%' => self.return_simple_token(TokenKind::Percent, start_location),
+            '&' => self.return_simple_token(TokenKind::Ampersand, start_location),
+            '|' => self.return_simple_token(TokenKind::Pipe, start_location),
+            '^' => self.return_simple_token(TokenKind::Caret, start_location),
+            '~' => self.return_simple_token(TokenKind::Tilde, start_location),
             '-' => {
                 self.advance_buffer(); // Consume the '-' character.
                                        // Check if the next character is '>'.
@@ -304,6 +402,13 @@ where
                     Err(err) => Err((start_location, err, self.cursor)),
                 })
             }
+            '\'' => {
+                let result = self.read_character_literal();
+                Some(match result {
+                    Ok(s) => Ok((start_location, TokenKind::CharacterLiteral(s), self.cursor)),
+                    Err(err) => Err((start_location, err, self.cursor)),
+                })
+            }
             '/' => {
                 self.advance_buffer(); // Consume the '/' character.
 
@@ -312,6 +417,14 @@ where
                     Some((_, '/')) => {
                         self.advance_buffer(); // Consume the 2nd '/' character.
 
+                        // Check if there's a '!' character indicating this is a file-level doc comment.
+                        if matches!(self.buffer.peek(), Some((_, '!'))) {
+                            self.advance_buffer(); // Consume the '!' character.
+                            let content_start_loc = self.cursor;
+                            let comment = self.read_line_comment();
+                            return Some(Ok((content_start_loc, TokenKind::FileDocComment(comment), self.cursor)));
+                        }
+
                         // Check if there's a 3rd '/' character indicating this may be a doc comment.
                         let mut is_doc_comment = matches!(self.buffer.peek(), Some((_, '/')));
                         if is_doc_comment {
@@ -339,21 +452,15 @@ where
                         }
                     }
 
-                    // The token is just "/", indicating a syntax error. '/' on its own isn't a valid Slice token.
-                    _ => {
-                        let error = ErrorKind::UnknownSymbol {
-                            symbol: "/".to_owned(),
-                            suggestion: Some("//".to_owned()),
-                        };
-                        Some(Err((start_location, error, self.cursor)))
-                    }
+                    // The token is just "/", indicating a division operator.
+                    _ => Some(Ok((start_location, TokenKind::Slash, self.cursor))),
                 }
             }
             '\\' => {
                 self.advance_buffer(); // Consume the '\' character.
                                        // Check if the next character could be the start of an identifier.
-                if matches!(self.buffer.peek(), Some((_, ch)) if ch.is_ascii_alphabetic()) {
-                    let identifier = self.read_alphanumeric();
+                if matches!(self.buffer.peek(), Some((_, ch)) if is_identifier_start(*ch)) {
+                    let identifier = self.read_identifier();
                     Some(Ok((start_location, TokenKind::Identifier(identifier), self.cursor)))
                 } else {
                     // The token is just "\", indicating a syntax error. '\' on its own isn't a valid Slice token.
@@ -364,12 +471,19 @@ where
                     Some(Err((start_location, error, self.cursor)))
                 }
             }
-            _ if c.is_ascii_alphabetic() => {
+            'r' if self.is_at_raw_string_literal() => {
+                let result = self.read_raw_string_literal();
+                Some(match result {
+                    Ok(s) => Ok((start_location, TokenKind::RawStringLiteral(s), self.cursor)),
+                    Err(err) => Err((start_location, err, self.cursor)),
+                })
+            }
+            _ if is_identifier_start(c) => {
                 let token = if self.attribute_mode {
                     // If we're lexing an attribute, return the identifier as-is, without checking if it's a keyword.
-                    TokenKind::Identifier(self.read_alphanumeric())
+                    TokenKind::Identifier(self.read_identifier())
                 } else {
-                    Self::check_if_keyword(self.read_alphanumeric())
+                    Self::check_if_keyword(self.read_identifier())
                 };
                 Some(Ok((start_location, token, self.cursor)))
             }
@@ -455,3 +569,10 @@ impl<'input> From<&'input str> for Lexer<'input, std::iter::Once<SourceBlock<'in
         Lexer::new(std::iter::once(source_block))
     }
 }
+
+/// Returns true if the provided identifier is a Slice keyword, and so must be escaped (`\identifier`) to be used as an
+/// identifier.
+pub(crate) fn is_keyword(identifier: &str) -> bool {
+    let token = Lexer::<std::iter::Once<SourceBlock>>::check_if_keyword(identifier);
+    !matches!(token, TokenKind::Identifier(_))
+}
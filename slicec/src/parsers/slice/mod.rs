@@ -53,8 +53,11 @@ fn generate_message(expected: &[String], found: impl std::fmt::Display) -> Strin
         .map(|s| match s.as_str() {
             "identifier" => "identifier".to_owned(),
             "string_literal" => "string literal".to_owned(),
+            "raw_string_literal" => "raw string literal".to_owned(),
+            "character_literal" => "character literal".to_owned(),
             "integer_literal" => "integer literal".to_owned(),
             "doc_comment" => "doc comment".to_owned(),
+            "file_doc_comment" => "file doc comment".to_owned(),
 
             // Definition keywords
             "module_keyword" => tokens::TokenKind::ModuleKeyword.to_string(),
@@ -66,6 +69,7 @@ fn generate_message(expected: &[String], found: impl std::fmt::Display) -> Strin
             "custom_keyword" => tokens::TokenKind::CustomKeyword.to_string(),
             "type_alias_keyword" => tokens::TokenKind::TypeAliasKeyword.to_string(),
             "result_keyword" => tokens::TokenKind::ResultKeyword.to_string(),
+            "const_keyword" => tokens::TokenKind::ConstKeyword.to_string(),
 
             // Collection keywords
             "sequence_keyword" => tokens::TokenKind::SequenceKeyword.to_string(),
@@ -88,12 +92,19 @@ fn generate_message(expected: &[String], found: impl std::fmt::Display) -> Strin
             "float32_keyword" => tokens::TokenKind::Float32Keyword.to_string(),
             "float64_keyword" => tokens::TokenKind::Float64Keyword.to_string(),
             "string_keyword" => tokens::TokenKind::StringKeyword.to_string(),
+            "uuid_keyword" => tokens::TokenKind::UuidKeyword.to_string(),
+            "timestamp_keyword" => tokens::TokenKind::TimestampKeyword.to_string(),
+            "duration_keyword" => tokens::TokenKind::DurationKeyword.to_string(),
             "any_class_keyword" => tokens::TokenKind::AnyClassKeyword.to_string(),
 
             // Other keywords
             "compact_keyword" => tokens::TokenKind::CompactKeyword.to_string(),
             "idempotent_keyword" => tokens::TokenKind::IdempotentKeyword.to_string(),
+            "internal_keyword" => tokens::TokenKind::InternalKeyword.to_string(),
+            "readonly_keyword" => tokens::TokenKind::ReadonlyKeyword.to_string(),
+            "oneway_keyword" => tokens::TokenKind::OnewayKeyword.to_string(),
             "mode_keyword" => tokens::TokenKind::ModeKeyword.to_string(),
+            "public_keyword" => tokens::TokenKind::PublicKeyword.to_string(),
             "stream_keyword" => tokens::TokenKind::StreamKeyword.to_string(),
             "tag_keyword" => tokens::TokenKind::TagKeyword.to_string(),
             "throws_keyword" => tokens::TokenKind::ThrowsKeyword.to_string(),
@@ -113,11 +124,20 @@ fn generate_message(expected: &[String], found: impl std::fmt::Display) -> Strin
 
             // Symbols
             "\",\"" => tokens::TokenKind::Comma.to_string(),
+            "\";\"" => tokens::TokenKind::Semicolon.to_string(),
             "\":\"" => tokens::TokenKind::Colon.to_string(),
             "\"::\"" => tokens::TokenKind::DoubleColon.to_string(),
             "\"=\"" => tokens::TokenKind::Equals.to_string(),
             "\"?\"" => tokens::TokenKind::QuestionMark.to_string(),
             "\"->\"" => tokens::TokenKind::Arrow.to_string(),
+            "\"+\"" => tokens::TokenKind::Plus.to_string(),
+            "\"*\"" => tokens::TokenKind::Star.to_string(),
+            "\"/\"" => tokens::TokenKind::Slash.to_string(),
+            "\"%\"" => tokens::TokenKind::Percent.to_string(),
+            "\"&\"" => tokens::TokenKind::Ampersand.to_string(),
+            "\"|\"" => tokens::TokenKind::Pipe.to_string(),
+            "\"^\"" => tokens::TokenKind::Caret.to_string(),
+            "\"~\"" => tokens::TokenKind::Tilde.to_string(),
             "\"-\"" => tokens::TokenKind::Minus.to_string(),
             _ => s.to_owned(),
         })
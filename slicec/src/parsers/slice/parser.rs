@@ -23,10 +23,9 @@ macro_rules! implement_parse_function {
                     error.push_into(self.diagnostics);
                     Err(())
                 }
-                Ok(parse_value) => match self.diagnostics.has_errors() {
-                    false => Ok(parse_value),
-                    true => Err(()),
-                },
+                // The parser recovers from most syntax errors (reporting them as it goes), so even if errors were
+                // reported, we return what was parsed, so that callers have a (possibly partial) AST to work with.
+                Ok(parse_value) => Ok(parse_value),
             }
         }
     };
@@ -39,6 +38,10 @@ pub struct Parser<'a> {
     pub(super) current_scope: Scope,
     pub(super) compilation_mode: CompilationMode,
     pub(super) previous_enumerator_value: Option<i128>,
+    /// The identifier of the field or parameter currently being parsed. Inline structs are named after it.
+    pub(super) current_member: String,
+    /// Inline structs which have been parsed, but haven't been added to the file's definitions yet.
+    pub(super) inline_structs: Vec<Definition>,
 }
 
 impl<'a> Parser<'a> {
@@ -46,9 +49,11 @@ impl<'a> Parser<'a> {
         parse_slice_file,
         SliceFileParser,
         (
+            Option<DocComment>,
             Option<FileCompilationMode>,
             Vec<WeakPtr<Attribute>>,
             Option<OwnedPtr<Module>>,
+            Vec<WeakPtr<Module>>,
             Vec<Definition>,
         ),
     );
@@ -61,6 +66,8 @@ impl<'a> Parser<'a> {
             compilation_mode: CompilationMode::default(),
             current_scope: Scope::default(),
             previous_enumerator_value: None,
+            current_member: String::new(),
+            inline_structs: Vec::new(),
         }
     }
 }
@@ -3,6 +3,7 @@
 //! This module defines all the tokens and errors that the Slice [Lexer](super::lexer::Lexer) can return.
 
 use crate::slice_file::Location;
+use crate::token_stream::TokenCategory;
 use std::fmt;
 
 pub type Token<'a> = (Location, TokenKind<'a>, Location);
@@ -21,6 +22,16 @@ pub enum TokenKind<'input> {
     /// Note that the value doesn't contain the enclosing quotation marks, only the characters in between them.
     StringLiteral(&'input str),
 
+    /// A raw string literal starts with an 'r', followed by any number of '#' characters and a double-quote. It ends at
+    /// the first double-quote that is followed by the same number of '#' characters. Raw string literals can span
+    /// multiple lines, and escape sequences within them aren't processed.
+    /// Note that the value doesn't contain the enclosing delimiters, only the characters in between them.
+    RawStringLiteral(&'input str), // r#"..."#
+
+    /// A character literal consists of any non-newline characters contained within a pair of unescaped single-quotes.
+    /// Note that the value doesn't contain the enclosing quotation marks, only the characters in between them.
+    CharacterLiteral(&'input str),
+
     /// A string of alphanumeric characters that starts with a number.
     /// We allow alphanumeric characters to support hex literals.
     IntegerLiteral(&'input str), // "[0-9][_a-zA-Z0-9]*"
@@ -29,6 +40,10 @@ pub enum TokenKind<'input> {
     /// Note that the value doesn't contain the slashes or the newline, only the characters in between them.
     DocComment(&'input str),
 
+    /// File-level documentation comments are preceded by "//!" and continue until end of line.
+    /// They can only appear at the top of a file, before any module declarations or definitions.
+    FileDocComment(&'input str),
+
     // Definition keywords
     ModuleKeyword,    // "module"
     StructKeyword,    // "struct"
@@ -39,6 +54,7 @@ pub enum TokenKind<'input> {
     CustomKeyword,    // "custom"
     TypeAliasKeyword, // "typealias"
     ResultKeyword,    // "Result"
+    ConstKeyword,     // "const"
 
     // Collection keywords
     SequenceKeyword,   // "Sequence"
@@ -61,12 +77,19 @@ pub enum TokenKind<'input> {
     Float32Keyword,   // "float32"
     Float64Keyword,   // "float64"
     StringKeyword,    // "string"
+    UuidKeyword,      // "uuid"
+    TimestampKeyword, // "timestamp"
+    DurationKeyword,  // "duration"
     AnyClassKeyword,  // "AnyClass"
 
     // Other keywords
     CompactKeyword,    // "compact"
     IdempotentKeyword, // "idempotent"
+    InternalKeyword,   // "internal"
+    ReadonlyKeyword,   // "readonly"
+    OnewayKeyword,     // "oneway"
     ModeKeyword,       // "mode"
+    PublicKeyword,     // "public"
     StreamKeyword,     // "stream"
     TagKeyword,        // "tag"
     ThrowsKeyword,     // "throws"
@@ -86,12 +109,21 @@ pub enum TokenKind<'input> {
 
     // Symbols
     Comma,        // ","
+    Semicolon,    // ";"
     Colon,        // ":"
     DoubleColon,  // "::"
     Equals,       // "="
     QuestionMark, // "?"
     Arrow,        // "->"
     Minus,        // "-"
+    Plus,         // "+"
+    Star,         // "*"
+    Slash,        // "/"
+    Percent,      // "%"
+    Ampersand,    // "&"
+    Pipe,         // "|"
+    Caret,        // "^"
+    Tilde,        // "~"
 }
 
 impl fmt::Display for TokenKind<'_> {
@@ -100,7 +132,10 @@ impl fmt::Display for TokenKind<'_> {
             Self::Identifier(input) => input,
             Self::IntegerLiteral(input) => input,
             Self::StringLiteral(input) => input,
+            Self::RawStringLiteral(input) => input,
+            Self::CharacterLiteral(input) => input,
             Self::DocComment(input) => input,
+            Self::FileDocComment(input) => input,
 
             // Keywords
             Self::ModuleKeyword => "module",
@@ -112,6 +147,7 @@ impl fmt::Display for TokenKind<'_> {
             Self::CustomKeyword => "custom",
             Self::TypeAliasKeyword => "typealias",
             Self::ResultKeyword => "Result",
+            Self::ConstKeyword => "const",
             Self::SequenceKeyword => "Sequence",
             Self::DictionaryKeyword => "Dictionary",
             Self::BoolKeyword => "bool",
@@ -130,10 +166,17 @@ impl fmt::Display for TokenKind<'_> {
             Self::Float32Keyword => "float32",
             Self::Float64Keyword => "float64",
             Self::StringKeyword => "string",
+            Self::UuidKeyword => "uuid",
+            Self::TimestampKeyword => "timestamp",
+            Self::DurationKeyword => "duration",
             Self::AnyClassKeyword => "AnyClass",
             Self::CompactKeyword => "compact",
             Self::IdempotentKeyword => "idempotent",
+            Self::InternalKeyword => "internal",
+            Self::ReadonlyKeyword => "readonly",
+            Self::OnewayKeyword => "oneway",
             Self::ModeKeyword => "mode",
+            Self::PublicKeyword => "public",
             Self::StreamKeyword => "stream",
             Self::TagKeyword => "tag",
             Self::ThrowsKeyword => "throws",
@@ -151,16 +194,111 @@ impl fmt::Display for TokenKind<'_> {
             Self::LeftChevron => "<",
             Self::RightChevron => ">",
             Self::Comma => ",",
+            Self::Semicolon => ";",
             Self::Colon => ":",
             Self::DoubleColon => "::",
             Self::Equals => "=",
             Self::QuestionMark => "?",
             Self::Arrow => "->",
             Self::Minus => "-",
+            Self::Plus => "+",
+            Self::Star => "*",
+            Self::Slash => "/",
+            Self::Percent => "%",
+            Self::Ampersand => "&",
+            Self::Pipe => "|",
+            Self::Caret => "^",
+            Self::Tilde => "~",
         })
     }
 }
 
+impl TokenKind<'_> {
+    /// Returns which category this token belongs to, for syntax highlighting.
+    pub fn category(&self) -> TokenCategory {
+        match self {
+            Self::Identifier(_) => TokenCategory::Identifier,
+            Self::IntegerLiteral(_) => TokenCategory::IntegerLiteral,
+            Self::StringLiteral(_) | Self::RawStringLiteral(_) => TokenCategory::StringLiteral,
+            Self::CharacterLiteral(_) => TokenCategory::CharacterLiteral,
+            Self::DocComment(_) | Self::FileDocComment(_) => TokenCategory::DocComment,
+
+            Self::ResultKeyword
+            | Self::SequenceKeyword
+            | Self::DictionaryKeyword
+            | Self::BoolKeyword
+            | Self::Int8Keyword
+            | Self::UInt8Keyword
+            | Self::Int16Keyword
+            | Self::UInt16Keyword
+            | Self::Int32Keyword
+            | Self::UInt32Keyword
+            | Self::VarInt32Keyword
+            | Self::VarUInt32Keyword
+            | Self::Int64Keyword
+            | Self::UInt64Keyword
+            | Self::VarInt62Keyword
+            | Self::VarUInt62Keyword
+            | Self::Float32Keyword
+            | Self::Float64Keyword
+            | Self::StringKeyword
+            | Self::UuidKeyword
+            | Self::TimestampKeyword
+            | Self::DurationKeyword
+            | Self::AnyClassKeyword => TokenCategory::BuiltinType,
+
+            Self::ModuleKeyword
+            | Self::StructKeyword
+            | Self::ExceptionKeyword
+            | Self::ClassKeyword
+            | Self::InterfaceKeyword
+            | Self::EnumKeyword
+            | Self::CustomKeyword
+            | Self::TypeAliasKeyword
+            | Self::ConstKeyword
+            | Self::CompactKeyword
+            | Self::IdempotentKeyword
+            | Self::InternalKeyword
+            | Self::ReadonlyKeyword
+            | Self::OnewayKeyword
+            | Self::ModeKeyword
+            | Self::PublicKeyword
+            | Self::StreamKeyword
+            | Self::TagKeyword
+            | Self::ThrowsKeyword
+            | Self::UncheckedKeyword => TokenCategory::Keyword,
+
+            Self::LeftParenthesis
+            | Self::RightParenthesis
+            | Self::LeftBracket
+            | Self::RightBracket
+            | Self::DoubleLeftBracket
+            | Self::DoubleRightBracket
+            | Self::LeftBrace
+            | Self::RightBrace
+            | Self::LeftChevron
+            | Self::RightChevron
+            | Self::Comma
+            | Self::Semicolon
+            | Self::Colon
+            | Self::DoubleColon => TokenCategory::Punctuation,
+
+            Self::Equals
+            | Self::QuestionMark
+            | Self::Arrow
+            | Self::Minus
+            | Self::Plus
+            | Self::Star
+            | Self::Slash
+            | Self::Percent
+            | Self::Ampersand
+            | Self::Pipe
+            | Self::Caret
+            | Self::Tilde => TokenCategory::Operator,
+        }
+    }
+}
+
 /// This enum specifies all the kinds of errors that the Slice [Lexer](super::lexer::Lexer) can return.
 #[derive(Clone, Debug)]
 pub enum ErrorKind {
@@ -171,15 +309,34 @@ pub enum ErrorKind {
     /// Ex: `-` isn't a valid symbol, but "->" is a valid symbol. So the parser will suggest "->" to the user.
     UnknownSymbol { symbol: String, suggestion: Option<String> },
 
-    /// Returned when a string is missing its closing quotation mark. Note that strings cannot span multiple lines.
+    /// Returned when a string is missing its closing quotation mark.
+    /// Note that only raw strings can span multiple lines.
     /// Ex: `"this is a bad string`, there's no closing '"' before EOL.
+    /// Ex: `r#"this is a bad raw string"`, there's no closing '"#' before EOF.
     UnterminatedStringLiteral,
 
+    /// Returned when a character literal is missing its closing quotation mark.
+    /// Ex: `'a`, there's no closing '\'' before EOL.
+    UnterminatedCharacterLiteral,
+
     /// Returned when a block comment is missing its closing "*/".
     /// Ex: `/* this is a bad comment`, there's no closing "*/" before EOF.
     UnterminatedBlockComment,
 }
 
+impl ErrorKind {
+    /// Returns which category the text that caused this error belongs to, for syntax highlighting.
+    /// Ex: an unterminated string literal is still highlighted as a string literal.
+    pub fn category(&self) -> TokenCategory {
+        match self {
+            Self::UnknownSymbol { .. } => TokenCategory::Unknown,
+            Self::UnterminatedStringLiteral => TokenCategory::StringLiteral,
+            Self::UnterminatedCharacterLiteral => TokenCategory::CharacterLiteral,
+            Self::UnterminatedBlockComment => TokenCategory::Comment,
+        }
+    }
+}
+
 impl fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -188,6 +345,7 @@ impl fmt::Display for ErrorKind {
                 None => write!(f, "unknown symbol '{symbol}'"),
             },
             Self::UnterminatedStringLiteral => f.write_str("unterminated string literal"),
+            Self::UnterminatedCharacterLiteral => f.write_str("unterminated character literal"),
             Self::UnterminatedBlockComment => f.write_str("unterminated block comment"),
         }
     }
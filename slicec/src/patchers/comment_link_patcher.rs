@@ -43,9 +43,19 @@ pub unsafe fn patch_ast(compilation_state: &mut CompilationState) {
             Node::Enumerator(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::CustomType(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::TypeAlias(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
-            _ => {} // Skip any elements that don't implement `Commentable`.
+            Node::Constant(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
+            Node::Module(ptr) => {
+                let module_def = ptr.borrow();
+                let scope = module_def.parser_scoped_identifier();
+                patcher.compute_patches_for_comment(module_def.comment(), &scope, &compilation_state.ast);
+            }
+            _ => {} // Skip any elements that can't have doc comments.
         }
     }
+    // File doc comments aren't stored in the AST, so we handle them separately. They're always in the global scope.
+    for slice_file in &compilation_state.files {
+        patcher.compute_patches_for_comment(slice_file.comment.as_ref(), "", &compilation_state.ast);
+    }
 
     // Mutably iterate through the AST and apply all the patches in the same oder they were computed.
     for node in compilation_state.ast.as_mut_slice() {
@@ -60,9 +70,14 @@ pub unsafe fn patch_ast(compilation_state: &mut CompilationState) {
             Node::Enumerator(ptr) => patch_element!(ptr, patcher),
             Node::CustomType(ptr) => patch_element!(ptr, patcher),
             Node::TypeAlias(ptr) => patch_element!(ptr, patcher),
-            _ => {} // Skip any elements that don't implement `Commentable`.
+            Node::Constant(ptr) => patch_element!(ptr, patcher),
+            Node::Module(ptr) => patch_element!(ptr, patcher),
+            _ => {} // Skip any elements that can't have doc comments.
         }
     }
+    for slice_file in &mut compilation_state.files {
+        patcher.apply_patches("", &mut slice_file.comment);
+    }
     debug_assert!(patcher.link_patches.is_empty());
 }
 
@@ -73,35 +88,43 @@ struct CommentLinkPatcher<'a> {
 
 impl CommentLinkPatcher<'_> {
     fn compute_patches_for(&mut self, commentable: &impl Commentable, ast: &Ast) {
-        if let Some(comment) = commentable.comment() {
+        let scope = commentable.parser_scoped_identifier();
+        self.compute_patches_for_comment(commentable.comment(), &scope, ast);
+    }
+
+    fn compute_patches_for_comment(&mut self, comment: Option<&DocComment>, scope: &str, ast: &Ast) {
+        if let Some(comment) = comment {
             if let Some(overview) = &comment.overview {
-                self.resolve_links_in(overview, commentable, ast);
+                self.resolve_links_in(overview, scope, ast);
             }
             for param_tag in &comment.params {
-                self.resolve_links_in(&param_tag.message, commentable, ast);
+                self.resolve_links_in(&param_tag.message, scope, ast);
             }
             for returns_tag in &comment.returns {
-                self.resolve_links_in(&returns_tag.message, commentable, ast);
+                self.resolve_links_in(&returns_tag.message, scope, ast);
             }
             for throws_tag in &comment.throws {
-                self.resolve_link(&throws_tag.thrown_type, commentable, ast);
-                self.resolve_links_in(&throws_tag.message, commentable, ast);
+                self.resolve_link(&throws_tag.thrown_type, scope, ast);
+                self.resolve_links_in(&throws_tag.message, scope, ast);
             }
             for see_tag in &comment.see {
-                self.resolve_link(&see_tag.link, commentable, ast);
+                self.resolve_link(&see_tag.link, scope, ast);
+            }
+            for locale_tag in &comment.locales {
+                self.resolve_links_in(&locale_tag.message, scope, ast);
             }
         }
     }
 
-    fn resolve_links_in(&mut self, message: &Message, commentable: &impl Commentable, ast: &Ast) {
+    fn resolve_links_in(&mut self, message: &Message, scope: &str, ast: &Ast) {
         for component in &message.value {
             if let MessageComponent::Link(link_tag) = component {
-                self.resolve_link(&link_tag.link, commentable, ast);
+                self.resolve_link(&link_tag.link, scope, ast);
             }
         }
     }
 
-    fn resolve_link<T>(&mut self, link: &TypeRefDefinition<T>, commentable: &impl Commentable, ast: &Ast)
+    fn resolve_link<T>(&mut self, link: &TypeRefDefinition<T>, scope: &str, ast: &Ast)
     where
         T: Element + ?Sized,
     {
@@ -112,7 +135,7 @@ impl CommentLinkPatcher<'_> {
 
         // Look up the linked-to entity in the AST.
         let result = ast
-            .find_node_with_scope(&identifier.value, &commentable.parser_scoped_identifier())
+            .find_node_with_scope(&identifier.value, scope)
             .and_then(<WeakPtr<dyn Entity>>::try_from);
 
         // If the lookup succeeded, store the result, otherwise report a lint violation and store `None` as a dummy.
@@ -136,7 +159,7 @@ impl CommentLinkPatcher<'_> {
                 };
                 Diagnostic::new(Lint::BrokenDocLink { message })
                     .set_span(identifier.span())
-                    .set_scope(commentable.parser_scoped_identifier())
+                    .set_scope(scope)
                     .push_into(self.diagnostics);
                 None
             }
@@ -161,6 +184,9 @@ impl CommentLinkPatcher<'_> {
             for see_tag in &mut comment.see {
                 patch_link!(self, see_tag);
             }
+            for locale_tag in &mut comment.locales {
+                self.patch_links_in(&mut locale_tag.message);
+            }
         }
     }
 
@@ -175,13 +201,18 @@ impl CommentLinkPatcher<'_> {
     fn patch_thrown_type(&mut self, scope: &str, tag: &mut ThrowsTag) {
         // Get the next patch out of the queue and apply it to the tag.
         if let Some(patch) = self.link_patches.pop_front().unwrap() {
-            // If the linked-to type isn't an exception report a lint violation and leave the link unpatched.
-            match patch.downcast::<Exception>() {
-                Ok(converted_patch) => {
-                    tag.thrown_type = TypeRefDefinition::Patched(converted_patch);
+            // If the linked-to entity isn't an exception or a type, report a lint violation and leave it unpatched.
+            let entity = patch.borrow();
+            match entity.concrete_entity() {
+                Entities::Exception(_)
+                | Entities::Struct(_)
+                | Entities::Class(_)
+                | Entities::Enum(_)
+                | Entities::CustomType(_)
+                | Entities::TypeAlias(_) => {
+                    tag.thrown_type = TypeRefDefinition::Patched(patch);
                 }
-                Err(original_patch) => {
-                    let entity = original_patch.borrow();
+                _ => {
                     let kind = entity.kind();
                     let note = format!(
                         "'{identifier}' is {a} {kind}",
@@ -203,9 +234,9 @@ impl CommentLinkPatcher<'_> {
                         ),
                         Some(entity.span()),
                     )
-                    .add_note("operations can only throw exceptions", None)
+                    .add_note("operations can only throw exceptions, or fail with an error type", None)
                     .add_note(note, Some(entity.span()))
-                    .set_span(tag.span())
+                    .set_span(&tag.thrown_type_span)
                     .set_scope(scope)
                     .push_into(self.diagnostics);
                 }
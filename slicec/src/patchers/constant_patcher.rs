@@ -0,0 +1,268 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::ast::node::Node;
+use crate::ast::{Ast, LookupError};
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::*;
+use crate::grammar::*;
+use crate::slice_file::Span;
+use std::collections::HashMap;
+
+pub unsafe fn patch_ast(compilation_state: &mut CompilationState) {
+    let mut evaluator = ConstantEvaluator {
+        values: HashMap::new(),
+        evaluation_stack: Vec::new(),
+        ast: &compilation_state.ast,
+        diagnostics: &mut compilation_state.diagnostics,
+    };
+
+    // Immutably iterate through the AST and evaluate the expression of every constant and member default value,
+    // and the value of every enumerator that depends on a constant (keyed by the enumerator's scoped identifier).
+    let mut patches = Vec::new();
+    let mut default_patches = Vec::new();
+    let mut enumerator_patches = HashMap::new();
+    for node in compilation_state.ast.as_slice() {
+        match node {
+            Node::Constant(constant_ptr) => patches.push(evaluator.evaluate_constant(constant_ptr.borrow())),
+            Node::Enum(enum_ptr) => evaluator.evaluate_enumerators(enum_ptr.borrow(), &mut enumerator_patches),
+            Node::Field(field_ptr) => default_patches.push(evaluator.evaluate_default(field_ptr.borrow())),
+            Node::Parameter(parameter_ptr) => default_patches.push(evaluator.evaluate_default(parameter_ptr.borrow())),
+            _ => {}
+        }
+    }
+
+    // Mutably iterate through the AST and store the computed values in the same order they were evaluated.
+    let mut patches = patches.into_iter();
+    let mut default_patches = default_patches.into_iter();
+    for node in compilation_state.ast.as_mut_slice() {
+        match node {
+            Node::Constant(constant_ptr) => constant_ptr.borrow_mut().value = patches.next().unwrap(),
+            Node::Field(field_ptr) => field_ptr.borrow_mut().default_value = default_patches.next().unwrap(),
+            Node::Parameter(parameter_ptr) => {
+                parameter_ptr.borrow_mut().default_value = default_patches.next().unwrap();
+            }
+            Node::Enumerator(enumerator_ptr) => {
+                let identifier = enumerator_ptr.borrow().parser_scoped_identifier();
+                if let Some(&patched_value) = enumerator_patches.get(&identifier) {
+                    match &mut enumerator_ptr.borrow_mut().value {
+                        EnumeratorValue::Implicit(value) => *value = patched_value,
+                        EnumeratorValue::Constant(_, value) => *value = Some(patched_value),
+                        EnumeratorValue::Explicit(_) => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+struct ConstantEvaluator<'a> {
+    /// Cache of the values of any constants that have already been evaluated, keyed by their scoped identifiers.
+    /// If a constant's expression couldn't be evaluated, it's value is stored as `None`.
+    values: HashMap<String, Option<ConstantValue>>,
+    /// Stack of the constants currently being evaluated, used to detect constants that (indirectly) reference themself.
+    evaluation_stack: Vec<String>,
+    ast: &'a Ast,
+    diagnostics: &'a mut Diagnostics,
+}
+
+impl<'a> ConstantEvaluator<'a> {
+    fn evaluate_constant(&mut self, constant: &'a Constant) -> Option<ConstantValue> {
+        let identifier = constant.parser_scoped_identifier();
+
+        // If we've already evaluated this constant, return its cached value.
+        if let Some(value) = self.values.get(&identifier) {
+            return value.clone();
+        }
+
+        // If we're already in the middle of evaluating this constant, its definition must contain a cycle.
+        if let Some(index) = self.evaluation_stack.iter().position(|id| id == &identifier) {
+            let cycle = self.evaluation_stack[index..].join(" -> ") + " -> " + &identifier;
+            Diagnostic::new(Error::SelfReferentialConstant { identifier, cycle })
+                .set_span(constant.span())
+                .push_into(self.diagnostics);
+            return None;
+        }
+
+        self.evaluation_stack.push(identifier.clone());
+        let value = self.evaluate(&constant.expression, constant.parser_scope());
+        self.evaluation_stack.pop();
+
+        self.values.insert(identifier, value.clone());
+        value
+    }
+
+    /// Evaluates the default value of the provided member, returning `None` if it doesn't have one.
+    fn evaluate_default(&mut self, member: &'a impl Member) -> Option<ConstantValue> {
+        let expression = member.default()?;
+        self.evaluate(expression, member.parser_scope())
+    }
+
+    /// Computes the values of any enumerators in the provided enum which reference a constant, along with the implicit
+    /// values of any enumerators following them (since those depend on the value of the constant).
+    fn evaluate_enumerators(&mut self, enum_def: &'a Enum, patches: &mut HashMap<String, i128>) {
+        let enumerators = enum_def.enumerators();
+        if !enumerators.iter().any(|e| matches!(e.value, EnumeratorValue::Constant(..))) {
+            return;
+        }
+
+        let mut previous_value: Option<i128> = None;
+        for enumerator in enumerators {
+            let value = match &enumerator.value {
+                EnumeratorValue::Explicit(integer) => integer.value,
+                EnumeratorValue::Implicit(_) => previous_value.map_or(0, |x| x.wrapping_add(1)),
+                EnumeratorValue::Constant(identifier, _) => {
+                    // If the constant couldn't be evaluated, an error has already been reported, so we use a
+                    // placeholder value. Patching stops after this phase anyways, since errors were reported.
+                    let scope = enumerator.parser_scope();
+                    self.evaluate_enumerator_reference(identifier, scope).unwrap_or_default()
+                }
+            };
+            patches.insert(enumerator.parser_scoped_identifier(), value);
+            previous_value = Some(value);
+        }
+    }
+
+    fn evaluate_enumerator_reference(&mut self, identifier: &Identifier, scope: &str) -> Option<i128> {
+        match self.evaluate_reference(identifier, scope)? {
+            ConstantValue::Integer(value) => Some(value),
+            other => {
+                Diagnostic::new(Error::TypeMismatch {
+                    expected: "integer".to_owned(),
+                    actual: other.kind().to_owned(),
+                    is_concrete: true,
+                })
+                .set_span(identifier.span())
+                .push_into(self.diagnostics);
+                None
+            }
+        }
+    }
+
+    fn evaluate(&mut self, expression: &'a Expression, scope: &str) -> Option<ConstantValue> {
+        match &expression.kind {
+            ExpressionKind::Literal(value) => Some(value.clone()),
+            ExpressionKind::Reference(identifier) => self.evaluate_reference(identifier, scope),
+            ExpressionKind::Unary(operator, operand) => {
+                let value = self.evaluate(operand, scope)?;
+                self.evaluate_unary(*operator, value, &expression.span)
+            }
+            ExpressionKind::Binary(lhs, operator, rhs) => {
+                // We evaluate both operands before checking them, so errors are reported for each of them.
+                let lhs = self.evaluate(lhs, scope);
+                let rhs = self.evaluate(rhs, scope);
+                self.evaluate_binary(lhs?, *operator, rhs?, &expression.span)
+            }
+        }
+    }
+
+    fn evaluate_reference(&mut self, identifier: &Identifier, scope: &str) -> Option<ConstantValue> {
+        match self.ast.find_element_with_scope::<Constant>(&identifier.value, scope) {
+            Ok(constant) => self.evaluate_constant(constant),
+            Err(err) => {
+                let mapped_error = match err {
+                    LookupError::DoesNotExist { identifier } => Error::DoesNotExist { identifier },
+                    LookupError::TypeMismatch {
+                        expected,
+                        actual,
+                        is_concrete,
+                    } => Error::TypeMismatch {
+                        expected,
+                        actual,
+                        is_concrete,
+                    },
+                };
+                Diagnostic::new(mapped_error)
+                    .set_span(identifier.span())
+                    .push_into(self.diagnostics);
+                None
+            }
+        }
+    }
+
+    fn evaluate_unary(&mut self, operator: UnaryOperator, value: ConstantValue, span: &Span) -> Option<ConstantValue> {
+        let result = match (operator, &value) {
+            (UnaryOperator::Negate, ConstantValue::Integer(i)) => i.checked_neg().map(ConstantValue::Integer),
+            (UnaryOperator::Not, ConstantValue::Integer(i)) => Some(ConstantValue::Integer(!i)),
+            (UnaryOperator::Not, ConstantValue::Bool(b)) => Some(ConstantValue::Bool(!b)),
+            _ => {
+                self.report_invalid_operands(operator.to_string(), format!("'{}'", value.kind()), span);
+                return None;
+            }
+        };
+
+        if result.is_none() {
+            self.report_overflow(span);
+        }
+        result
+    }
+
+    fn evaluate_binary(
+        &mut self,
+        lhs: ConstantValue,
+        operator: BinaryOperator,
+        rhs: ConstantValue,
+        span: &Span,
+    ) -> Option<ConstantValue> {
+        let result = match (&lhs, operator, &rhs) {
+            (ConstantValue::Integer(a), _, ConstantValue::Integer(b)) => {
+                let (a, b) = (*a, *b);
+                if b == 0 && matches!(operator, BinaryOperator::Divide | BinaryOperator::Remainder) {
+                    Diagnostic::new(Error::DivisionByZero)
+                        .set_span(span)
+                        .push_into(self.diagnostics);
+                    return None;
+                }
+
+                let value = match operator {
+                    BinaryOperator::Add => a.checked_add(b),
+                    BinaryOperator::Subtract => a.checked_sub(b),
+                    BinaryOperator::Multiply => a.checked_mul(b),
+                    BinaryOperator::Divide => a.checked_div(b),
+                    BinaryOperator::Remainder => a.checked_rem(b),
+                    BinaryOperator::ShiftLeft => u32::try_from(b)
+                        .ok()
+                        .and_then(|b| a.checked_shl(b))
+                        .filter(|shifted| shifted >> b == a), // Make sure no bits were shifted out of range.
+                    BinaryOperator::ShiftRight => u32::try_from(b).ok().and_then(|b| a.checked_shr(b)),
+                    BinaryOperator::BitAnd => Some(a & b),
+                    BinaryOperator::BitOr => Some(a | b),
+                    BinaryOperator::BitXor => Some(a ^ b),
+                };
+                value.map(ConstantValue::Integer)
+            }
+            (ConstantValue::Bool(a), BinaryOperator::BitAnd, ConstantValue::Bool(b)) => {
+                Some(ConstantValue::Bool(a & b))
+            }
+            (ConstantValue::Bool(a), BinaryOperator::BitOr, ConstantValue::Bool(b)) => Some(ConstantValue::Bool(a | b)),
+            (ConstantValue::Bool(a), BinaryOperator::BitXor, ConstantValue::Bool(b)) => {
+                Some(ConstantValue::Bool(a ^ b))
+            }
+            (ConstantValue::String(a), BinaryOperator::Add, ConstantValue::String(b)) => {
+                Some(ConstantValue::String(a.clone() + b))
+            }
+            _ => {
+                let operands = format!("'{}' and '{}'", lhs.kind(), rhs.kind());
+                self.report_invalid_operands(operator.to_string(), operands, span);
+                return None;
+            }
+        };
+
+        if result.is_none() {
+            self.report_overflow(span);
+        }
+        result
+    }
+
+    fn report_invalid_operands(&mut self, operator: String, operands: String, span: &Span) {
+        Diagnostic::new(Error::InvalidOperandTypes { operator, operands })
+            .set_span(span)
+            .push_into(self.diagnostics);
+    }
+
+    fn report_overflow(&mut self, span: &Span) {
+        Diagnostic::new(Error::ConstantExpressionOverflows)
+            .set_span(span)
+            .push_into(self.diagnostics);
+    }
+}
@@ -232,6 +232,51 @@ impl EncodingPatcher<'_> {
         }
     }
 
+    fn check_base_interface_mode(
+        &mut self,
+        interface_def: &Interface,
+        base_ref: &TypeRef<Interface>,
+        compilation_mode: CompilationMode,
+    ) {
+        // Interfaces defined in Slice1 mode support both encodings, so they can be inherited from in any mode.
+        // But interfaces defined in Slice2 mode can't be inherited from by interfaces defined in Slice1 mode.
+        let base = base_ref.definition();
+        let mut files = self.slice_files.iter();
+        let base_file = files.find(|f| f.relative_path == base.span().file).unwrap();
+        if compilation_mode != CompilationMode::Slice1 || base_file.compilation_mode() == CompilationMode::Slice1 {
+            return;
+        }
+
+        // Add a note for each operation that would be inherited from a different compilation mode,
+        // showing the chain of interfaces that it would be inherited through.
+        let mut notes = Vec::new();
+        for operation in base.all_operations() {
+            if operation.encoding != compilation_mode {
+                let mut chain = vec![interface_def.identifier()];
+                chain.extend(get_inheritance_chain(base, operation.parent()).iter().map(|i| i.identifier()));
+                notes.push(Note {
+                    message: format!(
+                        "operation '{}' is defined in {} mode, and would be inherited through '{}'",
+                        operation.identifier(),
+                        operation.encoding,
+                        chain.join("' -> '"),
+                    ),
+                    span: Some(operation.span().clone()),
+                });
+            }
+        }
+        notes.extend(self.get_mode_mismatch_note(interface_def));
+
+        Diagnostic::new(Error::IncompatibleBaseInterface {
+            interface_identifier: interface_def.identifier().to_owned(),
+            base_identifier: base.identifier().to_owned(),
+            mode: compilation_mode,
+        })
+        .set_span(base_ref.span())
+        .extend_notes(notes)
+        .push_into(self.diagnostics);
+    }
+
     fn get_mode_mismatch_note(&self, symbol: &impl Symbol) -> Option<Note> {
         let mut files = self.slice_files.iter();
         let slice_file = files.find(|f| f.relative_path == symbol.span().file).unwrap();
@@ -250,6 +295,23 @@ impl EncodingPatcher<'_> {
     }
 }
 
+/// Returns the chain of interfaces that `to` is inherited through by `from`, starting with `from` and ending with `to`.
+/// If `to` is inherited through multiple paths, this returns the left-most one.
+fn get_inheritance_chain<'a>(from: &'a Interface, to: &Interface) -> Vec<&'a Interface> {
+    if std::ptr::eq(from, to) {
+        return vec![from];
+    }
+    for base in from.base_interfaces() {
+        let chain = get_inheritance_chain(base, to);
+        if !chain.is_empty() {
+            let mut full_chain = vec![from];
+            full_chain.extend(chain);
+            return full_chain;
+        }
+    }
+    Vec::new()
+}
+
 fn disallowed_optional_suggestion(
     type_ref: &TypeRef<impl Type + ?Sized>,
     container: Option<&dyn Entity>,
@@ -394,7 +456,9 @@ impl ComputeSupportedEncodings for Interface {
 
         // Interfaces have no restrictions apart from those imposed by its file's compilation mode.
         // However all the types used in this interface's operations must be allowed within that mode.
-        for operation in self.all_operations() {
+        // Inherited operations keep the compilation mode of the file they were defined in, so they're only checked
+        // against that mode, when the interface that defines them is checked.
+        for operation in self.operations() {
             for member in operation.parameters_and_return_members() {
                 // This method automatically reports errors for encoding mismatches.
                 patcher.get_supported_encodings_for_type_ref(
@@ -411,6 +475,15 @@ impl ComputeSupportedEncodings for Interface {
                         .push_into(patcher.diagnostics)
                 }
             }
+
+            if let Some(error_type) = &operation.error_type {
+                patcher.get_supported_encodings_for_type_ref(error_type, compilation_mode, false, Some(operation));
+            }
+        }
+
+        // Interfaces can only inherit from interfaces that support their file's compilation mode.
+        for base_ref in &self.bases {
+            patcher.check_base_interface_mode(self, base_ref, compilation_mode);
         }
         None
     }
@@ -3,6 +3,7 @@
 //! TODO write a doc comment for the module.
 
 pub mod comment_link_patcher;
+pub mod constant_patcher;
 pub mod encoding_patcher;
 pub mod type_ref_patcher;
 
@@ -11,6 +12,8 @@ use crate::compilation_state::CompilationState;
 use crate::diagnostics::{Diagnostic, Error};
 use crate::grammar::attributes::*;
 use crate::grammar::Symbol;
+use crate::logging;
+use std::time::Instant;
 
 /// Since Slice definitions can be split across multiple files, and defined in any order, it is impossible for some
 /// things to be determined during parsing (as it's a sequential process).
@@ -19,14 +22,48 @@ use crate::grammar::Symbol;
 /// computed, in the following order:
 /// 1. References to other Slice types are verified and resolved.
 /// 2. Compute and store the Slice encodings that each element can be used with.
+/// 3. Evaluate the expressions of constants, and store their values.
+/// 4. Resolve links in doc comments.
 ///
 /// This function fails fast, so if any phase of patching fails, we skip any remaining phases.
 pub unsafe fn patch_ast(compilation_state: &mut CompilationState) {
-    let attribute_patcher = crate::patch_attributes!("", Allow, Compress, Deprecated, Oneway, SlicedFormat);
-    compilation_state.apply_unsafe(attribute_patcher);
-    compilation_state.apply_unsafe(type_ref_patcher::patch_ast);
-    compilation_state.apply_unsafe(encoding_patcher::patch_ast);
-    compilation_state.apply_unsafe(comment_link_patcher::patch_ast);
+    let attribute_patcher = crate::patch_attributes!(
+        "",
+        Allow,
+        Compress,
+        Deadline,
+        Deny,
+        Deprecated,
+        Experimental,
+        Flags,
+        Oneway,
+        Removed,
+        ReservedTags,
+        Since,
+        SlicedFormat,
+    );
+    apply_patcher(compilation_state, "attributes", attribute_patcher);
+    apply_patcher(compilation_state, "type references", type_ref_patcher::patch_ast);
+    apply_patcher(compilation_state, "encodings", encoding_patcher::patch_ast);
+    apply_patcher(compilation_state, "constants", constant_patcher::patch_ast);
+    apply_patcher(compilation_state, "comment links", comment_link_patcher::patch_ast);
+}
+
+/// Applies the provided patcher (see [CompilationState::apply_unsafe]), and logs how long it took.
+unsafe fn apply_patcher(
+    compilation_state: &mut CompilationState,
+    name: &str,
+    patcher: unsafe fn(&mut CompilationState),
+) {
+    // Patchers are skipped once an error has been reported, so there's nothing to log.
+    if compilation_state.diagnostics.has_errors() {
+        return;
+    }
+
+    let start = Instant::now();
+    compilation_state.apply_unsafe(patcher);
+    let duration_ms = logging::millis(start.elapsed());
+    log::debug!(target: logging::PATCHING, "patched {name}: duration_ms={duration_ms:.3}");
 }
 
 #[macro_export]
@@ -1,5 +1,6 @@
 // Copyright (c) ZeroC, Inc.
 
+use crate::annotations::{Annotations, TypeAliasReferences};
 use crate::ast::node::Node;
 use crate::ast::{Ast, LookupError};
 use crate::compilation_state::CompilationState;
@@ -12,6 +13,7 @@ pub unsafe fn patch_ast(compilation_state: &mut CompilationState) {
     let mut patcher = TypeRefPatcher {
         type_ref_patches: Vec::new(),
         diagnostics: &mut compilation_state.diagnostics,
+        annotations: &mut compilation_state.annotations,
     };
 
     // TODO why explain we split this logic so that we can for sure have an immutable AST.
@@ -22,6 +24,7 @@ pub unsafe fn patch_ast(compilation_state: &mut CompilationState) {
 struct TypeRefPatcher<'a> {
     type_ref_patches: Vec<PatchKind>,
     diagnostics: &'a mut Diagnostics,
+    annotations: &'a mut Annotations,
 }
 
 impl TypeRefPatcher<'_> {
@@ -51,10 +54,15 @@ impl TypeRefPatcher<'_> {
                         .map(PatchKind::BaseInterfaces)
                 }
                 Node::Operation(operation_ptr) => {
-                    operation_ptr.borrow().exception_specification.iter()
-                        .map(|type_ref| self.resolve_definition(type_ref, ast))
-                        .collect::<Option<Vec<_>>>() // None if any of the exceptions couldn't be resolved.
-                        .map(PatchKind::ExceptionSpecification)
+                    // Operations can either have an exception specification (Slice1) or an error type (Slice2).
+                    let operation = operation_ptr.borrow();
+                    match &operation.error_type {
+                        Some(type_ref) => self.resolve_error_type(type_ref, ast).map(PatchKind::ErrorType),
+                        None => operation.exception_specification.iter()
+                            .map(|type_ref| self.resolve_definition(type_ref, ast))
+                            .collect::<Option<Vec<_>>>() // None if any of the exceptions couldn't be resolved.
+                            .map(PatchKind::ExceptionSpecification),
+                    }
                 }
                 Node::Parameter(parameter_ptr) => {
                     let type_ref = &parameter_ptr.borrow().data_type;
@@ -71,6 +79,12 @@ impl TypeRefPatcher<'_> {
                     self.resolve_definition(type_ref, ast)
                         .map(PatchKind::TypeAliasUnderlyingType)
                 }
+                Node::Constant(constant_ptr) => constant_ptr
+                    .borrow()
+                    .data_type
+                    .as_ref()
+                    .and_then(|type_ref| self.resolve_definition(type_ref, ast))
+                    .map(PatchKind::ConstantType),
                 Node::ResultType(result_ptr) => {
                     let result_type = result_ptr.borrow();
                     let success_patch = self.resolve_definition(&result_type.success_type, ast);
@@ -150,6 +164,11 @@ impl TypeRefPatcher<'_> {
                         exception_type_ref.patch(exception_type_ptr, attributes);
                     }
                 }
+                PatchKind::ErrorType((error_type_ptr, attributes)) => {
+                    let operation_ptr: &mut OwnedPtr<Operation> = element.try_into().unwrap();
+                    let error_type_ref = operation_ptr.borrow_mut().error_type.as_mut().unwrap();
+                    error_type_ref.patch(error_type_ptr, attributes);
+                }
                 PatchKind::EnumUnderlyingType((enum_underlying_type_ptr, attributes)) => {
                     let enum_ptr: &mut OwnedPtr<Enum> = element.try_into().unwrap();
                     let enum_underlying_type_ref = enum_ptr.borrow_mut().underlying.as_mut().unwrap();
@@ -160,6 +179,11 @@ impl TypeRefPatcher<'_> {
                     let type_alias_underlying_type_ref = &mut type_alias_ptr.borrow_mut().underlying;
                     type_alias_underlying_type_ref.patch(type_alias_underlying_type_ptr, attributes);
                 }
+                PatchKind::ConstantType((constant_type_ptr, attributes)) => {
+                    let constant_ptr: &mut OwnedPtr<Constant> = element.try_into().unwrap();
+                    let constant_type_ref = constant_ptr.borrow_mut().data_type.as_mut().unwrap();
+                    constant_type_ref.patch(constant_type_ptr, attributes);
+                }
                 PatchKind::ResultTypes(success_patch, failure_patch) => {
                     let result_ptr: &mut OwnedPtr<ResultType> = element.try_into().unwrap();
                     if let Some((success_type_ptr, attributes)) = success_patch {
@@ -209,6 +233,11 @@ impl TypeRefPatcher<'_> {
                 self.check_for_deprecated_type(type_ref, node);
 
                 if let Node::TypeAlias(type_alias) = node {
+                    // Record which file referenced the alias, since the reference is resolved away by this patch.
+                    let alias = type_alias.borrow();
+                    let annotations = &mut self.annotations;
+                    let references = annotations.get_or_insert_with::<TypeAliasReferences>(alias, Default::default);
+                    references.insert(type_ref.span.file.clone());
                     self.resolve_type_alias(type_alias.borrow(), ast)
                 } else {
                     try_into_patch(node, Vec::new())
@@ -239,6 +268,21 @@ impl TypeRefPatcher<'_> {
         }
     }
 
+    /// Resolves the error type of an operation. Error types can be any type except for exceptions, which can only be
+    /// thrown by operations defined in Slice1 mode. So we check for them specifically, to report a clearer error.
+    fn resolve_error_type(&mut self, type_ref: &TypeRef, ast: &Ast) -> Option<Patch<dyn Type>> {
+        if let TypeRefDefinition::Unpatched(identifier) = &type_ref.definition {
+            if let Ok(Node::Exception(_)) = ast.find_node_with_scope(&identifier.value, type_ref.module_scope()) {
+                Diagnostic::new(Error::ExceptionSpecificationNotSupported)
+                    .set_span(type_ref.span())
+                    .set_scope(type_ref.parser_scope())
+                    .push_into(self.diagnostics);
+                return None;
+            }
+        }
+        self.resolve_definition(type_ref, ast)
+    }
+
     fn check_for_deprecated_type<T: Element + ?Sized>(&mut self, type_ref: &TypeRef<T>, node: &Node) {
         // Check if the type is an entity, and if so, check if it has the `deprecated` attribute.
         // Only entities can be deprecated, so this check is sufficient.
@@ -342,8 +386,10 @@ enum PatchKind {
     FieldType(Patch<dyn Type>),
     ParameterType(Patch<dyn Type>),
     ExceptionSpecification(Vec<Patch<Exception>>),
+    ErrorType(Patch<dyn Type>),
     EnumUnderlyingType(Patch<Primitive>),
     TypeAliasUnderlyingType(Patch<dyn Type>),
+    ConstantType(Patch<Primitive>),
     ResultTypes(Option<Patch<dyn Type>>, Option<Patch<dyn Type>>),
     SequenceType(Patch<dyn Type>),
     DictionaryTypes(Option<Patch<dyn Type>>, Option<Patch<dyn Type>>),
@@ -0,0 +1,37 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module implements preludes, which let embedders of the compiler provide built-in definitions.
+//!
+//! Prelude files are added to every compilation as reference files, before any other files are parsed. This makes
+//! their definitions resolvable and validatable like any other Slice definitions, without backends needing to hard-code
+//! their names, or users needing to pass them to the compiler themselves.
+
+use crate::slice_file::SliceFile;
+
+/// A Slice file containing built-in definitions that should be implicitly available to every compilation.
+/// Preludes are specified with the [prelude](crate::slice_options::SliceOptions::prelude) option.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PreludeFile {
+    /// The name of the prelude file. This is used in place of a path when reporting diagnostics.
+    pub name: String,
+
+    /// The raw (unparsed) contents of the prelude file.
+    pub contents: String,
+}
+
+impl PreludeFile {
+    pub fn new(name: impl Into<String>, contents: impl Into<String>) -> Self {
+        PreludeFile {
+            name: name.into(),
+            contents: contents.into(),
+        }
+    }
+}
+
+/// Adds the provided prelude files to the front of `files`, as reference files.
+pub(crate) fn inject_prelude(prelude: &[PreludeFile], files: &mut Vec<SliceFile>) {
+    let prelude_files = prelude
+        .iter()
+        .map(|file| SliceFile::new(file.name.clone(), file.contents.clone(), false));
+    files.splice(0..0, prelude_files);
+}
@@ -0,0 +1,366 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module decodes the parts of a Protobuf descriptor set (`descriptor.proto`) that the importer maps to Slice.
+//! Descriptors are decoded directly from the Protobuf wire format; any fields which aren't needed are skipped.
+
+use std::collections::HashMap;
+
+/// A `FileDescriptorProto`, describing a single `.proto` file.
+#[derive(Debug, Default)]
+pub(super) struct FileDescriptor {
+    pub name: String,
+    pub package: String,
+    pub syntax: String,
+    pub messages: Vec<MessageDescriptor>,
+    pub enums: Vec<EnumDescriptor>,
+    pub services: Vec<ServiceDescriptor>,
+    /// The leading comments of the file's elements, keyed by their location path (see `SourceCodeInfo`).
+    pub comments: HashMap<Vec<i32>, String>,
+}
+
+/// A `DescriptorProto`, describing a message.
+#[derive(Debug, Default)]
+pub(super) struct MessageDescriptor {
+    pub name: String,
+    pub fields: Vec<FieldDescriptor>,
+    pub nested_messages: Vec<MessageDescriptor>,
+    pub enums: Vec<EnumDescriptor>,
+    pub oneofs: Vec<String>,
+    pub is_map_entry: bool,
+    pub is_deprecated: bool,
+}
+
+/// A `FieldDescriptorProto`, describing a field of a message.
+#[derive(Debug, Default)]
+pub(super) struct FieldDescriptor {
+    pub name: String,
+    pub label: i32,
+    pub kind: i32,
+    pub type_name: String,
+    pub default_value: Option<String>,
+    pub oneof_index: Option<i32>,
+    pub is_proto3_optional: bool,
+    pub is_deprecated: bool,
+}
+
+/// An `EnumDescriptorProto`, describing an enum.
+#[derive(Debug, Default)]
+pub(super) struct EnumDescriptor {
+    pub name: String,
+    pub values: Vec<EnumValueDescriptor>,
+    pub is_deprecated: bool,
+}
+
+/// An `EnumValueDescriptorProto`, describing a value of an enum.
+#[derive(Debug, Default)]
+pub(super) struct EnumValueDescriptor {
+    pub name: String,
+    pub number: i32,
+    pub is_deprecated: bool,
+}
+
+/// A `ServiceDescriptorProto`, describing a service.
+#[derive(Debug, Default)]
+pub(super) struct ServiceDescriptor {
+    pub name: String,
+    pub methods: Vec<MethodDescriptor>,
+    pub is_deprecated: bool,
+}
+
+/// A `MethodDescriptorProto`, describing a method of a service.
+#[derive(Debug, Default)]
+pub(super) struct MethodDescriptor {
+    pub name: String,
+    pub input_type: String,
+    pub output_type: String,
+    pub is_client_streaming: bool,
+    pub is_server_streaming: bool,
+    pub is_idempotent: bool,
+    pub is_deprecated: bool,
+}
+
+// The values of `FieldDescriptorProto.Label` and `FieldDescriptorProto.Type` that the importer handles specially.
+pub(super) const LABEL_REQUIRED: i32 = 2;
+pub(super) const LABEL_REPEATED: i32 = 3;
+pub(super) const TYPE_GROUP: i32 = 10;
+pub(super) const TYPE_MESSAGE: i32 = 11;
+
+/// Decodes a `FileDescriptorSet`, returning the files it contains.
+pub(super) fn decode_file_descriptor_set(bytes: &[u8]) -> Result<Vec<FileDescriptor>, String> {
+    let mut files = Vec::new();
+    for_each_field(bytes, |number, value| {
+        if number == 1 {
+            files.push(decode_file(value.bytes()?)?);
+        }
+        Ok(())
+    })?;
+    Ok(files)
+}
+
+fn decode_file(bytes: &[u8]) -> Result<FileDescriptor, String> {
+    let mut file = FileDescriptor::default();
+    for_each_field(bytes, |number, value| {
+        match number {
+            1 => file.name = value.string()?,
+            2 => file.package = value.string()?,
+            4 => file.messages.push(decode_message(value.bytes()?)?),
+            5 => file.enums.push(decode_enum(value.bytes()?)?),
+            6 => file.services.push(decode_service(value.bytes()?)?),
+            9 => decode_source_code_info(value.bytes()?, &mut file.comments)?,
+            12 => file.syntax = value.string()?,
+            _ => {}
+        }
+        Ok(())
+    })?;
+    Ok(file)
+}
+
+fn decode_message(bytes: &[u8]) -> Result<MessageDescriptor, String> {
+    let mut message = MessageDescriptor::default();
+    for_each_field(bytes, |number, value| {
+        match number {
+            1 => message.name = value.string()?,
+            2 => message.fields.push(decode_field(value.bytes()?)?),
+            3 => message.nested_messages.push(decode_message(value.bytes()?)?),
+            4 => message.enums.push(decode_enum(value.bytes()?)?),
+            7 => {
+                // `MessageOptions`: `deprecated = 3`, `map_entry = 7`.
+                for_each_field(value.bytes()?, |number, value| {
+                    match number {
+                        3 => message.is_deprecated = value.bool()?,
+                        7 => message.is_map_entry = value.bool()?,
+                        _ => {}
+                    }
+                    Ok(())
+                })?;
+            }
+            8 => {
+                // `OneofDescriptorProto`: `name = 1`.
+                let mut name = String::new();
+                for_each_field(value.bytes()?, |number, value| {
+                    if number == 1 {
+                        name = value.string()?;
+                    }
+                    Ok(())
+                })?;
+                message.oneofs.push(name);
+            }
+            _ => {}
+        }
+        Ok(())
+    })?;
+    Ok(message)
+}
+
+fn decode_field(bytes: &[u8]) -> Result<FieldDescriptor, String> {
+    let mut field = FieldDescriptor::default();
+    for_each_field(bytes, |number, value| {
+        match number {
+            1 => field.name = value.string()?,
+            4 => field.label = value.int()?,
+            5 => field.kind = value.int()?,
+            6 => field.type_name = value.string()?,
+            7 => field.default_value = Some(value.string()?),
+            8 => field.is_deprecated = is_deprecated(value.bytes()?, 3)?,
+            9 => field.oneof_index = Some(value.int()?),
+            17 => field.is_proto3_optional = value.bool()?,
+            _ => {}
+        }
+        Ok(())
+    })?;
+    Ok(field)
+}
+
+fn decode_enum(bytes: &[u8]) -> Result<EnumDescriptor, String> {
+    let mut enum_def = EnumDescriptor::default();
+    for_each_field(bytes, |number, value| {
+        match number {
+            1 => enum_def.name = value.string()?,
+            2 => {
+                let mut enum_value = EnumValueDescriptor::default();
+                for_each_field(value.bytes()?, |number, value| {
+                    match number {
+                        1 => enum_value.name = value.string()?,
+                        2 => enum_value.number = value.int()?,
+                        3 => enum_value.is_deprecated = is_deprecated(value.bytes()?, 1)?,
+                        _ => {}
+                    }
+                    Ok(())
+                })?;
+                enum_def.values.push(enum_value);
+            }
+            3 => enum_def.is_deprecated = is_deprecated(value.bytes()?, 3)?,
+            _ => {}
+        }
+        Ok(())
+    })?;
+    Ok(enum_def)
+}
+
+fn decode_service(bytes: &[u8]) -> Result<ServiceDescriptor, String> {
+    let mut service = ServiceDescriptor::default();
+    for_each_field(bytes, |number, value| {
+        match number {
+            1 => service.name = value.string()?,
+            2 => service.methods.push(decode_method(value.bytes()?)?),
+            3 => service.is_deprecated = is_deprecated(value.bytes()?, 33)?,
+            _ => {}
+        }
+        Ok(())
+    })?;
+    Ok(service)
+}
+
+fn decode_method(bytes: &[u8]) -> Result<MethodDescriptor, String> {
+    let mut method = MethodDescriptor::default();
+    for_each_field(bytes, |number, value| {
+        match number {
+            1 => method.name = value.string()?,
+            2 => method.input_type = value.string()?,
+            3 => method.output_type = value.string()?,
+            4 => {
+                // `MethodOptions`: `deprecated = 33`, `idempotency_level = 34`.
+                // Both `NO_SIDE_EFFECTS` (1) and `IDEMPOTENT` (2) imply that the method is idempotent.
+                for_each_field(value.bytes()?, |number, value| {
+                    match number {
+                        33 => method.is_deprecated = value.bool()?,
+                        34 => method.is_idempotent = matches!(value.int()?, 1 | 2),
+                        _ => {}
+                    }
+                    Ok(())
+                })?;
+            }
+            5 => method.is_client_streaming = value.bool()?,
+            6 => method.is_server_streaming = value.bool()?,
+            _ => {}
+        }
+        Ok(())
+    })?;
+    Ok(method)
+}
+
+/// Decodes a `SourceCodeInfo`, storing the leading comment of each location which has one.
+fn decode_source_code_info(bytes: &[u8], comments: &mut HashMap<Vec<i32>, String>) -> Result<(), String> {
+    for_each_field(bytes, |number, value| {
+        if number != 1 {
+            return Ok(());
+        }
+
+        let (mut path, mut comment) = (Vec::new(), None);
+        for_each_field(value.bytes()?, |number, value| {
+            match (number, value) {
+                // Paths are normally packed, but unpacked paths are valid as well.
+                (1, Value::Bytes(packed)) => {
+                    let mut reader = Reader { bytes: packed, position: 0 };
+                    while reader.position < packed.len() {
+                        path.push(reader.read_varint()? as i32);
+                    }
+                }
+                (1, value) => path.push(value.int()?),
+                (3, value) => comment = Some(value.string()?),
+                _ => {}
+            }
+            Ok(())
+        })?;
+        if let Some(comment) = comment {
+            comments.insert(path, comment);
+        }
+        Ok(())
+    })
+}
+
+/// Returns true if the provided options message has its `deprecated` field (with the provided number) set.
+fn is_deprecated(options: &[u8], deprecated_field_number: u32) -> Result<bool, String> {
+    let mut is_deprecated = false;
+    for_each_field(options, |number, value| {
+        if number == deprecated_field_number {
+            is_deprecated = value.bool()?;
+        }
+        Ok(())
+    })?;
+    Ok(is_deprecated)
+}
+
+/// The value of a field, as it's encoded on the wire. Fixed-size values are never used by descriptors.
+enum Value<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Fixed,
+}
+
+impl<'a> Value<'a> {
+    fn bytes(self) -> Result<&'a [u8], String> {
+        match self {
+            Value::Bytes(bytes) => Ok(bytes),
+            _ => Err("expected a length-delimited field".to_owned()),
+        }
+    }
+
+    fn string(self) -> Result<String, String> {
+        String::from_utf8(self.bytes()?.to_vec()).map_err(|_| "expected a UTF-8 string".to_owned())
+    }
+
+    fn int(self) -> Result<i32, String> {
+        match self {
+            // Negative `int32`s are sign-extended to 64 bits, so truncating them is lossless.
+            Value::Varint(value) => Ok(value as i32),
+            _ => Err("expected a varint field".to_owned()),
+        }
+    }
+
+    fn bool(self) -> Result<bool, String> {
+        self.int().map(|value| value != 0)
+    }
+}
+
+/// Calls `f` with the number and value of each field in the provided message, in the order they're encoded.
+fn for_each_field<'a>(
+    bytes: &'a [u8],
+    mut f: impl FnMut(u32, Value<'a>) -> Result<(), String>,
+) -> Result<(), String> {
+    let mut reader = Reader { bytes, position: 0 };
+    while reader.position < bytes.len() {
+        let key = reader.read_varint()?;
+        let number = (key >> 3) as u32;
+        let value = match key & 0x7 {
+            0 => Value::Varint(reader.read_varint()?),
+            1 => reader.skip(8).map(|_| Value::Fixed)?,
+            2 => {
+                let length = reader.read_varint()? as usize;
+                Value::Bytes(reader.skip(length)?)
+            }
+            5 => reader.skip(4).map(|_| Value::Fixed)?,
+            wire_type => return Err(format!("unsupported wire type '{wire_type}' for field '{number}'")),
+        };
+        f(number, value)?;
+    }
+    Ok(())
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read_varint(&mut self) -> Result<u64, String> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = *self.skip(1)?.first().unwrap();
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("varint is longer than 10 bytes".to_owned())
+    }
+
+    /// Returns the next `length` bytes, and advances past them.
+    fn skip(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self.position.checked_add(length).filter(|end| *end <= self.bytes.len());
+        let end = end.ok_or_else(|| "unexpected end of input".to_owned())?;
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+}
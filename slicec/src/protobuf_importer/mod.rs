@@ -0,0 +1,498 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module converts Protobuf definitions into equivalent Slice definitions, to help migrate Protobuf APIs to Slice.
+//!
+//! The importer reads descriptor sets, which `protoc` generates from `.proto` files:
+//! ```text
+//! protoc --include_imports --include_source_info --descriptor_set_out=api.pb api/*.proto
+//! ```
+//! `--include_imports` makes the set self-contained, and `--include_source_info` preserves comments.
+//!
+//! Each `.proto` file is converted into a [FileBuilder], which can be compiled with
+//! [compile_from_builders](crate::compile_from_builders), or written to a Slice file with
+//! [to_slice_string](crate::grammar::ToSliceString::to_slice_string). Definitions are mapped as follows:
+//! - packages are mapped to modules, with each segment in PascalCase. Ex: `acme.orders` => `Acme::Orders`.
+//! - messages are mapped to structs. Nested messages and enums are moved to the top-level, and their identifiers are
+//!   prefixed with their parent's. Ex: `Order.Item` => `OrderItem`.
+//! - fields are mapped to fields, in camelCase. Repeated fields are mapped to sequences, map fields to dictionaries,
+//!   and fields with presence (like `optional` fields, or fields of message types) to optional types.
+//! - enums are mapped to enums with an underlying type of `int32`, which are unchecked for `proto3` files. Enumerators
+//!   are mapped to PascalCase, without the prefix of their enum's name. Ex: `STATUS_ACTIVE` => `Active`.
+//! - services are mapped to interfaces, and methods to operations with a single `request` parameter. Streaming
+//!   requests and responses are mapped to stream parameters and return types, and `google.protobuf.Empty` to nothing.
+//!
+//! Doc comments and the `deprecated` option are preserved. Constructs which don't translate exactly (like `oneof`s
+//! or default values) are recorded in the [ProtobufImport::notes], which should be reviewed after importing.
+//!
+//! Structs can't contain themselves, so fields which make a message recursive (Ex: `Node next = 1;` in `Node`) are
+//! skipped, and noted. These fields need to be remapped by hand (Ex: to a custom type).
+
+mod descriptor;
+
+use self::descriptor::*;
+use crate::ast_builder::*;
+use crate::utils::string_util::CaseStyle;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+/// The Slice definitions that were converted from a Protobuf descriptor set.
+#[derive(Clone, Debug)]
+pub struct ProtobufImport {
+    /// The converted files, one for each `.proto` file in the descriptor set, in the same order.
+    pub files: Vec<FileBuilder>,
+    /// Notes about the constructs which couldn't be mapped exactly, in the order they were encountered.
+    pub notes: Vec<MappingNote>,
+}
+
+/// Describes a Protobuf construct which couldn't be mapped to Slice exactly, and how it was mapped instead.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingNote {
+    /// The fully qualified Protobuf name of the element which contains the construct. Ex: `acme.Order.details`.
+    pub element: String,
+    /// A description of how the construct was mapped.
+    pub message: String,
+}
+
+impl fmt::Display for MappingNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.element, self.message)
+    }
+}
+
+/// Reads the descriptor set at the provided path, and converts it to Slice. See [import_descriptor_set].
+pub fn import_descriptor_set_file(path: impl AsRef<Path>) -> Result<ProtobufImport, String> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path).map_err(|error| format!("failed to read '{}': {error}", path.display()))?;
+    import_descriptor_set(&bytes).map_err(|error| format!("failed to decode '{}': {error}", path.display()))
+}
+
+/// Converts an encoded descriptor set (a `google.protobuf.FileDescriptorSet`) to Slice.
+///
+/// If the descriptor set is malformed, `Err` is returned with a description of the problem.
+pub fn import_descriptor_set(bytes: &[u8]) -> Result<ProtobufImport, String> {
+    let descriptors = decode_file_descriptor_set(bytes)?;
+
+    let mut importer = Importer::default();
+    for file in &descriptors {
+        importer.register_types(file);
+    }
+    let files = descriptors.iter().map(|file| importer.import_file(file)).collect();
+    Ok(ProtobufImport {
+        files,
+        notes: importer.notes,
+    })
+}
+
+/// The fully qualified name of `google.protobuf.Empty`, which is mapped to nothing when used by methods.
+const EMPTY_MESSAGE: &str = ".google.protobuf.Empty";
+
+#[derive(Default)]
+struct Importer {
+    /// The Slice types that each Protobuf type (by fully qualified name) is mapped to.
+    types: HashMap<String, MappedType>,
+    /// The (fully qualified) names of the types used by the fields of each message, including map entries.
+    field_types: HashMap<String, Vec<String>>,
+    notes: Vec<MappingNote>,
+}
+
+enum MappedType {
+    /// A message or enum, which is mapped to the definition with this (fully scoped) Slice identifier.
+    Definition(String),
+    /// A map entry, which is mapped to a dictionary. These hold the (kind, type name) of the key and value fields.
+    MapEntry((i32, String), (i32, String)),
+}
+
+/// The state of the file which is currently being imported.
+struct FileContext<'a> {
+    file: &'a FileDescriptor,
+    definitions: Vec<Definition>,
+}
+
+enum Definition {
+    Struct(StructBuilder),
+    Enum(EnumBuilder),
+    Interface(InterfaceBuilder),
+}
+
+impl Importer {
+    /// Registers the Slice identifiers of every message and enum in the provided file, so they can be referenced.
+    fn register_types(&mut self, file: &FileDescriptor) {
+        let module = module_for(file);
+        let proto_scope = proto_scope_for(file);
+        for message in &file.messages {
+            self.register_message(message, &proto_scope, &module, "");
+        }
+        for enum_def in &file.enums {
+            let identifier = format!("::{module}::{}", enum_def.name);
+            self.types.insert(format!("{proto_scope}.{}", enum_def.name), MappedType::Definition(identifier));
+        }
+    }
+
+    fn register_message(&mut self, message: &MessageDescriptor, proto_scope: &str, module: &str, prefix: &str) {
+        let proto_name = format!("{proto_scope}.{}", message.name);
+        let identifier = format!("{prefix}{}", message.name);
+
+        let mapped_type = match message.is_map_entry {
+            true => {
+                let find_field = |name| {
+                    let field = message.fields.iter().find(|field| field.name == name);
+                    field.map_or((0, String::new()), |field| (field.kind, field.type_name.clone()))
+                };
+                MappedType::MapEntry(find_field("key"), find_field("value"))
+            }
+            false => MappedType::Definition(format!("::{module}::{identifier}")),
+        };
+        self.types.insert(proto_name.clone(), mapped_type);
+        let field_types = message.fields.iter().map(|field| field.type_name.clone());
+        self.field_types.insert(proto_name.clone(), field_types.filter(|name| !name.is_empty()).collect());
+
+        for nested_message in &message.nested_messages {
+            self.register_message(nested_message, &proto_name, module, &identifier);
+        }
+        for enum_def in &message.enums {
+            let enum_identifier = format!("::{module}::{identifier}{}", enum_def.name);
+            self.types.insert(format!("{proto_name}.{}", enum_def.name), MappedType::Definition(enum_identifier));
+        }
+    }
+
+    fn import_file(&mut self, file: &FileDescriptor) -> FileBuilder {
+        if file.package.is_empty() {
+            self.note(&file.name, "the file has no package, so it was mapped to a module named after the file");
+        }
+
+        let mut context = FileContext {
+            file,
+            definitions: Vec::new(),
+        };
+
+        let proto_scope = proto_scope_for(file);
+        for (i, message) in file.messages.iter().enumerate() {
+            self.import_message(&mut context, message, &proto_scope, "", vec![4, i as i32]);
+        }
+        for (i, enum_def) in file.enums.iter().enumerate() {
+            self.import_enum(&mut context, enum_def, &proto_scope, "", vec![5, i as i32]);
+        }
+        for (i, service) in file.services.iter().enumerate() {
+            self.import_service(&mut context, service, &proto_scope, vec![6, i as i32]);
+        }
+
+        let path = Path::new(&file.name).with_extension("slice").to_string_lossy().into_owned();
+        let builder = FileBuilder::new(path, module_for(file));
+        context.definitions.into_iter().fold(builder, |builder, definition| match definition {
+            Definition::Struct(struct_builder) => builder.add_struct(struct_builder),
+            Definition::Enum(enum_builder) => builder.add_enum(enum_builder),
+            Definition::Interface(interface_builder) => builder.add_interface(interface_builder),
+        })
+    }
+
+    fn import_message(
+        &mut self,
+        context: &mut FileContext,
+        message: &MessageDescriptor,
+        proto_scope: &str,
+        prefix: &str,
+        location: Vec<i32>,
+    ) {
+        // Map entries are only used to describe the key and value types of map fields; they don't have a mapping.
+        if message.is_map_entry {
+            return;
+        }
+
+        let proto_name = format!("{proto_scope}.{}", message.name);
+        let element = &proto_name[1..];
+        let identifier = format!("{prefix}{}", message.name);
+        if !prefix.is_empty() {
+            self.note(element, format!("the nested message was moved to the top-level as '{identifier}'"));
+        }
+
+        let mut struct_builder = StructBuilder::new(&identifier);
+        if let Some(comment) = comment_at(context.file, &location) {
+            struct_builder = struct_builder.doc(comment);
+        }
+        if message.is_deprecated {
+            struct_builder = struct_builder.attribute("deprecated");
+        }
+
+        let is_proto3 = context.file.syntax == "proto3";
+        let mut noted_oneofs = HashSet::new();
+        for (i, field) in message.fields.iter().enumerate() {
+            let field_element = format!("{element}.{}", field.name);
+
+            if field.kind == TYPE_GROUP {
+                self.note(&field_element, "groups aren't supported, so the field was skipped");
+                continue;
+            }
+            if self.contains_message(&field.type_name, &proto_name) {
+                // Skipping the field breaks the cycle, so the other fields in the cycle don't need to be skipped too.
+                let field_types = self.field_types.get_mut(&proto_name).unwrap();
+                let index = field_types.iter().position(|name| name == &field.type_name).unwrap();
+                field_types.remove(index);
+
+                let message =
+                    format!("the field makes '{identifier}' contain itself, which structs can't do, so it was skipped");
+                self.note(&field_element, message);
+                continue;
+            }
+            if let Some(default_value) = &field.default_value {
+                self.note(&field_element, format!("the default value '{default_value}' wasn't mapped"));
+            }
+            // Proto3 'optional' fields are implemented with synthetic oneofs, which don't need to be noted.
+            if let Some(oneof_index) = field.oneof_index.filter(|_| !field.is_proto3_optional) {
+                if noted_oneofs.insert(oneof_index) {
+                    let oneof = message.oneofs.get(oneof_index as usize).map_or("", String::as_str);
+                    let message = format!(
+                        "the oneof '{oneof}' was mapped to separate optional fields, at most one of which should be set",
+                    );
+                    self.note(format!("{element}.{oneof}"), message);
+                }
+            }
+
+            let data_type = self.map_field_type(field, is_proto3, &field_element);
+            let mut member_builder = MemberBuilder::new(CaseStyle::CamelCase.convert(&field.name), data_type);
+            let mut field_location = location.clone();
+            field_location.extend([2, i as i32]);
+            if let Some(comment) = comment_at(context.file, &field_location) {
+                member_builder = member_builder.doc(comment);
+            }
+            if field.is_deprecated {
+                member_builder = member_builder.attribute("deprecated");
+            }
+            struct_builder = struct_builder.field(member_builder);
+        }
+        context.definitions.push(Definition::Struct(struct_builder));
+
+        for (i, nested_message) in message.nested_messages.iter().enumerate() {
+            let mut nested_location = location.clone();
+            nested_location.extend([3, i as i32]);
+            self.import_message(context, nested_message, &proto_name, &identifier, nested_location);
+        }
+        for (i, enum_def) in message.enums.iter().enumerate() {
+            let mut nested_location = location.clone();
+            nested_location.extend([4, i as i32]);
+            self.import_enum(context, enum_def, &proto_name, &identifier, nested_location);
+        }
+    }
+
+    fn import_enum(
+        &mut self,
+        context: &mut FileContext,
+        enum_def: &EnumDescriptor,
+        proto_scope: &str,
+        prefix: &str,
+        location: Vec<i32>,
+    ) {
+        let proto_name = format!("{proto_scope}.{}", enum_def.name);
+        let element = &proto_name[1..];
+        let identifier = format!("{prefix}{}", enum_def.name);
+        if !prefix.is_empty() {
+            self.note(element, format!("the nested enum was moved to the top-level as '{identifier}'"));
+        }
+
+        // Proto3 enums are open (they accept unknown values), which is what unchecked enums are for.
+        let mut enum_builder = EnumBuilder::new(&identifier).underlying("int32");
+        if context.file.syntax == "proto3" {
+            enum_builder = enum_builder.unchecked();
+        }
+        if let Some(comment) = comment_at(context.file, &location) {
+            enum_builder = enum_builder.doc(comment);
+        }
+        if enum_def.is_deprecated {
+            enum_builder = enum_builder.attribute("deprecated");
+        }
+
+        // By convention, Protobuf enumerators are prefixed with their enum's name, which is redundant in Slice.
+        let value_prefix = CaseStyle::SnakeCase.convert(&enum_def.name).to_uppercase() + "_";
+        let mut values = HashMap::new();
+        for (i, value) in enum_def.values.iter().enumerate() {
+            if let Some(original) = values.get(&value.number) {
+                let message = format!("the alias of '{original}' was skipped, since enumerator values must be unique");
+                self.note(format!("{element}.{}", value.name), message);
+                continue;
+            }
+            values.insert(value.number, &value.name);
+
+            let name = match value.name.strip_prefix(&value_prefix) {
+                Some(stripped) if stripped.starts_with(|c: char| c.is_ascii_alphabetic()) => stripped,
+                _ => &value.name,
+            };
+            let mut enumerator_builder =
+                EnumeratorBuilder::new(CaseStyle::PascalCase.convert(name)).value(i128::from(value.number));
+            let mut value_location = location.clone();
+            value_location.extend([2, i as i32]);
+            if let Some(comment) = comment_at(context.file, &value_location) {
+                enumerator_builder = enumerator_builder.doc(comment);
+            }
+            if value.is_deprecated {
+                enumerator_builder = enumerator_builder.attribute("deprecated");
+            }
+            enum_builder = enum_builder.enumerator(enumerator_builder);
+        }
+        context.definitions.push(Definition::Enum(enum_builder));
+    }
+
+    fn import_service(
+        &mut self,
+        context: &mut FileContext,
+        service: &ServiceDescriptor,
+        proto_scope: &str,
+        location: Vec<i32>,
+    ) {
+        let element = format!("{proto_scope}.{}", service.name)[1..].to_owned();
+
+        let mut interface_builder = InterfaceBuilder::new(&service.name);
+        if let Some(comment) = comment_at(context.file, &location) {
+            interface_builder = interface_builder.doc(comment);
+        }
+        if service.is_deprecated {
+            interface_builder = interface_builder.attribute("deprecated");
+        }
+
+        for (i, method) in service.methods.iter().enumerate() {
+            let method_element = format!("{element}.{}", method.name);
+            let mut operation_builder = OperationBuilder::new(CaseStyle::CamelCase.convert(&method.name));
+
+            if method.is_client_streaming || method.input_type != EMPTY_MESSAGE {
+                let input_type = self.map_type(TYPE_MESSAGE, &method.input_type, &method_element);
+                let input_type = stream_type(method.is_client_streaming, input_type);
+                operation_builder = operation_builder.parameter(MemberBuilder::new("request", input_type));
+            }
+            if method.is_server_streaming || method.output_type != EMPTY_MESSAGE {
+                let output_type = self.map_type(TYPE_MESSAGE, &method.output_type, &method_element);
+                operation_builder = operation_builder.returns(stream_type(method.is_server_streaming, output_type));
+            }
+
+            let mut method_location = location.clone();
+            method_location.extend([2, i as i32]);
+            if let Some(comment) = comment_at(context.file, &method_location) {
+                operation_builder = operation_builder.doc(comment);
+            }
+            if method.is_deprecated {
+                operation_builder = operation_builder.attribute("deprecated");
+            }
+            if method.is_idempotent {
+                operation_builder = operation_builder.idempotent();
+            }
+            interface_builder = interface_builder.operation(operation_builder);
+        }
+        context.definitions.push(Definition::Interface(interface_builder));
+    }
+
+    /// Returns the Slice type of the provided field, including any sequence, dictionary, or optional wrapper.
+    fn map_field_type(&mut self, field: &FieldDescriptor, is_proto3: bool, element: &str) -> String {
+        if field.label == LABEL_REPEATED {
+            if let Some(MappedType::MapEntry(key, value)) = self.types.get(&field.type_name) {
+                let (key, value) = (key.clone(), value.clone());
+                let key_type = self.map_type(key.0, &key.1, element);
+                let value_type = self.map_type(value.0, &value.1, element);
+                return format!("Dictionary<{key_type}, {value_type}>");
+            }
+            return format!("Sequence<{}>", self.map_type(field.kind, &field.type_name, element));
+        }
+
+        // In proto3, only message fields, fields in oneofs, and 'optional' fields track presence.
+        // In proto2, every field that isn't 'required' does.
+        let has_presence = match is_proto3 {
+            true => field.kind == TYPE_MESSAGE || field.oneof_index.is_some(),
+            false => field.label != LABEL_REQUIRED,
+        };
+        let data_type = self.map_type(field.kind, &field.type_name, element);
+        match has_presence {
+            true => data_type + "?",
+            false => data_type,
+        }
+    }
+
+    /// Returns the Slice type of the provided Protobuf type.
+    /// Types are identified by their kind, and for messages and enums, by their fully qualified name.
+    fn map_type(&mut self, kind: i32, type_name: &str, element: &str) -> String {
+        let primitive = match kind {
+            1 => "float64",
+            2 => "float32",
+            3 | 16 | 18 => "int64",
+            4 | 6 => "uint64",
+            5 | 15 | 17 => "int32",
+            7 | 13 => "uint32",
+            8 => "bool",
+            9 => "string",
+            12 => "Sequence<uint8>",
+            _ => "",
+        };
+        if !primitive.is_empty() {
+            return primitive.to_owned();
+        }
+
+        match self.types.get(type_name) {
+            Some(MappedType::Definition(identifier)) => identifier.clone(),
+            _ => {
+                let message = format!(
+                    "the type '{}' isn't in the descriptor set; make sure to generate it with '--include_imports'",
+                    type_name.trim_start_matches('.'),
+                );
+                self.note(element, message);
+                let segments = type_name.split('.').filter(|segment| !segment.is_empty());
+                let segments = segments.map(|segment| CaseStyle::PascalCase.convert(segment)).collect::<Vec<_>>();
+                format!("::{}", segments.join("::"))
+            }
+        }
+    }
+
+    /// Returns true if the type named `type_name` is, or (transitively) has a field of, the message named `message`.
+    fn contains_message(&self, type_name: &str, message: &str) -> bool {
+        let mut visited = HashSet::new();
+        let mut pending = vec![type_name];
+        while let Some(name) = pending.pop() {
+            if name == message {
+                return true;
+            }
+            if visited.insert(name) {
+                pending.extend(self.field_types.get(name).into_iter().flatten().map(String::as_str));
+            }
+        }
+        false
+    }
+
+    fn note(&mut self, element: impl Into<String>, message: impl Into<String>) {
+        self.notes.push(MappingNote {
+            element: element.into(),
+            message: message.into(),
+        });
+    }
+}
+
+/// Returns the Slice module that the provided file's definitions are mapped into.
+fn module_for(file: &FileDescriptor) -> String {
+    match file.package.is_empty() {
+        true => {
+            let stem = Path::new(&file.name).file_stem().unwrap_or_default().to_string_lossy();
+            CaseStyle::PascalCase.convert(&stem)
+        }
+        false => {
+            let segments = file.package.split('.').map(|segment| CaseStyle::PascalCase.convert(segment));
+            segments.collect::<Vec<_>>().join("::")
+        }
+    }
+}
+
+/// Returns the prefix of the fully qualified names of the provided file's top-level definitions. Ex: `.acme.orders`.
+fn proto_scope_for(file: &FileDescriptor) -> String {
+    match file.package.is_empty() {
+        true => String::new(),
+        false => format!(".{}", file.package),
+    }
+}
+
+/// Returns the leading comment of the element at the provided location, with the Protobuf comment indentation removed.
+fn comment_at(file: &FileDescriptor, location: &[i32]) -> Option<String> {
+    let comment = file.comments.get(location)?;
+    let lines = comment.lines().map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end());
+    let comment = lines.collect::<Vec<_>>().join("\n").trim().to_owned();
+    (!comment.is_empty()).then_some(comment)
+}
+
+fn stream_type(is_streaming: bool, data_type: String) -> String {
+    match is_streaming {
+        true => format!("stream {data_type}"),
+        false => data_type,
+    }
+}
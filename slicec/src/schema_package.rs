@@ -0,0 +1,268 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module implements schema packages, which bundle a set of Slice files into a single versioned artifact.
+//! A package holds the raw contents of each file, alongside a manifest recording a hash of those contents.
+//! Packages can optionally be signed with a shared key, and can be passed to the compiler as references (`-R`).
+//!
+//! Signatures are HMACs, so they only protect the integrity of packages exchanged between parties who already trust
+//! each other with the same secret key. Anyone who can verify a package can also sign one, so they aren't suitable
+//! for publishing packages to untrusted consumers.
+
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::slice_file::SliceFile;
+use crate::slice_options::SliceOptions;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::{fs, io};
+
+/// The version of the package format written by this version of the compiler.
+pub const SCHEMA_PACKAGE_FORMAT_VERSION: u32 = 1;
+
+/// The file extension used by schema packages.
+pub const SCHEMA_PACKAGE_EXTENSION: &str = "slicepkg";
+
+/// A single-file bundle of Slice source files.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaPackage {
+    pub manifest: PackageManifest,
+    pub files: Vec<PackagedFile>,
+
+    /// A hex-encoded HMAC-SHA256 of the serialized manifest, if the package was signed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// Metadata describing a schema package.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageManifest {
+    /// The version of the package format. See [SCHEMA_PACKAGE_FORMAT_VERSION].
+    pub format_version: u32,
+
+    /// The version of the schemas in this package, as specified by the publisher.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// The version of the compiler that created this package.
+    pub compiler_version: String,
+
+    /// A SHA-256 hash of the paths and contents of all the files in this package.
+    pub content_hash: String,
+}
+
+/// A Slice file stored in a schema package.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackagedFile {
+    /// The path of the file, as it was passed to the compiler when the package was created.
+    pub path: String,
+
+    /// A SHA-256 hash of the file's contents.
+    pub hash: String,
+
+    /// The raw (unparsed) contents of the file.
+    pub contents: String,
+}
+
+impl SchemaPackage {
+    /// Creates a package containing the source files in `files`, signing it with `key` if one is provided.
+    /// Reference files are not included in the package.
+    pub fn create(files: &[SliceFile], version: Option<String>, key: Option<&[u8]>) -> Self {
+        let mut files = files
+            .iter()
+            .filter(|file| file.is_source)
+            .map(|file| PackagedFile {
+                path: file.relative_path.clone(),
+                hash: hash_of(file.raw_text.as_bytes()),
+                contents: file.raw_text.clone(),
+            })
+            .collect::<Vec<_>>();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let manifest = PackageManifest {
+            format_version: SCHEMA_PACKAGE_FORMAT_VERSION,
+            version,
+            compiler_version: env!("CARGO_PKG_VERSION").to_owned(),
+            content_hash: compute_content_hash(&files),
+        };
+        let signature = key.map(|key| sign(key, &manifest));
+
+        SchemaPackage {
+            manifest,
+            files,
+            signature,
+        }
+    }
+
+    /// Checks that the contents of this package match the hashes recorded in it.
+    ///
+    /// If a `key` is provided, the package must also be signed with that key.
+    /// Returns a message describing the problem if the package fails verification.
+    pub fn verify(&self, key: Option<&[u8]>) -> Result<(), String> {
+        if self.manifest.format_version > SCHEMA_PACKAGE_FORMAT_VERSION {
+            let version = self.manifest.format_version;
+            return Err(format!("unsupported package format version '{version}'"));
+        }
+
+        for file in &self.files {
+            if hash_of(file.contents.as_bytes()) != file.hash {
+                return Err(format!("content hash mismatch for '{}'", file.path));
+            }
+        }
+        if compute_content_hash(&self.files) != self.manifest.content_hash {
+            return Err("content hash mismatch for package manifest".to_owned());
+        }
+
+        if let Some(key) = key {
+            match &self.signature {
+                Some(signature) if constant_time_eq(signature, &sign(key, &self.manifest)) => {}
+                Some(_) => return Err("package signature is invalid".to_owned()),
+                None => return Err("package is not signed".to_owned()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts the files in this package into reference [SliceFile]s.
+    /// Each file's path is prefixed by `package_path`, so diagnostics point into the package.
+    pub fn into_slice_files(self, package_path: &str) -> Vec<SliceFile> {
+        self.files
+            .into_iter()
+            .map(|file| {
+                let path = format!("{package_path}/{}", file.path.trim_start_matches('/'));
+                SliceFile::new(path, file.contents, false)
+            })
+            .collect()
+    }
+}
+
+/// Returns true if the path has the schema package extension.
+pub fn is_schema_package(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|ext| ext.to_str() == Some(SCHEMA_PACKAGE_EXTENSION))
+}
+
+/// Exports the compilation's source files to the schema package specified in `options`, if there is one.
+/// Nothing is written if `--dry-run` was set.
+pub(crate) fn export_schema_package(state: &mut CompilationState, options: &SliceOptions) {
+    let Some(path) = options.export_package.as_ref().filter(|_| !options.dry_run) else {
+        return;
+    };
+    let diagnostics = &mut state.diagnostics;
+
+    let key = match &options.package_key {
+        Some(key_path) => match read_key(key_path, diagnostics) {
+            Some(key) => Some(key),
+            None => return,
+        },
+        None => None,
+    };
+
+    let package = SchemaPackage::create(&state.files, options.package_version.clone(), key.as_deref());
+    let mut text = serde_json::to_string_pretty(&package).expect("failed to serialize schema package");
+    text.push('\n');
+
+    if let Err(error) = fs::write(path, text) {
+        Diagnostic::new(Error::IO {
+            action: "write",
+            path: path.clone(),
+            error,
+        })
+        .push_into(diagnostics);
+    }
+}
+
+/// Reads and verifies the schema package at `path`, returning the files it contains as reference files.
+/// If the package cannot be read, or fails verification, an error is reported and no files are returned.
+pub(crate) fn load_schema_package(path: &str, options: &SliceOptions, diagnostics: &mut Diagnostics) -> Vec<SliceFile> {
+    let key = match &options.package_key {
+        Some(key_path) => match read_key(key_path, diagnostics) {
+            Some(key) => Some(key),
+            None => return Vec::new(),
+        },
+        None => None,
+    };
+
+    let result = fs::read_to_string(path).and_then(|text| {
+        let package: SchemaPackage = serde_json::from_str(&text)
+            .map_err(|error| io::Error::other(format!("malformed schema package: {error}")))?;
+        package.verify(key.as_deref()).map_err(io::Error::other)?;
+        Ok(package)
+    });
+
+    match result {
+        Ok(package) => package.into_slice_files(path),
+        Err(error) => {
+            Diagnostic::new(Error::IO {
+                action: "read",
+                path: path.to_owned(),
+                error,
+            })
+            .push_into(diagnostics);
+            Vec::new()
+        }
+    }
+}
+
+fn read_key(path: &str, diagnostics: &mut Diagnostics) -> Option<Vec<u8>> {
+    match fs::read(path) {
+        Ok(key) => Some(key),
+        Err(error) => {
+            Diagnostic::new(Error::IO {
+                action: "read",
+                path: path.to_owned(),
+                error,
+            })
+            .push_into(diagnostics);
+            None
+        }
+    }
+}
+
+fn hash_of(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+fn compute_content_hash(files: &[PackagedFile]) -> String {
+    // Each path is terminated by a nul byte so that the boundary between a path and its file's hash is unambiguous.
+    let mut hash_engine = Sha256::new();
+    for file in files {
+        hash_engine.update(&file.path);
+        hash_engine.update([0]);
+        hash_engine.update(&file.hash);
+    }
+    format!("{:x}", hash_engine.finalize())
+}
+
+/// Computes an HMAC-SHA256 (RFC 2104) of the serialized `manifest` with the provided key, formatted as a
+/// lowercase-hexadecimal string. Since the manifest holds the content hash, this covers the contents of every file too.
+fn sign(key: &[u8], manifest: &PackageManifest) -> String {
+    let message = serde_json::to_string(manifest).expect("failed to serialize package manifest");
+    const BLOCK_SIZE: usize = 64;
+
+    // Keys longer than the block size are hashed first; shorter keys are padded with zeros.
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let inner_pad = block.map(|byte| byte ^ 0x36);
+    let outer_pad = block.map(|byte| byte ^ 0x5c);
+
+    let inner_hash = Sha256::new().chain_update(inner_pad).chain_update(message).finalize();
+    let outer_hash = Sha256::new()
+        .chain_update(outer_pad)
+        .chain_update(inner_hash)
+        .finalize();
+    format!("{outer_hash:x}")
+}
+
+/// Compares two strings in constant time (with respect to their contents), so checking a signature doesn't reveal how
+/// many of its leading characters are correct.
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    let difference = a.bytes().zip(b.bytes()).fold(0, |acc, (x, y)| acc | (x ^ y));
+    a.len() == b.len() && difference == 0
+}
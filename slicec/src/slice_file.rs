@@ -1,10 +1,13 @@
 // Copyright (c) ZeroC, Inc.
 
+use crate::ast::node_id::NodeId;
 use crate::grammar::*;
+use crate::trivia::Trivia;
 use crate::utils::ptr_util::WeakPtr;
 use console::style;
 use serde::Serialize;
 use std::cmp::{max, min, Ordering};
+use std::collections::HashSet;
 use std::fmt::{Display, Write};
 
 const EXPANDED_TAB: &str = "    ";
@@ -12,7 +15,7 @@ const EXPANDED_TAB: &str = "    ";
 /// Stores the row and column numbers of a location in a Slice file.
 /// These values are indexed starting at 1 instead of 0 for human readability.
 /// Ex: (1,1) is the start of a file: the first column in the first row.
-#[derive(Serialize, Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Serialize, Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
 pub struct Location {
     pub row: usize,
     pub col: usize,
@@ -40,7 +43,7 @@ impl Default for Location {
     }
 }
 
-#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Span {
     pub start: Location,
     pub end: Location,
@@ -72,10 +75,23 @@ pub struct SliceFile {
     pub relative_path: String,
     pub raw_text: String,
 
+    /// The doc comment at the top of this file, written with `//!` instead of `///`, if there is one.
+    pub comment: Option<DocComment>,
     pub mode: Option<FileCompilationMode>,
-    pub module: Option<WeakPtr<Module>>,
+    /// All the modules declared in this file in declaration order. This includes the module declared at the top of the
+    /// file (`module Foo`) if there is one, and any module blocks (`module Foo { ... }`).
+    pub modules: Vec<WeakPtr<Module>>,
     pub attributes: Vec<WeakPtr<Attribute>>,
     pub contents: Vec<Definition>,
+    /// The non-doc comments and blank lines in this file. This is only populated if trivia preservation is enabled.
+    /// See [SliceOptions::preserve_trivia](crate::slice_options::SliceOptions::preserve_trivia).
+    pub trivia: Vec<Trivia>,
+    /// The preprocessor symbols which are defined at the start of this file. This is set when the file is parsed.
+    ///
+    /// If it's already set before parsing, these symbols are used instead of the compilation's defined symbols. This
+    /// lets files imported from other compilations (see [ModuleImport](crate::importer::ModuleImport)) be parsed
+    /// with the same symbols they were originally parsed with.
+    pub defined_symbols: Option<HashSet<String>>,
 
     pub is_source: bool,
 }
@@ -94,10 +110,13 @@ impl SliceFile {
             filename,
             relative_path,
             raw_text,
+            comment: None,
             mode: None,
-            module: None,
+            modules: Vec::new(),
             attributes: Vec::new(),
             contents: Vec::new(),
+            trivia: Vec::new(),
+            defined_symbols: None,
             is_source,
         }
     }
@@ -113,6 +132,14 @@ impl SliceFile {
             .map_or(CompilationMode::default(), |mode| mode.version)
     }
 
+    /// Returns the trivia attached to the element with the provided handle, in the order it appears in this file.
+    pub fn trivia_for<T: ?Sized>(&self, id: NodeId<T>) -> impl Iterator<Item = &Trivia> {
+        let index = id.index();
+        self.trivia
+            .iter()
+            .filter(move |trivia| trivia.attached_to.is_some_and(|attached_to| attached_to.index() == index))
+    }
+
     /// Retrieves a formatted snippet from the slice file.
     pub(crate) fn get_snippet(&self, start: Location, end: Location) -> String {
         debug_assert!(start <= end);
@@ -4,6 +4,8 @@
 // Any type that starts with 'Grammar' is a slicec type, not a Slice-compiler definition type.
 #![cfg_attr(rustfmt, rustfmt_skip)] // Keep the `use ... as ...` one-per-line.
 use slicec::grammar::Attribute as GrammarAttribute;
+use slicec::grammar::Constant as GrammarConstant;
+use slicec::grammar::ConstantValue as GrammarConstantValue;
 use slicec::grammar::CustomType as GrammarCustomType;
 use slicec::grammar::Definition as GrammarDefinition;
 use slicec::grammar::Dictionary as GrammarDictionary;
@@ -22,22 +24,23 @@ use slicec::grammar::Struct as GrammarStruct;
 use slicec::grammar::Types as GrammarTypes;
 use slicec::grammar::TypeAlias as GrammarTypeAlias;
 use slicec::grammar::TypeRef as GrammarTypeRef;
+use slicec::grammar::Visibility as GrammarVisibility;
 use slicec::slice_file::SliceFile as GrammarSliceFile;
 
 // Pull in traits from 'slicec' so we can call their functions.
 use slicec::grammar::{Attributable, Commentable, Contained, Entity, Member, NamedSymbol, Type};
-// Pull in the attribute types without aliases, since they're not ambiguous.
-use slicec::grammar::attributes::{Allow, Compress, Deprecated, Oneway, SlicedFormat, Unparsed};
 
 // Pull in all the mapped Slice-compiler definition types.
 use crate::definition_types::*;
 
+use slice_codec::InvalidDataErrorKind;
+
 /// Returns an [EntityInfo] describing the provided element.
 fn get_entity_info_for(element: &impl Commentable) -> EntityInfo {
     EntityInfo {
         identifier: element.identifier().to_owned(),
         attributes: get_attributes_from(element.attributes()),
-        comment: element.comment().map(Into::into),
+        comment: element.effective_comment().map(Into::into),
     }
 }
 
@@ -65,7 +68,7 @@ fn get_doc_comment_for_parameter(parameter: &GrammarParameter) -> Option<DocComm
 fn convert_doc_comment_link(link_result: Result<&dyn Entity, &GrammarIdentifier>) -> EntityId {
     match link_result {
         Ok(entity) => entity.parser_scoped_identifier(),
-        Err(identifier) => identifier.value.clone(),
+        Err(identifier) => identifier.value.to_string(),
     }
 }
 
@@ -73,78 +76,54 @@ fn convert_doc_comment_link(link_result: Result<&dyn Entity, &GrammarIdentifier>
 fn get_attributes_from(attributes: Vec<&GrammarAttribute>) -> Vec<Attribute> {
     attributes.into_iter().map(|attribute| Attribute {
         directive: attribute.kind.directive().to_owned(),
-        args: get_attribute_args(attribute),
+        args: attribute.arguments(),
     })
     .collect()
 }
 
-// TODO this is a temporary hack because we know all the possible attributes.
-// The `Attribute` API doesn't offer a way to convert parsed-arguments back to a string.
-// And this entire API will be rewritten after porting slicec-cs, so no point changing it now.
-fn get_attribute_args(attribute: &GrammarAttribute) -> Vec<String> {
-    if let Some(unparsed) = attribute.downcast::<Unparsed>() {
-        return unparsed.args.clone();
-    }
-
-    if let Some(allow) = attribute.downcast::<Allow>() {
-        return allow.allowed_lints.clone();
-    }
-
-    if let Some(compress) = attribute.downcast::<Compress>() {
-        let mut args = Vec::new();
-        if compress.compress_args {
-            args.push("Args".to_owned());
-        }
-        if compress.compress_return {
-            args.push("Return".to_owned());
-        }
-        return args;
-    }
-
-    if let Some(deprecated) = attribute.downcast::<Deprecated>() {
-        return deprecated.reason.iter().cloned().collect();
-    }
-
-    if attribute.downcast::<Oneway>().is_some() {
-        return Vec::new();
-    }
-
-    if let Some(sliced_format) = attribute.downcast::<SlicedFormat>() {
-        let mut args = Vec::new();
-        if sliced_format.sliced_args {
-            args.push("Args".to_owned());
-        }
-        if sliced_format.sliced_return {
-            args.push("Return".to_owned());
-        }
-        return args;
-    }
-
-    panic!("Impossible attribute encountered")
-}
-
 // =========================== //
 // Direct conversion functions //
 // =========================== //
 
-impl From<&GrammarSliceFile> for SliceFile {
-    fn from(slice_file: &GrammarSliceFile) -> Self {
-        // Convert the slice_file's module declaration.
-        // TODO this crashes on an empty Slice file, we need to filter out empty files at an earlier stage.
-        let module = slice_file.module.as_ref().unwrap().borrow();
-        let converted_module = Module {
-            identifier: module.nested_module_identifier().to_owned(),
-            attributes: get_attributes_from(module.attributes()),
-        };
+/// Converts a Slice file from its representation in the AST, to its representation in the `Compiler` Slice module.
+///
+/// Since the `Compiler` module only allows one module declaration per file, files which use module blocks are split
+/// into multiple [`SliceFile`]s (one per module, each with the same path), listed in the order they were declared in.
+/// Modules which don't directly contain any definitions are omitted, unless they're the file's only module.
+/// Files without any module declarations (which must also be empty) aren't converted at all.
+///
+/// Returns an error if the file contains a value that can't be represented in the `Compiler` module.
+pub fn convert_slice_file(slice_file: &GrammarSliceFile) -> Result<Vec<SliceFile>, slice_codec::Error> {
+    let mut module_contents = slice_file
+        .modules
+        .iter()
+        .map(|module_ptr| (module_ptr.borrow(), Vec::new()))
+        .collect::<Vec<_>>();
+
+    // Sort the file's definitions by which module they're defined in.
+    for definition in &slice_file.contents {
+        let module = definition.borrow().get_module();
+        let (_, contents) = module_contents
+            .iter_mut()
+            .find(|(m, _)| std::ptr::eq(*m, module))
+            .expect("definition's module wasn't declared in its file");
+        contents.push(definition);
+    }
 
-        // Return a converted slice file.
-        SliceFile {
+    let is_only_module = module_contents.len() == 1;
+    module_contents
+        .into_iter()
+        .filter(|(_, contents)| is_only_module || !contents.is_empty())
+        .map(|(module, contents)| Ok(SliceFile {
             path: slice_file.relative_path.clone(),
-            module_declaration: converted_module,
+            module_declaration: Module {
+                identifier: module.nested_module_identifier().to_owned(),
+                attributes: get_attributes_from(module.attributes()),
+            },
             attributes: get_attributes_from(slice_file.attributes()),
-            contents: SliceFileContentsConverter::convert(&slice_file.contents),
-        }
-    }
+            contents: SliceFileContentsConverter::convert(contents)?,
+        }))
+        .collect()
 }
 
 impl From<&GrammarDocComment> for DocComment {
@@ -185,6 +164,7 @@ impl From<&GrammarMessageComponent> for MessageComponent {
 #[derive(Debug)]
 pub struct SliceFileContentsConverter {
     converted_contents: Vec<Symbol>,
+    error: Option<slice_codec::Error>,
 }
 
 impl SliceFileContentsConverter {
@@ -195,10 +175,13 @@ impl SliceFileContentsConverter {
     /// and storing them. In addition to top-level definitions, the returned [`Vec`] also contains [`Symbol`]s for each
     /// anonymous type encountered while iterating. Anonymous types always appear in the returned contents _before_
     /// the [`Symbol`]s that referenced them.
-    pub fn convert(contents: &[GrammarDefinition]) -> Vec<Symbol> {
+    ///
+    /// If any of the definitions couldn't be converted, this returns an error describing the first such failure.
+    pub fn convert(contents: Vec<&GrammarDefinition>) -> Result<Vec<Symbol>, slice_codec::Error> {
         // Create a new converter.
         let mut converter = SliceFileContentsConverter {
-            converted_contents: Vec::new()
+            converted_contents: Vec::new(),
+            error: None,
         };
 
         // Iterate through the provided file's contents, and convert each of it's top-level definitions.
@@ -209,13 +192,17 @@ impl SliceFileContentsConverter {
                 GrammarDefinition::Enum(v) => Symbol::Enum(converter.convert_enum(v.borrow())),
                 GrammarDefinition::CustomType(v) => Symbol::CustomType(converter.convert_custom_type(v.borrow())),
                 GrammarDefinition::TypeAlias(v) => Symbol::TypeAlias(converter.convert_type_alias(v.borrow())),
+                GrammarDefinition::Constant(v) => Symbol::Constant(converter.convert_constant(v.borrow())),
                 _ => panic!("TODO: remove classes and exceptions"),
             };
             converter.converted_contents.push(converted);
         }
 
-        // Return all the converted elements, consuming the converter.
-        converter.converted_contents
+        // Return all the converted elements (or the first error), consuming the converter.
+        match converter.error {
+            Some(error) => Err(error),
+            None => Ok(converter.converted_contents),
+        }
     }
 
     fn convert_type_ref(&mut self, type_ref: &GrammarTypeRef) -> TypeRef {
@@ -229,16 +216,19 @@ impl SliceFileContentsConverter {
     fn convert_struct(&mut self, struct_def: &GrammarStruct) -> Struct {
         Struct {
             entity_info: get_entity_info_for(struct_def),
+            is_internal: struct_def.visibility == GrammarVisibility::Internal,
             is_compact: struct_def.is_compact,
             fields: struct_def.fields().into_iter().map(|e| self.convert_field(e)).collect(),
+            constants: struct_def.constants().into_iter().map(|e| self.convert_constant(e)).collect(),
         }
     }
 
     fn convert_field(&mut self, field: &GrammarField) -> Field {
         Field {
             entity_info: get_entity_info_for(field),
-            tag: field.tag.as_ref().map(|integer| integer.value as i32),
+            tag: field.validated_tag(),
             data_type: self.convert_type_ref(field.data_type()),
+            default_value: field.default_value().map(|value| self.convert_constant_value(value)),
         }
     }
 
@@ -247,19 +237,26 @@ impl SliceFileContentsConverter {
 
         Interface {
             entity_info: get_entity_info_for(interface_def),
+            is_internal: interface_def.visibility == GrammarVisibility::Internal,
             bases: bases.into_iter().map(|i| i.module_scoped_identifier()).collect(),
             operations: interface_def.operations().into_iter().map(|e| self.convert_operation(e)).collect(),
+            constants: interface_def.constants().into_iter().map(|e| self.convert_constant(e)).collect(),
         }
     }
 
     fn convert_operation(&mut self, operation: &GrammarOperation) -> Operation {
         Operation {
             entity_info: get_entity_info_for(operation),
-            is_idempotent: operation.is_idempotent,
+            is_idempotent: operation.is_idempotent(),
+            is_readonly: operation.is_readonly(),
+            is_oneway: operation.is_oneway(),
             parameters: operation.parameters().into_iter().map(|e| self.convert_parameter(e)).collect(),
             has_streamed_parameter: operation.streamed_parameter().is_some(),
+            streamed_parameter_element_size: operation.streamed_parameter().and_then(|p| p.stream_element_size()),
             return_type: operation.return_members().into_iter().map(|e| self.convert_parameter(e)).collect(),
-            has_streamed_return: operation.streamed_return_member().is_some(), 
+            has_streamed_return: operation.streamed_return_member().is_some(),
+            streamed_return_element_size: operation.streamed_return_member().and_then(|p| p.stream_element_size()),
+            error_type: operation.error_type.as_ref().map(|type_ref| self.convert_type_ref(type_ref)),
         }
     }
 
@@ -272,28 +269,27 @@ impl SliceFileContentsConverter {
 
         Field {
             entity_info: parameter_info,
-            tag: parameter.tag.as_ref().map(|integer| integer.value as i32),
+            tag: parameter.validated_tag(),
             data_type: self.convert_type_ref(parameter.data_type()),
+            default_value: parameter.default_value().map(|value| self.convert_constant_value(value)),
         }
     }
 
     fn convert_enum(&mut self, enum_def: &GrammarEnum) -> Enum {
         Enum {
             entity_info: get_entity_info_for(enum_def),
+            is_internal: enum_def.visibility == GrammarVisibility::Internal,
             is_compact: enum_def.is_compact,
-            is_unchecked: enum_def.is_unchecked,
+            is_unchecked: enum_def.is_unchecked || enum_def.is_flags(),
             underlying: enum_def.underlying.as_ref().map(|type_ref| type_ref.type_string()),
             enumerators: enum_def.enumerators().into_iter().map(|e| self.convert_enumerator(e)).collect(), 
+            constants: enum_def.constants().into_iter().map(|e| self.convert_constant(e)).collect(),
         }
     }
 
     fn convert_enumerator(&mut self, enumerator: &GrammarEnumerator) -> Enumerator {
         let entity_info = get_entity_info_for(enumerator);
-        let raw_value = enumerator.value();
-        let value = Discriminant {
-            absolute_value: raw_value.unsigned_abs() as u64,
-            is_negative: raw_value.is_negative(),
-        };
+        let value = self.convert_discriminant(enumerator.value());
         let fields = enumerator.fields().into_iter().map(|e| self.convert_field(e)).collect();
 
         Enumerator { entity_info, value, fields }
@@ -301,17 +297,52 @@ impl SliceFileContentsConverter {
 
     fn convert_custom_type(&mut self, custom_type: &GrammarCustomType) -> CustomType {
         CustomType {
-            entity_info: get_entity_info_for(custom_type)
+            entity_info: get_entity_info_for(custom_type),
+            is_internal: custom_type.visibility == GrammarVisibility::Internal,
         }
     }
 
     fn convert_type_alias(&mut self, type_alias: &GrammarTypeAlias) -> TypeAlias {
         TypeAlias {
             entity_info: get_entity_info_for(type_alias),
+            is_internal: type_alias.visibility == GrammarVisibility::Internal,
             underlying_type: self.convert_type_ref(&type_alias.underlying),
         }
     }
 
+    fn convert_constant(&mut self, constant: &GrammarConstant) -> Constant {
+        Constant {
+            entity_info: get_entity_info_for(constant),
+            is_internal: constant.visibility == GrammarVisibility::Internal,
+            data_type: constant.data_type.as_ref().map(|type_ref| type_ref.type_string()),
+            value: self.convert_constant_value(constant.value()),
+        }
+    }
+
+    fn convert_constant_value(&mut self, value: &GrammarConstantValue) -> ConstantValue {
+        match value {
+            GrammarConstantValue::Integer(v) => ConstantValue::Integer(self.convert_discriminant(*v)),
+            GrammarConstantValue::Bool(v) => ConstantValue::Bool(*v),
+            GrammarConstantValue::String(v) => ConstantValue::String(v.clone()),
+        }
+    }
+
+    /// Converts an integer value to a [`Discriminant`].
+    /// If the value's magnitude doesn't fit in a `u64`, an error is recorded, and a `0` discriminant is returned.
+    fn convert_discriminant(&mut self, value: i128) -> Discriminant {
+        let absolute_value = u64::try_from(value.unsigned_abs()).unwrap_or_else(|_| {
+            let error = InvalidDataErrorKind::OutOfRange {
+                value,
+                min: -i128::from(u64::MAX),
+                max: i128::from(u64::MAX),
+                typename: "Discriminant",
+            };
+            self.error.get_or_insert(error.into());
+            0
+        });
+        Discriminant { absolute_value, is_negative: value.is_negative() }
+    }
+
     fn convert_sequence(&mut self, sequence: &GrammarSequence) -> SequenceType {
         SequenceType {
             element_type: self.convert_type_ref(&sequence.element_type),
@@ -363,3 +394,191 @@ impl SliceFileContentsConverter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use slice_codec::buffer::InputSource;
+    use slice_codec::decoder::Decoder;
+    use slice_codec::encode_into::EncodeInto;
+    use slice_codec::encoder::Encoder;
+    use slice_codec::slice2::Slice2;
+    use slicec::test_helpers::parse;
+    use test_case::test_case;
+
+    const TAG_END_MARKER: i32 = -1;
+
+    /// Compiles the provided Slice file and converts its contents, which must all be in a single module.
+    fn convert(slice: &str) -> Vec<Symbol> {
+        let compilation_state = parse(slice, None);
+        assert!(!compilation_state.diagnostics.has_errors(), "{:?}", compilation_state.diagnostics);
+
+        let mut converted_files = convert_slice_file(&compilation_state.files[0]).unwrap();
+        assert_eq!(converted_files.len(), 1);
+        converted_files.remove(0).contents
+    }
+
+    /// Encodes the provided value with the Slice2 encoding, and returns the encoded bytes.
+    fn encode(value: impl EncodeInto<Slice2>) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        Encoder::from(&mut buffer).encode(value).unwrap();
+        buffer
+    }
+
+    /// Decodes an [`EntityInfo`] without any attributes or doc comment, and returns its identifier.
+    fn decode_entity_info(decoder: &mut Decoder<impl InputSource>) -> String {
+        assert!(!decoder.decode::<bool>().unwrap()); // The bit-sequence: there's no doc comment.
+        let identifier = decoder.decode().unwrap();
+        assert_eq!(decoder.decode_size().unwrap(), 0); // There are no attributes.
+        assert_eq!(decoder.decode_varint::<i32>().unwrap(), TAG_END_MARKER);
+        identifier
+    }
+
+    #[test_case("internal", true; "internal")]
+    #[test_case("", false; "public")]
+    fn visibility_round_trips(modifier: &str, expected: bool) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                {modifier} custom C
+            "
+        );
+        let contents = convert(&slice);
+
+        // Act
+        let bytes = encode(&contents[0]);
+
+        // Assert
+        let mut decoder = Decoder::from(&bytes);
+        assert_eq!(decoder.decode_varint::<u8>().unwrap(), 3); // The discriminant of `Symbol::CustomType`.
+        assert_eq!(decode_entity_info(&mut decoder), "C");
+        assert_eq!(decoder.decode::<bool>().unwrap(), expected);
+        assert_eq!(decoder.decode_varint::<i32>().unwrap(), TAG_END_MARKER); // The end of the `CustomType`.
+        assert_eq!(decoder.decode_varint::<i32>().unwrap(), TAG_END_MARKER); // The end of the `Symbol`.
+        assert_eq!(decoder.remaining(), 0);
+    }
+
+    #[test]
+    fn visibility_is_converted_for_every_kind_of_definition() {
+        // Arrange
+        let slice = "
+            module Test
+            internal struct S {}
+            internal interface I {}
+            internal enum E { A }
+            internal typealias T = int32
+            internal const C = 5
+        ";
+
+        // Act
+        let contents = convert(slice);
+
+        // Assert
+        let visibilities = contents.iter().map(|symbol| match symbol {
+            Symbol::Struct(v) => v.is_internal,
+            Symbol::Interface(v) => v.is_internal,
+            Symbol::Enum(v) => v.is_internal,
+            Symbol::TypeAlias(v) => v.is_internal,
+            Symbol::Constant(v) => v.is_internal,
+            _ => panic!("unexpected symbol: {symbol:?}"),
+        });
+        assert_eq!(visibilities.collect::<Vec<_>>(), [true; 5]);
+    }
+
+    #[test_case("a: int32 = 7 - 12", Some(-5); "with default")]
+    #[test_case("a: int32", None; "without default")]
+    fn field_default_values_round_trip(field: &str, expected: Option<i64>) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                struct S {{ {field} }}
+            "
+        );
+        let contents = convert(&slice);
+        let Symbol::Struct(struct_def) = &contents[0] else { panic!("expected a struct") };
+
+        // Act
+        let bytes = encode(&struct_def.fields[0]);
+
+        // Assert
+        let mut decoder = Decoder::from(&bytes);
+        assert_eq!(decoder.decode::<u8>().unwrap(), u8::from(expected.is_some()) << 1); // The bit-sequence.
+        assert_eq!(decode_entity_info(&mut decoder), "a");
+        assert_eq!(decoder.decode::<String>().unwrap(), "int32");
+        assert!(!decoder.decode::<bool>().unwrap());
+        assert_eq!(decoder.decode_size().unwrap(), 0);
+        assert_eq!(decoder.decode_varint::<i32>().unwrap(), TAG_END_MARKER); // The end of the `TypeRef`.
+        if let Some(expected) = expected {
+            assert_eq!(decoder.decode_varint::<u8>().unwrap(), 0); // The discriminant of `ConstantValue::Integer`.
+            assert_eq!(decoder.decode::<u64>().unwrap(), expected.unsigned_abs());
+            assert_eq!(decoder.decode::<bool>().unwrap(), expected.is_negative());
+            assert_eq!(decoder.decode_varint::<i32>().unwrap(), TAG_END_MARKER); // The end of the `Discriminant`.
+            assert_eq!(decoder.decode_varint::<i32>().unwrap(), TAG_END_MARKER); // The end of the `ConstantValue`.
+        }
+        assert_eq!(decoder.decode_varint::<i32>().unwrap(), TAG_END_MARKER); // The end of the `Field`.
+        assert_eq!(decoder.remaining(), 0);
+    }
+
+    #[test]
+    fn parameter_default_values_are_converted() {
+        // Arrange
+        let slice = r#"
+            module Test
+            interface I {
+                op(a: string, b: bool = true, c: string = "hi")
+            }
+        "#;
+
+        // Act
+        let contents = convert(slice);
+
+        // Assert
+        let Symbol::Interface(interface_def) = &contents[0] else { panic!("expected an interface") };
+        let parameters = &interface_def.operations[0].parameters;
+        assert!(parameters[0].default_value.is_none());
+        assert!(matches!(parameters[1].default_value, Some(ConstantValue::Bool(true))));
+        assert!(matches!(&parameters[2].default_value, Some(ConstantValue::String(s)) if s == "hi"));
+    }
+
+    #[test_case("throws MyError", Some("Test::MyError"); "with error type")]
+    #[test_case("", None; "without error type")]
+    fn operation_error_types_round_trip(throws_clause: &str, expected: Option<&str>) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                enum MyError {{ NotFound }}
+                interface I {{
+                    op() {throws_clause}
+                }}
+            "
+        );
+        let contents = convert(&slice);
+        let Symbol::Interface(interface_def) = &contents[1] else { panic!("expected an interface") };
+
+        // Act
+        let bytes = encode(&interface_def.operations[0]);
+
+        // Assert
+        let mut decoder = Decoder::from(&bytes);
+        assert_eq!(decoder.decode::<u8>().unwrap(), u8::from(expected.is_some()) << 2); // The bit-sequence.
+        assert_eq!(decode_entity_info(&mut decoder), "op");
+        assert!(!decoder.decode::<bool>().unwrap()); // isIdempotent
+        assert!(!decoder.decode::<bool>().unwrap()); // isReadonly
+        assert!(!decoder.decode::<bool>().unwrap()); // isOneway
+        assert_eq!(decoder.decode_size().unwrap(), 0); // parameters
+        assert!(!decoder.decode::<bool>().unwrap()); // hasStreamedParameter
+        assert_eq!(decoder.decode_size().unwrap(), 0); // returnType
+        assert!(!decoder.decode::<bool>().unwrap()); // hasStreamedReturn
+        if let Some(expected) = expected {
+            assert_eq!(decoder.decode::<String>().unwrap(), expected);
+            assert!(!decoder.decode::<bool>().unwrap());
+            assert_eq!(decoder.decode_size().unwrap(), 0);
+            assert_eq!(decoder.decode_varint::<i32>().unwrap(), TAG_END_MARKER); // The end of the `TypeRef`.
+        }
+        assert_eq!(decoder.decode_varint::<i32>().unwrap(), TAG_END_MARKER); // The end of the `Operation`.
+        assert_eq!(decoder.remaining(), 0);
+    }
+}
@@ -1,8 +1,15 @@
 // Copyright (c) ZeroC, Inc.
 
-use crate::diagnostics::Lint;
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostics, Lint};
+use crate::prelude::PreludeFile;
+use crate::utils::string_util::CaseStyle;
 use clap::ArgAction::Append;
 use clap::{Parser, ValueEnum};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
 
 // Note: clap uses the doc-comments of fields to populate the '--help' output of slicec-xxx.
 //       boolean flags automatically default to false, and strings automatically default to empty.
@@ -12,11 +19,21 @@ use clap::{Parser, ValueEnum};
 #[derive(Debug, Default, Hash, Parser)]
 #[command(author, version, about, long_about = DESCRIPTION, rename_all = "kebab-case")]
 pub struct SliceOptions {
-    /// List of Slice files to compile.
-    #[arg(required = true)]
+    /// List of Slice files to compile. Pass '-' to read a Slice file from stdin.
+    #[arg(required_unless_present = "config")]
     pub sources: Vec<String>,
 
+    /// The name to report diagnostics for the Slice file read from stdin under. Defaults to '<stdin>'.
+    #[arg(long, value_name = "NAME")]
+    pub stdin_filename: Option<String>,
+
+    /// Load options from the specified configuration file (typically 'slice.toml').
+    /// Options passed on the command line take precedence over the options in this file.
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<String>,
+
     /// Add a directory or Slice file to the list of references.
+    /// Paths in referenced directories can be excluded with a '.sliceignore' file, which holds one glob per line.
     #[arg(short = 'R', num_args = 1, action = Append, value_name = "REFERENCE")]
     pub references: Vec<String>,
 
@@ -26,10 +43,49 @@ pub struct SliceOptions {
 
     /// Instruct the compiler to allow the specified lint.
     // TODO add a link to the lint reference in this doc comment!
-    #[arg(short = 'A', long = "allow", num_args = 1, action = Append, value_name = "LINT_NAME", value_parser = Lint::ALLOWABLE_LINT_IDENTIFIERS, hide_possible_values = true, ignore_case = true)]
+    #[arg(
+        short = 'A',
+        long = "allow",
+        num_args = 1,
+        action = Append,
+        value_name = "LINT_NAME",
+        value_parser = parse_lint_identifier
+    )]
     pub allowed_lints: Vec<String>,
 
-    /// Validate input files without generating code for them.
+    /// Instruct the compiler to report the specified lint as an error.
+    #[arg(long = "deny", num_args = 1, action = Append, value_name = "LINT_NAME", value_parser = parse_lint_identifier)]
+    pub denied_lints: Vec<String>,
+
+    /// Deny the use of the specified type or construct: '<TYPE>[:<CODE>][=<REASON>]'.
+    /// Any primitive type can be denied, along with 'Sequence', 'Dictionary', 'Result', 'custom', 'class',
+    /// and 'unchecked-enum'. Uses of it are reported with the provided code (which must be namespaced) instead of
+    /// E064, along with the provided reason. Ex: '--deny-type float32:myorg::NoFloats=use float64 instead'.
+    #[arg(long = "deny-type", num_args = 1, action = Append, value_name = "TYPE", value_parser = parse_denied_type)]
+    pub denied_types: Vec<DeniedType>,
+
+    /// Require the identifiers of a kind of definition to be written in a case style: '<KIND>=<STYLE>'.
+    /// Styles are 'PascalCase', 'camelCase', and 'snake_case'. Ex: '--naming-convention operation=camelCase'.
+    #[arg(
+        long = "naming-convention",
+        num_args = 1,
+        action = Append,
+        value_name = "CONVENTION",
+        value_parser = parse_naming_convention
+    )]
+    pub naming_conventions: Vec<NamingConvention>,
+
+    /// Report any tags which are skipped over by a type's tagged members, but haven't been reserved with the
+    /// 'reservedTags' attribute.
+    #[arg(long)]
+    pub audit_tags: bool,
+
+    /// Report any types, exceptions, and constants in the source files which can't be reached from an interface.
+    /// Definitions can be excluded from this check with the 'allow(UnusedDefinition)' attribute.
+    #[arg(long)]
+    pub report_unused: bool,
+
+    /// Validate input files without generating code for them, or writing any other output files.
     #[arg(long)]
     pub dry_run: bool,
 
@@ -37,6 +93,79 @@ pub struct SliceOptions {
     #[arg(short = 'O', long, value_name = "DIRECTORY")]
     pub output_dir: Option<String>,
 
+    /// Check enumerator values and tags against the specified lock file, creating it if it doesn't exist.
+    #[arg(long, value_name = "FILE")]
+    pub lock_file: Option<String>,
+
+    /// Regenerate the lock file instead of checking against it. Only meaningful with '--lock-file'.
+    #[arg(long, requires = "lock_file")]
+    pub update_lock_file: bool,
+
+    /// Bundle the source files into a schema package, which can be passed to other compilations with '-R'.
+    #[arg(long, value_name = "FILE")]
+    pub export_package: Option<String>,
+
+    /// Set the version recorded in the exported schema package. Only meaningful with '--export-package'.
+    #[arg(long, value_name = "VERSION", requires = "export_package")]
+    pub package_version: Option<String>,
+
+    /// Sign exported schema packages with the key stored in the specified file, and require that any referenced
+    /// schema packages are signed with it. Signing only checks integrity between parties that share this key.
+    #[arg(long, value_name = "FILE")]
+    pub package_key: Option<String>,
+
+    /// Write a dependency file listing the Slice files that each source file depends on, for use by build systems.
+    #[arg(long, value_name = "FILE")]
+    pub depfile: Option<String>,
+
+    /// Set which format to write the dependency file in. Only meaningful with '--depfile'.
+    #[arg(long, value_name = "FORMAT", value_enum, default_value_t = DependencyFileFormat::Make, ignore_case = true)]
+    pub depfile_format: DependencyFileFormat,
+
+    /// Generate reference documentation for the source files into the specified directory, with one page per module.
+    #[arg(long, value_name = "DIRECTORY")]
+    pub doc_dir: Option<String>,
+
+    /// Set which format to generate documentation in. Only meaningful with '--doc-dir'.
+    #[arg(long, value_name = "FORMAT", value_enum, default_value_t = DocFormat::Markdown, ignore_case = true)]
+    pub doc_format: DocFormat,
+
+    /// Write a diagram of the relationships between the definitions in the source files to the specified file.
+    #[arg(long, value_name = "FILE")]
+    pub diagram: Option<String>,
+
+    /// Set which format to write the diagram in. Only meaningful with '--diagram'.
+    #[arg(long, value_name = "FORMAT", value_enum, default_value_t = DiagramFormat::Dot, ignore_case = true)]
+    pub diagram_format: DiagramFormat,
+
+    /// Only draw the definitions in the specified module (and its submodules), along with the definitions that they're
+    /// related to.
+    #[arg(long, value_name = "MODULE", requires = "diagram")]
+    pub diagram_scope: Option<String>,
+
+    /// Only draw related definitions which are at most this many relationships away from a definition in scope. By
+    /// default, every related definition is drawn.
+    #[arg(long, value_name = "DEPTH", requires = "diagram")]
+    pub diagram_depth: Option<usize>,
+
+    /// Write a source map alongside each generated file, linking its generated code back to the Slice definitions.
+    #[arg(long)]
+    pub source_maps: bool,
+
+    /// Report additional information about the compilation, like which paths were skipped because of ignore files.
+    #[arg(long)]
+    pub verbose: bool,
+
+    /// Apply the suggested fixes of any reported warnings directly to the source files.
+    /// Fixes are only applied if compilation didn't report any errors.
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Keep running after compiling, and recompile whenever any of the source or reference files change.
+    /// Diagnostics are emitted after each compilation, but no code is generated.
+    #[arg(long, conflicts_with = "fix")]
+    pub watch: bool,
+
     /// Set which format to emit errors and warnings with.
     #[arg(long, value_name = "FORMAT", value_enum, default_value_t = DiagnosticFormat::Human, ignore_case = true)]
     pub diagnostic_format: DiagnosticFormat,
@@ -44,6 +173,68 @@ pub struct SliceOptions {
     /// Disable ANSI color codes in diagnostic output.
     #[arg(long)]
     pub disable_color: bool,
+
+    /// Limit how many diagnostics are emitted per diagnostic code. Any diagnostics past this limit are summarized.
+    #[arg(long, value_name = "COUNT")]
+    pub max_diagnostics_per_code: Option<usize>,
+
+    /// Sort diagnostics by file, and then by their position in the file, instead of emitting them in the order they
+    /// were reported. Diagnostics which aren't tied to a file are emitted first.
+    /// This has no effect on errors which are streamed by the 'ndjson' format.
+    #[arg(long)]
+    pub sort_diagnostics: bool,
+
+    /// Report how long each phase of compilation took, along with statistics about each file, to stderr.
+    /// The report is human-readable by default, and can be written as JSON with '--timings json'.
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        value_enum,
+        num_args = 0..=1,
+        default_missing_value = "human",
+        ignore_case = true
+    )]
+    pub timings: Option<TimingsFormat>,
+
+    /// Built-in definitions that are added to every compilation as reference files. See [PreludeFile].
+    /// This can only be set by embedders of the compiler, not on the command line.
+    #[arg(skip)]
+    pub prelude: Vec<PreludeFile>,
+
+    /// Identifiers which are reserved by the code generator, and so cannot be used in Slice definitions.
+    /// See [ReservedIdentifier]. This can only be set by embedders of the compiler, not on the command line.
+    #[arg(skip)]
+    pub reserved_identifiers: Vec<ReservedIdentifier>,
+
+    /// The keywords of each language that code is generated for. See [TargetKeywords].
+    /// This can only be set by embedders of the compiler, not on the command line.
+    #[arg(skip)]
+    pub target_keywords: Vec<TargetKeywords>,
+
+    /// Whether to keep the non-doc comments and blank lines of each file, and attach them to nearby elements.
+    /// This is needed by tools which rewrite Slice files, like formatters. See [SliceFile::trivia].
+    /// This can only be set by embedders of the compiler, not on the command line.
+    ///
+    /// [SliceFile::trivia]: crate::slice_file::SliceFile::trivia
+    #[arg(skip)]
+    pub preserve_trivia: bool,
+
+    /// Files whose contents are provided directly, instead of being read from disk. See [InMemoryFile].
+    /// This can only be set by embedders of the compiler, not on the command line.
+    #[arg(skip)]
+    pub in_memory_files: Vec<InMemoryFile>,
+
+    /// Additional validation passes, which are run after the built-in validators. See [ValidatorPlugin].
+    /// This can only be set by embedders of the compiler, not on the command line.
+    #[arg(skip)]
+    pub validator_plugins: Vec<ValidatorPlugin>,
+
+    /// The output that the 'ndjson' diagnostic format streams errors to, and that
+    /// [emit_diagnostics](crate::compilation_state::CompilationState::emit_diagnostics) writes diagnostics to.
+    /// If unset, diagnostics are written to stderr.
+    /// This can only be set by embedders of the compiler, not on the command line.
+    #[arg(skip)]
+    pub error_output: Option<ErrorOutput>,
 }
 
 /// Short description of slicec that is displayed in its help dialogue.
@@ -52,6 +243,287 @@ The Slice compiler.
 Parses Slice files into a typed Abstract Syntax Tree (AST) describing the provided Slice definitions.
 This AST is then encoded with Slice and written to stdout, to be consumed by other tools.";
 
+/// A type or construct that is denied by a project's type policy, along with an optional code and reason for denying
+/// it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeniedType {
+    /// The name of the denied type or construct. Ex: `float32`, `unchecked-enum`.
+    pub name: String,
+    /// The code to report uses of this type with, instead of `E064`. This must be namespaced to avoid collisions with
+    /// the compiler's own codes. Ex: `myorg::NoFloats`.
+    pub code: Option<String>,
+    /// An explanation of why this type is denied, which is included in any diagnostics about it.
+    pub reason: Option<String>,
+}
+
+impl DeniedType {
+    /// The names of all the types and constructs that can be denied.
+    pub const DENIABLE_NAMES: [&'static str; 26] = [
+        "bool",
+        "int8",
+        "uint8",
+        "int16",
+        "uint16",
+        "int32",
+        "uint32",
+        "varint32",
+        "varuint32",
+        "int64",
+        "uint64",
+        "varint62",
+        "varuint62",
+        "float32",
+        "float64",
+        "string",
+        "uuid",
+        "timestamp",
+        "duration",
+        "AnyClass",
+        "Sequence",
+        "Dictionary",
+        "Result",
+        "custom",
+        "class",
+        "unchecked-enum",
+    ];
+}
+
+/// The keywords (reserved words) of a language that code is generated for. Ex: `ref` and `params` in C#.
+///
+/// Unlike [reserved identifiers](ReservedIdentifier), identifiers are allowed to collide with keywords, since code
+/// generators escape them (Ex: `@ref`). But since escaped identifiers are awkward to use, any collisions are reported
+/// with the [KeywordCollision](Lint::KeywordCollision) lint. Keywords are matched exactly.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TargetKeywords {
+    /// The name of the language. Ex: `C#`.
+    pub language: String,
+    /// The language's keywords.
+    pub keywords: Vec<String>,
+}
+
+impl TargetKeywords {
+    pub fn new(language: impl Into<String>, keywords: &[&str]) -> Self {
+        TargetKeywords {
+            language: language.into(),
+            keywords: keywords.iter().map(|keyword| keyword.to_string()).collect(),
+        }
+    }
+
+    /// Returns true if the provided identifier is one of this language's keywords.
+    pub fn contains(&self, identifier: &str) -> bool {
+        self.keywords.iter().any(|keyword| keyword == identifier)
+    }
+}
+
+/// A naming convention, which requires the identifiers of a kind of definition to be written in a certain case style.
+/// Identifiers which don't follow their convention are reported with the [NamingConvention](Lint::NamingConvention)
+/// lint.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NamingConvention {
+    /// The kind of definition this convention applies to. Ex: `operation`.
+    /// This is the kind returned by [Element::kind](crate::grammar::Element::kind).
+    pub kind: String,
+    /// The case style that identifiers of this kind must be written in.
+    pub style: CaseStyle,
+}
+
+impl NamingConvention {
+    /// The kinds of definitions that naming conventions can be applied to.
+    pub const KINDS: [&'static str; 13] = [
+        "module",
+        "struct",
+        "class",
+        "exception",
+        "interface",
+        "operation",
+        "parameter",
+        "field",
+        "enum",
+        "enumerator",
+        "custom type",
+        "type alias",
+        "constant",
+    ];
+}
+
+/// An identifier which is reserved by a code generator, typically because it's used by synthesized members or helper
+/// types in the generated code (Ex: `returnValue`). Since code generators often change the case of identifiers,
+/// reserved identifiers are matched case-insensitively.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ReservedIdentifier {
+    /// The reserved identifier, or identifier prefix.
+    pub identifier: String,
+    /// If true, any identifier starting with `identifier` is reserved, instead of only `identifier` itself.
+    pub is_prefix: bool,
+    /// The name of the code generator which reserved this identifier. Ex: `slicec-cs`.
+    pub reserved_by: String,
+}
+
+impl ReservedIdentifier {
+    /// Creates a [ReservedIdentifier] which reserves exactly the provided identifier.
+    pub fn exact(identifier: impl Into<String>, reserved_by: impl Into<String>) -> Self {
+        ReservedIdentifier {
+            identifier: identifier.into(),
+            is_prefix: false,
+            reserved_by: reserved_by.into(),
+        }
+    }
+
+    /// Creates a [ReservedIdentifier] which reserves any identifier starting with the provided prefix.
+    pub fn prefix(prefix: impl Into<String>, reserved_by: impl Into<String>) -> Self {
+        ReservedIdentifier {
+            identifier: prefix.into(),
+            is_prefix: true,
+            reserved_by: reserved_by.into(),
+        }
+    }
+
+    /// Returns true if the provided identifier is reserved by this [ReservedIdentifier].
+    pub fn matches(&self, identifier: &str) -> bool {
+        match self.is_prefix {
+            true => identifier
+                .get(..self.identifier.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(&self.identifier)),
+            false => identifier.eq_ignore_ascii_case(&self.identifier),
+        }
+    }
+}
+
+/// A Slice file whose contents are held in memory, instead of being read from disk.
+/// This lets tools like editors compile unsaved buffers, while still reporting diagnostics under the buffers' file names.
+///
+/// If an in-memory file has the same path as a file that's being compiled, its contents are used in place of the file's
+/// contents on disk. Otherwise, it's compiled alongside the other files, as a source or reference file.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InMemoryFile {
+    /// The path of the file. This is used when reporting diagnostics, and doesn't need to exist on disk.
+    pub path: String,
+    /// The raw (unparsed) contents of the file.
+    pub contents: String,
+    /// True if this is a source file, false if it's a reference file.
+    /// This is ignored if the file replaces the contents of a file that's being compiled.
+    pub is_source: bool,
+}
+
+impl InMemoryFile {
+    pub fn new(path: impl Into<String>, contents: impl Into<String>, is_source: bool) -> Self {
+        InMemoryFile {
+            path: path.into(),
+            contents: contents.into(),
+            is_source,
+        }
+    }
+}
+
+/// A third-party validation pass, which embedders can use to enforce their own policies (Ex: naming conventions).
+///
+/// Plugins are run in the order they're provided, after all the built-in validators, but only if no errors have been
+/// reported. They can report any diagnostic, but should generally report [custom lints](Lint::Custom), which users can
+/// allow or deny (with `--allow`, `--deny`, or attributes) like any other lint.
+#[derive(Clone, Debug, Hash)]
+pub struct ValidatorPlugin {
+    /// The name of this plugin. Ex: `myorg-naming`.
+    pub name: String,
+    /// The function which performs the validation, by reporting any violations into the provided diagnostics.
+    pub validate: fn(&CompilationState, &mut Diagnostics),
+}
+
+impl ValidatorPlugin {
+    pub fn new(name: impl Into<String>, validate: fn(&CompilationState, &mut Diagnostics)) -> Self {
+        ValidatorPlugin {
+            name: name.into(),
+            validate,
+        }
+    }
+}
+
+/// A shared handle to an output that diagnostics can be written to. See [SliceOptions::error_output].
+///
+/// Clones of this handle all write to the same underlying output.
+#[derive(Clone)]
+pub struct ErrorOutput(Arc<Mutex<dyn Write + Send>>);
+
+impl ErrorOutput {
+    pub fn new(output: impl Write + Send + 'static) -> Self {
+        ErrorOutput(Arc::new(Mutex::new(output)))
+    }
+}
+
+impl Write for ErrorOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.lock().unwrap().flush()
+    }
+}
+
+impl fmt::Debug for ErrorOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ErrorOutput")
+    }
+}
+
+impl Hash for ErrorOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::ptr::hash(Arc::as_ptr(&self.0), state);
+    }
+}
+
+/// Parses a lint identifier, ignoring case for built-in lints.
+/// Custom lints are only known by the validator plugins that report them, so any namespaced identifier is accepted.
+fn parse_lint_identifier(s: &str) -> Result<String, String> {
+    let built_in = Lint::ALLOWABLE_LINT_IDENTIFIERS.iter().find(|lint| lint.eq_ignore_ascii_case(s));
+    match built_in {
+        Some(lint) => Ok(lint.to_string()),
+        None if Lint::is_allowable_identifier(s) => Ok(s.to_owned()),
+        None => Err(format!("'{s}' is not a valid lint identifier")),
+    }
+}
+
+/// Parses a denied type of the form '<TYPE>[:<CODE>][=<REASON>]'.
+fn parse_denied_type(s: &str) -> Result<DeniedType, String> {
+    let (name, reason) = match s.split_once('=') {
+        Some((name, reason)) => (name.trim(), Some(reason.trim().to_owned())),
+        None => (s.trim(), None),
+    };
+    let (name, code) = match name.split_once(':') {
+        Some((name, code)) => (name.trim(), Some(code.trim().to_owned())),
+        None => (name, None),
+    };
+
+    if !DeniedType::DENIABLE_NAMES.contains(&name) {
+        let possible_values = DeniedType::DENIABLE_NAMES.join(", ");
+        return Err(format!("'{name}' cannot be denied; possible values: {possible_values}"));
+    }
+    if let Some(code) = &code {
+        let is_namespaced = code
+            .split_once("::")
+            .is_some_and(|(namespace, rest)| !namespace.is_empty() && !rest.is_empty());
+        if !is_namespaced {
+            return Err(format!("'{code}' is not a valid code; codes must be namespaced. Ex: 'myorg::NoFloats'"));
+        }
+    }
+    Ok(DeniedType { name: name.to_owned(), code, reason })
+}
+
+/// Parses a naming convention of the form '<KIND>=<STYLE>'.
+/// Since kinds are passed on the command line, multi-word kinds can be separated by dashes. Ex: `type-alias`.
+fn parse_naming_convention(s: &str) -> Result<NamingConvention, String> {
+    let Some((kind, style)) = s.split_once('=') else {
+        return Err(format!("'{s}' is not a valid naming convention; expected '<KIND>=<STYLE>'"));
+    };
+
+    let kind = kind.trim().replace('-', " ");
+    if !NamingConvention::KINDS.contains(&kind.as_str()) {
+        let possible_values = NamingConvention::KINDS.map(|kind| kind.replace(' ', "-")).join(", ");
+        return Err(format!("'{kind}' has no naming convention; possible values: {possible_values}"));
+    }
+    let style = CaseStyle::from_str(style.trim(), true)?;
+    Ok(NamingConvention { kind, style })
+}
+
 /// This enum is used to specify the format for emitted diagnostics.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ValueEnum)]
 pub enum DiagnosticFormat {
@@ -59,6 +531,58 @@ pub enum DiagnosticFormat {
     #[default]
     Human,
 
+    /// Like `human`, except that diagnostics are rendered in the style of `rustc`: spans are underlined with carets,
+    /// and notes are rendered inline with the source code they refer to.
+    Pretty,
+
     /// Diagnostics will be serialized as JSON objects and printed to the console, one diagnostic per line.
     Json,
+
+    /// Like `json`, except that errors are printed (and flushed) as soon as they're reported, instead of after
+    /// compilation has finished. Warnings are still printed at the end, since `allow` attributes can suppress them.
+    Ndjson,
+}
+
+/// This enum is used to specify the format of dependency files.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ValueEnum)]
+pub enum DependencyFileFormat {
+    /// Dependencies are written as Makefile rules, with one rule per source file.
+    #[default]
+    Make,
+
+    /// Dependencies are written as a JSON object, mapping each source file to an array of its dependencies.
+    Json,
+}
+
+/// This enum is used to specify the format of generated documentation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ValueEnum)]
+pub enum DocFormat {
+    /// Documentation is written as Markdown files, which can be rendered by most code hosts and static site generators.
+    #[default]
+    Markdown,
+
+    /// Documentation is written as standalone HTML files, which can be served as a static site.
+    Html,
+}
+
+/// This enum is used to specify the format of diagrams.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ValueEnum)]
+pub enum DiagramFormat {
+    /// Diagrams are written as Graphviz directed graphs, which can be rendered with `dot`.
+    #[default]
+    Dot,
+
+    /// Diagrams are written as Mermaid class diagrams, which can be rendered by most code hosts.
+    Mermaid,
+}
+
+/// This enum is used to specify the format of the report written by the `--timings` option.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ValueEnum)]
+pub enum TimingsFormat {
+    /// Timings are written as an aligned table of phases, followed by a line of statistics for each file.
+    #[default]
+    Human,
+
+    /// Timings are written as a single JSON object, with durations in (fractional) milliseconds.
+    Json,
 }
@@ -0,0 +1,172 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module exposes the Slice lexer for use by tools like editors, which need to know how Slice source code is
+//! tokenized (for syntax highlighting for example), without parsing it.
+//!
+//! Unlike the token stream which is fed to the parser, the tokens returned by [tokenize] cover every character in
+//! the source, including whitespace, comments, and preprocessor directives.
+
+use crate::slice_file::Location;
+use std::ops::Range;
+
+/// The categories that tokens are grouped into for syntax highlighting.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TokenCategory {
+    /// Keywords which introduce definitions, or modify them. Ex: `struct`, `compact`, `tag`.
+    Keyword,
+    /// Keywords which name built-in types. Ex: `int32`, `string`, `Sequence`.
+    BuiltinType,
+    Identifier,
+    IntegerLiteral,
+    StringLiteral,
+    CharacterLiteral,
+    /// Brackets and separators. Ex: `{`, `<`, `::`, `,`.
+    Punctuation,
+    /// Symbols used in expressions, and in definitions. Ex: `=`, `?`, `->`, `+`.
+    Operator,
+    /// Doc comments, including the leading `///` (or `//!` for file doc comments).
+    DocComment,
+    /// Non-doc comments, including line comments (`// ...`) and block comments (`/* ... */`).
+    Comment,
+    /// An entire preprocessor directive. Ex: `#if FOO`, `#endif`.
+    PreprocessorDirective,
+    Whitespace,
+    /// Text which isn't valid Slice syntax.
+    Unknown,
+}
+
+/// A single token in a Slice source string, returned by [tokenize].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SyntaxToken<'a> {
+    pub category: TokenCategory,
+    /// The text of this token, exactly as it appears in the source.
+    pub text: &'a str,
+    /// The byte offsets of this token in the source.
+    pub range: Range<usize>,
+    /// The location of this token's first character.
+    pub start: Location,
+    /// The location immediately after this token's last character.
+    pub end: Location,
+}
+
+/// Splits the provided Slice source code into tokens, and returns them in order.
+///
+/// Every character of the source is covered by exactly one token, so concatenating the text of the returned tokens
+/// reproduces the source. Tokenizing never fails; invalid text is returned as [TokenCategory::Unknown] tokens.
+/// Since preprocessor directives aren't evaluated, the contents of inactive `#if` blocks are tokenized as normal.
+pub fn tokenize(source: &str) -> Vec<SyntaxToken<'_>> {
+    let line_index = LineIndex::new(source);
+
+    // Split the source into blocks of Slice code, separated by lines containing preprocessor directives.
+    let mut blocks = Vec::new();
+    let mut directives = Vec::new();
+    let mut block_start = Some(0);
+    let mut line_start = 0;
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('#') {
+            if let Some(start) = block_start.take().filter(|&start| start < line_start) {
+                blocks.push(start..line_start);
+            }
+            let directive_start = line_start + (line.len() - trimmed.len());
+            directives.push(directive_start..line_start + line.trim_end().len());
+        } else if block_start.is_none() {
+            block_start = Some(line_start);
+        }
+        line_start += line.len();
+    }
+    if let Some(start) = block_start {
+        blocks.push(start..source.len());
+    }
+
+    // Lex the blocks of Slice code, and merge the resulting tokens with the preprocessor directives.
+    let blocks = blocks
+        .into_iter()
+        .map(|range| (&source[range.clone()], line_index.location(range.start), line_index.location(range.end)))
+        .collect();
+    let lexed_tokens = crate::parsers::lex_blocks(blocks).into_iter().map(|(mut start, category, end)| {
+        // The lexer doesn't include the leading slashes in doc comments, but we want them to be highlighted.
+        if category == TokenCategory::DocComment {
+            start.col -= 3;
+        }
+        (line_index.offset(start)..line_index.offset(end), category)
+    });
+    let directive_tokens = directives.into_iter().map(|range| (range, TokenCategory::PreprocessorDirective));
+    let mut ranges = lexed_tokens.chain(directive_tokens).collect::<Vec<_>>();
+    ranges.sort_by_key(|(range, _)| range.start);
+
+    // Fill in the gaps between tokens (which are skipped over by the lexer) with whitespace and comment tokens.
+    let mut tokens = Vec::new();
+    let mut position = 0;
+    for (range, category) in ranges {
+        push_trivia(source, position..range.start, &mut tokens, &line_index);
+        position = range.end;
+        tokens.push(create_token(source, range, category, &line_index));
+    }
+    push_trivia(source, position..source.len(), &mut tokens, &line_index);
+    tokens
+}
+
+/// Splits the text in `range` into whitespace and comment tokens, and pushes them into `tokens`.
+fn push_trivia<'a>(source: &'a str, range: Range<usize>, tokens: &mut Vec<SyntaxToken<'a>>, line_index: &LineIndex) {
+    let mut position = range.start;
+    while position < range.end {
+        let rest = &source[position..range.end];
+        let whitespace_length = rest.len() - rest.trim_start().len();
+        let (category, length) = if rest.starts_with("//") {
+            (TokenCategory::Comment, rest.find('\n').unwrap_or(rest.len()))
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            (TokenCategory::Comment, comment.find("*/").map_or(rest.len(), |i| i + 4))
+        } else if whitespace_length > 0 {
+            (TokenCategory::Whitespace, whitespace_length)
+        } else {
+            // This shouldn't happen, since the lexer only skips over whitespace and comments, but just in case.
+            (TokenCategory::Unknown, rest.chars().next().unwrap().len_utf8())
+        };
+
+        tokens.push(create_token(source, position..position + length, category, line_index));
+        position += length;
+    }
+}
+
+fn create_token<'a>(
+    source: &'a str,
+    range: Range<usize>,
+    category: TokenCategory,
+    line_index: &LineIndex,
+) -> SyntaxToken<'a> {
+    SyntaxToken {
+        category,
+        text: &source[range.clone()],
+        start: line_index.location(range.start),
+        end: line_index.location(range.end),
+        range,
+    }
+}
+
+/// Helper struct for converting between byte offsets and [locations](Location) in a source string.
+struct LineIndex<'a> {
+    source: &'a str,
+    /// The byte offset of the start of each line in the source.
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    fn new(source: &'a str) -> Self {
+        let newlines = source.match_indices('\n').map(|(i, _)| i + 1);
+        let line_starts = std::iter::once(0).chain(newlines).collect();
+        LineIndex { source, line_starts }
+    }
+
+    fn offset(&self, location: Location) -> usize {
+        let line_start = self.line_starts[location.row - 1];
+        let mut characters = self.source[line_start..].char_indices();
+        characters.nth(location.col - 1).map_or(self.source.len(), |(i, _)| line_start + i)
+    }
+
+    fn location(&self, offset: usize) -> Location {
+        let row = self.line_starts.partition_point(|&line_start| line_start <= offset);
+        let col = self.source[self.line_starts[row - 1]..offset].chars().count() + 1;
+        Location { row, col }
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains the logic for preserving trivia: the non-doc comments and blank lines in a Slice file.
+//!
+//! Trivia isn't part of the Slice grammar, so the parser skips over it. But tools which rewrite Slice files (like
+//! formatters and refactoring tools) need to know where it was, so they can round-trip files without losing any of
+//! the user's comments. Trivia is only preserved if [preserve_trivia] is set. When it is, each file's trivia is stored
+//! in [SliceFile::trivia], and attached to the element it's closest to.
+//!
+//! [preserve_trivia]: crate::slice_options::SliceOptions::preserve_trivia
+
+use crate::ast::node_id::NodeId;
+use crate::ast::Ast;
+use crate::compilation_state::CompilationState;
+use crate::grammar::NamedSymbol;
+use crate::slice_file::{SliceFile, Span};
+use crate::token_stream::{tokenize, SyntaxToken, TokenCategory};
+
+/// A single piece of trivia in a Slice file.
+#[derive(Clone, Debug)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    /// The text of this trivia, exactly as it appears in the source. For blank lines, this is the whitespace between
+    /// the surrounding lines.
+    pub text: String,
+    pub span: Span,
+    /// The element this trivia is attached to, or `None` if there's no element for it to be attached to.
+    /// This can only happen for trivia which comes after every element in its file.
+    pub attached_to: Option<NodeId<dyn NamedSymbol>>,
+    pub position: TriviaPosition,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TriviaKind {
+    /// A line comment: `// ...`.
+    LineComment,
+    /// A block comment: `/* ... */`.
+    BlockComment,
+    /// A group of consecutive blank lines. This holds the number of blank lines in the group.
+    BlankLines(usize),
+}
+
+/// Describes where trivia is relative to the element it's attached to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TriviaPosition {
+    /// The trivia comes before the element. Ex: a comment on the line above a definition.
+    Leading,
+    /// The trivia comes after the element, on the same line that the element ends on. Ex: `a: int32 // comment`.
+    Trailing,
+}
+
+pub(crate) fn preserve_trivia(state: &mut CompilationState) {
+    let ast = &state.ast;
+    for file in &mut state.files {
+        file.trivia = collect_trivia(file, ast);
+    }
+}
+
+fn collect_trivia(file: &SliceFile, ast: &Ast) -> Vec<Trivia> {
+    // Find all the elements that were defined in this file, so we can attach trivia to them.
+    let elements = ast
+        .node_ids::<dyn NamedSymbol>()
+        .map(|id| (NodeId::new(id.index()), ast.get(id).span()))
+        .filter(|(_, span)| span.file == file.relative_path)
+        .collect::<Vec<_>>();
+
+    let tokens = tokenize(&file.raw_text);
+    let mut trivia = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        let kind = match token.category {
+            TokenCategory::Comment if token.text.starts_with("//") => TriviaKind::LineComment,
+            TokenCategory::Comment => TriviaKind::BlockComment,
+            TokenCategory::Whitespace => {
+                // A whitespace token containing `n` newlines spans `n - 1` blank lines, unless it's at the very start
+                // of the file, in which case, the first line is blank too.
+                let newlines = token.text.matches('\n').count();
+                match newlines.saturating_sub(usize::from(i != 0)) {
+                    0 => continue,
+                    blank_lines => TriviaKind::BlankLines(blank_lines),
+                }
+            }
+            _ => continue,
+        };
+
+        let span = Span::new(token.start, token.end, &file.relative_path);
+        let (attached_to, position) = attach(token, kind, &elements);
+        trivia.push(Trivia {
+            kind,
+            text: token.text.to_owned(),
+            span,
+            attached_to,
+            position,
+        });
+    }
+    trivia
+}
+
+/// Returns the element that the provided trivia should be attached to, and where it is relative to that element.
+///
+/// Comments which start on the same line that an element ends on are attached to that element as trailing trivia.
+/// All other trivia is attached to the next element in the file as leading trivia.
+fn attach(
+    token: &SyntaxToken,
+    kind: TriviaKind,
+    elements: &[(NodeId<dyn NamedSymbol>, &Span)],
+) -> (Option<NodeId<dyn NamedSymbol>>, TriviaPosition) {
+    if !matches!(kind, TriviaKind::BlankLines(_)) {
+        let preceding_element = elements
+            .iter()
+            .filter(|(_, span)| span.end.row == token.start.row && span.end <= token.start)
+            .max_by_key(|(_, span)| span.end);
+        if let Some((id, _)) = preceding_element {
+            return (Some(*id), TriviaPosition::Trailing);
+        }
+    }
+
+    let next_element = elements
+        .iter()
+        .filter(|(_, span)| span.start >= token.end)
+        .min_by_key(|(_, span)| span.start);
+    (next_element.map(|(id, _)| *id), TriviaPosition::Leading)
+}
@@ -1,8 +1,10 @@
 // Copyright (c) ZeroC, Inc.
 
 use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::grammar::attributes::Unparsed;
 use crate::grammar::AttributeKind;
 use crate::slice_file::Span;
+use crate::versioning::Version;
 
 /// Reports an error if the provided list of arguments is empty.
 pub fn check_that_arguments_were_provided(
@@ -63,6 +65,31 @@ pub fn check_that_exactly_one_argument_was_provided(
     check_that_at_most_one_argument_was_provided(arguments, directive, span, diagnostics);
 }
 
+/// Returns the version argument of a versioning attribute (like `since` or `removed`).
+/// Reports an error if there isn't exactly one argument, or if the argument isn't a valid [Version].
+pub fn parse_version_argument(
+    unparsed: &Unparsed,
+    directive: &str,
+    span: &Span,
+    diagnostics: &mut Diagnostics,
+) -> String {
+    check_that_exactly_one_argument_was_provided(&unparsed.args, directive, span, diagnostics);
+
+    let version = unparsed.args.first().cloned().unwrap_or_default();
+    if let Some(arg_span) = unparsed.arg_spans.first() {
+        if Version::parse(&version).is_none() {
+            Diagnostic::new(Error::ArgumentNotSupported {
+                argument: version.clone(),
+                directive: directive.to_owned(),
+            })
+            .set_span(arg_span)
+            .add_note("versions must be 1 to 3 numbers separated by '.', like \"2\", \"2.1\", or \"2.1.0\"", None)
+            .push_into(diagnostics);
+        }
+    }
+    version
+}
+
 /// Used to report an error when an attribute is applied to something it shouldn't be.
 /// This is only called by attributes in their `validate_on` functions.
 pub fn report_unexpected_attribute(
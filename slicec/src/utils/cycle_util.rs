@@ -0,0 +1,195 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains the logic for finding illegal cycles between types.
+//!
+//! A cycle is illegal if a type (transitively) contains itself by value, since it would have an infinite size.
+//! Only structs and enums (with fields) can cause cycles: classes use reference semantics, so they break cycles,
+//! exceptions can't be used as types, and type-alias cycles are caught while patching type references.
+//!
+//! The compiler reports an error for each cycle it finds, but tools can also find cycles themselves with [find_cycles].
+
+use crate::ast::node::Node;
+use crate::ast::Ast;
+use crate::grammar::*;
+use std::collections::{BTreeSet, HashSet};
+
+/// An illegal cycle, where a type contains itself by value, either directly or through other types.
+#[derive(Debug)]
+pub struct Cycle<'a> {
+    /// The type where the cycle starts (and ends).
+    pub origin: &'a dyn Entity,
+
+    /// The links of the cycle, in order. The last link leads back to the origin.
+    pub links: Vec<CycleLink<'a>>,
+}
+
+/// A single link in a [Cycle]: a field, whose type leads to the next type in the cycle.
+#[derive(Debug)]
+pub struct CycleLink<'a> {
+    /// The module scoped identifier of the type that this link leads to.
+    pub type_id: String,
+
+    /// The field which makes up this link.
+    pub field: &'a Field,
+}
+
+impl Cycle<'_> {
+    /// Returns the module scoped identifier of the type where this cycle starts (and ends).
+    pub fn type_id(&self) -> String {
+        self.origin.module_scoped_identifier()
+    }
+
+    /// Returns a string showing the path of this cycle, of the form "A -> B -> C -> A".
+    pub fn path(&self) -> String {
+        let mut path = self.type_id();
+        for link in &self.links {
+            path = path + " -> " + &link.type_id;
+        }
+        path
+    }
+}
+
+/// Returns every illegal cycle between the types in the provided AST.
+///
+/// For cycles made up of multiple types, only one cycle is returned, starting from whichever of its types is defined
+/// first, instead of returning the same cycle starting from each of them.
+pub fn find_cycles(ast: &Ast) -> Vec<Cycle<'_>> {
+    let mut cycle_detector = CycleDetector {
+        type_being_checked: None,
+        dependency_stack: Vec::new(),
+        reported_cycles: HashSet::new(),
+        cycles: Vec::new(),
+    };
+
+    for node in ast.as_slice() {
+        let (candidate, entity): (&dyn CycleCandidate, &dyn Entity) = match node {
+            Node::Struct(struct_def) => (struct_def.borrow(), struct_def.borrow()),
+            Node::Enum(enum_def) => (enum_def.borrow(), enum_def.borrow()),
+            _ => continue,
+        };
+
+        debug_assert!(cycle_detector.dependency_stack.is_empty());
+        cycle_detector.type_being_checked = Some((candidate.module_scoped_identifier(), entity));
+        candidate.check_for_cycles(&mut cycle_detector)
+    }
+    cycle_detector.cycles
+}
+
+/// This trait is implemented on a type if and only if it is possible for that type to cause a cycle.
+/// It contains a single method, used to check the type for cycles with the help of a [`CycleDetector`].
+trait CycleCandidate<'a>: Type + NamedSymbol {
+    fn check_for_cycles(&'a self, cycle_detector: &mut CycleDetector<'a>);
+}
+
+impl<'a> CycleCandidate<'a> for Struct {
+    /// Checks this struct's fields for cycles.
+    fn check_for_cycles(&'a self, cycle_detector: &mut CycleDetector<'a>) {
+        cycle_detector.check_fields_for_cycles(self);
+    }
+}
+
+impl<'a> CycleCandidate<'a> for Enum {
+    /// Iterates through the enumerators of this enum, and checks any fields for cycles.
+    fn check_for_cycles(&'a self, cycle_detector: &mut CycleDetector<'a>) {
+        for enumerator in self.enumerators() {
+            cycle_detector.check_fields_for_cycles(enumerator);
+        }
+    }
+}
+
+struct CycleDetector<'a> {
+    /// Stores a tuple of `(type_id, reference)` for the type currently being checked for cycles.
+    type_being_checked: Option<(String, &'a dyn Entity)>,
+
+    /// A stack containing all the fields we've seen in the dependency tree we're currently traversing through.
+    /// Each stack element is made up of the type-id of the field's type, and a reference to the field itself.
+    dependency_stack: Vec<(String, &'a Field)>,
+
+    /// Stores all the cycles we've found so far, so we can avoid returning duplicates.
+    reported_cycles: HashSet<BTreeSet<String>>,
+
+    /// The cycles that have been found.
+    cycles: Vec<Cycle<'a>>,
+}
+
+impl<'a> CycleDetector<'a> {
+    fn check_fields_for_cycles(&mut self, container: &'a dyn Container<Field>) {
+        for field in container.contents() {
+            self.check_field_type_for_cycles(field.data_type(), field);
+        }
+    }
+
+    fn check_field_type_for_cycles(&mut self, type_ref: &'a TypeRef, origin: &'a Field) {
+        match type_ref.concrete_type() {
+            // For struct or enum types, we push them onto the stack, and attempt to recursively check them.
+            Types::Struct(struct_ref) => self.push_to_stack_and_check(struct_ref, origin),
+            Types::Enum(enum_ref) => self.push_to_stack_and_check(enum_ref, origin),
+
+            Types::ResultType(result_type) => {
+                self.check_field_type_for_cycles(&result_type.success_type, origin);
+                self.check_field_type_for_cycles(&result_type.failure_type, origin);
+            }
+
+            Types::Sequence(sequence) => self.check_field_type_for_cycles(&sequence.element_type, origin),
+            Types::Dictionary(dictionary) => {
+                self.check_field_type_for_cycles(&dictionary.key_type, origin);
+                self.check_field_type_for_cycles(&dictionary.value_type, origin);
+            }
+
+            // Classes always break cycles since they use reference semantics.
+            Types::Class(_) => {}
+
+            // Primitive and custom types are terminal since they can't reference any other types.
+            Types::Primitive(_) | Types::CustomType(_) => {}
+        }
+    }
+
+    fn push_to_stack_and_check(&mut self, candidate: &'a dyn CycleCandidate<'a>, origin: &'a Field) {
+        let candidate_type_string = candidate.module_scoped_identifier();
+
+        // If the candidate's type is the type we're checking, then its definition is cyclic and we record it.
+        if self.type_being_checked.as_ref().unwrap().0 == candidate_type_string {
+            // We still push the offending field onto the stack, since it's the cycle's last link.
+            self.dependency_stack.push((candidate_type_string, origin));
+            self.record_cycle();
+            self.dependency_stack.pop();
+            return;
+        }
+
+        // If the candidate is in the dependency stack, but isn't the type we're checking, skip it.
+        // There is a cycle present (and so we have to return to avoid infinite recursion), but the
+        // candidate isn't the cause of the cycle, just a link or offshoot of it.
+        for (seen_type_id, _) in &self.dependency_stack {
+            if seen_type_id == &candidate_type_string {
+                return;
+            }
+        }
+
+        // If we haven't detected any cycles yet, it's safe to continue recursing.
+        // Push the current field and its type onto the stack, then check the candidate's fields.
+        self.dependency_stack.push((candidate_type_string, origin));
+        candidate.check_for_cycles(self);
+        self.dependency_stack.pop();
+    }
+
+    fn record_cycle(&mut self) {
+        // If we've already found this cycle, do not record it again. For cycles consisting of N different types,
+        // the cycle checker will detect N cycles, one for each type. But, logically these are all the same cycle.
+        //
+        // To prevent duplicates, we take a set of the cycle elements, and check whether we've already recorded it.
+        // Graph Theory tells us that directed cycles are NOT uniquely identified by their vertex sets, but good enough.
+        let cycle_set: BTreeSet<String> = self.dependency_stack.iter().map(|(id, _)| id.clone()).collect();
+        if !self.reported_cycles.insert(cycle_set) {
+            return;
+        }
+
+        let links = self.dependency_stack.iter().map(|(type_id, field)| CycleLink {
+            type_id: type_id.clone(),
+            field,
+        });
+        self.cycles.push(Cycle {
+            origin: self.type_being_checked.as_ref().unwrap().1,
+            links: links.collect(),
+        });
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module provides a way to sort definitions so that every definition comes after the definitions it depends on.
+//!
+//! Backends for languages which require types to be declared before they're used (like C++, or Python's dataclasses)
+//! can generate code in this order, instead of the order that definitions were declared in.
+
+use crate::ast::node::Node;
+use crate::ast::Ast;
+use crate::grammar::*;
+use std::collections::HashSet;
+
+/// Returns the structs, classes, exceptions, enums, custom types, type aliases, and interfaces in the provided AST,
+/// sorted so that each of them comes after the definitions it depends on. Otherwise, definitions are kept in the order
+/// they were declared in, so the result is deterministic.
+///
+/// A definition depends on the types of its fields, on its bases, and for type aliases, on their underlying type.
+/// Interfaces also depend on the types of their operations' parameters, return members, and thrown types.
+/// Sequences, dictionaries, and results are looked through, so a field of type `Sequence<S>` depends on `S`.
+///
+/// Cycles are only possible through classes, since classes use reference semantics (other cycles are rejected).
+/// When a cycle is found, it's broken at the reference which closed it, so a class can come before a type that uses
+/// it. Since classes are reference types, backends can forward declare them to handle this case.
+pub fn sort_by_dependencies(ast: &Ast) -> Vec<&dyn Entity> {
+    let mut sorter = DependencySorter {
+        visited: HashSet::new(),
+        sorted: Vec::new(),
+    };
+
+    for node in ast.as_slice() {
+        let entity: &dyn Entity = match node {
+            Node::Struct(struct_ptr) => struct_ptr.borrow(),
+            Node::Class(class_ptr) => class_ptr.borrow(),
+            Node::Exception(exception_ptr) => exception_ptr.borrow(),
+            Node::Enum(enum_ptr) => enum_ptr.borrow(),
+            Node::CustomType(custom_type_ptr) => custom_type_ptr.borrow(),
+            Node::TypeAlias(type_alias_ptr) => type_alias_ptr.borrow(),
+            Node::Interface(interface_ptr) => interface_ptr.borrow(),
+            _ => continue,
+        };
+        sorter.visit(entity);
+    }
+    sorter.sorted
+}
+
+struct DependencySorter<'a> {
+    /// The (parser scoped) identifiers of every definition that has been, or is being, sorted.
+    visited: HashSet<String>,
+
+    /// The definitions that have been sorted so far, in dependency order.
+    sorted: Vec<&'a dyn Entity>,
+}
+
+impl<'a> DependencySorter<'a> {
+    fn visit(&mut self, entity: &'a dyn Entity) {
+        // If we've already seen this definition, either it's already sorted, or we're in the middle of sorting it.
+        // In the second case, there's a cycle. We break it here by skipping the definition.
+        if !self.visited.insert(entity.parser_scoped_identifier()) {
+            return;
+        }
+
+        // Sort all of this definition's dependencies before the definition itself.
+        for dependency in direct_dependencies(entity) {
+            self.visit(dependency);
+        }
+        self.sorted.push(entity);
+    }
+}
+
+/// Returns the definitions that the provided entity directly depends on, in the order they're referenced.
+fn direct_dependencies(entity: &dyn Entity) -> Vec<&dyn Entity> {
+    let mut dependencies = Vec::new();
+
+    match entity.concrete_entity() {
+        Entities::Struct(struct_def) => add_fields(struct_def.fields(), &mut dependencies),
+        Entities::Class(class_def) => {
+            if let Some(base) = class_def.base_class() {
+                dependencies.push(base);
+            }
+            add_fields(class_def.fields(), &mut dependencies);
+        }
+        Entities::Exception(exception_def) => {
+            if let Some(base) = exception_def.base_exception() {
+                dependencies.push(base);
+            }
+            add_fields(exception_def.fields(), &mut dependencies);
+        }
+        Entities::Enum(enum_def) => {
+            for enumerator in enum_def.enumerators() {
+                add_fields(enumerator.fields(), &mut dependencies);
+            }
+        }
+        Entities::TypeAlias(type_alias) => add_type_ref_dependencies(&type_alias.underlying, &mut dependencies),
+        Entities::Interface(interface_def) => {
+            for base in interface_def.base_interfaces() {
+                dependencies.push(base);
+            }
+            for operation in interface_def.operations() {
+                for parameter in operation.parameters_and_return_members() {
+                    add_type_ref_dependencies(parameter.data_type(), &mut dependencies);
+                }
+                for thrown_type in operation.thrown_types() {
+                    match thrown_type {
+                        ThrownType::Exception(exception_ref) => dependencies.push(exception_ref.definition()),
+                        ThrownType::Error(type_ref) => add_type_ref_dependencies(type_ref, &mut dependencies),
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+    dependencies
+}
+
+fn add_fields<'a>(fields: Vec<&'a Field>, dependencies: &mut Vec<&'a dyn Entity>) {
+    for field in fields {
+        add_type_ref_dependencies(field.data_type(), dependencies);
+    }
+}
+
+fn add_type_ref_dependencies<'a>(type_ref: &'a TypeRef, dependencies: &mut Vec<&'a dyn Entity>) {
+    match type_ref.concrete_type() {
+        Types::Struct(struct_def) => dependencies.push(struct_def),
+        Types::Class(class_def) => dependencies.push(class_def),
+        Types::Enum(enum_def) => dependencies.push(enum_def),
+        Types::CustomType(custom_type) => dependencies.push(custom_type),
+        Types::ResultType(result_type) => {
+            add_type_ref_dependencies(&result_type.success_type, dependencies);
+            add_type_ref_dependencies(&result_type.failure_type, dependencies);
+        }
+        Types::Sequence(sequence) => add_type_ref_dependencies(&sequence.element_type, dependencies),
+        Types::Dictionary(dictionary) => {
+            add_type_ref_dependencies(&dictionary.key_type, dependencies);
+            add_type_ref_dependencies(&dictionary.value_type, dependencies);
+        }
+        Types::Primitive(_) => {}
+    }
+}
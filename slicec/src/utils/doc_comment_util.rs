@@ -0,0 +1,167 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module provides a configurable layer for transforming doc comment text into a backend's target doc format.
+//!
+//! Doc comments can contain characters and markup (like HTML tags) that must be escaped, translated, or removed
+//! before they can be emitted into generated code. Backends describe how to do this with a [DocTextPolicy], so that
+//! every backend handles doc comment text consistently.
+
+use crate::diagnostics::{Diagnostic, Diagnostics, Lint};
+use crate::grammar::{LinkTag, Message, MessageComponent};
+use crate::slice_file::Span;
+use std::collections::BTreeMap;
+
+/// Describes how doc comment text should be transformed for a target doc format.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DocTextPolicy {
+    /// If true, the XML special characters (`&`, `<`, `>`, `"`, and `'`) are escaped as entities.
+    /// Only text is escaped; HTML tags which are kept or translated are emitted as-is.
+    pub escape_xml_entities: bool,
+
+    /// Specifies how HTML tags (like `<b>` or `</p>`) in doc comments are handled.
+    pub html_tags: HtmlTagPolicy,
+
+    /// If true, each run of whitespace (including newlines) is collapsed into a single space,
+    /// and any leading or trailing whitespace is removed.
+    pub normalize_whitespace: bool,
+}
+
+/// Specifies how HTML tags in doc comments are handled. See [DocTextPolicy::html_tags].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum HtmlTagPolicy {
+    /// Tags are emitted unchanged.
+    #[default]
+    Keep,
+
+    /// Tags are removed, but any text between an opening and closing tag is kept.
+    Strip,
+
+    /// Tags are renamed according to the provided map, keeping any attributes. Ex: `"b" => "strong"`.
+    /// Tag names are matched case-insensitively, so the keys of this map should be lowercase.
+    /// Tags which aren't in the map can't be represented in the target format, so they're removed and reported
+    /// with the [UnsupportedDocMarkup](Lint::UnsupportedDocMarkup) lint.
+    Translate(BTreeMap<String, String>),
+}
+
+impl DocTextPolicy {
+    /// Transforms the provided doc comment text according to this policy.
+    ///
+    /// Any markup that can't be represented in the target format is reported with the `UnsupportedDocMarkup` lint,
+    /// using the provided `span` and `scope` (the scoped identifier of the element the doc comment is applied to).
+    pub fn transform(&self, text: &str, span: &Span, scope: &str, diagnostics: &mut Diagnostics) -> String {
+        let text = match self.normalize_whitespace {
+            true => text.split_whitespace().collect::<Vec<_>>().join(" "),
+            false => text.to_owned(),
+        };
+
+        let mut result = String::with_capacity(text.len());
+        let mut unsupported_tags: Vec<&str> = Vec::new();
+        let mut remaining = text.as_str();
+        while !remaining.is_empty() {
+            if let Some((tag, tag_name, name_start)) = parse_html_tag(remaining) {
+                let is_supported = self.transform_html_tag(tag, tag_name, name_start, &mut result);
+                if !is_supported && !unsupported_tags.contains(&tag_name) {
+                    unsupported_tags.push(tag_name);
+                }
+                remaining = &remaining[tag.len()..];
+            } else {
+                let c = remaining.chars().next().unwrap();
+                self.push_char(c, &mut result);
+                remaining = &remaining[c.len_utf8()..];
+            }
+        }
+
+        // Report all the unsupported tags with a single diagnostic, since they all share the same span.
+        if !unsupported_tags.is_empty() {
+            let markup = unsupported_tags.iter().map(|tag| format!("'<{tag}>'"));
+            Diagnostic::new(Lint::UnsupportedDocMarkup {
+                markup: markup.collect::<Vec<_>>().join(", "),
+            })
+            .set_span(span)
+            .set_scope(scope)
+            .push_into(diagnostics);
+        }
+        result
+    }
+
+    /// Transforms the text of a doc comment message according to this policy. Links are converted to text by the
+    /// provided `format_link` function, and are emitted as-is, without any transformations.
+    pub fn transform_message(
+        &self,
+        message: &Message,
+        scope: &str,
+        mut format_link: impl FnMut(&LinkTag) -> String,
+        diagnostics: &mut Diagnostics,
+    ) -> String {
+        let mut result = String::new();
+        for component in &message.value {
+            match component {
+                MessageComponent::Text(text) => {
+                    result.push_str(&self.transform(text, &message.span, scope, diagnostics));
+                }
+                MessageComponent::Link(link_tag) => result.push_str(&format_link(link_tag)),
+            }
+        }
+        result
+    }
+
+    /// Transforms an HTML tag, and writes the result into `result`.
+    /// Returns false if the tag can't be represented in the target format, and true otherwise.
+    fn transform_html_tag(&self, tag: &str, tag_name: &str, name_start: usize, result: &mut String) -> bool {
+        match &self.html_tags {
+            HtmlTagPolicy::Keep => result.push_str(tag),
+            HtmlTagPolicy::Strip => {}
+            HtmlTagPolicy::Translate(translations) => match translations.get(&tag_name.to_lowercase()) {
+                Some(translation) => {
+                    result.push_str(&tag[..name_start]);
+                    result.push_str(translation);
+                    result.push_str(&tag[name_start + tag_name.len()..]);
+                }
+                None => return false,
+            },
+        }
+        true
+    }
+
+    fn push_char(&self, c: char, result: &mut String) {
+        match (self.escape_xml_entities, c) {
+            (true, '&') => result.push_str("&amp;"),
+            (true, '<') => result.push_str("&lt;"),
+            (true, '>') => result.push_str("&gt;"),
+            (true, '"') => result.push_str("&quot;"),
+            (true, '\'') => result.push_str("&apos;"),
+            _ => result.push(c),
+        }
+    }
+}
+
+/// Checks if the provided text starts with an HTML tag. Tags start with '<' (or '</'), followed by a name made of
+/// ASCII letters, digits, and hyphens (starting with a letter), optionally followed by attributes, and end with '>'.
+///
+/// If it does, this returns the entire tag, the tag's name, and the offset of the name within the tag.
+fn parse_html_tag(text: &str) -> Option<(&str, &str, usize)> {
+    let rest = text.strip_prefix('<')?;
+    let name_start = if rest.starts_with('/') { 2 } else { 1 };
+
+    let name_length = text[name_start..]
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .unwrap_or(text.len() - name_start);
+    let tag_name = &text[name_start..name_start + name_length];
+    if !tag_name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    // The name must be followed by the end of the tag, or whitespace (separating it from attributes), or '/'.
+    let after_name = &text[name_start + name_length..];
+    if !after_name.starts_with(|c: char| c == '>' || c == '/' || c.is_whitespace()) {
+        return None;
+    }
+
+    // Tags can't contain nested '<' characters.
+    let end = after_name.find(['>', '<'])?;
+    if !after_name[end..].starts_with('>') {
+        return None;
+    }
+    let tag_length = name_start + name_length + end + 1;
+    Some((&text[..tag_length], tag_name, name_start))
+}
@@ -1,19 +1,24 @@
 // Copyright (c) ZeroC, Inc.
 
 use crate::diagnostics::{Diagnostic, Diagnostics, Error, Lint};
+use crate::logging;
+use crate::schema_package;
 use crate::slice_file::SliceFile;
 use crate::slice_options::SliceOptions;
+use crate::utils::ignore_util::IgnoreFile;
 use std::path::{Path, PathBuf};
 use std::{fs, io};
 
-/// A wrapper around a file path that implements Hash and Eq. This allows us to use a HashMap to store the path the user
-/// supplied while using the canonicalized path as the key.
+/// A wrapper around a file path that implements Eq. This allows us to detect duplicate files, even if the user
+/// supplied different paths for them.
 #[derive(Debug, Eq)]
 struct FilePath {
     // The path that the user supplied
     path: String,
     // The canonicalized path
     canonicalized_path: PathBuf,
+    // A value which identifies the file, regardless of how its path is spelled. See `file_identity`.
+    identity: FileIdentity,
     // True for source files, false for reference files.
     is_source: bool,
 }
@@ -21,9 +26,12 @@ struct FilePath {
 impl FilePath {
     /// Creates a new [FilePath] from the given path. If the path does not exist, an [Error] is returned.
     pub fn try_create(path: &str, is_source: bool) -> Result<Self, io::Error> {
-        PathBuf::from(path).canonicalize().map(|canonicalized_path| Self {
+        let canonicalized_path = PathBuf::from(path).canonicalize()?;
+        let identity = file_identity(&canonicalized_path)?;
+        Ok(Self {
             path: path.to_owned(),
             canonicalized_path,
+            identity,
             is_source,
         })
     }
@@ -31,10 +39,32 @@ impl FilePath {
 
 impl PartialEq for FilePath {
     fn eq(&self, other: &Self) -> bool {
-        self.canonicalized_path == other.canonicalized_path
+        self.identity == other.identity
     }
 }
 
+#[cfg(unix)]
+type FileIdentity = (u64, u64);
+
+#[cfg(not(unix))]
+type FileIdentity = String;
+
+/// Returns a value which identifies the file at the provided (canonicalized) path, regardless of how its path is
+/// spelled. On Unix, this is the file's device and inode numbers, which also identifies hard links to the same file,
+/// and paths which only differ by case on case-insensitive file systems (like the default on macOS). Elsewhere, file
+/// systems are assumed to be case-insensitive (like on Windows), so this is the lowercased path.
+#[cfg(unix)]
+fn file_identity(canonicalized_path: &Path) -> io::Result<FileIdentity> {
+    use std::os::unix::fs::MetadataExt;
+    let metadata = fs::metadata(canonicalized_path)?;
+    Ok((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_identity(canonicalized_path: &Path) -> io::Result<FileIdentity> {
+    Ok(canonicalized_path.to_string_lossy().to_lowercase())
+}
+
 /// This function takes a `Vec<FilePath>` and returns it, after removing any duplicate elements.
 /// A lint violation is reported for each duplicate element.
 fn remove_duplicate_file_paths(file_paths: Vec<FilePath>, diagnostics: &mut Diagnostics) -> Vec<FilePath> {
@@ -50,17 +80,32 @@ fn remove_duplicate_file_paths(file_paths: Vec<FilePath>, diagnostics: &mut Diag
     deduped_file_paths
 }
 
+/// The source path which means "read a Slice file from stdin".
+pub const STDIN_PATH: &str = "-";
+
+/// The name that the Slice file read from stdin is reported under, unless `--stdin-filename` is set.
+pub const DEFAULT_STDIN_FILENAME: &str = "<stdin>";
+
 pub fn resolve_files_from(options: &SliceOptions, diagnostics: &mut Diagnostics) -> Vec<SliceFile> {
     let mut file_paths = Vec::new();
 
     // Add any source files to the list of file paths, after removing duplicates.
-    let source_files = find_slice_files(&options.sources, true, diagnostics);
+    // Stdin isn't a file on disk, so it's read separately, after all the other files.
+    let (stdin_paths, source_paths): (Vec<_>, Vec<_>) =
+        options.sources.iter().cloned().partition(|path| path == STDIN_PATH);
+    let source_files = find_slice_files(&source_paths, true, options, diagnostics);
     file_paths.extend(remove_duplicate_file_paths(source_files, diagnostics));
 
     // Add any reference files to the list of file paths, after removing duplicates. We omit reference files that have
     // already been included as source files; we don't emit a warning for them, we just silently omit them. It's
     // important to do this after the source files, to ensure source files are given 'priority' over reference files.
-    let reference_files = find_slice_files(&options.references, false, diagnostics);
+    // Schema packages are loaded separately, after all the other files have been read.
+    let (package_paths, reference_paths): (Vec<_>, Vec<_>) = options
+        .references
+        .iter()
+        .cloned()
+        .partition(|path| schema_package::is_schema_package(path));
+    let reference_files = find_slice_files(&reference_paths, false, options, diagnostics);
     for reference_file in remove_duplicate_file_paths(reference_files, diagnostics) {
         if !file_paths.contains(&reference_file) {
             file_paths.push(reference_file);
@@ -69,9 +114,22 @@ pub fn resolve_files_from(options: &SliceOptions, diagnostics: &mut Diagnostics)
 
     // Iterate through the discovered files and try to read them into Strings.
     // Report an error if it fails, otherwise create a new `SliceFile` to hold the data.
+    // If the file's contents were provided in memory, those contents are used instead.
+    let mut in_memory_files = options.in_memory_files.iter().collect::<Vec<_>>();
     let mut files = Vec::new();
     for file_path in file_paths {
-        match fs::read_to_string(&file_path.path) {
+        let in_memory_index = in_memory_files.iter().position(|in_memory_file| {
+            FilePath::try_create(&in_memory_file.path, file_path.is_source).is_ok_and(|path| path == file_path)
+        });
+        if let Some(index) = in_memory_index {
+            let raw_text = in_memory_files.remove(index).contents.clone();
+            files.push(SliceFile::new(file_path.path, raw_text, file_path.is_source));
+            continue;
+        }
+
+        // We read from the canonicalized path, since on Windows, it's a verbatim path (`\\?\...`), which can be longer
+        // than the usual path length limit. But files are always reported under the path that the user supplied.
+        match fs::read_to_string(&file_path.canonicalized_path) {
             Ok(raw_text) => files.push(SliceFile::new(file_path.path, raw_text, file_path.is_source)),
             Err(error) => Diagnostic::new(Error::IO {
                 action: "read",
@@ -81,10 +139,57 @@ pub fn resolve_files_from(options: &SliceOptions, diagnostics: &mut Diagnostics)
             .push_into(diagnostics),
         }
     }
+
+    // Read the Slice file from stdin if it was passed as a source. Stdin can only be read once, so it's fine to pass it
+    // multiple times, but only one file is read from it.
+    if !stdin_paths.is_empty() {
+        let filename = options.stdin_filename.as_deref().unwrap_or(DEFAULT_STDIN_FILENAME);
+        match io::read_to_string(io::stdin()) {
+            Ok(raw_text) => files.push(SliceFile::new(filename.to_owned(), raw_text, true)),
+            Err(error) => Diagnostic::new(Error::IO {
+                action: "read",
+                path: filename.to_owned(),
+                error,
+            })
+            .push_into(diagnostics),
+        }
+    }
+
+    // Add any in-memory files that didn't replace the contents of a file on disk.
+    for in_memory_file in in_memory_files {
+        let path = in_memory_file.path.clone();
+        files.push(SliceFile::new(path, in_memory_file.contents.clone(), in_memory_file.is_source));
+    }
+
+    // Load the files contained in any schema packages that were passed as references.
+    for package_path in package_paths {
+        files.extend(schema_package::load_schema_package(&package_path, options, diagnostics));
+    }
+
+    for file in &files {
+        let (path, is_source, bytes) = (&file.relative_path, file.is_source, file.raw_text.len());
+        log::debug!(target: logging::FILES, "resolved '{path}': is_source={is_source} bytes={bytes}");
+    }
     files
 }
 
-fn find_slice_files(paths: &[String], are_source_files: bool, diagnostics: &mut Diagnostics) -> Vec<FilePath> {
+/// The state of a search for Slice files through a tree of directories.
+#[derive(Debug, Default)]
+struct DirectorySearch {
+    /// The canonicalized paths of the directories that are currently being searched.
+    ancestors: Vec<PathBuf>,
+    /// The ignore files of the directories that are currently being searched.
+    ignore_files: Vec<IgnoreFile>,
+    /// Whether to report the paths that are skipped because of ignore files.
+    report_ignored: bool,
+}
+
+fn find_slice_files(
+    paths: &[String],
+    are_source_files: bool,
+    options: &SliceOptions,
+    diagnostics: &mut Diagnostics,
+) -> Vec<FilePath> {
     // Directories can only be passed as references.
     let allow_directories = !are_source_files;
 
@@ -131,7 +236,11 @@ fn find_slice_files(paths: &[String], are_source_files: bool, diagnostics: &mut
             continue;
         }
 
-        slice_paths.extend(find_slice_files_in_path(path_buf, diagnostics));
+        let mut search = DirectorySearch {
+            report_ignored: options.verbose,
+            ..DirectorySearch::default()
+        };
+        slice_paths.extend(find_slice_files_in_path(path_buf, &mut search, diagnostics));
     }
 
     slice_paths
@@ -152,11 +261,37 @@ fn find_slice_files(paths: &[String], are_source_files: bool, diagnostics: &mut
         .collect()
 }
 
-fn find_slice_files_in_path(path: PathBuf, diagnostics: &mut Diagnostics) -> Vec<PathBuf> {
+/// Returns the paths of all the Slice files in the provided path, recursing into any directories.
+///
+/// If a directory is its own ancestor (because of a symbolic link), it's skipped with a warning, since recursing into
+/// it would never end.
+fn find_slice_files_in_path(
+    path: PathBuf,
+    search: &mut DirectorySearch,
+    diagnostics: &mut Diagnostics,
+) -> Vec<PathBuf> {
     let mut paths = Vec::new();
     if path.is_dir() {
-        // Recurse into the directory.
-        match find_slice_files_in_directory(&path, diagnostics) {
+        let result = path.canonicalize().and_then(|canonicalized_path| {
+            if search.ancestors.contains(&canonicalized_path) {
+                let path = path.display().to_string();
+                Diagnostic::new(Lint::SymlinkCycle { path }).push_into(diagnostics);
+                return Ok(Vec::new());
+            }
+
+            // Recurse into the directory, applying its ignore file (if it has one) to everything inside it.
+            let ignore_file = IgnoreFile::load(&path)?;
+            let has_ignore_file = ignore_file.is_some();
+            search.ignore_files.extend(ignore_file);
+            search.ancestors.push(canonicalized_path);
+            let child_paths = find_slice_files_in_directory(&path, search, diagnostics);
+            search.ancestors.pop();
+            if has_ignore_file {
+                search.ignore_files.pop();
+            }
+            child_paths
+        });
+        match result {
             Ok(child_paths) => paths.extend(child_paths),
             Err(error) => Diagnostic::new(Error::IO {
                 action: "read",
@@ -174,14 +309,20 @@ fn find_slice_files_in_path(path: PathBuf, diagnostics: &mut Diagnostics) -> Vec
     paths
 }
 
-fn find_slice_files_in_directory(path: &Path, diagnostics: &mut Diagnostics) -> io::Result<Vec<PathBuf>> {
-    let mut paths = Vec::new();
+fn find_slice_files_in_directory(
+    path: &Path,
+    search: &mut DirectorySearch,
+    diagnostics: &mut Diagnostics,
+) -> io::Result<Vec<PathBuf>> {
+    let mut child_paths = Vec::new();
     let dir = path.read_dir()?;
 
-    // Iterate though the directory and recurse into any subdirectories.
+    // Collect the directory's entries, so we can sort them. The order that entries are read in depends on the file
+    // system, so without sorting them, the order that files are compiled in (and diagnostics are reported in) could
+    // change between machines, or even between runs.
     for child in dir {
         match child {
-            Ok(child) => paths.extend(find_slice_files_in_path(child.path(), diagnostics)),
+            Ok(child) => child_paths.push(child.path()),
             Err(error) => {
                 // If we cannot read the directory entry, report an error and continue.
                 Diagnostic::new(Error::IO {
@@ -194,6 +335,28 @@ fn find_slice_files_in_directory(path: &Path, diagnostics: &mut Diagnostics) ->
             }
         }
     }
+    child_paths.sort();
+
+    // Iterate though the directory's entries and recurse into any subdirectories. Any ignored entries are skipped.
+    let mut paths = Vec::new();
+    for child_path in child_paths {
+        let is_dir = child_path.is_dir();
+        if let Some(ignore_file) = search.ignore_files.iter().find(|file| file.is_ignored(&child_path, is_dir)) {
+            let (path, ignore_file_path) = (child_path.display(), ignore_file.path.display());
+            log::debug!(target: logging::FILES, "skipped '{path}': ignored_by='{ignore_file_path}'");
+
+            // Only report ignored paths that we'd otherwise have searched.
+            if search.report_ignored && (is_dir || is_slice_file(&child_path)) {
+                Diagnostic::new(Lint::IgnoredPath {
+                    path: child_path.display().to_string(),
+                    ignore_file: ignore_file.path.display().to_string(),
+                })
+                .push_into(diagnostics);
+            }
+            continue;
+        }
+        paths.extend(find_slice_files_in_path(child_path, search, diagnostics));
+    }
     Ok(paths)
 }
 
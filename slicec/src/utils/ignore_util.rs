@@ -0,0 +1,148 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module implements ignore files (`.sliceignore`), which exclude paths from the directories that are searched for
+//! Slice files, like build output folders, or vendored examples.
+//!
+//! Ignore files contain one glob pattern per line. Blank lines, and lines starting with '#', are skipped. Each pattern
+//! applies to the directory containing the ignore file, and all of its subdirectories:
+//! - A pattern without a '/' matches the name of any file or directory. Ex: `*.generated.slice`.
+//! - A pattern containing a '/' matches paths relative to the ignore file's directory. Ex: `examples/legacy`.
+//!   A leading '/' can be used to anchor a pattern without any other slashes. Ex: `/build`.
+//! - A pattern ending with a '/' only matches directories. Ex: `bin/`.
+//!
+//! Within a pattern, `*` matches any number of characters (except '/'), `?` matches any single character (except '/'),
+//! and a `**` component matches any number of directories. Ex: `docs/**/draft.slice`.
+//!
+//! When a directory is ignored, none of its contents are searched.
+
+use std::path::{Component, Path, PathBuf};
+use std::{fs, io};
+
+/// The name of the file which holds ignore patterns.
+pub const IGNORE_FILE_NAME: &str = ".sliceignore";
+
+/// The patterns of an ignore file, along with the directory they apply to.
+#[derive(Clone, Debug)]
+pub struct IgnoreFile {
+    /// The path of the ignore file.
+    pub path: PathBuf,
+    /// The patterns in the ignore file.
+    patterns: Vec<IgnorePattern>,
+}
+
+#[derive(Clone, Debug)]
+struct IgnorePattern {
+    /// The components of the pattern, split on '/'.
+    components: Vec<String>,
+    /// True if the pattern is matched against relative paths, instead of just file names.
+    is_anchored: bool,
+    /// True if the pattern can only match directories.
+    is_directory_only: bool,
+}
+
+impl IgnoreFile {
+    /// Loads the ignore file in the provided directory. Returns `None` if the directory doesn't have one.
+    pub fn load(directory: &Path) -> io::Result<Option<Self>> {
+        let path = directory.join(IGNORE_FILE_NAME);
+        match fs::read_to_string(&path) {
+            Ok(contents) => Ok(Some(Self::parse(path, &contents))),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Parses the contents of an ignore file, which is located at the provided path.
+    pub fn parse(path: PathBuf, contents: &str) -> Self {
+        let patterns = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let is_directory_only = line.ends_with('/');
+                let line = line.trim_end_matches('/');
+                let is_anchored = line.contains('/');
+                let components = line
+                    .split('/')
+                    .filter(|component| !component.is_empty())
+                    .map(str::to_owned)
+                    .collect();
+                IgnorePattern {
+                    components,
+                    is_anchored,
+                    is_directory_only,
+                }
+            })
+            .filter(|pattern| !pattern.components.is_empty())
+            .collect();
+        IgnoreFile { path, patterns }
+    }
+
+    /// Returns true if the provided path is ignored by this file. Paths outside the ignore file's directory never are.
+    pub fn is_ignored(&self, path: &Path, is_directory: bool) -> bool {
+        let Some(relative_path) = self.path.parent().and_then(|directory| path.strip_prefix(directory).ok()) else {
+            return false;
+        };
+        let components = relative_path
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => name.to_str(),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let Some(name) = components.last() else {
+            return false;
+        };
+
+        self.patterns.iter().any(|pattern| {
+            let is_match = match pattern.is_anchored {
+                true => matches_components(&pattern.components, &components),
+                false => matches_name(&pattern.components[0], name),
+            };
+            is_match && (is_directory || !pattern.is_directory_only)
+        })
+    }
+}
+
+/// Returns true if the path components match the pattern components, where a `**` component matches any number of
+/// path components.
+fn matches_components(pattern: &[String], path: &[&str]) -> bool {
+    match (pattern.first(), path.first()) {
+        (Some(component), _) if component == "**" => {
+            matches_components(&pattern[1..], path) || (!path.is_empty() && matches_components(pattern, &path[1..]))
+        }
+        (Some(component), Some(name)) => matches_name(component, name) && matches_components(&pattern[1..], &path[1..]),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Returns true if the name matches the pattern, where `*` matches any number of characters, and `?` matches one.
+fn matches_name(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+
+    // Match greedily, and when a mismatch is found, backtrack to the most recent '*' and let it consume one more char.
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, consumed)) => {
+                    backtrack = Some((star, consumed + 1));
+                    p = star + 1;
+                    n = consumed + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
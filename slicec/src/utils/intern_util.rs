@@ -0,0 +1,174 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module provides string interning, which lets strings that appear many times in an AST (like identifiers and
+//! scopes) share a single allocation.
+//!
+//! Interned strings are reference counted, and are freed once the last handle to them is dropped. Each thread has its
+//! own table of interned strings, which only holds weak references to them, so the table never keeps a string alive,
+//! and threads never contend over it. Since parsing happens on a single thread, the identifiers and scopes of a
+//! compilation are all interned into the same table.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::{Arc, Weak};
+
+/// A handle to an interned string. Any two interned strings with the same contents that were interned on the same
+/// thread share the same allocation, so cloning and comparing them is as cheap as cloning and comparing pointers.
+///
+/// Interned strings dereference to `&str`, so they can be used anywhere a string slice can.
+#[derive(Clone)]
+pub struct InternedString(Arc<str>);
+
+impl InternedString {
+    /// Returns an interned copy of the provided string. If an equal string has already been interned on this thread
+    /// (and is still alive), this returns a handle to it, otherwise the string is copied into a new allocation.
+    pub fn new(s: &str) -> Self {
+        thread_local! {
+            static TABLE: RefCell<InternTable> = RefCell::default();
+        }
+        TABLE.with(|table| table.borrow_mut().intern(s))
+    }
+
+    /// Returns the string this handle refers to.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A table of interned strings, which only holds weak references to them.
+#[derive(Default)]
+struct InternTable {
+    strings: HashMap<Box<str>, Weak<str>>,
+
+    /// The number of strings the table held after it was last pruned. Once it's held twice as many, it's pruned again,
+    /// so the cost of removing dropped strings is spread over the strings which were interned in the meantime.
+    pruned_len: usize,
+}
+
+impl InternTable {
+    fn intern(&mut self, s: &str) -> InternedString {
+        if let Some(interned) = self.strings.get(s).and_then(Weak::upgrade) {
+            return InternedString(interned);
+        }
+
+        if self.strings.len() >= (self.pruned_len * 2).max(64) {
+            self.strings.retain(|_, weak| weak.strong_count() > 0);
+            self.pruned_len = self.strings.len();
+        }
+
+        let interned: Arc<str> = Arc::from(s);
+        self.strings.insert(s.into(), Arc::downgrade(&interned));
+        InternedString(interned)
+    }
+}
+
+impl Default for InternedString {
+    fn default() -> Self {
+        InternedString::new("")
+    }
+}
+
+impl Deref for InternedString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for InternedString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for InternedString {
+    fn from(s: &str) -> Self {
+        InternedString::new(s)
+    }
+}
+
+impl From<String> for InternedString {
+    fn from(s: String) -> Self {
+        InternedString::new(&s)
+    }
+}
+
+impl From<InternedString> for String {
+    fn from(s: InternedString) -> Self {
+        s.0.to_string()
+    }
+}
+
+// Equal strings interned on the same thread share an allocation, so comparing their pointers is usually enough.
+// But strings interned on different threads don't, so we fall back to comparing (and hashing) their contents.
+impl PartialEq for InternedString {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0 == other.0
+    }
+}
+
+impl Eq for InternedString {}
+
+impl Hash for InternedString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl PartialEq<str> for InternedString {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for InternedString {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for InternedString {
+    fn eq(&self, other: &String) -> bool {
+        *self.0 == **other
+    }
+}
+
+impl PartialEq<InternedString> for str {
+    fn eq(&self, other: &InternedString) -> bool {
+        self == &*other.0
+    }
+}
+
+impl PartialEq<InternedString> for &str {
+    fn eq(&self, other: &InternedString) -> bool {
+        *self == &*other.0
+    }
+}
+
+impl PartialOrd for InternedString {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InternedString {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl fmt::Debug for InternedString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for InternedString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&*self.0, f)
+    }
+}
@@ -1,6 +1,12 @@
 // Copyright (c) ZeroC, Inc.
 
 pub mod attribute_parsing_util;
+pub mod cycle_util;
+pub mod dependency_order_util;
+pub mod doc_comment_util;
 pub mod file_util;
+pub mod ignore_util;
+pub mod intern_util;
 pub mod ptr_util;
 pub mod string_util;
+pub mod type_id_util;
@@ -1,6 +1,63 @@
 // Copyright (c) ZeroC, Inc.
 
+use clap::ValueEnum;
+use convert_case::{Case, Casing};
+use std::fmt;
+
 /// Returns the indefinite article for the given word.
 pub fn indefinite_article(s: &str) -> String {
     in_definite::get_a_or_an(s).to_lowercase()
 }
+
+/// A convention for how the words of an identifier are capitalized and separated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ValueEnum)]
+pub enum CaseStyle {
+    /// Words are capitalized, with no separators. Ex: `MyIdentifier`.
+    #[value(name = "PascalCase")]
+    PascalCase,
+
+    /// Words are capitalized except for the first, with no separators. Ex: `myIdentifier`.
+    #[value(name = "camelCase")]
+    CamelCase,
+
+    /// Words are lowercase, and separated by underscores. Ex: `my_identifier`.
+    #[value(name = "snake_case")]
+    SnakeCase,
+}
+
+impl CaseStyle {
+    /// Returns true if the provided identifier follows this style.
+    ///
+    /// This check is intentionally lenient about word boundaries, since they can't be inferred from an identifier.
+    /// For example, both `HttpServer` and `HTTPServer` are considered PascalCase.
+    pub fn matches(self, identifier: &str) -> bool {
+        let identifier = identifier.trim_start_matches('_');
+        let starts_with_uppercase = identifier.starts_with(|c: char| c.is_uppercase());
+        match self {
+            Self::PascalCase => starts_with_uppercase && !identifier.contains('_'),
+            Self::CamelCase => !starts_with_uppercase && !identifier.contains('_'),
+            Self::SnakeCase => !identifier.contains(|c: char| c.is_uppercase()),
+        }
+    }
+
+    /// Returns the provided identifier converted to this style. Ex: `my_identifier` => `MyIdentifier`.
+    pub fn convert(self, identifier: &str) -> String {
+        let case = match self {
+            Self::PascalCase => Case::Pascal,
+            Self::CamelCase => Case::Camel,
+            Self::SnakeCase => Case::Snake,
+        };
+        identifier.to_case(case)
+    }
+}
+
+impl fmt::Display for CaseStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::PascalCase => "PascalCase",
+            Self::CamelCase => "camelCase",
+            Self::SnakeCase => "snake_case",
+        };
+        f.write_str(name)
+    }
+}
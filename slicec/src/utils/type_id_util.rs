@@ -0,0 +1,85 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module provides a configurable strategy for computing the type IDs of Slice definitions.
+//!
+//! Type IDs identify classes, exceptions, and interfaces at runtime. By default, slicec uses the same type IDs as Ice
+//! (see [DefaultTypeIdStrategy]), but runtimes which embed slicec can provide their own [TypeIdStrategy] to customize
+//! them. Since type IDs must identify definitions unambiguously, custom strategies should be checked with
+//! [check_type_ids], which reports an error if any definitions are assigned the same type ID.
+
+use crate::ast::node::Node;
+use crate::ast::Ast;
+use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::grammar::{Class, Entity};
+use std::collections::HashMap;
+
+/// A strategy for computing the type IDs of Slice definitions.
+pub trait TypeIdStrategy {
+    /// Returns the type ID of the provided entity.
+    fn type_id(&self, entity: &dyn Entity) -> String;
+
+    /// Returns the compact type ID of the provided class, or `None` if it doesn't have one.
+    /// By default, this is the compact ID specified in the class's definition (if any).
+    fn compact_type_id(&self, class_def: &Class) -> Option<u32> {
+        class_def.compact_id.as_ref().map(|compact_id| compact_id.value)
+    }
+}
+
+/// The default type ID strategy, which is compatible with Ice.
+/// Type IDs are an entity's fully scoped identifier, with a leading "::". Ex: `::Foo::Bar::MyClass`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultTypeIdStrategy;
+
+impl TypeIdStrategy for DefaultTypeIdStrategy {
+    fn type_id(&self, entity: &dyn Entity) -> String {
+        format!("::{}", entity.parser_scoped_identifier())
+    }
+}
+
+/// Checks that the provided strategy assigns a unique type ID to every class, exception, and interface in the AST,
+/// and a unique compact type ID to every class that has one. An error is reported for each duplicate ID.
+pub fn check_type_ids(strategy: &dyn TypeIdStrategy, ast: &Ast, diagnostics: &mut Diagnostics) {
+    let mut type_ids: HashMap<String, &dyn Entity> = HashMap::new();
+    let mut compact_type_ids: HashMap<u32, &dyn Entity> = HashMap::new();
+
+    for node in ast.as_slice() {
+        let entity: &dyn Entity = match node {
+            Node::Class(class_ptr) => {
+                let class_def = class_ptr.borrow();
+                if let Some(compact_id) = strategy.compact_type_id(class_def) {
+                    match compact_type_ids.get(&compact_id) {
+                        Some(&other) => {
+                            let error = Error::DuplicateCompactTypeId { compact_id };
+                            report_duplicate(error, class_def, other, diagnostics);
+                        }
+                        None => {
+                            compact_type_ids.insert(compact_id, class_def);
+                        }
+                    }
+                }
+                class_def
+            }
+            Node::Exception(exception_ptr) => exception_ptr.borrow(),
+            Node::Interface(interface_ptr) => interface_ptr.borrow(),
+            _ => continue,
+        };
+
+        let type_id = strategy.type_id(entity);
+        match type_ids.get(&type_id) {
+            Some(&other) => report_duplicate(Error::DuplicateTypeId { type_id }, entity, other, diagnostics),
+            None => {
+                type_ids.insert(type_id, entity);
+            }
+        }
+    }
+}
+
+fn report_duplicate(error: Error, entity: &dyn Entity, other: &dyn Entity, diagnostics: &mut Diagnostics) {
+    Diagnostic::new(error)
+        .set_span(entity.span())
+        .add_note(
+            format!("'{}' has the same type ID", other.parser_scoped_identifier()),
+            Some(other.span()),
+        )
+        .push_into(diagnostics);
+}
@@ -1,13 +1,16 @@
 // Copyright (c) ZeroC, Inc.
 
 use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::grammar::attributes::{Removed, Since};
 use crate::grammar::*;
+use crate::versioning::Version;
 use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::HashMap;
 
 pub fn validate_attributes(attributable: &(impl Attributable + AsAttributables), diagnostics: &mut Diagnostics) {
     let attributes = attributable.attributes();
     validate_repeated_attributes(&attributes, diagnostics);
+    validate_version_range(&attributes, diagnostics);
     for attribute in attributes {
         let concrete_type = attributable.concrete_attributable();
         attribute.kind.validate_on(concrete_type, attribute.span(), diagnostics);
@@ -42,3 +45,26 @@ pub fn validate_repeated_attributes(attributes: &[&Attribute], diagnostics: &mut
         }
     }
 }
+
+/// Validates that if an element was marked with both `since` and `removed`, it was removed after it was added.
+fn validate_version_range(attributes: &[&Attribute], diagnostics: &mut Diagnostics) {
+    let since = attributes.iter().find(|attribute| attribute.downcast::<Since>().is_some());
+    let removed = attributes.iter().find(|attribute| attribute.downcast::<Removed>().is_some());
+    let (Some(since), Some(removed)) = (since, removed) else {
+        return;
+    };
+
+    let since_version = &since.downcast::<Since>().unwrap().version;
+    let removed_version = &removed.downcast::<Removed>().unwrap().version;
+    if let (Some(added_in), Some(removed_in)) = (Version::parse(since_version), Version::parse(removed_version)) {
+        if removed_in <= added_in {
+            Diagnostic::new(Error::InvalidVersionRange {
+                since: since_version.clone(),
+                removed: removed_version.clone(),
+            })
+            .set_span(removed.span())
+            .add_note("the element was added here", Some(since.span()))
+            .push_into(diagnostics);
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::diagnostics::{Diagnostic, Diagnostics, Lint};
 use crate::grammar::*;
-use crate::slice_file::Span;
+use crate::slice_file::{SliceFile, Span};
 
 pub fn validate_common_doc_comments(commentable: &dyn Commentable, diagnostics: &mut Diagnostics) {
     // Only run this validation if a doc comment is present.
@@ -11,6 +11,45 @@ pub fn validate_common_doc_comments(commentable: &dyn Commentable, diagnostics:
     only_operations_have_parameters(comment, commentable, diagnostics);
     only_operations_can_return(comment, commentable, diagnostics);
     only_operations_can_throw(comment, commentable, diagnostics);
+    only_operations_have_examples(comment, commentable, diagnostics);
+    only_types_with_bases_can_inherit_doc(comment, commentable, diagnostics);
+    locales_are_unique(comment, commentable, diagnostics);
+}
+
+pub fn validate_module_doc_comment(module_def: &Module, diagnostics: &mut Diagnostics) {
+    if let Some(comment) = module_def.comment() {
+        let scope = module_def.parser_scoped_identifier();
+        only_entities_have_tags(comment, "modules", &scope, diagnostics);
+    }
+}
+
+pub fn validate_file_doc_comment(slice_file: &SliceFile, diagnostics: &mut Diagnostics) {
+    if let Some(comment) = &slice_file.comment {
+        only_entities_have_tags(comment, "files", "", diagnostics);
+    }
+}
+
+/// Module and file comments can only have an overview, and 'see' and 'locale' tags. Any other tag is reported.
+fn only_entities_have_tags(comment: &DocComment, target: &str, scope: &str, diagnostics: &mut Diagnostics) {
+    let tags = (comment.params.iter().map(|tag| tag as &dyn Symbol))
+        .chain(comment.returns.iter().map(|tag| tag as &dyn Symbol))
+        .chain(comment.throws.iter().map(|tag| tag as &dyn Symbol))
+        .chain(comment.examples.iter().map(|tag| tag as &dyn Symbol))
+        .chain(comment.inherit_doc.iter().map(|tag| tag as &dyn Symbol));
+
+    for tag in tags {
+        // All tag kinds are of the form "<kind> tag", so it's safe to unwrap.
+        let tag_kind = tag.kind().split_once(' ').unwrap().0;
+        Diagnostic::new(Lint::IncorrectDocComment {
+            message: format!(
+                "comment has {a} '{tag_kind}' tag, but '{tag_kind}' tags cannot be used on {target}",
+                a = crate::utils::string_util::indefinite_article(tag_kind),
+            ),
+        })
+        .set_span(tag.span())
+        .set_scope(scope)
+        .push_into(diagnostics);
+    }
 }
 
 fn only_operations_have_parameters(comment: &DocComment, entity: &dyn Commentable, diagnostics: &mut Diagnostics) {
@@ -38,6 +77,60 @@ fn only_operations_can_throw(comment: &DocComment, entity: &dyn Commentable, dia
     }
 }
 
+fn only_operations_have_examples(comment: &DocComment, entity: &dyn Commentable, diagnostics: &mut Diagnostics) {
+    if !matches!(entity.concrete_entity(), Entities::Operation(_)) {
+        for example_tag in &comment.examples {
+            report_only_operation_error(example_tag, example_tag.message.span(), entity, diagnostics);
+        }
+    }
+}
+
+fn only_types_with_bases_can_inherit_doc(
+    comment: &DocComment,
+    entity: &dyn Commentable,
+    diagnostics: &mut Diagnostics,
+) {
+    let Some(inherit_doc_tag) = &comment.inherit_doc else { return };
+
+    let has_base = match entity.concrete_entity() {
+        Entities::Class(class_def) => class_def.base_class().is_some(),
+        Entities::Exception(exception_def) => exception_def.base_exception().is_some(),
+        Entities::Interface(interface_def) => !interface_def.base_interfaces().is_empty(),
+        _ => false,
+    };
+    if !has_base {
+        Diagnostic::new(Lint::IncorrectDocComment {
+            message: format!(
+                "comment has an 'inheritdoc' tag, but {kind} '{identifier}' has no base to inherit documentation from",
+                kind = entity.kind(),
+                identifier = entity.identifier(),
+            ),
+        })
+        .set_span(inherit_doc_tag.span())
+        .set_scope(entity.parser_scoped_identifier())
+        .push_into(diagnostics);
+    }
+}
+
+fn locales_are_unique(comment: &DocComment, entity: &dyn Commentable, diagnostics: &mut Diagnostics) {
+    let mut seen_locales: Vec<&LocaleTag> = Vec::new();
+    for locale_tag in &comment.locales {
+        let locale = &locale_tag.locale.value;
+        let original = seen_locales.iter().find(|tag| tag.locale.value.eq_ignore_ascii_case(locale));
+        if let Some(original) = original {
+            Diagnostic::new(Lint::IncorrectDocComment {
+                message: format!("comment has multiple 'locale' tags for '{}'", locale_tag.locale.value),
+            })
+            .set_span(locale_tag.span())
+            .set_scope(entity.parser_scoped_identifier())
+            .add_note("the locale was first documented here", Some(original.span()))
+            .push_into(diagnostics);
+        } else {
+            seen_locales.push(locale_tag);
+        }
+    }
+}
+
 /// Helper function that reports an error if an operation-only comment-tag was used on something other than a comment.
 fn report_only_operation_error(
     tag: &impl Symbol,
@@ -58,11 +151,15 @@ fn report_only_operation_error(
         "param" => "have parameters",
         "returns" => "return",
         "throws" => "throw",
+        "example" => "have examples",
         _ => unreachable!("'report_only_operation_error' was called with unsupported tag '{tag_kind}'"),
     };
 
     Diagnostic::new(Lint::IncorrectDocComment {
-        message: format!("comment has a '{tag_kind}' tag, but only operations can {action_phrase}"),
+        message: format!(
+            "comment has {a} '{tag_kind}' tag, but only operations can {action_phrase}",
+            a = crate::utils::string_util::indefinite_article(tag_kind),
+        ),
     })
     .set_span(&(tag.span() + message_span))
     .set_scope(entity.parser_scoped_identifier())
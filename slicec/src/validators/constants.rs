@@ -0,0 +1,72 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::grammar::*;
+
+pub fn validate_constant(constant: &Constant, diagnostics: &mut Diagnostics) {
+    match &constant.data_type {
+        Some(data_type) => constant_value_matches_type(constant, data_type, diagnostics),
+        None => untyped_constant_value_is_in_range(constant, diagnostics),
+    }
+}
+
+/// Validate that an untyped integer constant's value fits within either an `int64` or a `uint64`,
+/// since those are the widest types that constants can be encoded as.
+fn untyped_constant_value_is_in_range(constant: &Constant, diagnostics: &mut Diagnostics) {
+    if let ConstantValue::Integer(value) = constant.value() {
+        let (min, max) = (i128::from(i64::MIN), i128::from(u64::MAX));
+        if *value < min || *value > max {
+            Diagnostic::new(Error::ConstantValueOutOfBounds {
+                identifier: constant.identifier().to_owned(),
+                value: *value,
+                min,
+                max,
+            })
+            .set_span(constant.expression.span())
+            .push_into(diagnostics);
+        }
+    }
+}
+
+/// Validate that a constant's value is compatible with its declared type, and fits within that type's bounds.
+fn constant_value_matches_type(constant: &Constant, data_type: &TypeRef<Primitive>, diagnostics: &mut Diagnostics) {
+    let primitive = data_type.definition();
+
+    // Constants can only hold integers, booleans, and strings, and can never be optional.
+    let is_supported = primitive.is_integral() || matches!(primitive, Primitive::Bool | Primitive::String);
+    if !is_supported || data_type.is_optional {
+        Diagnostic::new(Error::ConstantTypeNotSupported {
+            identifier: constant.identifier().to_owned(),
+            kind: data_type.type_string(),
+        })
+        .set_span(data_type.span())
+        .push_into(diagnostics);
+        return;
+    }
+
+    match (primitive, constant.value()) {
+        (Primitive::Bool, ConstantValue::Bool(_)) | (Primitive::String, ConstantValue::String(_)) => {}
+        (_, ConstantValue::Integer(value)) if primitive.is_integral() => {
+            let (min, max) = primitive.numeric_bounds().unwrap();
+            if *value < min || *value > max {
+                Diagnostic::new(Error::ConstantValueOutOfBounds {
+                    identifier: constant.identifier().to_owned(),
+                    value: *value,
+                    min,
+                    max,
+                })
+                .set_span(constant.expression.span())
+                .push_into(diagnostics);
+            }
+        }
+        (_, value) => {
+            Diagnostic::new(Error::TypeMismatch {
+                expected: primitive.kind().to_owned(),
+                actual: value.kind().to_owned(),
+                is_concrete: true,
+            })
+            .set_span(constant.expression.span())
+            .push_into(diagnostics);
+        }
+    }
+}
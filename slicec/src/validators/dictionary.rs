@@ -1,6 +1,6 @@
 // Copyright (c) ZeroC, Inc.
 
-use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::diagnostics::{Diagnostic, Diagnostics, Error, Note};
 use crate::grammar::*;
 
 pub fn validate_dictionary(dictionary: &Dictionary, diagnostics: &mut Diagnostics) {
@@ -14,73 +14,58 @@ fn has_allowed_key_type(dictionary: &Dictionary, diagnostics: &mut Diagnostics)
 }
 
 fn check_dictionary_key_type(type_ref: &TypeRef) -> Option<Diagnostic> {
-    // Optional types cannot be used as dictionary keys.
-    if type_ref.is_optional {
-        return Some(Diagnostic::new(Error::KeyMustBeNonOptional).set_span(type_ref.span()));
-    }
-
+    let reason = type_ref.is_valid_dictionary_key().err()?;
     let definition = type_ref.definition();
-    let is_valid = match definition.concrete_type() {
-        Types::Struct(struct_def) => {
-            // Only compact structs can be used for dictionary keys.
-            if !struct_def.is_compact {
-                return Some(Diagnostic::new(Error::StructKeyMustBeCompact).set_span(type_ref.span()));
-            }
 
-            // Check that all the fields of the struct are also valid key types.
-            // We collect the invalid fields so we can report them in the error message.
-            let errors = struct_def
-                .fields()
-                .into_iter()
-                .filter_map(|field| check_dictionary_key_type(field.data_type()))
-                .collect::<Vec<_>>();
-            if !errors.is_empty() {
-                let mut error = Diagnostic::new(Error::StructKeyContainsDisallowedType {
-                    struct_identifier: struct_def.identifier().to_owned(),
-                })
-                .set_span(type_ref.span());
+    let diagnostic = match reason {
+        InvalidKeyReason::Optional => Diagnostic::new(Error::KeyMustBeNonOptional),
+        InvalidKeyReason::NonCompactStruct => Diagnostic::new(Error::StructKeyMustBeCompact),
+        InvalidKeyReason::InvalidFields(_) => {
+            let Types::Struct(struct_def) = definition.concrete_type() else {
+                unreachable!("only structs can have invalid fields");
+            };
 
-                // Convert each error into a note and add it to the struct key error.
-                for e in errors {
-                    error = error.add_note(e.message(), e.span());
-                }
-                return Some(error);
-            }
-            true
+            // Add a note for each field that isn't a valid key type, including fields nested within other structs,
+            // so it's clear exactly which members are disqualifying the key.
+            let mut notes = Vec::new();
+            add_invalid_field_notes(struct_def, "", &mut notes);
+            Diagnostic::new(Error::StructKeyContainsDisallowedType {
+                struct_identifier: struct_def.identifier().to_owned(),
+            })
+            .extend_notes(notes)
         }
+        InvalidKeyReason::EnumWithoutUnderlyingType => Diagnostic::new(Error::KeyTypeNotSupported {
+            kind: formatted_kind(definition),
+        })
+        .add_note("only enums with underlying types can be used as dictionary keys", None),
+        InvalidKeyReason::UnsupportedType => Diagnostic::new(Error::KeyTypeNotSupported {
+            kind: formatted_kind(definition),
+        }),
+    };
+    Some(diagnostic.set_span(type_ref.span()))
+}
 
-        // Only enums with underlying types can be used as dictionary keys. Fields aren't allowed.
-        Types::Enum(enum_def) => {
-            if enum_def.underlying.is_none() {
-                let error = Diagnostic::new(Error::KeyTypeNotSupported {
-                    kind: formatted_kind(definition),
-                })
-                .set_span(type_ref.span())
-                .add_note("only enums with underlying types can be used as dictionary keys", None);
-                return Some(error);
-            }
-            true
-        }
+/// Adds a note for each of the provided struct's fields that aren't valid key types.
+/// For fields which are themselves structs, this recursively adds notes for their invalid fields too.
+/// Each field is identified by its path from the key type, ex: `inner.value`.
+fn add_invalid_field_notes(struct_def: &Struct, path_prefix: &str, notes: &mut Vec<Note>) {
+    for field in struct_def.fields() {
+        let Some(error) = check_dictionary_key_type(field.data_type()) else {
+            continue;
+        };
 
-        Types::Class(_) => false,
-        Types::CustomType(_) => true,
-        Types::ResultType(_) => false,
-        Types::Sequence(_) => false,
-        Types::Dictionary(_) => false,
-        Types::Primitive(primitive) => {
-            primitive.is_integral() || matches!(primitive, Primitive::Bool | Primitive::String)
-        }
-    };
+        let path = format!("{path_prefix}{}", field.identifier());
+        notes.push(Note {
+            message: format!("field '{path}': {}", error.message()),
+            span: error.span().cloned(),
+        });
 
-    if !is_valid {
-        return Some(
-            Diagnostic::new(Error::KeyTypeNotSupported {
-                kind: formatted_kind(definition),
-            })
-            .set_span(type_ref.span()),
-        );
+        if let Types::Struct(nested_struct) = field.data_type().concrete_type() {
+            if matches!(field.data_type().is_valid_dictionary_key(), Err(InvalidKeyReason::InvalidFields(_))) {
+                add_invalid_field_notes(nested_struct, &format!("{path}."), notes);
+            }
+        }
     }
-    None
 }
 
 fn formatted_kind(definition: &dyn Type) -> String {
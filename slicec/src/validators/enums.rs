@@ -1,6 +1,7 @@
 // Copyright (c) ZeroC, Inc.
 
 use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::grammar::attributes::Flags;
 use crate::grammar::*;
 
 use std::collections::HashMap;
@@ -14,6 +15,10 @@ pub fn validate_enum(enum_def: &Enum, diagnostics: &mut Diagnostics) {
     check_compact_modifier(enum_def, diagnostics);
     compact_enums_cannot_contain_tags(enum_def, diagnostics);
 
+    if let Some(flags) = enum_def.find_attribute::<Flags>() {
+        check_flag_values(enum_def, flags, diagnostics);
+    }
+
     // Fields in Slice1 files are already rejected by `encoding_patcher`.
     if enum_def.underlying.is_some() && !enum_def.supported_encodings().supports(Encoding::Slice1) {
         cannot_contain_fields(enum_def, diagnostics);
@@ -21,54 +26,25 @@ pub fn validate_enum(enum_def: &Enum, diagnostics: &mut Diagnostics) {
 }
 
 /// Validate that the enumerators are within the bounds of the specified underlying type.
+/// Enums without an underlying type (including all enums defined in Slice1 mode) must have positive `int32` values.
+/// This applies to checked and unchecked enums alike.
 fn backing_type_bounds(enum_def: &Enum, diagnostics: &mut Diagnostics) {
-    if enum_def.supported_encodings().supports(Encoding::Slice1) {
-        // Enum was defined in a Slice1 file, so it's underlying type is int32 and its enumerators must be positive.
-        for enumerator in enum_def.enumerators() {
-            let value = enumerator.value();
-            if value < 0 || value > i32::MAX as i128 {
-                Diagnostic::new(Error::EnumeratorValueOutOfBounds {
-                    enumerator_identifier: enumerator.identifier().to_owned(),
-                    value,
-                    min: 0,
-                    max: i32::MAX as i128,
-                })
-                .set_span(enumerator.span())
-                .push_into(diagnostics);
-            }
-        }
-    } else {
-        // Enum was defined in a Slice2 file.
-
-        fn check_bounds(enum_def: &Enum, (min, max): (i128, i128), diagnostics: &mut Diagnostics) {
-            enum_def
-                .enumerators()
-                .iter()
-                .filter(|enumerator| enumerator.value() < min || enumerator.value() > max)
-                .for_each(|enumerator| {
-                    let error = Error::EnumeratorValueOutOfBounds {
-                        enumerator_identifier: enumerator.identifier().to_owned(),
-                        value: enumerator.value(),
-                        min,
-                        max,
-                    };
-                    Diagnostic::new(error)
-                        .set_span(enumerator.span())
-                        .push_into(diagnostics);
-                });
-        }
-        match &enum_def.underlying {
-            Some(underlying_type) => {
-                // Non-integral underlying types are rejected by the `allowed_underlying_types` check.
-                if let Some(bounds) = underlying_type.numeric_bounds() {
-                    check_bounds(enum_def, bounds, diagnostics);
-                }
-            }
-            None => {
-                // For enumerators in Slice2, values must fit within varint32 and be positive.
-                const VARINT32_MAX: i128 = i32::MAX as i128;
-                check_bounds(enum_def, (0, VARINT32_MAX), diagnostics);
-            }
+    // Non-integral underlying types are rejected by the `allowed_underlying_types` check.
+    let Some((min, max)) = enum_def.enumerator_bounds() else {
+        return;
+    };
+
+    for enumerator in enum_def.enumerators() {
+        let value = enumerator.value();
+        if value < min || value > max {
+            Diagnostic::new(Error::EnumeratorValueOutOfBounds {
+                enumerator_identifier: enumerator.identifier().to_owned(),
+                value,
+                min,
+                max,
+            })
+            .set_span(enumerator.span())
+            .push_into(diagnostics);
         }
     }
 }
@@ -153,34 +129,19 @@ fn cannot_contain_fields(enum_def: &Enum, diagnostics: &mut Diagnostics) {
     }
 }
 
-/// Validate that compact enums do not have an underlying type and are not marked as 'unchecked'.
+/// Validate that compact enums are not marked as 'unchecked'.
 fn check_compact_modifier(enum_def: &Enum, diagnostics: &mut Diagnostics) {
-    if enum_def.is_compact {
-        if let Some(underlying) = &enum_def.underlying {
-            Diagnostic::new(Error::CannotBeCompact {
-                kind: enum_def.kind(),
-                identifier: enum_def.identifier().to_owned(),
-            })
-            .set_span(enum_def.span())
-            .add_note(
-                "compact enums cannot also have underlying types; try removing either the 'compact' modifier, or the underlying type",
-                Some(underlying.span()),
-            )
-            .push_into(diagnostics);
-        }
-
-        if enum_def.is_unchecked {
-            Diagnostic::new(Error::CannotBeCompact {
-                kind: enum_def.kind(),
-                identifier: enum_def.identifier().to_owned(),
-            })
-            .set_span(enum_def.span())
-            .add_note(
-                "An enum cannot be both unchecked and compact - try removing the 'compact' modifier",
-                None,
-            )
-            .push_into(diagnostics);
-        }
+    if enum_def.is_compact && enum_def.is_unchecked {
+        Diagnostic::new(Error::CannotBeCompact {
+            kind: enum_def.kind(),
+            identifier: enum_def.identifier().to_owned(),
+        })
+        .set_span(enum_def.span())
+        .add_note(
+            "An enum cannot be both unchecked and compact - try removing the 'compact' modifier",
+            None,
+        )
+        .push_into(diagnostics);
     }
 }
 
@@ -202,3 +163,40 @@ fn compact_enums_cannot_contain_tags(enum_def: &Enum, diagnostics: &mut Diagnost
         }
     }
 }
+
+/// Validate that the enumerators of a flags enum are valid flags.
+///
+/// Each enumerator must either be zero, a single bit (a power of two), or a combination of the bits used by the
+/// enum's single-bit enumerators. If the enum allows overlapping flags, multi-bit enumerators may use any bits.
+fn check_flag_values(enum_def: &Enum, flags: &Flags, diagnostics: &mut Diagnostics) {
+    let enumerators = enum_def.enumerators();
+    let defined_bits = enumerators
+        .iter()
+        .map(|enumerator| enumerator.value())
+        .filter(|value| *value > 0 && value.count_ones() == 1)
+        .fold(0, |bits, value| bits | value);
+
+    for enumerator in enumerators {
+        let value = enumerator.value();
+        let error = Error::InvalidFlagValue {
+            enumerator_identifier: enumerator.identifier().to_owned(),
+            value,
+        };
+
+        if value < 0 {
+            Diagnostic::new(error)
+                .set_span(enumerator.span())
+                .add_note("flag values cannot be negative", None)
+                .push_into(diagnostics);
+        } else if value.count_ones() > 1 && !flags.allow_overlap && value & !defined_bits != 0 {
+            Diagnostic::new(error)
+                .set_span(enumerator.span())
+                .add_note(
+                    "flag values must be powers of two, or combinations of other enumerators in the enum",
+                    None,
+                )
+                .add_note("to allow overlapping flags, use '[flags(AllowOverlap)]'", None)
+                .push_into(diagnostics);
+        }
+    }
+}
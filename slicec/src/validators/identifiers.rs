@@ -1,9 +1,10 @@
 // Copyright (c) ZeroC, Inc.
 
 use crate::ast::Ast;
-use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::diagnostics::{Diagnostic, Diagnostics, Error, Lint, Note};
 use crate::grammar::*;
 use std::collections::HashMap;
+use unicode_security::confusable_detection::skeleton;
 
 pub fn validate_inherited_identifiers(
     symbols: Vec<&impl NamedSymbol>,
@@ -28,7 +29,7 @@ fn check_for_shadowing(
         for inherited_identifier in &inherited_identifiers {
             if identifier.value == inherited_identifier.value {
                 Diagnostic::new(Error::Shadows {
-                    identifier: identifier.value.clone(),
+                    identifier: identifier.value.to_string(),
                 })
                 .set_span(identifier.span())
                 .add_note(
@@ -41,6 +42,126 @@ fn check_for_shadowing(
     }
 }
 
+/// Checks whether an interface inherits multiple, distinct operations with the same name through different bases.
+/// Collisions which are inherited through a single base interface are only reported on that base interface.
+pub fn validate_inherited_operations(interface: &Interface, diagnostics: &mut Diagnostics) {
+    let base_operations = interface
+        .base_interfaces()
+        .into_iter()
+        .map(Interface::all_operations)
+        .collect::<Vec<_>>();
+
+    // Group the inherited operations by name, preserving the order they were inherited in.
+    let mut groups: Vec<Vec<&Operation>> = Vec::new();
+    for operation in interface.all_inherited_operations() {
+        match groups.iter_mut().find(|group| group[0].identifier() == operation.identifier()) {
+            Some(group) => group.push(operation),
+            None => groups.push(vec![operation]),
+        }
+    }
+
+    for group in groups.into_iter().filter(|group| group.len() > 1) {
+        let is_inherited_from_single_base = base_operations
+            .iter()
+            .any(|operations| group.iter().all(|op| operations.iter().any(|other| std::ptr::eq(*op, *other))));
+        if is_inherited_from_single_base {
+            continue;
+        }
+
+        let notes = group.iter().map(|operation| Note {
+            message: format!("'{}' is inherited from '{}'", operation.identifier(), operation.parent().identifier()),
+            span: Some(operation.raw_identifier().span().clone()),
+        });
+        Diagnostic::new(Error::AmbiguousInheritedOperation {
+            interface_identifier: interface.identifier().to_owned(),
+            operation_identifier: group[0].identifier().to_owned(),
+        })
+        .set_span(interface.span())
+        .extend_notes(notes)
+        .push_into(diagnostics);
+    }
+}
+
+/// Checks whether an interface has multiple operations (declared or inherited) whose names only differ by case.
+/// These are valid Slice, but can collide once code generators convert identifiers to their language's case style.
+/// Like [validate_inherited_operations], collisions which are inherited through a single base interface are only
+/// reported on that base interface.
+pub fn validate_operation_case_collisions(interface: &Interface, diagnostics: &mut Diagnostics) {
+    let operations = interface.all_operations_flattened();
+    for (i, flattened) in operations.iter().enumerate() {
+        let operation = flattened.operation;
+        for other in &operations[..i] {
+            // Exact collisions are skipped, since they're already reported as errors.
+            let is_collision = flattened.collisions.iter().any(|op| std::ptr::eq(*op, other.operation));
+            if !is_collision || operation.identifier() == other.operation.identifier() {
+                continue;
+            }
+
+            let is_inherited_from_single_base = flattened
+                .inherited_through
+                .iter()
+                .any(|base| other.inherited_through.iter().any(|other_base| std::ptr::eq(*base, *other_base)));
+            if is_inherited_from_single_base {
+                continue;
+            }
+
+            let diagnostic = Diagnostic::new(Lint::OperationCaseCollision {
+                identifier: operation.identifier().to_owned(),
+                other_identifier: other.operation.identifier().to_owned(),
+            });
+            let diagnostic = match flattened.is_inherited() {
+                true => diagnostic
+                    .set_span(interface.span())
+                    .set_scope(interface.parser_scoped_identifier()),
+                false => diagnostic
+                    .set_span(operation.raw_identifier().span())
+                    .set_scope(operation.parser_scoped_identifier()),
+            };
+            diagnostic
+                .add_note(
+                    format!("'{}' was defined here", other.operation.identifier()),
+                    Some(other.operation.raw_identifier().span()),
+                )
+                .push_into(diagnostics);
+        }
+    }
+}
+
+/// Reports a lint for each identifier which looks like another identifier in the compilation, but isn't the same.
+/// Ex: 'Foo' and 'Fоo', where the second uses a Cyrillic 'о'. Identifiers are compared by their skeletons, as described
+/// by Unicode Technical Standard #39. Pairs of ASCII-only identifiers (Ex: 'rn' and 'm') aren't reported, since these
+/// are almost always intentional.
+pub fn check_for_confusable_identifiers(ast: &Ast, diagnostics: &mut Diagnostics) {
+    // Maps each skeleton to the symbols where each distinct identifier with that skeleton first appeared.
+    let mut seen_skeletons: HashMap<String, Vec<&dyn NamedSymbol>> = HashMap::new();
+
+    for node in ast.as_slice() {
+        let Ok(symbol) = <&dyn NamedSymbol>::try_from(node) else { continue };
+        let identifier = symbol.identifier();
+
+        let seen_symbols = seen_skeletons.entry(skeleton(identifier).collect()).or_default();
+        if seen_symbols.iter().any(|other| other.identifier() == identifier) {
+            continue;
+        }
+
+        let is_confusable = |other: &&&dyn NamedSymbol| !(identifier.is_ascii() && other.identifier().is_ascii());
+        if let Some(other) = seen_symbols.iter().find(is_confusable) {
+            Diagnostic::new(Lint::ConfusableIdentifier {
+                identifier: identifier.to_owned(),
+                other_identifier: other.identifier().to_owned(),
+            })
+            .set_span(symbol.raw_identifier().span())
+            .set_scope(symbol.parser_scoped_identifier())
+            .add_note(
+                format!("'{}' was defined here", other.identifier()),
+                Some(other.raw_identifier().span()),
+            )
+            .push_into(diagnostics);
+        }
+        seen_symbols.push(symbol);
+    }
+}
+
 pub fn check_for_redefinitions(ast: &Ast, diagnostics: &mut Diagnostics) {
     RedefinitionChecker { diagnostics }.check_for_redefinitions(ast);
 }
@@ -63,7 +184,7 @@ impl<'a> RedefinitionChecker<'a> {
             match definition.concrete_entity() {
                 Entities::Struct(struct_def) => {
                     self.check_if_redefined(struct_def, &mut seen_definitions);
-                    self.check_contents_for_redefinitions(struct_def.contents());
+                    self.check_members_and_constants_for_redefinitions(struct_def.fields(), struct_def.constants());
                 }
                 Entities::Class(class_def) => {
                     self.check_if_redefined(class_def, &mut seen_definitions);
@@ -75,7 +196,10 @@ impl<'a> RedefinitionChecker<'a> {
                 }
                 Entities::Interface(interface_def) => {
                     self.check_if_redefined(interface_def, &mut seen_definitions);
-                    self.check_contents_for_redefinitions(interface_def.contents());
+                    self.check_members_and_constants_for_redefinitions(
+                        interface_def.operations(),
+                        interface_def.constants(),
+                    );
 
                     for operation in interface_def.operations() {
                         self.check_contents_for_redefinitions(operation.parameters());
@@ -84,7 +208,7 @@ impl<'a> RedefinitionChecker<'a> {
                 }
                 Entities::Enum(enum_def) => {
                     self.check_if_redefined(enum_def, &mut seen_definitions);
-                    self.check_contents_for_redefinitions(enum_def.contents());
+                    self.check_members_and_constants_for_redefinitions(enum_def.enumerators(), enum_def.constants());
                 }
                 Entities::CustomType(custom_type) => {
                     self.check_if_redefined(custom_type, &mut seen_definitions);
@@ -92,6 +216,9 @@ impl<'a> RedefinitionChecker<'a> {
                 Entities::TypeAlias(type_alias) => {
                     self.check_if_redefined(type_alias, &mut seen_definitions);
                 }
+                Entities::Constant(constant) => {
+                    self.check_if_redefined(constant, &mut seen_definitions);
+                }
 
                 // No need to check `Field`, `Enumerator`, `Operation`, or `Parameter`; We just check their containers.
                 Entities::Field(_) | Entities::Enumerator(_) | Entities::Operation(_) | Entities::Parameter(_) => {}
@@ -107,6 +234,21 @@ impl<'a> RedefinitionChecker<'a> {
         }
     }
 
+    /// Checks a type's members and associated constants for redefinitions, since they share the type's scope.
+    fn check_members_and_constants_for_redefinitions<T: NamedSymbol>(
+        &mut self,
+        members: Vec<&T>,
+        constants: Vec<&Constant>,
+    ) {
+        let mut seen_definitions = HashMap::new();
+        for member in members {
+            self.check_if_redefined(member, &mut seen_definitions);
+        }
+        for constant in constants {
+            self.check_if_redefined(constant, &mut seen_definitions);
+        }
+    }
+
     /// Checks if the provided `definition` already has an entry in the `already_seen` map. If it does, we report a
     /// redefinition error, otherwise, we just add it to the map and return.
     fn check_if_redefined<'b>(
@@ -7,6 +7,7 @@ pub fn validate_members(members: Vec<&impl Member>, diagnostics: &mut Diagnostic
     tags_have_optional_types(members.clone(), diagnostics);
     tagged_members_cannot_use_classes(members.clone(), diagnostics);
     tags_are_unique(members.clone(), diagnostics);
+    default_values_match_types(members, diagnostics);
 }
 
 /// Validates that the tags are unique.
@@ -80,3 +81,61 @@ fn tagged_members_cannot_use_classes(members: Vec<&impl Member>, diagnostics: &m
         }
     }
 }
+
+/// Validate that members' default values are compatible with their types, and fit within those types' bounds.
+fn default_values_match_types(members: Vec<&impl Member>, diagnostics: &mut Diagnostics) {
+    for member in members {
+        let (Some(default), Some(value)) = (member.default(), member.default_value()) else {
+            continue;
+        };
+        let data_type = member.data_type();
+
+        // Like constants, default values can only be integers, booleans, and strings, and can never be optional.
+        let primitive = match data_type.concrete_type() {
+            Types::Primitive(primitive) if !data_type.is_optional => primitive,
+            _ => {
+                report_unsupported_default_type(member, diagnostics);
+                continue;
+            }
+        };
+        if !primitive.is_integral() && !matches!(primitive, Primitive::Bool | Primitive::String) {
+            report_unsupported_default_type(member, diagnostics);
+            continue;
+        }
+
+        match (primitive, value) {
+            (Primitive::Bool, ConstantValue::Bool(_)) | (Primitive::String, ConstantValue::String(_)) => {}
+            (_, ConstantValue::Integer(value)) if primitive.is_integral() => {
+                let (min, max) = primitive.numeric_bounds().unwrap();
+                if *value < min || *value > max {
+                    Diagnostic::new(Error::DefaultValueOutOfBounds {
+                        identifier: member.identifier().to_owned(),
+                        value: *value,
+                        min,
+                        max,
+                    })
+                    .set_span(default.span())
+                    .push_into(diagnostics);
+                }
+            }
+            (_, value) => {
+                Diagnostic::new(Error::TypeMismatch {
+                    expected: primitive.kind().to_owned(),
+                    actual: value.kind().to_owned(),
+                    is_concrete: true,
+                })
+                .set_span(default.span())
+                .push_into(diagnostics);
+            }
+        }
+    }
+}
+
+fn report_unsupported_default_type(member: &impl Member, diagnostics: &mut Diagnostics) {
+    Diagnostic::new(Error::DefaultValueTypeNotSupported {
+        identifier: member.identifier().to_owned(),
+        kind: member.data_type().type_string(),
+    })
+    .set_span(member.data_type().span())
+    .push_into(diagnostics);
+}
@@ -2,32 +2,53 @@
 
 mod attribute;
 mod comments;
+mod constants;
 mod cycle_detection;
 mod dictionary;
 mod enums;
 mod identifiers;
 mod members;
+mod naming_conventions;
 mod operations;
 mod parameters;
+mod plugins;
+mod reserved_identifiers;
 mod structs;
+mod tags;
 mod type_aliases;
+mod type_policy;
+mod unused_definitions;
+mod visibility;
 
 use crate::compilation_state::CompilationState;
-use crate::diagnostics::Diagnostics;
+use crate::diagnostics::{Diagnostic, Diagnostics};
 use crate::grammar::*;
+use crate::logging;
 use crate::slice_file::SliceFile;
+use crate::utils::type_id_util::{check_type_ids, DefaultTypeIdStrategy};
 use crate::visitor::Visitor;
+use std::time::Instant;
 
 use attribute::validate_attributes;
-use comments::validate_common_doc_comments;
+use comments::{validate_common_doc_comments, validate_file_doc_comment, validate_module_doc_comment};
+use constants::validate_constant;
 use dictionary::validate_dictionary;
 use enums::validate_enum;
-use identifiers::validate_inherited_identifiers;
+use identifiers::{validate_inherited_identifiers, validate_inherited_operations, validate_operation_case_collisions};
 use members::validate_members;
 use operations::validate_operation;
 use parameters::validate_parameters;
 use structs::validate_struct;
+use tags::validate_tags;
 use type_aliases::validate_type_alias;
+use visibility::validate_visibility;
+
+pub(crate) use naming_conventions::check_naming_conventions;
+pub(crate) use plugins::run_validator_plugins;
+pub(crate) use reserved_identifiers::{check_keyword_collisions, check_reserved_identifiers};
+pub(crate) use tags::audit_tags;
+pub(crate) use type_policy::check_type_policy;
+pub(crate) use unused_definitions::check_unused_definitions;
 
 pub(crate) fn validate_ast(compilation_state: &mut CompilationState) {
     let diagnostics = &mut compilation_state.diagnostics;
@@ -44,36 +65,92 @@ pub(crate) fn validate_ast(compilation_state: &mut CompilationState) {
         return;
     }
 
-    let mut validator = ValidatorVisitor::new(diagnostics);
-    for slice_file in &compilation_state.files {
-        slice_file.visit_with(&mut validator);
+    // Check for identifiers which look the same as other identifiers, but aren't.
+    identifiers::check_for_confusable_identifiers(&compilation_state.ast, diagnostics);
+
+    // Check that every definition that has a type ID has a unique one.
+    check_type_ids(&DefaultTypeIdStrategy, &compilation_state.ast, diagnostics);
+
+    // The remaining validation is done per-file, and files are independent of one another, so we validate them in
+    // parallel. Each thread collects its diagnostics separately, and they're merged in file order afterwards, so the
+    // order diagnostics are reported in is the same as if the files had been validated sequentially.
+    for diagnostic in validate_files_in_parallel(&compilation_state.files) {
+        diagnostic.push_into(diagnostics);
     }
 }
 
+/// Validates each of the provided files, and returns the diagnostics reported for them, ordered by file.
+/// Files are split into contiguous chunks, one per available thread, which are each validated on their own thread.
+fn validate_files_in_parallel(files: &[SliceFile]) -> Vec<Diagnostic> {
+    let thread_count = std::thread::available_parallelism().map_or(1, |count| count.get());
+    let chunk_size = files.len().div_ceil(thread_count).max(1);
+
+    // Each chunk gets its own diagnostics container, since the main container's listener can't be shared between
+    // threads.
+    let validate_chunk = |chunk: &[SliceFile]| -> Vec<Diagnostic> {
+        let mut chunk_diagnostics = Diagnostics::new();
+        let mut validator = ValidatorVisitor::new(&mut chunk_diagnostics);
+        for slice_file in chunk {
+            let start = Instant::now();
+            slice_file.visit_with(&mut validator);
+            let duration_ms = logging::millis(start.elapsed());
+            let path = &slice_file.relative_path;
+            log::debug!(target: logging::VALIDATION, "validated '{path}': duration_ms={duration_ms:.3}");
+        }
+        chunk_diagnostics.into_inner()
+    };
+
+    // If there's only a single chunk, there's no benefit to spawning a thread for it.
+    if files.len() <= chunk_size {
+        return validate_chunk(files);
+    }
+
+    std::thread::scope(|scope| {
+        let handles = files
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || validate_chunk(chunk)))
+            .collect::<Vec<_>>();
+
+        let results = handles.into_iter().map(|handle| handle.join().expect("validation thread panicked"));
+        results.flatten().collect()
+    })
+}
+
 struct ValidatorVisitor<'a> {
     diagnostics: &'a mut Diagnostics,
+
+    /// The compilation mode of the file currently being validated.
+    compilation_mode: CompilationMode,
 }
 
 impl<'a> ValidatorVisitor<'a> {
     pub fn new(diagnostics: &'a mut Diagnostics) -> Self {
-        ValidatorVisitor { diagnostics }
+        ValidatorVisitor {
+            diagnostics,
+            compilation_mode: CompilationMode::default(),
+        }
     }
 }
 
 impl<'a> Visitor for ValidatorVisitor<'a> {
     fn visit_file(&mut self, slice_file: &SliceFile) {
+        self.compilation_mode = slice_file.compilation_mode();
+        validate_file_doc_comment(slice_file, self.diagnostics);
         validate_attributes(slice_file, self.diagnostics);
     }
 
     fn visit_module(&mut self, module_def: &Module) {
+        validate_module_doc_comment(module_def, self.diagnostics);
         validate_attributes(module_def, self.diagnostics);
     }
 
     fn visit_class(&mut self, class: &Class) {
         validate_common_doc_comments(class, self.diagnostics);
         validate_attributes(class, self.diagnostics);
+        validate_visibility(class, self.diagnostics);
 
         validate_members(class.fields(), self.diagnostics);
+        validate_tags(class, class.fields(), self.compilation_mode, self.diagnostics);
 
         validate_inherited_identifiers(class.fields(), class.all_inherited_fields(), self.diagnostics);
     }
@@ -81,6 +158,7 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
     fn visit_enum(&mut self, enum_def: &Enum) {
         validate_common_doc_comments(enum_def, self.diagnostics);
         validate_attributes(enum_def, self.diagnostics);
+        validate_visibility(enum_def, self.diagnostics);
 
         validate_enum(enum_def, self.diagnostics);
     }
@@ -95,13 +173,16 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
         validate_attributes(enumerator, self.diagnostics);
 
         validate_members(enumerator.contents(), self.diagnostics);
+        validate_tags(enumerator, enumerator.contents(), self.compilation_mode, self.diagnostics);
     }
 
     fn visit_exception(&mut self, exception: &Exception) {
         validate_common_doc_comments(exception, self.diagnostics);
         validate_attributes(exception, self.diagnostics);
+        validate_visibility(exception, self.diagnostics);
 
         validate_members(exception.fields(), self.diagnostics);
+        validate_tags(exception, exception.fields(), self.compilation_mode, self.diagnostics);
 
         validate_inherited_identifiers(exception.fields(), exception.all_inherited_fields(), self.diagnostics);
     }
@@ -109,12 +190,15 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
     fn visit_interface(&mut self, interface: &Interface) {
         validate_common_doc_comments(interface, self.diagnostics);
         validate_attributes(interface, self.diagnostics);
+        validate_visibility(interface, self.diagnostics);
 
         validate_inherited_identifiers(
             interface.operations(),
             interface.all_inherited_operations(),
             self.diagnostics,
         );
+        validate_inherited_operations(interface, self.diagnostics);
+        validate_operation_case_collisions(interface, self.diagnostics);
     }
 
     fn visit_operation(&mut self, operation: &Operation) {
@@ -125,6 +209,8 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
 
         validate_members(operation.parameters(), self.diagnostics);
         validate_members(operation.return_members(), self.diagnostics);
+        validate_tags(operation, operation.parameters(), self.compilation_mode, self.diagnostics);
+        validate_tags(operation, operation.return_members(), self.compilation_mode, self.diagnostics);
 
         validate_parameters(&operation.parameters(), self.diagnostics);
         validate_parameters(&operation.return_members(), self.diagnostics);
@@ -137,10 +223,12 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
     fn visit_struct(&mut self, struct_def: &Struct) {
         validate_common_doc_comments(struct_def, self.diagnostics);
         validate_attributes(struct_def, self.diagnostics);
+        validate_visibility(struct_def, self.diagnostics);
 
         validate_struct(struct_def, self.diagnostics);
 
         validate_members(struct_def.fields(), self.diagnostics);
+        validate_tags(struct_def, struct_def.fields(), self.compilation_mode, self.diagnostics);
     }
 
     fn visit_field(&mut self, field: &Field) {
@@ -151,10 +239,18 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
     fn visit_type_alias(&mut self, type_alias: &TypeAlias) {
         validate_common_doc_comments(type_alias, self.diagnostics);
         validate_attributes(type_alias, self.diagnostics);
+        validate_visibility(type_alias, self.diagnostics);
 
         validate_type_alias(type_alias, self.diagnostics);
     }
 
+    fn visit_constant(&mut self, constant: &Constant) {
+        validate_common_doc_comments(constant, self.diagnostics);
+        validate_attributes(constant, self.diagnostics);
+
+        validate_constant(constant, self.diagnostics);
+    }
+
     fn visit_type_ref(&mut self, type_ref: &TypeRef) {
         validate_attributes(type_ref, self.diagnostics);
 
@@ -0,0 +1,39 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostic, Lint};
+use crate::grammar::*;
+use crate::slice_options::SliceOptions;
+
+/// Reports a lint for each identifier which doesn't follow the naming convention configured for its kind of definition.
+/// See [NamingConvention](crate::slice_options::NamingConvention) for more information.
+pub(crate) fn check_naming_conventions(state: &mut CompilationState, options: &SliceOptions) {
+    if options.naming_conventions.is_empty() {
+        return;
+    }
+
+    for node in state.ast.as_slice() {
+        let Ok(symbol) = <&dyn NamedSymbol>::try_from(node) else { continue };
+        let Some(convention) = options.naming_conventions.iter().find(|c| c.kind == symbol.kind()) else { continue };
+
+        // Nested module declarations (`module Foo::Bar`) are checked one segment at a time.
+        let identifier = symbol.raw_identifier();
+        let style = convention.style;
+        if identifier.value.split("::").all(|segment| style.matches(segment)) {
+            continue;
+        }
+
+        let segments = identifier.value.split("::");
+        let renamed = segments.map(|segment| style.convert(segment)).collect::<Vec<_>>().join("::");
+        Diagnostic::new(Lint::NamingConvention {
+            kind: convention.kind.clone(),
+            identifier: identifier.value.to_string(),
+            style: style.to_string(),
+        })
+        .set_span(identifier.span())
+        .set_scope(symbol.parser_scoped_identifier())
+        // Renaming a definition also requires updating its uses, so this can't be applied automatically.
+        .add_manual_suggestion(format!("rename it to '{renamed}'"), identifier.span(), renamed)
+        .push_into(&mut state.diagnostics);
+    }
+}
@@ -4,27 +4,80 @@ use crate::diagnostics::{Diagnostic, Diagnostics, Error, Lint};
 use crate::grammar::*;
 
 pub fn validate_operation(operation: &Operation, diagnostics: &mut Diagnostics) {
-    exception_specifications_can_only_be_used_in_slice1_mode(operation, diagnostics);
+    validate_modifiers(operation, diagnostics);
+    validate_oneway(operation, diagnostics);
     if let Some(comment) = operation.comment() {
         validate_param_tags(comment, operation, diagnostics);
         validate_returns_tags(comment, operation, diagnostics);
         validate_throws_tags(comment, operation, diagnostics);
+        validate_example_tags(comment, operation, diagnostics);
     }
 }
 
-fn exception_specifications_can_only_be_used_in_slice1_mode(operation: &Operation, diagnostics: &mut Diagnostics) {
-    if operation.encoding != CompilationMode::Slice1 && !operation.exception_specification.is_empty() {
-        // Create a span that covers the entire exception specification.
-        let mut span = operation.exception_specification.first().unwrap().span().clone();
-        span.end = operation.exception_specification.last().unwrap().span().end;
+/// Pairs of modifiers which cannot be applied to the same operation, along with an explanation of why.
+const CONFLICTING_MODIFIERS: [(OperationModifierKind, OperationModifierKind, &str); 1] = [(
+    OperationModifierKind::Readonly,
+    OperationModifierKind::Idempotent,
+    "readonly operations are implicitly idempotent",
+)];
 
-        Diagnostic::new(Error::ExceptionSpecificationNotSupported)
-            .set_span(&span)
+fn validate_modifiers(operation: &Operation, diagnostics: &mut Diagnostics) {
+    for (i, modifier) in operation.modifiers.iter().enumerate() {
+        let previous_modifiers = &operation.modifiers[..i];
+
+        let diagnostic = if let Some(previous) = previous_modifiers.iter().find(|m| m.kind == modifier.kind) {
+            Diagnostic::new(Error::DuplicateOperationModifier {
+                modifier: modifier.kind.keyword().to_owned(),
+            })
+            .add_note("the modifier was previously applied here:", Some(previous.span()))
+        } else if let Some((previous, reason)) = find_conflicting_modifier(modifier, previous_modifiers) {
+            Diagnostic::new(Error::ConflictingOperationModifiers {
+                modifier: modifier.kind.keyword().to_owned(),
+                conflicting_modifier: previous.kind.keyword().to_owned(),
+            })
+            .add_note(reason, None)
+        } else {
+            continue;
+        };
+
+        diagnostic
+            .set_span(modifier.span())
             .set_scope(operation.parser_scoped_identifier())
             .push_into(diagnostics);
     }
 }
 
+/// Oneway operations don't wait for a response, so they can't return or throw anything.
+fn validate_oneway(operation: &Operation, diagnostics: &mut Diagnostics) {
+    let Some(modifier) = operation.modifiers.iter().find(|m| m.kind == OperationModifierKind::Oneway) else {
+        return;
+    };
+
+    if !operation.return_type.is_empty() || operation.throws_anything() {
+        Diagnostic::new(Error::OnewayOperationReturnsData {
+            identifier: operation.identifier().to_owned(),
+        })
+        .set_span(operation.span())
+        .set_scope(operation.parser_scoped_identifier())
+        .add_note("the operation was marked oneway here:", Some(modifier.span()))
+        .push_into(diagnostics);
+    }
+}
+
+/// Returns the first of the `previous_modifiers` which conflicts with `modifier`, and the reason they conflict.
+fn find_conflicting_modifier<'a>(
+    modifier: &OperationModifier,
+    previous_modifiers: &'a [OperationModifier],
+) -> Option<(&'a OperationModifier, &'static str)> {
+    previous_modifiers.iter().find_map(|previous| {
+        let kinds = (modifier.kind, previous.kind);
+        CONFLICTING_MODIFIERS
+            .iter()
+            .find(|(a, b, _)| kinds == (*a, *b) || kinds == (*b, *a))
+            .map(|(_, _, reason)| (previous, *reason))
+    })
+}
+
 fn validate_param_tags(comment: &DocComment, operation: &Operation, diagnostics: &mut Diagnostics) {
     let parameters: Vec<_> = operation.parameters().iter().map(|p| p.identifier()).collect();
 
@@ -96,7 +149,7 @@ fn validate_returns_tags_for_operation_with_single_return(
                 format!("operation '{}' returns a single unnamed type", operation.identifier()),
                 Some(operation.span()),
             )
-            .add_note("try removing the identifier from your comment: \"@returns: ...\"", None)
+            .add_suggestion("try removing the identifier from your comment", returns_tag.span(), "@returns")
             .push_into(diagnostics);
         }
     }
@@ -130,13 +183,12 @@ fn validate_returns_tags_for_operation_with_return_tuple(
 
 fn validate_throws_tags(comment: &DocComment, operation: &Operation, diagnostics: &mut Diagnostics) {
     let throws_tags = &comment.throws;
-    if operation.exception_specification.is_empty() {
+    if operation.throws_anything() {
+        // If the operation can fail, ensure that its 'throws' tags agree with the types it can fail with.
+        validate_throws_tags_for_operation_with_throws_clause(throws_tags, operation, diagnostics);
+    } else {
         // If the operation doesn't throw, but its doc comment has 'throws' tags, report an error.
         validate_throws_tags_for_operation_with_no_throws_clause(throws_tags, operation, diagnostics);
-    } else {
-        // If the operation can throw exceptions, ensure that its 'throws' tags agree with them.
-        let thrown_exceptions = &operation.exception_specification;
-        validate_throws_tags_for_operation_with_throws_clause(throws_tags, operation, thrown_exceptions, diagnostics);
     }
 }
 
@@ -161,24 +213,28 @@ fn validate_throws_tags_for_operation_with_no_throws_clause(
 fn validate_throws_tags_for_operation_with_throws_clause(
     throws_tags: &[ThrowsTag],
     operation: &Operation,
-    exception_types: &[TypeRef<Exception>],
     diagnostics: &mut Diagnostics,
 ) {
+    let thrown_types = operation.thrown_types();
     for throws_tag in throws_tags {
-        if let Ok(documented_exception) = throws_tag.thrown_type() {
-            let is_correct = exception_types.iter().any(|thrown_exception| {
-                is_documented_exception_compatible(thrown_exception.definition(), documented_exception)
-            });
+        if let Ok(documented_type) = throws_tag.thrown_type() {
+            let is_correct = thrown_types
+                .iter()
+                .any(|thrown_type| is_documented_type_compatible(thrown_type, documented_type));
 
             if !is_correct {
+                let what = match operation.error_type {
+                    Some(_) => "fail with this error type",
+                    None => "throw this exception",
+                };
                 Diagnostic::new(Lint::IncorrectDocComment {
                     message: format!(
-                        "comment has a 'throws' tag for '{}', but operation '{}' doesn't throw this exception",
-                        documented_exception.identifier(),
+                        "comment has a 'throws' tag for '{}', but operation '{}' doesn't {what}",
+                        documented_type.identifier(),
                         operation.identifier(),
                     ),
                 })
-                .set_span(throws_tag.span())
+                .set_span(&throws_tag.thrown_type_span)
                 .set_scope(operation.parser_scoped_identifier())
                 .push_into(diagnostics);
             }
@@ -186,6 +242,21 @@ fn validate_throws_tags_for_operation_with_throws_clause(
     }
 }
 
+/// Returns true if `documented_type` matches the type in an operation's throws clause.
+/// Exceptions also match any exceptions derived from them, and error types also match aliases of them.
+fn is_documented_type_compatible(thrown_type: &ThrownType, documented_type: &dyn Entity) -> bool {
+    match (thrown_type, documented_type.concrete_entity()) {
+        (ThrownType::Exception(thrown_exception), Entities::Exception(documented_exception)) => {
+            is_documented_exception_compatible(thrown_exception.definition(), documented_exception)
+        }
+        (ThrownType::Error(error_type), Entities::TypeAlias(type_alias)) => {
+            std::ptr::addr_eq(error_type.definition(), type_alias.underlying.definition())
+        }
+        (ThrownType::Error(error_type), _) => std::ptr::addr_eq(error_type.definition(), documented_type),
+        _ => false,
+    }
+}
+
 /// Returns true if `documented_exception` is the same as, or derives from `thrown_exception`.
 fn is_documented_exception_compatible(thrown_exception: &Exception, documented_exception: &Exception) -> bool {
     if std::ptr::eq(thrown_exception, documented_exception) {
@@ -196,3 +267,102 @@ fn is_documented_exception_compatible(thrown_exception: &Exception, documented_e
         false
     }
 }
+
+fn validate_example_tags(comment: &DocComment, operation: &Operation, diagnostics: &mut Diagnostics) {
+    let parameters = operation.parameters();
+
+    for example_tag in &comment.examples {
+        let mut documented_parameters = Vec::new();
+        for argument in &example_tag.arguments {
+            let argument_identifier = argument.identifier.value.as_str();
+            let Some(parameter) = parameters.iter().find(|p| p.identifier() == argument_identifier) else {
+                Diagnostic::new(Lint::IncorrectDocComment {
+                    message: format!(
+                        "comment has an 'example' tag for '{argument_identifier}', but operation '{}' has no parameter with that name",
+                        operation.identifier(),
+                    ),
+                })
+                .set_span(argument.span())
+                .set_scope(operation.parser_scoped_identifier())
+                .push_into(diagnostics);
+                continue;
+            };
+
+            if documented_parameters.contains(&argument_identifier) {
+                Diagnostic::new(Lint::IncorrectDocComment {
+                    message: format!(
+                        "comment has an 'example' tag which provides multiple values for '{argument_identifier}'",
+                    ),
+                })
+                .set_span(argument.span())
+                .set_scope(operation.parser_scoped_identifier())
+                .push_into(diagnostics);
+            } else {
+                documented_parameters.push(argument_identifier);
+                validate_example_value(argument, parameter, operation, diagnostics);
+            }
+        }
+
+        // Only optional parameters can be omitted from an example.
+        for parameter in &parameters {
+            if !parameter.data_type.is_optional && !documented_parameters.contains(&parameter.identifier()) {
+                Diagnostic::new(Lint::IncorrectDocComment {
+                    message: format!(
+                        "comment has an 'example' tag which doesn't provide a value for parameter '{}'",
+                        parameter.identifier(),
+                    ),
+                })
+                .set_span(example_tag.span())
+                .set_scope(operation.parser_scoped_identifier())
+                .add_note("only optional parameters can be omitted from examples", None)
+                .push_into(diagnostics);
+            }
+        }
+    }
+}
+
+fn validate_example_value(
+    argument: &ExampleArgument,
+    parameter: &Parameter,
+    operation: &Operation,
+    diagnostics: &mut Diagnostics,
+) {
+    let data_type = parameter.data_type();
+    let is_valid = match (&argument.value, data_type.concrete_type()) {
+        (ExampleValue::Literal(ConstantValue::Integer(value)), Types::Primitive(primitive)) => {
+            match primitive.numeric_bounds() {
+                Some((min, max)) => (min..=max).contains(value),
+                None => matches!(primitive, Primitive::Float32 | Primitive::Float64),
+            }
+        }
+        (ExampleValue::Literal(ConstantValue::Bool(_)), Types::Primitive(primitive)) => primitive == &Primitive::Bool,
+        (ExampleValue::Literal(ConstantValue::String(_)), Types::Primitive(primitive)) => {
+            primitive == &Primitive::String
+        }
+        (ExampleValue::Enumerator(identifier), Types::Enum(enum_def)) => enum_def
+            .enumerators()
+            .iter()
+            .any(|enumerator| enumerator.identifier() == identifier.value),
+        _ => false,
+    };
+
+    if !is_valid {
+        Diagnostic::new(Lint::IncorrectDocComment {
+            message: format!(
+                "comment has an 'example' tag with an invalid value for parameter '{}'",
+                parameter.identifier(),
+            ),
+        })
+        .set_span(argument.span())
+        .set_scope(operation.parser_scoped_identifier())
+        .add_note(
+            format!(
+                "parameter '{}' is of type '{}'",
+                parameter.identifier(),
+                data_type.type_string(),
+            ),
+            Some(parameter.span()),
+        )
+        .push_into(diagnostics);
+    }
+}
@@ -6,6 +6,27 @@ use crate::grammar::*;
 pub fn validate_parameters(members: &[&Parameter], diagnostics: &mut Diagnostics) {
     stream_parameter_is_last(members, diagnostics);
     at_most_one_stream_parameter(members, diagnostics);
+    default_values_are_trailing(members, diagnostics);
+}
+
+/// Validates that once a parameter has a default value, every parameter after it also has one.
+fn default_values_are_trailing(members: &[&Parameter], diagnostics: &mut Diagnostics) {
+    let Some(index) = members.iter().position(|m| m.default.is_some()) else {
+        return;
+    };
+
+    let first_defaulted = members[index];
+    for m in members[index..].iter().filter(|m| m.default.is_none()) {
+        Diagnostic::new(Error::ParameterMissingDefaultValue {
+            parameter_identifier: m.identifier().to_owned(),
+        })
+        .set_span(m.span())
+        .add_note(
+            format!("parameter '{}' has a default value", first_defaulted.identifier()),
+            Some(first_defaulted.span()),
+        )
+        .push_into(diagnostics);
+    }
 }
 
 fn at_most_one_stream_parameter(members: &[&Parameter], diagnostics: &mut Diagnostics) {
@@ -0,0 +1,16 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::Diagnostics;
+use crate::slice_options::SliceOptions;
+
+/// Runs each of the validator plugins provided by the embedder, in order.
+/// See [ValidatorPlugin](crate::slice_options::ValidatorPlugin) for more information.
+pub(crate) fn run_validator_plugins(state: &mut CompilationState, options: &SliceOptions) {
+    for plugin in &options.validator_plugins {
+        // Plugins can't mutate the compilation state, so they report into a separate container, which we then merge.
+        let mut diagnostics = Diagnostics::new();
+        (plugin.validate)(state, &mut diagnostics);
+        state.diagnostics.extend(diagnostics);
+    }
+}
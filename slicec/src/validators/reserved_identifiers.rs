@@ -0,0 +1,62 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostic, Error, Lint};
+use crate::grammar::*;
+use crate::slice_options::SliceOptions;
+
+/// Reports an error for each identifier which collides with an identifier reserved by a code generator.
+/// See [ReservedIdentifier](crate::slice_options::ReservedIdentifier) for more information.
+pub(crate) fn check_reserved_identifiers(state: &mut CompilationState, options: &SliceOptions) {
+    if options.reserved_identifiers.is_empty() {
+        return;
+    }
+
+    for node in state.ast.as_slice() {
+        let Ok(symbol) = <&dyn NamedSymbol>::try_from(node) else { continue };
+        let identifier = symbol.raw_identifier();
+
+        // Nested module declarations (`module Foo::Bar`) are checked one segment at a time.
+        for segment in identifier.value.split("::") {
+            let reserved = options.reserved_identifiers.iter().find(|reserved| reserved.matches(segment));
+            if let Some(reserved) = reserved {
+                let mut diagnostic = Diagnostic::new(Error::ReservedIdentifier {
+                    identifier: segment.to_owned(),
+                    reserved_by: reserved.reserved_by.clone(),
+                })
+                .set_span(identifier.span());
+                if reserved.is_prefix {
+                    let message = format!("identifiers starting with '{}' are reserved", reserved.identifier);
+                    diagnostic = diagnostic.add_note(message, None);
+                }
+                diagnostic.push_into(&mut state.diagnostics);
+            }
+        }
+    }
+}
+
+/// Reports a lint for each identifier which collides with a keyword of a language that code is generated for.
+/// See [TargetKeywords](crate::slice_options::TargetKeywords) for more information.
+pub(crate) fn check_keyword_collisions(state: &mut CompilationState, options: &SliceOptions) {
+    if options.target_keywords.is_empty() {
+        return;
+    }
+
+    for node in state.ast.as_slice() {
+        let Ok(symbol) = <&dyn NamedSymbol>::try_from(node) else { continue };
+        let identifier = symbol.raw_identifier();
+
+        // Nested module declarations (`module Foo::Bar`) are checked one segment at a time.
+        for segment in identifier.value.split("::") {
+            for target in options.target_keywords.iter().filter(|target| target.contains(segment)) {
+                Diagnostic::new(Lint::KeywordCollision {
+                    identifier: segment.to_owned(),
+                    language: target.language.clone(),
+                })
+                .set_span(identifier.span())
+                .set_scope(symbol.parser_scoped_identifier())
+                .push_into(&mut state.diagnostics);
+            }
+        }
+    }
+}
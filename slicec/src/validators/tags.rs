@@ -0,0 +1,117 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::ast::node::Node;
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostic, Diagnostics, Error, Lint};
+use crate::grammar::attributes::{format_tag_range, ReservedTags};
+use crate::grammar::*;
+use crate::slice_file::Span;
+use crate::slice_options::SliceOptions;
+use std::ops::RangeInclusive;
+
+/// Validates the tags of the provided members: reports an error for each tag that is outside the range supported by
+/// the provided encoding, and for each tag that was reserved by the members' container.
+pub fn validate_tags(
+    container: &dyn Entity,
+    members: Vec<&impl Member>,
+    encoding: Encoding,
+    diagnostics: &mut Diagnostics,
+) {
+    let tag_range = encoding.tag_range();
+    let reserved_ranges = reserved_ranges(container);
+    for member in members {
+        let Some(raw_tag) = member.raw_tag() else { continue };
+        let tag = raw_tag.value;
+
+        if !tag_range.contains(&tag) {
+            Diagnostic::new(Error::TagValueOutOfBounds)
+                .set_span(&raw_tag.span)
+                .push_into(diagnostics);
+            continue;
+        }
+
+        if let Some((range, span)) = reserved_ranges.iter().find(|(range, _)| range.contains(&tag)) {
+            Diagnostic::new(Error::TagIsReserved {
+                identifier: member.identifier().to_owned(),
+                tag,
+            })
+            .set_span(member.span())
+            .add_note(format!("tags '{}' are reserved here", format_tag_range(range)), Some(span))
+            .push_into(diagnostics);
+        }
+    }
+}
+
+/// Reports a lint for each range of tags which is skipped over by a type's tagged members, but isn't reserved.
+/// Only the tags between the first and last tags in use are checked. This is only run if `--audit-tags` was set.
+pub(crate) fn audit_tags(state: &mut CompilationState, options: &SliceOptions) {
+    if !options.audit_tags {
+        return;
+    }
+
+    let diagnostics = &mut state.diagnostics;
+    for node in state.ast.as_slice() {
+        match node {
+            Node::Struct(struct_ptr) => audit_members(struct_ptr.borrow(), struct_ptr.borrow().fields(), diagnostics),
+            Node::Class(class_ptr) => audit_members(class_ptr.borrow(), class_ptr.borrow().fields(), diagnostics),
+            Node::Exception(exception_ptr) => {
+                let exception_def = exception_ptr.borrow();
+                audit_members(exception_def, exception_def.fields(), diagnostics);
+            }
+            Node::Enumerator(enumerator_ptr) => {
+                let enumerator = enumerator_ptr.borrow();
+                audit_members(enumerator, enumerator.fields(), diagnostics);
+            }
+            Node::Operation(operation_ptr) => {
+                // Parameters and return members are tagged independently of each other.
+                let operation = operation_ptr.borrow();
+                audit_members(operation, operation.parameters(), diagnostics);
+                audit_members(operation, operation.return_members(), diagnostics);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn audit_members(container: &dyn Entity, members: Vec<&impl Member>, diagnostics: &mut Diagnostics) {
+    let reserved_ranges = reserved_ranges(container);
+    let mut tagged_members = members
+        .into_iter()
+        .filter_map(|member| member.tag().map(|tag| (tag, member)))
+        .collect::<Vec<_>>();
+    tagged_members.sort_by_key(|(tag, _)| *tag);
+
+    for window in tagged_members.windows(2) {
+        let ((previous_tag, _), (next_tag, member)) = (window[0], window[1]);
+
+        // Walk through the skipped tags, jumping over any reserved ranges, and report the tags between them.
+        let mut tag = previous_tag.saturating_add(1);
+        while tag < next_tag {
+            if let Some((range, _)) = reserved_ranges.iter().find(|(range, _)| range.contains(&tag)) {
+                tag = range.end().saturating_add(1).max(tag + 1);
+                continue;
+            }
+
+            let next_reserved = reserved_ranges.iter().map(|(range, _)| *range.start()).filter(|start| *start > tag);
+            let gap_end = next_reserved.min().unwrap_or(next_tag).min(next_tag);
+            Diagnostic::new(Lint::UnreservedTagGap {
+                tags: format_tag_range(&(tag..=gap_end - 1)),
+            })
+            .set_span(member.span())
+            .set_scope(member.parser_scoped_identifier())
+            .push_into(diagnostics);
+            tag = gap_end;
+        }
+    }
+}
+
+/// Returns the ranges of tags reserved by the `reservedTags` attributes on the provided entity, along with the span of
+/// the attribute which reserved them.
+fn reserved_ranges(entity: &dyn Entity) -> Vec<(&RangeInclusive<u32>, &Span)> {
+    entity
+        .attributes()
+        .into_iter()
+        .filter_map(|attribute| Some((attribute.downcast::<ReservedTags>()?, attribute.span())))
+        .flat_map(|(reserved_tags, span)| reserved_tags.ranges.iter().map(move |range| (range, span)))
+        .collect()
+}
@@ -0,0 +1,74 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::grammar::*;
+use crate::slice_file::Span;
+use crate::slice_options::{DeniedType, SliceOptions};
+use crate::visitor::Visitor;
+
+/// Reports an error for each use of a type or construct that was denied with `--deny-type`.
+pub(crate) fn check_type_policy(state: &mut CompilationState, options: &SliceOptions) {
+    if options.denied_types.is_empty() {
+        return;
+    }
+
+    let mut checker = TypePolicyChecker {
+        denied_types: &options.denied_types,
+        diagnostics: &mut state.diagnostics,
+    };
+    for slice_file in &state.files {
+        slice_file.visit_with(&mut checker);
+    }
+}
+
+struct TypePolicyChecker<'a> {
+    denied_types: &'a [DeniedType],
+    diagnostics: &'a mut Diagnostics,
+}
+
+impl TypePolicyChecker<'_> {
+    fn check(&mut self, name: &str, span: &Span) {
+        if let Some(denied_type) = self.denied_types.iter().find(|denied| denied.name == name) {
+            let error = Error::DeniedTypeUsed {
+                name: name.to_owned(),
+                policy_code: denied_type.code.clone(),
+            };
+            let mut diagnostic = Diagnostic::new(error).set_span(span);
+            if let Some(reason) = &denied_type.reason {
+                diagnostic = diagnostic.add_note(reason, None);
+            }
+            diagnostic.push_into(self.diagnostics);
+        }
+    }
+}
+
+impl Visitor for TypePolicyChecker<'_> {
+    fn visit_enum(&mut self, enum_def: &Enum) {
+        if enum_def.is_unchecked {
+            self.check("unchecked-enum", enum_def.span());
+        }
+        if let Some(underlying) = &enum_def.underlying {
+            self.check(underlying.definition().kind(), underlying.span());
+        }
+    }
+
+    fn visit_constant(&mut self, constant: &Constant) {
+        if let Some(data_type) = &constant.data_type {
+            self.check(data_type.definition().kind(), data_type.span());
+        }
+    }
+
+    fn visit_type_ref(&mut self, type_ref: &TypeRef) {
+        let name = match type_ref.concrete_type() {
+            Types::Primitive(primitive) => primitive.kind(),
+            Types::Sequence(_) => "Sequence",
+            Types::Dictionary(_) => "Dictionary",
+            Types::ResultType(_) => "Result",
+            Types::CustomType(_) => "custom",
+            Types::Class(_) => "class",
+            Types::Struct(_) | Types::Enum(_) => return,
+        };
+        self.check(name, type_ref.span());
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::ast::node::Node;
+use crate::ast::Ast;
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{get_lint_level, Diagnostic, DiagnosticLevel, Lint};
+use crate::grammar::*;
+use crate::slice_options::SliceOptions;
+use crate::visitor::Visitor;
+use std::collections::HashSet;
+
+/// Reports a lint for each type, exception, and constant in the source files which is never used.
+///
+/// Definitions are used if they can be reached from an interface, by following the types of parameters, return members,
+/// fields, bases, and thrown exceptions, along with any constants referenced by constant expressions. Definitions in
+/// reference files, and definitions where this lint is allowed (Ex: `[allow(UnusedDefinition)]`), are always considered
+/// used, since they may be used by other projects. This is only run if `--report-unused` was set.
+pub(crate) fn check_unused_definitions(state: &mut CompilationState, options: &SliceOptions) {
+    if !options.report_unused {
+        return;
+    }
+
+    // Sort the definitions which can be unused into candidates (which are reported if they're never used), and roots
+    // (which are always used). Interfaces are also roots, since they're the public surface of a Slice project.
+    let mut candidates = Vec::new();
+    let mut pending = Vec::new();
+    for node in state.ast.as_slice() {
+        let entity: &dyn Entity = match node {
+            Node::Struct(ptr) => ptr.borrow(),
+            Node::Class(ptr) => ptr.borrow(),
+            Node::Exception(ptr) => ptr.borrow(),
+            Node::Enum(ptr) => ptr.borrow(),
+            Node::CustomType(ptr) => ptr.borrow(),
+            Node::Constant(ptr) => ptr.borrow(),
+            Node::Interface(ptr) => {
+                pending.push(ptr.borrow().parser_scoped_identifier());
+                continue;
+            }
+            _ => continue,
+        };
+
+        let file = state.files.iter().find(|file| file.relative_path == entity.span().file);
+        let lint = unused_definition_lint(entity);
+        let is_allowed = get_lint_level(&lint, file, Some(entity), options) == DiagnosticLevel::Allowed;
+        match file.is_some_and(|file| file.is_source) && !is_allowed {
+            true => candidates.push((entity, lint)),
+            false => pending.push(entity.parser_scoped_identifier()),
+        }
+    }
+
+    // Walk through every definition that's reachable from the roots, marking each of them as used.
+    let mut used = pending.iter().cloned().collect::<HashSet<_>>();
+    while let Some(identifier) = pending.pop() {
+        let Ok(entity) = state.ast.find_element::<dyn Entity>(&identifier) else { continue };
+
+        let mut collector = ReferenceCollector::new(&state.ast);
+        match entity.concrete_entity() {
+            Entities::Struct(struct_def) => struct_def.visit_with(&mut collector),
+            Entities::Class(class_def) => class_def.visit_with(&mut collector),
+            Entities::Exception(exception_def) => exception_def.visit_with(&mut collector),
+            Entities::Interface(interface_def) => interface_def.visit_with(&mut collector),
+            Entities::Enum(enum_def) => enum_def.visit_with(&mut collector),
+            Entities::Constant(constant) => constant.visit_with(&mut collector),
+            _ => {}
+        }
+        for reference in collector.references {
+            if used.insert(reference.clone()) {
+                pending.push(reference);
+            }
+        }
+    }
+
+    for (entity, lint) in candidates {
+        if !used.contains(&entity.parser_scoped_identifier()) {
+            Diagnostic::new(lint)
+                .set_span(entity.raw_identifier().span())
+                .set_scope(entity.parser_scoped_identifier())
+                .push_into(&mut state.diagnostics);
+        }
+    }
+}
+
+fn unused_definition_lint(entity: &dyn Entity) -> Lint {
+    Lint::UnusedDefinition {
+        kind: entity.kind().to_owned(),
+        identifier: entity.identifier().to_owned(),
+    }
+}
+
+/// A visitor which collects the (parser scoped) identifiers of every definition referenced by the elements it visits.
+struct ReferenceCollector<'a> {
+    ast: &'a Ast,
+    references: Vec<String>,
+}
+
+impl<'a> ReferenceCollector<'a> {
+    fn new(ast: &'a Ast) -> Self {
+        ReferenceCollector {
+            ast,
+            references: Vec::new(),
+        }
+    }
+
+    fn add_constant_reference(&mut self, identifier: &Identifier, scope: &str) {
+        if let Ok(constant) = self.ast.find_element_with_scope::<Constant>(&identifier.value, scope) {
+            self.references.push(constant.parser_scoped_identifier());
+        }
+    }
+
+    fn add_expression_references(&mut self, expression: &Expression, scope: &str) {
+        match &expression.kind {
+            ExpressionKind::Literal(_) => {}
+            ExpressionKind::Reference(identifier) => self.add_constant_reference(identifier, scope),
+            ExpressionKind::Unary(_, operand) => self.add_expression_references(operand, scope),
+            ExpressionKind::Binary(lhs, _, rhs) => {
+                self.add_expression_references(lhs, scope);
+                self.add_expression_references(rhs, scope);
+            }
+        }
+    }
+}
+
+impl<'a> Visitor for ReferenceCollector<'a> {
+    fn visit_class(&mut self, class_def: &Class) {
+        if let Some(base) = &class_def.base {
+            self.references.push(base.definition().parser_scoped_identifier());
+        }
+    }
+
+    fn visit_exception(&mut self, exception_def: &Exception) {
+        if let Some(base) = &exception_def.base {
+            self.references.push(base.definition().parser_scoped_identifier());
+        }
+    }
+
+    fn visit_interface(&mut self, interface_def: &Interface) {
+        for base in &interface_def.bases {
+            self.references.push(base.definition().parser_scoped_identifier());
+        }
+    }
+
+    fn visit_operation(&mut self, operation: &Operation) {
+        for exception in &operation.exception_specification {
+            self.references.push(exception.definition().parser_scoped_identifier());
+        }
+    }
+
+    fn visit_constant(&mut self, constant: &Constant) {
+        // Constants declared inside a type are used whenever that type is.
+        self.references.push(constant.parser_scoped_identifier());
+        self.add_expression_references(&constant.expression, constant.parser_scope());
+    }
+
+    fn visit_enumerator(&mut self, enumerator: &Enumerator) {
+        if let EnumeratorValue::Constant(identifier, _) = &enumerator.value {
+            self.add_constant_reference(identifier, enumerator.parser_scope());
+        }
+    }
+
+    fn visit_type_ref(&mut self, type_ref: &TypeRef) {
+        let definition: &dyn Entity = match type_ref.concrete_type() {
+            Types::Struct(struct_def) => struct_def,
+            Types::Class(class_def) => class_def,
+            Types::Enum(enum_def) => enum_def,
+            Types::CustomType(custom_type) => custom_type,
+            _ => return,
+        };
+        self.references.push(definition.parser_scoped_identifier());
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::diagnostics::{Diagnostic, Diagnostics, Error};
+use crate::grammar::*;
+use crate::slice_file::Span;
+
+/// Checks that the provided entity doesn't expose any internal types, if it's public.
+///
+/// Public definitions expose the types of their fields, parameters, and return members, along with their base types
+/// (and for type aliases, their underlying types). Types nested within sequences, dictionaries, and results are
+/// exposed too.
+pub fn validate_visibility(entity: &dyn Entity, diagnostics: &mut Diagnostics) {
+    // Each exposed internal type is stored alongside the span of the type reference which exposed it.
+    let mut exposed_types: Vec<(&dyn Entity, &Span)> = Vec::new();
+    match entity.concrete_entity() {
+        Entities::Struct(struct_def) if struct_def.visibility == Visibility::Public => {
+            find_internal_types_in_fields(struct_def.fields(), &mut exposed_types);
+        }
+        Entities::Class(class_def) if class_def.visibility == Visibility::Public => {
+            if let Some(base) = &class_def.base {
+                check_named_type(base.definition(), base.definition().visibility, base.span(), &mut exposed_types);
+            }
+            find_internal_types_in_fields(class_def.fields(), &mut exposed_types);
+        }
+        Entities::Exception(exception_def) if exception_def.visibility == Visibility::Public => {
+            if let Some(base) = &exception_def.base {
+                check_named_type(base.definition(), base.definition().visibility, base.span(), &mut exposed_types);
+            }
+            find_internal_types_in_fields(exception_def.fields(), &mut exposed_types);
+        }
+        Entities::Interface(interface_def) if interface_def.visibility == Visibility::Public => {
+            for base in &interface_def.bases {
+                check_named_type(base.definition(), base.definition().visibility, base.span(), &mut exposed_types);
+            }
+            for operation in interface_def.operations() {
+                for member in operation.parameters_and_return_members() {
+                    find_internal_types(&member.data_type, member.data_type.span(), &mut exposed_types);
+                }
+                for exception in &operation.exception_specification {
+                    let definition = exception.definition();
+                    check_named_type(definition, definition.visibility, exception.span(), &mut exposed_types);
+                }
+                if let Some(error_type) = &operation.error_type {
+                    find_internal_types(error_type, error_type.span(), &mut exposed_types);
+                }
+            }
+        }
+        Entities::Enum(enum_def) if enum_def.visibility == Visibility::Public => {
+            for enumerator in enum_def.enumerators() {
+                find_internal_types_in_fields(enumerator.contents(), &mut exposed_types);
+            }
+        }
+        Entities::TypeAlias(type_alias) if type_alias.visibility == Visibility::Public => {
+            find_internal_types(&type_alias.underlying, type_alias.underlying.span(), &mut exposed_types);
+        }
+        _ => {}
+    }
+
+    for (internal_type, span) in exposed_types {
+        Diagnostic::new(Error::ExposesInternalType {
+            kind: entity.kind().to_owned(),
+            identifier: entity.identifier().to_owned(),
+            type_identifier: internal_type.identifier().to_owned(),
+        })
+        .set_span(span)
+        .add_note(
+            format!("'{}' is declared as internal here", internal_type.identifier()),
+            Some(internal_type.span()),
+        )
+        .push_into(diagnostics);
+    }
+}
+
+fn find_internal_types_in_fields<'a>(fields: Vec<&'a Field>, exposed_types: &mut Vec<(&'a dyn Entity, &'a Span)>) {
+    for field in fields {
+        find_internal_types(&field.data_type, field.data_type.span(), exposed_types);
+    }
+}
+
+/// Checks whether the provided type reference (or any of the types nested within it) refers to an internal type.
+/// Any internal types are reported with the span of the type reference that exposed them, even when nested.
+fn find_internal_types<'a>(
+    type_ref: &'a TypeRef,
+    span: &'a Span,
+    exposed_types: &mut Vec<(&'a dyn Entity, &'a Span)>,
+) {
+    match type_ref.definition().concrete_type() {
+        Types::Struct(struct_def) => check_named_type(struct_def, struct_def.visibility, span, exposed_types),
+        Types::Class(class_def) => check_named_type(class_def, class_def.visibility, span, exposed_types),
+        Types::Enum(enum_def) => check_named_type(enum_def, enum_def.visibility, span, exposed_types),
+        Types::CustomType(custom_type) => check_named_type(custom_type, custom_type.visibility, span, exposed_types),
+        Types::ResultType(result_type) => {
+            find_internal_types(&result_type.success_type, span, exposed_types);
+            find_internal_types(&result_type.failure_type, span, exposed_types);
+        }
+        Types::Sequence(sequence) => find_internal_types(&sequence.element_type, span, exposed_types),
+        Types::Dictionary(dictionary) => {
+            find_internal_types(&dictionary.key_type, span, exposed_types);
+            find_internal_types(&dictionary.value_type, span, exposed_types);
+        }
+        Types::Primitive(_) => {}
+    }
+}
+
+fn check_named_type<'a>(
+    definition: &'a dyn Entity,
+    visibility: Visibility,
+    span: &'a Span,
+    exposed_types: &mut Vec<(&'a dyn Entity, &'a Span)>,
+) {
+    if visibility == Visibility::Internal {
+        exposed_types.push((definition, span));
+    }
+}
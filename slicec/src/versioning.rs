@@ -0,0 +1,133 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module implements versioned schemas, which let a single set of Slice files describe multiple versions of a
+//! protocol, instead of needing a separate copy of the files for each version.
+//!
+//! Elements are marked with the version they were added in using the [since](Since) attribute, and with the version
+//! they were removed in using the [removed](Removed) attribute. Elements without these attributes are available in
+//! every version. A [VersionedView] can then be used to see only the elements that are available in a specific version.
+
+use crate::ast::query::Query;
+use crate::ast::Ast;
+use crate::grammar::attributes::{Removed, Since};
+use crate::grammar::*;
+use std::fmt;
+
+/// A schema version, made of a major, minor, and patch number. Versions are written as 1 to 3 numbers separated by
+/// '.'; any numbers that are omitted are 0. Ex: `"2.1"` is the same as `"2.1.0"`.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Version { major, minor, patch }
+    }
+
+    /// Parses a version from the provided string, returning `None` if it isn't a valid version.
+    pub fn parse(s: &str) -> Option<Self> {
+        let components = s.split('.').collect::<Vec<_>>();
+        let is_number = |component: &&str| !component.is_empty() && component.chars().all(|c| c.is_ascii_digit());
+        if components.len() > 3 || !components.iter().all(is_number) {
+            return None;
+        }
+
+        let mut numbers = [0; 3];
+        for (number, component) in numbers.iter_mut().zip(components) {
+            *number = component.parse().ok()?;
+        }
+        Some(Version::new(numbers[0], numbers[1], numbers[2]))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The range of versions that an element is available in. This includes the version it was added in (if specified),
+/// and excludes the version it was removed in (if specified).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VersionRange {
+    pub since: Option<Version>,
+    pub removed: Option<Version>,
+}
+
+impl VersionRange {
+    /// Returns the range of versions that the provided element is available in, based on its own attributes.
+    /// This doesn't take into account the availability of the element's parents. See [VersionedView::contains].
+    pub fn of<T: Attributable + ?Sized>(element: &T) -> Self {
+        let since = element.find_attribute::<Since>().and_then(|since| Version::parse(&since.version));
+        let removed = element.find_attribute::<Removed>().and_then(|removed| Version::parse(&removed.version));
+        VersionRange { since, removed }
+    }
+
+    /// Returns true if the provided version is within this range.
+    pub fn contains(&self, version: &Version) -> bool {
+        self.since.is_none_or(|since| since <= *version) && self.removed.is_none_or(|removed| *version < removed)
+    }
+}
+
+/// A view of an [Ast] which only includes the elements that are available in a specific version.
+///
+/// The underlying AST isn't modified. Instead, code generators should pass any elements they're generating code for
+/// through the view (with [filter](VersionedView::filter) or [contains](VersionedView::contains)), so any unavailable
+/// elements are skipped. For example, to get the fields of a struct in version 2:
+///
+/// ```
+/// # use slicec::ast::Ast;
+/// # use slicec::grammar::*;
+/// # use slicec::versioning::{Version, VersionedView};
+/// # fn example(ast: &Ast, struct_def: &Struct) {
+/// let view = VersionedView::new(ast, Version::new(2, 0, 0));
+/// let fields = view.filter(struct_def.fields());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct VersionedView<'a> {
+    ast: &'a Ast,
+    version: Version,
+}
+
+impl<'a> VersionedView<'a> {
+    pub fn new(ast: &'a Ast, version: Version) -> Self {
+        VersionedView { ast, version }
+    }
+
+    /// Returns the version that this view is for.
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Returns true if the provided entity is available in this view's version. An entity is only available if it and
+    /// all of its parents are available. Ex: a parameter is unavailable if its operation was removed.
+    pub fn contains<T: Entity + ?Sized>(&self, entity: &T) -> bool {
+        if !VersionRange::of(entity).contains(&self.version) {
+            return false;
+        }
+
+        match entity.concrete_entity() {
+            Entities::Field(field) => self.contains(field.parent()),
+            Entities::Operation(operation) => self.contains(operation.parent()),
+            Entities::Parameter(parameter) => self.contains(parameter.parent()),
+            Entities::Enumerator(enumerator) => self.contains(enumerator.parent()),
+            _ => true,
+        }
+    }
+
+    /// Returns only the provided elements which are available in this view's version, preserving their order.
+    pub fn filter<'b, T: Entity + ?Sized>(&self, elements: Vec<&'b T>) -> Vec<&'b T> {
+        elements.into_iter().filter(|element| self.contains(*element)).collect()
+    }
+
+    /// Returns a query over the entities of the underlying AST which are available in this view's version.
+    pub fn query(&self) -> Query<'a, dyn Entity + 'a> {
+        let version = self.version;
+        let ast = self.ast;
+        ast.query().filter(move |entity| VersionedView::new(ast, version).contains(entity))
+    }
+}
@@ -13,12 +13,22 @@ use crate::slice_file::SliceFile;
 /// These methods are purely for the visitor's use, and shouldn't be called directly.
 /// To actually visit an element, call `visit_with` on the element.
 ///
-/// When a container is visited, first its `visit_x` method is called, then its
-/// contents are recursively visited.
+/// When a container is visited, first its `visit_x` method is called, then its contents are recursively visited,
+/// and finally its `visit_x_end` method is called.
 /// For example, calling `visit_with` on an interface containing only a single operation would invoke:
 /// - visit_interface
 ///     - visit_operation
+///         - visit_operation_modifier (called once per modifier, in the order they're listed)
 ///         - visit_parameter (called once per parameter, in the order they're listed)
+///     - visit_operation_end
+/// - visit_interface_end
+///
+/// The traversal order is deterministic: elements are always visited in the order they were defined in, and an
+/// element's contents are always visited in the order they're listed in their container. The only exception to this
+/// is operations, whose parameters are all visited before any of their return members.
+///
+/// To skip over some elements, call `walk_with` instead of `visit_with`. It takes a filter, which is called on each
+/// [Entity] before visiting it. If the filter returns `false`, that entity and all of its contents are skipped.
 #[allow(unused_variables)] // Keep parameter names for doc generation, even if not used in the default implementations.
 pub trait Visitor {
     /// This function is called by the visitor when it begins visiting a slice file,
@@ -27,47 +37,102 @@ pub trait Visitor {
     /// This shouldn't be called by users. To visit a slice file, use `[SliceFile::visit_with]`.
     fn visit_file(&mut self, slice_file: &SliceFile) {}
 
-    /// This function is called by the visitor when it visits a [Module],
+    /// This function is called by the visitor when it finishes visiting a slice file,
+    /// after it has visited through the file's contents.
+    ///
+    /// This shouldn't be called by users. To visit a slice file, use `[SliceFile::visit_with]`.
+    fn visit_file_end(&mut self, slice_file: &SliceFile) {}
+
+    /// This function is called by the visitor when it begins visiting a [Module],
+    /// before it visits through the definitions declared in the module.
     ///
-    /// This shouldn't be called by users. To visit a module, use `[Module::visit_with]`.
+    /// This shouldn't be called by users. To visit a module, use `[SliceFile::visit_with]`.
     fn visit_module(&mut self, module_def: &Module) {}
 
+    /// This function is called by the visitor when it finishes visiting a [Module],
+    /// after it has visited through the definitions declared in the module.
+    ///
+    /// This shouldn't be called by users. To visit a module, use `[SliceFile::visit_with]`.
+    fn visit_module_end(&mut self, module_def: &Module) {}
+
     /// This function is called by the visitor when it begins visiting a [Struct],
     /// before it visits through the struct's contents.
     ///
     /// This shouldn't be called by users. To visit a struct, use `[Struct::visit_with]`.
     fn visit_struct(&mut self, struct_def: &Struct) {}
 
+    /// This function is called by the visitor when it finishes visiting a [Struct],
+    /// after it has visited through the struct's contents.
+    ///
+    /// This shouldn't be called by users. To visit a struct, use `[Struct::visit_with]`.
+    fn visit_struct_end(&mut self, struct_def: &Struct) {}
+
     /// This function is called by the visitor when it begins visiting a [Class],
     /// before it visits through the class' contents.
     ///
     /// This shouldn't be called by users. To visit a class, use `[Class::visit_with]`.
     fn visit_class(&mut self, class_def: &Class) {}
 
+    /// This function is called by the visitor when it finishes visiting a [Class],
+    /// after it has visited through the class' contents.
+    ///
+    /// This shouldn't be called by users. To visit a class, use `[Class::visit_with]`.
+    fn visit_class_end(&mut self, class_def: &Class) {}
+
     /// This function is called by the visitor when it begins visiting an [Exception],
     /// before it visits through the exception's contents.
     ///
     /// This shouldn't be called by users. To visit an exception, use `[Exception::visit_with]`.
     fn visit_exception(&mut self, exception_def: &Exception) {}
 
+    /// This function is called by the visitor when it finishes visiting an [Exception],
+    /// after it has visited through the exception's contents.
+    ///
+    /// This shouldn't be called by users. To visit an exception, use `[Exception::visit_with]`.
+    fn visit_exception_end(&mut self, exception_def: &Exception) {}
+
     /// This function is called by the visitor when it begins visiting an [Interface],
     /// before it visits through the interface's contents.
     ///
     /// This shouldn't be called by users. To visit an interface, use `[Interface::visit_with]`.
     fn visit_interface(&mut self, interface_def: &Interface) {}
 
+    /// This function is called by the visitor when it finishes visiting an [Interface],
+    /// after it has visited through the interface's contents.
+    ///
+    /// This shouldn't be called by users. To visit an interface, use `[Interface::visit_with]`.
+    fn visit_interface_end(&mut self, interface_def: &Interface) {}
+
     /// This function is called by the visitor when it begins visiting an [Enum],
     /// before it visits through the enum's contents.
     ///
     /// This shouldn't be called by users. To visit an enum, use `[Enum::visit_with]`.
     fn visit_enum(&mut self, enum_def: &Enum) {}
 
+    /// This function is called by the visitor when it finishes visiting an [Enum],
+    /// after it has visited through the enum's contents.
+    ///
+    /// This shouldn't be called by users. To visit an enum, use `[Enum::visit_with]`.
+    fn visit_enum_end(&mut self, enum_def: &Enum) {}
+
     /// This function is called by the visitor when it begins visiting an [Operation],
     /// before it visits through the operation's contents.
     ///
     /// This shouldn't be called by users. To visit an operation, use `[Operation::visit_with]`.
     fn visit_operation(&mut self, operation: &Operation) {}
 
+    /// This function is called by the visitor when it finishes visiting an [Operation],
+    /// after it has visited through the operation's contents.
+    ///
+    /// This shouldn't be called by users. To visit an operation, use `[Operation::visit_with]`.
+    fn visit_operation_end(&mut self, operation: &Operation) {}
+
+    /// This function is called by the visitor for each modifier applied to an [Operation], after `visit_operation`,
+    /// but before the operation's parameters and return members are visited.
+    ///
+    /// This shouldn't be called by users. To visit an operation, use `[Operation::visit_with]`.
+    fn visit_operation_modifier(&mut self, modifier: &OperationModifier) {}
+
     /// This function is called by the visitor when it visits a [CustomType],
     ///
     /// This shouldn't be called by users. To visit a custom type, use `[CustomType::visit_with]`.
@@ -78,6 +143,11 @@ pub trait Visitor {
     /// This shouldn't be called by users. To visit a type alias, use `[TypeAlias::visit_with]`.
     fn visit_type_alias(&mut self, type_alias: &TypeAlias) {}
 
+    /// This function is called by the visitor when it visits a [Constant],
+    ///
+    /// This shouldn't be called by users. To visit a constant, use `[Constant::visit_with]`.
+    fn visit_constant(&mut self, constant: &Constant) {}
+
     /// This function is called by the visitor when it visits a [Field],
     ///
     /// This shouldn't be called by users. To visit a field, use `[Field::visit_with]`.
@@ -93,6 +163,12 @@ pub trait Visitor {
     /// This shouldn't be called by users. To visit an enumerator, use `[Enumerator::visit_with]`.
     fn visit_enumerator(&mut self, enumerator: &Enumerator) {}
 
+    /// This function is called by the visitor when it finishes visiting an [Enumerator],
+    /// after it has visited through the enumerator's contents.
+    ///
+    /// This shouldn't be called by users. To visit an enumerator, use `[Enumerator::visit_with]`.
+    fn visit_enumerator_end(&mut self, enumerator: &Enumerator) {}
+
     // TODO: This can probably be improved after splitting `TypeRef`. See https://github.com/icerpc/slicec/issues/452.
     /// This function is called by the visitor when it visits a [TypeRef].
     ///
@@ -103,35 +179,73 @@ pub trait Visitor {
 impl SliceFile {
     /// Visits the [SliceFile] with the provided `visitor`.
     ///
-    /// This function first calls `visitor.visit_file`, then if the file contains a module declaration it calls
-    /// `visitor.visit_module`, and finally it recursively visits any definitions defined in the file.
+    /// This function first calls `visitor.visit_file`, then visits each module declared in the file (in declaration
+    /// order), and finally calls `visitor.visit_file_end`. Each module is visited by calling `visitor.visit_module`,
+    /// then recursively visiting the definitions declared in that module, and then calling `visitor.visit_module_end`.
+    ///
+    /// Definitions which aren't declared in any module (which is an error) are visited before any of the modules.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [SliceFile] with the provided `visitor`, skipping any entities that `filter` returns `false` for.
+    ///
+    /// The file itself and its module declarations aren't entities, and so are always visited.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
         visitor.visit_file(self);
 
-        if let Some(module_def) = &self.module {
-            module_def.borrow().visit_with(visitor);
+        let is_in_module = |definition: &Definition, module: Option<&Module>| {
+            let definition_module = definition.borrow().get_raw_scope().module.as_ref();
+            match (definition_module, module) {
+                (Some(definition_module), Some(module)) => std::ptr::eq(definition_module.borrow(), module),
+                (definition_module, module) => definition_module.is_none() && module.is_none(),
+            }
+        };
+
+        for definition in self.contents.iter().filter(|d| is_in_module(d, None)) {
+            definition.walk_with(visitor, filter);
         }
 
-        for definition in &self.contents {
-            match definition {
-                Definition::Struct(struct_def) => struct_def.borrow().visit_with(visitor),
-                Definition::Class(class_def) => class_def.borrow().visit_with(visitor),
-                Definition::Exception(exception_def) => exception_def.borrow().visit_with(visitor),
-                Definition::Interface(interface_def) => interface_def.borrow().visit_with(visitor),
-                Definition::Enum(enum_def) => enum_def.borrow().visit_with(visitor),
-                Definition::CustomType(custom_type) => custom_type.borrow().visit_with(visitor),
-                Definition::TypeAlias(type_alias) => type_alias.borrow().visit_with(visitor),
+        for module_ptr in &self.modules {
+            let module_def = module_ptr.borrow();
+            visitor.visit_module(module_def);
+            for definition in self.contents.iter().filter(|d| is_in_module(d, Some(module_def))) {
+                definition.walk_with(visitor, filter);
             }
+            visitor.visit_module_end(module_def);
         }
+
+        visitor.visit_file_end(self);
     }
 }
 
 impl Module {
     /// Visits the [Module] with the provided `visitor`.
     ///
-    /// This function delegates to `visitor.visit_module`.
+    /// This function calls `visitor.visit_module`, and then `visitor.visit_module_end`. Modules don't own the
+    /// definitions declared in them (since a module can be declared in multiple places), so these aren't visited.
+    /// To visit a module along with its definitions, use [SliceFile::visit_with].
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
         visitor.visit_module(self);
+        visitor.visit_module_end(self);
+    }
+}
+
+impl Definition {
+    /// Visits the [Definition] with the provided `visitor`, skipping any entities that `filter` returns `false` for.
+    ///
+    /// This function delegates to the `walk_with` function of the underlying definition.
+    fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        match self {
+            Definition::Struct(struct_def) => struct_def.borrow().walk_with(visitor, filter),
+            Definition::Class(class_def) => class_def.borrow().walk_with(visitor, filter),
+            Definition::Exception(exception_def) => exception_def.borrow().walk_with(visitor, filter),
+            Definition::Interface(interface_def) => interface_def.borrow().walk_with(visitor, filter),
+            Definition::Enum(enum_def) => enum_def.borrow().walk_with(visitor, filter),
+            Definition::CustomType(custom_type) => custom_type.borrow().walk_with(visitor, filter),
+            Definition::TypeAlias(type_alias) => type_alias.borrow().walk_with(visitor, filter),
+            Definition::Constant(constant) => constant.borrow().walk_with(visitor, filter),
+        }
     }
 }
 
@@ -139,12 +253,28 @@ impl Struct {
     /// Visits the [Struct] with the provided `visitor`.
     ///
     /// This function first calls `visitor.visit_struct`, then recursively visits
-    /// the contents of the struct.
+    /// the contents of the struct, and finally calls `visitor.visit_struct_end`.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [Struct] with the provided `visitor`, skipping any entities that `filter` returns `false` for.
+    ///
+    /// If `filter` returns `false` for this struct, none of its `visit_x` methods are called,
+    /// and none of its contents are visited.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
         visitor.visit_struct(self);
         for field in &self.fields {
-            field.borrow().visit_with(visitor);
+            field.borrow().walk_with(visitor, filter);
+        }
+        for constant in &self.constants {
+            constant.borrow().walk_with(visitor, filter);
         }
+        visitor.visit_struct_end(self);
     }
 }
 
@@ -152,12 +282,25 @@ impl Class {
     /// Visits the [Class] with the provided `visitor`.
     ///
     /// This function first calls `visitor.visit_class`, then recursively visits
-    /// the contents of the class.
+    /// the contents of the class, and finally calls `visitor.visit_class_end`.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [Class] with the provided `visitor`, skipping any entities that `filter` returns `false` for.
+    ///
+    /// If `filter` returns `false` for this class, none of its `visit_x` methods are called,
+    /// and none of its contents are visited.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
         visitor.visit_class(self);
         for field in &self.fields {
-            field.borrow().visit_with(visitor);
+            field.borrow().walk_with(visitor, filter);
         }
+        visitor.visit_class_end(self);
     }
 }
 
@@ -165,12 +308,25 @@ impl Exception {
     /// Visits the [Exception] with the provided `visitor`.
     ///
     /// This function first calls `visitor.visit_exception`, then recursively visits
-    /// the contents of the exception.
+    /// the contents of the exception, and finally calls `visitor.visit_exception_end`.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [Exception] with the provided `visitor`, skipping any entities that `filter` returns `false` for.
+    ///
+    /// If `filter` returns `false` for this exception, none of its `visit_x` methods are called,
+    /// and none of its contents are visited.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
         visitor.visit_exception(self);
         for field in &self.fields {
-            field.borrow().visit_with(visitor);
+            field.borrow().walk_with(visitor, filter);
         }
+        visitor.visit_exception_end(self);
     }
 }
 
@@ -178,12 +334,28 @@ impl Interface {
     /// Visits the [Interface] with the provided `visitor`.
     ///
     /// This function first calls `visitor.visit_interface`, then recursively visits
-    /// the contents of the interface.
+    /// the contents of the interface, and finally calls `visitor.visit_interface_end`.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [Interface] with the provided `visitor`, skipping any entities that `filter` returns `false` for.
+    ///
+    /// If `filter` returns `false` for this interface, none of its `visit_x` methods are called,
+    /// and none of its contents are visited.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
         visitor.visit_interface(self);
         for operation in &self.operations {
-            operation.borrow().visit_with(visitor);
+            operation.borrow().walk_with(visitor, filter);
+        }
+        for constant in &self.constants {
+            constant.borrow().walk_with(visitor, filter);
         }
+        visitor.visit_interface_end(self);
     }
 }
 
@@ -191,28 +363,64 @@ impl Enum {
     /// Visits the [Enum] with the provided `visitor`.
     ///
     /// This function first calls `visitor.visit_enum`, then recursively visits
-    /// the contents of the enum.
+    /// the contents of the enum, and finally calls `visitor.visit_enum_end`.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [Enum] with the provided `visitor`, skipping any entities that `filter` returns `false` for.
+    ///
+    /// If `filter` returns `false` for this enum, none of its `visit_x` methods are called,
+    /// and none of its contents are visited.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
         visitor.visit_enum(self);
         for enumerator in &self.enumerators {
-            enumerator.borrow().visit_with(visitor);
+            enumerator.borrow().walk_with(visitor, filter);
         }
+        for constant in &self.constants {
+            constant.borrow().walk_with(visitor, filter);
+        }
+        visitor.visit_enum_end(self);
     }
 }
 
 impl Operation {
     /// Visits the [Operation] with the provided `visitor`.
     ///
-    /// This function first calls `visitor.visit_operation`, then recursively visits
-    /// the contents of the operation.
+    /// This function first calls `visitor.visit_operation`, then `visitor.visit_operation_modifier` for each of its
+    /// modifiers, then recursively visits the contents of the operation (including its error type, if it has one),
+    /// and finally calls `visitor.visit_operation_end`.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [Operation] with the provided `visitor`, skipping any entities that `filter` returns `false` for.
+    ///
+    /// If `filter` returns `false` for this operation, none of its `visit_x` methods are called,
+    /// and none of its contents are visited.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
         visitor.visit_operation(self);
+        for modifier in &self.modifiers {
+            visitor.visit_operation_modifier(modifier);
+        }
         for parameter in &self.parameters {
-            parameter.borrow().visit_with(visitor)
+            parameter.borrow().walk_with(visitor, filter);
         }
         for return_member in &self.return_type {
-            return_member.borrow().visit_with(visitor)
+            return_member.borrow().walk_with(visitor, filter);
         }
+        if let Some(error_type) = &self.error_type {
+            error_type.visit_with(visitor);
+        }
+        visitor.visit_operation_end(self);
     }
 }
 
@@ -221,6 +429,15 @@ impl CustomType {
     ///
     /// This function delegates to `visitor.visit_custom_type`.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [CustomType] with the provided `visitor`, unless `filter` returns `false` for this custom type.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
         visitor.visit_custom_type(self);
     }
 }
@@ -230,16 +447,52 @@ impl TypeAlias {
     ///
     /// This function delegates to `visitor.visit_type_alias`.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [TypeAlias] with the provided `visitor`, unless `filter` returns `false` for this type alias.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
         visitor.visit_type_alias(self);
         self.underlying.visit_with(visitor);
     }
 }
 
+impl Constant {
+    /// Visits the [Constant] with the provided `visitor`.
+    ///
+    /// This function delegates to `visitor.visit_constant`.
+    pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [Constant] with the provided `visitor`, unless `filter` returns `false` for this constant.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
+        visitor.visit_constant(self);
+    }
+}
+
 impl Field {
     /// Visits the [Field] with the provided `visitor`.
     ///
     /// This function delegates to `visitor.visit_field`.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [Field] with the provided `visitor`, unless `filter` returns `false` for this field.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
         visitor.visit_field(self);
         self.data_type.visit_with(visitor);
     }
@@ -248,8 +501,17 @@ impl Field {
 impl Parameter {
     /// Visits the [Parameter] with the provided `visitor`.
     ///
-    /// This function delegates to `visitor.visit_parameter`
+    /// This function delegates to `visitor.visit_parameter`.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [Parameter] with the provided `visitor`, unless `filter` returns `false` for this parameter.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
         visitor.visit_parameter(self);
         self.data_type.visit_with(visitor);
     }
@@ -258,14 +520,28 @@ impl Parameter {
 impl Enumerator {
     /// Visits the [Enumerator] with the provided `visitor`.
     ///
-    /// This function delegates to `visitor.visit_enumerator`.
+    /// This function first calls `visitor.visit_enumerator`, then recursively visits
+    /// the fields of the enumerator (if it has any), and finally calls `visitor.visit_enumerator_end`.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        self.walk_with(visitor, &mut |_| true);
+    }
+
+    /// Visits the [Enumerator] with the provided `visitor`, skipping any entities that `filter` returns `false` for.
+    ///
+    /// If `filter` returns `false` for this enumerator, none of its `visit_x` methods are called,
+    /// and none of its fields are visited.
+    pub fn walk_with(&self, visitor: &mut impl Visitor, filter: &mut impl FnMut(&dyn Entity) -> bool) {
+        if !filter(self) {
+            return;
+        }
+
         visitor.visit_enumerator(self);
         if let Some(fields) = &self.fields {
             for field in fields {
-                field.borrow().visit_with(visitor);
+                field.borrow().walk_with(visitor, filter);
             }
         }
+        visitor.visit_enumerator_end(self);
     }
 }
 
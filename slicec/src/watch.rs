@@ -0,0 +1,123 @@
+// Copyright (c) ZeroC, Inc.
+
+//! This module contains the logic for watch mode (`--watch`), where the compiler recompiles whenever its inputs change.
+//!
+//! Changes are detected by polling: a [Watcher] periodically takes a fingerprint of every Slice file that the
+//! compilation reads (the modification time and size of each file), and compares it against the previous fingerprint.
+//! Polling keeps the compiler free of platform-specific file notification APIs, and Slice projects are small enough
+//! that it's cheap.
+
+use crate::compilation_state::{CancellationToken, CompilationState};
+use crate::slice_options::SliceOptions;
+use crate::utils::ignore_util::IGNORE_FILE_NAME;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// How often the compiler checks for changes when run with `--watch`.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The state of every file that a compilation reads, keyed by path.
+/// Files which couldn't be read (because they were removed, for example) are recorded as `None`.
+type Fingerprint = BTreeMap<PathBuf, Option<(SystemTime, u64)>>;
+
+/// Detects changes to the files that compiling with a set of options would read.
+///
+/// This watches the source files, and every Slice file (and ignore file) in (or schema package passed as) a reference.
+/// Since referenced directories are walked each time, adding or removing Slice files in them is detected as well.
+///
+/// Only the files are watched, not the options themselves. Changes to a configuration file are ignored.
+#[derive(Debug)]
+pub struct Watcher<'a> {
+    options: &'a SliceOptions,
+    fingerprint: Fingerprint,
+}
+
+impl<'a> Watcher<'a> {
+    /// Creates a watcher for the files that compiling with the provided options would read, in their current state.
+    pub fn new(options: &'a SliceOptions) -> Self {
+        let fingerprint = take_fingerprint(options);
+        Watcher { options, fingerprint }
+    }
+
+    /// Returns true if any of the watched files were changed, added, or removed since the last time this was called
+    /// (or since the watcher was created, for the first call).
+    pub fn has_changed(&mut self) -> bool {
+        let fingerprint = take_fingerprint(self.options);
+        let has_changed = fingerprint != self.fingerprint;
+        self.fingerprint = fingerprint;
+        has_changed
+    }
+}
+
+/// Compiles the files specified by `options`, and then recompiles them each time they change, until the provided token
+/// is cancelled. After each compilation, its state is passed to `on_compile`, so it can emit diagnostics, or generate
+/// code.
+///
+/// Each compilation is a full compilation, so the state passed to `on_compile` is always the same as it would be for
+/// [compile_from_options](crate::compile_from_options). Changes are checked for once per `poll_interval`, and any
+/// compilation that's in progress when the token is cancelled is stopped early.
+pub fn watch(
+    options: &SliceOptions,
+    poll_interval: Duration,
+    patcher: unsafe fn(&mut CompilationState),
+    validator: fn(&mut CompilationState),
+    cancellation_token: &CancellationToken,
+    mut on_compile: impl FnMut(CompilationState),
+) {
+    // Take the fingerprint before compiling, so that any changes made during the compilation trigger a recompilation.
+    let mut watcher = Watcher::new(options);
+    on_compile(crate::compile_from_options_with_cancel(
+        options,
+        patcher,
+        validator,
+        cancellation_token,
+    ));
+
+    while !cancellation_token.is_cancelled() {
+        std::thread::sleep(poll_interval);
+        if watcher.has_changed() && !cancellation_token.is_cancelled() {
+            on_compile(crate::compile_from_options_with_cancel(
+                options,
+                patcher,
+                validator,
+                cancellation_token,
+            ));
+        }
+    }
+}
+
+fn take_fingerprint(options: &SliceOptions) -> Fingerprint {
+    let mut fingerprint = Fingerprint::new();
+    let mut visited_directories = HashSet::new();
+    for path in options.sources.iter().chain(&options.references) {
+        add_to_fingerprint(Path::new(path), &mut visited_directories, &mut fingerprint);
+    }
+    fingerprint
+}
+
+fn add_to_fingerprint(path: &Path, visited_directories: &mut HashSet<PathBuf>, fingerprint: &mut Fingerprint) {
+    if path.is_dir() {
+        // Each directory is only walked once, so symbolic links to parent directories can't cause infinite recursion.
+        // Unreadable directories are skipped; the compiler will report them when it tries to read them.
+        if !path.canonicalize().is_ok_and(|path| visited_directories.insert(path)) {
+            return;
+        }
+        if let Ok(entries) = path.read_dir() {
+            for entry in entries.flatten() {
+                let entry_path = entry.path();
+                let is_slice_file = entry_path.extension().is_some_and(|extension| extension == "slice");
+                let is_ignore_file = entry_path.file_name().is_some_and(|name| name == IGNORE_FILE_NAME);
+                if entry_path.is_dir() || is_slice_file || is_ignore_file {
+                    add_to_fingerprint(&entry_path, visited_directories, fingerprint);
+                }
+            }
+        }
+    } else {
+        let state = fs::metadata(path)
+            .and_then(|metadata| Ok((metadata.modified()?, metadata.len())))
+            .ok();
+        fingerprint.insert(path.to_owned(), state);
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::annotations::{Annotation, Annotations};
+use slicec::grammar::*;
+
+struct MappedName;
+
+impl Annotation for MappedName {
+    type Element = dyn NamedSymbol;
+    type Value = String;
+}
+
+struct WireSize;
+
+impl Annotation for WireSize {
+    type Element = Struct;
+    type Value = u32;
+}
+
+#[test]
+fn annotations_are_keyed_by_element_identity() {
+    // Arrange
+    let slice = "
+        module Test
+        interface I {
+            op(x: int32) -> (x: int32, y: bool)
+        }
+    ";
+    let mut state = parse(slice, None);
+    let ast = &state.ast;
+    let operation = ast.find_element::<Operation>("Test::I::op").unwrap();
+    let parameter = operation.parameters()[0];
+    let return_member = operation.return_members()[0];
+
+    // Act
+    let annotations = &mut state.annotations;
+    annotations.insert::<MappedName>(parameter, "inX".to_owned());
+    annotations.insert::<MappedName>(return_member, "outX".to_owned());
+
+    // Assert: the parameter and return member share an identifier, but not annotations.
+    assert_eq!(annotations.get::<MappedName>(parameter).unwrap(), "inX");
+    assert_eq!(annotations.get::<MappedName>(return_member).unwrap(), "outX");
+    assert_eq!(annotations.get::<MappedName>(operation.return_members()[1]), None);
+    assert_eq!(annotations.count::<MappedName>(), 2);
+}
+
+#[test]
+fn annotations_are_stored_in_separate_tables() {
+    // Arrange
+    let ast = parse_for_ast("module Test\nstruct S {}");
+    let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+    let mut annotations = Annotations::new();
+
+    // Act
+    annotations.insert::<MappedName>(struct_def, "MappedS".to_owned());
+    annotations.insert::<WireSize>(struct_def, 1);
+    annotations.clear::<MappedName>();
+
+    // Assert
+    assert!(!annotations.contains::<MappedName>(struct_def));
+    assert_eq!(annotations.get::<WireSize>(struct_def), Some(&1));
+}
+
+#[test]
+fn cached_values_are_only_computed_once() {
+    // Arrange
+    let ast = parse_for_ast("module Test\nstruct S { a: int32 }");
+    let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+    let mut annotations = Annotations::new();
+    let mut computations = 0;
+
+    // Act
+    for _ in 0..3 {
+        annotations.get_or_insert_with::<WireSize>(struct_def, || {
+            computations += 1;
+            4
+        });
+    }
+    *annotations.get_mut::<WireSize>(struct_def).unwrap() += 1;
+
+    // Assert
+    assert_eq!(computations, 1);
+    assert_eq!(annotations.remove::<WireSize>(struct_def), Some(5));
+    assert_eq!(annotations.count::<WireSize>(), 0);
+}
@@ -0,0 +1,133 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::{check_diagnostics, diagnostics_from_compilation_state};
+use slicec::ast_builder::*;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
+use slicec::slice_options::SliceOptions;
+
+#[test]
+fn built_files_are_compiled_into_the_ast() {
+    // Arrange
+    let file = FileBuilder::new("converted.slice", "Acme::Geometry")
+        .add_struct(
+            StructBuilder::new("Point")
+                .doc("A point in 2D space.")
+                .compact()
+                .field(MemberBuilder::new("x", "int32"))
+                .field(MemberBuilder::new("y", "int32")),
+        )
+        .add_interface(
+            InterfaceBuilder::new("Plotter").operation(
+                OperationBuilder::new("plot")
+                    .idempotent()
+                    .parameter(MemberBuilder::new("point", "Point"))
+                    .parameter(MemberBuilder::new("label", "string?").tag(1))
+                    .return_member(MemberBuilder::new("success", "bool"))
+                    .return_member(MemberBuilder::new("count", "uint32")),
+            ),
+        );
+
+    // Act
+    let state = slicec::compile_from_builders(&[file], None, |_| {}, |_| {});
+
+    // Assert
+    assert!(state.diagnostics.is_empty());
+
+    let point = state.ast.find_element::<Struct>("Acme::Geometry::Point").unwrap();
+    assert!(point.is_compact);
+    assert_eq!(point.fields().len(), 2);
+    assert_eq!(point.span().file, "converted.slice");
+
+    let plot = state.ast.find_element::<Operation>("Acme::Geometry::Plotter::plot").unwrap();
+    assert!(plot.is_idempotent());
+    assert_eq!(plot.parameters().len(), 2);
+    assert_eq!(plot.parameters()[1].tag.as_ref().unwrap().value, 1);
+    assert_eq!(plot.return_members().len(), 2);
+}
+
+#[test]
+fn built_files_are_emitted_as_canonical_slice() {
+    // Arrange
+    let file = FileBuilder::new("converted.slice", "Acme")
+        .add_struct(
+            StructBuilder::new("Point")
+                .doc("A point in 2D space.")
+                .attribute("deprecated")
+                .field(MemberBuilder::new("x", "int32").doc("The x coordinate."))
+                .field(MemberBuilder::new("y", "int32")),
+        )
+        .add_interface(
+            InterfaceBuilder::new("Plotter").operation(
+                OperationBuilder::new("plot")
+                    .parameter(MemberBuilder::new("x", "int32"))
+                    .parameter(MemberBuilder::new("y", "int32"))
+                    .returns("bool"),
+            ),
+        );
+
+    // Act
+    let state = slicec::compile_from_builders(&[file], None, |_| {}, |_| {});
+
+    // Assert
+    let expected = "\
+module Acme
+
+/// A point in 2D space.
+[deprecated]
+struct Point {
+    /// The x coordinate.
+    x: int32
+    y: int32
+}
+
+interface Plotter {
+    plot(x: int32, y: int32) -> bool
+}
+";
+    assert!(state.diagnostics.is_empty());
+    assert_eq!(state.files[0].raw_text, expected);
+}
+
+#[test]
+fn keyword_identifiers_are_escaped() {
+    // Arrange
+    let file = FileBuilder::new("converted.slice", "Acme::module").add_struct(
+        StructBuilder::new("struct")
+            .field(MemberBuilder::new("tag", "int32"))
+            .field(MemberBuilder::new("stream", "bool")),
+    );
+
+    // Act
+    let state = slicec::compile_from_builders(&[file], None, |_| {}, |_| {});
+
+    // Assert
+    assert!(state.diagnostics.is_empty());
+    assert!(state.files[0].raw_text.contains("struct \\struct {"));
+    assert!(state.files[0].raw_text.contains("\\tag: int32"));
+
+    let struct_def = state.ast.find_element::<Struct>("Acme::module::struct").unwrap();
+    let identifiers = struct_def.fields().iter().map(|f| f.identifier()).collect::<Vec<_>>();
+    assert_eq!(identifiers, ["tag", "stream"]);
+}
+
+#[test]
+fn errors_in_built_files_are_reported() {
+    // Arrange
+    let file = FileBuilder::new("converted.slice", "Acme")
+        .add_struct(StructBuilder::new("Line").field(MemberBuilder::new("start", "Point")));
+
+    // Act
+    let state = slicec::compile_from_builders(&[file], None, |_| {}, |_| {});
+
+    // Assert
+    let diagnostics = diagnostics_from_compilation_state(state, &SliceOptions::default());
+    assert_eq!(diagnostics[0].span().unwrap().file, "converted.slice");
+
+    let expected = Diagnostic::new(Error::DoesNotExist {
+        identifier: "Point".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
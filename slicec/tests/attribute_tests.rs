@@ -135,6 +135,223 @@ mod attributes {
         }
     }
 
+    mod deny {
+        use super::*;
+        use slicec::diagnostics::DiagnosticLevel;
+        use slicec::slice_options::SliceOptions;
+
+        const DEPRECATED_USAGE: &str = "
+            [deprecated]
+            struct S {}
+
+            struct UseS {
+                s: S
+            }
+        ";
+
+        #[test]
+        fn deny_with_invalid_argument() {
+            // Arrange
+            let slice = "[[deny(Fake)]]";
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::ArgumentNotSupported {
+                argument: "Fake".to_owned(),
+                directive: "deny".to_owned(),
+            });
+            check_diagnostics(diagnostics, [expected]);
+        }
+
+        #[test]
+        fn file_level_deny_reports_lints_as_errors() {
+            // Arrange
+            let slice = format!("[[deny(Deprecated)]]\nmodule Test\n{DEPRECATED_USAGE}");
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            assert_eq!(diagnostics.len(), 1);
+            assert_eq!(diagnostics[0].code(), "Deprecated");
+            assert_eq!(diagnostics[0].level(), DiagnosticLevel::Error);
+        }
+
+        #[test]
+        fn local_allow_overrides_file_level_deny() {
+            // Arrange
+            let slice = "
+                [[deny(All)]]
+                module Test
+
+                [deprecated]
+                struct S {}
+
+                [allow(Deprecated)]
+                struct UseS {
+                    s: S
+                }
+            ";
+
+            // Act/Assert
+            assert_parses(slice);
+        }
+
+        #[test]
+        fn specific_allow_overrides_deny_all() {
+            // Arrange
+            let slice = format!("[[deny(All)]]\n[[allow(Deprecated)]]\nmodule Test\n{DEPRECATED_USAGE}");
+
+            // Act/Assert
+            assert_parses(slice);
+        }
+
+        #[test]
+        fn deny_option_reports_lints_as_errors() {
+            // Arrange
+            let slice = format!("module Test\n{DEPRECATED_USAGE}");
+            let options = SliceOptions {
+                denied_lints: vec!["Deprecated".to_owned()],
+                ..Default::default()
+            };
+
+            // Act
+            let state = parse(slice, Some(&options));
+            let diagnostics = diagnostics_from_compilation_state(state, &options);
+
+            // Assert
+            assert_eq!(diagnostics.len(), 1);
+            assert_eq!(diagnostics[0].level(), DiagnosticLevel::Error);
+        }
+
+        #[test]
+        fn attributes_override_deny_option() {
+            // Arrange
+            let slice = format!("[[allow(Deprecated)]]\nmodule Test\n{DEPRECATED_USAGE}");
+            let options = SliceOptions {
+                denied_lints: vec!["All".to_owned()],
+                ..Default::default()
+            };
+
+            // Act
+            let state = parse(slice, Some(&options));
+            let diagnostics = diagnostics_from_compilation_state(state, &options);
+
+            // Assert
+            assert!(diagnostics.is_empty());
+        }
+    }
+
+    mod lint_levels {
+        use super::*;
+        use slicec::diagnostics::DiagnosticLevel;
+        use slicec::grammar::{Field, Struct};
+        use slicec::slice_options::SliceOptions;
+
+        #[test]
+        fn module_attributes_cascade_to_contained_elements() {
+            // Arrange
+            let slice = "
+                [allow(Deprecated)]
+                module Test
+
+                [deprecated]
+                struct S {}
+
+                interface I {
+                    op(s: S)
+                }
+            ";
+
+            // Act/Assert
+            assert_parses(slice);
+        }
+
+        #[test]
+        fn container_attributes_cascade_to_members() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [deprecated]
+                struct S {}
+
+                [deny(Deprecated)]
+                interface I {
+                    op(s: S) -> S
+                }
+            ";
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            assert_eq!(diagnostics.len(), 2);
+            assert!(diagnostics.iter().all(|diagnostic| diagnostic.level() == DiagnosticLevel::Error));
+        }
+
+        #[test]
+        fn effective_lint_level_can_be_queried() {
+            // Arrange
+            let slice = "
+                [deny(All)]
+                module Test
+
+                [allow(BrokenDocLink)]
+                struct S {
+                    a: int32
+
+                    [allow(All)]
+                    b: int32
+                }
+
+                struct T {}
+            ";
+            let options = SliceOptions::default();
+            let lint = Lint::BrokenDocLink { message: String::new() };
+            let deprecated = Lint::Deprecated { identifier: String::new(), reason: None };
+
+            // Act
+            let state = parse(slice, Some(&options));
+            let s = state.ast.find_element::<Struct>("Test::S").unwrap();
+            let a = state.ast.find_element::<Field>("Test::S::a").unwrap();
+            let b = state.ast.find_element::<Field>("Test::S::b").unwrap();
+            let t = state.ast.find_element::<Struct>("Test::T").unwrap();
+
+            // Assert
+            assert_eq!(state.get_lint_level(&lint, s, &options), DiagnosticLevel::Allowed);
+            assert_eq!(state.get_lint_level(&lint, a, &options), DiagnosticLevel::Allowed);
+            assert_eq!(state.get_lint_level(&deprecated, a, &options), DiagnosticLevel::Error);
+            assert_eq!(state.get_lint_level(&deprecated, b, &options), DiagnosticLevel::Allowed);
+            assert_eq!(state.get_lint_level(&lint, t, &options), DiagnosticLevel::Error);
+        }
+
+        #[test]
+        fn effective_lint_level_defaults_to_options() {
+            // Arrange
+            let slice = "
+                module Test
+                struct S {}
+            ";
+            let options = SliceOptions {
+                allowed_lints: vec!["Deprecated".to_owned()],
+                ..Default::default()
+            };
+            let deprecated = Lint::Deprecated { identifier: String::new(), reason: None };
+            let lint = Lint::BrokenDocLink { message: String::new() };
+
+            // Act
+            let state = parse(slice, Some(&options));
+            let s = state.ast.find_element::<Struct>("Test::S").unwrap();
+
+            // Assert
+            assert_eq!(state.get_lint_level(&deprecated, s, &options), DiagnosticLevel::Allowed);
+            assert_eq!(state.get_lint_level(&lint, s, &options), DiagnosticLevel::Warning);
+        }
+    }
+
     mod slice_api {
 
         use super::*;
@@ -146,6 +363,7 @@ mod attributes {
         fn sliced_format() {
             // Arrange
             let slice = "
+                mode = Slice1
                 module Test
 
                 interface I {
@@ -164,6 +382,31 @@ mod attributes {
             assert!(operation.slice_classes_in_return());
         }
 
+        #[test]
+        fn sliced_format_is_only_supported_in_slice1_mode() {
+            // Arrange
+            let slice = "
+                module Test
+
+                interface I {
+                    [slicedFormat(Args)]
+                    op(s: string)
+                }
+            ";
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::UnexpectedAttribute {
+                attribute: "slicedFormat".to_owned(),
+            })
+            .set_span(&Span::new((5, 22).into(), (5, 40).into(), "string-0"))
+            .add_note("the slicedFormat attribute can only be used in Slice1 mode", None);
+
+            check_diagnostics(diagnostics, [expected]);
+        }
+
         #[test]
         fn sliced_format_with_invalid_arguments_fails() {
             // Arrange
@@ -447,6 +690,41 @@ mod attributes {
             check_diagnostics(diagnostics, [expected]);
         }
 
+        #[test]
+        fn invalid_arguments_are_reported_at_their_own_spans() {
+            // Arrange
+            let slice = "
+                module Test
+
+                interface I {
+                    [compress(Args, Foo, \"Bar\")]
+                    op(s: string) -> string
+                }
+            ";
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let note = "'Args' and 'Return' are the only valid arguments";
+            let expected = [
+                Diagnostic::new(Error::ArgumentNotSupported {
+                    argument: "Foo".to_owned(),
+                    directive: "compress".to_owned(),
+                })
+                .set_span(&Span::new((5, 37).into(), (5, 40).into(), "string-0"))
+                .add_note(note, None),
+                Diagnostic::new(Error::ArgumentNotSupported {
+                    argument: "Bar".to_owned(),
+                    directive: "compress".to_owned(),
+                })
+                .set_span(&Span::new((5, 42).into(), (5, 47).into(), "string-0"))
+                .add_note(note, None),
+            ];
+
+            check_diagnostics(diagnostics, expected);
+        }
+
         #[test]
         fn cannot_compress_structs() {
             // Arrange
@@ -496,6 +774,223 @@ mod attributes {
             check_diagnostics(diagnostics, [expected]);
         }
 
+        #[test]
+        fn deadline() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [deadline(\"2m\")]
+                interface I {
+                    [deadline(\"500ms\")]
+                    op1()
+
+                    op2()
+                }
+            ";
+
+            // Act
+            let ast = parse_for_ast(slice);
+
+            // Assert
+            let op1 = ast.find_element::<Operation>("Test::I::op1").unwrap();
+            let op2 = ast.find_element::<Operation>("Test::I::op2").unwrap();
+            assert_eq!(op1.deadline(), Some(std::time::Duration::from_millis(500)));
+            assert_eq!(op2.deadline(), Some(std::time::Duration::from_secs(120)));
+        }
+
+        #[test_case("0s"; "zero")]
+        #[test_case("5"; "no unit")]
+        #[test_case("5 days"; "unknown unit")]
+        #[test_case("-5s"; "negative")]
+        fn deadline_with_invalid_arguments_fails(deadline: &str) {
+            // Arrange
+            let slice = format!(
+                "
+                module Test
+
+                interface I {{
+                    [deadline(\"{deadline}\")]
+                    op()
+                }}
+            "
+            );
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::ArgumentNotSupported {
+                argument: deadline.to_owned(),
+                directive: "deadline".to_owned(),
+            })
+            .add_note("deadlines must be a positive integer followed by 'ms', 's', 'm', or 'h'", None);
+
+            check_diagnostics(diagnostics, [expected]);
+        }
+
+        #[test]
+        fn deadline_only_works_on_interfaces_and_operations() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [deadline(\"1s\")]
+                struct S {}
+            ";
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::UnexpectedAttribute {
+                attribute: "deadline".to_owned(),
+            })
+            .add_note("the deadline attribute can only be applied to interfaces and operations", None);
+
+            check_diagnostics(diagnostics, [expected]);
+        }
+
+        #[test]
+        fn experimental() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [experimental]
+                struct S {}
+
+                [experimental(\"still being designed\")]
+                interface I {}
+            ";
+
+            // Act
+            let ast = parse_for_ast(slice);
+
+            // Assert
+            let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+            let interface_def = ast.find_element::<Interface>("Test::I").unwrap();
+            assert_eq!(struct_def.find_attribute::<Experimental>().unwrap().reason, None);
+            let experimental = interface_def.find_attribute::<Experimental>().unwrap();
+            assert_eq!(experimental.reason.as_deref(), Some("still being designed"));
+        }
+
+        #[test]
+        fn experimental_is_not_allowed_on_parameters() {
+            // Arrange
+            let slice = "
+                module Test
+
+                interface I {
+                    op([experimental] s: string)
+                }
+            ";
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::UnexpectedAttribute {
+                attribute: "experimental".to_owned(),
+            })
+            .add_note("parameters cannot be individually marked as experimental", None);
+
+            check_diagnostics(diagnostics, [expected]);
+        }
+
+        #[test]
+        fn since() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [since(\"2.1\")]
+                struct S {
+                    a: int32
+
+                    [since(\"2.1.3\")]
+                    tag(1) b: int32?
+                }
+            ";
+
+            // Act
+            let ast = parse_for_ast(slice);
+
+            // Assert
+            let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+            let field = ast.find_element::<Field>("Test::S::b").unwrap();
+            assert_eq!(struct_def.find_attribute::<Since>().unwrap().version, "2.1");
+            assert_eq!(field.find_attribute::<Since>().unwrap().version_components(), vec![2, 1, 3]);
+        }
+
+        #[test_case("v2"; "prefixed")]
+        #[test_case("2.1.0.4"; "too many components")]
+        #[test_case("2..1"; "empty component")]
+        #[test_case("2.1-beta"; "pre-release")]
+        fn since_with_invalid_arguments_fails(version: &str) {
+            // Arrange
+            let slice = format!(
+                "
+                module Test
+
+                [since(\"{version}\")]
+                struct S {{}}
+            "
+            );
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::ArgumentNotSupported {
+                argument: version.to_owned(),
+                directive: "since".to_owned(),
+            })
+            .add_note("versions must be 1 to 3 numbers separated by '.', like \"2\", \"2.1\", or \"2.1.0\"", None);
+
+            check_diagnostics(diagnostics, [expected]);
+        }
+
+        #[test]
+        fn since_requires_a_version() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [since]
+                struct S {}
+            ";
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::MissingRequiredArgument {
+                argument: "since".to_owned(),
+            });
+
+            check_diagnostics(diagnostics, [expected]);
+        }
+
+        #[test]
+        fn since_is_not_allowed_on_modules() {
+            // Arrange
+            let slice = "
+                [since(\"1\")]
+                module Test {}
+            ";
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::UnexpectedAttribute {
+                attribute: "since".to_owned(),
+            });
+
+            check_diagnostics(diagnostics, [expected]);
+        }
+
         #[test]
         fn non_repeatable_attributes_error() {
             // Arrange
@@ -586,6 +1081,7 @@ mod attributes {
 
         use super::*;
         use slicec::grammar::*;
+        use slicec::slice_file::Span;
         use test_case::test_case;
 
         #[test]
@@ -636,10 +1132,39 @@ mod attributes {
             assert_eq!(arguments, vec!["a", "b", "c"]);
         }
 
+        #[test]
+        fn foo_attribute_arguments_have_spans() {
+            // Arrange
+            let slice = "
+                module Test
+
+                interface I {
+                    [foo::bar(a, \"b c\")]
+                    op(s: string) -> string
+                }
+            ";
+
+            // Act
+            let ast = parse_for_ast(slice);
+
+            // Assert
+            let operation = ast.find_element::<Operation>("Test::I::op").unwrap();
+
+            let unparsed_attribute = operation.find_attribute::<Unparsed>().unwrap();
+            let expected = [
+                Span::new((5, 31).into(), (5, 32).into(), "string-0"),
+                Span::new((5, 34).into(), (5, 39).into(), "string-0"),
+            ];
+            assert_eq!(unparsed_attribute.arg_spans, expected);
+        }
+
         #[test_case("a", &["a"]; "single argument")]
         #[test_case("\"a b c\"", &["a b c"]; "quoted argument")]
         #[test_case("a,b,c", &["a", "b", "c"]; "multiple arguments")]
         #[test_case("\"a, b, c\"", &["a, b, c"]; "quoted argument with comma")]
+        #[test_case("r\"a\\nb\"", &["a\\nb"]; "raw argument")]
+        #[test_case("r#\"say \"hi\"\"#, b", &["say \"hi\"", "b"]; "raw argument with quotes")]
+        #[test_case("r##\"a\"#b\"##", &["a\"#b"]; "raw argument with multiple hashes")]
         fn attribute_parameters_multiple(input: &str, expected: &[&str]) {
             // Arrange
             let slice = format!(
@@ -665,6 +1190,7 @@ mod attributes {
         }
 
         #[test_case("a, \""; "quoted argument with unterminated string literal")]
+        #[test_case("r#\"a\""; "raw argument with unterminated raw string literal")]
         #[test_case("a, )"; "missing argument")]
         #[test_case("fizz buzz"; "unquoted argument with spaces")]
         fn attribute_with_invalid_parameters(input: &str) {
@@ -687,6 +1213,33 @@ mod attributes {
             assert!(compilation_state.diagnostics.has_errors());
         }
 
+        #[test]
+        fn raw_arguments_can_span_multiple_lines() {
+            // Arrange
+            let slice = r##"
+                module Test
+
+                interface I {
+                    [foo::bar(r#"
+SELECT *
+FROM "table"
+"#)]
+                    op(s: string) -> string
+                }
+            "##;
+
+            // Act
+            let ast = parse_for_ast(slice);
+
+            // Assert
+            let operation = ast.find_element::<Operation>("Test::I::op").unwrap();
+
+            let unparsed_attribute = operation.find_attribute::<Unparsed>().unwrap();
+            assert_eq!(unparsed_attribute.args, ["\nSELECT *\nFROM \"table\"\n"]);
+            let expected_span = Span::new((5, 31).into(), (8, 3).into(), "string-0");
+            assert_eq!(unparsed_attribute.arg_spans, [expected_span]);
+        }
+
         #[test]
         fn parent_attributes() {
             // Arrange
@@ -0,0 +1,140 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::bundler::{bundle, BundleError};
+use slicec::compilation_state::CompilationState;
+use slicec::compile_from_strings;
+
+/// Compiles the provided Slice files, marking all of them as source files.
+fn compile(slices: &[&str]) -> CompilationState {
+    let mut state = compile_from_strings(slices, None, |_| {}, |_| {});
+    assert!(!state.diagnostics.has_errors(), "{:?}", state.diagnostics);
+    for file in &mut state.files {
+        file.is_source = true;
+    }
+    state
+}
+
+#[test]
+fn modules_are_merged_across_files() {
+    // Arrange
+    let slice1 = "
+        module Foo
+        /// A point.
+        struct Point { x: int32, y: int32 }
+    ";
+    let slice2 = "
+        module Bar
+        struct Line { start: Foo::Point, end: Foo::Point }
+    ";
+    let slice3 = "
+        module Foo
+        enum Color { Red, Green }
+        module Nested { custom Timestamp }
+    ";
+    let state = compile(&[slice1, slice2, slice3]);
+
+    // Act
+    let bundled = bundle(&state, false).unwrap();
+
+    // Assert
+    let expected = "\
+module Bar {
+    struct Line {
+        start: ::Foo::Point
+        end: ::Foo::Point
+    }
+}
+
+module Foo {
+    /// A point.
+    struct Point {
+        x: int32
+        y: int32
+    }
+
+    enum Color {
+        Red
+        Green
+    }
+}
+
+module Foo::Nested {
+    custom Timestamp
+}
+";
+    assert_eq!(bundled, expected);
+    assert_parses(bundled);
+}
+
+#[test]
+fn file_attributes_and_compilation_mode_are_applied_to_the_bundle() {
+    // Arrange
+    let slice1 = "
+        mode = Slice1
+        [[allow(Deprecated)]]
+        module Foo
+        exception Problem {}
+    ";
+    let slice2 = "
+        mode = Slice1
+        [[allow(Deprecated)]]
+        module Foo
+        class Node {}
+    ";
+    let state = compile(&[slice1, slice2]);
+
+    // Act
+    let bundled = bundle(&state, false).unwrap();
+
+    // Assert
+    let expected = "\
+mode = Slice1
+[[allow(Deprecated)]]
+
+module Foo {
+    exception Problem {}
+
+    class Node {}
+}
+";
+    assert_eq!(bundled, expected);
+}
+
+#[test]
+fn reference_files_are_only_bundled_if_requested() {
+    // Arrange
+    let slice1 = "module Foo { struct S {} }";
+    let slice2 = "module Bar { struct T { s: Foo::S } }";
+    let mut state = compile(&[slice1, slice2]);
+    state.files[0].is_source = false;
+
+    // Act
+    let sources_only = bundle(&state, false).unwrap();
+    let self_contained = bundle(&state, true).unwrap();
+
+    // Assert
+    assert!(!sources_only.contains("module Foo"));
+    assert!(self_contained.contains("module Foo"));
+    assert_parses(self_contained);
+}
+
+#[test]
+fn files_with_different_compilation_modes_cannot_be_bundled() {
+    // Arrange
+    let slice1 = "mode = Slice1\nmodule Foo";
+    let slice2 = "module Bar";
+    let state = compile(&[slice1, slice2]);
+
+    // Act
+    let result = bundle(&state, false);
+
+    // Assert
+    let Err(BundleError::MixedCompilationModes { slice1_file, slice2_file }) = result else {
+        panic!("expected a 'MixedCompilationModes' error, but got: {result:?}");
+    };
+    assert_eq!(slice1_file, "string-0");
+    assert_eq!(slice2_file, "string-1");
+}
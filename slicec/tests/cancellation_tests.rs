@@ -0,0 +1,70 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::compilation_state::CancellationToken;
+use slicec::compile_from_options_with_cancel;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
+use slicec::slice_options::SliceOptions;
+use std::sync::LazyLock;
+
+/// Writes the provided Slice file into a fresh temp directory, and returns the directory, along with options for
+/// compiling the file.
+fn options_for(name: &str, slice: &str) -> (TempDir, SliceOptions) {
+    let directory = TempDir::new(name);
+    let path = directory.write_file("file.slice", slice);
+    let options = SliceOptions {
+        sources: vec![path.to_str().unwrap().to_owned()],
+        ..Default::default()
+    };
+    (directory, options)
+}
+
+#[test]
+fn compilations_run_to_completion_if_not_cancelled() {
+    // Arrange
+    let (_directory, options) = options_for("not-cancelled", "module Test\nstruct S {}");
+    let token = CancellationToken::new();
+
+    // Act
+    let state = compile_from_options_with_cancel(&options, |_| {}, |_| {}, &token);
+
+    // Assert
+    assert!(state.ast.find_element::<Struct>("Test::S").is_ok());
+    assert!(diagnostics_from_compilation_state(state, &options).is_empty());
+}
+
+#[test]
+fn cancelled_compilations_stop_before_parsing() {
+    // Arrange
+    let (_directory, options) = options_for("cancelled-early", "module Test\nstruct S {}");
+    let token = CancellationToken::new();
+    token.clone().cancel();
+
+    // Act
+    let state = compile_from_options_with_cancel(&options, |_| {}, |_| {}, &token);
+
+    // Assert
+    assert!(token.is_cancelled());
+    assert!(state.ast.find_element::<Struct>("Test::S").is_err());
+    let diagnostics = diagnostics_from_compilation_state(state, &options);
+    check_diagnostics(diagnostics, [Diagnostic::new(Error::CompilationCancelled)]);
+}
+
+#[test]
+fn cancelled_compilations_skip_the_remaining_passes() {
+    // Arrange
+    static TOKEN: LazyLock<CancellationToken> = LazyLock::new(CancellationToken::new);
+    let (_directory, options) = options_for("cancelled-while-patching", "module Test\nenum E : int8 {}");
+
+    // Act
+    // Cancel the compilation while it's being patched. The empty enum would be reported during validation otherwise.
+    let state = compile_from_options_with_cancel(&options, |_| TOKEN.cancel(), |_| {}, &TOKEN);
+
+    // Assert
+    assert!(state.ast.find_element::<Enum>("Test::E").is_ok());
+    let diagnostics = diagnostics_from_compilation_state(state, &options);
+    check_diagnostics(diagnostics, [Diagnostic::new(Error::CompilationCancelled)]);
+}
@@ -7,6 +7,7 @@ mod comments {
     use crate::test_helpers::*;
     use slicec::diagnostics::{Diagnostic, Error, Lint};
     use slicec::grammar::*;
+    use slicec::slice_file::Span;
     use test_case::test_case;
 
     #[test]
@@ -155,11 +156,70 @@ mod comments {
     }
 
     #[test]
-    fn doc_comments_not_supported_on_modules() {
+    fn doc_comments_can_be_applied_to_modules() {
         // Arrange
         let slice = "
             /// This is a module comment.
+            /// @see S
             module tests
+            struct S {}
+
+            /// This is a module block comment.
+            module Inner {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let module_def = ast.find_element::<Module>("tests").unwrap();
+        let comment = module_def.comment().unwrap();
+        let overview = comment.overview.as_ref().unwrap();
+        let MessageComponent::Text(text) = &overview.value[0] else { panic!() };
+        assert_eq!(text, "This is a module comment.");
+        assert_eq!(comment.see[0].linked_entity().unwrap().parser_scoped_identifier(), "tests::S");
+
+        let block_module = ast.find_element::<Module>("tests::Inner").unwrap();
+        assert!(block_module.comment().is_some());
+    }
+
+    #[test]
+    fn doc_comments_can_be_applied_to_files() {
+        // Arrange
+        let slice = "
+            //! This is a file comment.
+            //! See {@link tests::S}.
+            mode = Slice2
+
+            /// This is a module comment.
+            module tests
+            struct S {}
+        ";
+
+        // Act
+        let state = parse(slice, None);
+
+        // Assert
+        let comment = state.files[0].comment.as_ref().unwrap();
+        let overview = &comment.overview.as_ref().unwrap().value;
+        let MessageComponent::Text(text) = &overview[0] else { panic!() };
+        assert_eq!(text, "This is a file comment.");
+        let link_tag = overview.iter().find_map(|component| match component {
+            MessageComponent::Link(link_tag) => Some(link_tag),
+            MessageComponent::Text(_) => None,
+        });
+        assert_eq!(link_tag.unwrap().linked_entity().unwrap().identifier(), "S");
+
+        let module_comment = state.ast.find_element::<Module>("tests").unwrap().comment().unwrap();
+        assert_eq!(module_comment.span.start.row, 6);
+    }
+
+    #[test]
+    fn file_doc_comments_must_be_at_the_top_of_the_file() {
+        // Arrange
+        let slice = "
+            module tests
+            //! This is a misplaced file comment.
         ";
 
         // Act
@@ -167,7 +227,32 @@ mod comments {
 
         // Assert
         let expected = Diagnostic::new(Error::Syntax {
-            message: "doc comments cannot be applied to modules".to_owned(),
+            message: "expected one of 'doc comment', 'module', 'struct', 'exception', 'class', \
+                'interface', 'enum', 'custom', 'typealias', 'const', 'compact', 'internal', 'public', 'unchecked', '[', '{', ';', or '::', \
+                but found ' This is a misplaced file comment.'"
+                .to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("/// @param foo: bar.", "a 'param'", "param"; "param")]
+    #[test_case("/// @returns: bar.", "a 'returns'", "returns"; "returns")]
+    #[test_case("/// @inheritdoc", "an 'inheritdoc'", "inheritdoc"; "inheritdoc")]
+    fn module_comments_cannot_have_entity_tags(comment: &str, tag_with_article: &str, tag: &str) {
+        // Arrange
+        let slice = format!(
+            "
+            {comment}
+            module tests
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: format!("comment has {tag_with_article} tag, but '{tag}' tags cannot be used on modules"),
         });
         check_diagnostics(diagnostics, [expected]);
     }
@@ -584,22 +669,51 @@ mod comments {
         assert_eq!(documented_exception, &thrown_type);
     }
 
+    #[test]
+    fn tag_identifiers_have_spans() {
+        // Arrange
+        let slice = "
+            mode = Slice1
+            module tests
+
+            exception E {}
+
+            interface I {
+                /// @throws E: {@link I}
+                /// @see E
+                op() throws E
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let operation = ast.find_element::<Operation>("tests::I::op").unwrap();
+        let doc_comment = operation.comment().unwrap();
+
+        let throws_tag = &doc_comment.throws[0];
+        let MessageComponent::Link(link_tag) = &throws_tag.message.value[1] else { panic!() };
+        let see_tag = &doc_comment.see[0];
+        assert_eq!(throws_tag.thrown_type_span, Span::new((8, 29).into(), (8, 30).into(), "string-0"));
+        assert_eq!(link_tag.link_span, Span::new((8, 39).into(), (8, 40).into(), "string-0"));
+        assert_eq!(see_tag.link_span, Span::new((9, 26).into(), (9, 27).into(), "string-0"));
+    }
+
     #[test]
     fn throws_tag_is_rejected_for_operations_that_do_not_throw() {
         // Arrange
-        let slice = format!(
-            "
+        let slice = "
             mode = Slice1
             module tests
 
-            exception Foo {{}}
+            exception Foo {}
 
-            interface I {{
+            interface I {
                 /// @throws Foo: this tag is invalid.
                 op()
-            }}
-            ",
-        );
+            }
+        ";
 
         // Act
         let diagnostics = parse_for_diagnostics(slice);
@@ -669,6 +783,52 @@ mod comments {
         check_diagnostics(diagnostics, [expected]);
     }
 
+    #[test]
+    fn throws_tag_can_document_an_error_type() {
+        // Arrange
+        let slice = "
+            module tests
+
+            enum MyError { NotFound }
+            typealias Alias = MyError
+
+            interface I {
+                /// @throws MyError: if it's not found.
+                /// @throws Alias: also if it's not found.
+                op() throws MyError
+            }
+        ";
+
+        // Act/Assert
+        assert_parses(slice);
+    }
+
+    #[test]
+    fn throws_tag_is_rejected_if_it_does_not_match_the_error_type() {
+        // Arrange
+        let slice = "
+            module tests
+
+            struct MyError {}
+            struct Foo {}
+
+            interface I {
+                /// @throws Foo: this isn't the error type.
+                op() throws MyError
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: "comment has a 'throws' tag for 'Foo', but operation 'op' doesn't fail with this error type"
+                .to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
     #[test]
     fn multiple_throws_tags_can_be_specified() {
         // Arrange
@@ -811,4 +971,406 @@ mod comments {
         });
         check_diagnostics(diagnostics, [expected]);
     }
+
+    #[test]
+    fn example_tags_can_only_be_used_with_operations() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// @example(): bad tag.
+            struct Foo {}
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: "comment has an 'example' tag, but only operations can have examples".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn example_tags_are_parsed_into_structured_arguments() {
+        // Arrange
+        let slice = r#"
+            module tests
+
+            enum Color { Red, Green }
+
+            interface I {
+                /// @example(name = "a \"quoted\" name", count = -3, enabled = true, color = Green): a full example.
+                /// @example(name = "", count = 0, color = Red)
+                op(name: string, count: int32, enabled: bool?, color: Color)
+            }
+        "#;
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let operation = ast.find_element::<Operation>("tests::I::op").unwrap();
+        let examples = &operation.comment().unwrap().examples;
+        assert_eq!(examples.len(), 2);
+
+        let arguments = &examples[0].arguments;
+        let identifiers = arguments.iter().map(|a| a.identifier.value.as_str()).collect::<Vec<_>>();
+        assert_eq!(identifiers, ["name", "count", "enabled", "color"]);
+        assert!(matches!(
+            &arguments[0].value,
+            ExampleValue::Literal(ConstantValue::String(s)) if s == "a \"quoted\" name",
+        ));
+        assert!(matches!(arguments[1].value, ExampleValue::Literal(ConstantValue::Integer(-3))));
+        assert!(matches!(arguments[2].value, ExampleValue::Literal(ConstantValue::Bool(true))));
+        assert!(matches!(&arguments[3].value, ExampleValue::Enumerator(i) if i.value == "Green"));
+
+        let message = &examples[0].message.value;
+        assert!(matches!(&message[0], MessageComponent::Text(text) if text == "a full example."));
+        assert_eq!(examples[1].arguments.len(), 3);
+    }
+
+    #[test]
+    fn example_tag_is_rejected_if_it_names_a_non_existent_parameter() {
+        // Arrange
+        let slice = "
+            module tests
+
+            interface I {
+                /// @example(foo = 1)
+                op()
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: "comment has an 'example' tag for 'foo', but operation 'op' has no parameter with that name"
+                .to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn example_tag_cannot_provide_multiple_values_for_a_parameter() {
+        // Arrange
+        let slice = "
+            module tests
+
+            interface I {
+                /// @example(a = 1, a = 2)
+                op(a: int32)
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: "comment has an 'example' tag which provides multiple values for 'a'".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn example_tag_must_provide_values_for_all_non_optional_parameters() {
+        // Arrange
+        let slice = "
+            module tests
+
+            interface I {
+                /// @example(a = 1)
+                op(a: int32, b: string, c: bool?)
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: "comment has an 'example' tag which doesn't provide a value for parameter 'b'".to_owned(),
+        })
+        .add_note("only optional parameters can be omitted from examples", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("uint8", "256"; "out of range integer")]
+    #[test_case("int32", "\"1\""; "string for integer")]
+    #[test_case("string", "true"; "bool for string")]
+    #[test_case("bool", "1"; "integer for bool")]
+    #[test_case("E", "C"; "unknown enumerator")]
+    #[test_case("S", "A"; "unsupported type")]
+    fn example_tag_values_must_match_parameter_types(type_string: &str, value: &str) {
+        // Arrange
+        let slice = format!(
+            "
+            module tests
+
+            enum E {{ A, B }}
+            struct S {{}}
+
+            interface I {{
+                /// @example(p = {value})
+                op(p: {type_string})
+            }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: "comment has an 'example' tag with an invalid value for parameter 'p'".to_owned(),
+        })
+        .add_note(format!("parameter 'p' is of type '{type_string}'"), None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn example_tag_string_literals_must_be_terminated() {
+        // Arrange
+        let slice = r#"
+            module tests
+
+            interface I {
+                /// @example(a = "foo)
+                op(a: string)
+            }
+        "#;
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::MalformedDocComment {
+            message: "unterminated string literal".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("{@link}", "doc comment tag 'link' is missing its argument", (21, 28); "missing argument")]
+    #[test_case("{@link Foo", "missing a closing '}' on an inline doc comment tag", (21, 31); "unterminated")]
+    #[test_case("{@link Foo Bar}", "'Bar' is not a valid argument for doc comment tag 'link'", (32, 35); "extra")]
+    #[test_case("{@link Foo(1)}", "'(1)' is not a valid argument for doc comment tag 'link'", (31, 34); "symbol")]
+    #[test_case("{@link Foo::}", "'::' is not a valid argument for doc comment tag 'link'", (31, 33); "trailing scope")]
+    #[test_case(r#"{@link "Foo"}"#, r#"'"Foo"' is not a valid argument for doc comment tag 'link'"#, (28, 33); "str")]
+    fn malformed_inline_tags_are_reported_precisely(tag: &str, message: &str, columns: (usize, usize)) {
+        // Arrange
+        let slice = format!(
+            "
+            module tests
+
+            /// See {tag}
+            struct S {{}}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let span = Span::new((4, columns.0).into(), (4, columns.1).into(), "string-0");
+        let expected = Diagnostic::new(Lint::MalformedInlineTag {
+            message: message.to_owned(),
+        })
+        .set_span(&span);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn effective_comments_are_inherited_from_base_types() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// The base interface.
+            interface Base {}
+
+            interface Undocumented {}
+
+            interface Derived : Undocumented, Base {}
+
+            /// Derived documentation.
+            /// @inheritdoc
+            interface Explicit : Derived {}
+
+            /// Its own documentation.
+            interface Overridden : Base {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let overview_of = |identifier: &str| {
+            let interface_def = ast.find_element::<Interface>(identifier).unwrap();
+            let comment = interface_def.effective_comment()?;
+            Some(comment.overview.as_ref().unwrap().value.iter().map(|component| match component {
+                MessageComponent::Text(text) => text.clone(),
+                MessageComponent::Link(_) => unreachable!(),
+            }).collect::<String>())
+        };
+        assert_eq!(overview_of("tests::Undocumented"), None);
+        assert_eq!(overview_of("tests::Derived").as_deref(), Some("The base interface.\n"));
+        assert_eq!(overview_of("tests::Explicit").as_deref(), Some("The base interface.\n"));
+        assert_eq!(overview_of("tests::Overridden").as_deref(), Some("Its own documentation.\n"));
+
+        let explicit = ast.find_element::<Interface>("tests::Explicit").unwrap();
+        assert!(explicit.comment().unwrap().inherit_doc.is_some());
+    }
+
+    #[test]
+    fn classes_and_exceptions_inherit_comments_from_their_bases() {
+        // Arrange
+        let slice = "
+            mode = Slice1
+            module tests
+
+            /// A base class.
+            class A {}
+            class B : A {}
+
+            /// A base exception.
+            exception E {}
+            /// @inheritdoc
+            exception F : E {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let class_comment = ast.find_element::<Class>("tests::B").unwrap().effective_comment().unwrap();
+        assert_eq!(class_comment.span.start.row, 5);
+        let exception_comment = ast.find_element::<Exception>("tests::F").unwrap().effective_comment().unwrap();
+        assert_eq!(exception_comment.span.start.row, 9);
+    }
+
+    #[test]
+    fn inheritdoc_tags_can_only_be_used_on_types_with_bases() {
+        // Arrange
+        let slice = "
+            module tests
+
+            interface I {
+                /// @inheritdoc
+                op()
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: "comment has an 'inheritdoc' tag, but operation 'op' has no base to inherit documentation from"
+                .to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("fr", "Bonjour.\n"; "exact locale")]
+    #[test_case("FR", "Bonjour.\n"; "locales are case insensitive")]
+    #[test_case("pt-BR", "Olá.\n"; "dashes and underscores are equivalent")]
+    #[test_case("fr_CA", "Bonjour.\n"; "falls back to language")]
+    #[test_case("de", "Hello.\n"; "falls back to overview")]
+    fn localized_overviews_can_be_selected(locale: &str, expected: &str) {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// Hello.
+            /// @locale fr: Bonjour.
+            /// @locale pt_BR: Olá.
+            interface I {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let comment = ast.find_element::<Interface>("tests::I").unwrap().comment().unwrap();
+        assert_eq!(comment.locales.len(), 2);
+        assert_eq!(comment.locales[0].locale.value, "fr");
+
+        let overview = comment.localized_overview(locale).unwrap();
+        let text = overview.value.iter().map(|component| match component {
+            MessageComponent::Text(text) => text.as_str(),
+            MessageComponent::Link(_) => unreachable!(),
+        });
+        assert_eq!(text.collect::<String>(), expected);
+    }
+
+    #[test]
+    fn links_in_locale_tags_are_resolved() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// @locale fr: Voir {@link S}.
+            struct S {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let comment = ast.find_element::<Struct>("tests::S").unwrap().comment().unwrap();
+        let MessageComponent::Link(link_tag) = &comment.locales[0].message.value[1] else { panic!() };
+        assert_eq!(link_tag.linked_entity().unwrap().identifier(), "S");
+    }
+
+    #[test]
+    fn duplicate_locales_are_reported() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// Hello.
+            /// @locale fr: Bonjour.
+            /// @locale FR: Salut.
+            struct S {}
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: "comment has multiple 'locale' tags for 'FR'".to_owned(),
+        })
+        .add_note("the locale was first documented here", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn identifiers_in_doc_comments_are_normalized() {
+        // Arrange: the comment uses an 'o' and a combining diaeresis, but the definitions use a precomposed 'ö'.
+        let slice = "
+            module tests
+
+            struct Gr\u{f6}\u{df}e {}
+
+            interface I {
+                /// @param gro\u{308}\u{df}e: see {@link Gro\u{308}\u{df}e}.
+                op(gr\u{f6}\u{df}e: Gr\u{f6}\u{df}e)
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let param_tag = &ast.find_element::<Operation>("tests::I::op").unwrap().comment().unwrap().params[0];
+        assert_eq!(param_tag.identifier.value, "gr\u{f6}\u{df}e");
+
+        let MessageComponent::Link(link_tag) = &param_tag.message.value[1] else { panic!() };
+        assert_eq!(link_tag.linked_entity().unwrap().identifier(), "Gr\u{f6}\u{df}e");
+    }
 }
@@ -0,0 +1,141 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::TempDir;
+use clap::Parser;
+use slicec::config_file::{apply_config_file, ConfigFile};
+use slicec::diagnostics::Diagnostics;
+use slicec::slice_options::SliceOptions;
+use std::path::Path;
+use test_case::test_case;
+
+#[test]
+fn config_file_parses() {
+    // Arrange
+    let text = r#"
+        # The files to compile.
+        sources = ["a.slice", "b.slice"]
+        references = [
+            "common/", # with a trailing comma
+        ]
+        defined-symbols = ["DEBUG"]
+        output-dir = "generated"
+
+        [lints]
+        allow = ["Deprecated"]
+        deny = ["All"]
+    "#;
+
+    // Act
+    let config = ConfigFile::parse(text).unwrap();
+
+    // Assert
+    let expected = ConfigFile {
+        sources: Some(vec!["a.slice".to_owned(), "b.slice".to_owned()]),
+        references: Some(vec!["common/".to_owned()]),
+        defined_symbols: Some(vec!["DEBUG".to_owned()]),
+        output_dir: Some("generated".to_owned()),
+        allowed_lints: vec!["Deprecated".to_owned()],
+        denied_lints: vec!["All".to_owned()],
+    };
+    assert_eq!(config, expected);
+}
+
+#[test]
+fn empty_config_file_parses() {
+    // Act
+    let config = ConfigFile::parse("# Nothing to see here.\n").unwrap();
+
+    // Assert
+    assert_eq!(config, ConfigFile::default());
+}
+
+#[test_case("fake = []", "line 1: unknown option 'fake'"; "unknown option")]
+#[test_case("[fake]", "line 1: unknown table 'fake'"; "unknown table")]
+#[test_case("\n[lints]\nsources = []", "line 3: unknown option 'sources' in table 'lints'"; "option in wrong table")]
+#[test_case("sources = \"a.slice\"", "line 1: 'sources' must be an array of strings"; "wrong type")]
+#[test_case("output-dir = \"out", "line 1: unterminated string"; "unterminated string")]
+#[test_case("sources = [\"a\" \"b\"]", "line 1: expected ']' but found '\"'"; "missing comma")]
+#[test_case("[lints]\nallow = [\"Fake\"]", "line 2: 'Fake' is not a valid lint identifier"; "invalid lint")]
+fn malformed_config_files_are_rejected(text: &str, expected_message: &str) {
+    // Act
+    let error = ConfigFile::parse(text).unwrap_err();
+
+    // Assert
+    assert_eq!(error, expected_message);
+}
+
+#[test]
+fn command_line_options_take_precedence() {
+    // Arrange
+    let config = ConfigFile {
+        sources: Some(vec!["config.slice".to_owned()]),
+        references: Some(vec!["config/".to_owned()]),
+        output_dir: Some("config-out".to_owned()),
+        allowed_lints: vec!["BrokenDocLink".to_owned(), "Deprecated".to_owned()],
+        denied_lints: vec!["IncorrectDocComment".to_owned()],
+        ..Default::default()
+    };
+    let mut options = SliceOptions {
+        sources: vec!["cli.slice".to_owned()],
+        denied_lints: vec!["Deprecated".to_owned()],
+        ..Default::default()
+    };
+
+    // Act
+    config.apply_to(&mut options);
+
+    // Assert
+    assert_eq!(options.sources, ["cli.slice"]);
+    assert_eq!(options.references, ["config/"]);
+    assert_eq!(options.output_dir.as_deref(), Some("config-out"));
+    assert_eq!(options.allowed_lints, ["BrokenDocLink"]);
+    assert_eq!(options.denied_lints, ["Deprecated", "IncorrectDocComment"]);
+}
+
+#[test]
+fn config_file_paths_are_relative_to_the_file() {
+    // Arrange
+    let directory = TempDir::new("relative");
+    let path = directory.write_file("slice.toml", "sources = [\"a.slice\"]\noutput-dir = \"out\"");
+    let mut options = SliceOptions::try_parse_from(["slicec", "--config", path.to_str().unwrap()]).unwrap();
+
+    // Act
+    let mut diagnostics = Diagnostics::new();
+    apply_config_file(&mut options, &mut diagnostics);
+
+    // Assert
+    assert!(diagnostics.is_empty());
+    assert_eq!(Path::new(&options.sources[0]), directory.join("a.slice"));
+    assert_eq!(Path::new(options.output_dir.as_ref().unwrap()), directory.join("out"));
+}
+
+#[test]
+fn missing_config_file_is_reported() {
+    // Arrange
+    let mut options = SliceOptions {
+        config: Some("does-not-exist/slice.toml".to_owned()),
+        ..Default::default()
+    };
+
+    // Act
+    let mut diagnostics = Diagnostics::new();
+    apply_config_file(&mut options, &mut diagnostics);
+
+    // Assert
+    let diagnostics = diagnostics.into_inner();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code(), "E001");
+}
+
+#[test]
+fn sources_are_not_required_with_a_config_file() {
+    // Act
+    let with_config = SliceOptions::try_parse_from(["slicec", "--config", "slice.toml"]);
+    let without_config = SliceOptions::try_parse_from(["slicec"]);
+
+    // Assert
+    assert!(with_config.is_ok());
+    assert!(without_config.is_err());
+}
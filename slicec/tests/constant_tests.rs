@@ -0,0 +1,607 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod constants {
+
+    use crate::test_helpers::*;
+    use slicec::diagnostics::{Diagnostic, Error};
+    use slicec::grammar::*;
+    use slicec::slice_file::Span;
+    use test_case::test_case;
+
+    #[test_case("5", ConstantValue::Integer(5); "integer")]
+    #[test_case("-5", ConstantValue::Integer(-5); "negative integer")]
+    #[test_case("true", ConstantValue::Bool(true); "bool")]
+    #[test_case("\"hello\"", ConstantValue::String("hello".to_owned()); "string")]
+    #[test_case("r#\"C:\\\"x\"\"#", ConstantValue::String("C:\\\"x\"".to_owned()); "raw string")]
+    fn can_define_literal_constants(literal: &str, expected: ConstantValue) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C = {literal}
+            "
+        );
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let constant = ast.find_element::<Constant>("Test::C").unwrap();
+        assert_eq!(constant.value(), &expected);
+    }
+
+    #[test_case("1 + 2 * 3", 7; "precedence")]
+    #[test_case("(1 + 2) * 3", 9; "parentheses")]
+    #[test_case("10 - 4 - 3", 3; "left associativity")]
+    #[test_case("17 / 5", 3; "division")]
+    #[test_case("17 % 5", 2; "remainder")]
+    #[test_case("1 << 10", 1024; "shift left")]
+    #[test_case("1024 >> 3", 128; "shift right")]
+    #[test_case("8 | 6 & 3 ^ 1", 11; "bitwise operators")]
+    #[test_case("~0", -1; "complement")]
+    #[test_case("-(2 + 3)", -5; "negation")]
+    fn can_evaluate_integer_expressions(expression: &str, expected: i128) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C = {expression}
+            "
+        );
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let constant = ast.find_element::<Constant>("Test::C").unwrap();
+        assert_eq!(constant.value(), &ConstantValue::Integer(expected));
+    }
+
+    #[test_case("0b1010", 10; "binary")]
+    #[test_case("0o777", 511; "octal")]
+    #[test_case("0x1F", 31; "hexadecimal")]
+    #[test_case("'a'", 97; "character")]
+    #[test_case("'\\n'", 10; "escaped character")]
+    #[test_case("'\\''", 39; "escaped quote")]
+    #[test_case("'\\u{1F60A}'", 0x1F60A; "unicode escape")]
+    #[test_case("0b1 << 4 | 0o1", 17; "mixed bases")]
+    fn can_use_integer_literals_of_any_base(literal: &str, expected: i128) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C = {literal}
+            "
+        );
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let constant = ast.find_element::<Constant>("Test::C").unwrap();
+        assert_eq!(constant.value(), &ConstantValue::Integer(expected));
+    }
+
+    #[test_case("''"; "empty")]
+    #[test_case("'ab'"; "multiple characters")]
+    fn character_literals_must_contain_one_character(literal: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C = {literal}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::InvalidCharacterLiteral {
+            literal: literal.trim_matches('\'').to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn string_literals_can_contain_escape_sequences() {
+        // Arrange
+        let slice = r#"
+            module Test
+            const S = "a\tb\nc \"d\" \\ \u{e9}\u{1F60A}"
+        "#;
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let constant = ast.find_element::<Constant>("Test::S").unwrap();
+        let expected = "a\tb\nc \"d\" \\ \u{e9}\u{1F60A}";
+        assert_eq!(constant.value(), &ConstantValue::String(expected.to_owned()));
+    }
+
+    #[test_case("\\q", "\\q", "unknown escape character 'q'", 2; "unknown escape")]
+    #[test_case("\\u0041", "\\u", "expected '{' after '\\u'", 2; "missing open brace")]
+    #[test_case("\\u{41", "\\u{41", "expected hexadecimal digits followed by a closing '}'", 5; "missing close brace")]
+    #[test_case("\\u{}", "\\u{}", "unicode escapes must contain between 1 and 6 hexadecimal digits", 4; "no digits")]
+    #[test_case("\\u{D800}", "\\u{D800}", "'D800' isn't a valid Unicode code point", 8; "surrogate")]
+    fn malformed_escape_sequences_are_reported(escape: &str, sequence: &str, reason: &str, length: usize) {
+        // Arrange
+        let slice = format!("module Test\nconst S = \"ab{escape}\"");
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert: the span only covers the escape sequence, which starts after the opening quote and 'ab'.
+        let expected = Diagnostic::new(Error::InvalidEscapeSequence {
+            sequence: sequence.to_owned(),
+            reason: reason.to_owned(),
+        })
+        .set_span(&Span::new((2, 14).into(), (2, 14 + length).into(), "string-0"));
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn can_evaluate_bool_and_string_expressions() {
+        // Arrange
+        let slice = "
+            module Test
+            const B = true & ~false
+            const S = \"foo\" + \"bar\"
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let b = ast.find_element::<Constant>("Test::B").unwrap();
+        let s = ast.find_element::<Constant>("Test::S").unwrap();
+        assert_eq!(b.value(), &ConstantValue::Bool(true));
+        assert_eq!(s.value(), &ConstantValue::String("foobar".to_owned()));
+    }
+
+    #[test]
+    fn constants_can_reference_other_constants() {
+        // Arrange
+        let slice = "
+            module Test
+            const MaxSize: uint32 = KiB * 64
+            const KiB = 1024
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let constant = ast.find_element::<Constant>("Test::MaxSize").unwrap();
+        assert_eq!(constant.value(), &ConstantValue::Integer(65536));
+        assert!(matches!(constant.data_type.as_ref().unwrap().definition(), Primitive::UInt32));
+    }
+
+    #[test]
+    fn constants_can_reference_constants_in_other_modules() {
+        // Arrange
+        let slice1 = "
+            module A
+            const X = 3
+        ";
+        let slice2 = "
+            module B
+            const Y = ::A::X + 1
+        ";
+
+        // Act
+        let ast = parse_multiple_for_ast(&[slice1, slice2]);
+
+        // Assert
+        let constant = ast.find_element::<Constant>("B::Y").unwrap();
+        assert_eq!(constant.value(), &ConstantValue::Integer(4));
+    }
+
+    #[test]
+    fn references_must_be_constants() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {}
+            const C = S + 1
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::TypeMismatch {
+            expected: "constant".to_owned(),
+            actual: "struct".to_owned(),
+            is_concrete: true,
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn references_must_exist() {
+        // Arrange
+        let slice = "
+            module Test
+            const C = Fake * 2
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DoesNotExist {
+            identifier: "Fake".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn self_referential_constants_are_disallowed() {
+        // Arrange
+        let slice = "
+            module Test
+            const A = B + 1
+            const B = A + 1
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::SelfReferentialConstant {
+            identifier: "Test::A".to_owned(),
+            cycle: "Test::A -> Test::B -> Test::A".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("170141183460469231731687303715884105727 + 1"; "addition")]
+    #[test_case("(1 << 126) * 4"; "multiplication")]
+    #[test_case("1 << 127"; "shift left")]
+    fn overflows_are_disallowed(expression: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C = {expression}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::ConstantExpressionOverflows);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("/"; "division")]
+    #[test_case("%"; "remainder")]
+    fn division_by_zero_is_disallowed(operator: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const Zero = 0
+                const C = 5 {operator} Zero
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DivisionByZero);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("1 + true", "+", "'integer' and 'bool'"; "integer and bool")]
+    #[test_case("\"a\" * 2", "*", "'string' and 'integer'"; "string and integer")]
+    #[test_case("-true", "-", "'bool'"; "negated bool")]
+    fn operators_must_support_operand_types(expression: &str, operator: &str, operands: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C = {expression}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::InvalidOperandTypes {
+            operator: operator.to_owned(),
+            operands: operands.to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn values_must_fit_within_their_declared_type() {
+        // Arrange
+        let slice = "
+            module Test
+            const C: uint8 = 255 + 1
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::ConstantValueOutOfBounds {
+            identifier: "C".to_owned(),
+            value: 256,
+            min: 0,
+            max: 255,
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("1 << 100", 1 << 100; "far past the maximum")]
+    #[test_case("18446744073709551615 + 1", 18446744073709551616; "just past the maximum")]
+    #[test_case("-9223372036854775808 - 1", -9223372036854775809; "just past the minimum")]
+    fn untyped_values_must_fit_within_64_bits(expression: &str, value: i128) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C = {expression}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::ConstantValueOutOfBounds {
+            identifier: "C".to_owned(),
+            value,
+            min: i64::MIN.into(),
+            max: u64::MAX.into(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("18446744073709551615"; "maximum")]
+    #[test_case("-9223372036854775808"; "minimum")]
+    fn untyped_values_can_use_the_full_64_bit_range(expression: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C = {expression}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        check_diagnostics(diagnostics, [] as [Diagnostic; 0]);
+    }
+
+    #[test]
+    fn values_must_match_their_declared_type() {
+        // Arrange
+        let slice = "
+            module Test
+            const C: string = 5
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::TypeMismatch {
+            expected: "string".to_owned(),
+            actual: "integer".to_owned(),
+            is_concrete: true,
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("float64"; "floats")]
+    #[test_case("int32?"; "optionals")]
+    fn unsupported_types_are_disallowed(type_string: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C: {type_string} = 5
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::ConstantTypeNotSupported {
+            identifier: "C".to_owned(),
+            kind: type_string.to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn constant_types_must_be_primitives() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {}
+            const C: S = 5
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::TypeMismatch {
+            expected: "primitive".to_owned(),
+            actual: "struct".to_owned(),
+            is_concrete: true,
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn shift_operators_must_be_contiguous() {
+        // Arrange
+        let slice = "
+            module Test
+            const C = 1 < < 2
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::Syntax {
+            message: "expected '<<', but found whitespace between its characters".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn can_define_associated_constants() {
+        // Arrange
+        let slice = "
+            module Test
+            struct Color {
+                r: uint8
+                const Max = 255
+                g: uint8
+                b: uint8
+            }
+            enum Shape : uint8 {
+                Circle
+                const Count = 2
+                Square
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let struct_def = ast.find_element::<Struct>("Test::Color").unwrap();
+        assert_eq!(struct_def.fields().len(), 3);
+        assert_eq!(struct_def.constants().len(), 1);
+        assert_eq!(struct_def.constants()[0].parser_scoped_identifier(), "Test::Color::Max");
+        assert_eq!(struct_def.constants()[0].value(), &ConstantValue::Integer(255));
+
+        let enum_def = ast.find_element::<Enum>("Test::Shape").unwrap();
+        assert_eq!(enum_def.enumerators().len(), 2);
+        assert_eq!(enum_def.enumerators()[1].value(), 1);
+        assert_eq!(enum_def.constants().len(), 1);
+        assert_eq!(enum_def.constants()[0].value(), &ConstantValue::Integer(2));
+    }
+
+    #[test]
+    fn associated_constants_can_be_referenced() {
+        // Arrange
+        let slice = "
+            module Test
+            struct Limits {
+                const Max = 10
+                const Double = Max * 2
+            }
+            enum E : int32 {
+                const First = 5
+                A = First
+            }
+            const Y = Limits::Double + ::Test::Limits::Max
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let double = ast.find_element::<Constant>("Test::Limits::Double").unwrap();
+        assert_eq!(double.value(), &ConstantValue::Integer(20));
+        let y = ast.find_element::<Constant>("Test::Y").unwrap();
+        assert_eq!(y.value(), &ConstantValue::Integer(30));
+        let a = ast.find_element::<Enumerator>("Test::E::A").unwrap();
+        assert_eq!(a.value(), 5);
+    }
+
+    #[test]
+    fn associated_constants_cannot_redefine_members() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {
+                x: int32
+                const x = 1
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::Redefinition {
+            identifier: "x".to_owned(),
+        })
+        .add_note("'x' was previously defined here", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn can_define_interface_constants() {
+        // Arrange
+        let slice = "
+            module Test
+            interface Downloader {
+                const MaxChunkSize = 64 * 1024
+                download(offset: uint64) -> Sequence<uint8>
+                const DefaultOffset = 0
+            }
+            const LargeChunkSize = Downloader::MaxChunkSize * 4
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let interface_def = ast.find_element::<Interface>("Test::Downloader").unwrap();
+        assert_eq!(interface_def.operations().len(), 1);
+        assert_eq!(interface_def.constants().len(), 2);
+        assert_eq!(
+            interface_def.constants()[0].parser_scoped_identifier(),
+            "Test::Downloader::MaxChunkSize",
+        );
+
+        let max_chunk_size = ast.find_element::<Constant>("Test::Downloader::MaxChunkSize").unwrap();
+        assert_eq!(max_chunk_size.value(), &ConstantValue::Integer(65536));
+        let large_chunk_size = ast.find_element::<Constant>("Test::LargeChunkSize").unwrap();
+        assert_eq!(large_chunk_size.value(), &ConstantValue::Integer(262144));
+    }
+
+    #[test]
+    fn interface_constants_cannot_redefine_operations() {
+        // Arrange
+        let slice = "
+            module Test
+            interface I {
+                op()
+                const op = 1
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::Redefinition {
+            identifier: "op".to_owned(),
+        })
+        .add_note("'op' was previously defined here", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+}
@@ -332,11 +332,6 @@ mod type_aliases {
             Diagnostic::new(Error::DoesNotExist {
                 identifier: "Test::Foo".to_owned(),
             }),
-            Diagnostic::new(Error::SelfReferentialTypeAliasNeedsConcreteType {
-                identifier: "Test::Foo".to_owned(),
-            })
-            .add_note("failed to resolve type due to a cycle in its definition", None)
-            .add_note("cycle: Test::Foo -> Test::Foo".to_owned(), None),
             Diagnostic::new(Error::DoesNotExist {
                 identifier: "Test::Foo".to_owned(),
             }),
@@ -345,3 +340,51 @@ mod type_aliases {
         check_diagnostics(diagnostics, expected);
     }
 }
+
+mod find_cycles {
+    use super::*;
+    use slicec::grammar::*;
+    use slicec::utils::cycle_util::find_cycles;
+
+    #[test]
+    fn cycles_are_returned_with_their_complete_path() {
+        // Arrange
+        let slice = "
+            module Test
+            struct A { b: B }
+            struct B { c: Sequence<C> }
+            struct C { a: A }
+            struct Unrelated { a: A }
+        ";
+        let state = parse(slice, None);
+
+        // Act
+        let cycles = find_cycles(&state.ast);
+
+        // Assert
+        assert_eq!(cycles.len(), 1);
+        let cycle = &cycles[0];
+        assert_eq!(cycle.type_id(), "Test::A");
+        assert_eq!(cycle.path(), "Test::A -> Test::B -> Test::C -> Test::A");
+
+        let fields = cycle.links.iter().map(|link| link.field.identifier()).collect::<Vec<_>>();
+        assert_eq!(fields, ["b", "c", "a"]);
+    }
+
+    #[test]
+    fn acyclic_types_have_no_cycles() {
+        // Arrange
+        let slice = "
+            module Test
+            struct A { b: B }
+            struct B { i: int32 }
+        ";
+        let ast = parse_for_ast(slice);
+
+        // Act
+        let cycles = find_cycles(&ast);
+
+        // Assert
+        assert!(cycles.is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
+use slicec::slice_file::Span;
+use test_case::test_case;
+
+#[test_case("int32", "42", ConstantValue::Integer(42); "integer")]
+#[test_case("bool", "true", ConstantValue::Bool(true); "bool")]
+#[test_case("string", "\"hello\"", ConstantValue::String("hello".to_owned()); "string")]
+#[test_case("uint8", "MAX - 1", ConstantValue::Integer(254); "constant expression")]
+fn fields_can_have_default_values(data_type: &str, default: &str, expected: ConstantValue) {
+    // Arrange
+    let slice = format!(
+        "
+            module Test
+            const MAX: uint8 = 255
+            struct S {{ f: {data_type} = {default} }}
+        "
+    );
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let field = ast.find_element::<Field>("Test::S::f").unwrap();
+    assert!(field.default().is_some());
+    assert_eq!(field.default_value(), Some(&expected));
+}
+
+#[test]
+fn trailing_parameters_can_have_default_values() {
+    // Arrange
+    let slice = "
+        module Test
+        interface I {
+            op(a: string, b: int32 = 1, c: bool = false)
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let a = ast.find_element::<Parameter>("Test::I::op::a").unwrap();
+    let b = ast.find_element::<Parameter>("Test::I::op::b").unwrap();
+    let c = ast.find_element::<Parameter>("Test::I::op::c").unwrap();
+    assert_eq!(a.default_value(), None);
+    assert_eq!(b.default_value(), Some(&ConstantValue::Integer(1)));
+    assert_eq!(c.default_value(), Some(&ConstantValue::Bool(false)));
+}
+
+#[test]
+fn parameters_with_default_values_must_be_trailing() {
+    // Arrange
+    let slice = "
+        module Test
+        interface I {
+            op(a: int32 = 0, b: int32)
+        }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::ParameterMissingDefaultValue {
+        parameter_identifier: "b".to_owned(),
+    })
+    .set_span(&Span::new((4, 30).into(), (4, 38).into(), "string-0"))
+    .add_note(
+        "parameter 'a' has a default value",
+        Some(&Span::new((4, 16).into(), (4, 28).into(), "string-0")),
+    );
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn return_members_cannot_have_default_values() {
+    // Arrange
+    let slice = "
+        module Test
+        interface I {
+            op() -> (a: int32 = 0, b: int32)
+        }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::Syntax {
+        message: "return members cannot have default values".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn default_values_must_be_within_bounds() {
+    // Arrange
+    let slice = "
+        module Test
+        struct S { f: uint8 = 256 }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::DefaultValueOutOfBounds {
+        identifier: "f".to_owned(),
+        value: 256,
+        min: 0,
+        max: 255,
+    })
+    .set_span(&Span::new((3, 31).into(), (3, 34).into(), "string-0"));
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn default_values_must_match_their_type() {
+    // Arrange
+    let slice = "
+        module Test
+        struct S { f: bool = \"yes\" }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::TypeMismatch {
+        expected: "bool".to_owned(),
+        actual: "string".to_owned(),
+        is_concrete: true,
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test_case("float64"; "float")]
+#[test_case("int32?"; "optional")]
+#[test_case("Sequence<int32>"; "sequence")]
+fn default_values_require_supported_types(data_type: &str) {
+    // Arrange
+    let slice = format!(
+        "
+            module Test
+            struct S {{ f: {data_type} = 1 }}
+        "
+    );
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::DefaultValueTypeNotSupported {
+        identifier: "f".to_owned(),
+        kind: data_type.to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
@@ -0,0 +1,115 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::TempDir;
+use slicec::compile_from_options;
+use slicec::compile_from_strings;
+use slicec::dependency_file::collect_dependencies;
+use slicec::slice_options::{DependencyFileFormat, SliceOptions};
+use std::collections::{BTreeMap, BTreeSet};
+use test_case::test_case;
+
+/// Compiles the provided Slice files and returns the dependencies of each one.
+/// The files are named `string-0`, `string-1`, etc. in the order they're provided.
+fn dependencies_of(slice: &[&str]) -> BTreeMap<String, BTreeSet<String>> {
+    let state = compile_from_strings(slice, None, |_| {}, |_| {});
+    assert!(!state.diagnostics.has_errors());
+    collect_dependencies(&state.files)
+}
+
+fn set(files: &[&str]) -> BTreeSet<String> {
+    files.iter().map(|&file| file.to_owned()).collect()
+}
+
+#[test]
+fn dependencies_are_transitive() {
+    // Arrange
+    let slice1 = "module Test\nstruct A { b: B }";
+    let slice2 = "module Test\nstruct B { c: Sequence<C> }";
+    let slice3 = "module Test\nstruct C { i: int32 }";
+
+    // Act
+    let dependencies = dependencies_of(&[slice1, slice2, slice3]);
+
+    // Assert
+    assert_eq!(dependencies["string-0"], set(&["string-1", "string-2"]));
+    assert_eq!(dependencies["string-1"], set(&["string-2"]));
+    assert_eq!(dependencies["string-2"], set(&[]));
+}
+
+#[test_case("exception E : Base {}", "exception Base {}"; "exception base")]
+#[test_case("interface I : Base {}", "interface Base {}"; "interface base")]
+#[test_case("interface I { op() throws Base }", "exception Base {}"; "exception specification")]
+#[test_case("class C : Base {}", "class Base {}"; "class base")]
+fn base_types_and_exception_specifications_are_dependencies(definition: &str, base: &str) {
+    // Arrange
+    // Classes are only supported in Slice1 mode.
+    let slice1 = format!("mode = Slice1\nmodule Test\n{definition}");
+    let slice2 = format!("mode = Slice1\nmodule Test\n{base}");
+
+    // Act
+    let dependencies = dependencies_of(&[&slice1, &slice2]);
+
+    // Assert
+    assert_eq!(dependencies["string-0"], set(&["string-1"]));
+}
+
+#[test]
+fn files_defining_type_aliases_are_dependencies() {
+    // Arrange
+    let slice1 = "module Test\nstruct A { b: Alias }";
+    let slice2 = "module Test\ntypealias Alias = Sequence<C>";
+    let slice3 = "module Test\nstruct C {}";
+
+    // Act
+    let dependencies = dependencies_of(&[slice1, slice2, slice3]);
+
+    // Assert
+    assert_eq!(dependencies["string-0"], set(&["string-1", "string-2"]));
+}
+
+#[test_case(DependencyFileFormat::Make, "a.slice: b.slice\nb.slice:\n"; "make")]
+#[test_case(DependencyFileFormat::Json, "{\n  \"a.slice\": [\n    \"b.slice\"\n  ],\n  \"b.slice\": []\n}\n"; "json")]
+fn dependency_files_are_written(format: DependencyFileFormat, expected: &str) {
+    // Arrange
+    let directory = TempDir::new(&format!("depfile-{format:?}"));
+    directory.write_file("a.slice", "module Test\nstruct A { b: B }");
+    directory.write_file("b.slice", "module Test\nstruct B {}");
+    let path = |file: &str| directory.path_string(file);
+    let options = SliceOptions {
+        sources: vec![path("a.slice"), path("b.slice")],
+        depfile: Some(path("deps")),
+        depfile_format: format,
+        ..Default::default()
+    };
+
+    // Act
+    let state = compile_from_options(&options, |_| {}, |_| {});
+
+    // Assert
+    assert!(!state.diagnostics.has_errors());
+    let contents = std::fs::read_to_string(path("deps")).unwrap();
+    let prefix = format!("{}{}", directory.to_str().unwrap(), std::path::MAIN_SEPARATOR);
+    assert_eq!(contents.replace(&prefix, "").replace(&prefix.replace('\\', "\\\\"), ""), expected);
+}
+
+#[test]
+fn dependency_files_are_not_written_in_dry_runs() {
+    // Arrange
+    let directory = TempDir::new("depfile-dry-run");
+    let source = directory.write_file("a.slice", "module Test\nstruct A {}");
+    let options = SliceOptions {
+        sources: vec![source.display().to_string()],
+        depfile: Some(directory.path_string("deps")),
+        dry_run: true,
+        ..Default::default()
+    };
+
+    // Act
+    let state = compile_from_options(&options, |_| {}, |_| {});
+
+    // Assert
+    assert!(!state.diagnostics.has_errors());
+    assert!(!directory.join("deps").exists());
+}
@@ -0,0 +1,82 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::utils::dependency_order_util::sort_by_dependencies;
+
+fn sorted_identifiers(slice: &str) -> Vec<String> {
+    let ast = parse_for_ast(slice);
+    let sorted = sort_by_dependencies(&ast);
+    sorted.into_iter().map(|entity| entity.identifier().to_owned()).collect()
+}
+
+#[test]
+fn definitions_come_after_their_dependencies() {
+    // Arrange
+    let slice = "
+        module Test
+
+        interface I : Base {
+            op(s: Outer) -> Result<Color, Length>
+        }
+        interface Base {}
+        struct Outer { inner: Sequence<Inner>, lookup: Dictionary<string, Alias> }
+        typealias Alias = Inner
+        struct Inner { color: Color }
+        enum Color : uint8 { Red, Green }
+        custom Length
+    ";
+
+    // Act
+    let sorted = sorted_identifiers(slice);
+
+    // Assert
+    let expected = ["Base", "Color", "Inner", "Outer", "Length", "I", "Alias"];
+    assert_eq!(sorted, expected);
+}
+
+#[test]
+fn independent_definitions_keep_their_declaration_order() {
+    // Arrange
+    let slice = "
+        module Test
+
+        struct C {}
+        struct A {}
+        struct B {}
+    ";
+
+    // Act
+    let sorted = sorted_identifiers(slice);
+
+    // Assert
+    assert_eq!(sorted, ["C", "A", "B"]);
+}
+
+#[test]
+fn class_cycles_are_broken() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+
+        class Node : Base {
+            next: Node?
+            parent: Tree?
+        }
+        class Tree {
+            root: Node?
+        }
+        class Base {}
+        exception Error : BaseError { tree: Tree }
+        exception BaseError {}
+    ";
+
+    // Act
+    let sorted = sorted_identifiers(slice);
+
+    // Assert
+    // 'Node' is reached again while sorting 'Tree' (one of its own dependencies), so the cycle is broken there.
+    assert_eq!(sorted, ["Base", "Tree", "Node", "BaseError", "Error"]);
+}
@@ -0,0 +1,55 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use slicec::compile_from_strings;
+use slicec::diagnostics::examples::{all_examples, example_for, ExampleInput};
+use slicec::diagnostics::{Error, Lint};
+use slicec::slice_options::SliceOptions;
+use test_helpers::diagnostics_from_compilation_state;
+
+#[test]
+fn every_diagnostic_has_exactly_one_example() {
+    // Arrange
+    let codes = Error::ALL_CODES.iter().chain(Lint::ALL_CODES);
+
+    // Act
+    let example_codes = all_examples().iter().map(|example| example.code).collect::<Vec<_>>();
+
+    // Assert
+    assert_eq!(example_codes, codes.copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn every_example_reports_its_diagnostic() {
+    let mut failures = Vec::new();
+    for example in all_examples() {
+        // Arrange
+        let ExampleInput::Slice(files) = example.input else {
+            continue;
+        };
+
+        // Act
+        let state = compile_from_strings(files, None, |_| {}, |_| {});
+        let diagnostics = diagnostics_from_compilation_state(state, &SliceOptions::default());
+
+        // Assert
+        let codes = diagnostics.iter().map(|diagnostic| diagnostic.code()).collect::<Vec<_>>();
+        if !codes.contains(&example.code) {
+            failures.push(format!("example for '{}' reported {codes:?} instead", example.code));
+        }
+    }
+    assert!(failures.is_empty(), "{failures:#?}");
+}
+
+#[test]
+fn examples_can_be_looked_up_by_code() {
+    // Act
+    let example = example_for("E012").unwrap();
+    let missing_example = example_for("E999");
+
+    // Assert
+    assert_eq!(example.code, "E012");
+    assert!(matches!(example.input, ExampleInput::Slice(_)));
+    assert!(missing_example.is_none());
+}
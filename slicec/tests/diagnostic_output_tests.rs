@@ -4,8 +4,39 @@ mod test_helpers;
 
 mod output {
     use crate::test_helpers::parse;
-    use slicec::diagnostic_emitter::DiagnosticEmitter;
-    use slicec::slice_options::{DiagnosticFormat, SliceOptions};
+    use slicec::diagnostic_emitter::{DiagnosticEmitter, DiagnosticStream};
+    use slicec::diagnostics::{Diagnostic, Diagnostics, Error, Lint};
+    use slicec::slice_file::{Location, Span};
+    use slicec::slice_options::{DiagnosticFormat, ErrorOutput, SliceOptions};
+    use std::io::{self, Write};
+    use std::sync::{Arc, Mutex};
+
+    /// A writer whose contents can still be read after it's been handed to the compiler.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// A writer that fails every write, like a closed stderr.
+    struct BrokenOutput;
+
+    impl Write for BrokenOutput {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+    }
 
     #[test]
     fn output_to_json() {
@@ -38,9 +69,9 @@ mod output {
 
         // Assert
         let expected = concat!(
-            r#"{"message":"comment has a 'param' tag for 'x', but operation 'op' has no parameter with that name","severity":"warning","span":{"start":{"row":5,"col":17},"end":{"row":5,"col":25},"file":"string-0"},"notes":[],"error_code":"IncorrectDocComment"}"#,
+            r#"{"message":"comment has a 'param' tag for 'x', but operation 'op' has no parameter with that name","severity":"warning","span":{"start":{"row":5,"col":17},"end":{"row":5,"col":25},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"IncorrectDocComment"}"#,
             "\n",
-            r#"{"message":"invalid enum 'E': enums must contain at least one enumerator","severity":"error","span":{"start":{"row":9,"col":9},"end":{"row":9,"col":15},"file":"string-0"},"notes":[],"error_code":"E010"}"#,
+            r#"{"message":"invalid enum 'E': enums must contain at least one enumerator","severity":"error","span":{"start":{"row":9,"col":9},"end":{"row":9,"col":15},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"E010"}"#,
             "\n",
         );
         assert_eq!(expected, String::from_utf8(output).unwrap());
@@ -172,7 +203,7 @@ error [E010]: invalid enum 'E': enums must contain at least one enumerator
 
         // Assert: Only one of the two lints should be allowed.
         let expected = concat!(
-            r#"{"message":"comment has a 'param' tag for 'x', but operation 'op' has no parameter with that name","severity":"warning","span":{"start":{"row":6,"col":21},"end":{"row":6,"col":29},"file":"string-0"},"notes":[],"error_code":"IncorrectDocComment"}"#,
+            r#"{"message":"comment has a 'param' tag for 'x', but operation 'op' has no parameter with that name","severity":"warning","span":{"start":{"row":6,"col":21},"end":{"row":6,"col":29},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"IncorrectDocComment"}"#,
             "\n",
         );
         assert_eq!(expected, String::from_utf8(output).unwrap());
@@ -210,4 +241,491 @@ error [E010]: invalid enum 'E': enums must contain at least one enumerator
 ";
         assert_eq!(expected, String::from_utf8(output).unwrap());
     }
+
+    #[test]
+    fn output_to_console_in_pretty_format() {
+        let slice = "
+        module Foo
+        struct S {}
+        struct S {}
+        ";
+
+        // Disable ANSI color codes.
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Pretty,
+            disable_color: true,
+            ..Default::default()
+        };
+
+        let state = parse(slice, Some(&options));
+        let diagnostics = state.diagnostics.into_updated(&state.ast, &state.files, &options);
+
+        let mut output: Vec<u8> = Vec::new();
+        let mut emitter = DiagnosticEmitter::new(&mut output, &options, &state.files);
+
+        // Act
+        emitter.emit_diagnostics(diagnostics).unwrap();
+
+        // Assert: notes in the same file are rendered inline.
+        let expected = "\
+error[E012]: redefinition of 'S'
+ --> string-0:4:16
+  |
+3 |         struct S {}
+  |                - 'S' was previously defined here
+4 |         struct S {}
+  |                ^
+
+";
+        assert_eq!(expected, String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn pretty_format_renders_notes_from_other_files_separately() {
+        let slice = "module Foo";
+
+        // Disable ANSI color codes.
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Pretty,
+            disable_color: true,
+            ..Default::default()
+        };
+
+        let state = parse(slice, Some(&options));
+        let span = Span::new(Location { row: 1, col: 8 }, Location { row: 1, col: 11 }, "string-0");
+        let other_span = Span::new(Location { row: 12, col: 1 }, Location { row: 12, col: 4 }, "other.slice");
+        let diagnostics = vec![Diagnostic::new(Error::Syntax { message: "foo".to_owned() })
+            .set_span(&span)
+            .add_note("see here", Some(&other_span))
+            .add_note("some advice", None)];
+
+        let mut output: Vec<u8> = Vec::new();
+        let mut emitter = DiagnosticEmitter::new(&mut output, &options, &state.files);
+
+        // Act
+        emitter.emit_diagnostics(diagnostics).unwrap();
+
+        // Assert: the other file isn't available, so only its location can be rendered.
+        let expected = "\
+error[E002]: invalid syntax: foo
+  --> string-0:1:8
+   |
+1  | module Foo
+   |        ^^^
+   |
+   = note: some advice
+note: see here
+  --> other.slice:12:1
+
+";
+        assert_eq!(expected, String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn suggestions_are_rendered_with_their_edits_applied() {
+        let slice = "module Foo\ninterface I {\n    /// @returns x: true\n    op() -> bool\n}";
+
+        // Disable ANSI color codes.
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Pretty,
+            disable_color: true,
+            ..Default::default()
+        };
+
+        let state = parse(slice, Some(&options));
+        let diagnostics = state.diagnostics.into_updated(&state.ast, &state.files, &options);
+
+        let mut output: Vec<u8> = Vec::new();
+        let mut emitter = DiagnosticEmitter::new(&mut output, &options, &state.files);
+
+        // Act
+        emitter.emit_diagnostics(diagnostics).unwrap();
+
+        // Assert
+        let expected = "\
+warning[IncorrectDocComment]: comment has a 'returns' tag for 'x', but operation 'op' doesn't return anything with that name
+ --> string-0:3:9
+  |
+3 |     /// @returns x: true
+  |         ^^^^^^^^^^
+4 |     op() -> bool
+  |     ------------ operation 'op' returns a single unnamed type
+help: try removing the identifier from your comment
+  |
+3 |     /// @returns: true
+  |         ~~~~~~~~
+
+";
+        assert_eq!(expected, String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn suggestions_are_serialized_to_json() {
+        let slice = "module Foo";
+
+        // Set the output format to JSON.
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Json,
+            ..Default::default()
+        };
+
+        let state = parse(slice, Some(&options));
+        let span = Span::new(Location { row: 1, col: 8 }, Location { row: 1, col: 11 }, "string-0");
+        let diagnostics = vec![Diagnostic::new(Error::Syntax { message: "foo".to_owned() })
+            .set_span(&span)
+            .add_suggestion("rename it", &span, "Bar")];
+
+        let mut output: Vec<u8> = Vec::new();
+        let mut emitter = DiagnosticEmitter::new(&mut output, &options, &state.files);
+
+        // Act
+        emitter.emit_diagnostics(diagnostics).unwrap();
+
+        // Assert
+        let expected = concat!(
+            r#"{"message":"invalid syntax: foo","severity":"error","span":{"start":{"row":1,"col":8},"end":{"row":1,"col":11},"file":"string-0"},"notes":[],"suggestions":[{"message":"rename it","span":{"start":{"row":1,"col":8},"end":{"row":1,"col":11},"file":"string-0"},"replacement":"Bar","is_machine_applicable":true}],"error_code":"E002"}"#,
+            "\n",
+        );
+        assert_eq!(expected, String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn duplicate_diagnostics_are_only_emitted_once() {
+        let slice = "module Foo";
+
+        // Set the output format to JSON.
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Json,
+            ..Default::default()
+        };
+
+        let state = parse(slice, Some(&options));
+        let span = Span::new(Location { row: 1, col: 1 }, Location { row: 1, col: 7 }, "string-0");
+        let other_span = Span::new(Location { row: 1, col: 8 }, Location { row: 1, col: 11 }, "string-0");
+        let mut diagnostics = Diagnostics::new();
+        Diagnostic::new(Error::Syntax { message: "foo".to_owned() })
+            .set_span(&span)
+            .push_into(&mut diagnostics);
+        Diagnostic::new(Error::Syntax { message: "foo".to_owned() })
+            .set_span(&span)
+            .push_into(&mut diagnostics);
+        Diagnostic::new(Error::Syntax { message: "foo".to_owned() })
+            .set_span(&other_span)
+            .push_into(&mut diagnostics);
+
+        let mut output: Vec<u8> = Vec::new();
+        let mut emitter = DiagnosticEmitter::new(&mut output, &options, &state.files);
+
+        // Act
+        let diagnostics = diagnostics.into_updated(&state.ast, &state.files, &options);
+        emitter.emit_diagnostics(diagnostics).unwrap();
+
+        // Assert: the diagnostic with a different span isn't a duplicate.
+        let expected = concat!(
+            r#"{"message":"invalid syntax: foo","severity":"error","span":{"start":{"row":1,"col":1},"end":{"row":1,"col":7},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"E002"}"#,
+            "\n",
+            r#"{"message":"invalid syntax: foo","severity":"error","span":{"start":{"row":1,"col":8},"end":{"row":1,"col":11},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"E002"}"#,
+            "\n",
+        );
+        assert_eq!(expected, String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn diagnostics_without_spans_are_only_duplicates_if_their_messages_match() {
+        // Arrange
+        let options = SliceOptions::default();
+        let state = parse("module Foo", Some(&options));
+        let io_error = |path: &str| Error::IO {
+            action: "read",
+            path: path.to_owned(),
+            error: std::io::ErrorKind::NotFound.into(),
+        };
+
+        let mut diagnostics = Diagnostics::new();
+        Diagnostic::new(io_error("a.slice")).push_into(&mut diagnostics);
+        Diagnostic::new(io_error("b.slice")).push_into(&mut diagnostics);
+        Diagnostic::new(io_error("a.slice")).push_into(&mut diagnostics);
+
+        // Act
+        let diagnostics = diagnostics.into_updated(&state.ast, &state.files, &options);
+
+        // Assert
+        let messages = diagnostics.iter().map(Diagnostic::message).collect::<Vec<_>>();
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].contains("a.slice"));
+        assert!(messages[1].contains("b.slice"));
+    }
+
+    #[test]
+    fn diagnostics_past_the_limit_are_summarized() {
+        let slice = "
+        module Foo
+        enum A : int8 {}
+        enum B : int8 {}
+        enum C : int8 {}
+        ";
+
+        // Disable ANSI color codes, and only allow 1 diagnostic per code.
+        let options = SliceOptions {
+            disable_color: true,
+            max_diagnostics_per_code: Some(1),
+            ..Default::default()
+        };
+
+        let state = parse(slice, Some(&options));
+        let diagnostics = state.diagnostics.into_updated(&state.ast, &state.files, &options);
+
+        let mut output: Vec<u8> = Vec::new();
+        let mut emitter = DiagnosticEmitter::new(&mut output, &options, &state.files);
+
+        // Act
+        emitter.emit_diagnostics(diagnostics).unwrap();
+
+        // Assert
+        let expected = "\
+error [E010]: invalid enum 'A': enums must contain at least one enumerator
+ --> string-0:3:9
+  |
+3 |         enum A : int8 {}
+  |         ------
+  |
+note: 2 similar diagnostic(s) with code 'E010' were suppressed
+";
+        assert_eq!(expected, String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn listeners_are_notified_of_reported_diagnostics() {
+        // Arrange
+        let codes = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let listener_codes = codes.clone();
+        let mut diagnostics = Diagnostics::new();
+        diagnostics.set_listener(move |diagnostic| listener_codes.borrow_mut().push(diagnostic.code().to_owned()));
+
+        let mut other_diagnostics = Diagnostics::new();
+        Diagnostic::new(Error::Syntax { message: "bar".to_owned() }).push_into(&mut other_diagnostics);
+
+        // Act
+        Diagnostic::new(Error::Syntax { message: "foo".to_owned() }).push_into(&mut diagnostics);
+        let lint = Lint::Deprecated { identifier: "Bar".to_owned(), reason: None };
+        Diagnostic::new(lint).push_into(&mut diagnostics);
+        diagnostics.extend(other_diagnostics);
+
+        // Assert
+        assert_eq!(*codes.borrow(), ["E002", "Deprecated", "E002"]);
+    }
+
+    #[test]
+    fn only_errors_are_streamed() {
+        // Arrange
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Ndjson,
+            max_diagnostics_per_code: Some(2),
+            ..Default::default()
+        };
+        let span = Span::new(Location { row: 1, col: 1 }, Location { row: 1, col: 7 }, "string-0");
+        let other_span = Span::new(Location { row: 1, col: 8 }, Location { row: 1, col: 11 }, "string-0");
+        let lint = Lint::Deprecated { identifier: "Bar".to_owned(), reason: None };
+        let diagnostics = [
+            Diagnostic::new(Error::Syntax { message: "foo".to_owned() }).set_span(&span),
+            Diagnostic::new(lint).set_span(&span),
+            Diagnostic::new(Error::Syntax { message: "foo".to_owned() }).set_span(&span),
+            Diagnostic::new(Error::Syntax { message: "foo".to_owned() }).set_span(&other_span),
+            Diagnostic::new(Error::Syntax { message: "foo".to_owned() }),
+        ];
+
+        let mut output: Vec<u8> = Vec::new();
+        let mut stream = DiagnosticStream::new(&mut output, &options);
+
+        // Act
+        for diagnostic in &diagnostics {
+            stream.emit_diagnostic(diagnostic).unwrap();
+        }
+
+        // Assert: the lint, the duplicate, and the error past the limit aren't streamed.
+        let expected = concat!(
+            r#"{"message":"invalid syntax: foo","severity":"error","span":{"start":{"row":1,"col":1},"end":{"row":1,"col":7},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"E002"}"#,
+            "\n",
+            r#"{"message":"invalid syntax: foo","severity":"error","span":{"start":{"row":1,"col":8},"end":{"row":1,"col":11},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"E002"}"#,
+            "\n",
+        );
+        assert_eq!(expected, String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn deferred_diagnostics_exclude_errors() {
+        let slice = "
+        module Foo
+
+        interface I {
+            /// @param x: this is an x
+            op()
+        }
+
+        enum A : int8 {}
+        enum B : int8 {}
+        ";
+
+        // Set the output format to NDJSON, and only allow 1 diagnostic per code.
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Ndjson,
+            max_diagnostics_per_code: Some(1),
+            error_output: Some(ErrorOutput::new(io::sink())),
+            ..Default::default()
+        };
+
+        let state = parse(slice, Some(&options));
+        let diagnostics = state.diagnostics.into_updated(&state.ast, &state.files, &options);
+
+        let mut output: Vec<u8> = Vec::new();
+        let mut emitter = DiagnosticEmitter::new(&mut output, &options, &state.files);
+
+        // Act
+        emitter.emit_deferred_diagnostics(diagnostics).unwrap();
+
+        // Assert: errors are not emitted, but the errors that were suppressed are still summarized.
+        let expected = concat!(
+            r#"{"message":"comment has a 'param' tag for 'x', but operation 'op' has no parameter with that name","severity":"warning","span":{"start":{"row":5,"col":17},"end":{"row":5,"col":25},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"IncorrectDocComment"}"#,
+            "\n",
+            r#"{"message":"1 similar diagnostic(s) with code 'E010' were suppressed","severity":"note","span":null,"notes":[],"suggestions":[],"error_code":"E010"}"#,
+            "\n",
+        );
+        assert_eq!(expected, String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn errors_are_streamed_to_the_error_output() {
+        // Arrange
+        let slice = "
+        module Foo
+        enum A : int8 {}
+        ";
+        let buffer = SharedBuffer::default();
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Ndjson,
+            error_output: Some(ErrorOutput::new(buffer.clone())),
+            ..Default::default()
+        };
+
+        // Act
+        let state = parse(slice, Some(&options));
+
+        // Assert
+        let expected = concat!(
+            r#"{"message":"invalid enum 'A': enums must contain at least one enumerator","severity":"error","span":{"start":{"row":3,"col":9},"end":{"row":3,"col":15},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"E010"}"#,
+            "\n",
+        );
+        assert_eq!(expected, String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap());
+        assert!(state.diagnostics.has_errors());
+    }
+
+    #[test]
+    fn broken_error_outputs_are_ignored() {
+        // Arrange
+        let slice = "
+        module Foo
+        enum A : int8 {}
+        ";
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Ndjson,
+            error_output: Some(ErrorOutput::new(BrokenOutput)),
+            ..Default::default()
+        };
+
+        // Act
+        let state = parse(slice, Some(&options));
+
+        // Assert: the error is still recorded, even though it couldn't be streamed.
+        assert!(state.diagnostics.has_errors());
+    }
+
+    #[test]
+    fn diagnostics_are_emitted_to_the_error_output() {
+        // Arrange
+        let slice = "
+        module Foo
+        enum A : int8 {}
+        ";
+        let buffer = SharedBuffer::default();
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Json,
+            error_output: Some(ErrorOutput::new(buffer.clone())),
+            ..Default::default()
+        };
+        let state = parse(slice, Some(&options));
+
+        // Act
+        let has_errors = state.emit_diagnostics(&options);
+
+        // Assert
+        assert!(has_errors.unwrap());
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains(r#""error_code":"E010""#), "{output}");
+    }
+
+    #[test]
+    fn failing_to_emit_diagnostics_returns_an_error() {
+        // Arrange
+        let slice = "
+        module Foo
+        enum A : int8 {}
+        ";
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Json,
+            error_output: Some(ErrorOutput::new(BrokenOutput)),
+            ..Default::default()
+        };
+        let state = parse(slice, Some(&options));
+
+        // Act
+        let result = state.emit_diagnostics(&options);
+
+        // Assert
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+    }
+}
+
+mod ordering {
+    use crate::test_helpers::*;
+    use slicec::slice_options::SliceOptions;
+
+    #[test]
+    fn validation_diagnostics_are_reported_in_file_order() {
+        // Arrange
+        let slices = (0..32).map(|i| format!("module Test{i}\nenum E : int8 {{}}")).collect::<Vec<_>>();
+        let slices = slices.iter().map(String::as_str).collect::<Vec<_>>();
+
+        // Act
+        let diagnostics = parse_multiple_for_diagnostics(&slices);
+
+        // Assert
+        let files = diagnostics.iter().map(|d| d.span().unwrap().file.clone()).collect::<Vec<_>>();
+        let expected = (0..32).map(|i| format!("string-{i}")).collect::<Vec<_>>();
+        assert_eq!(files, expected);
+    }
+
+    #[test]
+    fn diagnostics_can_be_sorted_by_location() {
+        // Arrange
+        let slice = "
+            module Test
+            enum E : int8 {}
+            struct S {};
+        ";
+        let options = SliceOptions {
+            sort_diagnostics: true,
+            ..Default::default()
+        };
+
+        // Act
+        let unsorted = diagnostics_from_compilation_state(parse(slice, None), &SliceOptions::default());
+        let sorted = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+        // Assert: the lint is reported while parsing, before the error is reported during validation.
+        let rows = |diagnostics: &[slicec::diagnostics::Diagnostic]| {
+            diagnostics.iter().map(|d| d.span().unwrap().start.row).collect::<Vec<_>>()
+        };
+        assert_eq!(rows(&unsorted), [4, 3]);
+        assert_eq!(rows(&sorted), [3, 4]);
+    }
 }
@@ -0,0 +1,265 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::TempDir;
+use slicec::compilation_state::CompilationState;
+use slicec::diagram::generate_diagram;
+use slicec::slice_options::{DiagramFormat, InMemoryFile, SliceOptions};
+
+const WIDGETS_SLICE: &str = "
+module Acme::Widgets
+
+interface Widget : Base {
+    spin(gears: Sequence<Gear>) -> Result<Dictionary<string, Size>, SpinError>
+    watch() -> stream Gear
+}
+
+interface Base {}
+
+struct SpinError {
+    gear: Gear?
+}
+
+struct Gear {
+    teeth: int32
+    size: Size
+}
+
+typealias Size = Acme::Units::Length
+";
+
+const UNITS_SLICE: &str = "
+module Acme::Units
+
+enum Length : uint8 { Small, Large }
+
+custom Unused
+";
+
+fn compile(options: SliceOptions) -> CompilationState {
+    let in_memory_files = vec![
+        InMemoryFile::new("widgets.slice", WIDGETS_SLICE, true),
+        InMemoryFile::new("units.slice", UNITS_SLICE, true),
+    ];
+    compile_files(SliceOptions { in_memory_files, ..options })
+}
+
+fn compile_files(options: SliceOptions) -> CompilationState {
+    let state = slicec::compile_from_options(&options, |_| {}, |_| {});
+    assert!(state.diagnostics.is_empty(), "{:?}", state.diagnostics);
+    state
+}
+
+#[test]
+fn dot_diagrams_show_every_relationship() {
+    // Arrange
+    let state = compile(SliceOptions::default());
+
+    // Act
+    let diagram = generate_diagram(&state.files, DiagramFormat::Dot, None, None);
+
+    // Assert
+    let expected = r#"digraph Slice {
+    node [shape=box];
+
+    subgraph "cluster_Acme::Units" {
+        label="Acme::Units";
+        "Acme::Units::Length" [label="<<enum>>\nLength"];
+        "Acme::Units::Unused" [label="<<custom>>\nUnused"];
+    }
+
+    subgraph "cluster_Acme::Widgets" {
+        label="Acme::Widgets";
+        "Acme::Widgets::Base" [label="<<interface>>\nBase"];
+        "Acme::Widgets::Gear" [label="<<struct>>\nGear"];
+        "Acme::Widgets::SpinError" [label="<<struct>>\nSpinError"];
+        "Acme::Widgets::Widget" [label="<<interface>>\nWidget"];
+    }
+
+    "Acme::Widgets::Gear" -> "Acme::Units::Length" [dir=back, arrowtail=diamond, label="size"];
+    "Acme::Widgets::SpinError" -> "Acme::Widgets::Gear" [dir=back, arrowtail=diamond, label="gear"];
+    "Acme::Widgets::Widget" -> "Acme::Units::Length" [style=dashed, arrowhead=open, label="spin"];
+    "Acme::Widgets::Widget" -> "Acme::Widgets::Base" [arrowhead=empty];
+    "Acme::Widgets::Widget" -> "Acme::Widgets::Gear" [style=dashed, arrowhead=open, label="spin, watch"];
+    "Acme::Widgets::Widget" -> "Acme::Widgets::SpinError" [style=dashed, arrowhead=open, label="spin"];
+}
+"#;
+    assert_eq!(diagram, expected);
+}
+
+#[test]
+fn mermaid_diagrams_show_every_relationship() {
+    // Arrange
+    let state = compile(SliceOptions::default());
+
+    // Act
+    let diagram = generate_diagram(&state.files, DiagramFormat::Mermaid, Some("Acme::Widgets"), None);
+
+    // Assert
+    let expected = r#"classDiagram
+    namespace Acme__Units {
+        class Acme__Units__Length["Length"]
+    }
+    namespace Acme__Widgets {
+        class Acme__Widgets__Base["Base"]
+        class Acme__Widgets__Gear["Gear"]
+        class Acme__Widgets__SpinError["SpinError"]
+        class Acme__Widgets__Widget["Widget"]
+    }
+    <<enum>> Acme__Units__Length
+    <<interface>> Acme__Widgets__Base
+    <<struct>> Acme__Widgets__Gear
+    <<struct>> Acme__Widgets__SpinError
+    <<interface>> Acme__Widgets__Widget
+    Acme__Widgets__Gear *-- Acme__Units__Length : size
+    Acme__Widgets__SpinError *-- Acme__Widgets__Gear : gear
+    Acme__Widgets__Widget ..> Acme__Units__Length : spin
+    Acme__Widgets__Base <|-- Acme__Widgets__Widget
+    Acme__Widgets__Widget ..> Acme__Widgets__Gear : spin, watch
+    Acme__Widgets__Widget ..> Acme__Widgets__SpinError : spin
+"#;
+    assert_eq!(diagram, expected);
+}
+
+#[test]
+fn scope_and_depth_limit_which_definitions_are_drawn() {
+    // Arrange
+    let state = compile(SliceOptions::default());
+    let nodes = |scope: &str, depth: usize| {
+        let diagram = generate_diagram(&state.files, DiagramFormat::Mermaid, Some(scope), Some(depth));
+        let classes = diagram.lines().filter_map(|line| line.trim().strip_prefix("class "));
+        classes.map(|class| class.split('[').next().unwrap().to_owned()).collect::<Vec<_>>()
+    };
+
+    // Act
+    let units = nodes("::Acme::Units", 0);
+    let parent_module = nodes("Acme", 0);
+    let unknown_module = nodes("Acme::Widgets::SpinError", 1);
+    let widgets = nodes("Acme::Widgets", 0);
+
+    // Assert
+    assert_eq!(units, ["Acme__Units__Length", "Acme__Units__Unused"]);
+    assert_eq!(parent_module.len(), 6);
+    assert!(unknown_module.is_empty());
+    assert_eq!(widgets, [
+        "Acme__Widgets__Base",
+        "Acme__Widgets__Gear",
+        "Acme__Widgets__SpinError",
+        "Acme__Widgets__Widget",
+    ]);
+}
+
+#[test]
+fn related_definitions_are_drawn_up_to_the_depth() {
+    // Arrange
+    let in_memory_files = vec![
+        InMemoryFile::new("a.slice", "module A\nstruct S1 { s2: B::S2 }", true),
+        InMemoryFile::new("b.slice", "module B\nstruct S2 { s3: C::S3 }", true),
+        InMemoryFile::new("c.slice", "module C\nstruct S3 {}", true),
+    ];
+    let state = compile_files(SliceOptions { in_memory_files, ..Default::default() });
+
+    // Act
+    let diagram = generate_diagram(&state.files, DiagramFormat::Dot, Some("A"), Some(1));
+
+    // Assert
+    assert!(diagram.contains("\"A::S1\" -> \"B::S2\""));
+    assert!(!diagram.contains("C::S3"));
+}
+
+#[test]
+fn class_and_exception_relationships_are_drawn() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Acme
+
+        class Shape { tags: Sequence<string>, next: Shape? }
+        class Circle : Shape { center: Point }
+        class Point {}
+
+        exception DrawError { shape: Shape }
+        exception OutOfBounds : DrawError {}
+
+        interface Canvas { draw(shape: Shape) throws OutOfBounds }
+    ";
+    let in_memory_files = vec![InMemoryFile::new("shapes.slice", slice, true)];
+    let state = compile_files(SliceOptions { in_memory_files, ..Default::default() });
+
+    // Act
+    let diagram = generate_diagram(&state.files, DiagramFormat::Mermaid, None, None);
+
+    // Assert
+    let relationships = diagram.lines().skip_while(|line| !line.contains("--") && !line.contains("..>"));
+    assert_eq!(relationships.collect::<Vec<_>>(), [
+        "    Acme__Canvas ..> Acme__OutOfBounds : draw",
+        "    Acme__Canvas ..> Acme__Shape : draw",
+        "    Acme__Circle *-- Acme__Point : center",
+        "    Acme__Shape <|-- Acme__Circle",
+        "    Acme__DrawError *-- Acme__Shape : shape",
+        "    Acme__DrawError <|-- Acme__OutOfBounds",
+        "    Acme__Shape *-- Acme__Shape : next",
+    ]);
+}
+
+#[test]
+fn error_types_are_drawn_as_usages() {
+    // Arrange
+    let slice = "
+        module Acme
+
+        enum DrawError { OutOfBounds(point: Point), Unknown }
+        struct Point { x: int32, y: int32 }
+
+        interface Canvas { clear() throws DrawError }
+    ";
+    let in_memory_files = vec![InMemoryFile::new("canvas.slice", slice, true)];
+    let state = compile_files(SliceOptions { in_memory_files, ..Default::default() });
+
+    // Act
+    let diagram = generate_diagram(&state.files, DiagramFormat::Mermaid, None, None);
+
+    // Assert
+    let relationships = diagram.lines().skip_while(|line| !line.contains("--") && !line.contains("..>"));
+    assert_eq!(relationships.collect::<Vec<_>>(), [
+        "    Acme__Canvas ..> Acme__DrawError : clear",
+        "    Acme__DrawError *-- Acme__Point : point",
+    ]);
+}
+
+#[test]
+fn diagrams_are_written_to_the_requested_file() {
+    // Arrange
+    let directory = TempDir::new("diagram");
+    let path = directory.join("diagram.dot");
+    let options = SliceOptions {
+        diagram: Some(path.to_string_lossy().into_owned()),
+        ..Default::default()
+    };
+
+    // Act
+    let state = compile(options);
+
+    // Assert
+    let expected = generate_diagram(&state.files, DiagramFormat::Dot, None, None);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), expected);
+}
+
+#[test]
+fn diagrams_are_not_written_in_dry_runs() {
+    // Arrange
+    let directory = TempDir::new("diagram-dry-run");
+    let path = directory.join("diagram.dot");
+    let options = SliceOptions {
+        diagram: Some(path.to_string_lossy().into_owned()),
+        dry_run: true,
+        ..Default::default()
+    };
+
+    // Act
+    compile(options);
+
+    // Assert
+    assert!(!path.exists());
+}
@@ -2,6 +2,8 @@
 
 use crate::test_helpers::*;
 use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
+use slicec::slice_file::Span;
 use test_case::test_case;
 
 #[test]
@@ -34,6 +36,7 @@ fn optional_keys_are_disallowed() {
 #[test_case("varint62"; "varint62")]
 #[test_case("varuint62"; "varuint62")]
 #[test_case("string"; "string")]
+#[test_case("uuid"; "uuid")]
 fn allowed_primitive_types(key_type: &str) {
     // Arrange
     let slice = format!(
@@ -198,11 +201,53 @@ fn compact_struct_with_disallowed_fields_is_disallowed() {
     let expected = Diagnostic::new(Error::StructKeyContainsDisallowedType {
         struct_identifier: "Outer".to_owned(),
     })
-    .add_note("invalid dictionary key type: sequence", None)
     .add_note(
-        "struct 'Inner' contains fields that are not a valid dictionary key types",
-        None,
+        "field 'seq': invalid dictionary key type: sequence",
+        Some(&Span::new((10, 18).into(), (10, 32).into(), "string-0")),
+    )
+    .add_note(
+        "field 'i': struct 'Inner' contains fields that are not a valid dictionary key types",
+        Some(&Span::new((11, 16).into(), (11, 21).into(), "string-0")),
+    )
+    .add_note(
+        "field 'i.f32': invalid dictionary key type: float32",
+        Some(&Span::new((6, 18).into(), (6, 25).into(), "string-0")),
     );
 
     check_diagnostics(diagnostics, [expected]);
 }
+
+#[test]
+fn key_eligibility_can_be_queried() {
+    // Arrange
+    let slice = "
+        module Test
+        compact struct Inner { f: float32 }
+        compact struct Outer { a: int32, i: Inner, b: bool? }
+        struct NotCompact {}
+        enum E { A }
+        enum U : uint8 { A }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let outer = ast.find_element::<Struct>("Test::Outer").unwrap();
+    let expected = InvalidKeyReason::InvalidFields(vec![
+        (
+            "i".to_owned(),
+            InvalidKeyReason::InvalidFields(vec![("f".to_owned(), InvalidKeyReason::UnsupportedType)]),
+        ),
+        ("b".to_owned(), InvalidKeyReason::Optional),
+    ]);
+    assert_eq!(outer.is_valid_dictionary_key(), Err(expected));
+    assert_eq!(outer.fields()[0].data_type().is_valid_dictionary_key(), Ok(()));
+
+    let not_compact = ast.find_element::<Struct>("Test::NotCompact").unwrap();
+    assert_eq!(not_compact.is_valid_dictionary_key(), Err(InvalidKeyReason::NonCompactStruct));
+
+    let enum_def = ast.find_element::<Enum>("Test::E").unwrap();
+    assert_eq!(enum_def.is_valid_dictionary_key(), Err(InvalidKeyReason::EnumWithoutUnderlyingType));
+    assert_eq!(ast.find_element::<Enum>("Test::U").unwrap().is_valid_dictionary_key(), Ok(()));
+}
@@ -0,0 +1,114 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::diagnostics::{Diagnostic, Diagnostics, Lint};
+use slicec::grammar::*;
+use slicec::slice_file::{Location, Span};
+use slicec::utils::doc_comment_util::{DocTextPolicy, HtmlTagPolicy};
+use std::collections::BTreeMap;
+use test_case::test_case;
+
+fn transform(policy: &DocTextPolicy, text: &str) -> (String, Vec<Diagnostic>) {
+    let span = Span::new(Location { row: 1, col: 1 }, Location { row: 1, col: 2 }, "string-0");
+    let mut diagnostics = Diagnostics::new();
+    let result = policy.transform(text, &span, "Test::S", &mut diagnostics);
+    (result, diagnostics.into_inner())
+}
+
+#[test_case(false, "a < b && <b>c</b> 'd'"; "unescaped")]
+#[test_case(true, "a &lt; b &amp;&amp; <b>c</b> &apos;d&apos;"; "escaped")]
+fn xml_entities_can_be_escaped(escape_xml_entities: bool, expected: &str) {
+    // Arrange
+    let policy = DocTextPolicy {
+        escape_xml_entities,
+        ..Default::default()
+    };
+
+    // Act
+    let (result, diagnostics) = transform(&policy, "a < b && <b>c</b> 'd'");
+
+    // Assert
+    assert_eq!(result, expected);
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn html_tags_can_be_stripped() {
+    // Arrange
+    let policy = DocTextPolicy {
+        html_tags: HtmlTagPolicy::Strip,
+        ..Default::default()
+    };
+
+    // Act
+    let (result, diagnostics) = transform(&policy, "<p>Hello <i class=\"x\">world</i><br/></p>");
+
+    // Assert
+    assert_eq!(result, "Hello world");
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn untranslatable_html_tags_are_reported() {
+    // Arrange
+    let translations = BTreeMap::from([("b".to_owned(), "strong".to_owned())]);
+    let policy = DocTextPolicy {
+        html_tags: HtmlTagPolicy::Translate(translations),
+        ..Default::default()
+    };
+
+    // Act
+    let (result, diagnostics) = transform(&policy, "<b>x</b> <u>y</u> <B>z</B> <sub>2</sub>");
+
+    // Assert
+    assert_eq!(result, "<strong>x</strong> y <strong>z</strong> 2");
+    let expected = Diagnostic::new(Lint::UnsupportedDocMarkup {
+        markup: "'<u>', '<sub>'".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn whitespace_can_be_normalized() {
+    // Arrange
+    let policy = DocTextPolicy {
+        normalize_whitespace: true,
+        ..Default::default()
+    };
+
+    // Act
+    let (result, _) = transform(&policy, "  Hello, \n\t  world!\n");
+
+    // Assert
+    assert_eq!(result, "Hello, world!");
+}
+
+#[test]
+fn messages_are_transformed_with_links_formatted_separately() {
+    // Arrange
+    let slice = "
+        module Test
+
+        /// Returns <b>x</b> & {@link S}.
+        struct S {}
+    ";
+    let ast = parse_for_ast(slice);
+    let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+    let overview = struct_def.comment().unwrap().overview.as_ref().unwrap();
+    let policy = DocTextPolicy {
+        escape_xml_entities: true,
+        html_tags: HtmlTagPolicy::Strip,
+        ..Default::default()
+    };
+    let mut diagnostics = Diagnostics::new();
+
+    // Act
+    let format_link = |link: &LinkTag| format!("<see cref=\"{}\"/>", link.linked_entity().unwrap().identifier());
+    let result = policy.transform_message(overview, "Test::S", format_link, &mut diagnostics);
+
+    // Assert
+    assert_eq!(result, "Returns x &amp; <see cref=\"S\"/>.\n");
+    assert!(diagnostics.is_empty());
+}
@@ -0,0 +1,150 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::TempDir;
+use slicec::compilation_state::CompilationState;
+use slicec::doc_generator::{generate_documentation, DocPage};
+use slicec::slice_options::{DocFormat, InMemoryFile, SliceOptions};
+
+const SLICE: &str = "
+module Acme::Widgets
+
+/// A widget which can be {@link spin}ed.
+/// @see Gear
+[deprecated(\"use Gizmo\")]
+interface Widget : Base {
+    /// Spins the widget.
+    idempotent spin(speed: int32, gears: Sequence<Gear>) -> (done: bool, count: uint8?)
+}
+
+interface Base {}
+
+/// A gear.
+struct Gear {
+    /// The number of teeth.
+    teeth: int32
+    [deprecated] name: string
+    tag(1) size: Size?
+}
+
+unchecked enum Size : uint8 { Small = 1, Large = 5 }
+
+internal struct Hidden {}
+";
+
+fn compile(options: SliceOptions) -> CompilationState {
+    let in_memory_files = vec![InMemoryFile::new("widgets.slice", SLICE, true)];
+    let options = SliceOptions { in_memory_files, ..options };
+    let state = slicec::compile_from_options(&options, |_| {}, |_| {});
+    assert!(state.diagnostics.is_empty(), "{:?}", state.diagnostics);
+    state
+}
+
+fn find_page<'a>(pages: &'a [DocPage], path: &str) -> &'a str {
+    let page = pages.iter().find(|page| page.path == path);
+    &page.unwrap_or_else(|| panic!("no page was generated for '{path}'")).contents
+}
+
+#[test]
+fn markdown_pages_are_generated_for_each_module() {
+    // Arrange
+    let state = compile(SliceOptions::default());
+
+    // Act
+    let pages = generate_documentation(&state.files, DocFormat::Markdown);
+
+    // Assert
+    let paths = pages.iter().map(|page| page.path.as_str()).collect::<Vec<_>>();
+    assert_eq!(paths, ["index.md", "Acme.Widgets.md"]);
+    assert!(find_page(&pages, "index.md").contains("| [`Acme::Widgets`](Acme.Widgets.md) |"));
+
+    let module = find_page(&pages, "Acme.Widgets.md");
+    assert!(module.contains("# Module Acme::Widgets\n"));
+    assert!(module.contains("`interface Widget : `[`Base`](Acme.Widgets.md#base)\n"));
+    assert!(module.contains("**Deprecated** use Gizmo\n"));
+    assert!(module.contains("A widget which can be [`spin`](Acme.Widgets.md#widget)ed.\n"));
+    assert!(module.contains("See also: [`Gear`](Acme.Widgets.md#gear)\n"));
+    assert!(module.contains(
+        "| **idempotent** `spin` | `(speed: int32, gears: Sequence<`[`Gear`](Acme.Widgets.md#gear)`>)` | \
+         `(done: bool, count: uint8?)` | Spins the widget. |\n"
+    ));
+    assert!(module.contains("| `teeth` | `int32` | The number of teeth. |\n"));
+    assert!(module.contains("| `name` | `string` | **Deprecated** |\n"));
+    assert!(module.contains("| `size` (tag 1) | [`Size`](Acme.Widgets.md#size)`?` | |\n"));
+    assert!(module.contains("| `Large` | `5` | |\n"));
+}
+
+#[test]
+fn internal_definitions_are_not_documented() {
+    // Arrange
+    let state = compile(SliceOptions::default());
+
+    // Act
+    let pages = generate_documentation(&state.files, DocFormat::Markdown);
+
+    // Assert
+    assert!(!find_page(&pages, "Acme.Widgets.md").contains("Hidden"));
+}
+
+#[test]
+fn html_pages_link_to_anchors() {
+    // Arrange
+    let state = compile(SliceOptions::default());
+
+    // Act
+    let pages = generate_documentation(&state.files, DocFormat::Html);
+
+    // Assert
+    let index = find_page(&pages, "index.html");
+    assert!(index.starts_with("<!DOCTYPE html>\n"));
+    assert!(index.contains("<a href=\"Acme.Widgets.html\"><code>Acme::Widgets</code></a>"));
+
+    let module = find_page(&pages, "Acme.Widgets.html");
+    assert!(module.contains("<title>Module Acme::Widgets</title>"));
+    assert!(module.contains("<h3 id=\"gear\">Gear</h3>\n"));
+    assert!(module.contains(
+        "<td><code>(speed: int32, gears: Sequence&lt;</code><a href=\"Acme.Widgets.html#gear\"><code>Gear</code></a>\
+         <code>&gt;)</code></td>"
+    ));
+    assert!(module.contains("<span class=\"badge\">Deprecated</span>"));
+}
+
+#[test]
+fn documentation_is_written_to_the_doc_dir() {
+    // Arrange
+    let directory = TempDir::new("doc-dir");
+    let doc_dir = directory.join("docs");
+    let options = SliceOptions {
+        doc_dir: Some(doc_dir.to_string_lossy().into_owned()),
+        doc_format: DocFormat::Html,
+        ..Default::default()
+    };
+
+    // Act
+    let state = compile(options);
+
+    // Assert
+    let pages = generate_documentation(&state.files, DocFormat::Html);
+    for page in &pages {
+        assert_eq!(std::fs::read_to_string(doc_dir.join(&page.path)).unwrap(), page.contents);
+    }
+}
+
+#[test]
+fn documentation_is_not_written_in_dry_runs() {
+    // Arrange
+    let directory = TempDir::new("doc-dir-dry-run");
+    let doc_dir = directory.join("docs");
+    let options = SliceOptions {
+        doc_dir: Some(doc_dir.to_string_lossy().into_owned()),
+        dry_run: true,
+        ..Default::default()
+    };
+
+    // Act
+    compile(options);
+
+    // Assert
+    assert!(!doc_dir.exists());
+}
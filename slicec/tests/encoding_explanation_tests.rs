@@ -0,0 +1,142 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::encoding_explanation::{explain_encodings, EncodingExplanation};
+use slicec::grammar::*;
+use slicec::slice_file::Span;
+
+fn messages(explanation: &EncodingExplanation) -> Vec<&str> {
+    explanation.notes.iter().map(|note| note.message.as_str()).collect()
+}
+
+#[test]
+fn explanations_start_with_the_default_compilation_mode() {
+    // Arrange
+    let slice = "
+        module Test
+        struct S { a: int8 }
+    ";
+    let state = parse(slice, None);
+    let struct_def = state.ast.find_element::<Struct>("Test::S").unwrap();
+
+    // Act
+    let explanation = explain_encodings(struct_def, &state.files);
+
+    // Assert
+    assert_eq!(explanation.compilation_mode, CompilationMode::Slice2);
+    assert_eq!(explanation.supported_encodings[..], [Encoding::Slice2]);
+    assert_eq!(
+        messages(&explanation),
+        ["'string-0' uses Slice2 mode by default, which allows only the Slice2 encoding"],
+    );
+    assert!(explanation.notes[0].span.is_none());
+}
+
+#[test]
+fn explanations_point_to_the_members_which_restrict_encodings() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+        compact struct S {
+            a: int32
+            b: AnyClass
+        }
+    ";
+    let state = parse(slice, None);
+    let struct_def = state.ast.find_element::<Struct>("Test::S").unwrap();
+
+    // Act
+    let explanation = explain_encodings(struct_def, &state.files);
+
+    // Assert
+    assert_eq!(explanation.compilation_mode, CompilationMode::Slice1);
+    assert_eq!(explanation.supported_encodings[..], [Encoding::Slice1]);
+    assert_eq!(
+        messages(&explanation),
+        [
+            "'string-0' is set to Slice1 mode, which allows the Slice1 and Slice2 encodings",
+            "field 'b' has type 'AnyClass', which doesn't support Slice2",
+        ],
+    );
+    let mode_span = Span::new((2, 9).into(), (2, 22).into(), "string-0");
+    let field_span = Span::new((6, 13).into(), (6, 24).into(), "string-0");
+    assert_eq!(explanation.notes[0].span, Some(mode_span));
+    assert_eq!(explanation.notes[1].span, Some(field_span));
+}
+
+#[test]
+fn explanations_include_container_constraints() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+        class C { a: int32 }
+    ";
+    let state = parse(slice, None);
+    let class_def = state.ast.find_element::<Class>("Test::C").unwrap();
+
+    // Act
+    let explanation = explain_encodings(class_def, &state.files);
+
+    // Assert
+    assert_eq!(explanation.supported_encodings[..], [Encoding::Slice1]);
+    assert_eq!(messages(&explanation)[1..], ["classes only support Slice1"]);
+}
+
+#[test]
+fn explanations_distinguish_optional_types() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+        interface I {
+            op(a: int32?, tag(1) b: int32?)
+        }
+    ";
+    let state = parse(slice, None);
+    let a = state.ast.find_element::<Parameter>("Test::I::op::a").unwrap();
+    let b = state.ast.find_element::<Parameter>("Test::I::op::b").unwrap();
+
+    // Act
+    let a_explanation = explain_encodings(a, &state.files);
+    let b_explanation = explain_encodings(b, &state.files);
+
+    // Assert
+    assert_eq!(a_explanation.supported_encodings[..], [Encoding::Slice2]);
+    assert_eq!(
+        messages(&a_explanation)[1..],
+        ["parameter 'a' has an optional type ('int32?'), which doesn't support Slice1 unless it's tagged"],
+    );
+    assert_eq!(b_explanation.supported_encodings[..], [Encoding::Slice1, Encoding::Slice2]);
+    assert_eq!(b_explanation.notes.len(), 1);
+}
+
+#[test]
+fn enumerators_are_explained_by_their_enum() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+        enum E {
+            A
+            B(x: int32)
+        }
+    ";
+    let state = parse(slice, None);
+    let enumerator = state.ast.find_element::<Enumerator>("Test::E::A").unwrap();
+
+    // Act
+    let explanation = explain_encodings(enumerator, &state.files);
+
+    // Assert
+    assert_eq!(
+        messages(&explanation)[1..],
+        [
+            "enumerator 'B' has fields, and enums with enumerator fields don't support Slice1",
+            "enumerators support the same encodings as their enum",
+        ],
+    );
+}
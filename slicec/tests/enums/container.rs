@@ -5,7 +5,7 @@ use slicec::diagnostics::{Diagnostic, Error};
 use slicec::grammar::*;
 use test_case::test_case;
 
-#[test_case("10", "expected one of 'identifier', 'doc comment', '[', or '}', but found '10'"; "numeric identifier")]
+#[test_case("10", "expected one of 'identifier', 'doc comment', 'const', 'internal', 'public', '[', or '}', but found '10'"; "numeric identifier")]
 #[test_case("😊", "unknown symbol '😊'"; "unicode identifier")]
 fn enumerator_invalid_identifiers(identifier: &str, expected_message: &str) {
     // Arrange
@@ -203,7 +203,7 @@ mod associated_fields {
 
         let d = ast.find_element::<Enumerator>("Test::E::D").unwrap();
         assert!(matches!(d.value, EnumeratorValue::Implicit(3)));
-        assert!(d.fields.as_ref().unwrap().len() == 0);
+        assert!(d.fields.as_ref().unwrap().is_empty());
     }
 
     #[test_case("unchecked enum", true ; "unchecked")]
@@ -536,9 +536,11 @@ mod underlying_type {
 
             enum E : varint32 {
                 B = 0b1001111
+                O = 0o777
                 D = 128
                 H = 0xA4FD
                 N = -0xbc81
+                C = 'A'
             }
         ";
 
@@ -547,9 +549,137 @@ mod underlying_type {
 
         // Assert
         assert_eq!(ast.find_element::<Enumerator>("Test::E::B").unwrap().value(), 0b1001111);
+        assert_eq!(ast.find_element::<Enumerator>("Test::E::O").unwrap().value(), 0o777);
         assert_eq!(ast.find_element::<Enumerator>("Test::E::D").unwrap().value(), 128);
         assert_eq!(ast.find_element::<Enumerator>("Test::E::H").unwrap().value(), 0xA4FD);
         assert_eq!(ast.find_element::<Enumerator>("Test::E::N").unwrap().value(), -0xbc81);
+        assert_eq!(ast.find_element::<Enumerator>("Test::E::C").unwrap().value(), 65);
+    }
+
+    #[test]
+    fn enumerator_values_from_character_literals_must_be_in_range() {
+        // Arrange
+        let slice = "
+            module Test
+
+            enum E : uint8 {
+                A = '\u{263A}'
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::EnumeratorValueOutOfBounds {
+            enumerator_identifier: "A".to_owned(),
+            value: 0x263A,
+            min: 0,
+            max: 255,
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn enumerator_values_can_reference_constants() {
+        // Arrange
+        let slice = "
+            module Test
+
+            const BitOne = 1 << 1
+
+            enum Flags : uint8 {
+                A = BitOne
+                B
+                C = ::Test::BitTwo
+                D
+            }
+
+            const BitTwo = BitOne << 1
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        assert_eq!(ast.find_element::<Enumerator>("Test::Flags::A").unwrap().value(), 2);
+        assert_eq!(ast.find_element::<Enumerator>("Test::Flags::B").unwrap().value(), 3);
+        assert_eq!(ast.find_element::<Enumerator>("Test::Flags::C").unwrap().value(), 4);
+        assert_eq!(ast.find_element::<Enumerator>("Test::Flags::D").unwrap().value(), 5);
+    }
+
+    #[test]
+    fn enumerator_values_from_constants_must_be_unique() {
+        // Arrange
+        let slice = "
+            module Test
+
+            const Two = 2
+
+            enum E : uint8 {
+                A = 1
+                B
+                C = Two
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DuplicateEnumeratorValue { enumerator_value: 2 });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn enumerator_values_from_constants_must_be_in_range() {
+        // Arrange
+        let slice = "
+            module Test
+
+            const Big = 256
+
+            enum E : uint8 {
+                A = Big
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::EnumeratorValueOutOfBounds {
+            enumerator_identifier: "A".to_owned(),
+            value: 256,
+            min: 0,
+            max: 255,
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn enumerator_values_must_reference_integer_constants() {
+        // Arrange
+        let slice = "
+            module Test
+
+            const Name = \"name\"
+
+            enum E : uint8 {
+                A = Name
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::TypeMismatch {
+            expected: "integer".to_owned(),
+            actual: "string".to_owned(),
+            is_concrete: true,
+        });
+        check_diagnostics(diagnostics, [expected]);
     }
 
     #[test]
@@ -0,0 +1,186 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::test_helpers::*;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
+
+#[test]
+fn flags_enums_can_contain_single_bits_and_combinations() {
+    // Arrange
+    let slice = "
+        module Test
+
+        [flags]
+        enum Permissions : uint8 {
+            None = 0
+            Read = 1
+            Write = 2
+            Execute = 4
+            All = 7
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let enum_def = ast.find_element::<Enum>("Test::Permissions").unwrap();
+    assert!(enum_def.is_flags());
+}
+
+#[test]
+fn enums_are_not_flags_by_default() {
+    // Arrange
+    let slice = "
+        module Test
+
+        enum E : uint8 { A = 1 }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let enum_def = ast.find_element::<Enum>("Test::E").unwrap();
+    assert!(!enum_def.is_flags());
+}
+
+#[test]
+fn overlapping_flag_values_are_rejected() {
+    // Arrange
+    let slice = "
+        module Test
+
+        [flags]
+        enum E : uint8 {
+            A = 1
+            B = 2
+            C = 6
+        }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::InvalidFlagValue {
+        enumerator_identifier: "C".to_owned(),
+        value: 6,
+    })
+    .add_note(
+        "flag values must be powers of two, or combinations of other enumerators in the enum",
+        None,
+    )
+    .add_note("to allow overlapping flags, use '[flags(AllowOverlap)]'", None);
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn overlapping_flag_values_can_be_allowed() {
+    // Arrange
+    let slice = "
+        module Test
+
+        [flags(AllowOverlap)]
+        enum E : uint8 {
+            A = 1
+            B = 2
+            C = 6
+        }
+    ";
+
+    // Act/Assert
+    assert_parses(slice);
+}
+
+#[test]
+fn negative_flag_values_are_rejected() {
+    // Arrange
+    let slice = "
+        module Test
+
+        [flags(AllowOverlap)]
+        enum E : int8 {
+            A = 1
+            B = -1
+        }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::InvalidFlagValue {
+        enumerator_identifier: "B".to_owned(),
+        value: -1,
+    })
+    .add_note("flag values cannot be negative", None);
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn flags_attribute_requires_an_underlying_type() {
+    // Arrange
+    let slice = "
+        module Test
+
+        [flags]
+        enum E { A }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::UnexpectedAttribute {
+        attribute: "flags".to_owned(),
+    })
+    .add_note(
+        "the flags attribute can only be applied to enums with an underlying type",
+        None,
+    );
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn flags_attribute_is_rejected_on_non_enums() {
+    // Arrange
+    let slice = "
+        module Test
+
+        [flags]
+        struct S {}
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::UnexpectedAttribute {
+        attribute: "flags".to_owned(),
+    })
+    .add_note("the flags attribute can only be applied to enums", None);
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn flags_attribute_rejects_unknown_arguments() {
+    // Arrange
+    let slice = "
+        module Test
+
+        [flags(Foo)]
+        enum E : uint8 { A = 1 }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::ArgumentNotSupported {
+        argument: "Foo".to_owned(),
+        directive: "flags".to_owned(),
+    })
+    .add_note("'AllowOverlap' is the only valid argument", None);
+    check_diagnostics(diagnostics, [expected]);
+}
@@ -1,10 +1,12 @@
 // Copyright (c) ZeroC, Inc.
 
 mod container;
+mod flags;
 mod mode_compatibility;
 
 use crate::test_helpers::*;
 use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
 use slicec::slice_file::Span;
 use test_case::test_case;
 
@@ -106,28 +108,69 @@ fn enums_can_be_compact() {
     assert_parses(slice);
 }
 
+#[test_case("compact"; "compact")]
+#[test_case("unchecked"; "unchecked")]
+#[test_case(""; "checked")]
+fn enums_with_underlying_types_can_have_modifiers(modifier: &str) {
+    // Arrange
+    let slice = format!(
+        "
+            module Test
+
+            {modifier} enum E : uint8 {{ A, B = 255 }}
+        "
+    );
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let enum_def = ast.find_element::<Enum>("Test::E").unwrap();
+    assert_eq!(enum_def.underlying_type(), Some(&Primitive::UInt8));
+    assert_eq!(enum_def.enumerator_bounds(), Some((0, 255)));
+}
+
 #[test]
-fn compact_enums_cannot_have_underlying_types() {
+fn enums_without_underlying_types_are_bounded_by_int32() {
     // Arrange
     let slice = "
         module Test
 
-        compact enum E: uint8 { A }
+        compact enum E { A }
     ";
 
     // Act
-    let diagnostics = parse_for_diagnostics(slice);
+    let ast = parse_for_ast(slice);
 
     // Assert
-    let expected = Diagnostic::new(Error::CannotBeCompact {
-        kind: "enum",
-        identifier: "E".to_owned(),
-    })
-    .set_span(&Span::new((4, 9).into(), (4, 23).into(), "string-0"))
-    .add_note(
-        "compact enums cannot also have underlying types; try removing either the 'compact' modifier, or the underlying type",
-        Some(&Span::new((4, 25).into(), (4, 30).into(), "string-0")),
+    let enum_def = ast.find_element::<Enum>("Test::E").unwrap();
+    assert_eq!(enum_def.underlying_type(), None);
+    assert_eq!(enum_def.enumerator_bounds(), Some((0, i32::MAX as i128)));
+}
+
+#[test_case("compact"; "compact")]
+#[test_case("unchecked"; "unchecked")]
+#[test_case(""; "checked")]
+fn enumerators_must_fit_within_underlying_types(modifier: &str) {
+    // Arrange
+    let slice = format!(
+        "
+            module Test
+
+            {modifier} enum E : int8 {{ A = -129, B = 127 }}
+        "
     );
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::EnumeratorValueOutOfBounds {
+        enumerator_identifier: "A".to_owned(),
+        value: -129,
+        min: -128,
+        max: 127,
+    });
     check_diagnostics(diagnostics, [expected]);
 }
 
@@ -155,3 +198,46 @@ fn compact_enums_cannot_be_unchecked() {
     );
     check_diagnostics(diagnostics, [expected]);
 }
+
+#[test_case("A = 3, B = 5, C = 4", true; "contiguous")]
+#[test_case("A = 3, B = 6, C = 4", false; "with gaps")]
+#[test_case("A = -1", true; "single enumerator")]
+#[test_case("", false; "empty")]
+fn enums_report_whether_they_are_contiguous(enumerators: &str, expected: bool) {
+    // Arrange
+    let slice = format!(
+        "
+            module Test
+
+            unchecked enum E : int8 {{ {enumerators} }}
+        "
+    );
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let enum_def = ast.find_element::<Enum>("Test::E").unwrap();
+    assert_eq!(enum_def.is_contiguous(), expected);
+}
+
+#[test]
+fn enumerators_can_be_looked_up_by_value() {
+    // Arrange
+    let slice = "
+        module Test
+
+        enum E : int16 { A = 7, B = -2, C }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let enum_def = ast.find_element::<Enum>("Test::E").unwrap();
+    assert_eq!(enum_def.min_value(), Some(-2));
+    assert_eq!(enum_def.max_value(), Some(7));
+    assert_eq!(enum_def.enumerator_for_value(-1).unwrap().identifier(), "C");
+    assert_eq!(enum_def.enumerator_for_value(7).unwrap().identifier(), "A");
+    assert!(enum_def.enumerator_for_value(0).is_none());
+}
@@ -1,3 +1,4 @@
 // Copyright (c) ZeroC, Inc.
 
 mod files;
+mod test_helpers;
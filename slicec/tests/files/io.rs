@@ -1,10 +1,12 @@
 // Copyright (c) ZeroC, Inc.
 
 use slicec::diagnostics::{Diagnostic, Diagnostics, Lint};
-use slicec::slice_options::SliceOptions;
+use slicec::slice_options::{InMemoryFile, SliceOptions};
 use slicec::test_helpers::check_diagnostics;
 use slicec::utils::file_util::resolve_files_from;
+use std::io::Write;
 use std::path::PathBuf;
+use std::process::{Command, Stdio};
 
 #[test]
 fn file_passed_as_source_and_reference_file_is_ignored() {
@@ -146,3 +148,152 @@ fn compilation_preserves_order() {
     assert_eq!(files[2].relative_path, "tests/files/c.slice");
     assert_eq!(files[3].relative_path, "tests/files/test.slice");
 }
+
+#[test]
+fn files_in_referenced_directories_are_resolved_in_sorted_order() {
+    // Arrange
+    let mut diagnostics = Diagnostics::new();
+    let options = SliceOptions {
+        references: vec!["tests/files".to_owned()],
+        ..Default::default()
+    };
+
+    // Act
+    let files = resolve_files_from(&options, &mut diagnostics);
+
+    // Assert
+    let paths = files.iter().map(|file| file.relative_path.as_str()).collect::<Vec<_>>();
+    let expected = [
+        "tests/files/a.slice",
+        "tests/files/b.slice",
+        "tests/files/c.slice",
+        "tests/files/test.slice",
+    ];
+    assert_eq!(paths, expected);
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn in_memory_files_replace_files_on_disk() {
+    // Arrange
+    let mut diagnostics = Diagnostics::new();
+    let options = SliceOptions {
+        sources: vec!["tests/files/a.slice".to_owned()],
+        references: vec!["tests/files/b.slice".to_owned()],
+        in_memory_files: vec![
+            InMemoryFile::new("tests/files/../files/b.slice", "module Unsaved", true),
+            InMemoryFile::new("editor/new.slice", "module New", true),
+        ],
+        ..Default::default()
+    };
+
+    // Act
+    let files = resolve_files_from(&options, &mut diagnostics);
+
+    // Assert
+    assert!(diagnostics.is_empty());
+    assert_eq!(files.len(), 3);
+    assert_eq!(files[0].raw_text, std::fs::read_to_string("tests/files/a.slice").unwrap());
+    assert_eq!(files[1].relative_path, "tests/files/b.slice");
+    assert_eq!(files[1].raw_text, "module Unsaved");
+    assert!(!files[1].is_source);
+    assert_eq!(files[2].relative_path, "editor/new.slice");
+    assert!(files[2].is_source);
+}
+
+#[test]
+fn diagnostics_for_in_memory_files_use_their_paths() {
+    // Arrange
+    let options = SliceOptions {
+        in_memory_files: vec![InMemoryFile::new("editor/unsaved.slice", "module Test\nstruct S { x: Missing }", true)],
+        ..Default::default()
+    };
+
+    // Act
+    let state = slicec::compile_from_options(&options, |_| {}, |_| {});
+
+    // Assert
+    let diagnostics = state.diagnostics.into_inner();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].span().unwrap().file, "editor/unsaved.slice");
+}
+
+#[test]
+fn sources_can_be_read_from_stdin() {
+    // Arrange
+    let mut compiler = Command::new(env!("CARGO_BIN_EXE_slicec"))
+        .args(["-", "--stdin-filename", "editor/unsaved.slice"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // Act
+    let mut stdin = compiler.stdin.take().unwrap();
+    stdin.write_all(b"module Test\nstruct S { x: Missing }").unwrap();
+    drop(stdin);
+    let output = compiler.wait_with_output().unwrap();
+
+    // Assert
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("Diagnostics: (0, 1)"), "{stdout}");
+    assert!(stdout.contains(r#"file: "editor/unsaved.slice""#), "{stdout}");
+}
+
+#[cfg(unix)]
+mod links {
+    use slicec::diagnostics::{Diagnostic, Diagnostics, Lint};
+    use slicec::slice_options::SliceOptions;
+    use crate::test_helpers::{check_diagnostics, TempDir};
+    use slicec::utils::file_util::resolve_files_from;
+    use std::fs;
+
+    #[test]
+    fn hard_links_to_the_same_file_are_duplicates() {
+        // Arrange
+        let directory = TempDir::new("hard-links");
+        let original = directory.join("a.slice");
+        let link = directory.join("b.slice");
+        fs::write(&original, "module Test").unwrap();
+        fs::hard_link(&original, &link).unwrap();
+        let link = link.display().to_string();
+        let options = SliceOptions {
+            sources: vec![original.display().to_string(), link.clone()],
+            ..Default::default()
+        };
+        let mut diagnostics = Diagnostics::new();
+
+        // Act
+        let files = resolve_files_from(&options, &mut diagnostics);
+
+        // Assert
+        assert_eq!(files.len(), 1);
+        let expected = Diagnostic::new(Lint::DuplicateFile { path: link });
+        check_diagnostics(diagnostics.into_inner(), [expected]);
+    }
+
+    #[test]
+    fn symlink_cycles_are_skipped_with_warning() {
+        // Arrange
+        let directory = TempDir::new("symlink-cycle");
+        let nested = directory.join("nested");
+        fs::create_dir(&nested).unwrap();
+        fs::write(nested.join("a.slice"), "module Test").unwrap();
+        std::os::unix::fs::symlink(&directory, nested.join("parent")).unwrap();
+        let options = SliceOptions {
+            references: vec![directory.display().to_string()],
+            ..Default::default()
+        };
+        let mut diagnostics = Diagnostics::new();
+
+        // Act
+        let files = resolve_files_from(&options, &mut diagnostics);
+
+        // Assert
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].relative_path, nested.join("a.slice").display().to_string());
+        let path = nested.join("parent").display().to_string();
+        let expected = Diagnostic::new(Lint::SymlinkCycle { path });
+        check_diagnostics(diagnostics.into_inner(), [expected]);
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::TempDir;
+use slicec::compile_from_options;
+use slicec::diagnostics::{Diagnostics, Suggestion};
+use slicec::fixes::{apply_suggestions, collect_fixes, write_fixes, FixedFile};
+use slicec::slice_file::Span;
+use slicec::slice_options::SliceOptions;
+use std::path::Path;
+use test_case::test_case;
+
+/// A suggested edit, written as: (start location, end location, replacement text).
+type Edit<'a> = ((usize, usize), (usize, usize), &'a str);
+
+fn suggestion(start: (usize, usize), end: (usize, usize), replacement: &str) -> Suggestion {
+    Suggestion {
+        message: "fix it".to_owned(),
+        span: Span::new(start.into(), end.into(), "string-0"),
+        replacement: replacement.to_owned(),
+        is_machine_applicable: true,
+    }
+}
+
+/// Compiles the provided file, and returns the fixes that its diagnostics suggest.
+fn fixes_for(path: &Path, allowed_lints: Vec<String>) -> Vec<FixedFile> {
+    let options = SliceOptions {
+        sources: vec![path.to_str().unwrap().to_owned()],
+        allowed_lints,
+        ..Default::default()
+    };
+    let state = compile_from_options(&options, |_| {}, |_| {});
+    let diagnostics = state.diagnostics.into_updated(&state.ast, &state.files, &options);
+    collect_fixes(&state.files, &diagnostics)
+}
+
+#[test_case(&[((1, 1), (1, 4), "abc")], "abc bar ghi", 1; "replacement")]
+#[test_case(&[((1, 4), (1, 8), "")], "foo ghi", 1; "removal")]
+#[test_case(&[((1, 5), (1, 5), "new ")], "foo new bar ghi", 1; "insertion")]
+#[test_case(&[((1, 9), (1, 12), "xyz"), ((1, 1), (1, 4), "abc")], "abc bar xyz", 2; "out of order")]
+#[test_case(&[((1, 1), (1, 8), "abc"), ((1, 5), (1, 12), "xyz")], "abc ghi", 1; "overlapping")]
+#[test_case(&[((1, 1), (1, 4), "abc"), ((1, 1), (1, 4), "abc")], "abc bar ghi", 1; "duplicate")]
+fn suggestions_are_applied(edits: &[Edit], expected: &str, expected_count: usize) {
+    // Arrange
+    let text = "foo bar ghi";
+    let suggestions = edits
+        .iter()
+        .map(|(start, end, replacement)| suggestion(*start, *end, replacement))
+        .collect::<Vec<_>>();
+
+    // Act
+    let (fixed_text, fix_count) = apply_suggestions(text, &suggestions.iter().collect::<Vec<_>>());
+
+    // Assert
+    assert_eq!(fixed_text, expected);
+    assert_eq!(fix_count, expected_count);
+}
+
+#[test]
+fn suggestions_are_applied_across_lines_with_multibyte_characters() {
+    // Arrange
+    let text = "// é\n// ü ab\n";
+    let suggestions = [suggestion((2, 6), (2, 8), "xyz")];
+
+    // Act
+    let (fixed_text, fix_count) = apply_suggestions(text, &suggestions.iter().collect::<Vec<_>>());
+
+    // Assert
+    assert_eq!(fixed_text, "// é\n// ü xyz\n");
+    assert_eq!(fix_count, 1);
+}
+
+#[test]
+fn doc_comment_lints_are_fixed() {
+    // Arrange
+    let slice = "module Test\ninterface I {\n    /// @returns x: true\n    op() -> bool\n}\n";
+    let directory = TempDir::new("fix");
+    let path = directory.write_file("test.slice", slice);
+
+    // Act
+    let fixed_files = fixes_for(&path, vec![]);
+    let mut diagnostics = Diagnostics::new();
+    write_fixes(&fixed_files, &mut diagnostics);
+
+    // Assert
+    assert!(diagnostics.is_empty());
+    assert_eq!(fixed_files.len(), 1);
+    assert_eq!(fixed_files[0].fix_count, 1);
+    let expected = "module Test\ninterface I {\n    /// @returns: true\n    op() -> bool\n}\n";
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), expected);
+}
+
+#[test]
+fn allowed_lints_are_not_fixed() {
+    // Arrange
+    let slice = "module Test\ninterface I {\n    /// @returns x: true\n    op() -> bool\n}\n";
+    let directory = TempDir::new("fix-allowed");
+    let path = directory.write_file("test.slice", slice);
+
+    // Act
+    let fixed_files = fixes_for(&path, vec!["IncorrectDocComment".to_owned()]);
+
+    // Assert
+    assert!(fixed_files.is_empty());
+}
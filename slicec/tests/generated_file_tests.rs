@@ -0,0 +1,202 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::{check_diagnostics, TempDir};
+use slicec::diagnostics::{Diagnostic, Diagnostics, Error};
+use slicec::generated_file::{write_generated_files, write_if_changed, GeneratedFile, MANIFEST_FILE_NAME};
+use slicec::slice_file::Span;
+use slicec::slice_options::SliceOptions;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+fn options_for(directory: &Path) -> SliceOptions {
+    SliceOptions {
+        output_dir: Some(directory.to_str().unwrap().to_owned()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn files_are_written_to_the_output_directory() {
+    // Arrange
+    let temp_dir = TempDir::new("generated-write");
+    let directory = temp_dir.join("output");
+    let options = options_for(&directory);
+    let files = [GeneratedFile::new("A.cs", "class A {}"), GeneratedFile::new("nested/B.cs", "class B {}")];
+    let mut diagnostics = Diagnostics::new();
+
+    // Act
+    write_generated_files(&files, &options, &mut diagnostics);
+
+    // Assert
+    assert!(diagnostics.is_empty());
+    assert_eq!(std::fs::read_to_string(directory.join("A.cs")).unwrap(), "class A {}");
+    assert_eq!(std::fs::read_to_string(directory.join("nested/B.cs")).unwrap(), "class B {}");
+    let manifest = std::fs::read_to_string(directory.join(MANIFEST_FILE_NAME)).unwrap();
+    assert_eq!(manifest, "A.cs\nnested/B.cs\n");
+}
+
+#[test]
+fn dry_runs_do_not_write_files() {
+    // Arrange
+    let temp_dir = TempDir::new("generated-dry-run");
+    let directory = temp_dir.join("output");
+    let options = SliceOptions {
+        dry_run: true,
+        ..options_for(&directory)
+    };
+    let files = [GeneratedFile::new("A.cs", "class A {}")];
+    let mut diagnostics = Diagnostics::new();
+
+    // Act
+    write_generated_files(&files, &options, &mut diagnostics);
+
+    // Assert
+    assert!(diagnostics.is_empty());
+    assert!(!directory.exists());
+}
+
+#[test]
+fn stale_files_are_removed() {
+    // Arrange
+    let temp_dir = TempDir::new("generated-stale");
+    let directory = temp_dir.join("output");
+    let options = options_for(&directory);
+    let old_files = [GeneratedFile::new("A.cs", "class A {}"), GeneratedFile::new("B.cs", "class B {}")];
+    let new_files = [GeneratedFile::new("A.cs", "class A { int x; }")];
+    let mut diagnostics = Diagnostics::new();
+    write_generated_files(&old_files, &options, &mut diagnostics);
+
+    // Act
+    write_generated_files(&new_files, &options, &mut diagnostics);
+
+    // Assert
+    assert!(diagnostics.is_empty());
+    assert_eq!(std::fs::read_to_string(directory.join("A.cs")).unwrap(), "class A { int x; }");
+    assert!(!directory.join("B.cs").exists());
+    let manifest = std::fs::read_to_string(directory.join(MANIFEST_FILE_NAME)).unwrap();
+    assert_eq!(manifest, "A.cs\n");
+}
+
+#[test]
+fn files_with_the_same_path_are_rejected() {
+    // Arrange
+    let temp_dir = TempDir::new("generated-conflict");
+    let directory = temp_dir.join("output");
+    let options = options_for(&directory);
+    let span_a = Span::new((2, 1).into(), (2, 9).into(), "a.slice");
+    let span_b = Span::new((3, 1).into(), (3, 9).into(), "b.slice");
+    let mut file_a = GeneratedFile::new("Types.cs", "class A {}");
+    file_a.add_source_span(&span_a);
+    let mut file_b = GeneratedFile::new("Types.cs", "class B {}");
+    file_b.add_source_span(&span_b);
+    let mut diagnostics = Diagnostics::new();
+
+    // Act
+    write_generated_files(&[file_a, file_b], &options, &mut diagnostics);
+
+    // Assert
+    let note = "code for this definition is generated into the file";
+    let expected = Diagnostic::new(Error::GeneratedFileConflict {
+        path: "Types.cs".to_owned(),
+    })
+    .add_note(note, Some(&span_a))
+    .add_note(note, Some(&span_b));
+    check_diagnostics(diagnostics.into_inner(), [expected]);
+    assert!(!directory.exists());
+}
+
+#[test]
+fn appended_code_is_mapped_to_its_source() {
+    // Arrange
+    let struct_span = Span::new((2, 1).into(), (5, 2).into(), "a.slice");
+    let field_span = Span::new((3, 5).into(), (3, 14).into(), "a.slice");
+    let mut file = GeneratedFile::new("A.cs", "// Generated code\n");
+
+    // Act
+    file.append("class A\n{\n");
+    file.append_mapped("    int x;\n", &field_span);
+    file.append("}\n");
+    file.add_mapping(2, 5, &struct_span);
+
+    // Assert
+    assert_eq!(file.find_source(1), None);
+    assert_eq!(file.find_source(2), Some(&struct_span));
+    assert_eq!(file.find_source(4), Some(&field_span));
+    assert_eq!(file.source_spans, vec![field_span, struct_span]);
+}
+
+#[test]
+fn source_maps_are_written_alongside_generated_files() {
+    // Arrange
+    let temp_dir = TempDir::new("generated-source-maps");
+    let directory = temp_dir.join("output");
+    let options = SliceOptions {
+        source_maps: true,
+        ..options_for(&directory)
+    };
+    let span = Span::new((2, 1).into(), (2, 12).into(), "a.slice");
+    let mut file = GeneratedFile::new("A.cs", "");
+    file.append_mapped("class A\n{\n}\n", &span);
+    let mut diagnostics = Diagnostics::new();
+
+    // Act
+    write_generated_files(&[file], &options, &mut diagnostics);
+
+    // Assert
+    assert!(diagnostics.is_empty());
+    let source_map = std::fs::read_to_string(directory.join("A.cs.slicemap")).unwrap();
+    let expected = r#"{"file":"A.cs","mappings":[{"start_line":1,"end_line":3,"span":{"start":{"row":2,"col":1},"end":{"row":2,"col":12},"file":"a.slice"}}]}"#;
+    assert_eq!(source_map, expected);
+    let manifest = std::fs::read_to_string(directory.join(MANIFEST_FILE_NAME)).unwrap();
+    assert_eq!(manifest, "A.cs\nA.cs.slicemap\n");
+}
+
+#[test]
+fn unchanged_files_are_not_rewritten() {
+    // Arrange
+    let temp_dir = TempDir::new("generated-unchanged");
+    let directory = temp_dir.join("output");
+    let path = directory.join("nested").join("A.cs");
+    assert!(write_if_changed(&path, "class A {}").unwrap());
+
+    // Backdate the file, so we can tell whether it's rewritten.
+    let backdated = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+    std::fs::File::options().write(true).open(&path).unwrap().set_modified(backdated).unwrap();
+
+    // Act
+    let unchanged_written = write_if_changed(&path, "class A {}").unwrap();
+    let unchanged_modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+    let changed_written = write_if_changed(&path, "class A { }").unwrap();
+
+    // Assert
+    assert!(!unchanged_written);
+    assert_eq!(unchanged_modified, backdated);
+    assert!(changed_written);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "class A { }");
+
+    // No temporary files should be left behind.
+    let entries = std::fs::read_dir(path.parent().unwrap()).unwrap().count();
+    assert_eq!(entries, 1);
+}
+
+#[test]
+fn generated_files_can_write_themselves() {
+    // Arrange
+    let temp_dir = TempDir::new("generated-write-to");
+    let directory = temp_dir.join("output");
+    let span = Span::new((2, 1).into(), (2, 12).into(), "a.slice");
+    let mut file = GeneratedFile::new("A.cs", "");
+    file.append_mapped("class A {}\n", &span);
+
+    // Act
+    let first_written = file.write_to(&directory, true).unwrap();
+    let second_written = file.write_to(&directory, true).unwrap();
+
+    // Assert
+    assert!(first_written);
+    assert!(!second_written);
+    assert_eq!(std::fs::read_to_string(directory.join("A.cs")).unwrap(), "class A {}\n");
+    assert!(directory.join("A.cs.slicemap").exists());
+}
@@ -0,0 +1,131 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::TempDir;
+use slicec::compilation_state::CompilationState;
+use slicec::generated_file::GeneratedFile;
+use slicec::golden_files::{GoldenTest, SnapshotMismatch};
+use slicec::grammar::*;
+use std::fs;
+use std::path::Path;
+
+/// Returns a fresh fixtures directory in the system's temp directory, containing the provided fixtures.
+fn fixtures_dir(name: &str, fixtures: &[(&str, &str)]) -> TempDir {
+    let directory = TempDir::new(name);
+    for (path, contents) in fixtures {
+        directory.write_file(path, contents);
+    }
+    directory
+}
+
+/// A minimal code generator, which generates a file listing the fields of each struct.
+fn generate_code(state: &CompilationState) -> Vec<GeneratedFile> {
+    let structs = state.ast.query().of_type::<Struct>();
+    let mut files = structs
+        .iter()
+        .map(|struct_def| {
+            let mut contents = format!("struct {}\n", struct_def.identifier());
+            for field in struct_def.fields() {
+                contents += &format!("    {}\n", field.identifier());
+            }
+            GeneratedFile::new(format!("{}.txt", struct_def.identifier()), contents)
+        })
+        .collect::<Vec<_>>();
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files
+}
+
+fn golden_test(directory: &Path, bless: bool) -> GoldenTest {
+    GoldenTest {
+        bless,
+        ..GoldenTest::new(directory)
+    }
+}
+
+#[test]
+fn matching_snapshots_pass() {
+    // Arrange
+    let directory = fixtures_dir(
+        "golden-match",
+        &[
+            ("a.slice", "module Test\nstruct A { x: int32 }"),
+            ("many/b.slice", "module Test\nstruct B {}"),
+            ("many/c.slice", "module Test\nstruct C {}"),
+            ("snapshots/a/A.txt", "struct A\n    x\n"),
+            ("snapshots/many/B.txt", "struct B\n"),
+            ("snapshots/many/C.txt", "struct C\r\n"),
+        ],
+    );
+
+    // Act/Assert
+    golden_test(&directory, false).assert(generate_code);
+}
+
+#[test]
+fn mismatched_snapshots_are_reported() {
+    // Arrange
+    let directory = fixtures_dir(
+        "golden-mismatch",
+        &[
+            ("a.slice", "module Test\nstruct A { x: int32, y: int32 }\nstruct B {}"),
+            ("snapshots/a/A.txt", "struct A\n    x\n    z\n"),
+            ("snapshots/a/Old.txt", "struct Old\n"),
+        ],
+    );
+
+    // Act
+    let mismatches = golden_test(&directory, false).run(generate_code);
+
+    // Assert
+    let snapshots = directory.join("snapshots").join("a");
+    let [changed, missing, stale] = &mismatches[..] else {
+        panic!("unexpected mismatches: {mismatches:?}");
+    };
+    let SnapshotMismatch::Changed { snapshot, diff, .. } = changed else { panic!("{changed:?}") };
+    assert_eq!(snapshot, &snapshots.join("A.txt"));
+    assert_eq!(diff, "    struct A\n        x\n  -     z\n  +     y\n");
+    assert!(matches!(missing, SnapshotMismatch::Missing { snapshot, .. } if snapshot == &snapshots.join("B.txt")));
+    assert!(matches!(stale, SnapshotMismatch::Stale { snapshot } if snapshot == &snapshots.join("Old.txt")));
+}
+
+#[test]
+fn blessing_updates_snapshots() {
+    // Arrange
+    let directory = fixtures_dir(
+        "golden-bless",
+        &[
+            ("a.slice", "module Test\nstruct A {}\nstruct B {}"),
+            ("snapshots/a/A.txt", "outdated\n"),
+            ("snapshots/a/Old.txt", "struct Old\n"),
+        ],
+    );
+
+    // Act
+    let mismatches = golden_test(&directory, true).run(generate_code);
+
+    // Assert
+    assert!(mismatches.is_empty(), "{mismatches:?}");
+    let snapshots = directory.join("snapshots").join("a");
+    assert_eq!(fs::read_to_string(snapshots.join("A.txt")).unwrap(), "struct A\n");
+    assert_eq!(fs::read_to_string(snapshots.join("B.txt")).unwrap(), "struct B\n");
+    assert!(!snapshots.join("Old.txt").exists());
+    golden_test(&directory, false).assert(generate_code);
+}
+
+#[test]
+fn fixtures_which_fail_to_compile_are_reported() {
+    // Arrange
+    let directory = fixtures_dir("golden-failure", &[("bad.slice", "module Test\nstruct S { x: Missing }")]);
+
+    // Act
+    let mismatches = golden_test(&directory, true).run(generate_code);
+
+    // Assert
+    let [SnapshotMismatch::CompilationFailed { fixture, diagnostics }] = &mismatches[..] else {
+        panic!("unexpected mismatches: {mismatches:?}");
+    };
+    assert_eq!(fixture, "bad");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message(), "no element with identifier 'Missing' exists");
+}
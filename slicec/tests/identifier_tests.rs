@@ -3,8 +3,10 @@
 mod test_helpers;
 
 use crate::test_helpers::*;
-use slicec::diagnostics::{Diagnostic, Error};
-use slicec::grammar::{CustomType, Interface, Struct};
+use slicec::diagnostics::{Diagnostic, Error, Lint};
+use slicec::grammar::{CustomType, Field, Interface, Member, Struct};
+use slicec::slice_file::Span;
+use test_case::test_case;
 
 #[test]
 fn escaped_keywords() {
@@ -25,6 +27,30 @@ fn escaped_keywords() {
     assert!(ast.find_element::<CustomType>("module::custom").is_ok());
 }
 
+#[test_case("const"; "const")]
+#[test_case("readonly"; "readonly")]
+#[test_case("uuid"; "uuid")]
+#[test_case("timestamp"; "timestamp")]
+#[test_case("duration"; "duration")]
+#[test_case("oneway"; "oneway")]
+#[test_case("public"; "public")]
+#[test_case("internal"; "internal")]
+fn new_keywords_can_be_escaped(keyword: &str) {
+    // Arrange
+    let slice = format!(
+        r#"
+            module Test
+            struct S {{ \{keyword}: int32 }}
+        "#
+    );
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    assert!(ast.find_element::<Field>(&format!("Test::S::{keyword}")).is_ok());
+}
+
 #[test]
 fn escaped_identifiers() {
     // Arrange
@@ -80,16 +106,81 @@ fn escaped_scoped_identifiers_containing_keywords() {
 }
 
 #[test]
-fn must_be_ascii_alphanumeric_characters() {
+fn can_contain_unicode_letters() {
+    // Arrange
+    let slice = "
+        module 𒅋
+        struct Größe {
+            straße: int32
+        }
+        struct 半径 {}
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    assert!(ast.find_element::<Struct>("𒅋::Größe").is_ok());
+    assert!(ast.find_element::<Field>("𒅋::Größe::straße").is_ok());
+    assert!(ast.find_element::<Struct>("𒅋::半径").is_ok());
+}
+
+#[test]
+fn invalid_code_points_are_reported_precisely() {
     // Arrange
-    let slice = "module 𒅋";
+    let slice = "module Fo😊o";
 
     // Act
     let diagnostics = parse_for_diagnostics(slice);
 
     // Assert
     let expected = Diagnostic::new(Error::Syntax {
-        message: "unknown symbol '𒅋'".to_owned(),
-    });
+        message: "unknown symbol '😊'".to_owned(),
+    })
+    .set_span(&Span::new((1, 10).into(), (1, 11).into(), "string-0"));
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn identifiers_are_normalized() {
+    // Arrange: the struct is defined with a precomposed 'é', but referenced with an 'e' and a combining accent.
+    let slice = "
+        module Test
+        struct Caf\u{e9} {}
+        struct S {
+            c: Cafe\u{301}
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let field = ast.find_element::<Field>("Test::S::c").unwrap();
+    assert_eq!(field.data_type().type_string(), "Caf\u{e9}");
+}
+
+#[test]
+fn confusable_identifiers_are_reported() {
+    // Arrange: the second struct's name uses a Cyrillic 'о'.
+    let slice = "
+        module Test
+        struct Foo {}
+        struct F\u{43e}o {}
+        struct S {
+            rn: bool
+            m: bool
+        }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert: 'rn' and 'm' aren't reported, since they're both ASCII.
+    let expected = Diagnostic::new(Lint::ConfusableIdentifier {
+        identifier: "F\u{43e}o".to_owned(),
+        other_identifier: "Foo".to_owned(),
+    })
+    .add_note("'Foo' was defined here", None);
     check_diagnostics(diagnostics, [expected]);
 }
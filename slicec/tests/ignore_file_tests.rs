@@ -0,0 +1,111 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::{check_diagnostics, TempDir};
+use slicec::diagnostics::{Diagnostic, Diagnostics, Lint};
+use slicec::slice_options::SliceOptions;
+use slicec::utils::file_util::resolve_files_from;
+use slicec::utils::ignore_util::{IgnoreFile, IGNORE_FILE_NAME};
+use std::path::Path;
+use test_case::test_case;
+
+#[test_case("build", "build", true, true; "names match at the top level")]
+#[test_case("build", "src/build", true, true; "names match at any depth")]
+#[test_case("*.gen.slice", "src/a.gen.slice", false, true; "star matches any characters")]
+#[test_case("*.gen.slice", "src/a.slice", false, false; "star requires the rest of the pattern")]
+#[test_case("?.slice", "ab.slice", false, false; "question mark matches a single character")]
+#[test_case("bin/", "bin", false, false; "directory patterns don't match files")]
+#[test_case("bin/", "src/bin", true, true; "directory patterns match directories")]
+#[test_case("examples/legacy", "examples/legacy", true, true; "paths match relative to the ignore file")]
+#[test_case("examples/legacy", "src/examples/legacy", true, false; "paths are anchored to the ignore file")]
+#[test_case("/build", "src/build", true, false; "leading slashes anchor patterns")]
+#[test_case("docs/**/draft.slice", "docs/draft.slice", false, true; "double stars match zero directories")]
+#[test_case("docs/**/draft.slice", "docs/a/b/draft.slice", false, true; "double stars match many directories")]
+fn patterns_are_matched(pattern: &str, path: &str, is_directory: bool, expected: bool) {
+    // Arrange
+    let root = Path::new("project");
+    let ignore_file = IgnoreFile::parse(root.join(IGNORE_FILE_NAME), &format!("# A comment\n\n{pattern}\n"));
+
+    // Act
+    let is_ignored = ignore_file.is_ignored(&root.join(path), is_directory);
+
+    // Assert
+    assert_eq!(is_ignored, expected);
+}
+
+/// Returns a fresh directory in the system's temp directory, containing the provided files.
+fn temp_dir(name: &str, files: &[(&str, &str)]) -> TempDir {
+    let directory = TempDir::new(name);
+    for (path, contents) in files {
+        directory.write_file(path, contents);
+    }
+    directory
+}
+
+#[test]
+fn ignored_paths_are_not_searched() {
+    // Arrange
+    let directory = temp_dir(
+        "ignore-search",
+        &[
+            (IGNORE_FILE_NAME, "build/\n"),
+            ("a.slice", "module Test"),
+            ("build/b.slice", "module Test"),
+            ("nested/.sliceignore", "*.gen.slice"),
+            ("nested/c.slice", "module Test"),
+            ("nested/c.gen.slice", "module Test"),
+            ("nested/build/d.slice", "module Test"),
+        ],
+    );
+    let options = SliceOptions {
+        references: vec![directory.display().to_string()],
+        verbose: true,
+        ..Default::default()
+    };
+    let mut diagnostics = Diagnostics::new();
+
+    // Act
+    let files = resolve_files_from(&options, &mut diagnostics);
+
+    // Assert
+    let paths = files.iter().map(|file| file.relative_path.clone()).collect::<Vec<_>>();
+    let expected_paths = [directory.join("a.slice"), directory.join("nested").join("c.slice")];
+    assert_eq!(paths, expected_paths.map(|path| path.display().to_string()));
+
+    let root_ignore_file = directory.join(IGNORE_FILE_NAME).display().to_string();
+    let nested_ignore_file = directory.join("nested").join(IGNORE_FILE_NAME).display().to_string();
+    let expected = [
+        Diagnostic::new(Lint::IgnoredPath {
+            path: directory.join("build").display().to_string(),
+            ignore_file: root_ignore_file.clone(),
+        }),
+        Diagnostic::new(Lint::IgnoredPath {
+            path: directory.join("nested").join("build").display().to_string(),
+            ignore_file: root_ignore_file,
+        }),
+        Diagnostic::new(Lint::IgnoredPath {
+            path: directory.join("nested").join("c.gen.slice").display().to_string(),
+            ignore_file: nested_ignore_file,
+        }),
+    ];
+    check_diagnostics(diagnostics.into_inner(), expected);
+}
+
+#[test]
+fn ignored_paths_are_only_reported_in_verbose_mode() {
+    // Arrange
+    let directory = temp_dir("ignore-quiet", &[(IGNORE_FILE_NAME, "*.slice"), ("a.slice", "module Test")]);
+    let options = SliceOptions {
+        references: vec![directory.display().to_string()],
+        ..Default::default()
+    };
+    let mut diagnostics = Diagnostics::new();
+
+    // Act
+    let files = resolve_files_from(&options, &mut diagnostics);
+
+    // Assert
+    assert!(files.is_empty());
+    assert!(diagnostics.is_empty());
+}
@@ -0,0 +1,144 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::compilation_state::CompilationState;
+use slicec::compile_from_strings;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
+use slicec::importer::ModuleImport;
+use slicec::slice_options::SliceOptions;
+
+fn compile(inputs: &[&str]) -> CompilationState {
+    let state = compile_from_strings(inputs, None, |_| {}, |_| {});
+    assert!(!state.diagnostics.has_errors());
+    state
+}
+
+fn compile_with_imports(imports: &[ModuleImport]) -> CompilationState {
+    slicec::compile_with_imports(&SliceOptions::default(), imports, |_| {}, |_| {})
+}
+
+#[test]
+fn importing_a_module_includes_its_submodules() {
+    // Arrange
+    let source = compile(&[
+        "module Foo\nstruct A {}",
+        "module Foo::Bar\nstruct B {}",
+        "module Foobar\nstruct C {}",
+    ]);
+
+    // Act
+    let files = ModuleImport::new(&source, "Foo").collect_files().unwrap();
+
+    // Assert
+    let paths = files.iter().map(|file| file.relative_path.as_str()).collect::<Vec<_>>();
+    assert_eq!(paths, ["string-0", "string-1"]);
+    assert!(files.iter().all(|file| !file.is_source));
+}
+
+#[test]
+fn importing_a_module_includes_its_dependencies() {
+    // Arrange
+    let source = compile(&[
+        "module Foo\nstruct A { b: Bar::B, c: Sequence<Baz::C> }",
+        "module Bar\nstruct B { d: Qux::D }",
+        "module Baz\nenum C : uint8 { X = Value }\nconst Value = Qux::Value",
+        "module Qux\nstruct D {}\nconst Value = 3",
+        "module Unrelated\nstruct E {}",
+    ]);
+
+    // Act
+    let files = ModuleImport::new(&source, "Foo").collect_files().unwrap();
+
+    // Assert
+    let paths = files.iter().map(|file| file.relative_path.as_str()).collect::<Vec<_>>();
+    assert_eq!(paths, ["string-0", "string-1", "string-2", "string-3"]);
+}
+
+#[test]
+fn importing_a_module_only_includes_the_type_aliases_it_references() {
+    // Arrange
+    let source = compile(&[
+        "module Foo\nstruct A { b: Aliases::B }",
+        "module Aliases\ntypealias B = Bar::Inner",
+        "module Bar\ntypealias Inner = int32",
+        "module Unrelated\ntypealias C = string",
+    ]);
+
+    // Act
+    let files = ModuleImport::new(&source, "Foo").collect_files().unwrap();
+
+    // Assert
+    let paths = files.iter().map(|file| file.relative_path.as_str()).collect::<Vec<_>>();
+    assert_eq!(paths, ["string-0", "string-1", "string-2"]);
+}
+
+#[test]
+fn imported_files_are_parsed_with_their_original_symbols() {
+    // Arrange
+    let slice = "
+        module Foo
+        #if FEATURE
+        struct Enabled {}
+        #else
+        struct Disabled {}
+        #endif
+    ";
+    let options = SliceOptions {
+        defined_symbols: vec!["FEATURE".to_owned()],
+        ..Default::default()
+    };
+    let source = compile_from_strings(&[slice], Some(&options), |_| {}, |_| {});
+    assert!(!source.diagnostics.has_errors());
+
+    // Act
+    let state = compile_with_imports(&[ModuleImport::new(&source, "Foo")]);
+
+    // Assert
+    assert!(state.diagnostics.is_empty());
+    assert!(state.ast.find_element::<Struct>("Foo::Enabled").is_ok());
+    assert!(state.ast.find_element::<Struct>("Foo::Disabled").is_err());
+}
+
+#[test]
+fn imported_elements_are_anchored_in_the_new_compilation() {
+    // Arrange
+    let first = compile(&["mode = Slice1\nmodule Foo\nexception E {}\ninterface I { op() throws E }"]);
+    let second = compile(&[
+        "module Other\nstruct Unused {}",
+        "module Bar\nstruct S { tag(1) x: int32? }",
+    ]);
+
+    // Act
+    let state = compile_with_imports(&[ModuleImport::new(&first, "Foo"), ModuleImport::new(&second, "Bar")]);
+
+    // Assert
+    assert!(state.diagnostics.is_empty());
+    assert_eq!(state.files.len(), 2);
+    let operation = state.ast.find_element::<Operation>("Foo::I::op").unwrap();
+    let exception = operation.exception_specification[0].definition();
+    assert!(std::ptr::eq(
+        exception,
+        state.ast.find_element::<Exception>("Foo::E").unwrap()
+    ));
+    assert!(state.ast.find_element::<Struct>("Bar::S").is_ok());
+    assert!(state.ast.find_element::<Struct>("Other::Unused").is_err());
+}
+
+#[test]
+fn importing_a_missing_module_is_disallowed() {
+    // Arrange
+    let source = compile(&["module Foo\nstruct A {}"]);
+
+    // Act
+    let state = compile_with_imports(&[ModuleImport::new(&source, "Fake")]);
+
+    // Assert
+    let diagnostics = diagnostics_from_compilation_state(state, &SliceOptions::default());
+    let expected = Diagnostic::new(Error::DoesNotExist {
+        identifier: "Fake".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
@@ -0,0 +1,52 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::compilation_state::CompilationState;
+use slicec::compile_from_strings_infallible;
+use slicec::diagnostics::{Diagnostic, Error, Lint};
+use slicec::slice_options::SliceOptions;
+use test_case::test_case;
+
+#[test]
+fn panics_are_reported_as_internal_errors() {
+    // Arrange
+    let slice = "
+        module Test
+        struct S {};
+    ";
+    fn validator(_: &mut CompilationState) {
+        panic!("invariant violated");
+    }
+
+    // Act
+    let state = compile_from_strings_infallible(&[slice], None, |_| {}, validator);
+
+    // Assert: diagnostics reported before the panic are kept.
+    let diagnostics = diagnostics_from_compilation_state(state, &SliceOptions::default());
+    let expected = [
+        Diagnostic::new(Lint::RedundantSeparator {
+            separator: ";".to_owned(),
+        }),
+        Diagnostic::new(Error::InternalError {
+            message: "invariant violated".to_owned(),
+        }),
+    ];
+    check_diagnostics(diagnostics, expected);
+}
+
+#[test_case("mode = Slice1\nmodule Test\nclass C : int32 {}"; "class with primitive base")]
+#[test_case("mode = Slice1\nmodule Test\nexception E : sequence<bool> {}"; "exception with sequence base")]
+#[test_case("mode = Slice1\nmodule Test\ninterface I { op() throws string }"; "throwing a primitive")]
+#[test_case("/// @param\nstruct S {}"; "lint on definition without module")]
+#[test_case("module Test\nenum class E : uint8 { A }"; "recovered definition")]
+fn invalid_input_is_reported_without_panicking(slice: &str) {
+    // Act
+    let state = compile_from_strings_infallible(&[slice], None, |_| {}, |_| {});
+
+    // Assert
+    let diagnostics = diagnostics_from_compilation_state(state, &SliceOptions::default());
+    assert!(!diagnostics.is_empty());
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.code() != "E083"));
+}
@@ -1,7 +1,7 @@
 // Copyright (c) ZeroC, Inc.
 
 use crate::test_helpers::*;
-use slicec::diagnostics::{Diagnostic, Error};
+use slicec::diagnostics::{Diagnostic, Error, Lint};
 use slicec::grammar::*;
 
 #[test]
@@ -165,3 +165,165 @@ fn inherits_correct_operations() {
     assert_eq!(interface_d_def.all_inherited_operations()[0].identifier(), "opB");
     assert_eq!(interface_d_def.all_inherited_operations()[1].identifier(), "opA");
 }
+
+#[test]
+fn linearized_bases_follow_c3_order() {
+    // Arrange
+    let slice = "
+        module Test
+
+        interface A {}
+        interface B : A {}
+        interface C : A {}
+        interface D {}
+        interface E : B, C, D {}
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let interface_def = ast.find_element::<Interface>("Test::E").unwrap();
+    let linearized_bases = interface_def
+        .linearized_bases()
+        .into_iter()
+        .map(|base| base.identifier())
+        .collect::<Vec<_>>();
+    assert_eq!(linearized_bases, ["B", "C", "A", "D"]);
+}
+
+#[test]
+fn operations_inherited_through_a_diamond_are_not_ambiguous() {
+    // Arrange
+    let slice = "
+        module Test
+
+        interface A {
+            op()
+        }
+        interface B : A {}
+        interface C : A {}
+        interface D : B, C {}
+    ";
+
+    // Act/Assert
+    assert_parses(slice);
+}
+
+#[test]
+fn inheriting_distinct_operations_with_the_same_name_is_disallowed() {
+    // Arrange
+    let slice = "
+        module Test
+
+        interface I {
+            op()
+        }
+        interface J {
+            op(x: int32)
+        }
+        interface K : I, J {}
+        interface L : K, J {}
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert: the collision is only reported on 'K', since 'L' inherits both operations through 'K'.
+    let expected = Diagnostic::new(Error::AmbiguousInheritedOperation {
+        interface_identifier: "K".to_owned(),
+        operation_identifier: "op".to_owned(),
+    })
+    .add_note("'op' is inherited from 'I'", None)
+    .add_note("'op' is inherited from 'J'", None);
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn flattened_operations_report_where_they_were_inherited_from() {
+    // Arrange
+    let slice = "
+        module Test
+
+        interface A {
+            op()
+        }
+        interface B : A {}
+        interface C : A {
+            getItem()
+        }
+        interface D : B, C {
+            [allow(OperationCaseCollision)]
+            GetItem()
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let interface_def = ast.find_element::<Interface>("Test::D").unwrap();
+    let flattened = interface_def.all_operations_flattened();
+    let identifiers = flattened.iter().map(|f| f.operation.identifier()).collect::<Vec<_>>();
+    assert_eq!(identifiers, ["GetItem", "getItem", "op"]);
+
+    assert!(!flattened[0].is_inherited());
+    assert_eq!(flattened[0].collisions.len(), 1);
+    assert_eq!(flattened[0].collisions[0].identifier(), "getItem");
+
+    let bases = flattened[1].inherited_through.iter().map(|b| b.identifier()).collect::<Vec<_>>();
+    assert_eq!(bases, ["C"]);
+
+    let bases = flattened[2].inherited_through.iter().map(|b| b.identifier()).collect::<Vec<_>>();
+    assert_eq!(bases, ["B", "C"]);
+    assert!(flattened[2].collisions.is_empty());
+}
+
+#[test]
+fn operations_whose_names_only_differ_by_case_are_reported() {
+    // Arrange
+    let slice = "
+        module Test
+
+        interface I {
+            getItem()
+        }
+        interface J {
+            GetItem()
+        }
+        interface K : I, J {}
+        interface L : K {}
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert: the collision is only reported on 'K', since 'L' inherits both operations through 'K'.
+    let expected = Diagnostic::new(Lint::OperationCaseCollision {
+        identifier: "GetItem".to_owned(),
+        other_identifier: "getItem".to_owned(),
+    })
+    .add_note("'getItem' was defined here", None);
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn interfaces_cannot_inherit_from_builtin_types() {
+    // Arrange
+    let slice = "
+        module Test
+
+        interface I : bool {}
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::TypeMismatch {
+        expected: "interface".to_owned(),
+        actual: "bool".to_owned(),
+        is_concrete: true,
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
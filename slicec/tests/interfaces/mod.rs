@@ -90,3 +90,68 @@ fn cannot_redefine_operations() {
 
     check_diagnostics(diagnostics, [expected]);
 }
+
+#[test]
+fn referenced_types_are_collected_transitively() {
+    // Arrange
+    let slice = "
+        module Test
+
+        struct Inner {}
+        struct Outer { inner: Sequence<Inner?> }
+        enum Shape { Circle(radius: Radius), Square }
+        typealias Radius = Length
+        custom Length
+        struct Unused {}
+
+        interface Base {
+            getOuter() -> Outer
+        }
+        interface I : Base {
+            draw(shapes: Dictionary<string, Shape>) -> Result<bool, Inner>
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let interface_def = ast.find_element::<Interface>("Test::I").unwrap();
+    let referenced_types = interface_def
+        .referenced_types()
+        .into_iter()
+        .map(|definition| definition.identifier())
+        .collect::<Vec<_>>();
+    assert_eq!(referenced_types, ["Shape", "Length", "Inner", "Outer"]);
+}
+
+#[test]
+fn referenced_types_include_exceptions_and_bases() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+
+        class Base {}
+        class Derived : Base { s: S }
+        compact struct S { i: int32 }
+        exception BaseError { c: Derived }
+        exception MyError : BaseError {}
+
+        interface I {
+            op() throws MyError
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let interface_def = ast.find_element::<Interface>("Test::I").unwrap();
+    let referenced_types = interface_def
+        .referenced_types()
+        .into_iter()
+        .map(|definition| definition.identifier())
+        .collect::<Vec<_>>();
+    assert_eq!(referenced_types, ["MyError", "BaseError", "Derived", "S", "Base"]);
+}
@@ -2,7 +2,7 @@
 
 use crate::test_helpers::*;
 use slicec::diagnostics::{Diagnostic, Error};
-use slicec::grammar::CompilationMode;
+use slicec::grammar::{CompilationMode, Interface};
 
 #[test]
 fn parameters_must_be_allowed_within_compilation_mode() {
@@ -33,3 +33,73 @@ fn parameters_must_be_allowed_within_compilation_mode() {
 
     check_diagnostics(diagnostics, [expected]);
 }
+
+#[test]
+fn interfaces_can_inherit_from_slice1_interfaces() {
+    // Arrange
+    let slice1 = "
+        mode = Slice1
+        module Test
+
+        class C {}
+
+        interface A {
+            op(c: C)
+        }
+    ";
+    let slice2 = "
+        module Test
+
+        interface B : A {
+            op2(s: string?)
+        }
+    ";
+
+    // Act
+    let ast = parse_multiple_for_ast(&[slice1, slice2]);
+
+    // Assert
+    let b = ast.find_element::<Interface>("Test::B").unwrap();
+    let encodings = b.all_operations().iter().map(|op| op.encoding).collect::<Vec<_>>();
+    assert_eq!(encodings, [CompilationMode::Slice2, CompilationMode::Slice1]);
+}
+
+#[test]
+fn slice1_interfaces_cannot_inherit_from_slice2_interfaces() {
+    // Arrange
+    let slice1 = "
+        mode = Slice1
+        module Test
+
+        interface Legacy {
+            legacyOp()
+        }
+
+        interface C : B {}
+    ";
+    let slice2 = "
+        module Test
+
+        interface A : Legacy {
+            op()
+        }
+
+        interface B : A {}
+    ";
+
+    // Act
+    let diagnostics = parse_multiple_for_diagnostics(&[slice1, slice2]);
+
+    // Assert
+    let expected = Diagnostic::new(Error::IncompatibleBaseInterface {
+        interface_identifier: "C".to_owned(),
+        base_identifier: "B".to_owned(),
+        mode: CompilationMode::Slice1,
+    })
+    .add_note(
+        "operation 'op' is defined in Slice2 mode, and would be inherited through 'C' -> 'B' -> 'A'",
+        None,
+    );
+
+    check_diagnostics(diagnostics, [expected]);
+}
@@ -246,6 +246,7 @@ fn return_tuple_must_contain_two_or_more_elements(return_tuple: &str) {
 mod slice2 {
     use crate::test_helpers::*;
     use slicec::diagnostics::{Diagnostic, Error};
+    use slicec::grammar::{Operation, ThrownType};
 
     #[test]
     fn exception_specifications_are_not_supported() {
@@ -272,6 +273,59 @@ mod slice2 {
         let expected = Diagnostic::new(Error::ExceptionSpecificationNotSupported);
         check_diagnostics(diagnostics, [expected]);
     }
+
+    #[test]
+    fn operations_can_fail_with_an_error_type() {
+        // Arrange
+        let slice = "
+            module Test
+
+            enum MyError { NotFound, Other(message: string) }
+
+            interface I {
+                op() throws MyError
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let operation = ast.find_element::<Operation>("Test::I::op").unwrap();
+        let error_type = operation.error_type.as_ref().unwrap();
+        assert_eq!(error_type.type_string(), "MyError");
+        assert!(operation.exception_specification.is_empty());
+        assert!(operation.throws_anything());
+        assert!(matches!(operation.thrown_types()[..], [ThrownType::Error(_)]));
+    }
+
+    #[test]
+    fn operations_cannot_fail_with_multiple_error_types() {
+        // Arrange
+        let slice = "
+            module Test
+
+            struct A {}
+            struct B {}
+
+            interface I {
+                op() throws (A, B)
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::MultipleErrorTypes {
+            operation_identifier: "op".to_owned(),
+        })
+        .add_note(
+            "to fail with multiple kinds of errors, use an enum with fields as the error type",
+            None,
+        );
+        check_diagnostics(diagnostics, [expected]);
+    }
 }
 
 mod slice1 {
@@ -357,9 +411,10 @@ mod slice1 {
     fn operations_can_only_throw_exceptions() {
         // Arrange
         let slice = "
+            mode = Slice1
             module Test
 
-            struct S {}
+            compact struct S {}
 
             interface I {
                 op() throws S
@@ -383,6 +438,7 @@ mod streams {
     use crate::test_helpers::*;
     use slicec::diagnostics::{Diagnostic, Error};
     use slicec::grammar::*;
+    use test_case::test_case;
 
     #[test]
     fn can_have_streamed_parameter_and_return() {
@@ -451,4 +507,213 @@ mod streams {
         });
         check_diagnostics(diagnostics, [expected]);
     }
+
+    #[test_case("stream uint32", Some(4); "fixed size primitive")]
+    #[test_case("stream Point", Some(9); "fixed size struct")]
+    #[test_case("stream CompactPoint", Some(8); "fixed size compact struct")]
+    #[test_case("stream Point?", None; "optional struct")]
+    #[test_case("stream string", None; "variable size type")]
+    #[test_case("Point", None; "non-streamed parameter")]
+    fn streamed_parameters_expose_their_element_size(data_type: &str, expected: Option<u32>) {
+        // Arrange
+        let slice = format!(
+            "
+            module Test
+
+            struct Point {{ x: int32, y: int32 }}
+            compact struct CompactPoint {{ x: int32, y: int32 }}
+
+            interface I {{
+                op(p: {data_type}) -> {data_type}
+            }}
+            "
+        );
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let operation = ast.find_element::<Operation>("Test::I::op").unwrap();
+        assert_eq!(operation.parameters()[0].stream_element_size(), expected);
+        assert_eq!(operation.return_members()[0].stream_element_size(), expected);
+    }
+
+    #[test]
+    fn streamed_types_must_support_slice2() {
+        // Arrange
+        let slice1 = "
+            mode = Slice1
+            module Test
+
+            class C {}
+            compact struct S { c: C }
+        ";
+        let slice2 = "
+            module Test
+
+            interface I {
+                op(s: stream S)
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_multiple_for_diagnostics(&[slice1, slice2]);
+
+        // Assert
+        let expected = Diagnostic::new(Error::UnsupportedType {
+            kind: "S".to_owned(),
+            mode: CompilationMode::Slice2,
+        })
+        .add_note("this file's compilation mode is Slice2 by default", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+}
+
+mod modifiers {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("", &[]; "no modifiers")]
+    #[test_case("idempotent", &[OperationModifierKind::Idempotent]; "idempotent")]
+    #[test_case("readonly", &[OperationModifierKind::Readonly]; "readonly")]
+    #[test_case("oneway", &[OperationModifierKind::Oneway]; "oneway")]
+    fn operations_can_have_modifiers(modifiers: &str, expected: &[OperationModifierKind]) {
+        // Arrange
+        let slice = format!(
+            "
+            module Test
+
+            interface I {{
+                {modifiers} op()
+            }}
+            "
+        );
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let operation = ast.find_element::<Operation>("Test::I::op").unwrap();
+        let kinds = operation.modifiers.iter().map(|m| m.kind).collect::<Vec<_>>();
+        assert_eq!(kinds, expected);
+    }
+
+    #[test]
+    fn readonly_operations_are_idempotent() {
+        // Arrange
+        let slice = "
+            module Test
+
+            interface I {
+                readonly op()
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let operation = ast.find_element::<Operation>("Test::I::op").unwrap();
+        assert!(operation.is_readonly());
+        assert!(operation.is_idempotent());
+        assert!(!operation.has_modifier(OperationModifierKind::Idempotent));
+    }
+
+    #[test]
+    fn duplicate_modifiers_are_disallowed() {
+        // Arrange
+        let slice = "
+            module Test
+
+            interface I {
+                idempotent idempotent op()
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DuplicateOperationModifier {
+            modifier: "idempotent".to_owned(),
+        })
+        .add_note("the modifier was previously applied here:", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("readonly idempotent", "idempotent", "readonly"; "idempotent after readonly")]
+    #[test_case("idempotent readonly", "readonly", "idempotent"; "readonly after idempotent")]
+    fn conflicting_modifiers_are_disallowed(modifiers: &str, modifier: &str, conflicting_modifier: &str) {
+        // Arrange
+        let slice = format!(
+            "
+            module Test
+
+            interface I {{
+                {modifiers} op()
+            }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::ConflictingOperationModifiers {
+            modifier: modifier.to_owned(),
+            conflicting_modifier: conflicting_modifier.to_owned(),
+        })
+        .add_note("readonly operations are implicitly idempotent", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("oneway op()"; "modifier")]
+    #[test_case("[oneway] op()"; "attribute")]
+    fn oneway_operations_are_oneway(operation: &str) {
+        // Arrange
+        let slice = format!(
+            "
+            module Test
+
+            interface I {{
+                {operation}
+            }}
+            "
+        );
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let operation = ast.find_element::<Operation>("Test::I::op").unwrap();
+        assert!(operation.is_oneway());
+    }
+
+    #[test_case("oneway op() -> bool"; "return members")]
+    #[test_case("oneway op() throws E"; "throws clause")]
+    fn oneway_operations_cannot_return_or_throw(operation: &str) {
+        // Arrange
+        let slice = format!(
+            "
+            mode = Slice1
+            module Test
+
+            exception E {{}}
+
+            interface I {{
+                {operation}
+            }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::OnewayOperationReturnsData {
+            identifier: "op".to_owned(),
+        })
+        .add_note("the operation was marked oneway here:", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
 }
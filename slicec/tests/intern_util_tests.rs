@@ -0,0 +1,68 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::grammar::*;
+use slicec::utils::intern_util::InternedString;
+use std::hash::{BuildHasher, RandomState};
+
+#[test]
+fn equal_strings_are_interned_to_the_same_allocation() {
+    // Arrange
+    let owned = String::from("Foo::Bar");
+
+    // Act
+    let a = InternedString::new("Foo::Bar");
+    let b = InternedString::from(owned);
+    let c = InternedString::new("Foo::Baz");
+
+    // Assert
+    assert_eq!(a, b);
+    assert!(std::ptr::eq(a.as_str(), b.as_str()));
+    assert_ne!(a, c);
+    assert_eq!(a, "Foo::Bar");
+    assert_eq!(&*c, "Foo::Baz");
+}
+
+#[test]
+fn strings_interned_on_different_threads_are_equal() {
+    // Arrange
+    let a = InternedString::new("Foo::Bar");
+
+    // Act
+    let b = std::thread::spawn(|| InternedString::new("Foo::Bar")).join().unwrap();
+
+    // Assert
+    assert_eq!(a, b);
+    assert!(!std::ptr::eq(a.as_str(), b.as_str()));
+
+    let state = RandomState::new();
+    assert_eq!(state.hash_one(&a), state.hash_one(&b));
+}
+
+#[test]
+fn identifiers_and_scopes_are_interned() {
+    // Arrange
+    let slice = "
+        module Test
+        struct S {
+            s: S2
+        }
+        struct S2 {
+            s: int32
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let s = ast.find_element::<Field>("Test::S::s").unwrap();
+    let s2 = ast.find_element::<Field>("Test::S2::s").unwrap();
+    assert_eq!(s.raw_identifier().value, s2.raw_identifier().value);
+    assert!(std::ptr::eq(s.identifier(), s2.identifier()));
+
+    let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+    assert!(std::ptr::eq(struct_def.parser_scope(), struct_def.module_scope()));
+}
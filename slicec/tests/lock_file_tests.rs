@@ -0,0 +1,184 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::lock_file::LockedValues;
+use slicec::slice_options::SliceOptions;
+use std::path::Path;
+
+fn options_for(path: &Path, update_lock_file: bool) -> SliceOptions {
+    SliceOptions {
+        lock_file: Some(path.to_str().unwrap().to_owned()),
+        update_lock_file,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn lock_file_is_generated_if_missing() {
+    // Arrange
+    let directory = TempDir::new("generated");
+    let path = directory.join("slice.lock.json");
+    let slice = "
+        module Test
+        enum E : uint8 { A, B = 5 }
+        struct S { tag(3) s: string? }
+    ";
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(
+        parse(slice, Some(&options_for(&path, false))),
+        &SliceOptions::default(),
+    );
+
+    // Assert
+    assert!(diagnostics.is_empty());
+    let locked: LockedValues = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(locked.enumerators["Test::E::A"], 0);
+    assert_eq!(locked.enumerators["Test::E::B"], 5);
+    assert_eq!(locked.tags["Test::S::s"], 3);
+}
+
+#[test]
+fn changing_a_locked_value_is_disallowed() {
+    // Arrange
+    let directory = TempDir::new("changed");
+    let path = directory.join("slice.lock.json");
+    let original = "
+        module Test
+        enum E : uint8 { A, B }
+        struct S { tag(3) s: string? }
+    ";
+    let modified = "
+        module Test
+        enum E : uint8 { B, A }
+        struct S { tag(4) s: string? }
+    ";
+    let _ = parse(original, Some(&options_for(&path, false)));
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(
+        parse(modified, Some(&options_for(&path, false))),
+        &SliceOptions::default(),
+    );
+
+    // Assert
+    let note = "if this change is intentional, regenerate the lock file by passing '--update-lock-file'";
+    let expected = [
+        Diagnostic::new(Error::LockedValueChanged {
+            identifier: "Test::E::A".to_owned(),
+            kind: "enumerator value",
+            locked: 0,
+            actual: 1,
+        })
+        .add_note(note, None),
+        Diagnostic::new(Error::LockedValueChanged {
+            identifier: "Test::E::B".to_owned(),
+            kind: "enumerator value",
+            locked: 1,
+            actual: 0,
+        })
+        .add_note(note, None),
+        Diagnostic::new(Error::LockedValueChanged {
+            identifier: "Test::S::s".to_owned(),
+            kind: "tag",
+            locked: 3,
+            actual: 4,
+        })
+        .add_note(note, None),
+    ];
+    check_diagnostics(diagnostics, expected);
+}
+
+#[test]
+fn new_values_are_added_to_the_lock_file() {
+    // Arrange
+    let directory = TempDir::new("added");
+    let path = directory.join("slice.lock.json");
+    let _ = parse("module Test\nenum E : uint8 { A }", Some(&options_for(&path, false)));
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(
+        parse("module Test\nenum E : uint8 { A, B }", Some(&options_for(&path, false))),
+        &SliceOptions::default(),
+    );
+
+    // Assert
+    assert!(diagnostics.is_empty());
+    let locked: LockedValues = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(locked.enumerators.len(), 2);
+}
+
+#[test]
+fn lock_file_can_be_regenerated() {
+    // Arrange
+    let directory = TempDir::new("regenerated");
+    let path = directory.join("slice.lock.json");
+    let _ = parse("module Test\nenum E : uint8 { A = 1 }", Some(&options_for(&path, false)));
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(
+        parse("module Test\nenum E : uint8 { A = 2 }", Some(&options_for(&path, true))),
+        &SliceOptions::default(),
+    );
+
+    // Assert
+    assert!(diagnostics.is_empty());
+    let locked: LockedValues = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(locked.enumerators["Test::E::A"], 2);
+}
+
+#[test]
+fn lock_file_is_not_written_in_dry_runs() {
+    // Arrange
+    let directory = TempDir::new("dry-run");
+    let path = directory.join("slice.lock.json");
+    let dry_run_options = SliceOptions {
+        dry_run: true,
+        ..options_for(&path, false)
+    };
+
+    // Act: a missing lock file isn't generated, and new values aren't added to an existing one.
+    let _ = parse("module Test\nenum E : uint8 { A }", Some(&dry_run_options));
+    let generated = path.exists();
+    let _ = parse("module Test\nenum E : uint8 { A }", Some(&options_for(&path, false)));
+    let diagnostics = diagnostics_from_compilation_state(
+        parse("module Test\nenum E : uint8 { A, B }", Some(&dry_run_options)),
+        &SliceOptions::default(),
+    );
+
+    // Assert
+    assert!(!generated);
+    assert!(diagnostics.is_empty());
+    let locked: LockedValues = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(locked.enumerators.len(), 1);
+}
+
+#[test]
+fn locked_values_are_still_checked_in_dry_runs() {
+    // Arrange
+    let directory = TempDir::new("dry-run-changed");
+    let path = directory.join("slice.lock.json");
+    let _ = parse("module Test\nenum E : uint8 { A = 1 }", Some(&options_for(&path, false)));
+    let options = SliceOptions {
+        dry_run: true,
+        ..options_for(&path, false)
+    };
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(
+        parse("module Test\nenum E : uint8 { A = 2 }", Some(&options)),
+        &SliceOptions::default(),
+    );
+
+    // Assert
+    let expected = Diagnostic::new(Error::LockedValueChanged {
+        identifier: "Test::E::A".to_owned(),
+        kind: "enumerator value",
+        locked: 1,
+        actual: 2,
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
@@ -0,0 +1,82 @@
+// Copyright (c) ZeroC, Inc.
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use slicec::logging;
+use slicec::slice_options::{InMemoryFile, SliceOptions};
+use std::sync::Mutex;
+
+/// A logger which records the target, level, and message of every event.
+struct RecordingLogger(Mutex<Vec<(String, Level, String)>>);
+
+impl Log for RecordingLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        let event = (record.target().to_owned(), record.level(), record.args().to_string());
+        self.0.lock().unwrap().push(event);
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: RecordingLogger = RecordingLogger(Mutex::new(Vec::new()));
+
+// Loggers can only be installed once per process, so this is the only test in this file.
+#[test]
+fn compilation_events_are_logged() {
+    // Arrange
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Trace);
+    let options = SliceOptions {
+        in_memory_files: vec![InMemoryFile::new("a.slice", "module Test\nstruct S {}", true)],
+        ..Default::default()
+    };
+
+    // Act
+    let state = slicec::compile_from_options(&options, |_| {}, |_| {});
+
+    // Assert
+    assert!(state.diagnostics.is_empty());
+    let events = LOGGER.0.lock().unwrap();
+    assert_logged(
+        &events,
+        logging::FILES,
+        Level::Debug,
+        "resolved 'a.slice': is_source=true bytes=23",
+    );
+    assert_logged(
+        &events,
+        logging::PARSING,
+        Level::Debug,
+        "parsed 'a.slice': bytes=23 lines=2 preprocessing_ms=",
+    );
+    assert_logged(
+        &events,
+        logging::PATCHING,
+        Level::Debug,
+        "patched type references: duration_ms=",
+    );
+    assert_logged(
+        &events,
+        logging::VALIDATION,
+        Level::Debug,
+        "validated 'a.slice': duration_ms=",
+    );
+    assert_logged(
+        &events,
+        logging::PHASES,
+        Level::Info,
+        "finished validation: duration_ms=",
+    );
+}
+
+fn assert_logged(events: &[(String, Level, String)], target: &str, level: Level, prefix: &str) {
+    let is_match =
+        |(t, l, message): &(String, Level, String)| t == target && *l == level && message.starts_with(prefix);
+    assert!(
+        events.iter().any(is_match),
+        "no '{prefix}' event was logged: {events:#?}"
+    );
+}
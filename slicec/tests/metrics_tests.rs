@@ -0,0 +1,73 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::slice_options::TimingsFormat;
+
+#[test]
+fn metrics_are_collected_for_each_phase_and_file() {
+    // Arrange
+    let slice1 = "module Test\nstruct S {}";
+    let slice2 = "module Test\n\n// A comment.\ninterface I {}\n";
+
+    // Act
+    let state = slicec::compile_from_strings(&[slice1, slice2], None, |_| {}, |_| {});
+
+    // Assert
+    let phases = state.metrics.phases.iter().map(|phase| phase.name).collect::<Vec<_>>();
+    assert_eq!(phases, ["parsing", "patching", "validation", "checks", "output"]);
+    assert_eq!(state.metrics.total(), state.metrics.phases.iter().map(|phase| phase.duration).sum());
+    assert!(state.metrics.phase("parsing").is_some());
+
+    let files = &state.metrics.files;
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[1].relative_path, "string-1");
+    assert_eq!(files[1].lines, 4);
+    assert_eq!(files[1].bytes, slice2.len());
+}
+
+#[test]
+fn phases_after_errors_are_still_recorded() {
+    // Arrange
+    let slice = "module Test\nstruct S {";
+
+    // Act
+    let state = parse(slice, None);
+
+    // Assert
+    // Later phases are no-ops when there are errors, but they still run (and are timed).
+    assert!(state.diagnostics.has_errors());
+    assert_eq!(state.metrics.phases.len(), 5);
+}
+
+#[test]
+fn metrics_can_be_reported_as_json() {
+    // Arrange
+    let state = parse("module Test\nstruct S {}", None);
+
+    // Act
+    let report = state.metrics.report(TimingsFormat::Json);
+
+    // Assert
+    let json: serde_json::Value = serde_json::from_str(&report).unwrap();
+    assert_eq!(json["phases"][0]["name"], "parsing");
+    assert!(json["phases"][0]["duration_ms"].is_f64());
+    assert_eq!(json["files"][0]["relative_path"], "string-0");
+    assert_eq!(json["files"][0]["lines"], 2);
+    assert!(json["files"][0]["parsing_ms"].is_f64());
+}
+
+#[test]
+fn metrics_can_be_reported_for_humans() {
+    // Arrange
+    let state = parse("module Test\nstruct S {}", None);
+
+    // Act
+    let report = state.metrics.report(TimingsFormat::Human);
+
+    // Assert
+    assert!(report.starts_with("Compilation timings:\n    parsing          "));
+    assert!(report.contains("\n    total            "));
+    assert!(report.contains("\n    string-0 (2 lines, 23 bytes): preprocessing "));
+}
@@ -71,4 +71,106 @@ mod module {
         assert!(ast.find_element::<Struct>("Foo::Test1").is_ok());
         assert!(ast.find_element::<Struct>("Foo::Test2").is_ok());
     }
+
+    #[test]
+    fn can_use_block_syntax() {
+        // Arrange
+        let slice = "
+            module A {
+                struct S {}
+
+                module B::C {
+                    struct T {
+                        s: S
+                    }
+                }
+
+                module D {}
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        assert!(ast.find_element::<Module>("A").is_ok());
+        assert!(ast.find_element::<Module>("A::B::C").is_ok());
+        assert!(ast.find_element::<Module>("A::D").is_ok());
+
+        let t = ast.find_element::<Struct>("A::B::C::T").unwrap();
+        assert_eq!(t.module_scope(), "A::B::C");
+        assert_eq!(t.parser_scoped_identifier(), "A::B::C::T");
+        assert_eq!(t.get_module().identifier(), "C");
+
+        let field = ast.find_element::<Field>("A::B::C::T::s").unwrap();
+        let field_type = field.data_type.definition();
+        assert_eq!(field_type.type_string(), "S");
+    }
+
+    #[test]
+    fn multiple_module_blocks_can_be_declared_in_a_file() {
+        // Arrange
+        let slice = "
+            module A { struct S {} }
+            module B { struct S {} }
+        ";
+
+        // Act
+        let compilation_state = parse(slice, None);
+
+        // Assert
+        let ast = &compilation_state.ast;
+        assert!(ast.find_element::<Struct>("A::S").is_ok());
+        assert!(ast.find_element::<Struct>("B::S").is_ok());
+
+        let file = &compilation_state.files[0];
+        let modules = file.modules.iter().map(|m| m.borrow().nested_module_identifier());
+        assert_eq!(modules.collect::<Vec<_>>(), ["A", "B"]);
+    }
+
+    #[test]
+    fn module_blocks_are_nested_within_the_file_level_module() {
+        // Arrange
+        let slice = "
+            module A
+            module B {
+                struct S {}
+            }
+            struct T {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        assert!(ast.find_element::<Struct>("A::B::S").is_ok());
+        assert!(ast.find_element::<Struct>("A::T").is_ok());
+    }
+
+    #[test]
+    fn definitions_after_module_blocks_still_require_a_module() {
+        // Arrange
+        let slice = "
+            module A {}
+            struct S {}
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::Syntax {
+            message: "module declaration is required".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn module_blocks_can_be_empty() {
+        // Arrange
+        let slice = "module A {}";
+
+        // Act/Assert
+        assert_parses(slice);
+    }
 }
@@ -0,0 +1,182 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use clap::Parser;
+use slicec::diagnostics::{Diagnostic, Lint};
+use slicec::slice_options::{NamingConvention, SliceOptions};
+use slicec::utils::string_util::CaseStyle;
+use test_case::test_case;
+
+fn options_with(conventions: &[(&str, CaseStyle)]) -> SliceOptions {
+    let naming_conventions = conventions
+        .iter()
+        .map(|(kind, style)| NamingConvention {
+            kind: kind.to_string(),
+            style: *style,
+        })
+        .collect();
+    SliceOptions {
+        naming_conventions,
+        ..Default::default()
+    }
+}
+
+#[test_case("MyStruct", CaseStyle::PascalCase, true)]
+#[test_case("HTTPServer", CaseStyle::PascalCase, true)]
+#[test_case("myStruct", CaseStyle::PascalCase, false)]
+#[test_case("My_Struct", CaseStyle::PascalCase, false)]
+#[test_case("doThing", CaseStyle::CamelCase, true)]
+#[test_case("DoThing", CaseStyle::CamelCase, false)]
+#[test_case("do_thing", CaseStyle::SnakeCase, true)]
+#[test_case("doThing", CaseStyle::SnakeCase, false)]
+fn case_styles_match_identifiers(identifier: &str, style: CaseStyle, expected: bool) {
+    assert_eq!(style.matches(identifier), expected);
+}
+
+#[test_case("my_struct", CaseStyle::PascalCase, "MyStruct")]
+#[test_case("DoThing", CaseStyle::CamelCase, "doThing")]
+#[test_case("myField", CaseStyle::SnakeCase, "my_field")]
+fn case_styles_convert_identifiers(identifier: &str, style: CaseStyle, expected: &str) {
+    assert_eq!(style.convert(identifier), expected);
+}
+
+#[test]
+fn identifiers_which_break_conventions_are_reported() {
+    // Arrange
+    let slice = "
+        module Test
+
+        struct my_struct {
+            myField: int32
+        }
+
+        interface I {
+            DoThing()
+        }
+    ";
+    let options = options_with(&[
+        ("struct", CaseStyle::PascalCase),
+        ("field", CaseStyle::SnakeCase),
+        ("operation", CaseStyle::CamelCase),
+    ]);
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+    // Assert
+    let expected = [
+        ("field", "myField", "snake_case"),
+        ("struct", "my_struct", "PascalCase"),
+        ("operation", "DoThing", "camelCase"),
+    ]
+    .map(|(kind, identifier, style)| {
+        Diagnostic::new(Lint::NamingConvention {
+            kind: kind.to_owned(),
+            identifier: identifier.to_owned(),
+            style: style.to_owned(),
+        })
+    });
+    let suggestions = diagnostics
+        .iter()
+        .flat_map(|diagnostic| diagnostic.suggestions())
+        .map(|suggestion| suggestion.replacement.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(suggestions, ["my_field", "MyStruct", "doThing"]);
+    check_diagnostics(diagnostics, expected);
+}
+
+#[test]
+fn nested_module_identifiers_are_checked_by_segment() {
+    // Arrange
+    let slice = "module Foo::bar_baz";
+    let options = options_with(&[("module", CaseStyle::PascalCase)]);
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+    // Assert
+    assert_eq!(diagnostics[0].suggestions()[0].replacement, "Foo::BarBaz");
+    let expected = Diagnostic::new(Lint::NamingConvention {
+        kind: "module".to_owned(),
+        identifier: "Foo::bar_baz".to_owned(),
+        style: "PascalCase".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn identifiers_are_not_checked_without_conventions() {
+    // Arrange
+    let slice = "
+        module Test
+        struct my_struct {}
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    check_diagnostics(diagnostics, [] as [Diagnostic; 0]);
+}
+
+#[test]
+fn naming_convention_lints_can_be_allowed() {
+    // Arrange
+    let slice = "
+        module Test
+        [allow(NamingConvention)]
+        struct my_struct {}
+    ";
+    let options = options_with(&[("struct", CaseStyle::PascalCase)]);
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+    // Assert
+    check_diagnostics(diagnostics, [] as [Diagnostic; 0]);
+}
+
+#[test]
+fn rename_suggestions_are_not_machine_applicable() {
+    // Arrange
+    let slice = "
+        module Test
+        struct my_struct {}
+    ";
+    let options = options_with(&[("struct", CaseStyle::PascalCase)]);
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+    // Assert
+    let suggestion = &diagnostics[0].suggestions()[0];
+    assert_eq!(suggestion.message, "rename it to 'MyStruct'");
+    assert!(!suggestion.is_machine_applicable);
+}
+
+#[test_case("type-alias=snake_case", "type alias", CaseStyle::SnakeCase; "multi-word kind")]
+#[test_case("operation=camelcase", "operation", CaseStyle::CamelCase; "case-insensitive style")]
+fn naming_conventions_can_be_passed_on_the_command_line(argument: &str, kind: &str, style: CaseStyle) {
+    // Act
+    let options = SliceOptions::try_parse_from(["slicec", "a.slice", "--naming-convention", argument]).unwrap();
+
+    // Assert
+    let expected = NamingConvention {
+        kind: kind.to_owned(),
+        style,
+    };
+    assert_eq!(options.naming_conventions, [expected]);
+}
+
+#[test_case("operation"; "missing style")]
+#[test_case("attribute=camelCase"; "unknown kind")]
+#[test_case("operation=kebab-case"; "unknown style")]
+fn invalid_naming_conventions_are_rejected(argument: &str) {
+    // Act
+    let result = SliceOptions::try_parse_from(["slicec", "a.slice", "--naming-convention", argument]);
+
+    // Assert
+    assert!(result.is_err());
+}
@@ -0,0 +1,67 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::ast::node_id::NodeId;
+use slicec::ast::{Ast, LookupError};
+use slicec::grammar::*;
+
+#[test]
+fn node_ids_resolve_to_their_elements() {
+    // Arrange
+    let slice = "
+        module Test
+        struct S { a: int32 }
+        interface I { op() }
+    ";
+    let ast = parse_for_ast(slice);
+
+    // Act
+    let struct_id: NodeId<Struct> = ast.find_node_id("Test::S").unwrap();
+    let entity_id: NodeId<dyn Entity> = ast.find_node_id("Test::I::op").unwrap();
+
+    // Assert
+    assert_eq!(ast.get(struct_id).identifier(), "S");
+    assert_eq!(ast.get(entity_id).kind(), "operation");
+    assert_eq!(ast.as_slice()[struct_id.index()].to_string(), "Struct");
+}
+
+#[test]
+fn node_id_lookups_check_the_element_type() {
+    // Arrange
+    let ast = parse_for_ast("module Test\nstruct S {}");
+
+    // Act
+    let result = ast.find_node_id::<Interface>("Test::S");
+
+    // Assert
+    assert!(matches!(result, Err(LookupError::TypeMismatch { .. })));
+}
+
+#[test]
+fn node_ids_can_be_listed_by_type() {
+    // Arrange
+    let slice = "
+        module Test
+        struct A {}
+        enum E { X }
+        struct B {}
+    ";
+    let ast = parse_for_ast(slice);
+
+    // Act
+    let ids = ast.node_ids::<Struct>().collect::<Vec<_>>();
+
+    // Assert
+    let identifiers = ids.iter().map(|id| ast.get(*id).identifier()).collect::<Vec<_>>();
+    assert_eq!(identifiers, ["A", "B"]);
+    assert!(ids[0] < ids[1]);
+}
+
+#[test]
+fn asts_can_be_shared_between_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Ast>();
+    assert_send_sync::<NodeId<dyn Type>>();
+}
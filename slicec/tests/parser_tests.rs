@@ -3,8 +3,8 @@
 mod test_helpers;
 
 use crate::test_helpers::*;
-use slicec::diagnostics::{Diagnostic, Error};
-use slicec::grammar::{attributes, AttributeFunctions, Enumerator, Struct};
+use slicec::diagnostics::{Diagnostic, Error, Lint};
+use slicec::grammar::{attributes, AttributeFunctions, Enumerator, NamedSymbol, Struct};
 use slicec::slice_file::Span;
 
 #[test]
@@ -57,6 +57,28 @@ fn string_literals_cannot_contain_newlines() {
     check_diagnostics(diagnostics, [expected]);
 }
 
+#[test]
+fn character_literals_cannot_contain_newlines() {
+    // Arrange
+    let slice = "
+        module Test
+        const C = 'a
+        '
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let span = Span::new((3, 19).into(), (3, 21).into(), "string-0");
+    let expected = Diagnostic::new(Error::Syntax {
+        message: "unterminated character literal".to_owned(),
+    })
+    .set_span(&span);
+
+    check_diagnostics(diagnostics, [expected]);
+}
+
 #[test]
 fn string_literals_support_character_escaping() {
     // Arrange
@@ -73,7 +95,7 @@ fn string_literals_support_character_escaping() {
     // Assert
     let struct_def = ast.find_element::<Struct>("Test::Foo").unwrap();
     let deprecated = struct_def.find_attribute::<attributes::Deprecated>().unwrap();
-    assert_eq!(deprecated.reason, Some("This is a backslash\"\\\"n.".to_owned()))
+    assert_eq!(deprecated.reason, Some("This is a backslash\"\\\"\n.".to_owned()))
 }
 
 #[test]
@@ -110,7 +132,7 @@ fn files_are_parsed_independently() {
     let diagnostics = parse_multiple_for_diagnostics(&[slice1, slice2]);
 
     // Assert
-    let expected_message = "expected one of 'doc comment', 'struct', 'exception', 'class', 'interface', 'enum', 'custom', 'typealias', 'compact', 'unchecked', '[', or '::', but found '-'";
+    let expected_message = "expected one of 'doc comment', 'module', 'struct', 'exception', 'class', 'interface', 'enum', 'custom', 'typealias', 'const', 'compact', 'internal', 'public', 'unchecked', '[', '{', or ';', but found '-'";
     let expected = [
         Diagnostic::new(Error::Syntax {
             message: expected_message.to_owned(),
@@ -121,3 +143,250 @@ fn files_are_parsed_independently() {
     ];
     check_diagnostics(diagnostics, expected);
 }
+
+#[test]
+fn redundant_semicolons_are_accepted_with_a_lint() {
+    // Arrange
+    let slice = "
+        module Test;
+
+        struct S {
+            a: int32;
+            b: bool;
+        };
+
+        interface I {
+            op();
+        }
+
+        enum E : uint8 { A; B }
+        const C: int32 = 5;
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected: [Diagnostic; 7] = std::array::from_fn(|_| {
+        Diagnostic::new(Lint::RedundantSeparator {
+            separator: ";".to_owned(),
+        })
+    });
+    check_diagnostics(diagnostics, expected);
+}
+
+#[test]
+fn trailing_commas_in_comma_separated_lists_are_accepted_with_a_lint() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+
+        exception E1 {}
+        exception E2 {}
+
+        interface Base {}
+        interface I : Base, {
+            op() throws (E1, E2,)
+        }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Lint::RedundantSeparator {
+        separator: ",".to_owned(),
+    })
+    .set_span(&Span::new((9, 27).into(), (9, 28).into(), "string-0"));
+    let expected = [
+        expected,
+        Diagnostic::new(Lint::RedundantSeparator {
+            separator: ",".to_owned(),
+        }),
+    ];
+    check_diagnostics(diagnostics, expected);
+}
+
+#[test]
+fn commas_between_members_are_canonical() {
+    // Arrange
+    let slice = "
+        module Test
+
+        struct S { a: int32, b: bool, }
+        enum E { A, B, }
+        interface I {
+            op(a: int32, b: bool,)
+        }
+    ";
+
+    // Act/Assert
+    assert_parses(slice);
+}
+
+#[test]
+fn redundant_separator_lint_can_be_allowed() {
+    // Arrange
+    let slice = "
+        [[allow(RedundantSeparator)]]
+        module Test;
+
+        struct S { a: int32; };
+    ";
+
+    // Act/Assert
+    assert_parses(slice);
+}
+
+#[test]
+fn parser_recovers_from_syntax_errors_and_reports_all_of_them() {
+    // Arrange
+    let slice = "
+        module Test
+
+        struct S {
+            a: int32
+            b bool
+        }
+
+        interface I {
+            op(x: int32 -> bool
+        }
+
+        [foo(,)]
+        struct T {}
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = [
+        Diagnostic::new(Error::Syntax {
+            message: "expected ':', but found 'bool'".to_owned(),
+        })
+        .set_span(&Span::new((6, 15).into(), (6, 19).into(), "string-0")),
+        Diagnostic::new(Error::Syntax {
+            message: "expected one of 'identifier', 'doc comment', 'tag', ')', '[', ',', '=', or '?', but found '->'".to_owned(),
+        })
+        .set_span(&Span::new((10, 25).into(), (10, 27).into(), "string-0")),
+        Diagnostic::new(Error::Syntax {
+            message: "expected one of 'identifier', 'string literal', 'raw string literal', or ')', but found ','".to_owned(),
+        })
+        .set_span(&Span::new((13, 14).into(), (13, 15).into(), "string-0")),
+    ];
+    check_diagnostics(diagnostics, expected);
+}
+
+#[test]
+fn definitions_are_kept_after_syntax_errors() {
+    // Arrange
+    let slice = "
+        module Test
+
+        struct S {
+            a: int32
+            b bool
+        }
+
+        struct T {
+            c: S
+        }
+    ";
+
+    // Act
+    let state = parse(slice, None);
+
+    // Assert
+    assert!(state.diagnostics.has_errors());
+    let s = state.ast.find_element::<Struct>("Test::S").unwrap();
+    assert_eq!(s.fields().len(), 1);
+    assert_eq!(s.fields()[0].parser_scoped_identifier(), "Test::S::a");
+
+    // The definition after the error should be in the module's scope, not the broken struct's scope.
+    let t = state.ast.find_element::<Struct>("Test::T").unwrap();
+    assert_eq!(t.fields()[0].parser_scoped_identifier(), "Test::T::c");
+}
+
+#[test]
+fn parser_skips_broken_definitions() {
+    // Arrange
+    let slice = "
+        module Test
+
+        struct 5 {}
+
+        struct S {}
+    ";
+
+    // Act
+    let state = parse(slice, None);
+
+    // Assert
+    assert!(state.ast.find_element::<Struct>("Test::S").is_ok());
+    let diagnostics = diagnostics_from_compilation_state(state, &Default::default());
+    let expected = Diagnostic::new(Error::Syntax {
+        message: "expected 'identifier', but found '5'".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn a_malformed_member_reports_one_error_and_parsing_resumes_after_it() {
+    // Arrange
+    let slice = "
+        module Test
+
+        struct S {
+            a: int32
+            b bool;
+            c: int32
+        }
+
+        struct T {}
+    ";
+
+    // Act
+    let state = parse(slice, None);
+
+    // Assert
+    let s = state.ast.find_element::<Struct>("Test::S").unwrap();
+    let identifiers = s.fields().iter().map(|f| f.identifier()).collect::<Vec<_>>();
+    assert_eq!(identifiers, ["a", "c"]);
+    assert!(state.ast.find_element::<Struct>("Test::T").is_ok());
+
+    let diagnostics = diagnostics_from_compilation_state(state, &Default::default());
+    let expected = Diagnostic::new(Error::Syntax {
+        message: "expected ':', but found 'bool'".to_owned(),
+    })
+    .set_span(&Span::new((6, 15).into(), (6, 19).into(), "string-0"));
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn an_unclosed_attribute_on_a_member_reports_one_error() {
+    // Arrange
+    let slice = "
+        module Test
+
+        struct S {
+            [foo(
+            a: int32
+        }
+
+        struct T {}
+    ";
+
+    // Act
+    let state = parse(slice, None);
+
+    // Assert
+    assert!(state.ast.find_element::<Struct>("Test::T").is_ok());
+    let diagnostics = diagnostics_from_compilation_state(state, &Default::default());
+    let expected = Diagnostic::new(Error::Syntax {
+        message: "expected one of ')' or ',', but found ':'".to_owned(),
+    })
+    .set_span(&Span::new((6, 14).into(), (6, 15).into(), "string-0"));
+    check_diagnostics(diagnostics, [expected]);
+}
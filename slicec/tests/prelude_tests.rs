@@ -0,0 +1,79 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
+use slicec::prelude::PreludeFile;
+use slicec::slice_options::SliceOptions;
+
+fn options_with_prelude(contents: &str) -> SliceOptions {
+    SliceOptions {
+        prelude: vec![PreludeFile::new("builtins.slice", contents)],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn prelude_definitions_are_resolvable() {
+    // Arrange
+    let options = options_with_prelude(
+        "
+            module Builtin
+            interface Object {
+                ping()
+            }
+        ",
+    );
+    let slice = "
+        module Test
+        interface Greeter : Builtin::Object {}
+    ";
+
+    // Act
+    let state = parse(slice, Some(&options));
+
+    // Assert
+    assert!(state.diagnostics.is_empty());
+    let greeter = state.ast.find_element::<Interface>("Test::Greeter").unwrap();
+    let operations = greeter.all_operations();
+    assert_eq!(operations.len(), 1);
+    assert_eq!(operations[0].parser_scoped_identifier(), "Builtin::Object::ping");
+}
+
+#[test]
+fn prelude_files_are_added_first_as_reference_files() {
+    // Arrange
+    let options = options_with_prelude("module Builtin");
+
+    // Act
+    let state = parse("module Test", Some(&options));
+
+    // Assert
+    assert_eq!(state.files.len(), 2);
+    assert_eq!(state.files[0].relative_path, "builtins.slice");
+    assert!(!state.files[0].is_source);
+}
+
+#[test]
+fn prelude_definitions_are_validated() {
+    // Arrange
+    let options = options_with_prelude(
+        "
+            module Builtin
+            enum E : uint8 {}
+        ",
+    );
+
+    // Act
+    let state = parse("module Test", Some(&options));
+
+    // Assert
+    let diagnostics = diagnostics_from_compilation_state(state, &options);
+    assert_eq!(diagnostics[0].span().unwrap().file, "builtins.slice");
+    let expected = Diagnostic::new(Error::MustContainEnumerators {
+        enum_identifier: "E".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
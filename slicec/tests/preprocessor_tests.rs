@@ -5,6 +5,7 @@ pub mod test_helpers;
 use crate::test_helpers::*;
 use slicec::diagnostics::{Diagnostic, Error};
 use slicec::grammar::*;
+use slicec::slice_file::{Location, Span};
 use slicec::slice_options::SliceOptions;
 use test_case::test_case;
 
@@ -390,3 +391,33 @@ fn preprocessor_recovers_at_end_of_line() {
     ];
     check_diagnostics(diagnostics, expected);
 }
+
+#[test]
+fn diagnostic_spans_map_to_original_lines() {
+    // Arrange
+    let slice = "\
+module Test
+#define Foo
+#if Foo
+#   if Bar
+struct S {}
+#   endif
+#elif Baz
+struct S {}
+#else
+struct S {}
+#endif
+enum E : uint8 {}
+";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert: the span points at line 12, even though lines 2 through 11 were consumed by the preprocessor.
+    let span = Span::new(Location { row: 12, col: 1 }, Location { row: 12, col: 7 }, "string-0");
+    let expected = Diagnostic::new(Error::MustContainEnumerators {
+        enum_identifier: "E".to_owned(),
+    })
+    .set_span(&span);
+    check_diagnostics(diagnostics, [expected]);
+}
@@ -22,6 +22,9 @@ use test_case::test_case;
 #[test_case("float32", Primitive::Float32, "Slice2"; "float32")]
 #[test_case("float64", Primitive::Float64, "Slice2"; "float64")]
 #[test_case("string", Primitive::String, "Slice2"; "string")]
+#[test_case("uuid", Primitive::Uuid, "Slice2"; "uuid")]
+#[test_case("timestamp", Primitive::Timestamp, "Slice2"; "timestamp")]
+#[test_case("duration", Primitive::Duration, "Slice2"; "duration")]
 #[test_case("AnyClass", Primitive::AnyClass, "Slice1"; "AnyClass")]
 fn type_parses(slice_component: &str, expected: Primitive, mode: &str) {
     // Arrange
@@ -49,3 +52,44 @@ fn type_parses(slice_component: &str, expected: Primitive, mode: &str) {
         panic!("type alias was unpatched");
     }
 }
+
+#[test]
+fn uuid_is_fixed_size() {
+    // Arrange
+    let slice = "
+        module Test
+
+        compact struct S {
+            id: uuid
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+    assert_eq!(struct_def.fixed_wire_size(), Some(16));
+    assert_eq!(Primitive::Uuid.tag_format(), Some(TagFormat::VSize));
+}
+
+#[test_case("timestamp"; "timestamp")]
+#[test_case("duration"; "duration")]
+fn time_types_are_encoded_on_8_bytes(type_name: &str) {
+    // Arrange
+    let slice = format!(
+        "
+            module Test
+
+            typealias T = {type_name}
+        "
+    );
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let underlying = &ast.find_element::<TypeAlias>("Test::T").unwrap().underlying;
+    assert_eq!(underlying.fixed_wire_size(), Some(8));
+    assert_eq!(underlying.tag_format(), Some(TagFormat::F8));
+}
@@ -0,0 +1,248 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::grammar::*;
+
+/// Parses the provided Slice file, and re-emits it with [ToSliceString].
+fn print(slice: &str) -> String {
+    let state = parse(slice, None);
+    assert!(!state.diagnostics.has_errors(), "{:?}", state.diagnostics);
+    state.files[0].to_slice_string()
+}
+
+/// Checks that the provided Slice file is already in the canonical style, by re-emitting it and comparing the result.
+fn assert_round_trips(slice: &str) {
+    assert_eq!(print(slice), slice);
+}
+
+#[test]
+fn definitions_round_trip() {
+    // Arrange
+    let slice = "\
+module Test
+
+/// A struct.
+/// @see ::Test::Base
+compact struct Point {
+    x: int32
+    y: int32
+    const Origin: int32 = 0
+}
+
+[deprecated(\"use something else\")]
+struct Problem {
+    reason: string
+    tag(1) code: int32?
+    retries: uint8 = 3
+}
+
+interface Base {}
+
+/// Does things.
+interface Thing : Base {
+    op(a: Sequence<Point>, b: Dictionary<string, bool?>) -> Result<Point, string>
+    idempotent get() -> (x: int32, tag(2) y: bool?)
+    upload(data: stream uint8)
+    find(name: string, limit: int32 = 10, exact: bool = true)
+}
+
+unchecked enum Color : uint8 {
+    Red = 1
+    Green = 2
+}
+
+enum Shape {
+    Circle(radius: float64)
+    Square
+}
+
+internal custom Timestamp
+
+typealias Points = Sequence<Point>
+
+const Mask: int64 = (1 + 2) * 4 | ~0 >> 1
+";
+
+    // Act/Assert
+    assert_round_trips(slice);
+}
+
+#[test]
+fn slice1_definitions_round_trip() {
+    // Arrange
+    let slice = "\
+mode = Slice1
+[[allow(Deprecated)]]
+
+module Test
+
+class Base(5) {}
+
+class Derived : Base {
+    b: Base?
+}
+
+exception E {}
+
+interface I {
+    op() throws (E, E)
+}
+";
+
+    // Act/Assert
+    assert_round_trips(slice);
+}
+
+#[test]
+fn module_blocks_are_emitted_after_the_file_module() {
+    // Arrange
+    let slice = "
+        module Outer
+        struct A {}
+
+        module Inner {
+            struct B { a: A }
+        }
+    ";
+
+    // Act
+    let printed = print(slice);
+
+    // Assert
+    let expected = "\
+module Outer
+
+struct A {}
+
+module Inner {
+    struct B {
+        a: ::Outer::A
+    }
+}
+";
+    assert_eq!(printed, expected);
+    assert_round_trips(expected);
+}
+
+#[test]
+fn a_file_containing_a_single_module_block_is_emitted_with_a_module_declaration() {
+    // Arrange
+    let slice = "
+        module Outer {
+            struct A {}
+
+            module Inner {
+                struct B {}
+            }
+        }
+    ";
+
+    // Act
+    let printed = print(slice);
+
+    // Assert
+    let expected = "\
+module Outer
+
+struct A {}
+
+module Inner {
+    struct B {}
+}
+";
+    assert_eq!(printed, expected);
+    assert_round_trips(expected);
+}
+
+#[test]
+fn doc_comments_are_emitted_with_their_tags() {
+    // Arrange
+    let slice = "
+        //! A file comment.
+        module Test
+
+        interface I {
+            /// Does {@link S} things.
+            /// Over multiple lines.
+            /// @param x: the x.
+            /// @returns: something.
+            /// @example(x = 5): an example.
+            op(x: int32) -> bool
+        }
+
+        struct S {}
+    ";
+
+    // Act
+    let printed = print(slice);
+
+    // Assert
+    let expected = "\
+//! A file comment.
+
+module Test
+
+interface I {
+    /// Does {@link ::Test::S} things.
+    /// Over multiple lines.
+    /// @param x: the x.
+    /// @returns: something.
+    /// @example(x = 5): an example.
+    op(x: int32) -> bool
+}
+
+struct S {}
+";
+    assert_eq!(printed, expected);
+    assert_round_trips(expected);
+}
+
+#[test]
+fn type_aliases_are_emitted_as_their_underlying_types() {
+    // Arrange
+    let slice = r#"
+        module Test
+        typealias Name = [cs::type("System.String")] string
+        struct S { name: Name }
+    "#;
+
+    // Act
+    let printed = print(slice);
+
+    // Assert
+    assert!(printed.contains(r#"    name: [cs::type("System.String")] string"#), "{printed}");
+}
+
+#[test]
+fn elements_can_be_emitted_individually() {
+    // Arrange
+    let slice = "
+        module Test
+        struct S {
+            [deprecated]
+            a: Sequence<int32>?
+        }
+    ";
+    let ast = parse_for_ast(slice);
+
+    // Act
+    let field = ast.find_element::<Field>("Test::S::a").unwrap();
+
+    // Assert
+    assert_eq!(field.to_slice_string(), "[deprecated]\na: Sequence<int32>?");
+    assert_eq!(field.data_type.to_slice_string(), "Sequence<int32>?");
+}
+
+#[test]
+fn string_literals_are_escaped() {
+    // Arrange
+    let slice = r#"module Test
+
+const S: string = "a\tb\n\"c\" \\ \u{7f}"
+"#;
+
+    // Act/Assert
+    assert_round_trips(slice);
+}
@@ -0,0 +1,398 @@
+// Copyright (c) ZeroC, Inc.
+
+use slicec::grammar::ToSliceString;
+use slicec::protobuf_importer::{import_descriptor_set, MappingNote, ProtobufImport};
+
+// Helpers for encoding descriptors in the Protobuf wire format.
+
+fn encode_varint(mut value: u64, buffer: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn varint(number: u32, value: i64) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    encode_varint(u64::from(number) << 3, &mut buffer);
+    encode_varint(value as u64, &mut buffer);
+    buffer
+}
+
+fn bytes(number: u32, value: &[u8]) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    encode_varint((u64::from(number) << 3) | 2, &mut buffer);
+    encode_varint(value.len() as u64, &mut buffer);
+    buffer.extend_from_slice(value);
+    buffer
+}
+
+fn string(number: u32, value: &str) -> Vec<u8> {
+    bytes(number, value.as_bytes())
+}
+
+fn message(number: u32, fields: &[Vec<u8>]) -> Vec<u8> {
+    bytes(number, &fields.concat())
+}
+
+// Helpers for encoding specific descriptors. The numbers are from `google/protobuf/descriptor.proto`.
+
+const OPTIONAL: i64 = 1;
+const REQUIRED: i64 = 2;
+const REPEATED: i64 = 3;
+
+const INT32: i64 = 5;
+const BOOL: i64 = 8;
+const STRING: i64 = 9;
+const MESSAGE: i64 = 11;
+const BYTES: i64 = 12;
+const ENUM: i64 = 14;
+
+fn file(name: &str, package: &str, syntax: &str, definitions: &[Vec<u8>]) -> Vec<u8> {
+    let header = [string(1, name), string(2, package), string(12, syntax)];
+    message(1, &[header.concat(), definitions.concat()])
+}
+
+fn message_type(name: &str, contents: &[Vec<u8>]) -> Vec<u8> {
+    message(4, &[string(1, name), contents.concat()])
+}
+
+fn nested_type(name: &str, contents: &[Vec<u8>]) -> Vec<u8> {
+    message(3, &[string(1, name), contents.concat()])
+}
+
+fn field(name: &str, number: i64, label: i64, kind: i64, type_name: &str) -> Vec<u8> {
+    field_with(name, number, label, kind, type_name, &[])
+}
+
+fn field_with(name: &str, number: i64, label: i64, kind: i64, type_name: &str, extra: &[Vec<u8>]) -> Vec<u8> {
+    let type_name = if type_name.is_empty() { Vec::new() } else { string(6, type_name) };
+    let header = [string(1, name), varint(3, number), varint(4, label), varint(5, kind), type_name];
+    message(2, &[header.concat(), extra.concat()])
+}
+
+fn enum_type(number: u32, name: &str, values: &[(&str, i64)]) -> Vec<u8> {
+    let values = values.iter().map(|(name, value)| message(2, &[string(1, name), varint(2, *value)]));
+    message(number, &[string(1, name), values.collect::<Vec<_>>().concat()])
+}
+
+fn method(name: &str, input_type: &str, output_type: &str, extra: &[Vec<u8>]) -> Vec<u8> {
+    message(2, &[string(1, name), string(2, input_type), string(3, output_type), extra.concat()])
+}
+
+fn import(files: &[Vec<u8>]) -> ProtobufImport {
+    import_descriptor_set(&files.concat()).unwrap()
+}
+
+/// Compiles the imported files, and returns their emitted Slice, after checking they compile without diagnostics.
+fn compile(import: &ProtobufImport) -> Vec<String> {
+    let state = slicec::compile_from_builders(&import.files, None, |_| {}, |_| {});
+    assert!(state.diagnostics.is_empty(), "{:?}", state.diagnostics);
+    state.files.iter().map(|file| file.raw_text.clone()).collect()
+}
+
+#[test]
+fn messages_are_mapped_to_structs() {
+    // Arrange
+    let order = message_type(
+        "Order",
+        &[
+            field("order_id", 1, OPTIONAL, STRING, ""),
+            field("items", 2, REPEATED, MESSAGE, ".acme.orders.Order.Item"),
+            field("quantities", 3, REPEATED, MESSAGE, ".acme.orders.Order.QuantitiesEntry"),
+            field("signature", 4, OPTIONAL, BYTES, ""),
+            field("shipping", 5, OPTIONAL, MESSAGE, ".acme.orders.Address"),
+            // A proto3 'optional' field, which is in a synthetic oneof.
+            field_with("priority", 6, OPTIONAL, INT32, "", &[varint(9, 0), varint(17, 1)]),
+            message(8, &[string(1, "_priority")]),
+            nested_type("Item", &[field("sku", 1, OPTIONAL, STRING, "")]),
+            nested_type(
+                "QuantitiesEntry",
+                &[
+                    field("key", 1, OPTIONAL, STRING, ""),
+                    field("value", 2, OPTIONAL, INT32, ""),
+                    message(7, &[varint(7, 1)]),
+                ],
+            ),
+        ],
+    );
+    let address = message_type("Address", &[field("street", 1, OPTIONAL, STRING, "")]);
+    let files = [file("acme/orders.proto", "acme.orders", "proto3", &[order, address])];
+
+    // Act
+    let import = import(&files);
+
+    // Assert
+    let expected = "\
+module Acme::Orders
+
+struct Order {
+    orderId: string
+    items: Sequence<::Acme::Orders::OrderItem>
+    quantities: Dictionary<string, int32>
+    signature: Sequence<uint8>
+    shipping: ::Acme::Orders::Address?
+    priority: int32?
+}
+
+struct OrderItem {
+    sku: string
+}
+
+struct Address {
+    street: string
+}
+";
+    assert_eq!(import.files[0].path, "acme/orders.slice");
+    assert_eq!(compile(&import), [expected]);
+    assert_eq!(import.notes, [MappingNote {
+        element: "acme.orders.Order.Item".to_owned(),
+        message: "the nested message was moved to the top-level as 'OrderItem'".to_owned(),
+    }]);
+}
+
+#[test]
+fn enums_are_mapped_to_enums() {
+    // Arrange
+    let status = enum_type(5, "OrderStatus", &[
+        ("ORDER_STATUS_UNKNOWN", 0),
+        ("ORDER_STATUS_SHIPPED", 1),
+        ("ORDER_STATUS_SENT", 1),
+        ("CANCELLED", 2),
+    ]);
+    let files = [
+        file("v3.proto", "v3", "proto3", std::slice::from_ref(&status)),
+        file("v2.proto", "v2", "proto2", &[status]),
+    ];
+
+    // Act
+    let import = import(&files);
+
+    // Assert
+    let expected = |module: &str, unchecked: &str| {
+        format!(
+            "\
+module {module}
+
+{unchecked}enum OrderStatus : int32 {{
+    Unknown = 0
+    Shipped = 1
+    Cancelled = 2
+}}
+"
+        )
+    };
+    assert_eq!(compile(&import), [expected("V3", "unchecked "), expected("V2", "")]);
+
+    let note = import.notes[0].to_string();
+    assert_eq!(
+        note,
+        "v3.OrderStatus.ORDER_STATUS_SENT: the alias of 'ORDER_STATUS_SHIPPED' was skipped, since enumerator values \
+         must be unique",
+    );
+}
+
+#[test]
+fn services_are_mapped_to_interfaces() {
+    // Arrange
+    let empty = file("google/protobuf/empty.proto", "google.protobuf", "proto3", &[message_type("Empty", &[])]);
+    let order = message_type("Order", &[field("id", 1, OPTIONAL, INT32, "")]);
+    let service = message(
+        6,
+        &[
+            string(1, "OrderService"),
+            // `idempotency_level = NO_SIDE_EFFECTS`.
+            method("GetOrder", ".acme.Order", ".acme.Order", &[message(4, &[varint(34, 1)])]),
+            method("Ping", ".google.protobuf.Empty", ".google.protobuf.Empty", &[]),
+            method("Upload", ".acme.Order", ".google.protobuf.Empty", &[varint(5, 1)]),
+            method("Watch", ".google.protobuf.Empty", ".acme.Order", &[varint(6, 1)]),
+        ],
+    );
+    let files = [empty, file("acme.proto", "acme", "proto3", &[order, service])];
+
+    // Act
+    let import = import(&files);
+
+    // Assert
+    let expected = "\
+module Acme
+
+struct Order {
+    id: int32
+}
+
+interface OrderService {
+    idempotent getOrder(request: ::Acme::Order) -> ::Acme::Order
+    ping()
+    upload(request: stream ::Acme::Order)
+    watch() -> stream ::Acme::Order
+}
+";
+    assert_eq!(compile(&import)[1], expected);
+    assert!(import.notes.is_empty());
+}
+
+#[test]
+fn constructs_without_an_exact_mapping_are_noted() {
+    // Arrange
+    let shape = message_type(
+        "Shape",
+        &[
+            field("id", 1, REQUIRED, INT32, ""),
+            field_with("label", 2, OPTIONAL, STRING, "", &[string(7, "none")]),
+            field_with("circle", 3, OPTIONAL, INT32, "", &[varint(9, 0)]),
+            field_with("square", 4, OPTIONAL, INT32, "", &[varint(9, 0)]),
+            field("legacy", 5, OPTIONAL, 10, ".Shape.Legacy"),
+            field("created", 6, OPTIONAL, MESSAGE, ".google.protobuf.Timestamp"),
+            message(8, &[string(1, "kind")]),
+        ],
+    );
+    let files = [file("shapes.proto", "", "proto2", &[shape])];
+
+    // Act
+    let import = import(&files);
+
+    // Assert
+    let notes = import.notes.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(notes, [
+        "shapes.proto: the file has no package, so it was mapped to a module named after the file",
+        "Shape.label: the default value 'none' wasn't mapped",
+        "Shape.kind: the oneof 'kind' was mapped to separate optional fields, at most one of which should be set",
+        "Shape.legacy: groups aren't supported, so the field was skipped",
+        "Shape.created: the type 'google.protobuf.Timestamp' isn't in the descriptor set; make sure to generate it \
+         with '--include_imports'",
+    ]);
+
+    let expected = "\
+module Shapes
+
+struct Shape {
+    id: int32
+    label: string?
+    circle: int32?
+    square: int32?
+    created: ::Google::Protobuf::Timestamp?
+}
+";
+    assert_eq!(import.files[0].to_slice_string(), expected);
+}
+
+#[test]
+fn comments_and_deprecation_are_preserved() {
+    // Arrange
+    let location = |path: &[i64], comment: &str| {
+        let path = path.iter().map(|segment| varint(1, *segment)).collect::<Vec<_>>().concat();
+        message(1, &[path, string(3, comment)])
+    };
+    let source_code_info = message(
+        9,
+        &[
+            location(&[4, 0], " A widget.\n\n More details.\n"),
+            location(&[4, 0, 2, 0], " The widget's name.\n"),
+        ],
+    );
+    let widget = message_type(
+        "Widget",
+        &[
+            field_with("name", 1, OPTIONAL, STRING, "", &[message(8, &[varint(3, 1)])]),
+            field("is_enabled", 2, OPTIONAL, BOOL, ""),
+            message(7, &[varint(3, 1)]),
+        ],
+    );
+    let files = [file("widgets.proto", "acme.widgets", "proto3", &[widget, source_code_info])];
+
+    // Act
+    let import = import(&files);
+
+    // Assert
+    let expected = "\
+module Acme::Widgets
+
+/// A widget.
+///
+/// More details.
+[deprecated]
+struct Widget {
+    /// The widget's name.
+    [deprecated]
+    name: string
+    isEnabled: bool
+}
+";
+    assert_eq!(compile(&import), [expected]);
+}
+
+#[test]
+fn enum_fields_refer_to_the_mapped_enum() {
+    // Arrange
+    let color = enum_type(4, "Color", &[("RED", 0), ("GREEN", 1)]);
+    let pixel = message_type("Pixel", &[field("color", 1, OPTIONAL, ENUM, ".gfx.Pixel.Color"), color]);
+    let files = [file("gfx.proto", "gfx", "proto3", &[pixel])];
+
+    // Act
+    let import = import(&files);
+
+    // Assert
+    let emitted = compile(&import);
+    assert!(emitted[0].contains("color: ::Gfx::PixelColor\n"));
+    assert!(emitted[0].contains("unchecked enum PixelColor : int32 {"));
+}
+
+#[test]
+fn malformed_descriptor_sets_are_rejected() {
+    // Arrange
+    let mut bytes = file("a.proto", "a", "proto3", &[]);
+    bytes.truncate(bytes.len() - 2);
+
+    // Act
+    let result = import_descriptor_set(&bytes);
+
+    // Assert
+    assert_eq!(result.unwrap_err(), "unexpected end of input");
+}
+
+#[test]
+fn recursive_fields_are_skipped() {
+    // Arrange
+    let node = message_type(
+        "Node",
+        &[
+            field("value", 1, OPTIONAL, INT32, ""),
+            field("next", 2, OPTIONAL, MESSAGE, ".graph.Node"),
+            field("children", 3, REPEATED, MESSAGE, ".graph.Node"),
+            field("parent", 4, OPTIONAL, MESSAGE, ".graph.Edge"),
+        ],
+    );
+    let edge = message_type("Edge", &[field("target", 1, OPTIONAL, MESSAGE, ".graph.Node")]);
+    let graph = message_type("Graph", &[field("root", 1, OPTIONAL, MESSAGE, ".graph.Node")]);
+    let files = [file("graph.proto", "graph", "proto3", &[node, edge, graph])];
+
+    // Act
+    let import = import(&files);
+
+    // Assert
+    let notes = import.notes.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(notes, [
+        "graph.Node.next: the field makes 'Node' contain itself, which structs can't do, so it was skipped",
+        "graph.Node.children: the field makes 'Node' contain itself, which structs can't do, so it was skipped",
+        "graph.Node.parent: the field makes 'Node' contain itself, which structs can't do, so it was skipped",
+    ]);
+
+    let expected = "\
+module Graph
+
+struct Node {
+    value: int32
+}
+
+struct Edge {
+    target: ::Graph::Node?
+}
+
+struct Graph {
+    root: ::Graph::Node?
+}
+";
+    assert_eq!(compile(&import), [expected]);
+}
@@ -0,0 +1,77 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::grammar::*;
+
+#[test]
+fn queries_can_filter_by_type_module_and_attribute() {
+    // Arrange
+    let slice1 = "
+        module Foo
+        interface I {
+            [deprecated] op1()
+            op2()
+        }
+        struct S {
+            [deprecated] f: int32
+        }
+    ";
+    let slice2 = "
+        module Bar
+        interface J {
+            [deprecated] op3()
+        }
+    ";
+    let ast = parse_multiple_for_ast(&[slice1, slice2]);
+
+    // Act
+    let query = ast.query().of_type::<Operation>().in_module("Foo").with_attribute("deprecated");
+    let operations = query.into_iter().map(|op| op.identifier()).collect::<Vec<_>>();
+
+    // Assert
+    assert_eq!(operations, ["op1"]);
+}
+
+#[test]
+fn queries_return_elements_in_ast_order() {
+    // Arrange
+    let slice = "
+        module Test
+        struct A {}
+        enum E { X }
+        struct B {}
+    ";
+    let ast = parse_for_ast(slice);
+
+    // Act
+    let structs = ast.query().of_type::<Struct>().iter().map(|s| s.identifier()).collect::<Vec<_>>();
+    let entities = ast.query().in_module("Test").iter().map(|e| e.identifier()).collect::<Vec<_>>();
+
+    // Assert
+    // Elements are added to the AST once they're fully parsed, so enumerators come before their enums.
+    assert_eq!(structs, ["A", "B"]);
+    assert_eq!(entities, ["A", "X", "E", "B"]);
+}
+
+#[test]
+fn queries_can_use_custom_predicates() {
+    // Arrange
+    let slice = "
+        module Test
+        struct S {
+            a: int32
+            tag(1) b: string?
+            tag(2) c: bool?
+        }
+    ";
+    let ast = parse_for_ast(slice);
+
+    // Act
+    let query = ast.query().of_type::<Field>().filter(|field| field.is_tagged()).with_identifier("c");
+    let fields = query.iter().map(|field| field.parser_scoped_identifier()).collect::<Vec<_>>();
+
+    // Assert
+    assert_eq!(fields, ["Test::S::c"]);
+}
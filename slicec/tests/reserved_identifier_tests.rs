@@ -0,0 +1,163 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::slice_options::{ReservedIdentifier, SliceOptions};
+use test_case::test_case;
+
+fn options_with_reserved(reserved_identifiers: Vec<ReservedIdentifier>) -> SliceOptions {
+    SliceOptions {
+        reserved_identifiers,
+        ..Default::default()
+    }
+}
+
+#[test_case("struct IceData {}", "IceData"; "struct")]
+#[test_case("interface I { iceOp() }", "iceOp"; "operation")]
+#[test_case("interface I { op(ICE_value: int32) }", "ICE_value"; "parameter")]
+#[test_case("enum E : uint8 { iceA }", "iceA"; "enumerator")]
+fn identifiers_with_reserved_prefixes_are_rejected(definition: &str, identifier: &str) {
+    // Arrange
+    let slice = format!("module Test\n{definition}");
+    let options = options_with_reserved(vec![ReservedIdentifier::prefix("ice", "slicec-cs")]);
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+    // Assert
+    let expected = Diagnostic::new(Error::ReservedIdentifier {
+        identifier: identifier.to_owned(),
+        reserved_by: "slicec-cs".to_owned(),
+    })
+    .add_note("identifiers starting with 'ice' are reserved", None);
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn reserved_identifiers_only_match_exactly() {
+    // Arrange
+    let slice = "
+        module Test
+        interface I {
+            op() -> (ReturnValue: int32, returnValues: int32)
+        }
+    ";
+    let options = options_with_reserved(vec![ReservedIdentifier::exact("returnValue", "slicec-cs")]);
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+    // Assert
+    let expected = Diagnostic::new(Error::ReservedIdentifier {
+        identifier: "ReturnValue".to_owned(),
+        reserved_by: "slicec-cs".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn nested_module_segments_are_checked() {
+    // Arrange
+    let slice = "module Foo::Ice::Bar";
+    let options = options_with_reserved(vec![ReservedIdentifier::exact("Ice", "slicec-cs")]);
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+    // Assert
+    let expected = Diagnostic::new(Error::ReservedIdentifier {
+        identifier: "Ice".to_owned(),
+        reserved_by: "slicec-cs".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn identifiers_are_unrestricted_by_default() {
+    // Arrange
+    let slice = "
+        module Test
+        struct IceData {
+            returnValue: int32
+        }
+    ";
+
+    // Act/Assert
+    assert_parses(slice);
+}
+
+mod keywords {
+    use crate::test_helpers::*;
+    use slicec::diagnostics::{Diagnostic, Lint};
+    use slicec::slice_options::{SliceOptions, TargetKeywords};
+    use test_case::test_case;
+
+    fn options_with_keywords(target_keywords: Vec<TargetKeywords>) -> SliceOptions {
+        SliceOptions {
+            target_keywords,
+            ..Default::default()
+        }
+    }
+
+    #[test_case("struct S { ref: int32 }", "ref"; "field")]
+    #[test_case("interface I { op(params: int32) }", "params"; "parameter")]
+    #[test_case("enum E : uint8 { ref }", "ref"; "enumerator")]
+    fn identifiers_which_collide_with_keywords_are_reported(definition: &str, identifier: &str) {
+        // Arrange
+        let slice = format!("module Test\n{definition}");
+        let options = options_with_keywords(vec![TargetKeywords::new("C#", &["ref", "params"])]);
+
+        // Act
+        let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::KeywordCollision {
+            identifier: identifier.to_owned(),
+            language: "C#".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn collisions_are_reported_for_each_language() {
+        // Arrange
+        let slice = "module Foo::type::Bar";
+        let options = options_with_keywords(vec![
+            TargetKeywords::new("Rust", &["type"]),
+            TargetKeywords::new("Swift", &["Type", "type"]),
+        ]);
+
+        // Act
+        let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+        // Assert
+        let expected = ["Rust", "Swift"].map(|language| {
+            Diagnostic::new(Lint::KeywordCollision {
+                identifier: "type".to_owned(),
+                language: language.to_owned(),
+            })
+        });
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn keyword_collisions_can_be_allowed() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {
+                [allow(KeywordCollision)]
+                ref: int32
+            }
+        ";
+        let options = options_with_keywords(vec![TargetKeywords::new("C#", &["ref"])]);
+
+        // Act
+        let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+        // Assert
+        check_diagnostics(diagnostics, [] as [Diagnostic; 0]);
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::grammar::Struct;
+use slicec::schema_package::{SchemaPackage, SCHEMA_PACKAGE_FORMAT_VERSION};
+use slicec::slice_file::SliceFile;
+use slicec::slice_options::SliceOptions;
+use std::path::Path;
+
+fn path_string(path: &Path) -> String {
+    path.to_str().unwrap().to_owned()
+}
+
+fn source_file(path: &str, contents: &str) -> SliceFile {
+    SliceFile::new(path.to_owned(), contents.to_owned(), true)
+}
+
+#[test]
+fn packages_only_contain_source_files() {
+    // Arrange
+    let files = [
+        source_file("b.slice", "module B"),
+        source_file("a.slice", "module A"),
+        SliceFile::new("c.slice".to_owned(), "module C".to_owned(), false),
+    ];
+
+    // Act
+    let package = SchemaPackage::create(&files, Some("1.2.0".to_owned()), None);
+
+    // Assert
+    let paths = package.files.iter().map(|file| file.path.as_str()).collect::<Vec<_>>();
+    assert_eq!(paths, ["a.slice", "b.slice"]);
+    assert_eq!(package.manifest.format_version, SCHEMA_PACKAGE_FORMAT_VERSION);
+    assert_eq!(package.manifest.version.as_deref(), Some("1.2.0"));
+    assert_eq!(package.signature, None);
+    assert_eq!(package.verify(None), Ok(()));
+}
+
+#[test]
+fn modified_packages_fail_verification() {
+    // Arrange
+    let files = [source_file("a.slice", "module A")];
+    let mut package = SchemaPackage::create(&files, None, None);
+
+    // Act
+    package.files[0].contents = "module Evil".to_owned();
+
+    // Assert
+    assert_eq!(
+        package.verify(None),
+        Err("content hash mismatch for 'a.slice'".to_owned())
+    );
+}
+
+#[test]
+fn signed_packages_can_be_verified() {
+    // Arrange
+    let files = [source_file("a.slice", "module A")];
+
+    // Act
+    let package = SchemaPackage::create(&files, None, Some(b"secret"));
+
+    // Assert
+    assert!(package.signature.is_some());
+    assert_eq!(package.verify(Some(b"secret")), Ok(()));
+    assert_eq!(
+        package.verify(Some(b"other")),
+        Err("package signature is invalid".to_owned())
+    );
+}
+
+#[test]
+fn signatures_cover_the_whole_manifest() {
+    // Arrange
+    let files = [source_file("a.slice", "module A")];
+    let mut package = SchemaPackage::create(&files, Some("1.0.0".to_owned()), Some(b"secret"));
+
+    // Act
+    package.manifest.version = Some("2.0.0".to_owned());
+
+    // Assert
+    assert_eq!(
+        package.verify(Some(b"secret")),
+        Err("package signature is invalid".to_owned())
+    );
+}
+
+#[test]
+fn unsigned_packages_fail_verification_when_a_key_is_required() {
+    // Arrange
+    let files = [source_file("a.slice", "module A")];
+    let package = SchemaPackage::create(&files, None, None);
+
+    // Act
+    let result = package.verify(Some(b"secret"));
+
+    // Assert
+    assert_eq!(result, Err("package is not signed".to_owned()));
+}
+
+#[test]
+fn exported_packages_can_be_used_as_references() {
+    // Arrange
+    let directory = TempDir::new("exported");
+    let package_path = directory.join("exported.slicepkg");
+    let library_path = directory.write_file("library.slice", "module Test\nstruct Inner {}");
+    let consumer_path = directory.write_file("consumer.slice", "module Consumer\nstruct S { inner: Test::Inner }");
+
+    let export_options = SliceOptions {
+        sources: vec![path_string(&library_path)],
+        export_package: Some(path_string(&package_path)),
+        ..Default::default()
+    };
+    let state = slicec::compile_from_options(&export_options, |_| {}, |_| {});
+    assert!(state.diagnostics.is_empty());
+    std::fs::remove_file(&library_path).unwrap();
+
+    let options = SliceOptions {
+        sources: vec![path_string(&consumer_path)],
+        references: vec![path_string(&package_path)],
+        ..Default::default()
+    };
+
+    // Act
+    let state = slicec::compile_from_options(&options, |_| {}, |_| {});
+
+    // Assert
+    assert!(state.diagnostics.is_empty());
+    assert!(state.ast.find_element::<Struct>("Test::Inner").is_ok());
+    assert!(!state.files[1].is_source);
+    assert!(state.files[1].relative_path.starts_with(&path_string(&package_path)));
+}
+
+#[test]
+fn packages_are_not_exported_in_dry_runs() {
+    // Arrange
+    let directory = TempDir::new("export-dry-run");
+    let package_path = directory.join("exported.slicepkg");
+    let source_path = directory.write_file("a.slice", "module A");
+    let options = SliceOptions {
+        sources: vec![path_string(&source_path)],
+        export_package: Some(path_string(&package_path)),
+        dry_run: true,
+        ..Default::default()
+    };
+
+    // Act
+    let state = slicec::compile_from_options(&options, |_| {}, |_| {});
+
+    // Assert
+    assert!(state.diagnostics.is_empty());
+    assert!(!package_path.exists());
+}
+
+#[test]
+fn tampered_packages_are_rejected() {
+    // Arrange
+    let directory = TempDir::new("tampered");
+    let package_path = directory.join("tampered.slicepkg");
+    let mut package = SchemaPackage::create(&[source_file("a.slice", "module A")], None, None);
+    package.files[0].contents = "module Evil".to_owned();
+    std::fs::write(&package_path, serde_json::to_string(&package).unwrap()).unwrap();
+
+    let options = SliceOptions {
+        sources: vec![],
+        references: vec![path_string(&package_path)],
+        ..Default::default()
+    };
+
+    // Act
+    let state = slicec::compile_from_options(&options, |_| {}, |_| {});
+
+    // Assert
+    let diagnostics = diagnostics_from_compilation_state(state, &options);
+    assert_eq!(diagnostics.len(), 1);
+    let message = diagnostics[0].message();
+    assert!(message.ends_with("content hash mismatch for 'a.slice'"), "{message}");
+}
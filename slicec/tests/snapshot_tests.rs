@@ -0,0 +1,64 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::compilation_state::CompilationSnapshot;
+use slicec::grammar::*;
+use slicec::slice_options::SliceOptions;
+
+#[test]
+fn snapshots_share_their_compilation() {
+    // Arrange
+    let slice = "
+        module Test
+        struct S {}
+    ";
+    let options = SliceOptions::default();
+
+    // Act
+    let snapshot = parse(slice, Some(&options)).into_snapshot(&options);
+    let clone = snapshot.clone();
+
+    // Assert
+    assert!(snapshot.ptr_eq(&clone));
+    assert!(!clone.has_errors());
+    assert_eq!(clone.files().len(), 1);
+
+    let original = snapshot.ast().find_element::<Struct>("Test::S").unwrap();
+    let cloned = clone.ast().find_element::<Struct>("Test::S").unwrap();
+    assert!(std::ptr::eq(original, cloned));
+}
+
+#[test]
+fn snapshots_keep_their_diagnostics() {
+    // Arrange
+    let slice = "
+        module Test
+        enum E : int8 {}
+    ";
+    let options = SliceOptions::default();
+
+    // Act
+    let snapshot = parse(slice, Some(&options)).into_snapshot(&options);
+
+    // Assert
+    assert!(snapshot.has_errors());
+    assert_eq!(snapshot.diagnostics().len(), 1);
+}
+
+#[test]
+fn snapshots_can_be_shared_between_threads() {
+    // Arrange
+    let options = SliceOptions::default();
+    let snapshot = parse("module Test\nstruct S {}", Some(&options)).into_snapshot(&options);
+
+    // Act
+    let clone = snapshot.clone();
+    let handle = std::thread::spawn(move || clone.ast().find_element::<Struct>("Test::S").is_ok());
+
+    // Assert
+    assert!(handle.join().unwrap());
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<CompilationSnapshot>();
+}
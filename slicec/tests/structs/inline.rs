@@ -0,0 +1,86 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::test_helpers::*;
+use slicec::grammar::*;
+
+#[test]
+fn inline_structs_are_named_after_where_they_are_declared() {
+    // Arrange
+    let slice = "
+        module Test
+        interface Geometry {
+            getOrigin() -> struct { x: int32, y: int32 }
+            move(by: compact struct { dx: int32, dy: int32 }) -> (a: bool, b: struct { z: bool })
+        }
+        struct Line {
+            start: struct { x: int32 }
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let return_struct = ast.find_element::<Struct>("Test::GeometryGetOriginReturnValue").unwrap();
+    let field_identifiers = return_struct.fields().iter().map(|f| f.identifier()).collect::<Vec<_>>();
+    assert_eq!(field_identifiers, ["x", "y"]);
+    assert!(!return_struct.is_compact);
+
+    let parameter_struct = ast.find_element::<Struct>("Test::GeometryMoveBy").unwrap();
+    assert!(parameter_struct.is_compact);
+    assert!(ast.find_element::<Struct>("Test::GeometryMoveB").is_ok());
+    assert!(ast.find_element::<Field>("Test::LineStart::x").is_ok());
+}
+
+#[test]
+fn inline_structs_are_referenced_by_their_members() {
+    // Arrange
+    let slice = "
+        module Test
+        struct Line {
+            start: struct { x: int32, y: int32 }
+            end: Sequence<struct { x: int32, inner: struct { z: bool } }>
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let start = ast.find_element::<Field>("Test::Line::start").unwrap();
+    let Types::Struct(start_struct) = start.data_type().concrete_type() else { panic!() };
+    assert_eq!(start_struct.parser_scoped_identifier(), "Test::LineStart");
+
+    let inner = ast.find_element::<Field>("Test::LineEnd::inner").unwrap();
+    assert_eq!(inner.data_type().type_string(), "LineEndInner");
+}
+
+#[test]
+fn inline_structs_are_defined_before_the_definition_that_declares_them() {
+    // Arrange
+    let slice = "
+        module Test
+        struct Point {
+            location: struct { x: int32 }
+        }
+    ";
+    let state = parse(slice, None);
+
+    // Act
+    let printed = state.files[0].to_slice_string();
+
+    // Assert
+    let expected = "\
+module Test
+
+struct PointLocation {
+    x: int32
+}
+
+struct Point {
+    location: PointLocation
+}
+";
+    assert_eq!(printed, expected);
+    assert_parses(printed);
+}
@@ -1,5 +1,6 @@
 // Copyright (c) ZeroC, Inc.
 
 mod container;
+mod inline;
 mod mode_compatibility;
 mod tags;
@@ -7,6 +7,7 @@ mod tags {
     use crate::test_helpers::*;
     use slicec::diagnostics::{Diagnostic, Error};
     use slicec::grammar::*;
+    use slicec::slice_file::Span;
     use test_case::test_case;
 
     #[test]
@@ -239,4 +240,242 @@ mod tags {
         });
         check_diagnostics(diagnostics, [expected]);
     }
+
+    #[test_case("Slice1")]
+    #[test_case("Slice2")]
+    fn tag_values_are_validated_in_every_compilation_mode(mode: &str) {
+        // Arrange
+        let slice = format!(
+            "
+            mode = {mode}
+            module Test
+            interface I {{
+                op(tag(2147483648) a: int32?)
+            }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::TagValueOutOfBounds)
+            .set_span(&Span::new((5, 24).into(), (5, 34).into(), "string-0"));
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn validated_tags_are_returned_as_varint32s() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {
+                tag(2147483647) a: int32?
+                b: int32
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let a = ast.find_element::<Field>("Test::S::a").unwrap();
+        let b = ast.find_element::<Field>("Test::S::b").unwrap();
+        assert_eq!(a.validated_tag(), Some(i32::MAX));
+        assert_eq!(b.validated_tag(), None);
+    }
+
+    #[test]
+    fn fields_are_encoded_in_tag_order_after_required_fields() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {
+                tag(10) a: int32?
+                b: string
+                tag(2) c: bool?
+                d: int8
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+        let order = struct_def.encoding_order(Encoding::Slice2);
+        let identifiers = order.into_iter().map(|field| field.identifier()).collect::<Vec<_>>();
+        assert_eq!(identifiers, ["b", "d", "c", "a"]);
+    }
+
+    #[test]
+    fn class_fields_are_encoded_one_slice_at_a_time() {
+        // Arrange
+        let slice = "
+            mode = Slice1
+            module Test
+            class Base {
+                tag(1) a: int32?
+                b: string
+            }
+            class Derived : Base {
+                c: bool
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let class_def = ast.find_element::<Class>("Test::Derived").unwrap();
+        let order = class_def.encoding_order(Encoding::Slice1);
+        let identifiers = order.into_iter().map(|field| field.identifier()).collect::<Vec<_>>();
+        assert_eq!(identifiers, ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn member_layouts_partition_required_and_tagged_members() {
+        // Arrange
+        let slice = "
+            module Test
+            interface I {
+                op(tag(3) a: int32?, b: string?, tag(1) c: bool?, d: int8, e: stream uint8)
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let operation = ast.find_element::<Operation>("Test::I::op").unwrap();
+        let layout = operation.parameter_layout();
+        fn identifiers(members: Vec<&Parameter>) -> Vec<&str> {
+            members.into_iter().map(|member| member.identifier()).collect()
+        }
+        assert_eq!(identifiers(layout.required.clone()), ["b", "d"]);
+        assert_eq!(identifiers(layout.tagged.clone()), ["c", "a"]);
+        assert_eq!(identifiers(layout.ordered()), ["b", "d", "c", "a"]);
+        assert_eq!(identifiers(layout.bit_sequence_members(Encoding::Slice2)), ["b"]);
+        assert!(layout.bit_sequence_members(Encoding::Slice1).is_empty());
+        assert_eq!(layout.bit_sequence_size(Encoding::Slice2), 1);
+    }
+}
+
+mod reserved_tags {
+
+    use crate::test_helpers::*;
+    use slicec::diagnostics::{Diagnostic, Error, Lint};
+    use slicec::slice_options::SliceOptions;
+    use test_case::test_case;
+
+    #[test_case("7", 7; "single tag")]
+    #[test_case("100..200", 199; "exclusive range")]
+    #[test_case("100..=199", 150; "inclusive range")]
+    fn cannot_use_reserved_tags(reserved: &str, tag: u32) {
+        // Arrange
+        let slice = format!(
+            "
+            module Test
+            [reservedTags(\"{reserved}\")]
+            struct S {{
+                tag({tag}) a: int32?
+            }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::TagIsReserved {
+            identifier: "a".to_owned(),
+            tag,
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn reserved_tags_are_checked_for_operations() {
+        // Arrange
+        let slice = "
+            module Test
+            interface I {
+                [reservedTags(\"0..=4\")]
+                op(tag(5) a: int32?) -> (tag(3) b: int32?, tag(8) c: int32?)
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::TagIsReserved {
+            identifier: "b".to_owned(),
+            tag: 3,
+        })
+        .add_note("tags '0..=4' are reserved here", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("abc"; "not a tag")]
+    #[test_case("5..5"; "empty range")]
+    #[test_case("9..=3"; "backwards range")]
+    fn invalid_ranges_are_rejected(reserved: &str) {
+        // Arrange
+        let slice = format!("module Test\n[reservedTags(\"{reserved}\")]\nstruct S {{}}");
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::ArgumentNotSupported {
+            argument: reserved.to_owned(),
+            directive: "reservedTags".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn unreserved_gaps_are_reported_when_auditing_tags() {
+        // Arrange
+        let slice = "
+            module Test
+            [reservedTags(\"3..5\")]
+            struct S {
+                tag(0) a: int32?
+                tag(1) b: int32?
+                tag(7) c: int32?
+                tag(9) d: int32?
+            }
+        ";
+        let options = SliceOptions {
+            audit_tags: true,
+            ..Default::default()
+        };
+
+        // Act
+        let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+        // Assert
+        let expected = ["2", "5..=6", "8"].map(|tags| {
+            let tags = tags.to_owned();
+            Diagnostic::new(Lint::UnreservedTagGap { tags })
+        });
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn gaps_are_not_reported_by_default() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {
+                tag(0) a: int32?
+                tag(10) b: int32?
+            }
+        ";
+
+        // Act/Assert
+        assert_parses(slice);
+    }
 }
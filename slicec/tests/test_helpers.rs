@@ -1,69 +1,62 @@
 // Copyright (c) ZeroC, Inc.
 
-//! This module contains test helper functions that are specific to slicec.
-//! For the general purpose test helpers, see 'src/test_helpers.rs'.
+//! This module re-exports slicec's test helpers, which are available with the `test-support` feature.
+//! See 'src/test_helpers.rs'. It also contains helpers which are only used by slicec's own tests, like [TempDir].
 
 // It's fine if a test doesn't need all of these functions.
-#![allow(dead_code)]
+#![allow(dead_code, unused_imports)]
 
-use slicec::ast::Ast;
-use slicec::compilation_state::CompilationState;
-use slicec::compile_from_strings;
-use slicec::diagnostics::Diagnostic;
-use slicec::slice_options::SliceOptions;
-
-// Re-export the general purpose test helpers to make it easier for tests to use them.
 pub use slicec::test_helpers::*;
 
-/// This function parses the provided Slice file.
-/// It is the lowest level test helper function, returning a full [`CompilationState`] instead of only part of it.
-/// It also allows tests to configure the compiler by passing in [`SliceOptions`].
-#[must_use]
-pub fn parse(slice: impl Into<String>, options: Option<&SliceOptions>) -> CompilationState {
-    compile_from_strings(&[&slice.into()], options, |_| {}, |_| {})
-}
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// A fresh (empty) directory in the system's temp directory, for tests that read or write real files.
+/// The directory, and everything in it, is removed when this is dropped.
+#[derive(Debug)]
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Creates a fresh directory for the test with the provided name, removing any directory left over from a previous
+    /// run. Names only need to be unique within a test binary, since the directory's name includes the process ID.
+    pub fn new(name: &str) -> Self {
+        let directory = std::env::temp_dir().join(format!("slicec-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).expect("failed to create temp directory");
+        TempDir(directory)
+    }
 
-/// This function parses the provided Slice file and returns the AST generated by doing so.
-/// If any errors are encountered during parsing, it panics.
-#[must_use]
-pub fn parse_for_ast(slice: impl Into<String>) -> Ast {
-    let compilation_state = parse(slice, None);
-    if compilation_state.diagnostics.has_errors() {
-        panic!("{:?}", compilation_state.diagnostics);
+    /// Writes a file with the provided contents at `path` (relative to this directory), creating any missing parent
+    /// directories, and returns the file's full path.
+    pub fn write_file(&self, path: &str, contents: &str) -> PathBuf {
+        let path = self.0.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).expect("failed to create directory");
+        std::fs::write(&path, contents).expect("failed to write file");
+        path
     }
-    compilation_state.ast
-}
 
-/// This function parses the provided Slice files and returns the AST generated by doing so.
-/// Each string is treated as a separate Slice file by the parser.
-#[must_use]
-pub fn parse_multiple_for_ast(slice: &[&str]) -> Ast {
-    let compilation_state = compile_from_strings(slice, None, |_| {}, |_| {});
-    if compilation_state.diagnostics.has_errors() {
-        panic!("{:?}", compilation_state.diagnostics);
+    /// Returns the full path of `path` (relative to this directory) as a string.
+    pub fn path_string(&self, path: &str) -> String {
+        self.0.join(path).display().to_string()
     }
-    compilation_state.ast
 }
 
-/// This function parses the provided Slice file and returns any Diagnostics that were emitted during parsing.
-#[must_use]
-pub fn parse_for_diagnostics(slice: impl Into<String>) -> Vec<Diagnostic> {
-    parse_multiple_for_diagnostics(&[&slice.into()])
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
 }
 
-/// This function parses the provided Slice files and returns any Diagnostics that were emitted during parsing.
-/// Each string is treated as a separate Slice file by the parser.
-#[must_use]
-pub fn parse_multiple_for_diagnostics(slice: &[&str]) -> Vec<Diagnostic> {
-    diagnostics_from_compilation_state(
-        compile_from_strings(slice, None, |_| {}, |_| {}),
-        &SliceOptions::default(),
-    )
+impl AsRef<Path> for TempDir {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
 }
 
-/// Asserts that the provided slice parses okay, producing no errors.
-pub fn assert_parses(slice: impl Into<String>) {
-    let diagnostics = parse_for_diagnostics(slice);
-    let expected: [Diagnostic; 0] = []; // Compiler needs the type hint.
-    check_diagnostics(diagnostics, expected);
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
 }
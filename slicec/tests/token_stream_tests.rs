@@ -0,0 +1,98 @@
+// Copyright (c) ZeroC, Inc.
+
+use slicec::slice_file::Location;
+use slicec::token_stream::{tokenize, TokenCategory};
+use test_case::test_case;
+
+#[test]
+fn tokens_cover_the_entire_source() {
+    // Arrange
+    let slice = "
+        //! File comment.
+        #define FOO
+        module Test // The module.
+
+        /// A struct.
+        struct S {
+            /* A field. */ tag(1) x: Sequence<int32>?
+        }
+        #if FOO
+        const y: string = \"€\"
+        #endif
+    ";
+
+    // Act
+    let tokens = tokenize(slice);
+
+    // Assert
+    let text = tokens.iter().map(|token| token.text).collect::<String>();
+    assert_eq!(text, slice);
+
+    let mut position = 0;
+    for token in &tokens {
+        assert_eq!(token.range.start, position);
+        assert_eq!(&slice[token.range.clone()], token.text);
+        position = token.range.end;
+    }
+}
+
+#[test]
+fn tokens_are_categorized() {
+    // Arrange
+    let slice = "#if FOO\n/// Doc.\nstruct S { x: int32 = 5 } // Comment.\n#endif";
+
+    // Act
+    let tokens = tokenize(slice);
+
+    // Assert
+    let categorized = tokens
+        .iter()
+        .filter(|token| token.category != TokenCategory::Whitespace)
+        .map(|token| (token.category, token.text))
+        .collect::<Vec<_>>();
+    let expected = vec![
+        (TokenCategory::PreprocessorDirective, "#if FOO"),
+        (TokenCategory::DocComment, "/// Doc."),
+        (TokenCategory::Keyword, "struct"),
+        (TokenCategory::Identifier, "S"),
+        (TokenCategory::Punctuation, "{"),
+        (TokenCategory::Identifier, "x"),
+        (TokenCategory::Punctuation, ":"),
+        (TokenCategory::BuiltinType, "int32"),
+        (TokenCategory::Operator, "="),
+        (TokenCategory::IntegerLiteral, "5"),
+        (TokenCategory::Punctuation, "}"),
+        (TokenCategory::Comment, "// Comment."),
+        (TokenCategory::PreprocessorDirective, "#endif"),
+    ];
+    assert_eq!(categorized, expected);
+}
+
+#[test]
+fn tokens_have_locations() {
+    // Arrange
+    let slice = "module Test\n/* Ünï */ struct S {}";
+
+    // Act
+    let tokens = tokenize(slice);
+
+    // Assert
+    // Columns are counted in characters, not bytes.
+    let identifier = tokens.iter().find(|token| token.text == "S").unwrap();
+    assert_eq!(identifier.start, Location { row: 2, col: 18 });
+    assert_eq!(identifier.end, Location { row: 2, col: 19 });
+    assert_eq!(identifier.range, 31..32);
+}
+
+#[test_case("\"unterminated", TokenCategory::StringLiteral; "string literal")]
+#[test_case("/* unterminated", TokenCategory::Comment; "block comment")]
+#[test_case("$", TokenCategory::Unknown; "unknown symbol")]
+fn invalid_text_is_tokenized(slice: &str, expected: TokenCategory) {
+    // Act
+    let tokens = tokenize(slice);
+
+    // Assert
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].category, expected);
+    assert_eq!(tokens[0].text, slice);
+}
@@ -0,0 +1,114 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::grammar::*;
+use slicec::slice_options::SliceOptions;
+use slicec::trivia::{TriviaKind, TriviaPosition};
+
+fn trivia_options() -> SliceOptions {
+    SliceOptions {
+        preserve_trivia: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn trivia_is_not_preserved_by_default() {
+    // Arrange
+    let slice = "
+        module Test
+
+        // A comment.
+        struct S {}
+    ";
+
+    // Act
+    let state = parse(slice, None);
+
+    // Assert
+    assert!(state.files[0].trivia.is_empty());
+}
+
+#[test]
+fn comments_and_blank_lines_are_attached_to_the_next_element() {
+    // Arrange
+    let slice = "
+        module Test
+
+        // A comment about S.
+        /// A doc comment.
+        struct S {
+            /* A block comment. */
+            a: int32
+        }
+    ";
+
+    // Act
+    let state = parse(slice, Some(&trivia_options()));
+
+    // Assert
+    let file = &state.files[0];
+    let struct_id = state.ast.find_node_id::<Struct>("Test::S").unwrap();
+    let struct_trivia = file.trivia_for(struct_id).collect::<Vec<_>>();
+    assert_eq!(struct_trivia.len(), 2);
+    assert_eq!(struct_trivia[0].kind, TriviaKind::BlankLines(1));
+    assert_eq!(struct_trivia[1].kind, TriviaKind::LineComment);
+    assert_eq!(struct_trivia[1].text, "// A comment about S.");
+    assert!(struct_trivia.iter().all(|trivia| trivia.position == TriviaPosition::Leading));
+
+    let field_id = state.ast.find_node_id::<Field>("Test::S::a").unwrap();
+    let field_trivia = file.trivia_for(field_id).collect::<Vec<_>>();
+    assert_eq!(field_trivia.len(), 1);
+    assert_eq!(field_trivia[0].kind, TriviaKind::BlockComment);
+    assert_eq!(field_trivia[0].text, "/* A block comment. */");
+    assert_eq!(field_trivia[0].span.start.row, 7);
+}
+
+#[test]
+fn comments_on_the_same_line_as_an_element_are_trailing() {
+    // Arrange
+    let slice = "
+        module Test
+        enum E {
+            A // The first enumerator.
+            B
+        }
+    ";
+
+    // Act
+    let state = parse(slice, Some(&trivia_options()));
+
+    // Assert
+    let file = &state.files[0];
+    let a_id = state.ast.find_node_id::<Enumerator>("Test::E::A").unwrap();
+    let a_trivia = file.trivia_for(a_id).collect::<Vec<_>>();
+    assert_eq!(a_trivia.len(), 1);
+    assert_eq!(a_trivia[0].text, "// The first enumerator.");
+    assert_eq!(a_trivia[0].position, TriviaPosition::Trailing);
+
+    let b_id = state.ast.find_node_id::<Enumerator>("Test::E::B").unwrap();
+    assert_eq!(file.trivia_for(b_id).count(), 0);
+}
+
+#[test]
+fn trivia_after_every_element_is_not_attached() {
+    // Arrange
+    let slice = "
+        module Test
+        struct S {}
+
+        // A comment at the end of the file.
+    ";
+
+    // Act
+    let state = parse(slice, Some(&trivia_options()));
+
+    // Assert
+    let file = &state.files[0];
+    let trivia = file.trivia.last().unwrap();
+    assert_eq!(trivia.kind, TriviaKind::LineComment);
+    assert!(trivia.attached_to.is_none());
+    assert_eq!(trivia.position, TriviaPosition::Leading);
+}
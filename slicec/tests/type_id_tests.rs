@@ -0,0 +1,110 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::compilation_state::CompilationState;
+use slicec::compile_from_strings;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
+use slicec::slice_options::SliceOptions;
+use slicec::utils::type_id_util::{check_type_ids, DefaultTypeIdStrategy, TypeIdStrategy};
+
+/// A type ID strategy that only uses an entity's identifier, ignoring its module.
+struct UnscopedTypeIdStrategy;
+
+impl TypeIdStrategy for UnscopedTypeIdStrategy {
+    fn type_id(&self, entity: &dyn Entity) -> String {
+        entity.identifier().to_owned()
+    }
+}
+
+fn check_unscoped_type_ids(state: &mut CompilationState) {
+    check_type_ids(&UnscopedTypeIdStrategy, &state.ast, &mut state.diagnostics);
+}
+
+#[test]
+fn default_type_ids_are_fully_scoped() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Foo::Bar
+
+        class C(5) {}
+        exception E {}
+        interface I {}
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let class_def = ast.find_element::<Class>("Foo::Bar::C").unwrap();
+    let exception_def = ast.find_element::<Exception>("Foo::Bar::E").unwrap();
+    let interface_def = ast.find_element::<Interface>("Foo::Bar::I").unwrap();
+    assert_eq!(DefaultTypeIdStrategy.type_id(class_def), "::Foo::Bar::C");
+    assert_eq!(DefaultTypeIdStrategy.compact_type_id(class_def), Some(5));
+    assert_eq!(DefaultTypeIdStrategy.type_id(exception_def), "::Foo::Bar::E");
+    assert_eq!(DefaultTypeIdStrategy.type_id(interface_def), "::Foo::Bar::I");
+}
+
+#[test]
+fn duplicate_compact_type_ids_are_disallowed() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+
+        class A(1) {}
+        class B(1) {}
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::DuplicateCompactTypeId { compact_id: 1 })
+        .add_note("'Test::A' has the same type ID", None);
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn custom_strategies_are_checked_for_duplicate_type_ids() {
+    // Arrange
+    let slice1 = "
+        module Foo
+        interface I {}
+    ";
+    let slice2 = "
+        module Bar
+        interface I {}
+    ";
+
+    // Act
+    let state = compile_from_strings(&[slice1, slice2], None, |_| {}, check_unscoped_type_ids);
+    let diagnostics = diagnostics_from_compilation_state(state, &SliceOptions::default());
+
+    // Assert
+    let expected = Diagnostic::new(Error::DuplicateTypeId {
+        type_id: "I".to_owned(),
+    })
+    .add_note("'Foo::I' has the same type ID", None);
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn custom_strategies_with_unique_type_ids_are_accepted() {
+    // Arrange
+    let slice = "
+        module Foo
+        interface I {}
+        interface J {}
+    ";
+
+    // Act
+    let state = compile_from_strings(&[slice], None, |_| {}, check_unscoped_type_ids);
+    let diagnostics = diagnostics_from_compilation_state(state, &SliceOptions::default());
+
+    // Assert
+    assert!(diagnostics.is_empty());
+}
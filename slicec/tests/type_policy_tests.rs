@@ -0,0 +1,203 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod type_policy {
+
+    use crate::test_helpers::*;
+    use clap::Parser;
+    use slicec::diagnostics::{Diagnostic, Error};
+    use slicec::slice_options::{DeniedType, SliceOptions};
+    use test_case::test_case;
+
+    fn options_denying(denied_types: &[&str]) -> SliceOptions {
+        let args = denied_types.iter().flat_map(|denied| ["--deny-type", denied]);
+        SliceOptions::try_parse_from(["slicec", "file.slice"].into_iter().chain(args)).unwrap()
+    }
+
+    #[test_case("float32", "f: float32"; "primitives")]
+    #[test_case("AnyClass", "c: AnyClass?"; "any class")]
+    #[test_case("Sequence", "s: Sequence<int32>"; "sequences")]
+    #[test_case("Dictionary", "d: Dictionary<int32, int32>"; "dictionaries")]
+    #[test_case("custom", "c: MyCustom"; "custom types")]
+    fn denied_types_are_rejected(denied: &str, field: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                mode = Slice1
+                module Test
+                custom MyCustom
+                class C {{
+                    {field}
+                }}
+            "
+        );
+        let options = options_denying(&[denied]);
+
+        // Act
+        let state = parse(slice, Some(&options));
+        let diagnostics = diagnostics_from_compilation_state(state, &options);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DeniedTypeUsed {
+            name: denied.to_owned(),
+            policy_code: None,
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn nested_denied_types_are_rejected() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {
+                s: Sequence<Dictionary<string, float64>>
+            }
+        ";
+        let options = options_denying(&["float64"]);
+
+        // Act
+        let state = parse(slice, Some(&options));
+        let diagnostics = diagnostics_from_compilation_state(state, &options);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DeniedTypeUsed {
+            name: "float64".to_owned(),
+            policy_code: None,
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn unchecked_enums_can_be_denied() {
+        // Arrange
+        let slice = "
+            module Test
+            unchecked enum E : uint8 {}
+        ";
+        let options = options_denying(&["unchecked-enum=unchecked enums break exhaustive matching"]);
+
+        // Act
+        let state = parse(slice, Some(&options));
+        let diagnostics = diagnostics_from_compilation_state(state, &options);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DeniedTypeUsed {
+            name: "unchecked-enum".to_owned(),
+            policy_code: None,
+        })
+        .add_note("unchecked enums break exhaustive matching", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn denied_types_are_reported_with_their_policy_codes() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {
+                a: float32
+                b: Sequence<int32>
+            }
+        ";
+        let options = options_denying(&["float32:myorg::NoFloats=use float64 instead", "Sequence"]);
+
+        // Act
+        let state = parse(slice, Some(&options));
+        let diagnostics = diagnostics_from_compilation_state(state, &options);
+
+        // Assert
+        assert_eq!(diagnostics[0].code(), "myorg::NoFloats");
+        assert_eq!(diagnostics[1].code(), "E064");
+
+        let expected = [
+            Diagnostic::new(Error::DeniedTypeUsed {
+                name: "float32".to_owned(),
+                policy_code: Some("myorg::NoFloats".to_owned()),
+            })
+            .add_note("use float64 instead", None),
+            Diagnostic::new(Error::DeniedTypeUsed {
+                name: "Sequence".to_owned(),
+                policy_code: None,
+            }),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn types_that_are_not_denied_are_allowed() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {
+                a: float64
+                b: Sequence<string>
+            }
+        ";
+        let options = options_denying(&["float32", "Dictionary"]);
+
+        // Act
+        let state = parse(slice, Some(&options));
+        let diagnostics = diagnostics_from_compilation_state(state, &options);
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn reasons_are_parsed_from_the_command_line() {
+        // Act
+        let options = options_denying(&["float32=use float64 instead"]);
+
+        // Assert
+        let expected = DeniedType {
+            name: "float32".to_owned(),
+            code: None,
+            reason: Some("use float64 instead".to_owned()),
+        };
+        assert_eq!(options.denied_types, [expected]);
+    }
+
+    #[test]
+    fn codes_are_parsed_from_the_command_line() {
+        // Act
+        let options = options_denying(&["AnyClass:myorg::NoClasses", "float32 : myorg::NoFloats = use float64"]);
+
+        // Assert
+        let expected = [
+            DeniedType {
+                name: "AnyClass".to_owned(),
+                code: Some("myorg::NoClasses".to_owned()),
+                reason: None,
+            },
+            DeniedType {
+                name: "float32".to_owned(),
+                code: Some("myorg::NoFloats".to_owned()),
+                reason: Some("use float64".to_owned()),
+            },
+        ];
+        assert_eq!(options.denied_types, expected);
+    }
+
+    #[test_case("float32:NoFloats"; "unnamespaced")]
+    #[test_case("float32:myorg::"; "missing name")]
+    #[test_case("float32:"; "empty")]
+    fn codes_must_be_namespaced(denied: &str) {
+        // Act
+        let result = SliceOptions::try_parse_from(["slicec", "file.slice", "--deny-type", denied]);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn unknown_types_cannot_be_denied() {
+        // Act
+        let result = SliceOptions::try_parse_from(["slicec", "file.slice", "--deny-type", "float128"]);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
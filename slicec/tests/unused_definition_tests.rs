@@ -0,0 +1,145 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::compile_from_options;
+use slicec::diagnostics::{Diagnostic, Lint};
+use slicec::slice_options::SliceOptions;
+use std::path::PathBuf;
+
+/// Writes the provided Slice files into a fresh temp directory, and returns the directory, along with the files' paths.
+/// Only definitions in source files can be reported as unused, so these tests compile real files, instead of strings.
+fn write_files(name: &str, files: &[&str]) -> (TempDir, Vec<PathBuf>) {
+    let directory = TempDir::new(name);
+    let paths = files
+        .iter()
+        .enumerate()
+        .map(|(i, slice)| directory.write_file(&format!("file{i}.slice"), slice))
+        .collect();
+    (directory, paths)
+}
+
+/// Compiles the provided source and reference files with `--report-unused`, and returns the emitted diagnostics.
+fn compile_for_unused(sources: &[PathBuf], references: &[PathBuf]) -> Vec<Diagnostic> {
+    let to_strings = |paths: &[PathBuf]| paths.iter().map(|path| path.to_str().unwrap().to_owned()).collect();
+    let options = SliceOptions {
+        sources: to_strings(sources),
+        references: to_strings(references),
+        report_unused: true,
+        ..Default::default()
+    };
+    let state = compile_from_options(&options, |_| {}, |_| {});
+    diagnostics_from_compilation_state(state, &options)
+}
+
+fn unused(kind: &str, identifier: &str) -> Diagnostic {
+    Diagnostic::new(Lint::UnusedDefinition {
+        kind: kind.to_owned(),
+        identifier: identifier.to_owned(),
+    })
+}
+
+#[test]
+fn definitions_which_cannot_be_reached_from_an_interface_are_reported() {
+    // Arrange
+    let slice = "
+        module Test
+
+        const MaxSize = KiB / 8
+        const KiB = 1024
+        const Unused = 5
+
+        enum Color : uint8 { Red = MaxSize, Green }
+        struct Point { x: int32, color: Color }
+        struct Line { start: Point, end: Point }
+        custom Timestamp
+
+        interface Canvas {
+            draw(points: Sequence<Point>)
+        }
+    ";
+    let (_directory, paths) = write_files("unused-reachability", &[slice]);
+
+    // Act
+    let diagnostics = compile_for_unused(&paths, &[]);
+
+    // Assert
+    let expected = [
+        unused("constant", "Unused"),
+        unused("struct", "Line"),
+        unused("custom type", "Timestamp"),
+    ];
+    check_diagnostics(diagnostics, expected);
+}
+
+#[test]
+fn thrown_exceptions_and_bases_are_used() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+
+        exception BaseError {}
+        exception DerivedError : BaseError {}
+        exception OtherError {}
+
+        class Shape {}
+        class Circle : Shape {}
+
+        interface Base {
+            op() throws DerivedError
+        }
+        interface Derived : Base {
+            getCircle() -> Circle
+        }
+    ";
+    let (_directory, paths) = write_files("unused-bases", &[slice]);
+
+    // Act
+    let diagnostics = compile_for_unused(&paths, &[]);
+
+    // Assert
+    check_diagnostics(diagnostics, [unused("exception", "OtherError")]);
+}
+
+#[test]
+fn definitions_can_be_excluded_with_attributes() {
+    // Arrange
+    let slice = "
+        module Test
+
+        [allow(UnusedDefinition)]
+        struct Kept { inner: Inner }
+
+        struct Inner {}
+    ";
+    let (_directory, paths) = write_files("unused-allowed", &[slice]);
+
+    // Act
+    let diagnostics = compile_for_unused(&paths, &[]);
+
+    // Assert
+    check_diagnostics(diagnostics, [] as [Diagnostic; 0]);
+}
+
+#[test]
+fn definitions_in_reference_files_are_not_reported() {
+    // Arrange
+    let source = "
+        module Test
+        struct Used {}
+    ";
+    let reference = "
+        module Other
+        struct Unused {}
+        struct UsesSource { used: Test::Used }
+    ";
+    let (_directory, paths) = write_files("unused-references", &[source, reference]);
+
+    // Act
+    let diagnostics = compile_for_unused(&paths[..1], &paths[1..]);
+
+    // Assert
+    check_diagnostics(diagnostics, [] as [Diagnostic; 0]);
+}
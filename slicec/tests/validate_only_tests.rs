@@ -0,0 +1,113 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::compilation_state::CancellationToken;
+use slicec::diagnostics::{Diagnostic, Error, Lint};
+use slicec::grammar::*;
+use slicec::slice_options::SliceOptions;
+use slicec::{compile_from_options, validate_from_options, validate_from_options_with_cancel};
+
+/// Writes the provided Slice file into a fresh temp directory, and returns the directory.
+fn write_slice_file(name: &str, slice: &str) -> TempDir {
+    let directory = TempDir::new(name);
+    directory.write_file("file.slice", slice);
+    directory
+}
+
+fn options_for(directory: &std::path::Path) -> SliceOptions {
+    SliceOptions {
+        sources: vec![directory.join("file.slice").to_str().unwrap().to_owned()],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn validation_reports_the_same_diagnostics_as_compilation() {
+    // Arrange
+    let slice = "
+        module Test
+
+        /// {@link Fake}
+        struct S {}
+
+        struct S {}
+    ";
+    let directory = write_slice_file("validate-diagnostics", slice);
+    let options = options_for(&directory);
+
+    // Act
+    let validated = validate_from_options(&options);
+    let compiled = compile_from_options(&options, |_| {}, |_| {});
+
+    // Assert
+    let expected = [
+        Diagnostic::new(Lint::BrokenDocLink {
+            message: "no element named 'Fake' exists in scope".to_owned(),
+        }),
+        Diagnostic::new(Error::Redefinition {
+            identifier: "S".to_owned(),
+        }),
+    ];
+    let compiled_diagnostics = diagnostics_from_compilation_state(compiled, &options);
+    let validated_diagnostics = diagnostics_from_compilation_state(validated, &options);
+    assert_eq!(compiled_diagnostics.len(), validated_diagnostics.len());
+    check_diagnostics(validated_diagnostics, expected);
+}
+
+#[test]
+fn validation_skips_the_output_phase() {
+    // Arrange
+    let directory = write_slice_file("validate-output", "module Test\nstruct S {}");
+    let depfile = directory.join("file.d");
+    let options = SliceOptions {
+        depfile: Some(depfile.to_str().unwrap().to_owned()),
+        ..options_for(&directory)
+    };
+
+    // Act
+    let state = validate_from_options(&options);
+
+    // Assert
+    assert!(state.ast.find_element::<Struct>("Test::S").is_ok());
+    assert!(state.metrics.phase("checks").is_some());
+    assert!(state.metrics.phase("output").is_none());
+    assert!(!depfile.exists());
+    assert!(diagnostics_from_compilation_state(state, &options).is_empty());
+}
+
+#[test]
+fn validation_skips_collecting_trivia() {
+    // Arrange
+    let directory = write_slice_file("validate-trivia", "module Test\n\n// A comment.\nstruct S {}");
+    let options = SliceOptions {
+        preserve_trivia: true,
+        ..options_for(&directory)
+    };
+
+    // Act
+    let validated = validate_from_options(&options);
+    let compiled = compile_from_options(&options, |_| {}, |_| {});
+
+    // Assert
+    assert!(validated.files[0].trivia.is_empty());
+    assert!(!compiled.files[0].trivia.is_empty());
+}
+
+#[test]
+fn validation_can_be_cancelled() {
+    // Arrange
+    let directory = write_slice_file("validate-cancelled", "module Test\nstruct S {}");
+    let options = options_for(&directory);
+    let token = CancellationToken::new();
+    token.clone().cancel();
+
+    // Act
+    let state = validate_from_options_with_cancel(&options, &token);
+
+    // Assert
+    assert!(state.ast.find_element::<Struct>("Test::S").is_err());
+    let diagnostics = diagnostics_from_compilation_state(state, &options);
+    check_diagnostics(diagnostics, [Diagnostic::new(Error::CompilationCancelled)]);
+}
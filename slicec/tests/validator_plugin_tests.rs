@@ -0,0 +1,86 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::compilation_state::CompilationState;
+use slicec::diagnostics::{Diagnostic, Diagnostics, Lint};
+use slicec::grammar::*;
+use slicec::slice_options::{SliceOptions, ValidatorPlugin};
+use test_case::test_case;
+
+/// An example plugin which requires that struct identifiers start with an uppercase letter.
+fn check_struct_case(state: &CompilationState, diagnostics: &mut Diagnostics) {
+    for struct_def in state.ast.as_slice().iter().filter_map(|node| <&Struct>::try_from(node).ok()) {
+        if struct_def.identifier().starts_with(|c: char| c.is_lowercase()) {
+            Diagnostic::new(Lint::Custom {
+                code: "myorg::StructCase".to_owned(),
+                message: format!("struct '{}' should start with an uppercase letter", struct_def.identifier()),
+            })
+            .set_span(struct_def.span())
+            .set_scope(struct_def.parser_scoped_identifier())
+            .push_into(diagnostics);
+        }
+    }
+}
+
+fn options_with_plugin(allowed_lints: Vec<String>) -> SliceOptions {
+    SliceOptions {
+        allowed_lints,
+        validator_plugins: vec![ValidatorPlugin::new("myorg", check_struct_case)],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn validator_plugins_can_report_custom_lints() {
+    // Arrange
+    let slice = "
+        module Test
+        struct point {}
+        struct Line {}
+    ";
+    let options = options_with_plugin(vec![]);
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+    // Assert
+    let expected = Diagnostic::new(Lint::Custom {
+        code: "myorg::StructCase".to_owned(),
+        message: "struct 'point' should start with an uppercase letter".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test_case(r#"[[allow("myorg::StructCase")]]"#, vec![]; "file attribute")]
+#[test_case("", vec!["myorg::StructCase".to_owned()]; "command line")]
+#[test_case("", vec!["All".to_owned()]; "all")]
+fn custom_lints_can_be_allowed(file_attribute: &str, allowed_lints: Vec<String>) {
+    // Arrange
+    let slice = format!("{file_attribute}\nmodule Test\nstruct point {{}}");
+    let options = options_with_plugin(allowed_lints);
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+    // Assert
+    check_diagnostics(diagnostics, [] as [Diagnostic; 0]);
+}
+
+#[test]
+fn validator_plugins_do_not_run_if_there_are_errors() {
+    // Arrange
+    let slice = "
+        module Test
+        struct point {}
+        struct point {}
+    ";
+    let options = options_with_plugin(vec![]);
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(parse(slice, Some(&options)), &options);
+
+    // Assert
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.code() != "myorg::StructCase"));
+}
@@ -0,0 +1,174 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
+use slicec::slice_file::Span;
+use slicec::versioning::{Version, VersionRange, VersionedView};
+use test_case::test_case;
+
+#[test_case("2", Some(Version::new(2, 0, 0)); "major")]
+#[test_case("2.1", Some(Version::new(2, 1, 0)); "minor")]
+#[test_case("2.1.3", Some(Version::new(2, 1, 3)); "patch")]
+#[test_case("2.1.3.4", None; "too many components")]
+#[test_case("2.", None; "empty component")]
+#[test_case("v2", None; "prefixed")]
+fn versions_can_be_parsed(version: &str, expected: Option<Version>) {
+    // Act
+    let parsed = Version::parse(version);
+
+    // Assert
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn versions_are_ordered_by_their_components() {
+    // Arrange
+    let versions = ["1.10", "1.2", "2", "1.2.1"].map(|version| Version::parse(version).unwrap());
+
+    // Act
+    let mut sorted = versions;
+    sorted.sort();
+
+    // Assert
+    assert_eq!(sorted.map(|version| version.to_string()), ["1.2.0", "1.2.1", "1.10.0", "2.0.0"]);
+}
+
+#[test]
+fn version_ranges_include_since_and_exclude_removed() {
+    // Arrange
+    let range = VersionRange {
+        since: Some(Version::new(2, 0, 0)),
+        removed: Some(Version::new(3, 0, 0)),
+    };
+
+    // Act/Assert
+    assert!(!range.contains(&Version::new(1, 9, 9)));
+    assert!(range.contains(&Version::new(2, 0, 0)));
+    assert!(range.contains(&Version::new(2, 9, 0)));
+    assert!(!range.contains(&Version::new(3, 0, 0)));
+}
+
+#[test_case(1, &["a", "b"]; "version 1")]
+#[test_case(2, &["a", "b", "c"]; "version 2")]
+#[test_case(3, &["a", "c"]; "version 3")]
+fn views_only_include_available_fields(major: u64, expected: &[&str]) {
+    // Arrange
+    let slice = "
+        module Test
+        struct S {
+            a: int32
+
+            [removed(\"3\")]
+            b: int32
+
+            [since(\"2\")]
+            tag(1) c: int32?
+        }
+    ";
+    let ast = parse_for_ast(slice);
+    let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+
+    // Act
+    let view = VersionedView::new(&ast, Version::new(major, 0, 0));
+    let fields = view.filter(struct_def.fields());
+
+    // Assert
+    let identifiers = fields.iter().map(|field| field.identifier()).collect::<Vec<_>>();
+    assert_eq!(identifiers, expected);
+}
+
+#[test]
+fn elements_are_unavailable_if_their_parent_is_unavailable() {
+    // Arrange
+    let slice = "
+        module Test
+        interface I {
+            [since(\"2.1\")]
+            op(a: int32) -> bool
+        }
+    ";
+    let ast = parse_for_ast(slice);
+    let parameter = ast.find_element::<Parameter>("Test::I::op::a").unwrap();
+
+    // Act
+    let old_view = VersionedView::new(&ast, Version::new(2, 0, 0));
+    let new_view = VersionedView::new(&ast, Version::new(2, 1, 0));
+
+    // Assert
+    assert!(!old_view.contains(parameter));
+    assert!(new_view.contains(parameter));
+}
+
+#[test]
+fn views_can_be_queried() {
+    // Arrange
+    let slice = "
+        module Test
+        struct A {}
+
+        [since(\"2\")]
+        struct B {}
+
+        [removed(\"2\")]
+        struct C {}
+    ";
+    let ast = parse_for_ast(slice);
+
+    // Act
+    let view = VersionedView::new(&ast, Version::new(2, 0, 0));
+    let structs = view.query().of_type::<Struct>();
+
+    // Assert
+    let identifiers = structs.iter().map(|struct_def| struct_def.identifier()).collect::<Vec<_>>();
+    assert_eq!(identifiers, ["A", "B"]);
+}
+
+#[test]
+fn elements_must_be_removed_after_they_were_added() {
+    // Arrange
+    let slice = "
+        module Test
+        [since(\"2.0\")]
+        [removed(\"2\")]
+        struct S {}
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::InvalidVersionRange {
+        since: "2.0".to_owned(),
+        removed: "2".to_owned(),
+    })
+    .set_span(&Span::new((4, 10).into(), (4, 22).into(), "string-0"))
+    .add_note(
+        "the element was added here",
+        Some(&Span::new((3, 10).into(), (3, 22).into(), "string-0")),
+    );
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn removed_requires_a_valid_version() {
+    // Arrange
+    let slice = "
+        module Test
+        [removed(\"soon\")]
+        struct S {}
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::ArgumentNotSupported {
+        argument: "soon".to_owned(),
+        directive: "removed".to_owned(),
+    })
+    .add_note("versions must be 1 to 3 numbers separated by '.', like \"2\", \"2.1\", or \"2.1.0\"", None);
+    check_diagnostics(diagnostics, [expected]);
+}
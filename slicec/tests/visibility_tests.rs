@@ -0,0 +1,135 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
+use slicec::slice_file::Span;
+use test_case::test_case;
+
+#[test_case("", Visibility::Public; "default")]
+#[test_case("public", Visibility::Public; "public")]
+#[test_case("internal", Visibility::Internal; "internal")]
+fn definitions_can_have_visibility_modifiers(modifier: &str, expected: Visibility) {
+    // Arrange
+    let slice = format!(
+        "
+            module Test
+            {modifier} struct S {{}}
+            {modifier} compact enum E {{ A }}
+            {modifier} interface I {{}}
+            {modifier} custom C
+            {modifier} typealias T = int32
+            {modifier} const K: int32 = 0
+        "
+    );
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    assert_eq!(ast.find_element::<Struct>("Test::S").unwrap().visibility, expected);
+    assert_eq!(ast.find_element::<Enum>("Test::E").unwrap().visibility, expected);
+    assert_eq!(ast.find_element::<Interface>("Test::I").unwrap().visibility, expected);
+    assert_eq!(ast.find_element::<CustomType>("Test::C").unwrap().visibility, expected);
+    assert_eq!(ast.find_element::<TypeAlias>("Test::T").unwrap().visibility, expected);
+    assert_eq!(ast.find_element::<Constant>("Test::K").unwrap().visibility, expected);
+}
+
+#[test]
+fn visibility_modifiers_are_included_in_spans() {
+    // Arrange
+    let slice = "
+        module Test
+        internal compact struct S { a: int32 }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+    assert_eq!(struct_def.span(), &Span::new((3, 9).into(), (3, 34).into(), "string-0"));
+}
+
+#[test_case("struct S { a: Secret }"; "struct fields")]
+#[test_case("struct S { a: Sequence<Secret> }"; "sequence elements")]
+#[test_case("struct S { a: Dictionary<string, Secret?> }"; "dictionary values")]
+#[test_case("interface I { op(a: Secret) }"; "parameters")]
+#[test_case("interface I { op() -> Result<bool, Secret> }"; "return types")]
+#[test_case("enum E { A(a: Secret) }"; "enumerator fields")]
+#[test_case("typealias T = Secret"; "type aliases")]
+fn public_definitions_cannot_expose_internal_types(definition: &str) {
+    // Arrange
+    let slice = format!(
+        "
+            module Test
+            internal struct Secret {{}}
+            {definition}
+        "
+    );
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert_eq!(diagnostics[0].code(), "E078");
+}
+
+#[test]
+fn public_classes_cannot_inherit_from_internal_classes() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+        internal class Base {}
+        class Derived : Base {}
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::ExposesInternalType {
+        kind: "class".to_owned(),
+        identifier: "Derived".to_owned(),
+        type_identifier: "Base".to_owned(),
+    })
+    .set_span(&Span::new((5, 25).into(), (5, 29).into(), "string-0"))
+    .add_note(
+        "'Base' is declared as internal here",
+        Some(&Span::new((4, 9).into(), (4, 28).into(), "string-0")),
+    );
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn internal_definitions_can_use_internal_types() {
+    // Arrange
+    let slice = "
+        module Test
+        internal struct Secret {}
+        internal struct S { a: Secret }
+        internal interface I { op(a: Secret) -> Sequence<Secret> }
+        internal typealias T = Secret
+    ";
+
+    // Act/Assert
+    assert_parses(slice);
+}
+
+#[test]
+fn public_definitions_can_use_public_types() {
+    // Arrange
+    let slice = "
+        module Test
+        public struct Visible {}
+        struct S { a: Visible }
+        interface I { op(a: Visible) -> Sequence<Visible> }
+    ";
+
+    // Act/Assert
+    assert_parses(slice);
+}
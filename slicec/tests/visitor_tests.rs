@@ -0,0 +1,211 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::grammar::*;
+use slicec::slice_file::SliceFile;
+use slicec::visitor::Visitor;
+
+/// A visitor which records the order that elements were visited in.
+#[derive(Default)]
+struct RecordingVisitor {
+    events: Vec<String>,
+}
+
+impl RecordingVisitor {
+    fn record(&mut self, event: &str, entity: &dyn Entity) {
+        self.events.push(format!("{event} {}", entity.identifier()));
+    }
+}
+
+impl Visitor for RecordingVisitor {
+    fn visit_file(&mut self, _: &SliceFile) {
+        self.events.push("file".to_owned());
+    }
+
+    fn visit_file_end(&mut self, _: &SliceFile) {
+        self.events.push("file_end".to_owned());
+    }
+
+    fn visit_module(&mut self, module_def: &Module) {
+        self.events.push(format!("module {}", module_def.nested_module_identifier()));
+    }
+
+    fn visit_module_end(&mut self, module_def: &Module) {
+        self.events.push(format!("module_end {}", module_def.nested_module_identifier()));
+    }
+
+    fn visit_struct(&mut self, struct_def: &Struct) {
+        self.record("struct", struct_def);
+    }
+
+    fn visit_struct_end(&mut self, struct_def: &Struct) {
+        self.record("struct_end", struct_def);
+    }
+
+    fn visit_interface(&mut self, interface_def: &Interface) {
+        self.record("interface", interface_def);
+    }
+
+    fn visit_interface_end(&mut self, interface_def: &Interface) {
+        self.record("interface_end", interface_def);
+    }
+
+    fn visit_enum(&mut self, enum_def: &Enum) {
+        self.record("enum", enum_def);
+    }
+
+    fn visit_enum_end(&mut self, enum_def: &Enum) {
+        self.record("enum_end", enum_def);
+    }
+
+    fn visit_operation(&mut self, operation: &Operation) {
+        self.record("operation", operation);
+    }
+
+    fn visit_operation_end(&mut self, operation: &Operation) {
+        self.record("operation_end", operation);
+    }
+
+    fn visit_operation_modifier(&mut self, modifier: &OperationModifier) {
+        self.events.push(format!("modifier {}", modifier.kind.keyword()));
+    }
+
+    fn visit_enumerator(&mut self, enumerator: &Enumerator) {
+        self.record("enumerator", enumerator);
+    }
+
+    fn visit_enumerator_end(&mut self, enumerator: &Enumerator) {
+        self.record("enumerator_end", enumerator);
+    }
+
+    fn visit_field(&mut self, field: &Field) {
+        self.record("field", field);
+    }
+
+    fn visit_parameter(&mut self, parameter: &Parameter) {
+        self.record("parameter", parameter);
+    }
+}
+
+const SLICE: &str = "
+    module Test
+
+    struct S {
+        a: int32
+        b: string
+    }
+
+    interface I {
+        readonly op(x: int32) -> (y: bool, z: bool)
+    }
+
+    enum E {
+        A
+        B(f: int8)
+    }
+";
+
+#[test]
+fn visitor_calls_post_hooks_after_visiting_contents() {
+    // Arrange
+    let compilation_state = parse(SLICE, None);
+    let mut visitor = RecordingVisitor::default();
+
+    // Act
+    compilation_state.files[0].visit_with(&mut visitor);
+
+    // Assert
+    let expected = [
+        "file",
+        "module Test",
+        "struct S",
+        "field a",
+        "field b",
+        "struct_end S",
+        "interface I",
+        "operation op",
+        "modifier readonly",
+        "parameter x",
+        "parameter y",
+        "parameter z",
+        "operation_end op",
+        "interface_end I",
+        "enum E",
+        "enumerator A",
+        "enumerator_end A",
+        "enumerator B",
+        "field f",
+        "enumerator_end B",
+        "enum_end E",
+        "module_end Test",
+        "file_end",
+    ];
+    assert_eq!(visitor.events, expected);
+}
+
+#[test]
+fn walk_with_skips_filtered_subtrees() {
+    // Arrange
+    let compilation_state = parse(SLICE, None);
+    let mut visitor = RecordingVisitor::default();
+
+    // Act
+    compilation_state.files[0].walk_with(&mut visitor, &mut |entity| {
+        !matches!(entity.identifier(), "I" | "b" | "B")
+    });
+
+    // Assert
+    let expected = [
+        "file",
+        "module Test",
+        "struct S",
+        "field a",
+        "struct_end S",
+        "enum E",
+        "enumerator A",
+        "enumerator_end A",
+        "enum_end E",
+        "module_end Test",
+        "file_end",
+    ];
+    assert_eq!(visitor.events, expected);
+}
+
+#[test]
+fn modules_are_visited_along_with_their_definitions() {
+    // Arrange
+    let slice = "
+        module A
+        struct S {}
+
+        module B {
+            struct T {}
+        }
+
+        struct U {}
+    ";
+    let compilation_state = parse(slice, None);
+    let mut visitor = RecordingVisitor::default();
+
+    // Act
+    compilation_state.files[0].visit_with(&mut visitor);
+
+    // Assert
+    let expected = [
+        "file",
+        "module A",
+        "struct S",
+        "struct_end S",
+        "struct U",
+        "struct_end U",
+        "module_end A",
+        "module A::B",
+        "struct T",
+        "struct_end T",
+        "module_end A::B",
+        "file_end",
+    ];
+    assert_eq!(visitor.events, expected);
+}
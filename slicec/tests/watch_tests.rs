@@ -0,0 +1,74 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::TempDir;
+use slicec::compilation_state::CancellationToken;
+use slicec::slice_options::SliceOptions;
+use slicec::watch::{watch, Watcher};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn path_string(path: PathBuf) -> String {
+    path.to_str().unwrap().to_owned()
+}
+
+#[test]
+fn changes_to_watched_files_are_detected() {
+    // Arrange
+    let directory = TempDir::new("watch-changes");
+    let source = directory.join("a.slice");
+    let references = directory.join("references");
+    fs::write(&source, "module Test").unwrap();
+    fs::create_dir(&references).unwrap();
+    fs::write(references.join("b.slice"), "module Test").unwrap();
+    fs::write(references.join("notes.txt"), "not Slice").unwrap();
+    let options = SliceOptions {
+        sources: vec![path_string(source.clone())],
+        references: vec![path_string(references.clone())],
+        ..Default::default()
+    };
+    let mut watcher = Watcher::new(&options);
+
+    // Act/Assert
+    assert!(!watcher.has_changed());
+
+    // The contents have a different length, so the change is detected regardless of the file system's timestamps.
+    fs::write(&source, "module Test\nstruct S {}").unwrap();
+    assert!(watcher.has_changed());
+    assert!(!watcher.has_changed());
+
+    fs::write(references.join("c.slice"), "module Test").unwrap();
+    assert!(watcher.has_changed());
+
+    fs::remove_file(references.join("b.slice")).unwrap();
+    assert!(watcher.has_changed());
+
+    // Only Slice files are watched.
+    fs::write(references.join("notes.txt"), "still not Slice").unwrap();
+    assert!(!watcher.has_changed());
+}
+
+#[test]
+fn watching_compiles_until_cancelled() {
+    // Arrange
+    let directory = TempDir::new("watch-compile");
+    let source = directory.join("a.slice");
+    fs::write(&source, "module Test\nstruct S { x: Missing }").unwrap();
+    let options = SliceOptions {
+        sources: vec![path_string(source)],
+        ..Default::default()
+    };
+    let cancellation_token = CancellationToken::new();
+    let mut error_counts = Vec::new();
+
+    // Act
+    watch(&options, Duration::ZERO, |_| {}, |_| {}, &cancellation_token, |state| {
+        error_counts.push(state.diagnostics.into_inner().len());
+        cancellation_token.cancel();
+    });
+
+    // Assert
+    assert_eq!(error_counts, [1]);
+}
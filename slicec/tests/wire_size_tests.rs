@@ -0,0 +1,140 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+use crate::test_helpers::*;
+use slicec::grammar::*;
+use test_case::test_case;
+
+#[test_case("int32", 4, Some(4); "fixed size")]
+#[test_case("varuint62", 1, Some(8); "variable size")]
+#[test_case("string", 1, None; "string")]
+#[test_case("Sequence<uint8>", 1, None; "sequence")]
+#[test_case("Dictionary<uint8, bool>", 1, None; "dictionary")]
+#[test_case("Result<bool, int64>", 2, Some(9); "result")]
+fn wire_sizes_of_types(type_string: &str, expected_min: u32, expected_max: Option<u32>) {
+    // Arrange
+    let slice = format!(
+        "
+            module Test
+            typealias T = {type_string}
+        "
+    );
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let underlying = &ast.find_element::<TypeAlias>("Test::T").unwrap().underlying;
+    assert_eq!(underlying.min_wire_size(), expected_min);
+    assert_eq!(underlying.max_wire_size(), expected_max);
+}
+
+#[test]
+fn struct_wire_sizes_include_bit_sequences_tags_and_tag_end_markers() {
+    // Arrange
+    let slice = "
+        module Test
+        struct S {
+            a: int32
+            b: bool?
+            tag(1) c: int64?
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+
+    // bit sequence (1) + a (4) + tag end marker (1).
+    assert_eq!(struct_def.min_wire_size(), 6);
+    // bit sequence (1) + a (4) + b (1) + c's tag (1), size (1), and value (8) + tag end marker (1).
+    assert_eq!(struct_def.max_wire_size(), Some(17));
+}
+
+#[test]
+fn fixed_size_types_have_equal_min_and_max_sizes() {
+    // Arrange
+    let slice = "
+        module Test
+        compact struct S {
+            a: int32
+            b: uuid
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+    assert_eq!(struct_def.fixed_wire_size(), Some(20));
+    assert_eq!(struct_def.min_wire_size(), 20);
+    assert_eq!(struct_def.max_wire_size(), Some(20));
+}
+
+#[test]
+fn class_graphs_are_unbounded() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+        class C {
+            next: C?
+        }
+        compact struct S {
+            c: C
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+    assert_eq!(struct_def.min_wire_size(), 1);
+    assert_eq!(struct_def.max_wire_size(), None);
+}
+
+#[test]
+fn enums_with_underlying_types_use_their_underlying_size() {
+    // Arrange
+    let slice = "
+        module Test
+        enum E : varint32 { A, B }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let enum_def = ast.find_element::<Enum>("Test::E").unwrap();
+    assert_eq!(enum_def.min_wire_size(), 1);
+    assert_eq!(enum_def.max_wire_size(), Some(8));
+}
+
+#[test_case("", 3, Some(7); "checked")]
+#[test_case("unchecked", 3, None; "unchecked")]
+fn enums_with_fields_use_their_enumerators_sizes(modifier: &str, expected_min: u32, expected_max: Option<u32>) {
+    // Arrange
+    let slice = format!(
+        "
+            module Test
+            {modifier} enum E {{
+                A
+                B(x: int32)
+            }}
+        "
+    );
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    // Each enumerator is a discriminant (1), the size of its fields (1), its fields, and a tag end marker (1).
+    let enum_def = ast.find_element::<Enum>("Test::E").unwrap();
+    assert_eq!(enum_def.min_wire_size(), expected_min);
+    assert_eq!(enum_def.max_wire_size(), expected_max);
+}